@@ -6,9 +6,9 @@
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:lazynote_flutter/core/bindings/frb_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `atom_list_failure`, `atom_type_label`, `atom_update_status_impl`, `calendar_list_by_range_impl`, `calendar_update_event_impl`, `code`, `code`, `code`, `code`, `entry_create_note_impl`, `entry_create_task_impl`, `entry_schedule_impl`, `entry_search_impl`, `failure`, `is_db_busy`, `log_dart_event_impl`, `map_db_error`, `map_log_dart_event_error`, `map_note_service_error`, `map_repo_error`, `map_task_service_error`, `map_tree_repo_error`, `map_tree_service_error`, `map_workspace_db_error`, `message`, `message`, `message`, `message`, `normalize_entry_limit`, `normalize_log_dart_event_level`, `normalize_section_limit`, `note_create_impl`, `note_failure`, `note_get_impl`, `note_set_tags_impl`, `note_update_impl`, `notes_list_impl`, `parse_entry_search_kind`, `parse_folder_delete_mode`, `parse_note_id`, `parse_optional_parent_node_id`, `parse_workspace_atom_id`, `parse_workspace_node_id`, `resolve_entry_db_path`, `set_configured_entry_db_path`, `success`, `tags_list_impl`, `tasks_list_inbox_impl`, `tasks_list_today_impl`, `tasks_list_upcoming_impl`, `to_atom_list_item`, `to_entry_search_item`, `to_note_item`, `to_workspace_node_item`, `try_log_dart_event`, `validate_log_dart_event_event_name`, `validate_log_dart_event_message`, `validate_log_dart_event_module`, `with_atom_service`, `with_note_service`, `with_task_service`, `with_tree_service`, `workspace_create_folder_impl`, `workspace_create_note_ref_impl`, `workspace_delete_folder_impl`, `workspace_failure`, `workspace_list_children_impl`, `workspace_list_failure`, `workspace_move_node_impl`, `workspace_node_failure`, `workspace_node_kind_label`, `workspace_rename_node_impl`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AtomFfiError`, `LogDartEventFfiError`, `NotesFfiError`, `WorkspaceFfiError`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `atom_convert_failure`, `atom_convert_impl`, `atom_list_failure`, `atom_reminders_impl`, `atom_schedule_failure`, `atom_schedule_impl`, `atom_schedule_result`, `atom_set_all_day_impl`, `atom_set_due_impl`, `atom_set_priority_impl`, `atom_set_recurrence_impl`, `atom_task_meta_failure`, `atom_task_meta_impl`, `atom_task_meta_result`, `atom_time_entries_impl`, `atom_type_label`, `atom_update_status_impl`, `atom_update_status_with_impl`, `attachment_import_impl`, `attachment_resolve_impl`, `attachments_gc_impl`, `calendar_conflicts_failure`, `calendar_conflicts_impl`, `calendar_days_failure`, `calendar_days_impl`, `calendar_days_result`, `calendar_free_slots_impl`, `calendar_list_by_range_impl`, `calendar_month_impl`, `calendar_update_event_impl`, `calendar_week_impl`, `code`, `code`, `code`, `code`, `code`, `code`, `code`, `configured_calendar`, `entry_create_note_impl`, `entry_create_task_impl`, `entry_quick_add_parse_impl`, `entry_schedule_impl`, `entry_search_check_impl`, `entry_search_error_code`, `entry_search_impl`, `entry_search_page_impl`, `failure`, `flatten_outline`, `is_db_busy`, `links_launch_plan_impl`, `links_list_impl`, `links_search_impl`, `local_time_windows_impl`, `log_dart_event_impl`, `map_attachment_service_error`, `map_calendar_service_error`, `map_db_error`, `map_log_dart_event_error`, `map_note_service_error`, `map_recurrence_service_error`, `map_reminder_service_error`, `map_repo_error`, `map_revision_service_error`, `map_task_service_error`, `map_time_tracking_service_error`, `map_trash_service_error`, `map_tree_repo_error`, `map_tree_service_error`, `map_workspace_db_error`, `message`, `message`, `message`, `message`, `message`, `message`, `message`, `normalize_entry_limit`, `normalize_log_dart_event_level`, `normalize_section_limit`, `note_attachments_impl`, `note_create_impl`, `note_failure`, `note_get_impl`, `note_get_required`, `note_links_impl`, `note_outline_impl`, `note_properties_impl`, `note_revision_diff_impl`, `note_revision_restore_impl`, `note_revisions_list_impl`, `note_set_tags_impl`, `note_set_title_impl`, `note_tag_sources_impl`, `note_title_impl`, `note_update_impl`, `notes_list_by_tags_impl`, `notes_list_impl`, `parse_dependency_pair`, `parse_entry_search_kind`, `parse_folder_delete_mode`, `parse_local_date`, `parse_note_id`, `parse_occurrence_scope`, `parse_optional_parent_node_id`, `parse_reminder_id`, `parse_reminder_trigger`, `parse_time_atom_id`, `parse_time_date`, `parse_time_entry_id`, `parse_workspace_atom_id`, `parse_workspace_node_id`, `priority_label`, `recurrence_delete_occurrence_impl`, `recurrence_edit_occurrence_impl`, `recurrence_failure`, `recurrence_result`, `reminder_ack_impl`, `reminder_add_impl`, `reminder_remove_impl`, `reminder_response`, `reminder_snooze_impl`, `reminders_due_impl`, `resolve_entry_db_path`, `resource_links_response`, `set_configured_entry_db_path`, `set_configured_time_zone`, `subtask_failure`, `subtask_result`, `subtask_set_parent_impl`, `subtasks_reorder_impl`, `success`, `syntax_error_response`, `tag_delete_impl`, `tag_rename_impl`, `tags_list_impl`, `tags_merge_impl`, `tags_usage_impl`, `task_add_dependency_impl`, `task_dependencies_impl`, `task_dependency_failure`, `task_dependency_result`, `task_promote_checklist_impl`, `task_remove_dependency_impl`, `task_status_label`, `task_subtasks_impl`, `tasks_list_inbox_impl`, `tasks_list_inbox_unblocked_impl`, `tasks_list_inbox_with`, `tasks_list_overdue_impl`, `tasks_list_project_order_impl`, `tasks_list_subtasks_impl`, `tasks_list_today_impl`, `tasks_list_today_local_impl`, `tasks_list_today_unblocked_impl`, `tasks_list_today_with`, `tasks_list_upcoming_impl`, `tasks_list_upcoming_local_impl`, `time_entry_add_impl`, `time_entry_delete_impl`, `time_entry_response`, `time_entry_update_impl`, `time_report_impl`, `time_timer_running_impl`, `time_timer_start_impl`, `time_timer_stop_impl`, `to_atom_list_item`, `to_attachment_item`, `to_calendar_conflict_item`, `to_calendar_day_item`, `to_calendar_entry_item`, `to_entry_search_hit_item`, `to_entry_search_item`, `to_launch_plan_response`, `to_note_diff_line_item`, `to_note_item`, `to_note_link_item`, `to_note_property_item`, `to_note_revision_item`, `to_note_title_response`, `to_reminder_item`, `to_resource_link_item`, `to_tag_edit_response`, `to_time_entry_item`, `to_trash_list_item`, `to_trash_purge_response`, `to_workspace_node_item`, `trash_list_impl`, `trash_purge_expired_impl`, `trash_purge_failure`, `trash_purge_impl`, `trash_restore_impl`, `try_log_dart_event`, `validate_log_dart_event_event_name`, `validate_log_dart_event_message`, `validate_log_dart_event_module`, `weekday_label`, `with_atom_convert_service`, `with_atom_service`, `with_attachment_service`, `with_calendar_service`, `with_link_service`, `with_note_service`, `with_recurrence_service`, `with_reminder_service`, `with_resource_link_service`, `with_revision_service`, `with_task_service`, `with_time_tracking_service`, `with_trash_service`, `with_tree_service`, `workspace_create_folder_impl`, `workspace_create_note_ref_impl`, `workspace_delete_folder_impl`, `workspace_failure`, `workspace_list_children_impl`, `workspace_list_failure`, `workspace_move_node_impl`, `workspace_node_failure`, `workspace_node_kind_label`, `workspace_rename_node_impl`, `workspace_repair_note_titles_impl`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AtomFfiError`, `AttachmentFfiError`, `LogDartEventFfiError`, `NotesFfiError`, `ReminderFfiError`, `TimeFfiError`, `WorkspaceFfiError`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
 /// Minimal health-check API for FRB smoke integration.
 ///
@@ -49,6 +49,29 @@ String initLogging({required String level, required String logDir}) =>
 String configureEntryDbPath({required String dbPath}) =>
     RustLib.instance.api.crateApiConfigureEntryDbPath(dbPath: dbPath);
 
+/// Configures the process-local timezone used by `*_local` section queries,
+/// `local_time_windows` and recurring timed atom expansion.
+///
+/// Input semantics:
+/// - `time_zone`: IANA name (e.g. `Europe/Berlin`), resolved by Flutter when
+///   the user setting is `system`. Looked up in the tz database bundled into
+///   Rust Core; no system zoneinfo or network access.
+/// - `week_start`: weekday name, e.g. `monday|sunday|saturday`
+///   (case-insensitive).
+///
+/// # FFI contract
+/// - Sync call, non-blocking.
+/// - Safe to call multiple times; latest successful setting wins. Until the
+///   first successful call, windows are computed in `UTC` with Monday weeks.
+/// - Returns empty string on success, error message on validation failure.
+String configureTimeZone({
+  required String timeZone,
+  required String weekStart,
+}) => RustLib.instance.api.crateApiConfigureTimeZone(
+  timeZone: timeZone,
+  weekStart: weekStart,
+);
+
 /// Writes one structured Dart event into the Rust session log stream.
 ///
 /// # FFI contract
@@ -85,6 +108,48 @@ Future<EntrySearchResponse> entrySearch({
   limit: limit,
 );
 
+/// Searches one page with scores, highlight ranges and a next-page cursor.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Never panics.
+/// - `cursor`: `next_cursor` from the previous page; `None` starts over.
+/// - Same `kind`/limit rules and error codes as [`entry_search`], plus
+///   `invalid_cursor`.
+Future<EntrySearchPageResponse> entrySearchPage({
+  required String text,
+  String? kind,
+  int? limit,
+  String? cursor,
+}) => RustLib.instance.api.crateApiEntrySearchPage(
+  text: text,
+  kind: kind,
+  limit: limit,
+  cursor: cursor,
+);
+
+/// Parses single-entry search text with the structured query grammar.
+///
+/// # FFI contract
+/// - Sync call, no DB access.
+/// - Offsets are char indices so the UI can underline the offending span.
+/// - Returns `invalid_query_syntax` with `error_start/error_end` on failure.
+EntrySearchSyntaxResponse entrySearchCheck({required String text}) =>
+    RustLib.instance.api.crateApiEntrySearchCheck(text: text);
+
+/// Parses single-entry text into a typed quick-add draft.
+///
+/// # FFI contract
+/// - Sync call, no DB access; deterministic for the same `text`, `now_ms`
+///   and configured timezone (`configure_time_zone`).
+/// - Recognizes English and Chinese dates/times, `#tags`, `!high|!medium|!low`
+///   (`!高|!中|!低`) and `task:|event:|note:` prefixes.
+/// - Never fails; unrecognized text stays in `title`. Nothing is persisted.
+EntryQuickAddResponse entryQuickAddParse({
+  required String text,
+  required PlatformInt64 nowMs,
+}) => RustLib.instance.api.crateApiEntryQuickAddParse(text: text, nowMs: nowMs);
+
 /// Creates a note from single-entry command flow.
 ///
 /// # FFI contract
@@ -148,6 +213,98 @@ Future<NoteResponse> noteUpdate({
 Future<NoteResponse> noteGet({required String atomId}) =>
     RustLib.instance.api.crateApiNoteGet(atomId: atomId);
 
+/// Lists captured revisions of one note, newest first.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Limit normalization: default 20, max 100.
+/// - Rapid autosaves are coalesced; not every `note_update` yields a revision.
+Future<NoteRevisionsListResponse> noteRevisionsList({
+  required String atomId,
+  int? limit,
+  int? offset,
+}) => RustLib.instance.api.crateApiNoteRevisionsList(
+  atomId: atomId,
+  limit: limit,
+  offset: offset,
+);
+
+/// Computes a line-level diff between two note revisions.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `to_revision_id = None` diffs against the current note content.
+/// - Returns `revision_not_found` when either revision does not belong to
+///   the note.
+Future<NoteRevisionDiffResponse> noteRevisionDiff({
+  required String atomId,
+  required PlatformInt64 fromRevisionId,
+  PlatformInt64? toRevisionId,
+}) => RustLib.instance.api.crateApiNoteRevisionDiff(
+  atomId: atomId,
+  fromRevisionId: fromRevisionId,
+  toRevisionId: toRevisionId,
+);
+
+/// Restores one revision as the current note content.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - The replaced content is kept as a new revision, so restore is undoable.
+/// - Returns the restored note on success.
+Future<NoteResponse> noteRevisionRestore({
+  required String atomId,
+  required PlatformInt64 revisionId,
+}) => RustLib.instance.api.crateApiNoteRevisionRestore(
+  atomId: atomId,
+  revisionId: revisionId,
+);
+
+/// Loads outgoing wiki-links and backlinks for one note.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Outgoing links keep document order; unresolved ones carry
+///   `target_atom_id = None`.
+/// - Backlinks exclude self-references and deleted sources.
+Future<NoteLinksResponse> noteLinks({required String atomId}) =>
+    RustLib.instance.api.crateApiNoteLinks(atomId: atomId);
+
+/// Lists Markdown links and bare URLs indexed for one note.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Items keep document order.
+Future<ResourceLinksResponse> linksList({required String atomId}) =>
+    RustLib.instance.api.crateApiLinksList(atomId: atomId);
+
+/// Searches indexed link targets and labels across active atoms.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Case-insensitive substring match; blank query returns no items.
+/// - Limit normalization: default 20, max 100.
+Future<ResourceLinksResponse> linksSearch({
+  required String query,
+  int? limit,
+}) => RustLib.instance.api.crateApiLinksSearch(query: query, limit: limit);
+
+/// Builds a vetted "open all" plan for the links of one note.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Schemes are limited to `http/https/file`; callers cannot widen it.
+/// - `file://` targets must exist locally; `max_items` defaults to 10 and
+///   clamps to 50.
+/// - UI must only open `items`; core never launches anything itself.
+Future<LaunchPlanResponse> linksLaunchPlan({
+  required String atomId,
+  int? maxItems,
+}) => RustLib.instance.api.crateApiLinksLaunchPlan(
+  atomId: atomId,
+  maxItems: maxItems,
+);
+
 /// Lists notes with optional single-tag filter and pagination.
 ///
 /// # FFI contract
@@ -161,17 +318,90 @@ Future<NotesListResponse> notesList({String? tag, int? limit, int? offset}) =>
       offset: offset,
     );
 
+/// Lists notes matching a boolean tag expression with pagination.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `tag_expr` syntax: `(work OR client) AND NOT archived`; `AND`/`OR`/`NOT`
+///   are case-insensitive, `-tag` means `NOT tag`, adjacent tags are ANDed.
+/// - Returns `invalid_tag_expr` (message carries char offsets) on parse errors.
+/// - Limit normalization: default 10, max 50.
+Future<NotesListResponse> notesListByTags({
+  required String tagExpr,
+  int? limit,
+  int? offset,
+}) => RustLib.instance.api.crateApiNotesListByTags(
+  tagExpr: tagExpr,
+  limit: limit,
+  offset: offset,
+);
+
 /// Atomically replaces full tag set for one note.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
 /// - `tags` is treated as complete replacement, not incremental patch.
+/// - Replaces manual tags only; they are kept even when the content carries
+///   the same inline `#hashtag`.
 /// - Returns typed envelope with stable error codes.
 Future<NoteResponse> noteSetTags({
   required String atomId,
   required List<String> tags,
 }) => RustLib.instance.api.crateApiNoteSetTags(atomId: atomId, tags: tags);
 
+/// Splits one note's tags by source (manual vs inline `#hashtag`).
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `NoteItem.tags` is the union of both lists; a tag can appear in both.
+/// - Inline tags follow the content and are not removable via
+///   `note_set_tags`.
+Future<NoteTagSourcesResponse> noteTagSources({required String atomId}) =>
+    RustLib.instance.api.crateApiNoteTagSources(atomId: atomId);
+
+/// Lists typed properties parsed from one note's YAML frontmatter.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Properties are re-derived from content on every save; empty when the
+///   note has no (or malformed) frontmatter.
+/// - Exactly one of `text` / `number` / `items` is populated per item.
+Future<NotePropertiesResponse> noteProperties({required String atomId}) =>
+    RustLib.instance.api.crateApiNoteProperties(atomId: atomId);
+
+/// Returns one note's heading outline plus word/char counts and checklist
+/// progress.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Headings come from a CommonMark/GFM parse (headings inside code blocks
+///   are ignored); byte offsets index into `NoteItem.content`.
+/// - Counts are cached per save and exclude frontmatter and markup.
+Future<NoteOutlineResponse> noteOutline({required String atomId}) =>
+    RustLib.instance.api.crateApiNoteOutline(atomId: atomId);
+
+/// Returns one note's title projection.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `title` = `title_override`, else `derived_title`, else `Untitled note`.
+/// - `derived_title` is the first heading, else the first text line (max 80
+///   chars), refreshed on every save.
+Future<NoteTitleResponse> noteTitle({required String atomId}) =>
+    RustLib.instance.api.crateApiNoteTitle(atomId: atomId);
+
+/// Sets or clears one note's explicit title.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `title = None` or blank clears the override; whitespace runs collapse.
+/// - Workspace note_refs that were never renamed manually take the new
+///   effective title; `[[Title]]` links re-resolve.
+Future<NoteTitleResponse> noteSetTitle({
+  required String atomId,
+  String? title,
+}) => RustLib.instance.api.crateApiNoteSetTitle(atomId: atomId, title: title);
+
 /// Lists normalized tags known by storage.
 ///
 /// # FFI contract
@@ -179,6 +409,44 @@ Future<NoteResponse> noteSetTags({
 /// - Returns typed envelope with stable error codes.
 Future<TagsListResponse> tagsList() => RustLib.instance.api.crateApiTagsList();
 
+/// Renames one tag and its `/` descendants across all atoms.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `work` -> `job` also moves `work/client` to `job/client`.
+/// - Returns `tag_not_found` when `from` has no row, `tag_conflict` when a
+///   renamed path already exists (use `tags_merge` instead).
+Future<TagEditResponse> tagRename({required String from, required String to}) =>
+    RustLib.instance.api.crateApiTagRename(from: from, to: to);
+
+/// Merges source tags (and descendants) into one target tag.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Atoms carrying both a source and the target keep a single link.
+/// - Returns `invalid_tag` when `target` sits below a source tag.
+Future<TagEditResponse> tagsMerge({
+  required List<String> sources,
+  required String target,
+}) => RustLib.instance.api.crateApiTagsMerge(sources: sources, target: target);
+
+/// Removes one tag and its `/` descendants from every atom.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Returns `tag_not_found` when no matching tag exists.
+Future<TagEditResponse> tagDelete({required String name}) =>
+    RustLib.instance.api.crateApiTagDelete(name: name);
+
+/// Lists per-tag usage counts over live atoms.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Implicit parents (`work` for a lone `work/client`) report
+///   `direct_count = 0`.
+Future<TagsUsageResponse> tagsUsage() =>
+    RustLib.instance.api.crateApiTagsUsage();
+
 /// Lists workspace child nodes under optional parent.
 ///
 /// # FFI contract
@@ -208,6 +476,8 @@ Future<WorkspaceNodeResponse> workspaceCreateFolder({
 /// # FFI contract
 /// - Async call, DB-backed execution.
 /// - `atom_id` must be UUID string of a note atom.
+/// - `display_name = None` names the node after the note title and keeps it
+///   following title changes until renamed.
 Future<WorkspaceNodeResponse> workspaceCreateNoteRef({
   String? parentNodeId,
   required String atomId,
@@ -231,6 +501,15 @@ Future<WorkspaceActionResponse> workspaceRenameNode({
   newName: newName,
 );
 
+/// Renames title-following note_refs whose name is stale.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Fixes legacy `Untitled note` rows from the note_ref backfill; safe to
+///   call on every startup (returns `renamed_count = 0` once repaired).
+Future<WorkspaceRepairResponse> workspaceRepairNoteTitles() =>
+    RustLib.instance.api.crateApiWorkspaceRepairNoteTitles();
+
 /// Moves one workspace node under optional new parent and target order.
 ///
 /// # FFI contract
@@ -268,6 +547,16 @@ Future<WorkspaceActionResponse> workspaceDeleteFolder({
 Future<AtomListResponse> tasksListInbox({int? limit, int? offset}) =>
     RustLib.instance.api.crateApiTasksListInbox(limit: limit, offset: offset);
 
+/// Lists inbox atoms without blocked tasks.
+///
+/// # FFI contract
+/// - Same as `tasks_list_inbox`, minus tasks with an unfinished prerequisite.
+Future<AtomListResponse> tasksListInboxUnblocked({int? limit, int? offset}) =>
+    RustLib.instance.api.crateApiTasksListInboxUnblocked(
+      limit: limit,
+      offset: offset,
+    );
+
 /// Lists atoms active today based on time-matrix rules.
 ///
 /// # FFI contract
@@ -286,6 +575,23 @@ Future<AtomListResponse> tasksListToday({
   offset: offset,
 );
 
+/// Lists today atoms without blocked tasks.
+///
+/// # FFI contract
+/// - Same as `tasks_list_today`, minus tasks (and recurring task series)
+///   with an unfinished prerequisite.
+Future<AtomListResponse> tasksListTodayUnblocked({
+  required PlatformInt64 bodMs,
+  required PlatformInt64 eodMs,
+  int? limit,
+  int? offset,
+}) => RustLib.instance.api.crateApiTasksListTodayUnblocked(
+  bodMs: bodMs,
+  eodMs: eodMs,
+  limit: limit,
+  offset: offset,
+);
+
 /// Lists atoms anchored entirely in the future.
 ///
 /// # FFI contract
@@ -302,6 +608,79 @@ Future<AtomListResponse> tasksListUpcoming({
   offset: offset,
 );
 
+/// Lists atoms active on the local day of `now_ms`.
+///
+/// # FFI contract
+/// - `now_ms`: current instant in epoch ms; the day window is computed by
+///   Rust Core in the timezone set via `configure_time_zone` (DST-aware).
+/// - All-day atoms match by local date and are listed before timed atoms.
+/// - `hide_blocked`: omit tasks with an unfinished prerequisite.
+/// - Async call, DB-backed execution. Excludes done/cancelled atoms.
+Future<AtomListResponse> tasksListTodayLocal({
+  required PlatformInt64 nowMs,
+  required bool hideBlocked,
+  int? limit,
+  int? offset,
+}) => RustLib.instance.api.crateApiTasksListTodayLocal(
+  nowMs: nowMs,
+  hideBlocked: hideBlocked,
+  limit: limit,
+  offset: offset,
+);
+
+/// Lists atoms anchored after the local day of `now_ms`.
+///
+/// # FFI contract
+/// - `now_ms`: current instant in epoch ms; see `tasks_list_today_local`.
+/// - All-day atoms sort at their local midnight.
+/// - Async call, DB-backed execution. Excludes done/cancelled atoms.
+Future<AtomListResponse> tasksListUpcomingLocal({
+  required PlatformInt64 nowMs,
+  int? limit,
+  int? offset,
+}) => RustLib.instance.api.crateApiTasksListUpcomingLocal(
+  nowMs: nowMs,
+  limit: limit,
+  offset: offset,
+);
+
+/// Returns local day, week and month boundaries containing `now_ms`.
+///
+/// # FFI contract
+/// - Sync call, non-blocking; no DB access.
+/// - Uses the timezone and week start set via `configure_time_zone`.
+/// - Boundaries are inclusive epoch ms; DST days are 23h or 25h long.
+LocalTimeWindowsResponse localTimeWindows({required PlatformInt64 nowMs}) =>
+    RustLib.instance.api.crateApiLocalTimeWindows(nowMs: nowMs);
+
+/// Reads whether one atom is timed or all-day, with its bounds.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `atom_not_found` for missing or deleted atoms.
+Future<AtomScheduleResponse> atomSchedule({required String atomId}) =>
+    RustLib.instance.api.crateApiAtomSchedule(atomId: atomId);
+
+/// Makes any atom all-day over local dates `start_date..=end_date`.
+///
+/// # FFI contract
+/// - Dates are `YYYY-MM-DD`; `end_date` is inclusive and defaults to
+///   `start_date`.
+/// - Stored bounds are floating: the atom covers the same dates in every
+///   timezone. `calendar_update_event` makes it timed again.
+/// - `invalid_argument` for malformed dates; `invalid_time_range` when
+///   `end_date < start_date`.
+/// - Async call, DB-backed execution.
+Future<AtomScheduleResponse> atomSetAllDay({
+  required String atomId,
+  required String startDate,
+  String? endDate,
+}) => RustLib.instance.api.crateApiAtomSetAllDay(
+  atomId: atomId,
+  startDate: startDate,
+  endDate: endDate,
+);
+
 /// Updates `task_status` for any atom type (universal completion).
 ///
 /// # FFI contract
@@ -316,6 +695,174 @@ Future<EntryActionResponse> atomUpdateStatus({
   status: status,
 );
 
+/// Updates `task_status`, optionally stopping running timers on completion.
+///
+/// # FFI contract
+/// - Same as `atom_update_status`.
+/// - `stop_timer_at`: when set, this device's running timers on every atom
+///   that becomes `done` (auto-completed parents included) stop at this
+///   epoch ms; null leaves timers running.
+Future<EntryActionResponse> atomUpdateStatusWith({
+  required String atomId,
+  String? status,
+  PlatformInt64? stopTimerAt,
+}) => RustLib.instance.api.crateApiAtomUpdateStatusWith(
+  atomId: atomId,
+  status: status,
+  stopTimerAt: stopTimerAt,
+);
+
+/// Lists incomplete atoms past their deadline, highest priority first.
+///
+/// # FFI contract
+/// - `now_ms`: current time in epoch ms; deadlines strictly before it are
+///   overdue.
+/// - Deadline = `due_at`, else `end_at` of a deadline-only atom.
+/// - Order: priority `high > medium > low > none`, then earliest deadline.
+/// - Async call, DB-backed execution. Excludes done/cancelled atoms.
+Future<AtomListResponse> tasksListOverdue({
+  required PlatformInt64 nowMs,
+  int? limit,
+  int? offset,
+}) => RustLib.instance.api.crateApiTasksListOverdue(
+  nowMs: nowMs,
+  limit: limit,
+  offset: offset,
+);
+
+/// Reads priority and deadline of one atom.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `atom_not_found` for missing or deleted atoms.
+Future<AtomTaskMetaResponse> atomTaskMeta({required String atomId}) =>
+    RustLib.instance.api.crateApiAtomTaskMeta(atomId: atomId);
+
+/// Sets or clears the priority of any atom type.
+///
+/// # FFI contract
+/// - `priority`: one of `low|medium|high`, or null to clear.
+/// - Async call, DB-backed execution. Idempotent.
+Future<AtomTaskMetaResponse> atomSetPriority({
+  required String atomId,
+  String? priority,
+}) => RustLib.instance.api.crateApiAtomSetPriority(
+  atomId: atomId,
+  priority: priority,
+);
+
+/// Sets or clears the `due_at` deadline of any atom type.
+///
+/// # FFI contract
+/// - `due_at`: epoch ms, or null to clear. Independent of `start_at/end_at`.
+/// - Async call, DB-backed execution. Idempotent.
+Future<AtomTaskMetaResponse> atomSetDue({
+  required String atomId,
+  PlatformInt64? dueAt,
+}) => RustLib.instance.api.crateApiAtomSetDue(atomId: atomId, dueAt: dueAt);
+
+/// Lists the active subtasks of one task in display order.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution. Not paginated; `applied_limit` echoes
+///   the item count.
+/// - Includes done/cancelled subtasks; excludes deleted ones.
+Future<AtomListResponse> tasksListSubtasks({required String parentId}) =>
+    RustLib.instance.api.crateApiTasksListSubtasks(parentId: parentId);
+
+/// Reads parent, subtask order and completion progress of one task.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `total` excludes cancelled subtasks.
+Future<SubtaskResponse> taskSubtasks({required String atomId}) =>
+    RustLib.instance.api.crateApiTaskSubtasks(atomId: atomId);
+
+/// Moves a task under a parent task, or back to top level.
+///
+/// # FFI contract
+/// - Both atoms must be tasks (`not_a_task` otherwise).
+/// - `parent_id`: null detaches the task.
+/// - `target_index`: position among siblings; null or out of range appends.
+/// - `cycle_detected` when the parent is the task itself or one of its
+///   descendants.
+/// - Response describes the moved task.
+Future<SubtaskResponse> subtaskSetParent({
+  required String atomId,
+  String? parentId,
+  int? targetIndex,
+}) => RustLib.instance.api.crateApiSubtaskSetParent(
+  atomId: atomId,
+  parentId: parentId,
+  targetIndex: targetIndex,
+);
+
+/// Rewrites the subtask order of one task.
+///
+/// # FFI contract
+/// - `ordered_ids` must list every active subtask exactly once
+///   (`invalid_argument` otherwise).
+Future<SubtaskResponse> subtasksReorder({
+  required String parentId,
+  required List<String> orderedIds,
+}) => RustLib.instance.api.crateApiSubtasksReorder(
+  parentId: parentId,
+  orderedIds: orderedIds,
+);
+
+/// Turns the top-level `- [ ]`/`- [x]` items of a task into subtasks.
+///
+/// # FFI contract
+/// - Checked items become `done` subtasks; items are removed from the
+///   parent content and appended after existing subtasks.
+/// - Returns the full subtask list; `message` reports the promoted count.
+Future<SubtaskResponse> taskPromoteChecklist({required String atomId}) =>
+    RustLib.instance.api.crateApiTaskPromoteChecklist(atomId: atomId);
+
+/// Reads blocked state, prerequisites and dependents of one task.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `not_a_task` when the atom is not a task.
+Future<TaskDependencyResponse> taskDependencies({required String atomId}) =>
+    RustLib.instance.api.crateApiTaskDependencies(atomId: atomId);
+
+/// Makes a task depend on a prerequisite task.
+///
+/// # FFI contract
+/// - Both atoms must be tasks (`not_a_task` otherwise).
+/// - `cycle_detected` when the prerequisite is the task itself or already
+///   depends on it, directly or transitively.
+/// - Idempotent: adding an existing dependency succeeds.
+Future<TaskDependencyResponse> taskAddDependency({
+  required String atomId,
+  required String dependsOnId,
+}) => RustLib.instance.api.crateApiTaskAddDependency(
+  atomId: atomId,
+  dependsOnId: dependsOnId,
+);
+
+/// Removes a dependency between two tasks.
+///
+/// # FFI contract
+/// - Idempotent: removing a missing dependency succeeds.
+Future<TaskDependencyResponse> taskRemoveDependency({
+  required String atomId,
+  required String dependsOnId,
+}) => RustLib.instance.api.crateApiTaskRemoveDependency(
+  atomId: atomId,
+  dependsOnId: dependsOnId,
+);
+
+/// Lists the subtask tree of a project task in dependency order.
+///
+/// # FFI contract
+/// - Prerequisites come before their dependents; ties keep outline order.
+/// - Includes all statuses; not paginated (`applied_limit` echoes the item
+///   count).
+Future<AtomListResponse> tasksListProjectOrder({required String projectId}) =>
+    RustLib.instance.api.crateApiTasksListProjectOrder(projectId: projectId);
+
 /// Lists atoms with both `start_at` and `end_at` that overlap the given time range.
 ///
 /// # FFI contract
@@ -350,83 +897,3376 @@ Future<EntryActionResponse> calendarUpdateEvent({
   endMs: endMs,
 );
 
-/// Atom list item returned by section queries (Inbox/Today/Upcoming).
-class AtomListItem {
-  /// Stable atom ID in string form.
-  final String atomId;
+/// Returns per-day buckets for local dates `start_date..=end_date`.
+///
+/// # FFI contract
+/// - Dates are `YYYY-MM-DD` in the timezone set via `configure_time_zone`.
+/// - Multi-day atoms appear in every day they touch; timed entries carry
+///   overlap `column`/`column_count`. Includes all statuses.
+/// - `invalid_argument` for malformed dates or ranges above 93 days;
+///   `invalid_time_range` when `end_date < start_date`.
+/// - Async call, DB-backed execution.
+Future<CalendarDaysResponse> calendarDays({
+  required String startDate,
+  required String endDate,
+}) => RustLib.instance.api.crateApiCalendarDays(
+  startDate: startDate,
+  endDate: endDate,
+);
 
-  /// Atom projection kind (`note|task|event`).
-  final String kind;
+/// Returns per-day buckets for the local week containing `now_ms`.
+///
+/// # FFI contract
+/// - Week start follows `configure_time_zone`; otherwise as `calendar_days`.
+Future<CalendarDaysResponse> calendarWeek({required PlatformInt64 nowMs}) =>
+    RustLib.instance.api.crateApiCalendarWeek(nowMs: nowMs);
+
+/// Returns per-day buckets for the local month containing `now_ms`.
+///
+/// # FFI contract
+/// - Same as `calendar_days` for the whole month.
+Future<CalendarDaysResponse> calendarMonth({required PlatformInt64 nowMs}) =>
+    RustLib.instance.api.crateApiCalendarMonth(nowMs: nowMs);
+
+/// Lists double-booked events touching local dates `start_date..=end_date`.
+///
+/// # FFI contract
+/// - Only timed `event` atoms with a positive duration that are not
+///   `cancelled` count; touching events (`end == start`) do not conflict.
+/// - Errors as `calendar_days`. Async call, DB-backed execution.
+Future<CalendarConflictsResponse> calendarConflicts({
+  required String startDate,
+  required String endDate,
+}) => RustLib.instance.api.crateApiCalendarConflicts(
+  startDate: startDate,
+  endDate: endDate,
+);
+
+/// Finds free gaps of at least `duration_minutes` in `[start_ms, end_ms)`.
+///
+/// # FFI contract
+/// - Busy time: timed atoms of any kind with a positive duration, except
+///   `cancelled`; all-day atoms never block time.
+/// - `invalid_time_range` when `end_ms <= start_ms`; `invalid_argument` for
+///   `duration_minutes == 0` or ranges above 93 days.
+/// - Async call, DB-backed execution.
+Future<CalendarFreeSlotsResponse> calendarFreeSlots({
+  required PlatformInt64 startMs,
+  required PlatformInt64 endMs,
+  required int durationMinutes,
+}) => RustLib.instance.api.crateApiCalendarFreeSlots(
+  startMs: startMs,
+  endMs: endMs,
+  durationMinutes: durationMinutes,
+);
+
+/// Converts one atom between `note`, `task` and `event` in place.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `target_kind`: one of `note|task|event`; UUID, content, tags and
+///   workspace note_refs are kept.
+/// - `note`: clears status and times. `task`: keeps status (default `todo`).
+///   `event`: start = `start_ms`, else current start, else a deadline-only
+///   `end_at`; returns `event_start_required` when none applies.
+/// - `start_ms`/`end_ms` override current times for task/event targets;
+///   `invalid_time_range` when the result has `end < start`.
+/// - note_refs hide while the atom is not a note and reappear on converting
+///   back.
+Future<AtomConvertResponse> atomConvert({
+  required String atomId,
+  required String targetKind,
+  PlatformInt64? startMs,
+  PlatformInt64? endMs,
+}) => RustLib.instance.api.crateApiAtomConvert(
+  atomId: atomId,
+  targetKind: targetKind,
+  startMs: startMs,
+  endMs: endMs,
+);
+
+/// Sets or clears the recurrence rule of one atom.
+///
+/// # FFI contract
+/// - `rule`: RFC 5545 RRULE value (`FREQ=WEEKLY;BYDAY=MO,WE`), or null to
+///   make the atom a single atom again. Stored in canonical form.
+/// - The series is anchored at the atom's `start_at` and expands in the
+///   configured time zone.
+/// - `invalid_argument` for malformed rules or atoms without `start_at`.
+/// - Async call, DB-backed execution. Idempotent.
+Future<RecurrenceResponse> atomSetRecurrence({
+  required String atomId,
+  String? rule,
+}) =>
+    RustLib.instance.api.crateApiAtomSetRecurrence(atomId: atomId, rule: rule);
+
+/// Edits one occurrence of a recurring series.
+///
+/// # FFI contract
+/// - Occurrences are addressed by `series_id` (the item `atom_id`) plus
+///   `occurrence_start_at` as returned in list/calendar items.
+/// - `scope`: `this` detaches a standalone atom for the occurrence;
+///   `this_and_following` splits the series there (or edits it in place
+///   from the first occurrence).
+/// - Null fields keep the series value; changing only `start_at` keeps the
+///   duration. `status` is `todo|in_progress|done|cancelled`.
+/// - `recurrence_rule` replaces the rule of the following part and is only
+///   accepted with `this_and_following`.
+/// - Returns the atom now holding the occurrence.
+/// - `atom_not_found`, `not_recurring`, `occurrence_not_found` (not generated
+///   by the rule or already excepted), `invalid_time_range`.
+/// - Async call, DB-backed execution.
+Future<RecurrenceResponse> recurrenceEditOccurrence({
+  required String seriesId,
+  required PlatformInt64 occurrenceStartAt,
+  required String scope,
+  String? content,
+  PlatformInt64? startAt,
+  PlatformInt64? endAt,
+  String? status,
+  String? recurrenceRule,
+}) => RustLib.instance.api.crateApiRecurrenceEditOccurrence(
+  seriesId: seriesId,
+  occurrenceStartAt: occurrenceStartAt,
+  scope: scope,
+  content: content,
+  startAt: startAt,
+  endAt: endAt,
+  status: status,
+  recurrenceRule: recurrenceRule,
+);
+
+/// Deletes one occurrence of a recurring series.
+///
+/// # FFI contract
+/// - `scope`: `this` skips the occurrence (EXDATE); `this_and_following`
+///   ends the series before it, or soft-deletes the whole series from the
+///   first occurrence.
+/// - Returns the series ID and its remaining rule (null once deleted).
+/// - Same error codes as `recurrence_edit_occurrence`.
+/// - Async call, DB-backed execution.
+Future<RecurrenceResponse> recurrenceDeleteOccurrence({
+  required String seriesId,
+  required PlatformInt64 occurrenceStartAt,
+  required String scope,
+}) => RustLib.instance.api.crateApiRecurrenceDeleteOccurrence(
+  seriesId: seriesId,
+  occurrenceStartAt: occurrenceStartAt,
+  scope: scope,
+);
+
+/// Lists soft-deleted atoms that can still be restored.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `kind`: optional `all|note|task|event`; invalid values return
+///   `invalid_argument`.
+/// - `limit` defaults to 20 and clamps to 100.
+/// - Sorted by deletion time descending.
+Future<TrashListResponse> trashList({String? kind, int? limit, int? offset}) =>
+    RustLib.instance.api.crateApiTrashList(
+      kind: kind,
+      limit: limit,
+      offset: offset,
+    );
+
+/// Restores one trashed atom.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Returns `atom_not_found` when the atom is not in the trash.
+/// - A restored note always has an active `note_ref`; `note_ref_node_id` is
+///   set when one had to be revived or created (at root level).
+Future<TrashRestoreResponse> trashRestore({required String atomId}) =>
+    RustLib.instance.api.crateApiTrashRestore(atomId: atomId);
+
+/// Permanently purges one trashed atom.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Returns `atom_not_found` for missing or non-deleted atoms.
+/// - When a provider mapping has not synced the deletion, content is
+///   scrubbed and a tombstone is kept (`kept_for_sync = 1`).
+Future<TrashPurgeResponse> trashPurge({required String atomId}) =>
+    RustLib.instance.api.crateApiTrashPurge(atomId: atomId);
+
+/// Purges atoms that stayed in the trash longer than the retention window.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution; intended for app start/idle.
+/// - `retention_days`: the `trash.retention_days` setting; null means 30,
+///   `0` disables auto-purge, values clamp to 3650.
+/// - Same tombstone rule as [`trash_purge`].
+Future<TrashPurgeResponse> trashPurgeExpired({int? retentionDays}) =>
+    RustLib.instance.api.crateApiTrashPurgeExpired(
+      retentionDays: retentionDays,
+    );
+
+/// Copies one file into the content-addressed attachment store.
+///
+/// # FFI contract
+/// - Async call, DB + filesystem execution.
+/// - Blob is stored next to the entry DB under `attachments/`.
+/// - Identical bytes are stored once (`deduplicated = true` on repeats).
+/// - Returns `attachment.uri` (`attachment://<sha256>`) for use in markdown.
+Future<AttachmentImportResponse> attachmentImport({
+  required String sourcePath,
+}) => RustLib.instance.api.crateApiAttachmentImport(sourcePath: sourcePath);
+
+/// Resolves an `attachment://` URI to its stored blob path.
+///
+/// # FFI contract
+/// - Async call, DB + filesystem execution.
+/// - Accepts `attachment://<sha256>` or a bare digest.
+/// - Returns `attachment_not_found` when the blob is not on this device.
+Future<AttachmentResolveResponse> attachmentResolve({required String uri}) =>
+    RustLib.instance.api.crateApiAttachmentResolve(uri: uri);
+
+/// Lists attachments referenced by one atom's content.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Document order; references without a stored blob are skipped.
+Future<AttachmentsListResponse> noteAttachments({required String atomId}) =>
+    RustLib.instance.api.crateApiNoteAttachments(atomId: atomId);
+
+/// Removes attachments no atom references anymore.
+///
+/// # FFI contract
+/// - Async call, DB + filesystem execution; intended for app idle.
+/// - `grace_ms`: keep unreferenced attachments younger than this window so
+///   freshly imported files survive until saved; null means 24 hours.
+/// - Trashed atoms still count as references until purged.
+Future<AttachmentGcResponse> attachmentsGc({PlatformInt64? graceMs}) =>
+    RustLib.instance.api.crateApiAttachmentsGc(graceMs: graceMs);
+
+/// Attaches a reminder to an atom.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `anchor`: `absolute` (requires `remind_at`), or `start|due` with
+///   `offset_ms` (negative = before, null = 0).
+/// - `missing_anchor` when the atom has no `start_at`/`due_at` to anchor to.
+/// - Firings scheduled before the call are never delivered.
+Future<ReminderResponse> reminderAdd({
+  required String atomId,
+  required String anchor,
+  PlatformInt64? remindAt,
+  PlatformInt64? offsetMs,
+}) => RustLib.instance.api.crateApiReminderAdd(
+  atomId: atomId,
+  anchor: anchor,
+  remindAt: remindAt,
+  offsetMs: offsetMs,
+);
+
+/// Lists reminders of one atom.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Oldest first; `next_fire_at` is computed from the atom at read time.
+Future<ReminderListResponse> atomReminders({required String atomId}) =>
+    RustLib.instance.api.crateApiAtomReminders(atomId: atomId);
+
+/// Removes one reminder.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Idempotent: removing a missing reminder succeeds.
+Future<ReminderResponse> reminderRemove({required String reminderId}) =>
+    RustLib.instance.api.crateApiReminderRemove(reminderId: reminderId);
+
+/// Acknowledges one delivered firing.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `fire_at` is `DueReminderItem.fire_at`; acknowledging a scheduled
+///   firing also acknowledges every earlier one, a snoozed one clears the
+///   snooze.
+Future<ReminderResponse> reminderAck({
+  required String reminderId,
+  required PlatformInt64 fireAt,
+}) => RustLib.instance.api.crateApiReminderAck(
+  reminderId: reminderId,
+  fireAt: fireAt,
+);
+
+/// Snoozes one delivered firing.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `fire_at` is `DueReminderItem.fire_at`; the firing is redelivered once
+///   at `until_ms` (`snoozed = true`).
+/// - `invalid_snooze` unless `until_ms > fire_at`.
+Future<ReminderResponse> reminderSnooze({
+  required String reminderId,
+  required PlatformInt64 fireAt,
+  required PlatformInt64 untilMs,
+}) => RustLib.instance.api.crateApiReminderSnooze(
+  reminderId: reminderId,
+  fireAt: fireAt,
+  untilMs: untilMs,
+);
+
+/// Polls reminder firings due at `now_ms`.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution; intended for a scheduler loop or
+///   platform notifier.
+/// - Per reminder: its latest missed scheduled firing (older ones collapse)
+///   plus a due snooze. Recurring atoms fire once per occurrence.
+/// - Items stay due until acknowledged or snoozed.
+/// - `next_wakeup_at` is the earliest pending fire time after `now_ms`.
+Future<DueRemindersResponse> remindersDue({required PlatformInt64 nowMs}) =>
+    RustLib.instance.api.crateApiRemindersDue(nowMs: nowMs);
+
+/// Starts this device's timer on a task.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - At most one running timer per device: a timer running on another task
+///   stops at `now_ms` first; one on the same task is returned unchanged.
+/// - `not_a_task` for non-task atoms.
+Future<TimeEntryResponse> timeTimerStart({
+  required String atomId,
+  required PlatformInt64 nowMs,
+}) => RustLib.instance.api.crateApiTimeTimerStart(atomId: atomId, nowMs: nowMs);
+
+/// Stops this device's running timer.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Idempotent: with no running timer succeeds with `entry = null`.
+Future<TimeEntryResponse> timeTimerStop({required PlatformInt64 nowMs}) =>
+    RustLib.instance.api.crateApiTimeTimerStop(nowMs: nowMs);
+
+/// Returns this device's running timer.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `entry = null` when no timer runs.
+Future<TimeEntryResponse> timeTimerRunning() =>
+    RustLib.instance.api.crateApiTimeTimerRunning();
+
+/// Records a finished time session on a task.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `invalid_time_range` unless `ended_at > started_at`.
+/// - Blank `note` is stored as null.
+Future<TimeEntryResponse> timeEntryAdd({
+  required String atomId,
+  required PlatformInt64 startedAt,
+  required PlatformInt64 endedAt,
+  String? note,
+}) => RustLib.instance.api.crateApiTimeEntryAdd(
+  atomId: atomId,
+  startedAt: startedAt,
+  endedAt: endedAt,
+  note: note,
+);
+
+/// Edits one time entry; null arguments keep the stored value.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `atom_id` moves the entry to another task; `ended_at` stops a running
+///   entry; blank `note` clears it.
+/// - `invalid_time_range` when a stopped entry would not end after its start.
+Future<TimeEntryResponse> timeEntryUpdate({
+  required String entryId,
+  String? atomId,
+  PlatformInt64? startedAt,
+  PlatformInt64? endedAt,
+  String? note,
+}) => RustLib.instance.api.crateApiTimeEntryUpdate(
+  entryId: entryId,
+  atomId: atomId,
+  startedAt: startedAt,
+  endedAt: endedAt,
+  note: note,
+);
+
+/// Deletes one time entry.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Idempotent: deleting a missing entry succeeds.
+Future<TimeEntryResponse> timeEntryDelete({required String entryId}) =>
+    RustLib.instance.api.crateApiTimeEntryDelete(entryId: entryId);
+
+/// Lists time entries of one atom.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Oldest start first, all devices; running timers count up to `now_ms`
+///   in `duration_ms` and `total_ms`.
+Future<TimeEntryListResponse> atomTimeEntries({
+  required String atomId,
+  required PlatformInt64 nowMs,
+}) =>
+    RustLib.instance.api.crateApiAtomTimeEntries(atomId: atomId, nowMs: nowMs);
+
+/// Reports tracked time for local dates `start_date..=end_date`.
+///
+/// # FFI contract
+/// - Dates are `YYYY-MM-DD` in the timezone set via `configure_time_zone`.
+/// - Entries are clipped to the range; running timers count up to `now_ms`.
+/// - `tags`: an entry counts toward every tag of its task; untagged time is
+///   not listed.
+/// - `folders`: a task belongs to the folders holding notes that link to it
+///   (or to a parent task); totals include subfolders.
+/// - `invalid_argument` for malformed dates or ranges above 366 days;
+///   `invalid_time_range` when `end_date < start_date`.
+/// - Async call, DB-backed execution; trashed tasks are excluded.
+Future<TimeReportResponse> timeReport({
+  required String startDate,
+  required String endDate,
+  required PlatformInt64 nowMs,
+}) => RustLib.instance.api.crateApiTimeReport(
+  startDate: startDate,
+  endDate: endDate,
+  nowMs: nowMs,
+);
+
+/// Atom kind conversion response envelope.
+class AtomConvertResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Converted atom ID (unchanged by conversion).
+  final String? atomId;
+
+  /// Resulting kind (`note|task|event`).
+  final String? kind;
+
+  /// Resulting task status string, or null if statusless.
+  final String? taskStatus;
+
+  /// Resulting start boundary in epoch ms.
+  final PlatformInt64? startAt;
+
+  /// Resulting end boundary in epoch ms.
+  final PlatformInt64? endAt;
+
+  const AtomConvertResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    this.atomId,
+    this.kind,
+    this.taskStatus,
+    this.startAt,
+    this.endAt,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      atomId.hashCode ^
+      kind.hashCode ^
+      taskStatus.hashCode ^
+      startAt.hashCode ^
+      endAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AtomConvertResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          atomId == other.atomId &&
+          kind == other.kind &&
+          taskStatus == other.taskStatus &&
+          startAt == other.startAt &&
+          endAt == other.endAt;
+}
+
+/// Atom list item returned by section queries (Inbox/Today/Upcoming).
+class AtomListItem {
+  /// Stable atom ID in string form.
+  final String atomId;
+
+  /// Atom projection kind (`note|task|event`).
+  final String kind;
+
+  /// Raw markdown content.
+  final String content;
+
+  /// Derived plain-text preview.
+  final String? previewText;
+
+  /// Derived first markdown image path.
+  final String? previewImage;
+
+  /// Normalized lowercase tags for this atom.
+  final List<String> tags;
+
+  /// Epoch ms — start boundary (NULL = no start).
+  final PlatformInt64? startAt;
+
+  /// Epoch ms — end boundary (NULL = no end).
+  final PlatformInt64? endAt;
+
+  /// Current task status string, or null if statusless.
+  final String? taskStatus;
+
+  /// Update timestamp in epoch milliseconds.
+  final PlatformInt64 updatedAt;
+
+  const AtomListItem({
+    required this.atomId,
+    required this.kind,
+    required this.content,
+    this.previewText,
+    this.previewImage,
+    required this.tags,
+    this.startAt,
+    this.endAt,
+    this.taskStatus,
+    required this.updatedAt,
+  });
+
+  @override
+  int get hashCode =>
+      atomId.hashCode ^
+      kind.hashCode ^
+      content.hashCode ^
+      previewText.hashCode ^
+      previewImage.hashCode ^
+      tags.hashCode ^
+      startAt.hashCode ^
+      endAt.hashCode ^
+      taskStatus.hashCode ^
+      updatedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AtomListItem &&
+          runtimeType == other.runtimeType &&
+          atomId == other.atomId &&
+          kind == other.kind &&
+          content == other.content &&
+          previewText == other.previewText &&
+          previewImage == other.previewImage &&
+          tags == other.tags &&
+          startAt == other.startAt &&
+          endAt == other.endAt &&
+          taskStatus == other.taskStatus &&
+          updatedAt == other.updatedAt;
+}
+
+/// Section list response envelope.
+class AtomListResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Section items.
+  final List<AtomListItem> items;
+
+  /// Effective limit after normalization.
+  final int appliedLimit;
+
+  const AtomListResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.items,
+    required this.appliedLimit,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      items.hashCode ^
+      appliedLimit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AtomListResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          items == other.items &&
+          appliedLimit == other.appliedLimit;
+}
+
+/// Atom schedule (timed or all-day) response envelope.
+class AtomScheduleResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Target atom ID.
+  final String? atomId;
+
+  /// Whether `start_at/end_at` are floating all-day dates.
+  final bool allDay;
+
+  /// Start boundary: epoch ms, or floating ms when all-day.
+  final PlatformInt64? startAt;
+
+  /// End boundary: epoch ms, or floating ms when all-day.
+  final PlatformInt64? endAt;
+
+  /// First all-day date (`YYYY-MM-DD`), or null when timed.
+  final String? startDate;
+
+  /// Last all-day date (`YYYY-MM-DD`, inclusive), or null when timed.
+  final String? endDate;
+
+  const AtomScheduleResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    this.atomId,
+    required this.allDay,
+    this.startAt,
+    this.endAt,
+    this.startDate,
+    this.endDate,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      atomId.hashCode ^
+      allDay.hashCode ^
+      startAt.hashCode ^
+      endAt.hashCode ^
+      startDate.hashCode ^
+      endDate.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AtomScheduleResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          atomId == other.atomId &&
+          allDay == other.allDay &&
+          startAt == other.startAt &&
+          endAt == other.endAt &&
+          startDate == other.startDate &&
+          endDate == other.endDate;
+}
+
+/// Task priority/deadline response envelope.
+class AtomTaskMetaResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Target atom ID.
+  final String? atomId;
+
+  /// Priority (`low|medium|high`), or null if unprioritized.
+  final String? priority;
+
+  /// Deadline in epoch ms, or null if none.
+  final PlatformInt64? dueAt;
+
+  const AtomTaskMetaResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    this.atomId,
+    this.priority,
+    this.dueAt,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      atomId.hashCode ^
+      priority.hashCode ^
+      dueAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AtomTaskMetaResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          atomId == other.atomId &&
+          priority == other.priority &&
+          dueAt == other.dueAt;
+}
+
+/// Attachment GC response envelope.
+class AttachmentGcResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Zero-reference metadata rows removed.
+  final int removedRows;
+
+  /// Blob files removed.
+  final int removedBlobs;
+
+  /// Bytes freed on disk.
+  final BigInt freedBytes;
+
+  const AttachmentGcResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.removedRows,
+    required this.removedBlobs,
+    required this.freedBytes,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      removedRows.hashCode ^
+      removedBlobs.hashCode ^
+      freedBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AttachmentGcResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          removedRows == other.removedRows &&
+          removedBlobs == other.removedBlobs &&
+          freedBytes == other.freedBytes;
+}
+
+/// Attachment import response envelope.
+class AttachmentImportResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Stored attachment on success.
+  final AttachmentItem? attachment;
+
+  /// Whether identical bytes were already stored.
+  final bool deduplicated;
+
+  const AttachmentImportResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    this.attachment,
+    required this.deduplicated,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      attachment.hashCode ^
+      deduplicated.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AttachmentImportResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          attachment == other.attachment &&
+          deduplicated == other.deduplicated;
+}
+
+/// One stored attachment for FFI.
+class AttachmentItem {
+  /// Lowercase hex SHA-256 of the blob.
+  final String sha256;
+
+  /// Markdown-usable `attachment://<sha256>` URI.
+  final String uri;
+
+  /// Blob size in bytes.
+  final PlatformInt64 byteSize;
+
+  /// MIME type guessed from the first imported file name.
+  final String? mimeType;
+
+  /// File name of the first import.
+  final String? originalName;
+
+  /// Latest import timestamp in epoch milliseconds; GC grace counts from it.
+  final PlatformInt64 createdAt;
+
+  /// Number of atoms referencing this attachment.
+  final int refCount;
+
+  const AttachmentItem({
+    required this.sha256,
+    required this.uri,
+    required this.byteSize,
+    this.mimeType,
+    this.originalName,
+    required this.createdAt,
+    required this.refCount,
+  });
+
+  @override
+  int get hashCode =>
+      sha256.hashCode ^
+      uri.hashCode ^
+      byteSize.hashCode ^
+      mimeType.hashCode ^
+      originalName.hashCode ^
+      createdAt.hashCode ^
+      refCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AttachmentItem &&
+          runtimeType == other.runtimeType &&
+          sha256 == other.sha256 &&
+          uri == other.uri &&
+          byteSize == other.byteSize &&
+          mimeType == other.mimeType &&
+          originalName == other.originalName &&
+          createdAt == other.createdAt &&
+          refCount == other.refCount;
+}
+
+/// Attachment resolve response envelope.
+class AttachmentResolveResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Attachment metadata on success.
+  final AttachmentItem? attachment;
+
+  /// Absolute blob path for rendering/opening.
+  final String? filePath;
+
+  const AttachmentResolveResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    this.attachment,
+    this.filePath,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      attachment.hashCode ^
+      filePath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AttachmentResolveResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          attachment == other.attachment &&
+          filePath == other.filePath;
+}
+
+/// Per-atom attachment list response envelope.
+class AttachmentsListResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Attachments in document order.
+  final List<AttachmentItem> items;
+
+  const AttachmentsListResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.items,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ items.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AttachmentsListResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          items == other.items;
+}
+
+/// Two double-booked events.
+class CalendarConflictItem {
+  /// Event that starts first.
+  final String firstAtomId;
+
+  /// Occurrence start of the first event when recurring.
+  final PlatformInt64? firstOccurrenceStartAt;
+
+  /// Overlapping event.
+  final String secondAtomId;
+
+  /// Occurrence start of the second event when recurring.
+  final PlatformInt64? secondOccurrenceStartAt;
+
+  /// Start of the shared time, epoch ms.
+  final PlatformInt64 overlapStartMs;
+
+  /// End (exclusive) of the shared time, epoch ms.
+  final PlatformInt64 overlapEndMs;
+
+  const CalendarConflictItem({
+    required this.firstAtomId,
+    this.firstOccurrenceStartAt,
+    required this.secondAtomId,
+    this.secondOccurrenceStartAt,
+    required this.overlapStartMs,
+    required this.overlapEndMs,
+  });
+
+  @override
+  int get hashCode =>
+      firstAtomId.hashCode ^
+      firstOccurrenceStartAt.hashCode ^
+      secondAtomId.hashCode ^
+      secondOccurrenceStartAt.hashCode ^
+      overlapStartMs.hashCode ^
+      overlapEndMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CalendarConflictItem &&
+          runtimeType == other.runtimeType &&
+          firstAtomId == other.firstAtomId &&
+          firstOccurrenceStartAt == other.firstOccurrenceStartAt &&
+          secondAtomId == other.secondAtomId &&
+          secondOccurrenceStartAt == other.secondOccurrenceStartAt &&
+          overlapStartMs == other.overlapStartMs &&
+          overlapEndMs == other.overlapEndMs;
+}
+
+/// Calendar conflicts response envelope.
+class CalendarConflictsResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Conflicts ordered by overlap start.
+  final List<CalendarConflictItem> items;
+
+  const CalendarConflictsResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.items,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ items.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CalendarConflictsResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          items == other.items;
+}
+
+/// One local day of a calendar view.
+class CalendarDayItem {
+  /// Local date (`YYYY-MM-DD`).
+  final String date;
+
+  /// First instant of the day, epoch ms.
+  final PlatformInt64 startMs;
+
+  /// Last instant of the day, epoch ms (inclusive).
+  final PlatformInt64 endMs;
+
+  /// All-day atoms covering the date.
+  final List<CalendarEntryItem> allDay;
+
+  /// Timed atoms touching the day, ordered by visible start.
+  final List<CalendarEntryItem> timed;
+
+  const CalendarDayItem({
+    required this.date,
+    required this.startMs,
+    required this.endMs,
+    required this.allDay,
+    required this.timed,
+  });
+
+  @override
+  int get hashCode =>
+      date.hashCode ^
+      startMs.hashCode ^
+      endMs.hashCode ^
+      allDay.hashCode ^
+      timed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CalendarDayItem &&
+          runtimeType == other.runtimeType &&
+          date == other.date &&
+          startMs == other.startMs &&
+          endMs == other.endMs &&
+          allDay == other.allDay &&
+          timed == other.timed;
+}
+
+/// Calendar day buckets response envelope.
+class CalendarDaysResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// One bucket per local date, in order.
+  final List<CalendarDayItem> days;
+
+  const CalendarDaysResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.days,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ days.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CalendarDaysResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          days == other.days;
+}
+
+/// One atom placed on one local day of a calendar view.
+class CalendarEntryItem {
+  /// Stable atom ID (series ID for recurring occurrences).
+  final String atomId;
+
+  /// Atom projection kind (`note|task|event`).
+  final String kind;
+
+  /// Raw markdown content.
+  final String content;
+
+  /// Derived plain-text preview.
+  final String? previewText;
+
+  /// Current task status string, or null if statusless.
+  final String? taskStatus;
+
+  /// Whether `start_at/end_at` are floating all-day dates.
+  final bool allDay;
+
+  /// Start boundary of the atom (or occurrence).
+  final PlatformInt64 startAt;
+
+  /// End boundary of the atom (or occurrence).
+  final PlatformInt64 endAt;
+
+  /// Original occurrence start for recurring series, else null.
+  final PlatformInt64? occurrenceStartAt;
+
+  /// Start of the part shown on this day, epoch ms.
+  final PlatformInt64 visibleStartMs;
+
+  /// End (exclusive) of the part shown on this day, epoch ms.
+  final PlatformInt64 visibleEndMs;
+
+  /// Whether the atom started on an earlier day.
+  final bool continuesBefore;
+
+  /// Whether the atom ends on a later day.
+  final bool continuesAfter;
+
+  /// Zero-based layout column inside its overlap group.
+  final int column;
+
+  /// Columns used by its overlap group.
+  final int columnCount;
+
+  const CalendarEntryItem({
+    required this.atomId,
+    required this.kind,
+    required this.content,
+    this.previewText,
+    this.taskStatus,
+    required this.allDay,
+    required this.startAt,
+    required this.endAt,
+    this.occurrenceStartAt,
+    required this.visibleStartMs,
+    required this.visibleEndMs,
+    required this.continuesBefore,
+    required this.continuesAfter,
+    required this.column,
+    required this.columnCount,
+  });
+
+  @override
+  int get hashCode =>
+      atomId.hashCode ^
+      kind.hashCode ^
+      content.hashCode ^
+      previewText.hashCode ^
+      taskStatus.hashCode ^
+      allDay.hashCode ^
+      startAt.hashCode ^
+      endAt.hashCode ^
+      occurrenceStartAt.hashCode ^
+      visibleStartMs.hashCode ^
+      visibleEndMs.hashCode ^
+      continuesBefore.hashCode ^
+      continuesAfter.hashCode ^
+      column.hashCode ^
+      columnCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CalendarEntryItem &&
+          runtimeType == other.runtimeType &&
+          atomId == other.atomId &&
+          kind == other.kind &&
+          content == other.content &&
+          previewText == other.previewText &&
+          taskStatus == other.taskStatus &&
+          allDay == other.allDay &&
+          startAt == other.startAt &&
+          endAt == other.endAt &&
+          occurrenceStartAt == other.occurrenceStartAt &&
+          visibleStartMs == other.visibleStartMs &&
+          visibleEndMs == other.visibleEndMs &&
+          continuesBefore == other.continuesBefore &&
+          continuesAfter == other.continuesAfter &&
+          column == other.column &&
+          columnCount == other.columnCount;
+}
+
+/// A free gap in the calendar.
+class CalendarFreeSlotItem {
+  /// Start of the gap, epoch ms.
+  final PlatformInt64 startMs;
+
+  /// End (exclusive) of the gap, epoch ms.
+  final PlatformInt64 endMs;
+
+  const CalendarFreeSlotItem({required this.startMs, required this.endMs});
+
+  @override
+  int get hashCode => startMs.hashCode ^ endMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CalendarFreeSlotItem &&
+          runtimeType == other.runtimeType &&
+          startMs == other.startMs &&
+          endMs == other.endMs;
+}
+
+/// Calendar free slots response envelope.
+class CalendarFreeSlotsResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Free gaps in time order.
+  final List<CalendarFreeSlotItem> slots;
+
+  const CalendarFreeSlotsResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.slots,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ slots.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CalendarFreeSlotsResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          slots == other.slots;
+}
+
+/// One reminder firing due for delivery.
+class DueReminderItem {
+  /// Reminder state.
+  final ReminderItem reminder;
+
+  /// Owning atom projection kind (`note|task|event`).
+  final String atomKind;
+
+  /// Owning atom markdown content.
+  final String content;
+
+  /// Owning atom plain-text preview.
+  final String? previewText;
+
+  /// Delivery time in epoch ms; pass back to ack/snooze.
+  final PlatformInt64 fireAt;
+
+  /// Occurrence start for recurring atoms.
+  final PlatformInt64? occurrenceStartAt;
+
+  /// Whether this is a snoozed firing being redelivered.
+  final bool snoozed;
+
+  const DueReminderItem({
+    required this.reminder,
+    required this.atomKind,
+    required this.content,
+    this.previewText,
+    required this.fireAt,
+    this.occurrenceStartAt,
+    required this.snoozed,
+  });
+
+  @override
+  int get hashCode =>
+      reminder.hashCode ^
+      atomKind.hashCode ^
+      content.hashCode ^
+      previewText.hashCode ^
+      fireAt.hashCode ^
+      occurrenceStartAt.hashCode ^
+      snoozed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DueReminderItem &&
+          runtimeType == other.runtimeType &&
+          reminder == other.reminder &&
+          atomKind == other.atomKind &&
+          content == other.content &&
+          previewText == other.previewText &&
+          fireAt == other.fireAt &&
+          occurrenceStartAt == other.occurrenceStartAt &&
+          snoozed == other.snoozed;
+}
+
+/// Due reminder poll response envelope.
+class DueRemindersResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Due firings ordered by `fire_at`.
+  final List<DueReminderItem> items;
+
+  /// Earliest pending fire time after `now_ms`; when to poll next.
+  final PlatformInt64? nextWakeupAt;
+
+  const DueRemindersResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.items,
+    this.nextWakeupAt,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      items.hashCode ^
+      nextWakeupAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DueRemindersResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          items == other.items &&
+          nextWakeupAt == other.nextWakeupAt;
+}
+
+/// Generic action response envelope for single-entry command flow.
+class EntryActionResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Optional created atom ID.
+  final String? atomId;
+
+  /// Human-readable response message for diagnostics/UI.
+  final String message;
+
+  const EntryActionResponse({
+    required this.ok,
+    this.atomId,
+    required this.message,
+  });
+
+  @override
+  int get hashCode => ok.hashCode ^ atomId.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EntryActionResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          atomId == other.atomId &&
+          message == other.message;
+}
+
+/// Natural-language quick-add draft response.
+class EntryQuickAddResponse {
+  /// Always true; parsing never fails.
+  final bool ok;
+
+  /// Reserved for future failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Draft kind (`note|task|event`).
+  final String kind;
+
+  /// Input with recognized tokens removed.
+  final String title;
+
+  /// Start epoch ms, or floating date start when `all_day`.
+  final PlatformInt64? startAt;
+
+  /// End epoch ms, or floating date end when `all_day`.
+  final PlatformInt64? endAt;
+
+  /// Whether a date without time was recognized.
+  final bool allDay;
+
+  /// Normalized tags in input order.
+  final List<String> tags;
+
+  /// Priority (`low|medium|high`), or null.
+  final String? priority;
+
+  /// Recognized tokens ordered by position.
+  final List<EntryQuickAddToken> tokens;
+
+  const EntryQuickAddResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.kind,
+    required this.title,
+    this.startAt,
+    this.endAt,
+    required this.allDay,
+    required this.tags,
+    this.priority,
+    required this.tokens,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      kind.hashCode ^
+      title.hashCode ^
+      startAt.hashCode ^
+      endAt.hashCode ^
+      allDay.hashCode ^
+      tags.hashCode ^
+      priority.hashCode ^
+      tokens.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EntryQuickAddResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          kind == other.kind &&
+          title == other.title &&
+          startAt == other.startAt &&
+          endAt == other.endAt &&
+          allDay == other.allDay &&
+          tags == other.tags &&
+          priority == other.priority &&
+          tokens == other.tokens;
+}
+
+/// One recognized token of a quick-add draft.
+class EntryQuickAddToken {
+  /// Token category (`kind|date|time|duration|tag|priority`).
+  final String kind;
+
+  /// Start byte offset (inclusive).
+  final int start;
+
+  /// End byte offset (exclusive).
+  final int end;
+
+  /// Start char offset (inclusive).
+  final int charStart;
+
+  /// End char offset (exclusive).
+  final int charEnd;
+
+  const EntryQuickAddToken({
+    required this.kind,
+    required this.start,
+    required this.end,
+    required this.charStart,
+    required this.charEnd,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      start.hashCode ^
+      end.hashCode ^
+      charStart.hashCode ^
+      charEnd.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EntryQuickAddToken &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          start == other.start &&
+          end == other.end &&
+          charStart == other.charStart &&
+          charEnd == other.charEnd;
+}
+
+/// Highlighted span inside [`EntrySearchHitItem::snippet`].
+class EntrySearchHighlight {
+  /// Start byte offset (inclusive, UTF-8).
+  final int start;
+
+  /// End byte offset (exclusive, UTF-8).
+  final int end;
+
+  /// Start char offset (inclusive).
+  final int charStart;
+
+  /// End char offset (exclusive).
+  final int charEnd;
+
+  const EntrySearchHighlight({
+    required this.start,
+    required this.end,
+    required this.charStart,
+    required this.charEnd,
+  });
+
+  @override
+  int get hashCode =>
+      start.hashCode ^ end.hashCode ^ charStart.hashCode ^ charEnd.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EntrySearchHighlight &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end &&
+          charStart == other.charStart &&
+          charEnd == other.charEnd;
+}
+
+/// Ranked search hit with structured highlights.
+class EntrySearchHitItem {
+  /// Stable atom ID in string form.
+  final String atomId;
+
+  /// Atom projection kind (`note|task|event`).
+  final String kind;
+
+  /// Plain-text snippet (no highlight markers).
+  final String snippet;
+
+  /// Matched spans inside `snippet`.
+  final List<EntrySearchHighlight> highlights;
+
+  /// Raw bm25 value (lower is better); `None` for filter/substring-only hits.
+  final double? bm25;
+
+  /// Final ranking score after boosts (higher is better).
+  final double score;
+
+  const EntrySearchHitItem({
+    required this.atomId,
+    required this.kind,
+    required this.snippet,
+    required this.highlights,
+    this.bm25,
+    required this.score,
+  });
+
+  @override
+  int get hashCode =>
+      atomId.hashCode ^
+      kind.hashCode ^
+      snippet.hashCode ^
+      highlights.hashCode ^
+      bm25.hashCode ^
+      score.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EntrySearchHitItem &&
+          runtimeType == other.runtimeType &&
+          atomId == other.atomId &&
+          kind == other.kind &&
+          snippet == other.snippet &&
+          highlights == other.highlights &&
+          bm25 == other.bm25 &&
+          score == other.score;
+}
+
+/// Search item returned by single-entry search API.
+class EntrySearchItem {
+  /// Stable atom ID in string form.
+  final String atomId;
+
+  /// Atom projection kind (`note|task|event`).
+  final String kind;
+
+  /// Short snippet summary for result display.
+  final String snippet;
+
+  const EntrySearchItem({
+    required this.atomId,
+    required this.kind,
+    required this.snippet,
+  });
+
+  @override
+  int get hashCode => atomId.hashCode ^ kind.hashCode ^ snippet.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EntrySearchItem &&
+          runtimeType == other.runtimeType &&
+          atomId == other.atomId &&
+          kind == other.kind &&
+          snippet == other.snippet;
+}
+
+/// Paged search response envelope.
+class EntrySearchPageResponse {
+  /// Whether search execution succeeded.
+  final bool ok;
+
+  /// Optional stable error code for machine branching.
+  final String? errorCode;
+
+  /// Human-readable response message for diagnostics.
+  final String message;
+
+  /// Hits in ranking order.
+  final List<EntrySearchHitItem> items;
+
+  /// Cursor for the next page; `None` on the last page.
+  final String? nextCursor;
+
+  /// Effective applied search limit.
+  final int appliedLimit;
+
+  const EntrySearchPageResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.items,
+    this.nextCursor,
+    required this.appliedLimit,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      items.hashCode ^
+      nextCursor.hashCode ^
+      appliedLimit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EntrySearchPageResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          items == other.items &&
+          nextCursor == other.nextCursor &&
+          appliedLimit == other.appliedLimit;
+}
+
+/// Search response envelope for single-entry search flow.
+class EntrySearchResponse {
+  /// Whether search execution succeeded.
+  final bool ok;
+
+  /// Optional stable error code for machine branching.
+  final String? errorCode;
+
+  /// Search results (empty when no hits or scaffold mode).
+  final List<EntrySearchItem> items;
+
+  /// Human-readable response message for diagnostics.
+  final String message;
+
+  /// Effective applied search limit.
+  final int appliedLimit;
+
+  const EntrySearchResponse({
+    required this.ok,
+    this.errorCode,
+    required this.items,
+    required this.message,
+    required this.appliedLimit,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      items.hashCode ^
+      message.hashCode ^
+      appliedLimit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EntrySearchResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          items == other.items &&
+          message == other.message &&
+          appliedLimit == other.appliedLimit;
+}
+
+/// Syntax check response for the structured search grammar.
+class EntrySearchSyntaxResponse {
+  /// Whether the text parsed successfully.
+  final bool ok;
+
+  /// Stable error code (`invalid_query_syntax`) when parsing failed.
+  final String? errorCode;
+
+  /// Parse failure category (`unterminated_quote|dangling_negation|missing_value|invalid_value`).
+  final String? syntaxError;
+
+  /// Start char offset of the offending span (inclusive).
+  final int? errorStart;
+
+  /// End char offset of the offending span (exclusive).
+  final int? errorEnd;
+
+  /// Number of recognized field filters.
+  final int filterCount;
+
+  /// Human-readable response message for diagnostics.
+  final String message;
+
+  const EntrySearchSyntaxResponse({
+    required this.ok,
+    this.errorCode,
+    this.syntaxError,
+    this.errorStart,
+    this.errorEnd,
+    required this.filterCount,
+    required this.message,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      syntaxError.hashCode ^
+      errorStart.hashCode ^
+      errorEnd.hashCode ^
+      filterCount.hashCode ^
+      message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EntrySearchSyntaxResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          syntaxError == other.syntaxError &&
+          errorStart == other.errorStart &&
+          errorEnd == other.errorEnd &&
+          filterCount == other.filterCount &&
+          message == other.message;
+}
+
+/// One vetted launch target.
+class LaunchPlanItem {
+  /// Source link row id.
+  final PlatformInt64 linkId;
+
+  /// Target kind label (`folder|file|url`).
+  final String kind;
+
+  /// Target as written in content.
+  final String target;
+
+  /// Optional Markdown label.
+  final String? label;
+
+  /// Resolved local path for `folder`/`file` targets.
+  final String? localPath;
+
+  const LaunchPlanItem({
+    required this.linkId,
+    required this.kind,
+    required this.target,
+    this.label,
+    this.localPath,
+  });
+
+  @override
+  int get hashCode =>
+      linkId.hashCode ^
+      kind.hashCode ^
+      target.hashCode ^
+      label.hashCode ^
+      localPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LaunchPlanItem &&
+          runtimeType == other.runtimeType &&
+          linkId == other.linkId &&
+          kind == other.kind &&
+          target == other.target &&
+          label == other.label &&
+          localPath == other.localPath;
+}
+
+/// Launch plan response envelope.
+class LaunchPlanResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Targets to open, in launch order.
+  final List<LaunchPlanItem> items;
+
+  /// Links that failed validation.
+  final List<LaunchRejectionItem> rejected;
+
+  /// Valid targets dropped by the safety cap.
+  final int cappedCount;
+
+  /// Effective cap after normalization.
+  final int appliedMaxItems;
+
+  const LaunchPlanResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.items,
+    required this.rejected,
+    required this.cappedCount,
+    required this.appliedMaxItems,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      items.hashCode ^
+      rejected.hashCode ^
+      cappedCount.hashCode ^
+      appliedMaxItems.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LaunchPlanResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          items == other.items &&
+          rejected == other.rejected &&
+          cappedCount == other.cappedCount &&
+          appliedMaxItems == other.appliedMaxItems;
+}
+
+/// One link excluded from a launch plan.
+class LaunchRejectionItem {
+  /// Source link row id.
+  final PlatformInt64 linkId;
+
+  /// Target as written in content.
+  final String target;
+
+  /// Reason label (`scheme_not_allowed|invalid_target|not_local_path|path_not_found`).
+  final String reason;
+
+  const LaunchRejectionItem({
+    required this.linkId,
+    required this.target,
+    required this.reason,
+  });
+
+  @override
+  int get hashCode => linkId.hashCode ^ target.hashCode ^ reason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LaunchRejectionItem &&
+          runtimeType == other.runtimeType &&
+          linkId == other.linkId &&
+          target == other.target &&
+          reason == other.reason;
+}
+
+/// Local day/week/month boundaries response envelope.
+class LocalTimeWindowsResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Configured IANA timezone name.
+  final String timeZone;
+
+  /// Configured first weekday (`monday|...|sunday`).
+  final String weekStart;
+
+  /// First instant of the local day, epoch ms.
+  final PlatformInt64 dayStartMs;
+
+  /// Last instant of the local day, epoch ms.
+  final PlatformInt64 dayEndMs;
+
+  /// First instant of the local week, epoch ms.
+  final PlatformInt64 weekStartMs;
+
+  /// Last instant of the local week, epoch ms.
+  final PlatformInt64 weekEndMs;
+
+  /// First instant of the local month, epoch ms.
+  final PlatformInt64 monthStartMs;
+
+  /// Last instant of the local month, epoch ms.
+  final PlatformInt64 monthEndMs;
+
+  const LocalTimeWindowsResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.timeZone,
+    required this.weekStart,
+    required this.dayStartMs,
+    required this.dayEndMs,
+    required this.weekStartMs,
+    required this.weekEndMs,
+    required this.monthStartMs,
+    required this.monthEndMs,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      timeZone.hashCode ^
+      weekStart.hashCode ^
+      dayStartMs.hashCode ^
+      dayEndMs.hashCode ^
+      weekStartMs.hashCode ^
+      weekEndMs.hashCode ^
+      monthStartMs.hashCode ^
+      monthEndMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LocalTimeWindowsResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          timeZone == other.timeZone &&
+          weekStart == other.weekStart &&
+          dayStartMs == other.dayStartMs &&
+          dayEndMs == other.dayEndMs &&
+          weekStartMs == other.weekStartMs &&
+          weekEndMs == other.weekEndMs &&
+          monthStartMs == other.monthStartMs &&
+          monthEndMs == other.monthEndMs;
+}
+
+/// Dart-side diagnostics logging response envelope.
+class LogDartEventResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  const LogDartEventResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+  });
+
+  @override
+  int get hashCode => ok.hashCode ^ errorCode.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LogDartEventResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message;
+}
+
+/// One line of a note revision diff.
+class NoteDiffLineItem {
+  /// Line kind label (`unchanged|added|removed`).
+  final String kind;
+
+  /// Line text without trailing newline.
+  final String text;
+
+  /// 1-based line number in the older version.
+  final int? oldLine;
+
+  /// 1-based line number in the newer version.
+  final int? newLine;
+
+  const NoteDiffLineItem({
+    required this.kind,
+    required this.text,
+    this.oldLine,
+    this.newLine,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^ text.hashCode ^ oldLine.hashCode ^ newLine.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoteDiffLineItem &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          text == other.text &&
+          oldLine == other.oldLine &&
+          newLine == other.newLine;
+}
+
+/// Note DTO returned by notes/tags APIs.
+class NoteItem {
+  /// Stable note atom id.
+  final String atomId;
+
+  /// Raw markdown content.
+  final String content;
+
+  /// Derived plain-text preview.
+  final String? previewText;
+
+  /// Derived first markdown image path.
+  final String? previewImage;
+
+  /// Update timestamp in epoch milliseconds.
+  final PlatformInt64 updatedAt;
+
+  /// Normalized tags attached to the note.
+  final List<String> tags;
+
+  const NoteItem({
+    required this.atomId,
+    required this.content,
+    this.previewText,
+    this.previewImage,
+    required this.updatedAt,
+    required this.tags,
+  });
+
+  @override
+  int get hashCode =>
+      atomId.hashCode ^
+      content.hashCode ^
+      previewText.hashCode ^
+      previewImage.hashCode ^
+      updatedAt.hashCode ^
+      tags.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoteItem &&
+          runtimeType == other.runtimeType &&
+          atomId == other.atomId &&
+          content == other.content &&
+          previewText == other.previewText &&
+          previewImage == other.previewImage &&
+          updatedAt == other.updatedAt &&
+          tags == other.tags;
+}
+
+/// One wiki-link edge returned by note link APIs.
+class NoteLinkItem {
+  /// Atom whose content contains the reference.
+  final String sourceAtomId;
+
+  /// Target text as written inside `[[...]]`.
+  final String target;
+
+  /// Optional display label (`[[target|label]]`).
+  final String? label;
+
+  /// Link kind label (`title|uuid`).
+  final String kind;
+
+  /// Resolved active target atom; `None` when unresolved.
+  final String? targetAtomId;
+
+  const NoteLinkItem({
+    required this.sourceAtomId,
+    required this.target,
+    this.label,
+    required this.kind,
+    this.targetAtomId,
+  });
+
+  @override
+  int get hashCode =>
+      sourceAtomId.hashCode ^
+      target.hashCode ^
+      label.hashCode ^
+      kind.hashCode ^
+      targetAtomId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoteLinkItem &&
+          runtimeType == other.runtimeType &&
+          sourceAtomId == other.sourceAtomId &&
+          target == other.target &&
+          label == other.label &&
+          kind == other.kind &&
+          targetAtomId == other.targetAtomId;
+}
+
+/// Note link graph response envelope.
+class NoteLinksResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Links written in this note, in document order.
+  final List<NoteLinkItem> outgoing;
+
+  /// Links from other active atoms resolving to this note.
+  final List<NoteLinkItem> backlinks;
+
+  const NoteLinksResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.outgoing,
+    required this.backlinks,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      outgoing.hashCode ^
+      backlinks.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoteLinksResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          outgoing == other.outgoing &&
+          backlinks == other.backlinks;
+}
+
+/// One heading of a note outline, flattened in document order.
+class NoteOutlineHeading {
+  /// Markdown heading level, 1..=6.
+  final int level;
+
+  /// Nesting depth in the outline tree (0 = top level).
+  final int depth;
+
+  /// Index of the parent heading in `headings`; null for top level.
+  final int? parentIndex;
+
+  /// Plain heading text without markup.
+  final String text;
+
+  /// Start byte offset in note content (inclusive, UTF-8).
+  final int byteStart;
+
+  /// End byte offset in note content (exclusive, UTF-8).
+  final int byteEnd;
+
+  const NoteOutlineHeading({
+    required this.level,
+    required this.depth,
+    this.parentIndex,
+    required this.text,
+    required this.byteStart,
+    required this.byteEnd,
+  });
+
+  @override
+  int get hashCode =>
+      level.hashCode ^
+      depth.hashCode ^
+      parentIndex.hashCode ^
+      text.hashCode ^
+      byteStart.hashCode ^
+      byteEnd.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoteOutlineHeading &&
+          runtimeType == other.runtimeType &&
+          level == other.level &&
+          depth == other.depth &&
+          parentIndex == other.parentIndex &&
+          text == other.text &&
+          byteStart == other.byteStart &&
+          byteEnd == other.byteEnd;
+}
+
+/// Note outline and content statistics response envelope.
+class NoteOutlineResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Headings in document (pre-order) order.
+  final List<NoteOutlineHeading> headings;
+
+  /// Words in rendered text (each CJK character counts as one).
+  final int wordCount;
+
+  /// Non-whitespace characters in rendered text.
+  final int charCount;
+
+  /// Checked GFM task list items.
+  final int checklistDone;
+
+  /// All GFM task list items.
+  final int checklistTotal;
+
+  const NoteOutlineResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.headings,
+    required this.wordCount,
+    required this.charCount,
+    required this.checklistDone,
+    required this.checklistTotal,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      headings.hashCode ^
+      wordCount.hashCode ^
+      charCount.hashCode ^
+      checklistDone.hashCode ^
+      checklistTotal.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoteOutlineResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          headings == other.headings &&
+          wordCount == other.wordCount &&
+          charCount == other.charCount &&
+          checklistDone == other.checklistDone &&
+          checklistTotal == other.checklistTotal;
+}
+
+/// Note frontmatter properties response envelope.
+class NotePropertiesResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Properties in frontmatter declaration order.
+  final List<NotePropertyItem> items;
+
+  const NotePropertiesResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.items,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ items.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NotePropertiesResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          items == other.items;
+}
+
+/// One typed frontmatter property for FFI.
+class NotePropertyItem {
+  /// Lowercase property key.
+  final String key;
+
+  /// `string|number|date|bool|list`.
+  final String valueType;
+
+  /// Text form for `string`, `date` and `bool` (`true`/`false`).
+  final String? text;
+
+  /// Value for `number`.
+  final double? number;
+
+  /// Elements for `list`.
+  final List<String> items;
+
+  const NotePropertyItem({
+    required this.key,
+    required this.valueType,
+    this.text,
+    this.number,
+    required this.items,
+  });
+
+  @override
+  int get hashCode =>
+      key.hashCode ^
+      valueType.hashCode ^
+      text.hashCode ^
+      number.hashCode ^
+      items.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NotePropertyItem &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          valueType == other.valueType &&
+          text == other.text &&
+          number == other.number &&
+          items == other.items;
+}
+
+/// Note create/update/get response envelope.
+class NoteResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Returned note payload on success.
+  final NoteItem? note;
+
+  const NoteResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    this.note,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ note.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoteResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          note == other.note;
+}
+
+/// Note revision diff response envelope.
+class NoteRevisionDiffResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Diff lines in document order.
+  final List<NoteDiffLineItem> lines;
+
+  const NoteRevisionDiffResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.lines,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ lines.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoteRevisionDiffResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          lines == other.lines;
+}
+
+/// Note revision DTO returned by revision history APIs.
+class NoteRevisionItem {
+  /// Revision id, unique per database.
+  final PlatformInt64 revisionId;
+
+  /// Stable note atom id.
+  final String atomId;
+
+  /// Markdown content captured by this revision.
+  final String content;
+
+  /// Preview text captured by this revision.
+  final String? previewText;
+
+  /// `updated_at` of the captured state in epoch milliseconds.
+  final PlatformInt64 sourceUpdatedAt;
+
+  /// Capture timestamp in epoch milliseconds.
+  final PlatformInt64 capturedAt;
+
+  const NoteRevisionItem({
+    required this.revisionId,
+    required this.atomId,
+    required this.content,
+    this.previewText,
+    required this.sourceUpdatedAt,
+    required this.capturedAt,
+  });
+
+  @override
+  int get hashCode =>
+      revisionId.hashCode ^
+      atomId.hashCode ^
+      content.hashCode ^
+      previewText.hashCode ^
+      sourceUpdatedAt.hashCode ^
+      capturedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoteRevisionItem &&
+          runtimeType == other.runtimeType &&
+          revisionId == other.revisionId &&
+          atomId == other.atomId &&
+          content == other.content &&
+          previewText == other.previewText &&
+          sourceUpdatedAt == other.sourceUpdatedAt &&
+          capturedAt == other.capturedAt;
+}
+
+/// Note revision list response envelope.
+class NoteRevisionsListResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Revisions sorted newest first.
+  final List<NoteRevisionItem> items;
+
+  /// Effective limit after normalization.
+  final int appliedLimit;
+
+  const NoteRevisionsListResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.items,
+    required this.appliedLimit,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      items.hashCode ^
+      appliedLimit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoteRevisionsListResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          items == other.items &&
+          appliedLimit == other.appliedLimit;
+}
+
+/// Per-source tag split of one note.
+class NoteTagSourcesResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Tags set explicitly through `note_set_tags`.
+  final List<String> manualTags;
+
+  /// Tags derived from content: inline `#hashtags` and frontmatter
+  /// `tags:` / `aliases:`.
+  final List<String> inlineTags;
+
+  const NoteTagSourcesResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.manualTags,
+    required this.inlineTags,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      manualTags.hashCode ^
+      inlineTags.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoteTagSourcesResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          manualTags == other.manualTags &&
+          inlineTags == other.inlineTags;
+}
+
+/// Note title projection response envelope.
+class NoteTitleResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Effective title; `None` only on failure.
+  final String? title;
+
+  /// Title derived from the first heading or first line of content.
+  final String? derivedTitle;
+
+  /// Explicit title override, if set.
+  final String? titleOverride;
+
+  const NoteTitleResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    this.title,
+    this.derivedTitle,
+    this.titleOverride,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      title.hashCode ^
+      derivedTitle.hashCode ^
+      titleOverride.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoteTitleResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          title == other.title &&
+          derivedTitle == other.derivedTitle &&
+          titleOverride == other.titleOverride;
+}
+
+/// Note list response envelope.
+class NotesListResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Note list items sorted by `updated_at DESC, uuid ASC`.
+  final List<NoteItem> items;
+
+  /// Effective limit after normalization.
+  final int appliedLimit;
+
+  const NotesListResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.items,
+    required this.appliedLimit,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      items.hashCode ^
+      appliedLimit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NotesListResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          items == other.items &&
+          appliedLimit == other.appliedLimit;
+}
+
+/// Recurring series / occurrence edit response envelope.
+class RecurrenceResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Atom holding the result: the series, a detached occurrence or a
+  /// split-off series.
+  final String? atomId;
+
+  /// Canonical RRULE of that atom, or null when it does not recur.
+  final String? recurrenceRule;
+
+  const RecurrenceResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    this.atomId,
+    this.recurrenceRule,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      atomId.hashCode ^
+      recurrenceRule.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecurrenceResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          atomId == other.atomId &&
+          recurrenceRule == other.recurrenceRule;
+}
+
+/// One reminder attached to an atom.
+class ReminderItem {
+  /// Stable reminder ID in string form.
+  final String reminderId;
+
+  /// Owning atom ID.
+  final String atomId;
+
+  /// Fire anchor (`absolute|start|due`).
+  final String anchor;
+
+  /// Absolute fire time in epoch ms (`absolute` only).
+  final PlatformInt64? remindAt;
+
+  /// Offset from the anchor in ms, negative = before (`start|due` only).
+  final PlatformInt64? offsetMs;
+
+  /// Latest acknowledged scheduled fire time in epoch ms.
+  final PlatformInt64? ackedThrough;
+
+  /// Pending snooze redelivery time in epoch ms.
+  final PlatformInt64? snoozedUntil;
+
+  /// Next pending fire time in epoch ms; null when exhausted or silent.
+  final PlatformInt64? nextFireAt;
+
+  /// Creation time in epoch ms.
+  final PlatformInt64 createdAt;
+
+  const ReminderItem({
+    required this.reminderId,
+    required this.atomId,
+    required this.anchor,
+    this.remindAt,
+    this.offsetMs,
+    this.ackedThrough,
+    this.snoozedUntil,
+    this.nextFireAt,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode =>
+      reminderId.hashCode ^
+      atomId.hashCode ^
+      anchor.hashCode ^
+      remindAt.hashCode ^
+      offsetMs.hashCode ^
+      ackedThrough.hashCode ^
+      snoozedUntil.hashCode ^
+      nextFireAt.hashCode ^
+      createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReminderItem &&
+          runtimeType == other.runtimeType &&
+          reminderId == other.reminderId &&
+          atomId == other.atomId &&
+          anchor == other.anchor &&
+          remindAt == other.remindAt &&
+          offsetMs == other.offsetMs &&
+          ackedThrough == other.ackedThrough &&
+          snoozedUntil == other.snoozedUntil &&
+          nextFireAt == other.nextFireAt &&
+          createdAt == other.createdAt;
+}
+
+/// Per-atom reminder list response envelope.
+class ReminderListResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Reminders, oldest first.
+  final List<ReminderItem> items;
+
+  const ReminderListResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.items,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ items.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReminderListResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          items == other.items;
+}
+
+/// Single reminder response envelope.
+class ReminderResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Reminder state after the operation; null on failure and removal.
+  final ReminderItem? reminder;
+
+  const ReminderResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    this.reminder,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ reminder.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReminderResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          reminder == other.reminder;
+}
+
+/// One indexed resource link (Markdown link or bare URL).
+class ResourceLinkItem {
+  /// Stable link row id.
+  final PlatformInt64 linkId;
+
+  /// Atom whose content contains the link.
+  final String atomId;
+
+  /// Target as written in content.
+  final String target;
+
+  /// Lowercase scheme without `:`.
+  final String scheme;
+
+  /// Optional Markdown label.
+  final String? label;
+
+  const ResourceLinkItem({
+    required this.linkId,
+    required this.atomId,
+    required this.target,
+    required this.scheme,
+    this.label,
+  });
+
+  @override
+  int get hashCode =>
+      linkId.hashCode ^
+      atomId.hashCode ^
+      target.hashCode ^
+      scheme.hashCode ^
+      label.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ResourceLinkItem &&
+          runtimeType == other.runtimeType &&
+          linkId == other.linkId &&
+          atomId == other.atomId &&
+          target == other.target &&
+          scheme == other.scheme &&
+          label == other.label;
+}
+
+/// Resource link list/search response envelope.
+class ResourceLinksResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Links in document (list) or recency (search) order.
+  final List<ResourceLinkItem> items;
+
+  const ResourceLinksResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.items,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ items.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ResourceLinksResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          items == other.items;
+}
+
+/// Subtask hierarchy response envelope.
+class SubtaskResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Target task ID.
+  final String? atomId;
+
+  /// Parent task ID of the target, or null if top-level.
+  final String? parentId;
+
+  /// Active subtask IDs of the target in display order.
+  final List<String> subtaskIds;
+
+  /// Subtasks with status `done`.
+  final int done;
+
+  /// Subtasks counted for progress (cancelled excluded).
+  final int total;
+
+  const SubtaskResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    this.atomId,
+    this.parentId,
+    required this.subtaskIds,
+    required this.done,
+    required this.total,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      atomId.hashCode ^
+      parentId.hashCode ^
+      subtaskIds.hashCode ^
+      done.hashCode ^
+      total.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SubtaskResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          atomId == other.atomId &&
+          parentId == other.parentId &&
+          subtaskIds == other.subtaskIds &&
+          done == other.done &&
+          total == other.total;
+}
+
+/// Tag rename/merge/delete response envelope.
+class TagEditResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Number of atoms whose tag set changed.
+  final int affectedAtoms;
+
+  const TagEditResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.affectedAtoms,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      affectedAtoms.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TagEditResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          affectedAtoms == other.affectedAtoms;
+}
+
+/// Usage counters for one hierarchical tag path.
+class TagUsageItem {
+  /// Normalized tag path, e.g. `work/client`.
+  final String name;
+
+  /// Live atoms carrying exactly this tag.
+  final int directCount;
+
+  /// Live atoms carrying this tag or any descendant.
+  final int totalCount;
+
+  const TagUsageItem({
+    required this.name,
+    required this.directCount,
+    required this.totalCount,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^ directCount.hashCode ^ totalCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TagUsageItem &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          directCount == other.directCount &&
+          totalCount == other.totalCount;
+}
+
+/// Tags list response envelope.
+class TagsListResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Normalized tags known by storage.
+  final List<String> tags;
+
+  const TagsListResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.tags,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ tags.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TagsListResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          tags == other.tags;
+}
+
+/// Tag usage response envelope.
+class TagsUsageResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Tag paths sorted by name, including implicit parents.
+  final List<TagUsageItem> items;
+
+  const TagsUsageResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.items,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ items.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TagsUsageResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          items == other.items;
+}
+
+/// Task dependency response envelope.
+class TaskDependencyResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Target task ID.
+  final String? atomId;
+
+  /// Whether any prerequisite is still unfinished.
+  final bool blocked;
+
+  /// Active prerequisite task IDs, oldest dependency first.
+  final List<String> prerequisiteIds;
+
+  /// Active task IDs depending on the target, oldest dependency first.
+  final List<String> dependentIds;
+
+  const TaskDependencyResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    this.atomId,
+    required this.blocked,
+    required this.prerequisiteIds,
+    required this.dependentIds,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      atomId.hashCode ^
+      blocked.hashCode ^
+      prerequisiteIds.hashCode ^
+      dependentIds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TaskDependencyResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          atomId == other.atomId &&
+          blocked == other.blocked &&
+          prerequisiteIds == other.prerequisiteIds &&
+          dependentIds == other.dependentIds;
+}
+
+/// Tracked time of one local day.
+class TimeDayTotalItem {
+  /// Local date (`YYYY-MM-DD`).
+  final String date;
+
+  /// Tracked ms inside the day.
+  final PlatformInt64 totalMs;
+
+  const TimeDayTotalItem({required this.date, required this.totalMs});
+
+  @override
+  int get hashCode => date.hashCode ^ totalMs.hashCode;
 
-  /// Raw markdown content.
-  final String content;
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TimeDayTotalItem &&
+          runtimeType == other.runtimeType &&
+          date == other.date &&
+          totalMs == other.totalMs;
+}
 
-  /// Derived plain-text preview.
-  final String? previewText;
+/// One tracked time session on a task.
+class TimeEntryItem {
+  /// Stable entry ID in string form.
+  final String entryId;
 
-  /// Derived first markdown image path.
-  final String? previewImage;
+  /// Tracked task atom ID.
+  final String atomId;
 
-  /// Normalized lowercase tags for this atom.
-  final List<String> tags;
+  /// Device that recorded the entry.
+  final String deviceId;
 
-  /// Epoch ms — start boundary (NULL = no start).
-  final PlatformInt64? startAt;
+  /// Session start in epoch ms.
+  final PlatformInt64 startedAt;
 
-  /// Epoch ms — end boundary (NULL = no end).
-  final PlatformInt64? endAt;
+  /// Session end in epoch ms; null while the timer runs.
+  final PlatformInt64? endedAt;
 
-  /// Current task status string, or null if statusless.
-  final String? taskStatus;
+  /// Optional free-text note.
+  final String? note;
 
-  /// Update timestamp in epoch milliseconds.
-  final PlatformInt64 updatedAt;
+  /// Whether the timer is still running.
+  final bool running;
 
-  const AtomListItem({
+  /// Tracked ms; running timers count up to the response time.
+  final PlatformInt64 durationMs;
+
+  const TimeEntryItem({
+    required this.entryId,
     required this.atomId,
-    required this.kind,
-    required this.content,
-    this.previewText,
-    this.previewImage,
-    required this.tags,
-    this.startAt,
-    this.endAt,
-    this.taskStatus,
-    required this.updatedAt,
+    required this.deviceId,
+    required this.startedAt,
+    this.endedAt,
+    this.note,
+    required this.running,
+    required this.durationMs,
   });
 
   @override
   int get hashCode =>
+      entryId.hashCode ^
       atomId.hashCode ^
-      kind.hashCode ^
-      content.hashCode ^
-      previewText.hashCode ^
-      previewImage.hashCode ^
-      tags.hashCode ^
-      startAt.hashCode ^
-      endAt.hashCode ^
-      taskStatus.hashCode ^
-      updatedAt.hashCode;
+      deviceId.hashCode ^
+      startedAt.hashCode ^
+      endedAt.hashCode ^
+      note.hashCode ^
+      running.hashCode ^
+      durationMs.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is AtomListItem &&
+      other is TimeEntryItem &&
           runtimeType == other.runtimeType &&
+          entryId == other.entryId &&
           atomId == other.atomId &&
-          kind == other.kind &&
-          content == other.content &&
-          previewText == other.previewText &&
-          previewImage == other.previewImage &&
-          tags == other.tags &&
-          startAt == other.startAt &&
-          endAt == other.endAt &&
-          taskStatus == other.taskStatus &&
-          updatedAt == other.updatedAt;
+          deviceId == other.deviceId &&
+          startedAt == other.startedAt &&
+          endedAt == other.endedAt &&
+          note == other.note &&
+          running == other.running &&
+          durationMs == other.durationMs;
 }
 
-/// Section list response envelope.
-class AtomListResponse {
+/// Per-atom time entry list response envelope.
+class TimeEntryListResponse {
   /// Whether operation succeeded.
   final bool ok;
 
@@ -436,18 +4276,18 @@ class AtomListResponse {
   /// Human-readable message for diagnostics/UI.
   final String message;
 
-  /// Section items.
-  final List<AtomListItem> items;
+  /// Entries, oldest start first.
+  final List<TimeEntryItem> items;
 
-  /// Effective limit after normalization.
-  final int appliedLimit;
+  /// Sum of `duration_ms` over `items`.
+  final PlatformInt64 totalMs;
 
-  const AtomListResponse({
+  const TimeEntryListResponse({
     required this.ok,
     this.errorCode,
     required this.message,
     required this.items,
-    required this.appliedLimit,
+    required this.totalMs,
   });
 
   @override
@@ -456,208 +4296,212 @@ class AtomListResponse {
       errorCode.hashCode ^
       message.hashCode ^
       items.hashCode ^
-      appliedLimit.hashCode;
+      totalMs.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is AtomListResponse &&
+      other is TimeEntryListResponse &&
           runtimeType == other.runtimeType &&
           ok == other.ok &&
           errorCode == other.errorCode &&
           message == other.message &&
           items == other.items &&
-          appliedLimit == other.appliedLimit;
+          totalMs == other.totalMs;
 }
 
-/// Generic action response envelope for single-entry command flow.
-class EntryActionResponse {
+/// Single time entry response envelope.
+class TimeEntryResponse {
   /// Whether operation succeeded.
   final bool ok;
 
-  /// Optional created atom ID.
-  final String? atomId;
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
 
-  /// Human-readable response message for diagnostics/UI.
+  /// Human-readable message for diagnostics/UI.
   final String message;
 
-  const EntryActionResponse({
+  /// Entry after the operation; null on failure, deletion or no timer.
+  final TimeEntryItem? entry;
+
+  const TimeEntryResponse({
     required this.ok,
-    this.atomId,
+    this.errorCode,
     required this.message,
+    this.entry,
   });
 
   @override
-  int get hashCode => ok.hashCode ^ atomId.hashCode ^ message.hashCode;
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ entry.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is EntryActionResponse &&
+      other is TimeEntryResponse &&
           runtimeType == other.runtimeType &&
           ok == other.ok &&
-          atomId == other.atomId &&
-          message == other.message;
+          errorCode == other.errorCode &&
+          message == other.message &&
+          entry == other.entry;
 }
 
-/// Search item returned by single-entry search API.
-class EntrySearchItem {
-  /// Stable atom ID in string form.
-  final String atomId;
+/// Tracked time attributed to one workspace folder.
+class TimeFolderTotalItem {
+  /// Folder node ID.
+  final String folderId;
 
-  /// Atom projection kind (`note|task|event`).
-  final String kind;
+  /// Display path from the root (`A/B`).
+  final String path;
 
-  /// Short snippet summary for result display.
-  final String snippet;
+  /// Tracked ms inside the report range, subfolders included.
+  final PlatformInt64 totalMs;
 
-  const EntrySearchItem({
-    required this.atomId,
-    required this.kind,
-    required this.snippet,
+  const TimeFolderTotalItem({
+    required this.folderId,
+    required this.path,
+    required this.totalMs,
   });
 
   @override
-  int get hashCode => atomId.hashCode ^ kind.hashCode ^ snippet.hashCode;
+  int get hashCode => folderId.hashCode ^ path.hashCode ^ totalMs.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is EntrySearchItem &&
+      other is TimeFolderTotalItem &&
           runtimeType == other.runtimeType &&
-          atomId == other.atomId &&
-          kind == other.kind &&
-          snippet == other.snippet;
+          folderId == other.folderId &&
+          path == other.path &&
+          totalMs == other.totalMs;
 }
 
-/// Search response envelope for single-entry search flow.
-class EntrySearchResponse {
-  /// Whether search execution succeeded.
+/// Time report response envelope.
+class TimeReportResponse {
+  /// Whether operation succeeded.
   final bool ok;
 
-  /// Optional stable error code for machine branching.
+  /// Stable machine-readable error code for failure paths.
   final String? errorCode;
 
-  /// Search results (empty when no hits or scaffold mode).
-  final List<EntrySearchItem> items;
-
-  /// Human-readable response message for diagnostics.
+  /// Human-readable message for diagnostics/UI.
   final String message;
 
-  /// Effective applied search limit.
-  final int appliedLimit;
+  /// One item per local date in the range, zero days included.
+  final List<TimeDayTotalItem> days;
 
-  const EntrySearchResponse({
+  /// Per-tag totals, largest first.
+  final List<TimeTagTotalItem> tags;
+
+  /// Per-folder totals, ordered by path.
+  final List<TimeFolderTotalItem> folders;
+
+  const TimeReportResponse({
     required this.ok,
     this.errorCode,
-    required this.items,
     required this.message,
-    required this.appliedLimit,
+    required this.days,
+    required this.tags,
+    required this.folders,
   });
 
   @override
   int get hashCode =>
       ok.hashCode ^
       errorCode.hashCode ^
-      items.hashCode ^
       message.hashCode ^
-      appliedLimit.hashCode;
+      days.hashCode ^
+      tags.hashCode ^
+      folders.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is EntrySearchResponse &&
+      other is TimeReportResponse &&
           runtimeType == other.runtimeType &&
           ok == other.ok &&
           errorCode == other.errorCode &&
-          items == other.items &&
           message == other.message &&
-          appliedLimit == other.appliedLimit;
+          days == other.days &&
+          tags == other.tags &&
+          folders == other.folders;
 }
 
-/// Dart-side diagnostics logging response envelope.
-class LogDartEventResponse {
-  /// Whether operation succeeded.
-  final bool ok;
-
-  /// Stable machine-readable error code for failure paths.
-  final String? errorCode;
+/// Tracked time of atoms carrying one tag.
+class TimeTagTotalItem {
+  /// Normalized lowercase tag.
+  final String tag;
 
-  /// Human-readable message for diagnostics/UI.
-  final String message;
+  /// Tracked ms inside the report range.
+  final PlatformInt64 totalMs;
 
-  const LogDartEventResponse({
-    required this.ok,
-    this.errorCode,
-    required this.message,
-  });
+  const TimeTagTotalItem({required this.tag, required this.totalMs});
 
   @override
-  int get hashCode => ok.hashCode ^ errorCode.hashCode ^ message.hashCode;
+  int get hashCode => tag.hashCode ^ totalMs.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is LogDartEventResponse &&
+      other is TimeTagTotalItem &&
           runtimeType == other.runtimeType &&
-          ok == other.ok &&
-          errorCode == other.errorCode &&
-          message == other.message;
+          tag == other.tag &&
+          totalMs == other.totalMs;
 }
 
-/// Note DTO returned by notes/tags APIs.
-class NoteItem {
-  /// Stable note atom id.
+/// One trashed atom item for FFI.
+class TrashListItem {
+  /// Stable atom ID in string form.
   final String atomId;
 
-  /// Raw markdown content.
+  /// Atom kind: `note|task|event`.
+  final String kind;
+
+  /// Raw content at deletion time.
   final String content;
 
-  /// Derived plain-text preview.
+  /// Optional preview text (notes only).
   final String? previewText;
 
-  /// Derived first markdown image path.
-  final String? previewImage;
-
-  /// Update timestamp in epoch milliseconds.
-  final PlatformInt64 updatedAt;
+  /// Deletion timestamp in epoch milliseconds.
+  final PlatformInt64 deletedAt;
 
-  /// Normalized tags attached to the note.
-  final List<String> tags;
+  /// Whether a provider still has to sync the deletion.
+  final bool syncPending;
 
-  const NoteItem({
+  const TrashListItem({
     required this.atomId,
+    required this.kind,
     required this.content,
     this.previewText,
-    this.previewImage,
-    required this.updatedAt,
-    required this.tags,
+    required this.deletedAt,
+    required this.syncPending,
   });
 
   @override
   int get hashCode =>
       atomId.hashCode ^
+      kind.hashCode ^
       content.hashCode ^
       previewText.hashCode ^
-      previewImage.hashCode ^
-      updatedAt.hashCode ^
-      tags.hashCode;
+      deletedAt.hashCode ^
+      syncPending.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is NoteItem &&
+      other is TrashListItem &&
           runtimeType == other.runtimeType &&
           atomId == other.atomId &&
+          kind == other.kind &&
           content == other.content &&
           previewText == other.previewText &&
-          previewImage == other.previewImage &&
-          updatedAt == other.updatedAt &&
-          tags == other.tags;
+          deletedAt == other.deletedAt &&
+          syncPending == other.syncPending;
 }
 
-/// Note create/update/get response envelope.
-class NoteResponse {
+/// Trash list response envelope.
+class TrashListResponse {
   /// Whether operation succeeded.
   final bool ok;
 
@@ -667,33 +4511,42 @@ class NoteResponse {
   /// Human-readable message for diagnostics/UI.
   final String message;
 
-  /// Returned note payload on success.
-  final NoteItem? note;
+  /// Trashed items, most recently deleted first.
+  final List<TrashListItem> items;
 
-  const NoteResponse({
+  /// Effective limit after normalization.
+  final int appliedLimit;
+
+  const TrashListResponse({
     required this.ok,
     this.errorCode,
     required this.message,
-    this.note,
+    required this.items,
+    required this.appliedLimit,
   });
 
   @override
   int get hashCode =>
-      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ note.hashCode;
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      items.hashCode ^
+      appliedLimit.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is NoteResponse &&
+      other is TrashListResponse &&
           runtimeType == other.runtimeType &&
           ok == other.ok &&
           errorCode == other.errorCode &&
           message == other.message &&
-          note == other.note;
+          items == other.items &&
+          appliedLimit == other.appliedLimit;
 }
 
-/// Note list response envelope.
-class NotesListResponse {
+/// Trash purge response envelope (single or retention batch).
+class TrashPurgeResponse {
   /// Whether operation succeeded.
   final bool ok;
 
@@ -703,18 +4556,18 @@ class NotesListResponse {
   /// Human-readable message for diagnostics/UI.
   final String message;
 
-  /// Note list items sorted by `updated_at DESC, uuid ASC`.
-  final List<NoteItem> items;
+  /// Atoms removed permanently.
+  final int purged;
 
-  /// Effective limit after normalization.
-  final int appliedLimit;
+  /// Atoms kept as content-free tombstones until sync catches up.
+  final int keptForSync;
 
-  const NotesListResponse({
+  const TrashPurgeResponse({
     required this.ok,
     this.errorCode,
     required this.message,
-    required this.items,
-    required this.appliedLimit,
+    required this.purged,
+    required this.keptForSync,
   });
 
   @override
@@ -722,23 +4575,23 @@ class NotesListResponse {
       ok.hashCode ^
       errorCode.hashCode ^
       message.hashCode ^
-      items.hashCode ^
-      appliedLimit.hashCode;
+      purged.hashCode ^
+      keptForSync.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is NotesListResponse &&
+      other is TrashPurgeResponse &&
           runtimeType == other.runtimeType &&
           ok == other.ok &&
           errorCode == other.errorCode &&
           message == other.message &&
-          items == other.items &&
-          appliedLimit == other.appliedLimit;
+          purged == other.purged &&
+          keptForSync == other.keptForSync;
 }
 
-/// Tags list response envelope.
-class TagsListResponse {
+/// Trash restore response envelope.
+class TrashRestoreResponse {
   /// Whether operation succeeded.
   final bool ok;
 
@@ -748,29 +4601,38 @@ class TagsListResponse {
   /// Human-readable message for diagnostics/UI.
   final String message;
 
-  /// Normalized tags known by storage.
-  final List<String> tags;
+  /// Restored atom ID on success.
+  final String? atomId;
 
-  const TagsListResponse({
+  /// `note_ref` node revived or created for the restored note, if any.
+  final String? noteRefNodeId;
+
+  const TrashRestoreResponse({
     required this.ok,
     this.errorCode,
     required this.message,
-    required this.tags,
+    this.atomId,
+    this.noteRefNodeId,
   });
 
   @override
   int get hashCode =>
-      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ tags.hashCode;
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      atomId.hashCode ^
+      noteRefNodeId.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is TagsListResponse &&
+      other is TrashRestoreResponse &&
           runtimeType == other.runtimeType &&
           ok == other.ok &&
           errorCode == other.errorCode &&
           message == other.message &&
-          tags == other.tags;
+          atomId == other.atomId &&
+          noteRefNodeId == other.noteRefNodeId;
 }
 
 /// Workspace action response envelope.
@@ -925,3 +4787,42 @@ class WorkspaceNodeResponse {
           message == other.message &&
           node == other.node;
 }
+
+/// Workspace note title repair response envelope.
+class WorkspaceRepairResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Number of note_ref nodes renamed to their note title.
+  final int renamedCount;
+
+  const WorkspaceRepairResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.renamedCount,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      renamedCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WorkspaceRepairResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          renamedCount == other.renamedCount;
+}
@@ -65,7 +65,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 2046837960;
+  int get rustContentHash => 1246707851;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -76,11 +76,82 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<AtomConvertResponse> crateApiAtomConvert({
+    required String atomId,
+    required String targetKind,
+    PlatformInt64? startMs,
+    PlatformInt64? endMs,
+  });
+
+  Future<ReminderListResponse> crateApiAtomReminders({required String atomId});
+
+  Future<AtomScheduleResponse> crateApiAtomSchedule({required String atomId});
+
+  Future<AtomScheduleResponse> crateApiAtomSetAllDay({
+    required String atomId,
+    required String startDate,
+    String? endDate,
+  });
+
+  Future<AtomTaskMetaResponse> crateApiAtomSetDue({
+    required String atomId,
+    PlatformInt64? dueAt,
+  });
+
+  Future<AtomTaskMetaResponse> crateApiAtomSetPriority({
+    required String atomId,
+    String? priority,
+  });
+
+  Future<RecurrenceResponse> crateApiAtomSetRecurrence({
+    required String atomId,
+    String? rule,
+  });
+
+  Future<AtomTaskMetaResponse> crateApiAtomTaskMeta({required String atomId});
+
+  Future<TimeEntryListResponse> crateApiAtomTimeEntries({
+    required String atomId,
+    required PlatformInt64 nowMs,
+  });
+
   Future<EntryActionResponse> crateApiAtomUpdateStatus({
     required String atomId,
     String? status,
   });
 
+  Future<EntryActionResponse> crateApiAtomUpdateStatusWith({
+    required String atomId,
+    String? status,
+    PlatformInt64? stopTimerAt,
+  });
+
+  Future<AttachmentImportResponse> crateApiAttachmentImport({
+    required String sourcePath,
+  });
+
+  Future<AttachmentResolveResponse> crateApiAttachmentResolve({
+    required String uri,
+  });
+
+  Future<AttachmentGcResponse> crateApiAttachmentsGc({PlatformInt64? graceMs});
+
+  Future<CalendarConflictsResponse> crateApiCalendarConflicts({
+    required String startDate,
+    required String endDate,
+  });
+
+  Future<CalendarDaysResponse> crateApiCalendarDays({
+    required String startDate,
+    required String endDate,
+  });
+
+  Future<CalendarFreeSlotsResponse> crateApiCalendarFreeSlots({
+    required PlatformInt64 startMs,
+    required PlatformInt64 endMs,
+    required int durationMinutes,
+  });
+
   Future<AtomListResponse> crateApiCalendarListByRange({
     required PlatformInt64 startMs,
     required PlatformInt64 endMs,
@@ -88,14 +159,27 @@ abstract class RustLibApi extends BaseApi {
     int? offset,
   });
 
+  Future<CalendarDaysResponse> crateApiCalendarMonth({
+    required PlatformInt64 nowMs,
+  });
+
   Future<EntryActionResponse> crateApiCalendarUpdateEvent({
     required String atomId,
     required PlatformInt64 startMs,
     required PlatformInt64 endMs,
   });
 
+  Future<CalendarDaysResponse> crateApiCalendarWeek({
+    required PlatformInt64 nowMs,
+  });
+
   String crateApiConfigureEntryDbPath({required String dbPath});
 
+  String crateApiConfigureTimeZone({
+    required String timeZone,
+    required String weekStart,
+  });
+
   String crateApiCoreVersion();
 
   Future<EntryActionResponse> crateApiEntryCreateNote({
@@ -106,6 +190,11 @@ abstract class RustLibApi extends BaseApi {
     required String content,
   });
 
+  EntryQuickAddResponse crateApiEntryQuickAddParse({
+    required String text,
+    required PlatformInt64 nowMs,
+  });
+
   Future<EntryActionResponse> crateApiEntrySchedule({
     required String title,
     required PlatformInt64 startEpochMs,
@@ -118,8 +207,33 @@ abstract class RustLibApi extends BaseApi {
     int? limit,
   });
 
+  EntrySearchSyntaxResponse crateApiEntrySearchCheck({required String text});
+
+  Future<EntrySearchPageResponse> crateApiEntrySearchPage({
+    required String text,
+    String? kind,
+    int? limit,
+    String? cursor,
+  });
+
   String crateApiInitLogging({required String level, required String logDir});
 
+  Future<LaunchPlanResponse> crateApiLinksLaunchPlan({
+    required String atomId,
+    int? maxItems,
+  });
+
+  Future<ResourceLinksResponse> crateApiLinksList({required String atomId});
+
+  Future<ResourceLinksResponse> crateApiLinksSearch({
+    required String query,
+    int? limit,
+  });
+
+  LocalTimeWindowsResponse crateApiLocalTimeWindows({
+    required PlatformInt64 nowMs,
+  });
+
   LogDartEventResponse crateApiLogDartEvent({
     required String level,
     required String eventName,
@@ -127,15 +241,55 @@ abstract class RustLibApi extends BaseApi {
     required String message,
   });
 
+  Future<AttachmentsListResponse> crateApiNoteAttachments({
+    required String atomId,
+  });
+
   Future<NoteResponse> crateApiNoteCreate({required String content});
 
   Future<NoteResponse> crateApiNoteGet({required String atomId});
 
+  Future<NoteLinksResponse> crateApiNoteLinks({required String atomId});
+
+  Future<NoteOutlineResponse> crateApiNoteOutline({required String atomId});
+
+  Future<NotePropertiesResponse> crateApiNoteProperties({
+    required String atomId,
+  });
+
+  Future<NoteRevisionDiffResponse> crateApiNoteRevisionDiff({
+    required String atomId,
+    required PlatformInt64 fromRevisionId,
+    PlatformInt64? toRevisionId,
+  });
+
+  Future<NoteResponse> crateApiNoteRevisionRestore({
+    required String atomId,
+    required PlatformInt64 revisionId,
+  });
+
+  Future<NoteRevisionsListResponse> crateApiNoteRevisionsList({
+    required String atomId,
+    int? limit,
+    int? offset,
+  });
+
   Future<NoteResponse> crateApiNoteSetTags({
     required String atomId,
     required List<String> tags,
   });
 
+  Future<NoteTitleResponse> crateApiNoteSetTitle({
+    required String atomId,
+    String? title,
+  });
+
+  Future<NoteTagSourcesResponse> crateApiNoteTagSources({
+    required String atomId,
+  });
+
+  Future<NoteTitleResponse> crateApiNoteTitle({required String atomId});
+
   Future<NoteResponse> crateApiNoteUpdate({
     required String atomId,
     required String content,
@@ -147,12 +301,123 @@ abstract class RustLibApi extends BaseApi {
     int? offset,
   });
 
+  Future<NotesListResponse> crateApiNotesListByTags({
+    required String tagExpr,
+    int? limit,
+    int? offset,
+  });
+
   String crateApiPing();
 
+  Future<RecurrenceResponse> crateApiRecurrenceDeleteOccurrence({
+    required String seriesId,
+    required PlatformInt64 occurrenceStartAt,
+    required String scope,
+  });
+
+  Future<RecurrenceResponse> crateApiRecurrenceEditOccurrence({
+    required String seriesId,
+    required PlatformInt64 occurrenceStartAt,
+    required String scope,
+    String? content,
+    PlatformInt64? startAt,
+    PlatformInt64? endAt,
+    String? status,
+    String? recurrenceRule,
+  });
+
+  Future<ReminderResponse> crateApiReminderAck({
+    required String reminderId,
+    required PlatformInt64 fireAt,
+  });
+
+  Future<ReminderResponse> crateApiReminderAdd({
+    required String atomId,
+    required String anchor,
+    PlatformInt64? remindAt,
+    PlatformInt64? offsetMs,
+  });
+
+  Future<ReminderResponse> crateApiReminderRemove({required String reminderId});
+
+  Future<ReminderResponse> crateApiReminderSnooze({
+    required String reminderId,
+    required PlatformInt64 fireAt,
+    required PlatformInt64 untilMs,
+  });
+
+  Future<DueRemindersResponse> crateApiRemindersDue({
+    required PlatformInt64 nowMs,
+  });
+
+  Future<SubtaskResponse> crateApiSubtaskSetParent({
+    required String atomId,
+    String? parentId,
+    int? targetIndex,
+  });
+
+  Future<SubtaskResponse> crateApiSubtasksReorder({
+    required String parentId,
+    required List<String> orderedIds,
+  });
+
+  Future<TagEditResponse> crateApiTagDelete({required String name});
+
+  Future<TagEditResponse> crateApiTagRename({
+    required String from,
+    required String to,
+  });
+
   Future<TagsListResponse> crateApiTagsList();
 
+  Future<TagEditResponse> crateApiTagsMerge({
+    required List<String> sources,
+    required String target,
+  });
+
+  Future<TagsUsageResponse> crateApiTagsUsage();
+
+  Future<TaskDependencyResponse> crateApiTaskAddDependency({
+    required String atomId,
+    required String dependsOnId,
+  });
+
+  Future<TaskDependencyResponse> crateApiTaskDependencies({
+    required String atomId,
+  });
+
+  Future<SubtaskResponse> crateApiTaskPromoteChecklist({
+    required String atomId,
+  });
+
+  Future<TaskDependencyResponse> crateApiTaskRemoveDependency({
+    required String atomId,
+    required String dependsOnId,
+  });
+
+  Future<SubtaskResponse> crateApiTaskSubtasks({required String atomId});
+
   Future<AtomListResponse> crateApiTasksListInbox({int? limit, int? offset});
 
+  Future<AtomListResponse> crateApiTasksListInboxUnblocked({
+    int? limit,
+    int? offset,
+  });
+
+  Future<AtomListResponse> crateApiTasksListOverdue({
+    required PlatformInt64 nowMs,
+    int? limit,
+    int? offset,
+  });
+
+  Future<AtomListResponse> crateApiTasksListProjectOrder({
+    required String projectId,
+  });
+
+  Future<AtomListResponse> crateApiTasksListSubtasks({
+    required String parentId,
+  });
+
   Future<AtomListResponse> crateApiTasksListToday({
     required PlatformInt64 bodMs,
     required PlatformInt64 eodMs,
@@ -160,12 +425,78 @@ abstract class RustLibApi extends BaseApi {
     int? offset,
   });
 
+  Future<AtomListResponse> crateApiTasksListTodayLocal({
+    required PlatformInt64 nowMs,
+    required bool hideBlocked,
+    int? limit,
+    int? offset,
+  });
+
+  Future<AtomListResponse> crateApiTasksListTodayUnblocked({
+    required PlatformInt64 bodMs,
+    required PlatformInt64 eodMs,
+    int? limit,
+    int? offset,
+  });
+
   Future<AtomListResponse> crateApiTasksListUpcoming({
     required PlatformInt64 eodMs,
     int? limit,
     int? offset,
   });
 
+  Future<AtomListResponse> crateApiTasksListUpcomingLocal({
+    required PlatformInt64 nowMs,
+    int? limit,
+    int? offset,
+  });
+
+  Future<TimeEntryResponse> crateApiTimeEntryAdd({
+    required String atomId,
+    required PlatformInt64 startedAt,
+    required PlatformInt64 endedAt,
+    String? note,
+  });
+
+  Future<TimeEntryResponse> crateApiTimeEntryDelete({required String entryId});
+
+  Future<TimeEntryResponse> crateApiTimeEntryUpdate({
+    required String entryId,
+    String? atomId,
+    PlatformInt64? startedAt,
+    PlatformInt64? endedAt,
+    String? note,
+  });
+
+  Future<TimeReportResponse> crateApiTimeReport({
+    required String startDate,
+    required String endDate,
+    required PlatformInt64 nowMs,
+  });
+
+  Future<TimeEntryResponse> crateApiTimeTimerRunning();
+
+  Future<TimeEntryResponse> crateApiTimeTimerStart({
+    required String atomId,
+    required PlatformInt64 nowMs,
+  });
+
+  Future<TimeEntryResponse> crateApiTimeTimerStop({
+    required PlatformInt64 nowMs,
+  });
+
+  Future<TrashListResponse> crateApiTrashList({
+    String? kind,
+    int? limit,
+    int? offset,
+  });
+
+  Future<TrashPurgeResponse> crateApiTrashPurge({required String atomId});
+
+  Future<TrashPurgeResponse> crateApiTrashPurgeExpired({int? retentionDays});
+
+  Future<TrashRestoreResponse> crateApiTrashRestore({required String atomId});
+
   Future<WorkspaceNodeResponse> crateApiWorkspaceCreateFolder({
     String? parentNodeId,
     required String name,
@@ -196,6 +527,8 @@ abstract class RustLibApi extends BaseApi {
     required String nodeId,
     required String newName,
   });
+
+  Future<WorkspaceRepairResponse> crateApiWorkspaceRepairNoteTitles();
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -207,16 +540,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<EntryActionResponse> crateApiAtomUpdateStatus({
+  Future<AtomConvertResponse> crateApiAtomConvert({
     required String atomId,
-    String? status,
+    required String targetKind,
+    PlatformInt64? startMs,
+    PlatformInt64? endMs,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(atomId, serializer);
-          sse_encode_opt_String(status, serializer);
+          sse_encode_String(targetKind, serializer);
+          sse_encode_opt_box_autoadd_i_64(startMs, serializer);
+          sse_encode_opt_box_autoadd_i_64(endMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -225,36 +562,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_entry_action_response,
+          decodeSuccessData: sse_decode_atom_convert_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiAtomUpdateStatusConstMeta,
-        argValues: [atomId, status],
+        constMeta: kCrateApiAtomConvertConstMeta,
+        argValues: [atomId, targetKind, startMs, endMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiAtomUpdateStatusConstMeta => const TaskConstMeta(
-    debugName: 'atom_update_status',
-    argNames: ['atomId', 'status'],
+  TaskConstMeta get kCrateApiAtomConvertConstMeta => const TaskConstMeta(
+    debugName: 'atom_convert',
+    argNames: ['atomId', 'targetKind', 'startMs', 'endMs'],
   );
 
   @override
-  Future<AtomListResponse> crateApiCalendarListByRange({
-    required PlatformInt64 startMs,
-    required PlatformInt64 endMs,
-    int? limit,
-    int? offset,
-  }) {
+  Future<ReminderListResponse> crateApiAtomReminders({required String atomId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(startMs, serializer);
-          sse_encode_i_64(endMs, serializer);
-          sse_encode_opt_box_autoadd_u_32(limit, serializer);
-          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          sse_encode_String(atomId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -263,35 +592,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_atom_list_response,
+          decodeSuccessData: sse_decode_reminder_list_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCalendarListByRangeConstMeta,
-        argValues: [startMs, endMs, limit, offset],
+        constMeta: kCrateApiAtomRemindersConstMeta,
+        argValues: [atomId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCalendarListByRangeConstMeta =>
-      const TaskConstMeta(
-        debugName: 'calendar_list_by_range',
-        argNames: ['startMs', 'endMs', 'limit', 'offset'],
-      );
+  TaskConstMeta get kCrateApiAtomRemindersConstMeta =>
+      const TaskConstMeta(debugName: 'atom_reminders', argNames: ['atomId']);
 
   @override
-  Future<EntryActionResponse> crateApiCalendarUpdateEvent({
-    required String atomId,
-    required PlatformInt64 startMs,
-    required PlatformInt64 endMs,
-  }) {
+  Future<AtomScheduleResponse> crateApiAtomSchedule({required String atomId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(atomId, serializer);
-          sse_encode_i_64(startMs, serializer);
-          sse_encode_i_64(endMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -300,79 +620,100 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_entry_action_response,
+          decodeSuccessData: sse_decode_atom_schedule_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCalendarUpdateEventConstMeta,
-        argValues: [atomId, startMs, endMs],
+        constMeta: kCrateApiAtomScheduleConstMeta,
+        argValues: [atomId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCalendarUpdateEventConstMeta =>
-      const TaskConstMeta(
-        debugName: 'calendar_update_event',
-        argNames: ['atomId', 'startMs', 'endMs'],
-      );
+  TaskConstMeta get kCrateApiAtomScheduleConstMeta =>
+      const TaskConstMeta(debugName: 'atom_schedule', argNames: ['atomId']);
 
   @override
-  String crateApiConfigureEntryDbPath({required String dbPath}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<AtomScheduleResponse> crateApiAtomSetAllDay({
+    required String atomId,
+    required String startDate,
+    String? endDate,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dbPath, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+          sse_encode_String(atomId, serializer);
+          sse_encode_String(startDate, serializer);
+          sse_encode_opt_String(endDate, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_atom_schedule_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiConfigureEntryDbPathConstMeta,
-        argValues: [dbPath],
+        constMeta: kCrateApiAtomSetAllDayConstMeta,
+        argValues: [atomId, startDate, endDate],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiConfigureEntryDbPathConstMeta =>
-      const TaskConstMeta(
-        debugName: 'configure_entry_db_path',
-        argNames: ['dbPath'],
-      );
+  TaskConstMeta get kCrateApiAtomSetAllDayConstMeta => const TaskConstMeta(
+    debugName: 'atom_set_all_day',
+    argNames: ['atomId', 'startDate', 'endDate'],
+  );
 
   @override
-  String crateApiCoreVersion() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
-        },
+  Future<AtomTaskMetaResponse> crateApiAtomSetDue({
+    required String atomId,
+    PlatformInt64? dueAt,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_opt_box_autoadd_i_64(dueAt, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 5,
+            port: port_,
+          );
+        },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_atom_task_meta_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiCoreVersionConstMeta,
-        argValues: [],
+        constMeta: kCrateApiAtomSetDueConstMeta,
+        argValues: [atomId, dueAt],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCoreVersionConstMeta =>
-      const TaskConstMeta(debugName: 'core_version', argNames: []);
+  TaskConstMeta get kCrateApiAtomSetDueConstMeta => const TaskConstMeta(
+    debugName: 'atom_set_due',
+    argNames: ['atomId', 'dueAt'],
+  );
 
   @override
-  Future<EntryActionResponse> crateApiEntryCreateNote({
-    required String content,
+  Future<AtomTaskMetaResponse> crateApiAtomSetPriority({
+    required String atomId,
+    String? priority,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(content, serializer);
+          sse_encode_String(atomId, serializer);
+          sse_encode_opt_String(priority, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -381,30 +722,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_entry_action_response,
+          decodeSuccessData: sse_decode_atom_task_meta_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiEntryCreateNoteConstMeta,
-        argValues: [content],
+        constMeta: kCrateApiAtomSetPriorityConstMeta,
+        argValues: [atomId, priority],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiEntryCreateNoteConstMeta => const TaskConstMeta(
-    debugName: 'entry_create_note',
-    argNames: ['content'],
+  TaskConstMeta get kCrateApiAtomSetPriorityConstMeta => const TaskConstMeta(
+    debugName: 'atom_set_priority',
+    argNames: ['atomId', 'priority'],
   );
 
   @override
-  Future<EntryActionResponse> crateApiEntryCreateTask({
-    required String content,
+  Future<RecurrenceResponse> crateApiAtomSetRecurrence({
+    required String atomId,
+    String? rule,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(content, serializer);
+          sse_encode_String(atomId, serializer);
+          sse_encode_opt_String(rule, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -413,34 +756,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_entry_action_response,
+          decodeSuccessData: sse_decode_recurrence_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiEntryCreateTaskConstMeta,
-        argValues: [content],
+        constMeta: kCrateApiAtomSetRecurrenceConstMeta,
+        argValues: [atomId, rule],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiEntryCreateTaskConstMeta => const TaskConstMeta(
-    debugName: 'entry_create_task',
-    argNames: ['content'],
+  TaskConstMeta get kCrateApiAtomSetRecurrenceConstMeta => const TaskConstMeta(
+    debugName: 'atom_set_recurrence',
+    argNames: ['atomId', 'rule'],
   );
 
   @override
-  Future<EntryActionResponse> crateApiEntrySchedule({
-    required String title,
-    required PlatformInt64 startEpochMs,
-    PlatformInt64? endEpochMs,
-  }) {
+  Future<AtomTaskMetaResponse> crateApiAtomTaskMeta({required String atomId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(title, serializer);
-          sse_encode_i_64(startEpochMs, serializer);
-          sse_encode_opt_box_autoadd_i_64(endEpochMs, serializer);
+          sse_encode_String(atomId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -449,34 +786,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_entry_action_response,
+          decodeSuccessData: sse_decode_atom_task_meta_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiEntryScheduleConstMeta,
-        argValues: [title, startEpochMs, endEpochMs],
+        constMeta: kCrateApiAtomTaskMetaConstMeta,
+        argValues: [atomId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiEntryScheduleConstMeta => const TaskConstMeta(
-    debugName: 'entry_schedule',
-    argNames: ['title', 'startEpochMs', 'endEpochMs'],
-  );
+  TaskConstMeta get kCrateApiAtomTaskMetaConstMeta =>
+      const TaskConstMeta(debugName: 'atom_task_meta', argNames: ['atomId']);
 
   @override
-  Future<EntrySearchResponse> crateApiEntrySearch({
-    required String text,
-    String? kind,
-    int? limit,
+  Future<TimeEntryListResponse> crateApiAtomTimeEntries({
+    required String atomId,
+    required PlatformInt64 nowMs,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(text, serializer);
-          sse_encode_opt_String(kind, serializer);
-          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_String(atomId, serializer);
+          sse_encode_i_64(nowMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -485,87 +818,101 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_entry_search_response,
+          decodeSuccessData: sse_decode_time_entry_list_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiEntrySearchConstMeta,
-        argValues: [text, kind, limit],
+        constMeta: kCrateApiAtomTimeEntriesConstMeta,
+        argValues: [atomId, nowMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiEntrySearchConstMeta => const TaskConstMeta(
-    debugName: 'entry_search',
-    argNames: ['text', 'kind', 'limit'],
+  TaskConstMeta get kCrateApiAtomTimeEntriesConstMeta => const TaskConstMeta(
+    debugName: 'atom_time_entries',
+    argNames: ['atomId', 'nowMs'],
   );
 
   @override
-  String crateApiInitLogging({required String level, required String logDir}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<EntryActionResponse> crateApiAtomUpdateStatus({
+    required String atomId,
+    String? status,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(level, serializer);
-          sse_encode_String(logDir, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+          sse_encode_String(atomId, serializer);
+          sse_encode_opt_String(status, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 10,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_entry_action_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiInitLoggingConstMeta,
-        argValues: [level, logDir],
+        constMeta: kCrateApiAtomUpdateStatusConstMeta,
+        argValues: [atomId, status],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitLoggingConstMeta => const TaskConstMeta(
-    debugName: 'init_logging',
-    argNames: ['level', 'logDir'],
+  TaskConstMeta get kCrateApiAtomUpdateStatusConstMeta => const TaskConstMeta(
+    debugName: 'atom_update_status',
+    argNames: ['atomId', 'status'],
   );
 
   @override
-  LogDartEventResponse crateApiLogDartEvent({
-    required String level,
-    required String eventName,
-    required String module,
-    required String message,
+  Future<EntryActionResponse> crateApiAtomUpdateStatusWith({
+    required String atomId,
+    String? status,
+    PlatformInt64? stopTimerAt,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(level, serializer);
-          sse_encode_String(eventName, serializer);
-          sse_encode_String(module, serializer);
-          sse_encode_String(message, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+          sse_encode_String(atomId, serializer);
+          sse_encode_opt_String(status, serializer);
+          sse_encode_opt_box_autoadd_i_64(stopTimerAt, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 11,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_log_dart_event_response,
+          decodeSuccessData: sse_decode_entry_action_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiLogDartEventConstMeta,
-        argValues: [level, eventName, module, message],
+        constMeta: kCrateApiAtomUpdateStatusWithConstMeta,
+        argValues: [atomId, status, stopTimerAt],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLogDartEventConstMeta => const TaskConstMeta(
-    debugName: 'log_dart_event',
-    argNames: ['level', 'eventName', 'module', 'message'],
-  );
+  TaskConstMeta get kCrateApiAtomUpdateStatusWithConstMeta =>
+      const TaskConstMeta(
+        debugName: 'atom_update_status_with',
+        argNames: ['atomId', 'status', 'stopTimerAt'],
+      );
 
   @override
-  Future<NoteResponse> crateApiNoteCreate({required String content}) {
+  Future<AttachmentImportResponse> crateApiAttachmentImport({
+    required String sourcePath,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(content, serializer);
+          sse_encode_String(sourcePath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -574,26 +921,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_note_response,
+          decodeSuccessData: sse_decode_attachment_import_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiNoteCreateConstMeta,
-        argValues: [content],
+        constMeta: kCrateApiAttachmentImportConstMeta,
+        argValues: [sourcePath],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNoteCreateConstMeta =>
-      const TaskConstMeta(debugName: 'note_create', argNames: ['content']);
+  TaskConstMeta get kCrateApiAttachmentImportConstMeta => const TaskConstMeta(
+    debugName: 'attachment_import',
+    argNames: ['sourcePath'],
+  );
 
   @override
-  Future<NoteResponse> crateApiNoteGet({required String atomId}) {
+  Future<AttachmentResolveResponse> crateApiAttachmentResolve({
+    required String uri,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(atomId, serializer);
+          sse_encode_String(uri, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -602,30 +953,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_note_response,
+          decodeSuccessData: sse_decode_attachment_resolve_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiNoteGetConstMeta,
-        argValues: [atomId],
+        constMeta: kCrateApiAttachmentResolveConstMeta,
+        argValues: [uri],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNoteGetConstMeta =>
-      const TaskConstMeta(debugName: 'note_get', argNames: ['atomId']);
+  TaskConstMeta get kCrateApiAttachmentResolveConstMeta =>
+      const TaskConstMeta(debugName: 'attachment_resolve', argNames: ['uri']);
 
   @override
-  Future<NoteResponse> crateApiNoteSetTags({
-    required String atomId,
-    required List<String> tags,
-  }) {
+  Future<AttachmentGcResponse> crateApiAttachmentsGc({PlatformInt64? graceMs}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(atomId, serializer);
-          sse_encode_list_String(tags, serializer);
+          sse_encode_opt_box_autoadd_i_64(graceMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -634,32 +981,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_note_response,
+          decodeSuccessData: sse_decode_attachment_gc_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiNoteSetTagsConstMeta,
-        argValues: [atomId, tags],
+        constMeta: kCrateApiAttachmentsGcConstMeta,
+        argValues: [graceMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNoteSetTagsConstMeta => const TaskConstMeta(
-    debugName: 'note_set_tags',
-    argNames: ['atomId', 'tags'],
-  );
+  TaskConstMeta get kCrateApiAttachmentsGcConstMeta =>
+      const TaskConstMeta(debugName: 'attachments_gc', argNames: ['graceMs']);
 
   @override
-  Future<NoteResponse> crateApiNoteUpdate({
-    required String atomId,
-    required String content,
+  Future<CalendarConflictsResponse> crateApiCalendarConflicts({
+    required String startDate,
+    required String endDate,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(atomId, serializer);
-          sse_encode_String(content, serializer);
+          sse_encode_String(startDate, serializer);
+          sse_encode_String(endDate, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -668,34 +1013,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_note_response,
+          decodeSuccessData: sse_decode_calendar_conflicts_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiNoteUpdateConstMeta,
-        argValues: [atomId, content],
+        constMeta: kCrateApiCalendarConflictsConstMeta,
+        argValues: [startDate, endDate],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNoteUpdateConstMeta => const TaskConstMeta(
-    debugName: 'note_update',
-    argNames: ['atomId', 'content'],
+  TaskConstMeta get kCrateApiCalendarConflictsConstMeta => const TaskConstMeta(
+    debugName: 'calendar_conflicts',
+    argNames: ['startDate', 'endDate'],
   );
 
   @override
-  Future<NotesListResponse> crateApiNotesList({
-    String? tag,
-    int? limit,
-    int? offset,
+  Future<CalendarDaysResponse> crateApiCalendarDays({
+    required String startDate,
+    required String endDate,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_String(tag, serializer);
-          sse_encode_opt_box_autoadd_u_32(limit, serializer);
-          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          sse_encode_String(startDate, serializer);
+          sse_encode_String(endDate, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -704,49 +1047,72 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_notes_list_response,
+          decodeSuccessData: sse_decode_calendar_days_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiNotesListConstMeta,
-        argValues: [tag, limit, offset],
+        constMeta: kCrateApiCalendarDaysConstMeta,
+        argValues: [startDate, endDate],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNotesListConstMeta => const TaskConstMeta(
-    debugName: 'notes_list',
-    argNames: ['tag', 'limit', 'offset'],
+  TaskConstMeta get kCrateApiCalendarDaysConstMeta => const TaskConstMeta(
+    debugName: 'calendar_days',
+    argNames: ['startDate', 'endDate'],
   );
 
   @override
-  String crateApiPing() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<CalendarFreeSlotsResponse> crateApiCalendarFreeSlots({
+    required PlatformInt64 startMs,
+    required PlatformInt64 endMs,
+    required int durationMinutes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+          sse_encode_i_64(startMs, serializer);
+          sse_encode_i_64(endMs, serializer);
+          sse_encode_u_32(durationMinutes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_calendar_free_slots_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiPingConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCalendarFreeSlotsConstMeta,
+        argValues: [startMs, endMs, durationMinutes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiPingConstMeta =>
-      const TaskConstMeta(debugName: 'ping', argNames: []);
+  TaskConstMeta get kCrateApiCalendarFreeSlotsConstMeta => const TaskConstMeta(
+    debugName: 'calendar_free_slots',
+    argNames: ['startMs', 'endMs', 'durationMinutes'],
+  );
 
   @override
-  Future<TagsListResponse> crateApiTagsList() {
+  Future<AtomListResponse> crateApiCalendarListByRange({
+    required PlatformInt64 startMs,
+    required PlatformInt64 endMs,
+    int? limit,
+    int? offset,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(startMs, serializer);
+          sse_encode_i_64(endMs, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -755,27 +1121,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_tags_list_response,
+          decodeSuccessData: sse_decode_atom_list_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTagsListConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCalendarListByRangeConstMeta,
+        argValues: [startMs, endMs, limit, offset],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTagsListConstMeta =>
-      const TaskConstMeta(debugName: 'tags_list', argNames: []);
+  TaskConstMeta get kCrateApiCalendarListByRangeConstMeta =>
+      const TaskConstMeta(
+        debugName: 'calendar_list_by_range',
+        argNames: ['startMs', 'endMs', 'limit', 'offset'],
+      );
 
   @override
-  Future<AtomListResponse> crateApiTasksListInbox({int? limit, int? offset}) {
+  Future<CalendarDaysResponse> crateApiCalendarMonth({
+    required PlatformInt64 nowMs,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_box_autoadd_u_32(limit, serializer);
-          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          sse_encode_i_64(nowMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -784,36 +1154,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_atom_list_response,
+          decodeSuccessData: sse_decode_calendar_days_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTasksListInboxConstMeta,
-        argValues: [limit, offset],
+        constMeta: kCrateApiCalendarMonthConstMeta,
+        argValues: [nowMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTasksListInboxConstMeta => const TaskConstMeta(
-    debugName: 'tasks_list_inbox',
-    argNames: ['limit', 'offset'],
-  );
+  TaskConstMeta get kCrateApiCalendarMonthConstMeta =>
+      const TaskConstMeta(debugName: 'calendar_month', argNames: ['nowMs']);
 
   @override
-  Future<AtomListResponse> crateApiTasksListToday({
-    required PlatformInt64 bodMs,
-    required PlatformInt64 eodMs,
-    int? limit,
-    int? offset,
+  Future<EntryActionResponse> crateApiCalendarUpdateEvent({
+    required String atomId,
+    required PlatformInt64 startMs,
+    required PlatformInt64 endMs,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(bodMs, serializer);
-          sse_encode_i_64(eodMs, serializer);
-          sse_encode_opt_box_autoadd_u_32(limit, serializer);
-          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          sse_encode_String(atomId, serializer);
+          sse_encode_i_64(startMs, serializer);
+          sse_encode_i_64(endMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -822,34 +1188,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_atom_list_response,
+          decodeSuccessData: sse_decode_entry_action_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTasksListTodayConstMeta,
-        argValues: [bodMs, eodMs, limit, offset],
+        constMeta: kCrateApiCalendarUpdateEventConstMeta,
+        argValues: [atomId, startMs, endMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTasksListTodayConstMeta => const TaskConstMeta(
-    debugName: 'tasks_list_today',
-    argNames: ['bodMs', 'eodMs', 'limit', 'offset'],
-  );
+  TaskConstMeta get kCrateApiCalendarUpdateEventConstMeta =>
+      const TaskConstMeta(
+        debugName: 'calendar_update_event',
+        argNames: ['atomId', 'startMs', 'endMs'],
+      );
 
   @override
-  Future<AtomListResponse> crateApiTasksListUpcoming({
-    required PlatformInt64 eodMs,
-    int? limit,
-    int? offset,
+  Future<CalendarDaysResponse> crateApiCalendarWeek({
+    required PlatformInt64 nowMs,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(eodMs, serializer);
-          sse_encode_opt_box_autoadd_u_32(limit, serializer);
-          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          sse_encode_i_64(nowMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -858,1303 +1221,7736 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_atom_list_response,
+          decodeSuccessData: sse_decode_calendar_days_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTasksListUpcomingConstMeta,
-        argValues: [eodMs, limit, offset],
+        constMeta: kCrateApiCalendarWeekConstMeta,
+        argValues: [nowMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTasksListUpcomingConstMeta => const TaskConstMeta(
-    debugName: 'tasks_list_upcoming',
-    argNames: ['eodMs', 'limit', 'offset'],
-  );
+  TaskConstMeta get kCrateApiCalendarWeekConstMeta =>
+      const TaskConstMeta(debugName: 'calendar_week', argNames: ['nowMs']);
 
   @override
-  Future<WorkspaceNodeResponse> crateApiWorkspaceCreateFolder({
-    String? parentNodeId,
-    required String name,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiConfigureEntryDbPath({required String dbPath}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_String(parentNodeId, serializer);
-          sse_encode_String(name, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 22,
-            port: port_,
-          );
+          sse_encode_String(dbPath, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_workspace_node_response,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiWorkspaceCreateFolderConstMeta,
-        argValues: [parentNodeId, name],
+        constMeta: kCrateApiConfigureEntryDbPathConstMeta,
+        argValues: [dbPath],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWorkspaceCreateFolderConstMeta =>
+  TaskConstMeta get kCrateApiConfigureEntryDbPathConstMeta =>
       const TaskConstMeta(
-        debugName: 'workspace_create_folder',
-        argNames: ['parentNodeId', 'name'],
+        debugName: 'configure_entry_db_path',
+        argNames: ['dbPath'],
       );
 
   @override
-  Future<WorkspaceNodeResponse> crateApiWorkspaceCreateNoteRef({
-    String? parentNodeId,
-    required String atomId,
-    String? displayName,
+  String crateApiConfigureTimeZone({
+    required String timeZone,
+    required String weekStart,
   }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_String(parentNodeId, serializer);
-          sse_encode_String(atomId, serializer);
-          sse_encode_opt_String(displayName, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 23,
-            port: port_,
-          );
+          sse_encode_String(timeZone, serializer);
+          sse_encode_String(weekStart, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_workspace_node_response,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiWorkspaceCreateNoteRefConstMeta,
-        argValues: [parentNodeId, atomId, displayName],
+        constMeta: kCrateApiConfigureTimeZoneConstMeta,
+        argValues: [timeZone, weekStart],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWorkspaceCreateNoteRefConstMeta =>
-      const TaskConstMeta(
-        debugName: 'workspace_create_note_ref',
-        argNames: ['parentNodeId', 'atomId', 'displayName'],
-      );
+  TaskConstMeta get kCrateApiConfigureTimeZoneConstMeta => const TaskConstMeta(
+    debugName: 'configure_time_zone',
+    argNames: ['timeZone', 'weekStart'],
+  );
 
   @override
-  Future<WorkspaceActionResponse> crateApiWorkspaceDeleteFolder({
-    required String nodeId,
-    required String mode,
+  String crateApiCoreVersion() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCoreVersionConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCoreVersionConstMeta =>
+      const TaskConstMeta(debugName: 'core_version', argNames: []);
+
+  @override
+  Future<EntryActionResponse> crateApiEntryCreateNote({
+    required String content,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(nodeId, serializer);
-          sse_encode_String(mode, serializer);
+          sse_encode_String(content, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 25,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_workspace_action_response,
+          decodeSuccessData: sse_decode_entry_action_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiWorkspaceDeleteFolderConstMeta,
-        argValues: [nodeId, mode],
+        constMeta: kCrateApiEntryCreateNoteConstMeta,
+        argValues: [content],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWorkspaceDeleteFolderConstMeta =>
-      const TaskConstMeta(
-        debugName: 'workspace_delete_folder',
-        argNames: ['nodeId', 'mode'],
-      );
+  TaskConstMeta get kCrateApiEntryCreateNoteConstMeta => const TaskConstMeta(
+    debugName: 'entry_create_note',
+    argNames: ['content'],
+  );
 
   @override
-  Future<WorkspaceListChildrenResponse> crateApiWorkspaceListChildren({
-    String? parentNodeId,
+  Future<EntryActionResponse> crateApiEntryCreateTask({
+    required String content,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_String(parentNodeId, serializer);
+          sse_encode_String(content, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 26,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_workspace_list_children_response,
+          decodeSuccessData: sse_decode_entry_action_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiWorkspaceListChildrenConstMeta,
-        argValues: [parentNodeId],
+        constMeta: kCrateApiEntryCreateTaskConstMeta,
+        argValues: [content],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWorkspaceListChildrenConstMeta =>
-      const TaskConstMeta(
-        debugName: 'workspace_list_children',
-        argNames: ['parentNodeId'],
-      );
+  TaskConstMeta get kCrateApiEntryCreateTaskConstMeta => const TaskConstMeta(
+    debugName: 'entry_create_task',
+    argNames: ['content'],
+  );
 
   @override
-  Future<WorkspaceActionResponse> crateApiWorkspaceMoveNode({
-    required String nodeId,
-    String? newParentId,
-    PlatformInt64? targetOrder,
+  EntryQuickAddResponse crateApiEntryQuickAddParse({
+    required String text,
+    required PlatformInt64 nowMs,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_i_64(nowMs, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_entry_quick_add_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiEntryQuickAddParseConstMeta,
+        argValues: [text, nowMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEntryQuickAddParseConstMeta => const TaskConstMeta(
+    debugName: 'entry_quick_add_parse',
+    argNames: ['text', 'nowMs'],
+  );
+
+  @override
+  Future<EntryActionResponse> crateApiEntrySchedule({
+    required String title,
+    required PlatformInt64 startEpochMs,
+    PlatformInt64? endEpochMs,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(nodeId, serializer);
-          sse_encode_opt_String(newParentId, serializer);
-          sse_encode_opt_box_autoadd_i_64(targetOrder, serializer);
+          sse_encode_String(title, serializer);
+          sse_encode_i_64(startEpochMs, serializer);
+          sse_encode_opt_box_autoadd_i_64(endEpochMs, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 28,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_workspace_action_response,
+          decodeSuccessData: sse_decode_entry_action_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiWorkspaceMoveNodeConstMeta,
-        argValues: [nodeId, newParentId, targetOrder],
+        constMeta: kCrateApiEntryScheduleConstMeta,
+        argValues: [title, startEpochMs, endEpochMs],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWorkspaceMoveNodeConstMeta => const TaskConstMeta(
-    debugName: 'workspace_move_node',
-    argNames: ['nodeId', 'newParentId', 'targetOrder'],
+  TaskConstMeta get kCrateApiEntryScheduleConstMeta => const TaskConstMeta(
+    debugName: 'entry_schedule',
+    argNames: ['title', 'startEpochMs', 'endEpochMs'],
   );
 
   @override
-  Future<WorkspaceActionResponse> crateApiWorkspaceRenameNode({
-    required String nodeId,
-    required String newName,
+  Future<EntrySearchResponse> crateApiEntrySearch({
+    required String text,
+    String? kind,
+    int? limit,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(nodeId, serializer);
-          sse_encode_String(newName, serializer);
+          sse_encode_String(text, serializer);
+          sse_encode_opt_String(kind, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 27,
+            funcId: 29,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_workspace_action_response,
+          decodeSuccessData: sse_decode_entry_search_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiWorkspaceRenameNodeConstMeta,
-        argValues: [nodeId, newName],
+        constMeta: kCrateApiEntrySearchConstMeta,
+        argValues: [text, kind, limit],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWorkspaceRenameNodeConstMeta =>
-      const TaskConstMeta(
-        debugName: 'workspace_rename_node',
-        argNames: ['nodeId', 'newName'],
-      );
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
+  TaskConstMeta get kCrateApiEntrySearchConstMeta => const TaskConstMeta(
+    debugName: 'entry_search',
+    argNames: ['text', 'kind', 'limit'],
+  );
 
-  @protected
-  AtomListItem dco_decode_atom_list_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return AtomListItem(
-      atomId: dco_decode_String(arr[0]),
-      kind: dco_decode_String(arr[1]),
-      content: dco_decode_String(arr[2]),
+  @override
+  EntrySearchSyntaxResponse crateApiEntrySearchCheck({required String text}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_entry_search_syntax_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiEntrySearchCheckConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEntrySearchCheckConstMeta =>
+      const TaskConstMeta(debugName: 'entry_search_check', argNames: ['text']);
+
+  @override
+  Future<EntrySearchPageResponse> crateApiEntrySearchPage({
+    required String text,
+    String? kind,
+    int? limit,
+    String? cursor,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_opt_String(kind, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_String(cursor, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_entry_search_page_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiEntrySearchPageConstMeta,
+        argValues: [text, kind, limit, cursor],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEntrySearchPageConstMeta => const TaskConstMeta(
+    debugName: 'entry_search_page',
+    argNames: ['text', 'kind', 'limit', 'cursor'],
+  );
+
+  @override
+  String crateApiInitLogging({required String level, required String logDir}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(level, serializer);
+          sse_encode_String(logDir, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitLoggingConstMeta,
+        argValues: [level, logDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitLoggingConstMeta => const TaskConstMeta(
+    debugName: 'init_logging',
+    argNames: ['level', 'logDir'],
+  );
+
+  @override
+  Future<LaunchPlanResponse> crateApiLinksLaunchPlan({
+    required String atomId,
+    int? maxItems,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_opt_box_autoadd_u_32(maxItems, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_launch_plan_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLinksLaunchPlanConstMeta,
+        argValues: [atomId, maxItems],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLinksLaunchPlanConstMeta => const TaskConstMeta(
+    debugName: 'links_launch_plan',
+    argNames: ['atomId', 'maxItems'],
+  );
+
+  @override
+  Future<ResourceLinksResponse> crateApiLinksList({required String atomId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_resource_links_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLinksListConstMeta,
+        argValues: [atomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLinksListConstMeta =>
+      const TaskConstMeta(debugName: 'links_list', argNames: ['atomId']);
+
+  @override
+  Future<ResourceLinksResponse> crateApiLinksSearch({
+    required String query,
+    int? limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_resource_links_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLinksSearchConstMeta,
+        argValues: [query, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLinksSearchConstMeta => const TaskConstMeta(
+    debugName: 'links_search',
+    argNames: ['query', 'limit'],
+  );
+
+  @override
+  LocalTimeWindowsResponse crateApiLocalTimeWindows({
+    required PlatformInt64 nowMs,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(nowMs, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_local_time_windows_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLocalTimeWindowsConstMeta,
+        argValues: [nowMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLocalTimeWindowsConstMeta =>
+      const TaskConstMeta(debugName: 'local_time_windows', argNames: ['nowMs']);
+
+  @override
+  LogDartEventResponse crateApiLogDartEvent({
+    required String level,
+    required String eventName,
+    required String module,
+    required String message,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(level, serializer);
+          sse_encode_String(eventName, serializer);
+          sse_encode_String(module, serializer);
+          sse_encode_String(message, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_log_dart_event_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLogDartEventConstMeta,
+        argValues: [level, eventName, module, message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLogDartEventConstMeta => const TaskConstMeta(
+    debugName: 'log_dart_event',
+    argNames: ['level', 'eventName', 'module', 'message'],
+  );
+
+  @override
+  Future<AttachmentsListResponse> crateApiNoteAttachments({
+    required String atomId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_attachments_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNoteAttachmentsConstMeta,
+        argValues: [atomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNoteAttachmentsConstMeta =>
+      const TaskConstMeta(debugName: 'note_attachments', argNames: ['atomId']);
+
+  @override
+  Future<NoteResponse> crateApiNoteCreate({required String content}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(content, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_note_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNoteCreateConstMeta,
+        argValues: [content],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNoteCreateConstMeta =>
+      const TaskConstMeta(debugName: 'note_create', argNames: ['content']);
+
+  @override
+  Future<NoteResponse> crateApiNoteGet({required String atomId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_note_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNoteGetConstMeta,
+        argValues: [atomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNoteGetConstMeta =>
+      const TaskConstMeta(debugName: 'note_get', argNames: ['atomId']);
+
+  @override
+  Future<NoteLinksResponse> crateApiNoteLinks({required String atomId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_note_links_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNoteLinksConstMeta,
+        argValues: [atomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNoteLinksConstMeta =>
+      const TaskConstMeta(debugName: 'note_links', argNames: ['atomId']);
+
+  @override
+  Future<NoteOutlineResponse> crateApiNoteOutline({required String atomId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_note_outline_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNoteOutlineConstMeta,
+        argValues: [atomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNoteOutlineConstMeta =>
+      const TaskConstMeta(debugName: 'note_outline', argNames: ['atomId']);
+
+  @override
+  Future<NotePropertiesResponse> crateApiNoteProperties({
+    required String atomId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_note_properties_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNotePropertiesConstMeta,
+        argValues: [atomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNotePropertiesConstMeta =>
+      const TaskConstMeta(debugName: 'note_properties', argNames: ['atomId']);
+
+  @override
+  Future<NoteRevisionDiffResponse> crateApiNoteRevisionDiff({
+    required String atomId,
+    required PlatformInt64 fromRevisionId,
+    PlatformInt64? toRevisionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_i_64(fromRevisionId, serializer);
+          sse_encode_opt_box_autoadd_i_64(toRevisionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_note_revision_diff_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNoteRevisionDiffConstMeta,
+        argValues: [atomId, fromRevisionId, toRevisionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNoteRevisionDiffConstMeta => const TaskConstMeta(
+    debugName: 'note_revision_diff',
+    argNames: ['atomId', 'fromRevisionId', 'toRevisionId'],
+  );
+
+  @override
+  Future<NoteResponse> crateApiNoteRevisionRestore({
+    required String atomId,
+    required PlatformInt64 revisionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_i_64(revisionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_note_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNoteRevisionRestoreConstMeta,
+        argValues: [atomId, revisionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNoteRevisionRestoreConstMeta =>
+      const TaskConstMeta(
+        debugName: 'note_revision_restore',
+        argNames: ['atomId', 'revisionId'],
+      );
+
+  @override
+  Future<NoteRevisionsListResponse> crateApiNoteRevisionsList({
+    required String atomId,
+    int? limit,
+    int? offset,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_note_revisions_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNoteRevisionsListConstMeta,
+        argValues: [atomId, limit, offset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNoteRevisionsListConstMeta => const TaskConstMeta(
+    debugName: 'note_revisions_list',
+    argNames: ['atomId', 'limit', 'offset'],
+  );
+
+  @override
+  Future<NoteResponse> crateApiNoteSetTags({
+    required String atomId,
+    required List<String> tags,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_list_String(tags, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_note_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNoteSetTagsConstMeta,
+        argValues: [atomId, tags],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNoteSetTagsConstMeta => const TaskConstMeta(
+    debugName: 'note_set_tags',
+    argNames: ['atomId', 'tags'],
+  );
+
+  @override
+  Future<NoteTitleResponse> crateApiNoteSetTitle({
+    required String atomId,
+    String? title,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_opt_String(title, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_note_title_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNoteSetTitleConstMeta,
+        argValues: [atomId, title],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNoteSetTitleConstMeta => const TaskConstMeta(
+    debugName: 'note_set_title',
+    argNames: ['atomId', 'title'],
+  );
+
+  @override
+  Future<NoteTagSourcesResponse> crateApiNoteTagSources({
+    required String atomId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_note_tag_sources_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNoteTagSourcesConstMeta,
+        argValues: [atomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNoteTagSourcesConstMeta =>
+      const TaskConstMeta(debugName: 'note_tag_sources', argNames: ['atomId']);
+
+  @override
+  Future<NoteTitleResponse> crateApiNoteTitle({required String atomId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_note_title_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNoteTitleConstMeta,
+        argValues: [atomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNoteTitleConstMeta =>
+      const TaskConstMeta(debugName: 'note_title', argNames: ['atomId']);
+
+  @override
+  Future<NoteResponse> crateApiNoteUpdate({
+    required String atomId,
+    required String content,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_String(content, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_note_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNoteUpdateConstMeta,
+        argValues: [atomId, content],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNoteUpdateConstMeta => const TaskConstMeta(
+    debugName: 'note_update',
+    argNames: ['atomId', 'content'],
+  );
+
+  @override
+  Future<NotesListResponse> crateApiNotesList({
+    String? tag,
+    int? limit,
+    int? offset,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(tag, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_notes_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNotesListConstMeta,
+        argValues: [tag, limit, offset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNotesListConstMeta => const TaskConstMeta(
+    debugName: 'notes_list',
+    argNames: ['tag', 'limit', 'offset'],
+  );
+
+  @override
+  Future<NotesListResponse> crateApiNotesListByTags({
+    required String tagExpr,
+    int? limit,
+    int? offset,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(tagExpr, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_notes_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiNotesListByTagsConstMeta,
+        argValues: [tagExpr, limit, offset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiNotesListByTagsConstMeta => const TaskConstMeta(
+    debugName: 'notes_list_by_tags',
+    argNames: ['tagExpr', 'limit', 'offset'],
+  );
+
+  @override
+  String crateApiPing() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPingConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPingConstMeta =>
+      const TaskConstMeta(debugName: 'ping', argNames: []);
+
+  @override
+  Future<RecurrenceResponse> crateApiRecurrenceDeleteOccurrence({
+    required String seriesId,
+    required PlatformInt64 occurrenceStartAt,
+    required String scope,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(seriesId, serializer);
+          sse_encode_i_64(occurrenceStartAt, serializer);
+          sse_encode_String(scope, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_recurrence_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRecurrenceDeleteOccurrenceConstMeta,
+        argValues: [seriesId, occurrenceStartAt, scope],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRecurrenceDeleteOccurrenceConstMeta =>
+      const TaskConstMeta(
+        debugName: 'recurrence_delete_occurrence',
+        argNames: ['seriesId', 'occurrenceStartAt', 'scope'],
+      );
+
+  @override
+  Future<RecurrenceResponse> crateApiRecurrenceEditOccurrence({
+    required String seriesId,
+    required PlatformInt64 occurrenceStartAt,
+    required String scope,
+    String? content,
+    PlatformInt64? startAt,
+    PlatformInt64? endAt,
+    String? status,
+    String? recurrenceRule,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(seriesId, serializer);
+          sse_encode_i_64(occurrenceStartAt, serializer);
+          sse_encode_String(scope, serializer);
+          sse_encode_opt_String(content, serializer);
+          sse_encode_opt_box_autoadd_i_64(startAt, serializer);
+          sse_encode_opt_box_autoadd_i_64(endAt, serializer);
+          sse_encode_opt_String(status, serializer);
+          sse_encode_opt_String(recurrenceRule, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_recurrence_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRecurrenceEditOccurrenceConstMeta,
+        argValues: [
+          seriesId,
+          occurrenceStartAt,
+          scope,
+          content,
+          startAt,
+          endAt,
+          status,
+          recurrenceRule,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRecurrenceEditOccurrenceConstMeta =>
+      const TaskConstMeta(
+        debugName: 'recurrence_edit_occurrence',
+        argNames: [
+          'seriesId',
+          'occurrenceStartAt',
+          'scope',
+          'content',
+          'startAt',
+          'endAt',
+          'status',
+          'recurrenceRule',
+        ],
+      );
+
+  @override
+  Future<ReminderResponse> crateApiReminderAck({
+    required String reminderId,
+    required PlatformInt64 fireAt,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(reminderId, serializer);
+          sse_encode_i_64(fireAt, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_reminder_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiReminderAckConstMeta,
+        argValues: [reminderId, fireAt],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReminderAckConstMeta => const TaskConstMeta(
+    debugName: 'reminder_ack',
+    argNames: ['reminderId', 'fireAt'],
+  );
+
+  @override
+  Future<ReminderResponse> crateApiReminderAdd({
+    required String atomId,
+    required String anchor,
+    PlatformInt64? remindAt,
+    PlatformInt64? offsetMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_String(anchor, serializer);
+          sse_encode_opt_box_autoadd_i_64(remindAt, serializer);
+          sse_encode_opt_box_autoadd_i_64(offsetMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_reminder_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiReminderAddConstMeta,
+        argValues: [atomId, anchor, remindAt, offsetMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReminderAddConstMeta => const TaskConstMeta(
+    debugName: 'reminder_add',
+    argNames: ['atomId', 'anchor', 'remindAt', 'offsetMs'],
+  );
+
+  @override
+  Future<ReminderResponse> crateApiReminderRemove({
+    required String reminderId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(reminderId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_reminder_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiReminderRemoveConstMeta,
+        argValues: [reminderId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReminderRemoveConstMeta => const TaskConstMeta(
+    debugName: 'reminder_remove',
+    argNames: ['reminderId'],
+  );
+
+  @override
+  Future<ReminderResponse> crateApiReminderSnooze({
+    required String reminderId,
+    required PlatformInt64 fireAt,
+    required PlatformInt64 untilMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(reminderId, serializer);
+          sse_encode_i_64(fireAt, serializer);
+          sse_encode_i_64(untilMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_reminder_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiReminderSnoozeConstMeta,
+        argValues: [reminderId, fireAt, untilMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReminderSnoozeConstMeta => const TaskConstMeta(
+    debugName: 'reminder_snooze',
+    argNames: ['reminderId', 'fireAt', 'untilMs'],
+  );
+
+  @override
+  Future<DueRemindersResponse> crateApiRemindersDue({
+    required PlatformInt64 nowMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(nowMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_due_reminders_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRemindersDueConstMeta,
+        argValues: [nowMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRemindersDueConstMeta =>
+      const TaskConstMeta(debugName: 'reminders_due', argNames: ['nowMs']);
+
+  @override
+  Future<SubtaskResponse> crateApiSubtaskSetParent({
+    required String atomId,
+    String? parentId,
+    int? targetIndex,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_opt_String(parentId, serializer);
+          sse_encode_opt_box_autoadd_u_32(targetIndex, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_subtask_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSubtaskSetParentConstMeta,
+        argValues: [atomId, parentId, targetIndex],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSubtaskSetParentConstMeta => const TaskConstMeta(
+    debugName: 'subtask_set_parent',
+    argNames: ['atomId', 'parentId', 'targetIndex'],
+  );
+
+  @override
+  Future<SubtaskResponse> crateApiSubtasksReorder({
+    required String parentId,
+    required List<String> orderedIds,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(parentId, serializer);
+          sse_encode_list_String(orderedIds, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_subtask_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSubtasksReorderConstMeta,
+        argValues: [parentId, orderedIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSubtasksReorderConstMeta => const TaskConstMeta(
+    debugName: 'subtasks_reorder',
+    argNames: ['parentId', 'orderedIds'],
+  );
+
+  @override
+  Future<TagEditResponse> crateApiTagDelete({required String name}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tag_edit_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTagDeleteConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTagDeleteConstMeta =>
+      const TaskConstMeta(debugName: 'tag_delete', argNames: ['name']);
+
+  @override
+  Future<TagEditResponse> crateApiTagRename({
+    required String from,
+    required String to,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(from, serializer);
+          sse_encode_String(to, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tag_edit_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTagRenameConstMeta,
+        argValues: [from, to],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTagRenameConstMeta =>
+      const TaskConstMeta(debugName: 'tag_rename', argNames: ['from', 'to']);
+
+  @override
+  Future<TagsListResponse> crateApiTagsList() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tags_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTagsListConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTagsListConstMeta =>
+      const TaskConstMeta(debugName: 'tags_list', argNames: []);
+
+  @override
+  Future<TagEditResponse> crateApiTagsMerge({
+    required List<String> sources,
+    required String target,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(sources, serializer);
+          sse_encode_String(target, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tag_edit_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTagsMergeConstMeta,
+        argValues: [sources, target],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTagsMergeConstMeta => const TaskConstMeta(
+    debugName: 'tags_merge',
+    argNames: ['sources', 'target'],
+  );
+
+  @override
+  Future<TagsUsageResponse> crateApiTagsUsage() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tags_usage_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTagsUsageConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTagsUsageConstMeta =>
+      const TaskConstMeta(debugName: 'tags_usage', argNames: []);
+
+  @override
+  Future<TaskDependencyResponse> crateApiTaskAddDependency({
+    required String atomId,
+    required String dependsOnId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_String(dependsOnId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_task_dependency_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTaskAddDependencyConstMeta,
+        argValues: [atomId, dependsOnId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTaskAddDependencyConstMeta => const TaskConstMeta(
+    debugName: 'task_add_dependency',
+    argNames: ['atomId', 'dependsOnId'],
+  );
+
+  @override
+  Future<TaskDependencyResponse> crateApiTaskDependencies({
+    required String atomId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_task_dependency_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTaskDependenciesConstMeta,
+        argValues: [atomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTaskDependenciesConstMeta =>
+      const TaskConstMeta(debugName: 'task_dependencies', argNames: ['atomId']);
+
+  @override
+  Future<SubtaskResponse> crateApiTaskPromoteChecklist({
+    required String atomId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_subtask_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTaskPromoteChecklistConstMeta,
+        argValues: [atomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTaskPromoteChecklistConstMeta =>
+      const TaskConstMeta(
+        debugName: 'task_promote_checklist',
+        argNames: ['atomId'],
+      );
+
+  @override
+  Future<TaskDependencyResponse> crateApiTaskRemoveDependency({
+    required String atomId,
+    required String dependsOnId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_String(dependsOnId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_task_dependency_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTaskRemoveDependencyConstMeta,
+        argValues: [atomId, dependsOnId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTaskRemoveDependencyConstMeta =>
+      const TaskConstMeta(
+        debugName: 'task_remove_dependency',
+        argNames: ['atomId', 'dependsOnId'],
+      );
+
+  @override
+  Future<SubtaskResponse> crateApiTaskSubtasks({required String atomId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_subtask_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTaskSubtasksConstMeta,
+        argValues: [atomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTaskSubtasksConstMeta =>
+      const TaskConstMeta(debugName: 'task_subtasks', argNames: ['atomId']);
+
+  @override
+  Future<AtomListResponse> crateApiTasksListInbox({int? limit, int? offset}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_atom_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTasksListInboxConstMeta,
+        argValues: [limit, offset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTasksListInboxConstMeta => const TaskConstMeta(
+    debugName: 'tasks_list_inbox',
+    argNames: ['limit', 'offset'],
+  );
+
+  @override
+  Future<AtomListResponse> crateApiTasksListInboxUnblocked({
+    int? limit,
+    int? offset,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_atom_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTasksListInboxUnblockedConstMeta,
+        argValues: [limit, offset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTasksListInboxUnblockedConstMeta =>
+      const TaskConstMeta(
+        debugName: 'tasks_list_inbox_unblocked',
+        argNames: ['limit', 'offset'],
+      );
+
+  @override
+  Future<AtomListResponse> crateApiTasksListOverdue({
+    required PlatformInt64 nowMs,
+    int? limit,
+    int? offset,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(nowMs, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_atom_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTasksListOverdueConstMeta,
+        argValues: [nowMs, limit, offset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTasksListOverdueConstMeta => const TaskConstMeta(
+    debugName: 'tasks_list_overdue',
+    argNames: ['nowMs', 'limit', 'offset'],
+  );
+
+  @override
+  Future<AtomListResponse> crateApiTasksListProjectOrder({
+    required String projectId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(projectId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_atom_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTasksListProjectOrderConstMeta,
+        argValues: [projectId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTasksListProjectOrderConstMeta =>
+      const TaskConstMeta(
+        debugName: 'tasks_list_project_order',
+        argNames: ['projectId'],
+      );
+
+  @override
+  Future<AtomListResponse> crateApiTasksListSubtasks({
+    required String parentId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(parentId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_atom_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTasksListSubtasksConstMeta,
+        argValues: [parentId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTasksListSubtasksConstMeta => const TaskConstMeta(
+    debugName: 'tasks_list_subtasks',
+    argNames: ['parentId'],
+  );
+
+  @override
+  Future<AtomListResponse> crateApiTasksListToday({
+    required PlatformInt64 bodMs,
+    required PlatformInt64 eodMs,
+    int? limit,
+    int? offset,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(bodMs, serializer);
+          sse_encode_i_64(eodMs, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_atom_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTasksListTodayConstMeta,
+        argValues: [bodMs, eodMs, limit, offset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTasksListTodayConstMeta => const TaskConstMeta(
+    debugName: 'tasks_list_today',
+    argNames: ['bodMs', 'eodMs', 'limit', 'offset'],
+  );
+
+  @override
+  Future<AtomListResponse> crateApiTasksListTodayLocal({
+    required PlatformInt64 nowMs,
+    required bool hideBlocked,
+    int? limit,
+    int? offset,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(nowMs, serializer);
+          sse_encode_bool(hideBlocked, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_atom_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTasksListTodayLocalConstMeta,
+        argValues: [nowMs, hideBlocked, limit, offset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTasksListTodayLocalConstMeta =>
+      const TaskConstMeta(
+        debugName: 'tasks_list_today_local',
+        argNames: ['nowMs', 'hideBlocked', 'limit', 'offset'],
+      );
+
+  @override
+  Future<AtomListResponse> crateApiTasksListTodayUnblocked({
+    required PlatformInt64 bodMs,
+    required PlatformInt64 eodMs,
+    int? limit,
+    int? offset,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(bodMs, serializer);
+          sse_encode_i_64(eodMs, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_atom_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTasksListTodayUnblockedConstMeta,
+        argValues: [bodMs, eodMs, limit, offset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTasksListTodayUnblockedConstMeta =>
+      const TaskConstMeta(
+        debugName: 'tasks_list_today_unblocked',
+        argNames: ['bodMs', 'eodMs', 'limit', 'offset'],
+      );
+
+  @override
+  Future<AtomListResponse> crateApiTasksListUpcoming({
+    required PlatformInt64 eodMs,
+    int? limit,
+    int? offset,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(eodMs, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_atom_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTasksListUpcomingConstMeta,
+        argValues: [eodMs, limit, offset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTasksListUpcomingConstMeta => const TaskConstMeta(
+    debugName: 'tasks_list_upcoming',
+    argNames: ['eodMs', 'limit', 'offset'],
+  );
+
+  @override
+  Future<AtomListResponse> crateApiTasksListUpcomingLocal({
+    required PlatformInt64 nowMs,
+    int? limit,
+    int? offset,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(nowMs, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_atom_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTasksListUpcomingLocalConstMeta,
+        argValues: [nowMs, limit, offset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTasksListUpcomingLocalConstMeta =>
+      const TaskConstMeta(
+        debugName: 'tasks_list_upcoming_local',
+        argNames: ['nowMs', 'limit', 'offset'],
+      );
+
+  @override
+  Future<TimeEntryResponse> crateApiTimeEntryAdd({
+    required String atomId,
+    required PlatformInt64 startedAt,
+    required PlatformInt64 endedAt,
+    String? note,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_i_64(startedAt, serializer);
+          sse_encode_i_64(endedAt, serializer);
+          sse_encode_opt_String(note, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_time_entry_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTimeEntryAddConstMeta,
+        argValues: [atomId, startedAt, endedAt, note],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTimeEntryAddConstMeta => const TaskConstMeta(
+    debugName: 'time_entry_add',
+    argNames: ['atomId', 'startedAt', 'endedAt', 'note'],
+  );
+
+  @override
+  Future<TimeEntryResponse> crateApiTimeEntryDelete({required String entryId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(entryId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_time_entry_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTimeEntryDeleteConstMeta,
+        argValues: [entryId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTimeEntryDeleteConstMeta => const TaskConstMeta(
+    debugName: 'time_entry_delete',
+    argNames: ['entryId'],
+  );
+
+  @override
+  Future<TimeEntryResponse> crateApiTimeEntryUpdate({
+    required String entryId,
+    String? atomId,
+    PlatformInt64? startedAt,
+    PlatformInt64? endedAt,
+    String? note,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(entryId, serializer);
+          sse_encode_opt_String(atomId, serializer);
+          sse_encode_opt_box_autoadd_i_64(startedAt, serializer);
+          sse_encode_opt_box_autoadd_i_64(endedAt, serializer);
+          sse_encode_opt_String(note, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 86,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_time_entry_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTimeEntryUpdateConstMeta,
+        argValues: [entryId, atomId, startedAt, endedAt, note],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTimeEntryUpdateConstMeta => const TaskConstMeta(
+    debugName: 'time_entry_update',
+    argNames: ['entryId', 'atomId', 'startedAt', 'endedAt', 'note'],
+  );
+
+  @override
+  Future<TimeReportResponse> crateApiTimeReport({
+    required String startDate,
+    required String endDate,
+    required PlatformInt64 nowMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(startDate, serializer);
+          sse_encode_String(endDate, serializer);
+          sse_encode_i_64(nowMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_time_report_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTimeReportConstMeta,
+        argValues: [startDate, endDate, nowMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTimeReportConstMeta => const TaskConstMeta(
+    debugName: 'time_report',
+    argNames: ['startDate', 'endDate', 'nowMs'],
+  );
+
+  @override
+  Future<TimeEntryResponse> crateApiTimeTimerRunning() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 88,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_time_entry_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTimeTimerRunningConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTimeTimerRunningConstMeta =>
+      const TaskConstMeta(debugName: 'time_timer_running', argNames: []);
+
+  @override
+  Future<TimeEntryResponse> crateApiTimeTimerStart({
+    required String atomId,
+    required PlatformInt64 nowMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_i_64(nowMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 89,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_time_entry_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTimeTimerStartConstMeta,
+        argValues: [atomId, nowMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTimeTimerStartConstMeta => const TaskConstMeta(
+    debugName: 'time_timer_start',
+    argNames: ['atomId', 'nowMs'],
+  );
+
+  @override
+  Future<TimeEntryResponse> crateApiTimeTimerStop({
+    required PlatformInt64 nowMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(nowMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 90,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_time_entry_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTimeTimerStopConstMeta,
+        argValues: [nowMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTimeTimerStopConstMeta =>
+      const TaskConstMeta(debugName: 'time_timer_stop', argNames: ['nowMs']);
+
+  @override
+  Future<TrashListResponse> crateApiTrashList({
+    String? kind,
+    int? limit,
+    int? offset,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(kind, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 91,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_trash_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTrashListConstMeta,
+        argValues: [kind, limit, offset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrashListConstMeta => const TaskConstMeta(
+    debugName: 'trash_list',
+    argNames: ['kind', 'limit', 'offset'],
+  );
+
+  @override
+  Future<TrashPurgeResponse> crateApiTrashPurge({required String atomId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 92,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_trash_purge_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTrashPurgeConstMeta,
+        argValues: [atomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrashPurgeConstMeta =>
+      const TaskConstMeta(debugName: 'trash_purge', argNames: ['atomId']);
+
+  @override
+  Future<TrashPurgeResponse> crateApiTrashPurgeExpired({int? retentionDays}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_u_32(retentionDays, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 93,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_trash_purge_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTrashPurgeExpiredConstMeta,
+        argValues: [retentionDays],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrashPurgeExpiredConstMeta => const TaskConstMeta(
+    debugName: 'trash_purge_expired',
+    argNames: ['retentionDays'],
+  );
+
+  @override
+  Future<TrashRestoreResponse> crateApiTrashRestore({required String atomId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_trash_restore_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTrashRestoreConstMeta,
+        argValues: [atomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrashRestoreConstMeta =>
+      const TaskConstMeta(debugName: 'trash_restore', argNames: ['atomId']);
+
+  @override
+  Future<WorkspaceNodeResponse> crateApiWorkspaceCreateFolder({
+    String? parentNodeId,
+    required String name,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(parentNodeId, serializer);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 95,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_workspace_node_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkspaceCreateFolderConstMeta,
+        argValues: [parentNodeId, name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkspaceCreateFolderConstMeta =>
+      const TaskConstMeta(
+        debugName: 'workspace_create_folder',
+        argNames: ['parentNodeId', 'name'],
+      );
+
+  @override
+  Future<WorkspaceNodeResponse> crateApiWorkspaceCreateNoteRef({
+    String? parentNodeId,
+    required String atomId,
+    String? displayName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(parentNodeId, serializer);
+          sse_encode_String(atomId, serializer);
+          sse_encode_opt_String(displayName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 96,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_workspace_node_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkspaceCreateNoteRefConstMeta,
+        argValues: [parentNodeId, atomId, displayName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkspaceCreateNoteRefConstMeta =>
+      const TaskConstMeta(
+        debugName: 'workspace_create_note_ref',
+        argNames: ['parentNodeId', 'atomId', 'displayName'],
+      );
+
+  @override
+  Future<WorkspaceActionResponse> crateApiWorkspaceDeleteFolder({
+    required String nodeId,
+    required String mode,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(nodeId, serializer);
+          sse_encode_String(mode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 97,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_workspace_action_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkspaceDeleteFolderConstMeta,
+        argValues: [nodeId, mode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkspaceDeleteFolderConstMeta =>
+      const TaskConstMeta(
+        debugName: 'workspace_delete_folder',
+        argNames: ['nodeId', 'mode'],
+      );
+
+  @override
+  Future<WorkspaceListChildrenResponse> crateApiWorkspaceListChildren({
+    String? parentNodeId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(parentNodeId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_workspace_list_children_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkspaceListChildrenConstMeta,
+        argValues: [parentNodeId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkspaceListChildrenConstMeta =>
+      const TaskConstMeta(
+        debugName: 'workspace_list_children',
+        argNames: ['parentNodeId'],
+      );
+
+  @override
+  Future<WorkspaceActionResponse> crateApiWorkspaceMoveNode({
+    required String nodeId,
+    String? newParentId,
+    PlatformInt64? targetOrder,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(nodeId, serializer);
+          sse_encode_opt_String(newParentId, serializer);
+          sse_encode_opt_box_autoadd_i_64(targetOrder, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_workspace_action_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkspaceMoveNodeConstMeta,
+        argValues: [nodeId, newParentId, targetOrder],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkspaceMoveNodeConstMeta => const TaskConstMeta(
+    debugName: 'workspace_move_node',
+    argNames: ['nodeId', 'newParentId', 'targetOrder'],
+  );
+
+  @override
+  Future<WorkspaceActionResponse> crateApiWorkspaceRenameNode({
+    required String nodeId,
+    required String newName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(nodeId, serializer);
+          sse_encode_String(newName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_workspace_action_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkspaceRenameNodeConstMeta,
+        argValues: [nodeId, newName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkspaceRenameNodeConstMeta =>
+      const TaskConstMeta(
+        debugName: 'workspace_rename_node',
+        argNames: ['nodeId', 'newName'],
+      );
+
+  @override
+  Future<WorkspaceRepairResponse> crateApiWorkspaceRepairNoteTitles() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_workspace_repair_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkspaceRepairNoteTitlesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkspaceRepairNoteTitlesConstMeta =>
+      const TaskConstMeta(
+        debugName: 'workspace_repair_note_titles',
+        argNames: [],
+      );
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AtomConvertResponse dco_decode_atom_convert_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return AtomConvertResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      atomId: dco_decode_opt_String(arr[3]),
+      kind: dco_decode_opt_String(arr[4]),
+      taskStatus: dco_decode_opt_String(arr[5]),
+      startAt: dco_decode_opt_box_autoadd_i_64(arr[6]),
+      endAt: dco_decode_opt_box_autoadd_i_64(arr[7]),
+    );
+  }
+
+  @protected
+  AtomListItem dco_decode_atom_list_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return AtomListItem(
+      atomId: dco_decode_String(arr[0]),
+      kind: dco_decode_String(arr[1]),
+      content: dco_decode_String(arr[2]),
+      previewText: dco_decode_opt_String(arr[3]),
+      previewImage: dco_decode_opt_String(arr[4]),
+      tags: dco_decode_list_String(arr[5]),
+      startAt: dco_decode_opt_box_autoadd_i_64(arr[6]),
+      endAt: dco_decode_opt_box_autoadd_i_64(arr[7]),
+      taskStatus: dco_decode_opt_String(arr[8]),
+      updatedAt: dco_decode_i_64(arr[9]),
+    );
+  }
+
+  @protected
+  AtomListResponse dco_decode_atom_list_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return AtomListResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_atom_list_item(arr[3]),
+      appliedLimit: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  AtomScheduleResponse dco_decode_atom_schedule_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return AtomScheduleResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      atomId: dco_decode_opt_String(arr[3]),
+      allDay: dco_decode_bool(arr[4]),
+      startAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      endAt: dco_decode_opt_box_autoadd_i_64(arr[6]),
+      startDate: dco_decode_opt_String(arr[7]),
+      endDate: dco_decode_opt_String(arr[8]),
+    );
+  }
+
+  @protected
+  AtomTaskMetaResponse dco_decode_atom_task_meta_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return AtomTaskMetaResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      atomId: dco_decode_opt_String(arr[3]),
+      priority: dco_decode_opt_String(arr[4]),
+      dueAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  AttachmentGcResponse dco_decode_attachment_gc_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return AttachmentGcResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      removedRows: dco_decode_u_32(arr[3]),
+      removedBlobs: dco_decode_u_32(arr[4]),
+      freedBytes: dco_decode_u_64(arr[5]),
+    );
+  }
+
+  @protected
+  AttachmentImportResponse dco_decode_attachment_import_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return AttachmentImportResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      attachment: dco_decode_opt_box_autoadd_attachment_item(arr[3]),
+      deduplicated: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  AttachmentItem dco_decode_attachment_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return AttachmentItem(
+      sha256: dco_decode_String(arr[0]),
+      uri: dco_decode_String(arr[1]),
+      byteSize: dco_decode_i_64(arr[2]),
+      mimeType: dco_decode_opt_String(arr[3]),
+      originalName: dco_decode_opt_String(arr[4]),
+      createdAt: dco_decode_i_64(arr[5]),
+      refCount: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  AttachmentResolveResponse dco_decode_attachment_resolve_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return AttachmentResolveResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      attachment: dco_decode_opt_box_autoadd_attachment_item(arr[3]),
+      filePath: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  AttachmentsListResponse dco_decode_attachments_list_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AttachmentsListResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_attachment_item(arr[3]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AttachmentItem dco_decode_box_autoadd_attachment_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_attachment_item(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  NoteItem dco_decode_box_autoadd_note_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_note_item(raw);
+  }
+
+  @protected
+  ReminderItem dco_decode_box_autoadd_reminder_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_reminder_item(raw);
+  }
+
+  @protected
+  TimeEntryItem dco_decode_box_autoadd_time_entry_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_time_entry_item(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  WorkspaceNodeItem dco_decode_box_autoadd_workspace_node_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_workspace_node_item(raw);
+  }
+
+  @protected
+  CalendarConflictItem dco_decode_calendar_conflict_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return CalendarConflictItem(
+      firstAtomId: dco_decode_String(arr[0]),
+      firstOccurrenceStartAt: dco_decode_opt_box_autoadd_i_64(arr[1]),
+      secondAtomId: dco_decode_String(arr[2]),
+      secondOccurrenceStartAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      overlapStartMs: dco_decode_i_64(arr[4]),
+      overlapEndMs: dco_decode_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  CalendarConflictsResponse dco_decode_calendar_conflicts_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CalendarConflictsResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_calendar_conflict_item(arr[3]),
+    );
+  }
+
+  @protected
+  CalendarDayItem dco_decode_calendar_day_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CalendarDayItem(
+      date: dco_decode_String(arr[0]),
+      startMs: dco_decode_i_64(arr[1]),
+      endMs: dco_decode_i_64(arr[2]),
+      allDay: dco_decode_list_calendar_entry_item(arr[3]),
+      timed: dco_decode_list_calendar_entry_item(arr[4]),
+    );
+  }
+
+  @protected
+  CalendarDaysResponse dco_decode_calendar_days_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CalendarDaysResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      days: dco_decode_list_calendar_day_item(arr[3]),
+    );
+  }
+
+  @protected
+  CalendarEntryItem dco_decode_calendar_entry_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 15)
+      throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+    return CalendarEntryItem(
+      atomId: dco_decode_String(arr[0]),
+      kind: dco_decode_String(arr[1]),
+      content: dco_decode_String(arr[2]),
+      previewText: dco_decode_opt_String(arr[3]),
+      taskStatus: dco_decode_opt_String(arr[4]),
+      allDay: dco_decode_bool(arr[5]),
+      startAt: dco_decode_i_64(arr[6]),
+      endAt: dco_decode_i_64(arr[7]),
+      occurrenceStartAt: dco_decode_opt_box_autoadd_i_64(arr[8]),
+      visibleStartMs: dco_decode_i_64(arr[9]),
+      visibleEndMs: dco_decode_i_64(arr[10]),
+      continuesBefore: dco_decode_bool(arr[11]),
+      continuesAfter: dco_decode_bool(arr[12]),
+      column: dco_decode_u_32(arr[13]),
+      columnCount: dco_decode_u_32(arr[14]),
+    );
+  }
+
+  @protected
+  CalendarFreeSlotItem dco_decode_calendar_free_slot_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CalendarFreeSlotItem(
+      startMs: dco_decode_i_64(arr[0]),
+      endMs: dco_decode_i_64(arr[1]),
+    );
+  }
+
+  @protected
+  CalendarFreeSlotsResponse dco_decode_calendar_free_slots_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CalendarFreeSlotsResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      slots: dco_decode_list_calendar_free_slot_item(arr[3]),
+    );
+  }
+
+  @protected
+  DueReminderItem dco_decode_due_reminder_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return DueReminderItem(
+      reminder: dco_decode_reminder_item(arr[0]),
+      atomKind: dco_decode_String(arr[1]),
+      content: dco_decode_String(arr[2]),
+      previewText: dco_decode_opt_String(arr[3]),
+      fireAt: dco_decode_i_64(arr[4]),
+      occurrenceStartAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      snoozed: dco_decode_bool(arr[6]),
+    );
+  }
+
+  @protected
+  DueRemindersResponse dco_decode_due_reminders_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DueRemindersResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_due_reminder_item(arr[3]),
+      nextWakeupAt: dco_decode_opt_box_autoadd_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  EntryActionResponse dco_decode_entry_action_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return EntryActionResponse(
+      ok: dco_decode_bool(arr[0]),
+      atomId: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  EntryQuickAddResponse dco_decode_entry_quick_add_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return EntryQuickAddResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      kind: dco_decode_String(arr[3]),
+      title: dco_decode_String(arr[4]),
+      startAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      endAt: dco_decode_opt_box_autoadd_i_64(arr[6]),
+      allDay: dco_decode_bool(arr[7]),
+      tags: dco_decode_list_String(arr[8]),
+      priority: dco_decode_opt_String(arr[9]),
+      tokens: dco_decode_list_entry_quick_add_token(arr[10]),
+    );
+  }
+
+  @protected
+  EntryQuickAddToken dco_decode_entry_quick_add_token(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return EntryQuickAddToken(
+      kind: dco_decode_String(arr[0]),
+      start: dco_decode_u_32(arr[1]),
+      end: dco_decode_u_32(arr[2]),
+      charStart: dco_decode_u_32(arr[3]),
+      charEnd: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  EntrySearchHighlight dco_decode_entry_search_highlight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return EntrySearchHighlight(
+      start: dco_decode_u_32(arr[0]),
+      end: dco_decode_u_32(arr[1]),
+      charStart: dco_decode_u_32(arr[2]),
+      charEnd: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  EntrySearchHitItem dco_decode_entry_search_hit_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return EntrySearchHitItem(
+      atomId: dco_decode_String(arr[0]),
+      kind: dco_decode_String(arr[1]),
+      snippet: dco_decode_String(arr[2]),
+      highlights: dco_decode_list_entry_search_highlight(arr[3]),
+      bm25: dco_decode_opt_box_autoadd_f_64(arr[4]),
+      score: dco_decode_f_64(arr[5]),
+    );
+  }
+
+  @protected
+  EntrySearchItem dco_decode_entry_search_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return EntrySearchItem(
+      atomId: dco_decode_String(arr[0]),
+      kind: dco_decode_String(arr[1]),
+      snippet: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  EntrySearchPageResponse dco_decode_entry_search_page_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return EntrySearchPageResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_entry_search_hit_item(arr[3]),
+      nextCursor: dco_decode_opt_String(arr[4]),
+      appliedLimit: dco_decode_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  EntrySearchResponse dco_decode_entry_search_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return EntrySearchResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      items: dco_decode_list_entry_search_item(arr[2]),
+      message: dco_decode_String(arr[3]),
+      appliedLimit: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  EntrySearchSyntaxResponse dco_decode_entry_search_syntax_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return EntrySearchSyntaxResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      syntaxError: dco_decode_opt_String(arr[2]),
+      errorStart: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      errorEnd: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      filterCount: dco_decode_u_32(arr[5]),
+      message: dco_decode_String(arr[6]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  LaunchPlanItem dco_decode_launch_plan_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LaunchPlanItem(
+      linkId: dco_decode_i_64(arr[0]),
+      kind: dco_decode_String(arr[1]),
+      target: dco_decode_String(arr[2]),
+      label: dco_decode_opt_String(arr[3]),
+      localPath: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  LaunchPlanResponse dco_decode_launch_plan_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return LaunchPlanResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_launch_plan_item(arr[3]),
+      rejected: dco_decode_list_launch_rejection_item(arr[4]),
+      cappedCount: dco_decode_u_32(arr[5]),
+      appliedMaxItems: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  LaunchRejectionItem dco_decode_launch_rejection_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LaunchRejectionItem(
+      linkId: dco_decode_i_64(arr[0]),
+      target: dco_decode_String(arr[1]),
+      reason: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<AtomListItem> dco_decode_list_atom_list_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_atom_list_item).toList();
+  }
+
+  @protected
+  List<AttachmentItem> dco_decode_list_attachment_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_attachment_item).toList();
+  }
+
+  @protected
+  List<CalendarConflictItem> dco_decode_list_calendar_conflict_item(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_calendar_conflict_item)
+        .toList();
+  }
+
+  @protected
+  List<CalendarDayItem> dco_decode_list_calendar_day_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_calendar_day_item).toList();
+  }
+
+  @protected
+  List<CalendarEntryItem> dco_decode_list_calendar_entry_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_calendar_entry_item).toList();
+  }
+
+  @protected
+  List<CalendarFreeSlotItem> dco_decode_list_calendar_free_slot_item(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_calendar_free_slot_item)
+        .toList();
+  }
+
+  @protected
+  List<DueReminderItem> dco_decode_list_due_reminder_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_due_reminder_item).toList();
+  }
+
+  @protected
+  List<EntryQuickAddToken> dco_decode_list_entry_quick_add_token(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_entry_quick_add_token)
+        .toList();
+  }
+
+  @protected
+  List<EntrySearchHighlight> dco_decode_list_entry_search_highlight(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_entry_search_highlight)
+        .toList();
+  }
+
+  @protected
+  List<EntrySearchHitItem> dco_decode_list_entry_search_hit_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_entry_search_hit_item)
+        .toList();
+  }
+
+  @protected
+  List<EntrySearchItem> dco_decode_list_entry_search_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_entry_search_item).toList();
+  }
+
+  @protected
+  List<LaunchPlanItem> dco_decode_list_launch_plan_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_launch_plan_item).toList();
+  }
+
+  @protected
+  List<LaunchRejectionItem> dco_decode_list_launch_rejection_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_launch_rejection_item)
+        .toList();
+  }
+
+  @protected
+  List<NoteDiffLineItem> dco_decode_list_note_diff_line_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_note_diff_line_item).toList();
+  }
+
+  @protected
+  List<NoteItem> dco_decode_list_note_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_note_item).toList();
+  }
+
+  @protected
+  List<NoteLinkItem> dco_decode_list_note_link_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_note_link_item).toList();
+  }
+
+  @protected
+  List<NoteOutlineHeading> dco_decode_list_note_outline_heading(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_note_outline_heading).toList();
+  }
+
+  @protected
+  List<NotePropertyItem> dco_decode_list_note_property_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_note_property_item).toList();
+  }
+
+  @protected
+  List<NoteRevisionItem> dco_decode_list_note_revision_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_note_revision_item).toList();
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<ReminderItem> dco_decode_list_reminder_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_reminder_item).toList();
+  }
+
+  @protected
+  List<ResourceLinkItem> dco_decode_list_resource_link_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_resource_link_item).toList();
+  }
+
+  @protected
+  List<TagUsageItem> dco_decode_list_tag_usage_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_tag_usage_item).toList();
+  }
+
+  @protected
+  List<TimeDayTotalItem> dco_decode_list_time_day_total_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_time_day_total_item).toList();
+  }
+
+  @protected
+  List<TimeEntryItem> dco_decode_list_time_entry_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_time_entry_item).toList();
+  }
+
+  @protected
+  List<TimeFolderTotalItem> dco_decode_list_time_folder_total_item(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_time_folder_total_item)
+        .toList();
+  }
+
+  @protected
+  List<TimeTagTotalItem> dco_decode_list_time_tag_total_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_time_tag_total_item).toList();
+  }
+
+  @protected
+  List<TrashListItem> dco_decode_list_trash_list_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_trash_list_item).toList();
+  }
+
+  @protected
+  List<WorkspaceNodeItem> dco_decode_list_workspace_node_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_workspace_node_item).toList();
+  }
+
+  @protected
+  LocalTimeWindowsResponse dco_decode_local_time_windows_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return LocalTimeWindowsResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      timeZone: dco_decode_String(arr[3]),
+      weekStart: dco_decode_String(arr[4]),
+      dayStartMs: dco_decode_i_64(arr[5]),
+      dayEndMs: dco_decode_i_64(arr[6]),
+      weekStartMs: dco_decode_i_64(arr[7]),
+      weekEndMs: dco_decode_i_64(arr[8]),
+      monthStartMs: dco_decode_i_64(arr[9]),
+      monthEndMs: dco_decode_i_64(arr[10]),
+    );
+  }
+
+  @protected
+  LogDartEventResponse dco_decode_log_dart_event_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LogDartEventResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  NoteDiffLineItem dco_decode_note_diff_line_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return NoteDiffLineItem(
+      kind: dco_decode_String(arr[0]),
+      text: dco_decode_String(arr[1]),
+      oldLine: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      newLine: dco_decode_opt_box_autoadd_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  NoteItem dco_decode_note_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return NoteItem(
+      atomId: dco_decode_String(arr[0]),
+      content: dco_decode_String(arr[1]),
+      previewText: dco_decode_opt_String(arr[2]),
+      previewImage: dco_decode_opt_String(arr[3]),
+      updatedAt: dco_decode_i_64(arr[4]),
+      tags: dco_decode_list_String(arr[5]),
+    );
+  }
+
+  @protected
+  NoteLinkItem dco_decode_note_link_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return NoteLinkItem(
+      sourceAtomId: dco_decode_String(arr[0]),
+      target: dco_decode_String(arr[1]),
+      label: dco_decode_opt_String(arr[2]),
+      kind: dco_decode_String(arr[3]),
+      targetAtomId: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  NoteLinksResponse dco_decode_note_links_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return NoteLinksResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      outgoing: dco_decode_list_note_link_item(arr[3]),
+      backlinks: dco_decode_list_note_link_item(arr[4]),
+    );
+  }
+
+  @protected
+  NoteOutlineHeading dco_decode_note_outline_heading(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return NoteOutlineHeading(
+      level: dco_decode_u_32(arr[0]),
+      depth: dco_decode_u_32(arr[1]),
+      parentIndex: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      text: dco_decode_String(arr[3]),
+      byteStart: dco_decode_u_32(arr[4]),
+      byteEnd: dco_decode_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  NoteOutlineResponse dco_decode_note_outline_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return NoteOutlineResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      headings: dco_decode_list_note_outline_heading(arr[3]),
+      wordCount: dco_decode_u_32(arr[4]),
+      charCount: dco_decode_u_32(arr[5]),
+      checklistDone: dco_decode_u_32(arr[6]),
+      checklistTotal: dco_decode_u_32(arr[7]),
+    );
+  }
+
+  @protected
+  NotePropertiesResponse dco_decode_note_properties_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return NotePropertiesResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_note_property_item(arr[3]),
+    );
+  }
+
+  @protected
+  NotePropertyItem dco_decode_note_property_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return NotePropertyItem(
+      key: dco_decode_String(arr[0]),
+      valueType: dco_decode_String(arr[1]),
+      text: dco_decode_opt_String(arr[2]),
+      number: dco_decode_opt_box_autoadd_f_64(arr[3]),
+      items: dco_decode_list_String(arr[4]),
+    );
+  }
+
+  @protected
+  NoteResponse dco_decode_note_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return NoteResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      note: dco_decode_opt_box_autoadd_note_item(arr[3]),
+    );
+  }
+
+  @protected
+  NoteRevisionDiffResponse dco_decode_note_revision_diff_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return NoteRevisionDiffResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      lines: dco_decode_list_note_diff_line_item(arr[3]),
+    );
+  }
+
+  @protected
+  NoteRevisionItem dco_decode_note_revision_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return NoteRevisionItem(
+      revisionId: dco_decode_i_64(arr[0]),
+      atomId: dco_decode_String(arr[1]),
+      content: dco_decode_String(arr[2]),
+      previewText: dco_decode_opt_String(arr[3]),
+      sourceUpdatedAt: dco_decode_i_64(arr[4]),
+      capturedAt: dco_decode_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  NoteRevisionsListResponse dco_decode_note_revisions_list_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return NoteRevisionsListResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_note_revision_item(arr[3]),
+      appliedLimit: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  NoteTagSourcesResponse dco_decode_note_tag_sources_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return NoteTagSourcesResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      manualTags: dco_decode_list_String(arr[3]),
+      inlineTags: dco_decode_list_String(arr[4]),
+    );
+  }
+
+  @protected
+  NoteTitleResponse dco_decode_note_title_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return NoteTitleResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      title: dco_decode_opt_String(arr[3]),
+      derivedTitle: dco_decode_opt_String(arr[4]),
+      titleOverride: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  NotesListResponse dco_decode_notes_list_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return NotesListResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_note_item(arr[3]),
+      appliedLimit: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  AttachmentItem? dco_decode_opt_box_autoadd_attachment_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_attachment_item(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  NoteItem? dco_decode_opt_box_autoadd_note_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_note_item(raw);
+  }
+
+  @protected
+  ReminderItem? dco_decode_opt_box_autoadd_reminder_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_reminder_item(raw);
+  }
+
+  @protected
+  TimeEntryItem? dco_decode_opt_box_autoadd_time_entry_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_time_entry_item(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  WorkspaceNodeItem? dco_decode_opt_box_autoadd_workspace_node_item(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_workspace_node_item(raw);
+  }
+
+  @protected
+  RecurrenceResponse dco_decode_recurrence_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RecurrenceResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      atomId: dco_decode_opt_String(arr[3]),
+      recurrenceRule: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  ReminderItem dco_decode_reminder_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return ReminderItem(
+      reminderId: dco_decode_String(arr[0]),
+      atomId: dco_decode_String(arr[1]),
+      anchor: dco_decode_String(arr[2]),
+      remindAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      offsetMs: dco_decode_opt_box_autoadd_i_64(arr[4]),
+      ackedThrough: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      snoozedUntil: dco_decode_opt_box_autoadd_i_64(arr[6]),
+      nextFireAt: dco_decode_opt_box_autoadd_i_64(arr[7]),
+      createdAt: dco_decode_i_64(arr[8]),
+    );
+  }
+
+  @protected
+  ReminderListResponse dco_decode_reminder_list_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ReminderListResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_reminder_item(arr[3]),
+    );
+  }
+
+  @protected
+  ReminderResponse dco_decode_reminder_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ReminderResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      reminder: dco_decode_opt_box_autoadd_reminder_item(arr[3]),
+    );
+  }
+
+  @protected
+  ResourceLinkItem dco_decode_resource_link_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ResourceLinkItem(
+      linkId: dco_decode_i_64(arr[0]),
+      atomId: dco_decode_String(arr[1]),
+      target: dco_decode_String(arr[2]),
+      scheme: dco_decode_String(arr[3]),
+      label: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  ResourceLinksResponse dco_decode_resource_links_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ResourceLinksResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_resource_link_item(arr[3]),
+    );
+  }
+
+  @protected
+  SubtaskResponse dco_decode_subtask_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return SubtaskResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      atomId: dco_decode_opt_String(arr[3]),
+      parentId: dco_decode_opt_String(arr[4]),
+      subtaskIds: dco_decode_list_String(arr[5]),
+      done: dco_decode_u_32(arr[6]),
+      total: dco_decode_u_32(arr[7]),
+    );
+  }
+
+  @protected
+  TagEditResponse dco_decode_tag_edit_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TagEditResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      affectedAtoms: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  TagUsageItem dco_decode_tag_usage_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TagUsageItem(
+      name: dco_decode_String(arr[0]),
+      directCount: dco_decode_u_32(arr[1]),
+      totalCount: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  TagsListResponse dco_decode_tags_list_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TagsListResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      tags: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  TagsUsageResponse dco_decode_tags_usage_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TagsUsageResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_tag_usage_item(arr[3]),
+    );
+  }
+
+  @protected
+  TaskDependencyResponse dco_decode_task_dependency_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return TaskDependencyResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      atomId: dco_decode_opt_String(arr[3]),
+      blocked: dco_decode_bool(arr[4]),
+      prerequisiteIds: dco_decode_list_String(arr[5]),
+      dependentIds: dco_decode_list_String(arr[6]),
+    );
+  }
+
+  @protected
+  TimeDayTotalItem dco_decode_time_day_total_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TimeDayTotalItem(
+      date: dco_decode_String(arr[0]),
+      totalMs: dco_decode_i_64(arr[1]),
+    );
+  }
+
+  @protected
+  TimeEntryItem dco_decode_time_entry_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return TimeEntryItem(
+      entryId: dco_decode_String(arr[0]),
+      atomId: dco_decode_String(arr[1]),
+      deviceId: dco_decode_String(arr[2]),
+      startedAt: dco_decode_i_64(arr[3]),
+      endedAt: dco_decode_opt_box_autoadd_i_64(arr[4]),
+      note: dco_decode_opt_String(arr[5]),
+      running: dco_decode_bool(arr[6]),
+      durationMs: dco_decode_i_64(arr[7]),
+    );
+  }
+
+  @protected
+  TimeEntryListResponse dco_decode_time_entry_list_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TimeEntryListResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_time_entry_item(arr[3]),
+      totalMs: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  TimeEntryResponse dco_decode_time_entry_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TimeEntryResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      entry: dco_decode_opt_box_autoadd_time_entry_item(arr[3]),
+    );
+  }
+
+  @protected
+  TimeFolderTotalItem dco_decode_time_folder_total_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TimeFolderTotalItem(
+      folderId: dco_decode_String(arr[0]),
+      path: dco_decode_String(arr[1]),
+      totalMs: dco_decode_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  TimeReportResponse dco_decode_time_report_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return TimeReportResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      days: dco_decode_list_time_day_total_item(arr[3]),
+      tags: dco_decode_list_time_tag_total_item(arr[4]),
+      folders: dco_decode_list_time_folder_total_item(arr[5]),
+    );
+  }
+
+  @protected
+  TimeTagTotalItem dco_decode_time_tag_total_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TimeTagTotalItem(
+      tag: dco_decode_String(arr[0]),
+      totalMs: dco_decode_i_64(arr[1]),
+    );
+  }
+
+  @protected
+  TrashListItem dco_decode_trash_list_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return TrashListItem(
+      atomId: dco_decode_String(arr[0]),
+      kind: dco_decode_String(arr[1]),
+      content: dco_decode_String(arr[2]),
       previewText: dco_decode_opt_String(arr[3]),
-      previewImage: dco_decode_opt_String(arr[4]),
-      tags: dco_decode_list_String(arr[5]),
-      startAt: dco_decode_opt_box_autoadd_i_64(arr[6]),
-      endAt: dco_decode_opt_box_autoadd_i_64(arr[7]),
-      taskStatus: dco_decode_opt_String(arr[8]),
-      updatedAt: dco_decode_i_64(arr[9]),
+      deletedAt: dco_decode_i_64(arr[4]),
+      syncPending: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  TrashListResponse dco_decode_trash_list_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TrashListResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_trash_list_item(arr[3]),
+      appliedLimit: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  TrashPurgeResponse dco_decode_trash_purge_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TrashPurgeResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      purged: dco_decode_u_32(arr[3]),
+      keptForSync: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  TrashRestoreResponse dco_decode_trash_restore_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TrashRestoreResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      atomId: dco_decode_opt_String(arr[3]),
+      noteRefNodeId: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  WorkspaceActionResponse dco_decode_workspace_action_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WorkspaceActionResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  WorkspaceListChildrenResponse dco_decode_workspace_list_children_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return WorkspaceListChildrenResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_workspace_node_item(arr[3]),
+    );
+  }
+
+  @protected
+  WorkspaceNodeItem dco_decode_workspace_node_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return WorkspaceNodeItem(
+      nodeId: dco_decode_String(arr[0]),
+      kind: dco_decode_String(arr[1]),
+      parentNodeId: dco_decode_opt_String(arr[2]),
+      atomId: dco_decode_opt_String(arr[3]),
+      displayName: dco_decode_String(arr[4]),
+      sortOrder: dco_decode_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  WorkspaceNodeResponse dco_decode_workspace_node_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return WorkspaceNodeResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      node: dco_decode_opt_box_autoadd_workspace_node_item(arr[3]),
+    );
+  }
+
+  @protected
+  WorkspaceRepairResponse dco_decode_workspace_repair_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return WorkspaceRepairResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      renamedCount: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AtomConvertResponse sse_decode_atom_convert_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_atomId = sse_decode_opt_String(deserializer);
+    var var_kind = sse_decode_opt_String(deserializer);
+    var var_taskStatus = sse_decode_opt_String(deserializer);
+    var var_startAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_endAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return AtomConvertResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      atomId: var_atomId,
+      kind: var_kind,
+      taskStatus: var_taskStatus,
+      startAt: var_startAt,
+      endAt: var_endAt,
+    );
+  }
+
+  @protected
+  AtomListItem sse_decode_atom_list_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_atomId = sse_decode_String(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_previewText = sse_decode_opt_String(deserializer);
+    var var_previewImage = sse_decode_opt_String(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    var var_startAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_endAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_taskStatus = sse_decode_opt_String(deserializer);
+    var var_updatedAt = sse_decode_i_64(deserializer);
+    return AtomListItem(
+      atomId: var_atomId,
+      kind: var_kind,
+      content: var_content,
+      previewText: var_previewText,
+      previewImage: var_previewImage,
+      tags: var_tags,
+      startAt: var_startAt,
+      endAt: var_endAt,
+      taskStatus: var_taskStatus,
+      updatedAt: var_updatedAt,
+    );
+  }
+
+  @protected
+  AtomListResponse sse_decode_atom_list_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_atom_list_item(deserializer);
+    var var_appliedLimit = sse_decode_u_32(deserializer);
+    return AtomListResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+      appliedLimit: var_appliedLimit,
+    );
+  }
+
+  @protected
+  AtomScheduleResponse sse_decode_atom_schedule_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_atomId = sse_decode_opt_String(deserializer);
+    var var_allDay = sse_decode_bool(deserializer);
+    var var_startAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_endAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_startDate = sse_decode_opt_String(deserializer);
+    var var_endDate = sse_decode_opt_String(deserializer);
+    return AtomScheduleResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      atomId: var_atomId,
+      allDay: var_allDay,
+      startAt: var_startAt,
+      endAt: var_endAt,
+      startDate: var_startDate,
+      endDate: var_endDate,
+    );
+  }
+
+  @protected
+  AtomTaskMetaResponse sse_decode_atom_task_meta_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_atomId = sse_decode_opt_String(deserializer);
+    var var_priority = sse_decode_opt_String(deserializer);
+    var var_dueAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return AtomTaskMetaResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      atomId: var_atomId,
+      priority: var_priority,
+      dueAt: var_dueAt,
+    );
+  }
+
+  @protected
+  AttachmentGcResponse sse_decode_attachment_gc_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_removedRows = sse_decode_u_32(deserializer);
+    var var_removedBlobs = sse_decode_u_32(deserializer);
+    var var_freedBytes = sse_decode_u_64(deserializer);
+    return AttachmentGcResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      removedRows: var_removedRows,
+      removedBlobs: var_removedBlobs,
+      freedBytes: var_freedBytes,
+    );
+  }
+
+  @protected
+  AttachmentImportResponse sse_decode_attachment_import_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_attachment =
+        sse_decode_opt_box_autoadd_attachment_item(deserializer);
+    var var_deduplicated = sse_decode_bool(deserializer);
+    return AttachmentImportResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      attachment: var_attachment,
+      deduplicated: var_deduplicated,
+    );
+  }
+
+  @protected
+  AttachmentItem sse_decode_attachment_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sha256 = sse_decode_String(deserializer);
+    var var_uri = sse_decode_String(deserializer);
+    var var_byteSize = sse_decode_i_64(deserializer);
+    var var_mimeType = sse_decode_opt_String(deserializer);
+    var var_originalName = sse_decode_opt_String(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    var var_refCount = sse_decode_u_32(deserializer);
+    return AttachmentItem(
+      sha256: var_sha256,
+      uri: var_uri,
+      byteSize: var_byteSize,
+      mimeType: var_mimeType,
+      originalName: var_originalName,
+      createdAt: var_createdAt,
+      refCount: var_refCount,
+    );
+  }
+
+  @protected
+  AttachmentResolveResponse sse_decode_attachment_resolve_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_attachment =
+        sse_decode_opt_box_autoadd_attachment_item(deserializer);
+    var var_filePath = sse_decode_opt_String(deserializer);
+    return AttachmentResolveResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      attachment: var_attachment,
+      filePath: var_filePath,
+    );
+  }
+
+  @protected
+  AttachmentsListResponse sse_decode_attachments_list_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_attachment_item(deserializer);
+    return AttachmentsListResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  AttachmentItem sse_decode_box_autoadd_attachment_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_attachment_item(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  NoteItem sse_decode_box_autoadd_note_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_note_item(deserializer));
+  }
+
+  @protected
+  ReminderItem sse_decode_box_autoadd_reminder_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_reminder_item(deserializer));
+  }
+
+  @protected
+  TimeEntryItem sse_decode_box_autoadd_time_entry_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_time_entry_item(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  WorkspaceNodeItem sse_decode_box_autoadd_workspace_node_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_workspace_node_item(deserializer));
+  }
+
+  @protected
+  CalendarConflictItem sse_decode_calendar_conflict_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_firstAtomId = sse_decode_String(deserializer);
+    var var_firstOccurrenceStartAt =
+        sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_secondAtomId = sse_decode_String(deserializer);
+    var var_secondOccurrenceStartAt =
+        sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_overlapStartMs = sse_decode_i_64(deserializer);
+    var var_overlapEndMs = sse_decode_i_64(deserializer);
+    return CalendarConflictItem(
+      firstAtomId: var_firstAtomId,
+      firstOccurrenceStartAt: var_firstOccurrenceStartAt,
+      secondAtomId: var_secondAtomId,
+      secondOccurrenceStartAt: var_secondOccurrenceStartAt,
+      overlapStartMs: var_overlapStartMs,
+      overlapEndMs: var_overlapEndMs,
+    );
+  }
+
+  @protected
+  CalendarConflictsResponse sse_decode_calendar_conflicts_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_calendar_conflict_item(deserializer);
+    return CalendarConflictsResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+    );
+  }
+
+  @protected
+  CalendarDayItem sse_decode_calendar_day_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_date = sse_decode_String(deserializer);
+    var var_startMs = sse_decode_i_64(deserializer);
+    var var_endMs = sse_decode_i_64(deserializer);
+    var var_allDay = sse_decode_list_calendar_entry_item(deserializer);
+    var var_timed = sse_decode_list_calendar_entry_item(deserializer);
+    return CalendarDayItem(
+      date: var_date,
+      startMs: var_startMs,
+      endMs: var_endMs,
+      allDay: var_allDay,
+      timed: var_timed,
+    );
+  }
+
+  @protected
+  CalendarDaysResponse sse_decode_calendar_days_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_days = sse_decode_list_calendar_day_item(deserializer);
+    return CalendarDaysResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      days: var_days,
+    );
+  }
+
+  @protected
+  CalendarEntryItem sse_decode_calendar_entry_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_atomId = sse_decode_String(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_previewText = sse_decode_opt_String(deserializer);
+    var var_taskStatus = sse_decode_opt_String(deserializer);
+    var var_allDay = sse_decode_bool(deserializer);
+    var var_startAt = sse_decode_i_64(deserializer);
+    var var_endAt = sse_decode_i_64(deserializer);
+    var var_occurrenceStartAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_visibleStartMs = sse_decode_i_64(deserializer);
+    var var_visibleEndMs = sse_decode_i_64(deserializer);
+    var var_continuesBefore = sse_decode_bool(deserializer);
+    var var_continuesAfter = sse_decode_bool(deserializer);
+    var var_column = sse_decode_u_32(deserializer);
+    var var_columnCount = sse_decode_u_32(deserializer);
+    return CalendarEntryItem(
+      atomId: var_atomId,
+      kind: var_kind,
+      content: var_content,
+      previewText: var_previewText,
+      taskStatus: var_taskStatus,
+      allDay: var_allDay,
+      startAt: var_startAt,
+      endAt: var_endAt,
+      occurrenceStartAt: var_occurrenceStartAt,
+      visibleStartMs: var_visibleStartMs,
+      visibleEndMs: var_visibleEndMs,
+      continuesBefore: var_continuesBefore,
+      continuesAfter: var_continuesAfter,
+      column: var_column,
+      columnCount: var_columnCount,
+    );
+  }
+
+  @protected
+  CalendarFreeSlotItem sse_decode_calendar_free_slot_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startMs = sse_decode_i_64(deserializer);
+    var var_endMs = sse_decode_i_64(deserializer);
+    return CalendarFreeSlotItem(startMs: var_startMs, endMs: var_endMs);
+  }
+
+  @protected
+  CalendarFreeSlotsResponse sse_decode_calendar_free_slots_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_slots = sse_decode_list_calendar_free_slot_item(deserializer);
+    return CalendarFreeSlotsResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      slots: var_slots,
+    );
+  }
+
+  @protected
+  DueReminderItem sse_decode_due_reminder_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_reminder = sse_decode_reminder_item(deserializer);
+    var var_atomKind = sse_decode_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_previewText = sse_decode_opt_String(deserializer);
+    var var_fireAt = sse_decode_i_64(deserializer);
+    var var_occurrenceStartAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_snoozed = sse_decode_bool(deserializer);
+    return DueReminderItem(
+      reminder: var_reminder,
+      atomKind: var_atomKind,
+      content: var_content,
+      previewText: var_previewText,
+      fireAt: var_fireAt,
+      occurrenceStartAt: var_occurrenceStartAt,
+      snoozed: var_snoozed,
+    );
+  }
+
+  @protected
+  DueRemindersResponse sse_decode_due_reminders_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_due_reminder_item(deserializer);
+    var var_nextWakeupAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return DueRemindersResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+      nextWakeupAt: var_nextWakeupAt,
+    );
+  }
+
+  @protected
+  EntryActionResponse sse_decode_entry_action_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_atomId = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return EntryActionResponse(
+      ok: var_ok,
+      atomId: var_atomId,
+      message: var_message,
+    );
+  }
+
+  @protected
+  EntryQuickAddResponse sse_decode_entry_quick_add_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_startAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_endAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_allDay = sse_decode_bool(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    var var_priority = sse_decode_opt_String(deserializer);
+    var var_tokens = sse_decode_list_entry_quick_add_token(deserializer);
+    return EntryQuickAddResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      kind: var_kind,
+      title: var_title,
+      startAt: var_startAt,
+      endAt: var_endAt,
+      allDay: var_allDay,
+      tags: var_tags,
+      priority: var_priority,
+      tokens: var_tokens,
+    );
+  }
+
+  @protected
+  EntryQuickAddToken sse_decode_entry_quick_add_token(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_String(deserializer);
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    var var_charStart = sse_decode_u_32(deserializer);
+    var var_charEnd = sse_decode_u_32(deserializer);
+    return EntryQuickAddToken(
+      kind: var_kind,
+      start: var_start,
+      end: var_end,
+      charStart: var_charStart,
+      charEnd: var_charEnd,
+    );
+  }
+
+  @protected
+  EntrySearchHighlight sse_decode_entry_search_highlight(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    var var_charStart = sse_decode_u_32(deserializer);
+    var var_charEnd = sse_decode_u_32(deserializer);
+    return EntrySearchHighlight(
+      start: var_start,
+      end: var_end,
+      charStart: var_charStart,
+      charEnd: var_charEnd,
+    );
+  }
+
+  @protected
+  EntrySearchHitItem sse_decode_entry_search_hit_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_atomId = sse_decode_String(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_snippet = sse_decode_String(deserializer);
+    var var_highlights = sse_decode_list_entry_search_highlight(deserializer);
+    var var_bm25 = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_score = sse_decode_f_64(deserializer);
+    return EntrySearchHitItem(
+      atomId: var_atomId,
+      kind: var_kind,
+      snippet: var_snippet,
+      highlights: var_highlights,
+      bm25: var_bm25,
+      score: var_score,
+    );
+  }
+
+  @protected
+  EntrySearchItem sse_decode_entry_search_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_atomId = sse_decode_String(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_snippet = sse_decode_String(deserializer);
+    return EntrySearchItem(
+      atomId: var_atomId,
+      kind: var_kind,
+      snippet: var_snippet,
+    );
+  }
+
+  @protected
+  EntrySearchPageResponse sse_decode_entry_search_page_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_entry_search_hit_item(deserializer);
+    var var_nextCursor = sse_decode_opt_String(deserializer);
+    var var_appliedLimit = sse_decode_u_32(deserializer);
+    return EntrySearchPageResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+      nextCursor: var_nextCursor,
+      appliedLimit: var_appliedLimit,
+    );
+  }
+
+  @protected
+  EntrySearchResponse sse_decode_entry_search_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_items = sse_decode_list_entry_search_item(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_appliedLimit = sse_decode_u_32(deserializer);
+    return EntrySearchResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      items: var_items,
+      message: var_message,
+      appliedLimit: var_appliedLimit,
+    );
+  }
+
+  @protected
+  EntrySearchSyntaxResponse sse_decode_entry_search_syntax_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_syntaxError = sse_decode_opt_String(deserializer);
+    var var_errorStart = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_errorEnd = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_filterCount = sse_decode_u_32(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return EntrySearchSyntaxResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      syntaxError: var_syntaxError,
+      errorStart: var_errorStart,
+      errorEnd: var_errorEnd,
+      filterCount: var_filterCount,
+      message: var_message,
+    );
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  LaunchPlanItem sse_decode_launch_plan_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_linkId = sse_decode_i_64(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_target = sse_decode_String(deserializer);
+    var var_label = sse_decode_opt_String(deserializer);
+    var var_localPath = sse_decode_opt_String(deserializer);
+    return LaunchPlanItem(
+      linkId: var_linkId,
+      kind: var_kind,
+      target: var_target,
+      label: var_label,
+      localPath: var_localPath,
+    );
+  }
+
+  @protected
+  LaunchPlanResponse sse_decode_launch_plan_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_launch_plan_item(deserializer);
+    var var_rejected = sse_decode_list_launch_rejection_item(deserializer);
+    var var_cappedCount = sse_decode_u_32(deserializer);
+    var var_appliedMaxItems = sse_decode_u_32(deserializer);
+    return LaunchPlanResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+      rejected: var_rejected,
+      cappedCount: var_cappedCount,
+      appliedMaxItems: var_appliedMaxItems,
+    );
+  }
+
+  @protected
+  LaunchRejectionItem sse_decode_launch_rejection_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_linkId = sse_decode_i_64(deserializer);
+    var var_target = sse_decode_String(deserializer);
+    var var_reason = sse_decode_String(deserializer);
+    return LaunchRejectionItem(
+      linkId: var_linkId,
+      target: var_target,
+      reason: var_reason,
+    );
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AtomListItem> sse_decode_list_atom_list_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AtomListItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_atom_list_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AttachmentItem> sse_decode_list_attachment_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AttachmentItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_attachment_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CalendarConflictItem> sse_decode_list_calendar_conflict_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CalendarConflictItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_calendar_conflict_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CalendarDayItem> sse_decode_list_calendar_day_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CalendarDayItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_calendar_day_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CalendarEntryItem> sse_decode_list_calendar_entry_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CalendarEntryItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_calendar_entry_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CalendarFreeSlotItem> sse_decode_list_calendar_free_slot_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CalendarFreeSlotItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_calendar_free_slot_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DueReminderItem> sse_decode_list_due_reminder_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DueReminderItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_due_reminder_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EntryQuickAddToken> sse_decode_list_entry_quick_add_token(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EntryQuickAddToken>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_entry_quick_add_token(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EntrySearchHighlight> sse_decode_list_entry_search_highlight(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EntrySearchHighlight>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_entry_search_highlight(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EntrySearchHitItem> sse_decode_list_entry_search_hit_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EntrySearchHitItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_entry_search_hit_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EntrySearchItem> sse_decode_list_entry_search_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EntrySearchItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_entry_search_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LaunchPlanItem> sse_decode_list_launch_plan_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LaunchPlanItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_launch_plan_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LaunchRejectionItem> sse_decode_list_launch_rejection_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LaunchRejectionItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_launch_rejection_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NoteDiffLineItem> sse_decode_list_note_diff_line_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NoteDiffLineItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_note_diff_line_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NoteItem> sse_decode_list_note_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NoteItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_note_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NoteLinkItem> sse_decode_list_note_link_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NoteLinkItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_note_link_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NoteOutlineHeading> sse_decode_list_note_outline_heading(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NoteOutlineHeading>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_note_outline_heading(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NotePropertyItem> sse_decode_list_note_property_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NotePropertyItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_note_property_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NoteRevisionItem> sse_decode_list_note_revision_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NoteRevisionItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_note_revision_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<ReminderItem> sse_decode_list_reminder_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReminderItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_reminder_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ResourceLinkItem> sse_decode_list_resource_link_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ResourceLinkItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_resource_link_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TagUsageItem> sse_decode_list_tag_usage_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TagUsageItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_tag_usage_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TimeDayTotalItem> sse_decode_list_time_day_total_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TimeDayTotalItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_time_day_total_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TimeEntryItem> sse_decode_list_time_entry_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TimeEntryItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_time_entry_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TimeFolderTotalItem> sse_decode_list_time_folder_total_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TimeFolderTotalItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_time_folder_total_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TimeTagTotalItem> sse_decode_list_time_tag_total_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TimeTagTotalItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_time_tag_total_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TrashListItem> sse_decode_list_trash_list_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TrashListItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_trash_list_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WorkspaceNodeItem> sse_decode_list_workspace_node_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WorkspaceNodeItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_workspace_node_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LocalTimeWindowsResponse sse_decode_local_time_windows_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_timeZone = sse_decode_String(deserializer);
+    var var_weekStart = sse_decode_String(deserializer);
+    var var_dayStartMs = sse_decode_i_64(deserializer);
+    var var_dayEndMs = sse_decode_i_64(deserializer);
+    var var_weekStartMs = sse_decode_i_64(deserializer);
+    var var_weekEndMs = sse_decode_i_64(deserializer);
+    var var_monthStartMs = sse_decode_i_64(deserializer);
+    var var_monthEndMs = sse_decode_i_64(deserializer);
+    return LocalTimeWindowsResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      timeZone: var_timeZone,
+      weekStart: var_weekStart,
+      dayStartMs: var_dayStartMs,
+      dayEndMs: var_dayEndMs,
+      weekStartMs: var_weekStartMs,
+      weekEndMs: var_weekEndMs,
+      monthStartMs: var_monthStartMs,
+      monthEndMs: var_monthEndMs,
+    );
+  }
+
+  @protected
+  LogDartEventResponse sse_decode_log_dart_event_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return LogDartEventResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+    );
+  }
+
+  @protected
+  NoteDiffLineItem sse_decode_note_diff_line_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_String(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_oldLine = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_newLine = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return NoteDiffLineItem(
+      kind: var_kind,
+      text: var_text,
+      oldLine: var_oldLine,
+      newLine: var_newLine,
+    );
+  }
+
+  @protected
+  NoteItem sse_decode_note_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_atomId = sse_decode_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_previewText = sse_decode_opt_String(deserializer);
+    var var_previewImage = sse_decode_opt_String(deserializer);
+    var var_updatedAt = sse_decode_i_64(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    return NoteItem(
+      atomId: var_atomId,
+      content: var_content,
+      previewText: var_previewText,
+      previewImage: var_previewImage,
+      updatedAt: var_updatedAt,
+      tags: var_tags,
+    );
+  }
+
+  @protected
+  NoteLinkItem sse_decode_note_link_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sourceAtomId = sse_decode_String(deserializer);
+    var var_target = sse_decode_String(deserializer);
+    var var_label = sse_decode_opt_String(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_targetAtomId = sse_decode_opt_String(deserializer);
+    return NoteLinkItem(
+      sourceAtomId: var_sourceAtomId,
+      target: var_target,
+      label: var_label,
+      kind: var_kind,
+      targetAtomId: var_targetAtomId,
+    );
+  }
+
+  @protected
+  NoteLinksResponse sse_decode_note_links_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_outgoing = sse_decode_list_note_link_item(deserializer);
+    var var_backlinks = sse_decode_list_note_link_item(deserializer);
+    return NoteLinksResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      outgoing: var_outgoing,
+      backlinks: var_backlinks,
+    );
+  }
+
+  @protected
+  NoteOutlineHeading sse_decode_note_outline_heading(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_level = sse_decode_u_32(deserializer);
+    var var_depth = sse_decode_u_32(deserializer);
+    var var_parentIndex = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_byteStart = sse_decode_u_32(deserializer);
+    var var_byteEnd = sse_decode_u_32(deserializer);
+    return NoteOutlineHeading(
+      level: var_level,
+      depth: var_depth,
+      parentIndex: var_parentIndex,
+      text: var_text,
+      byteStart: var_byteStart,
+      byteEnd: var_byteEnd,
+    );
+  }
+
+  @protected
+  NoteOutlineResponse sse_decode_note_outline_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_headings = sse_decode_list_note_outline_heading(deserializer);
+    var var_wordCount = sse_decode_u_32(deserializer);
+    var var_charCount = sse_decode_u_32(deserializer);
+    var var_checklistDone = sse_decode_u_32(deserializer);
+    var var_checklistTotal = sse_decode_u_32(deserializer);
+    return NoteOutlineResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      headings: var_headings,
+      wordCount: var_wordCount,
+      charCount: var_charCount,
+      checklistDone: var_checklistDone,
+      checklistTotal: var_checklistTotal,
+    );
+  }
+
+  @protected
+  NotePropertiesResponse sse_decode_note_properties_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_note_property_item(deserializer);
+    return NotePropertiesResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+    );
+  }
+
+  @protected
+  NotePropertyItem sse_decode_note_property_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_valueType = sse_decode_String(deserializer);
+    var var_text = sse_decode_opt_String(deserializer);
+    var var_number = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_items = sse_decode_list_String(deserializer);
+    return NotePropertyItem(
+      key: var_key,
+      valueType: var_valueType,
+      text: var_text,
+      number: var_number,
+      items: var_items,
+    );
+  }
+
+  @protected
+  NoteResponse sse_decode_note_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_note = sse_decode_opt_box_autoadd_note_item(deserializer);
+    return NoteResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      note: var_note,
+    );
+  }
+
+  @protected
+  NoteRevisionDiffResponse sse_decode_note_revision_diff_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_lines = sse_decode_list_note_diff_line_item(deserializer);
+    return NoteRevisionDiffResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      lines: var_lines,
+    );
+  }
+
+  @protected
+  NoteRevisionItem sse_decode_note_revision_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_revisionId = sse_decode_i_64(deserializer);
+    var var_atomId = sse_decode_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_previewText = sse_decode_opt_String(deserializer);
+    var var_sourceUpdatedAt = sse_decode_i_64(deserializer);
+    var var_capturedAt = sse_decode_i_64(deserializer);
+    return NoteRevisionItem(
+      revisionId: var_revisionId,
+      atomId: var_atomId,
+      content: var_content,
+      previewText: var_previewText,
+      sourceUpdatedAt: var_sourceUpdatedAt,
+      capturedAt: var_capturedAt,
+    );
+  }
+
+  @protected
+  NoteRevisionsListResponse sse_decode_note_revisions_list_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_note_revision_item(deserializer);
+    var var_appliedLimit = sse_decode_u_32(deserializer);
+    return NoteRevisionsListResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+      appliedLimit: var_appliedLimit,
+    );
+  }
+
+  @protected
+  NoteTagSourcesResponse sse_decode_note_tag_sources_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_manualTags = sse_decode_list_String(deserializer);
+    var var_inlineTags = sse_decode_list_String(deserializer);
+    return NoteTagSourcesResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      manualTags: var_manualTags,
+      inlineTags: var_inlineTags,
+    );
+  }
+
+  @protected
+  NoteTitleResponse sse_decode_note_title_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_derivedTitle = sse_decode_opt_String(deserializer);
+    var var_titleOverride = sse_decode_opt_String(deserializer);
+    return NoteTitleResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      title: var_title,
+      derivedTitle: var_derivedTitle,
+      titleOverride: var_titleOverride,
+    );
+  }
+
+  @protected
+  NotesListResponse sse_decode_notes_list_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_note_item(deserializer);
+    var var_appliedLimit = sse_decode_u_32(deserializer);
+    return NotesListResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+      appliedLimit: var_appliedLimit,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AttachmentItem? sse_decode_opt_box_autoadd_attachment_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_attachment_item(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  NoteItem? sse_decode_opt_box_autoadd_note_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_note_item(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ReminderItem? sse_decode_opt_box_autoadd_reminder_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_reminder_item(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  TimeEntryItem? sse_decode_opt_box_autoadd_time_entry_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_time_entry_item(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  WorkspaceNodeItem? sse_decode_opt_box_autoadd_workspace_node_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_workspace_node_item(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RecurrenceResponse sse_decode_recurrence_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_atomId = sse_decode_opt_String(deserializer);
+    var var_recurrenceRule = sse_decode_opt_String(deserializer);
+    return RecurrenceResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      atomId: var_atomId,
+      recurrenceRule: var_recurrenceRule,
+    );
+  }
+
+  @protected
+  ReminderItem sse_decode_reminder_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_reminderId = sse_decode_String(deserializer);
+    var var_atomId = sse_decode_String(deserializer);
+    var var_anchor = sse_decode_String(deserializer);
+    var var_remindAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_offsetMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_ackedThrough = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_snoozedUntil = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_nextFireAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    return ReminderItem(
+      reminderId: var_reminderId,
+      atomId: var_atomId,
+      anchor: var_anchor,
+      remindAt: var_remindAt,
+      offsetMs: var_offsetMs,
+      ackedThrough: var_ackedThrough,
+      snoozedUntil: var_snoozedUntil,
+      nextFireAt: var_nextFireAt,
+      createdAt: var_createdAt,
+    );
+  }
+
+  @protected
+  ReminderListResponse sse_decode_reminder_list_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_reminder_item(deserializer);
+    return ReminderListResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+    );
+  }
+
+  @protected
+  ReminderResponse sse_decode_reminder_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_reminder = sse_decode_opt_box_autoadd_reminder_item(deserializer);
+    return ReminderResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      reminder: var_reminder,
+    );
+  }
+
+  @protected
+  ResourceLinkItem sse_decode_resource_link_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_linkId = sse_decode_i_64(deserializer);
+    var var_atomId = sse_decode_String(deserializer);
+    var var_target = sse_decode_String(deserializer);
+    var var_scheme = sse_decode_String(deserializer);
+    var var_label = sse_decode_opt_String(deserializer);
+    return ResourceLinkItem(
+      linkId: var_linkId,
+      atomId: var_atomId,
+      target: var_target,
+      scheme: var_scheme,
+      label: var_label,
+    );
+  }
+
+  @protected
+  ResourceLinksResponse sse_decode_resource_links_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_resource_link_item(deserializer);
+    return ResourceLinksResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+    );
+  }
+
+  @protected
+  SubtaskResponse sse_decode_subtask_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_atomId = sse_decode_opt_String(deserializer);
+    var var_parentId = sse_decode_opt_String(deserializer);
+    var var_subtaskIds = sse_decode_list_String(deserializer);
+    var var_done = sse_decode_u_32(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    return SubtaskResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      atomId: var_atomId,
+      parentId: var_parentId,
+      subtaskIds: var_subtaskIds,
+      done: var_done,
+      total: var_total,
+    );
+  }
+
+  @protected
+  TagEditResponse sse_decode_tag_edit_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_affectedAtoms = sse_decode_u_32(deserializer);
+    return TagEditResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      affectedAtoms: var_affectedAtoms,
+    );
+  }
+
+  @protected
+  TagUsageItem sse_decode_tag_usage_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_directCount = sse_decode_u_32(deserializer);
+    var var_totalCount = sse_decode_u_32(deserializer);
+    return TagUsageItem(
+      name: var_name,
+      directCount: var_directCount,
+      totalCount: var_totalCount,
+    );
+  }
+
+  @protected
+  TagsListResponse sse_decode_tags_list_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    return TagsListResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      tags: var_tags,
+    );
+  }
+
+  @protected
+  TagsUsageResponse sse_decode_tags_usage_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_tag_usage_item(deserializer);
+    return TagsUsageResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+    );
+  }
+
+  @protected
+  TaskDependencyResponse sse_decode_task_dependency_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_atomId = sse_decode_opt_String(deserializer);
+    var var_blocked = sse_decode_bool(deserializer);
+    var var_prerequisiteIds = sse_decode_list_String(deserializer);
+    var var_dependentIds = sse_decode_list_String(deserializer);
+    return TaskDependencyResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      atomId: var_atomId,
+      blocked: var_blocked,
+      prerequisiteIds: var_prerequisiteIds,
+      dependentIds: var_dependentIds,
+    );
+  }
+
+  @protected
+  TimeDayTotalItem sse_decode_time_day_total_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_date = sse_decode_String(deserializer);
+    var var_totalMs = sse_decode_i_64(deserializer);
+    return TimeDayTotalItem(date: var_date, totalMs: var_totalMs);
+  }
+
+  @protected
+  TimeEntryItem sse_decode_time_entry_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_entryId = sse_decode_String(deserializer);
+    var var_atomId = sse_decode_String(deserializer);
+    var var_deviceId = sse_decode_String(deserializer);
+    var var_startedAt = sse_decode_i_64(deserializer);
+    var var_endedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_note = sse_decode_opt_String(deserializer);
+    var var_running = sse_decode_bool(deserializer);
+    var var_durationMs = sse_decode_i_64(deserializer);
+    return TimeEntryItem(
+      entryId: var_entryId,
+      atomId: var_atomId,
+      deviceId: var_deviceId,
+      startedAt: var_startedAt,
+      endedAt: var_endedAt,
+      note: var_note,
+      running: var_running,
+      durationMs: var_durationMs,
+    );
+  }
+
+  @protected
+  TimeEntryListResponse sse_decode_time_entry_list_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_time_entry_item(deserializer);
+    var var_totalMs = sse_decode_i_64(deserializer);
+    return TimeEntryListResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+      totalMs: var_totalMs,
+    );
+  }
+
+  @protected
+  TimeEntryResponse sse_decode_time_entry_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_entry = sse_decode_opt_box_autoadd_time_entry_item(deserializer);
+    return TimeEntryResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      entry: var_entry,
+    );
+  }
+
+  @protected
+  TimeFolderTotalItem sse_decode_time_folder_total_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_folderId = sse_decode_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_totalMs = sse_decode_i_64(deserializer);
+    return TimeFolderTotalItem(
+      folderId: var_folderId,
+      path: var_path,
+      totalMs: var_totalMs,
+    );
+  }
+
+  @protected
+  TimeReportResponse sse_decode_time_report_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_days = sse_decode_list_time_day_total_item(deserializer);
+    var var_tags = sse_decode_list_time_tag_total_item(deserializer);
+    var var_folders = sse_decode_list_time_folder_total_item(deserializer);
+    return TimeReportResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      days: var_days,
+      tags: var_tags,
+      folders: var_folders,
+    );
+  }
+
+  @protected
+  TimeTagTotalItem sse_decode_time_tag_total_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tag = sse_decode_String(deserializer);
+    var var_totalMs = sse_decode_i_64(deserializer);
+    return TimeTagTotalItem(tag: var_tag, totalMs: var_totalMs);
+  }
+
+  @protected
+  TrashListItem sse_decode_trash_list_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_atomId = sse_decode_String(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_previewText = sse_decode_opt_String(deserializer);
+    var var_deletedAt = sse_decode_i_64(deserializer);
+    var var_syncPending = sse_decode_bool(deserializer);
+    return TrashListItem(
+      atomId: var_atomId,
+      kind: var_kind,
+      content: var_content,
+      previewText: var_previewText,
+      deletedAt: var_deletedAt,
+      syncPending: var_syncPending,
+    );
+  }
+
+  @protected
+  TrashListResponse sse_decode_trash_list_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_trash_list_item(deserializer);
+    var var_appliedLimit = sse_decode_u_32(deserializer);
+    return TrashListResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+      appliedLimit: var_appliedLimit,
+    );
+  }
+
+  @protected
+  TrashPurgeResponse sse_decode_trash_purge_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_purged = sse_decode_u_32(deserializer);
+    var var_keptForSync = sse_decode_u_32(deserializer);
+    return TrashPurgeResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      purged: var_purged,
+      keptForSync: var_keptForSync,
+    );
+  }
+
+  @protected
+  TrashRestoreResponse sse_decode_trash_restore_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_atomId = sse_decode_opt_String(deserializer);
+    var var_noteRefNodeId = sse_decode_opt_String(deserializer);
+    return TrashRestoreResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      atomId: var_atomId,
+      noteRefNodeId: var_noteRefNodeId,
+    );
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  WorkspaceActionResponse sse_decode_workspace_action_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return WorkspaceActionResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+    );
+  }
+
+  @protected
+  WorkspaceListChildrenResponse sse_decode_workspace_list_children_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_workspace_node_item(deserializer);
+    return WorkspaceListChildrenResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+    );
+  }
+
+  @protected
+  WorkspaceNodeItem sse_decode_workspace_node_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_nodeId = sse_decode_String(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_parentNodeId = sse_decode_opt_String(deserializer);
+    var var_atomId = sse_decode_opt_String(deserializer);
+    var var_displayName = sse_decode_String(deserializer);
+    var var_sortOrder = sse_decode_i_64(deserializer);
+    return WorkspaceNodeItem(
+      nodeId: var_nodeId,
+      kind: var_kind,
+      parentNodeId: var_parentNodeId,
+      atomId: var_atomId,
+      displayName: var_displayName,
+      sortOrder: var_sortOrder,
+    );
+  }
+
+  @protected
+  WorkspaceNodeResponse sse_decode_workspace_node_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_node = sse_decode_opt_box_autoadd_workspace_node_item(deserializer);
+    return WorkspaceNodeResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      node: var_node,
+    );
+  }
+
+  @protected
+  WorkspaceRepairResponse sse_decode_workspace_repair_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_renamedCount = sse_decode_u_32(deserializer);
+    return WorkspaceRepairResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      renamedCount: var_renamedCount,
     );
   }
 
   @protected
-  AtomListResponse dco_decode_atom_list_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return AtomListResponse(
-      ok: dco_decode_bool(arr[0]),
-      errorCode: dco_decode_opt_String(arr[1]),
-      message: dco_decode_String(arr[2]),
-      items: dco_decode_list_atom_list_item(arr[3]),
-      appliedLimit: dco_decode_u_32(arr[4]),
-    );
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_atom_convert_response(
+    AtomConvertResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_String(self.atomId, serializer);
+    sse_encode_opt_String(self.kind, serializer);
+    sse_encode_opt_String(self.taskStatus, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.startAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.endAt, serializer);
+  }
+
+  @protected
+  void sse_encode_atom_list_item(AtomListItem self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.atomId, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_opt_String(self.previewText, serializer);
+    sse_encode_opt_String(self.previewImage, serializer);
+    sse_encode_list_String(self.tags, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.startAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.endAt, serializer);
+    sse_encode_opt_String(self.taskStatus, serializer);
+    sse_encode_i_64(self.updatedAt, serializer);
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  void sse_encode_atom_list_response(
+    AtomListResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_atom_list_item(self.items, serializer);
+    sse_encode_u_32(self.appliedLimit, serializer);
   }
 
   @protected
-  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_i_64(raw);
+  void sse_encode_atom_schedule_response(
+    AtomScheduleResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_String(self.atomId, serializer);
+    sse_encode_bool(self.allDay, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.startAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.endAt, serializer);
+    sse_encode_opt_String(self.startDate, serializer);
+    sse_encode_opt_String(self.endDate, serializer);
   }
 
   @protected
-  NoteItem dco_decode_box_autoadd_note_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_note_item(raw);
+  void sse_encode_atom_task_meta_response(
+    AtomTaskMetaResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_String(self.atomId, serializer);
+    sse_encode_opt_String(self.priority, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.dueAt, serializer);
   }
 
   @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_attachment_gc_response(
+    AttachmentGcResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_u_32(self.removedRows, serializer);
+    sse_encode_u_32(self.removedBlobs, serializer);
+    sse_encode_u_64(self.freedBytes, serializer);
   }
 
   @protected
-  WorkspaceNodeItem dco_decode_box_autoadd_workspace_node_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_workspace_node_item(raw);
+  void sse_encode_attachment_import_response(
+    AttachmentImportResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_box_autoadd_attachment_item(self.attachment, serializer);
+    sse_encode_bool(self.deduplicated, serializer);
   }
 
   @protected
-  EntryActionResponse dco_decode_entry_action_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return EntryActionResponse(
-      ok: dco_decode_bool(arr[0]),
-      atomId: dco_decode_opt_String(arr[1]),
-      message: dco_decode_String(arr[2]),
-    );
+  void sse_encode_attachment_item(
+    AttachmentItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.sha256, serializer);
+    sse_encode_String(self.uri, serializer);
+    sse_encode_i_64(self.byteSize, serializer);
+    sse_encode_opt_String(self.mimeType, serializer);
+    sse_encode_opt_String(self.originalName, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+    sse_encode_u_32(self.refCount, serializer);
   }
 
   @protected
-  EntrySearchItem dco_decode_entry_search_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return EntrySearchItem(
-      atomId: dco_decode_String(arr[0]),
-      kind: dco_decode_String(arr[1]),
-      snippet: dco_decode_String(arr[2]),
-    );
+  void sse_encode_attachment_resolve_response(
+    AttachmentResolveResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_box_autoadd_attachment_item(self.attachment, serializer);
+    sse_encode_opt_String(self.filePath, serializer);
+  }
+
+  @protected
+  void sse_encode_attachments_list_response(
+    AttachmentsListResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_attachment_item(self.items, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_attachment_item(
+    AttachmentItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_attachment_item(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_note_item(
+    NoteItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_note_item(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_reminder_item(
+    ReminderItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_reminder_item(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_time_entry_item(
+    TimeEntryItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_time_entry_item(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_workspace_node_item(
+    WorkspaceNodeItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_workspace_node_item(self, serializer);
+  }
+
+  @protected
+  void sse_encode_calendar_conflict_item(
+    CalendarConflictItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.firstAtomId, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.firstOccurrenceStartAt, serializer);
+    sse_encode_String(self.secondAtomId, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.secondOccurrenceStartAt, serializer);
+    sse_encode_i_64(self.overlapStartMs, serializer);
+    sse_encode_i_64(self.overlapEndMs, serializer);
+  }
+
+  @protected
+  void sse_encode_calendar_conflicts_response(
+    CalendarConflictsResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_calendar_conflict_item(self.items, serializer);
+  }
+
+  @protected
+  void sse_encode_calendar_day_item(
+    CalendarDayItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.date, serializer);
+    sse_encode_i_64(self.startMs, serializer);
+    sse_encode_i_64(self.endMs, serializer);
+    sse_encode_list_calendar_entry_item(self.allDay, serializer);
+    sse_encode_list_calendar_entry_item(self.timed, serializer);
+  }
+
+  @protected
+  void sse_encode_calendar_days_response(
+    CalendarDaysResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_calendar_day_item(self.days, serializer);
+  }
+
+  @protected
+  void sse_encode_calendar_entry_item(
+    CalendarEntryItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.atomId, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_opt_String(self.previewText, serializer);
+    sse_encode_opt_String(self.taskStatus, serializer);
+    sse_encode_bool(self.allDay, serializer);
+    sse_encode_i_64(self.startAt, serializer);
+    sse_encode_i_64(self.endAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.occurrenceStartAt, serializer);
+    sse_encode_i_64(self.visibleStartMs, serializer);
+    sse_encode_i_64(self.visibleEndMs, serializer);
+    sse_encode_bool(self.continuesBefore, serializer);
+    sse_encode_bool(self.continuesAfter, serializer);
+    sse_encode_u_32(self.column, serializer);
+    sse_encode_u_32(self.columnCount, serializer);
+  }
+
+  @protected
+  void sse_encode_calendar_free_slot_item(
+    CalendarFreeSlotItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.startMs, serializer);
+    sse_encode_i_64(self.endMs, serializer);
+  }
+
+  @protected
+  void sse_encode_calendar_free_slots_response(
+    CalendarFreeSlotsResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_calendar_free_slot_item(self.slots, serializer);
+  }
+
+  @protected
+  void sse_encode_due_reminder_item(
+    DueReminderItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_reminder_item(self.reminder, serializer);
+    sse_encode_String(self.atomKind, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_opt_String(self.previewText, serializer);
+    sse_encode_i_64(self.fireAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.occurrenceStartAt, serializer);
+    sse_encode_bool(self.snoozed, serializer);
+  }
+
+  @protected
+  void sse_encode_due_reminders_response(
+    DueRemindersResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_due_reminder_item(self.items, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.nextWakeupAt, serializer);
+  }
+
+  @protected
+  void sse_encode_entry_action_response(
+    EntryActionResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.atomId, serializer);
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  EntrySearchResponse dco_decode_entry_search_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return EntrySearchResponse(
-      ok: dco_decode_bool(arr[0]),
-      errorCode: dco_decode_opt_String(arr[1]),
-      items: dco_decode_list_entry_search_item(arr[2]),
-      message: dco_decode_String(arr[3]),
-      appliedLimit: dco_decode_u_32(arr[4]),
-    );
+  void sse_encode_entry_quick_add_response(
+    EntryQuickAddResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.startAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.endAt, serializer);
+    sse_encode_bool(self.allDay, serializer);
+    sse_encode_list_String(self.tags, serializer);
+    sse_encode_opt_String(self.priority, serializer);
+    sse_encode_list_entry_quick_add_token(self.tokens, serializer);
   }
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  void sse_encode_entry_quick_add_token(
+    EntryQuickAddToken self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.kind, serializer);
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+    sse_encode_u_32(self.charStart, serializer);
+    sse_encode_u_32(self.charEnd, serializer);
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  void sse_encode_entry_search_highlight(
+    EntrySearchHighlight self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
+    sse_encode_u_32(self.charStart, serializer);
+    sse_encode_u_32(self.charEnd, serializer);
   }
 
   @protected
-  List<AtomListItem> dco_decode_list_atom_list_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_atom_list_item).toList();
+  void sse_encode_entry_search_hit_item(
+    EntrySearchHitItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.atomId, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.snippet, serializer);
+    sse_encode_list_entry_search_highlight(self.highlights, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.bm25, serializer);
+    sse_encode_f_64(self.score, serializer);
   }
 
   @protected
-  List<EntrySearchItem> dco_decode_list_entry_search_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_entry_search_item).toList();
+  void sse_encode_entry_search_item(
+    EntrySearchItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.atomId, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.snippet, serializer);
   }
 
   @protected
-  List<NoteItem> dco_decode_list_note_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_note_item).toList();
+  void sse_encode_entry_search_page_response(
+    EntrySearchPageResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_entry_search_hit_item(self.items, serializer);
+    sse_encode_opt_String(self.nextCursor, serializer);
+    sse_encode_u_32(self.appliedLimit, serializer);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_entry_search_response(
+    EntrySearchResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_list_entry_search_item(self.items, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_u_32(self.appliedLimit, serializer);
   }
 
   @protected
-  List<WorkspaceNodeItem> dco_decode_list_workspace_node_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_workspace_node_item).toList();
+  void sse_encode_entry_search_syntax_response(
+    EntrySearchSyntaxResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_opt_String(self.syntaxError, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.errorStart, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.errorEnd, serializer);
+    sse_encode_u_32(self.filterCount, serializer);
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  LogDartEventResponse dco_decode_log_dart_event_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return LogDartEventResponse(
-      ok: dco_decode_bool(arr[0]),
-      errorCode: dco_decode_opt_String(arr[1]),
-      message: dco_decode_String(arr[2]),
-    );
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  NoteItem dco_decode_note_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return NoteItem(
-      atomId: dco_decode_String(arr[0]),
-      content: dco_decode_String(arr[1]),
-      previewText: dco_decode_opt_String(arr[2]),
-      previewImage: dco_decode_opt_String(arr[3]),
-      updatedAt: dco_decode_i_64(arr[4]),
-      tags: dco_decode_list_String(arr[5]),
-    );
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  NoteResponse dco_decode_note_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return NoteResponse(
-      ok: dco_decode_bool(arr[0]),
-      errorCode: dco_decode_opt_String(arr[1]),
-      message: dco_decode_String(arr[2]),
-      note: dco_decode_opt_box_autoadd_note_item(arr[3]),
-    );
+  void sse_encode_launch_plan_item(
+    LaunchPlanItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.linkId, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_opt_String(self.label, serializer);
+    sse_encode_opt_String(self.localPath, serializer);
   }
 
   @protected
-  NotesListResponse dco_decode_notes_list_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return NotesListResponse(
-      ok: dco_decode_bool(arr[0]),
-      errorCode: dco_decode_opt_String(arr[1]),
-      message: dco_decode_String(arr[2]),
-      items: dco_decode_list_note_item(arr[3]),
-      appliedLimit: dco_decode_u_32(arr[4]),
-    );
+  void sse_encode_launch_plan_response(
+    LaunchPlanResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_launch_plan_item(self.items, serializer);
+    sse_encode_list_launch_rejection_item(self.rejected, serializer);
+    sse_encode_u_32(self.cappedCount, serializer);
+    sse_encode_u_32(self.appliedMaxItems, serializer);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_launch_rejection_item(
+    LaunchRejectionItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.linkId, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_String(self.reason, serializer);
   }
 
   @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  NoteItem? dco_decode_opt_box_autoadd_note_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_note_item(raw);
+  void sse_encode_list_atom_list_item(
+    List<AtomListItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_atom_list_item(item, serializer);
+    }
   }
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  void sse_encode_list_attachment_item(
+    List<AttachmentItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_attachment_item(item, serializer);
+    }
   }
 
   @protected
-  WorkspaceNodeItem? dco_decode_opt_box_autoadd_workspace_node_item(
-    dynamic raw,
+  void sse_encode_list_calendar_conflict_item(
+    List<CalendarConflictItem> self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_workspace_node_item(raw);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_calendar_conflict_item(item, serializer);
+    }
   }
 
   @protected
-  TagsListResponse dco_decode_tags_list_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return TagsListResponse(
-      ok: dco_decode_bool(arr[0]),
-      errorCode: dco_decode_opt_String(arr[1]),
-      message: dco_decode_String(arr[2]),
-      tags: dco_decode_list_String(arr[3]),
-    );
+  void sse_encode_list_calendar_day_item(
+    List<CalendarDayItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_calendar_day_item(item, serializer);
+    }
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_calendar_entry_item(
+    List<CalendarEntryItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_calendar_entry_item(item, serializer);
+    }
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_calendar_free_slot_item(
+    List<CalendarFreeSlotItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_calendar_free_slot_item(item, serializer);
+    }
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_list_due_reminder_item(
+    List<DueReminderItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_due_reminder_item(item, serializer);
+    }
   }
 
   @protected
-  WorkspaceActionResponse dco_decode_workspace_action_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return WorkspaceActionResponse(
-      ok: dco_decode_bool(arr[0]),
-      errorCode: dco_decode_opt_String(arr[1]),
-      message: dco_decode_String(arr[2]),
-    );
+  void sse_encode_list_entry_quick_add_token(
+    List<EntryQuickAddToken> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_entry_quick_add_token(item, serializer);
+    }
   }
 
   @protected
-  WorkspaceListChildrenResponse dco_decode_workspace_list_children_response(
-    dynamic raw,
+  void sse_encode_list_entry_search_highlight(
+    List<EntrySearchHighlight> self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return WorkspaceListChildrenResponse(
-      ok: dco_decode_bool(arr[0]),
-      errorCode: dco_decode_opt_String(arr[1]),
-      message: dco_decode_String(arr[2]),
-      items: dco_decode_list_workspace_node_item(arr[3]),
-    );
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_entry_search_highlight(item, serializer);
+    }
   }
 
   @protected
-  WorkspaceNodeItem dco_decode_workspace_node_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return WorkspaceNodeItem(
-      nodeId: dco_decode_String(arr[0]),
-      kind: dco_decode_String(arr[1]),
-      parentNodeId: dco_decode_opt_String(arr[2]),
-      atomId: dco_decode_opt_String(arr[3]),
-      displayName: dco_decode_String(arr[4]),
-      sortOrder: dco_decode_i_64(arr[5]),
-    );
+  void sse_encode_list_entry_search_hit_item(
+    List<EntrySearchHitItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_entry_search_hit_item(item, serializer);
+    }
   }
 
   @protected
-  WorkspaceNodeResponse dco_decode_workspace_node_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return WorkspaceNodeResponse(
-      ok: dco_decode_bool(arr[0]),
-      errorCode: dco_decode_opt_String(arr[1]),
-      message: dco_decode_String(arr[2]),
-      node: dco_decode_opt_box_autoadd_workspace_node_item(arr[3]),
-    );
+  void sse_encode_list_entry_search_item(
+    List<EntrySearchItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_entry_search_item(item, serializer);
+    }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_list_launch_plan_item(
+    List<LaunchPlanItem> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_launch_plan_item(item, serializer);
+    }
   }
 
   @protected
-  AtomListItem sse_decode_atom_list_item(SseDeserializer deserializer) {
+  void sse_encode_list_launch_rejection_item(
+    List<LaunchRejectionItem> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_atomId = sse_decode_String(deserializer);
-    var var_kind = sse_decode_String(deserializer);
-    var var_content = sse_decode_String(deserializer);
-    var var_previewText = sse_decode_opt_String(deserializer);
-    var var_previewImage = sse_decode_opt_String(deserializer);
-    var var_tags = sse_decode_list_String(deserializer);
-    var var_startAt = sse_decode_opt_box_autoadd_i_64(deserializer);
-    var var_endAt = sse_decode_opt_box_autoadd_i_64(deserializer);
-    var var_taskStatus = sse_decode_opt_String(deserializer);
-    var var_updatedAt = sse_decode_i_64(deserializer);
-    return AtomListItem(
-      atomId: var_atomId,
-      kind: var_kind,
-      content: var_content,
-      previewText: var_previewText,
-      previewImage: var_previewImage,
-      tags: var_tags,
-      startAt: var_startAt,
-      endAt: var_endAt,
-      taskStatus: var_taskStatus,
-      updatedAt: var_updatedAt,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_launch_rejection_item(item, serializer);
+    }
   }
 
   @protected
-  AtomListResponse sse_decode_atom_list_response(SseDeserializer deserializer) {
+  void sse_encode_list_note_diff_line_item(
+    List<NoteDiffLineItem> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_ok = sse_decode_bool(deserializer);
-    var var_errorCode = sse_decode_opt_String(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    var var_items = sse_decode_list_atom_list_item(deserializer);
-    var var_appliedLimit = sse_decode_u_32(deserializer);
-    return AtomListResponse(
-      ok: var_ok,
-      errorCode: var_errorCode,
-      message: var_message,
-      items: var_items,
-      appliedLimit: var_appliedLimit,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_note_diff_line_item(item, serializer);
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_list_note_item(
+    List<NoteItem> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_note_item(item, serializer);
+    }
   }
 
   @protected
-  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+  void sse_encode_list_note_link_item(
+    List<NoteLinkItem> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_64(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_note_link_item(item, serializer);
+    }
   }
 
   @protected
-  NoteItem sse_decode_box_autoadd_note_item(SseDeserializer deserializer) {
+  void sse_encode_list_note_outline_heading(
+    List<NoteOutlineHeading> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_note_item(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_note_outline_heading(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_list_note_property_item(
+    List<NotePropertyItem> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_32(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_note_property_item(item, serializer);
+    }
   }
 
   @protected
-  WorkspaceNodeItem sse_decode_box_autoadd_workspace_node_item(
-    SseDeserializer deserializer,
+  void sse_encode_list_note_revision_item(
+    List<NoteRevisionItem> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_workspace_node_item(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_note_revision_item(item, serializer);
+    }
   }
 
   @protected
-  EntryActionResponse sse_decode_entry_action_response(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_ok = sse_decode_bool(deserializer);
-    var var_atomId = sse_decode_opt_String(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    return EntryActionResponse(
-      ok: var_ok,
-      atomId: var_atomId,
-      message: var_message,
-    );
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  EntrySearchItem sse_decode_entry_search_item(SseDeserializer deserializer) {
+  void sse_encode_list_reminder_item(
+    List<ReminderItem> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_atomId = sse_decode_String(deserializer);
-    var var_kind = sse_decode_String(deserializer);
-    var var_snippet = sse_decode_String(deserializer);
-    return EntrySearchItem(
-      atomId: var_atomId,
-      kind: var_kind,
-      snippet: var_snippet,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_reminder_item(item, serializer);
+    }
   }
 
   @protected
-  EntrySearchResponse sse_decode_entry_search_response(
-    SseDeserializer deserializer,
+  void sse_encode_list_resource_link_item(
+    List<ResourceLinkItem> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_ok = sse_decode_bool(deserializer);
-    var var_errorCode = sse_decode_opt_String(deserializer);
-    var var_items = sse_decode_list_entry_search_item(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    var var_appliedLimit = sse_decode_u_32(deserializer);
-    return EntrySearchResponse(
-      ok: var_ok,
-      errorCode: var_errorCode,
-      items: var_items,
-      message: var_message,
-      appliedLimit: var_appliedLimit,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_resource_link_item(item, serializer);
+    }
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_list_tag_usage_item(
+    List<TagUsageItem> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_tag_usage_item(item, serializer);
+    }
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_list_time_day_total_item(
+    List<TimeDayTotalItem> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_time_day_total_item(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<AtomListItem> sse_decode_list_atom_list_item(
-    SseDeserializer deserializer,
+  void sse_encode_list_time_entry_item(
+    List<TimeEntryItem> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AtomListItem>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_atom_list_item(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_time_entry_item(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<EntrySearchItem> sse_decode_list_entry_search_item(
-    SseDeserializer deserializer,
+  void sse_encode_list_time_folder_total_item(
+    List<TimeFolderTotalItem> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EntrySearchItem>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_entry_search_item(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_time_folder_total_item(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<NoteItem> sse_decode_list_note_item(SseDeserializer deserializer) {
+  void sse_encode_list_time_tag_total_item(
+    List<TimeTagTotalItem> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NoteItem>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_note_item(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_time_tag_total_item(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_list_trash_list_item(
+    List<TrashListItem> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_trash_list_item(item, serializer);
+    }
   }
 
   @protected
-  List<WorkspaceNodeItem> sse_decode_list_workspace_node_item(
-    SseDeserializer deserializer,
+  void sse_encode_list_workspace_node_item(
+    List<WorkspaceNodeItem> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WorkspaceNodeItem>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_workspace_node_item(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_workspace_node_item(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  LogDartEventResponse sse_decode_log_dart_event_response(
-    SseDeserializer deserializer,
+  void sse_encode_local_time_windows_response(
+    LocalTimeWindowsResponse self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_ok = sse_decode_bool(deserializer);
-    var var_errorCode = sse_decode_opt_String(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    return LogDartEventResponse(
-      ok: var_ok,
-      errorCode: var_errorCode,
-      message: var_message,
-    );
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_String(self.timeZone, serializer);
+    sse_encode_String(self.weekStart, serializer);
+    sse_encode_i_64(self.dayStartMs, serializer);
+    sse_encode_i_64(self.dayEndMs, serializer);
+    sse_encode_i_64(self.weekStartMs, serializer);
+    sse_encode_i_64(self.weekEndMs, serializer);
+    sse_encode_i_64(self.monthStartMs, serializer);
+    sse_encode_i_64(self.monthEndMs, serializer);
   }
 
   @protected
-  NoteItem sse_decode_note_item(SseDeserializer deserializer) {
+  void sse_encode_log_dart_event_response(
+    LogDartEventResponse self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_atomId = sse_decode_String(deserializer);
-    var var_content = sse_decode_String(deserializer);
-    var var_previewText = sse_decode_opt_String(deserializer);
-    var var_previewImage = sse_decode_opt_String(deserializer);
-    var var_updatedAt = sse_decode_i_64(deserializer);
-    var var_tags = sse_decode_list_String(deserializer);
-    return NoteItem(
-      atomId: var_atomId,
-      content: var_content,
-      previewText: var_previewText,
-      previewImage: var_previewImage,
-      updatedAt: var_updatedAt,
-      tags: var_tags,
-    );
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  NoteResponse sse_decode_note_response(SseDeserializer deserializer) {
+  void sse_encode_note_diff_line_item(
+    NoteDiffLineItem self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_ok = sse_decode_bool(deserializer);
-    var var_errorCode = sse_decode_opt_String(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    var var_note = sse_decode_opt_box_autoadd_note_item(deserializer);
-    return NoteResponse(
-      ok: var_ok,
-      errorCode: var_errorCode,
-      message: var_message,
-      note: var_note,
-    );
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.oldLine, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.newLine, serializer);
   }
 
   @protected
-  NotesListResponse sse_decode_notes_list_response(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_note_item(NoteItem self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_ok = sse_decode_bool(deserializer);
-    var var_errorCode = sse_decode_opt_String(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    var var_items = sse_decode_list_note_item(deserializer);
-    var var_appliedLimit = sse_decode_u_32(deserializer);
-    return NotesListResponse(
-      ok: var_ok,
-      errorCode: var_errorCode,
-      message: var_message,
-      items: var_items,
-      appliedLimit: var_appliedLimit,
-    );
+    sse_encode_String(self.atomId, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_opt_String(self.previewText, serializer);
+    sse_encode_opt_String(self.previewImage, serializer);
+    sse_encode_i_64(self.updatedAt, serializer);
+    sse_encode_list_String(self.tags, serializer);
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_note_link_item(NoteLinkItem self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_String(self.sourceAtomId, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_opt_String(self.label, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_opt_String(self.targetAtomId, serializer);
   }
 
   @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+  void sse_encode_note_links_response(
+    NoteLinksResponse self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_64(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_note_link_item(self.outgoing, serializer);
+    sse_encode_list_note_link_item(self.backlinks, serializer);
   }
 
   @protected
-  NoteItem? sse_decode_opt_box_autoadd_note_item(SseDeserializer deserializer) {
+  void sse_encode_note_outline_heading(
+    NoteOutlineHeading self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_note_item(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_u_32(self.level, serializer);
+    sse_encode_u_32(self.depth, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.parentIndex, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_u_32(self.byteStart, serializer);
+    sse_encode_u_32(self.byteEnd, serializer);
   }
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_note_outline_response(
+    NoteOutlineResponse self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_note_outline_heading(self.headings, serializer);
+    sse_encode_u_32(self.wordCount, serializer);
+    sse_encode_u_32(self.charCount, serializer);
+    sse_encode_u_32(self.checklistDone, serializer);
+    sse_encode_u_32(self.checklistTotal, serializer);
   }
 
   @protected
-  WorkspaceNodeItem? sse_decode_opt_box_autoadd_workspace_node_item(
-    SseDeserializer deserializer,
+  void sse_encode_note_properties_response(
+    NotePropertiesResponse self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_workspace_node_item(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_note_property_item(self.items, serializer);
   }
 
   @protected
-  TagsListResponse sse_decode_tags_list_response(SseDeserializer deserializer) {
+  void sse_encode_note_property_item(
+    NotePropertyItem self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_ok = sse_decode_bool(deserializer);
-    var var_errorCode = sse_decode_opt_String(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    var var_tags = sse_decode_list_String(deserializer);
-    return TagsListResponse(
-      ok: var_ok,
-      errorCode: var_errorCode,
-      message: var_message,
-      tags: var_tags,
-    );
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.valueType, serializer);
+    sse_encode_opt_String(self.text, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.number, serializer);
+    sse_encode_list_String(self.items, serializer);
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_note_response(NoteResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_box_autoadd_note_item(self.note, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_note_revision_diff_response(
+    NoteRevisionDiffResponse self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_note_diff_line_item(self.lines, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_note_revision_item(
+    NoteRevisionItem self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.revisionId, serializer);
+    sse_encode_String(self.atomId, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_opt_String(self.previewText, serializer);
+    sse_encode_i_64(self.sourceUpdatedAt, serializer);
+    sse_encode_i_64(self.capturedAt, serializer);
   }
 
   @protected
-  WorkspaceActionResponse sse_decode_workspace_action_response(
-    SseDeserializer deserializer,
+  void sse_encode_note_revisions_list_response(
+    NoteRevisionsListResponse self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_ok = sse_decode_bool(deserializer);
-    var var_errorCode = sse_decode_opt_String(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    return WorkspaceActionResponse(
-      ok: var_ok,
-      errorCode: var_errorCode,
-      message: var_message,
-    );
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_note_revision_item(self.items, serializer);
+    sse_encode_u_32(self.appliedLimit, serializer);
   }
 
   @protected
-  WorkspaceListChildrenResponse sse_decode_workspace_list_children_response(
-    SseDeserializer deserializer,
+  void sse_encode_note_tag_sources_response(
+    NoteTagSourcesResponse self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_ok = sse_decode_bool(deserializer);
-    var var_errorCode = sse_decode_opt_String(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    var var_items = sse_decode_list_workspace_node_item(deserializer);
-    return WorkspaceListChildrenResponse(
-      ok: var_ok,
-      errorCode: var_errorCode,
-      message: var_message,
-      items: var_items,
-    );
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_String(self.manualTags, serializer);
+    sse_encode_list_String(self.inlineTags, serializer);
   }
 
   @protected
-  WorkspaceNodeItem sse_decode_workspace_node_item(
-    SseDeserializer deserializer,
+  void sse_encode_note_title_response(
+    NoteTitleResponse self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_nodeId = sse_decode_String(deserializer);
-    var var_kind = sse_decode_String(deserializer);
-    var var_parentNodeId = sse_decode_opt_String(deserializer);
-    var var_atomId = sse_decode_opt_String(deserializer);
-    var var_displayName = sse_decode_String(deserializer);
-    var var_sortOrder = sse_decode_i_64(deserializer);
-    return WorkspaceNodeItem(
-      nodeId: var_nodeId,
-      kind: var_kind,
-      parentNodeId: var_parentNodeId,
-      atomId: var_atomId,
-      displayName: var_displayName,
-      sortOrder: var_sortOrder,
-    );
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.derivedTitle, serializer);
+    sse_encode_opt_String(self.titleOverride, serializer);
   }
 
   @protected
-  WorkspaceNodeResponse sse_decode_workspace_node_response(
-    SseDeserializer deserializer,
+  void sse_encode_notes_list_response(
+    NotesListResponse self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_ok = sse_decode_bool(deserializer);
-    var var_errorCode = sse_decode_opt_String(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    var var_node = sse_decode_opt_box_autoadd_workspace_node_item(deserializer);
-    return WorkspaceNodeResponse(
-      ok: var_ok,
-      errorCode: var_errorCode,
-      message: var_message,
-      node: var_node,
-    );
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_note_item(self.items, serializer);
+    sse_encode_u_32(self.appliedLimit, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_attachment_item(
+    AttachmentItem? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_attachment_item(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_atom_list_item(AtomListItem self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.atomId, serializer);
-    sse_encode_String(self.kind, serializer);
-    sse_encode_String(self.content, serializer);
-    sse_encode_opt_String(self.previewText, serializer);
-    sse_encode_opt_String(self.previewImage, serializer);
-    sse_encode_list_String(self.tags, serializer);
-    sse_encode_opt_box_autoadd_i_64(self.startAt, serializer);
-    sse_encode_opt_box_autoadd_i_64(self.endAt, serializer);
-    sse_encode_opt_String(self.taskStatus, serializer);
-    sse_encode_i_64(self.updatedAt, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_atom_list_response(
-    AtomListResponse self,
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.ok, serializer);
-    sse_encode_opt_String(self.errorCode, serializer);
-    sse_encode_String(self.message, serializer);
-    sse_encode_list_atom_list_item(self.items, serializer);
-    sse_encode_u_32(self.appliedLimit, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_note_item(
+    NoteItem? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_note_item(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_i_64(
-    PlatformInt64 self,
+  void sse_encode_opt_box_autoadd_reminder_item(
+    ReminderItem? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_reminder_item(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_note_item(
-    NoteItem self,
+  void sse_encode_opt_box_autoadd_time_entry_item(
+    TimeEntryItem? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_note_item(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_time_entry_item(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_workspace_node_item(
-    WorkspaceNodeItem self,
+  void sse_encode_opt_box_autoadd_workspace_node_item(
+    WorkspaceNodeItem? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_workspace_node_item(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_workspace_node_item(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_entry_action_response(
-    EntryActionResponse self,
+  void sse_encode_recurrence_response(
+    RecurrenceResponse self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_bool(self.ok, serializer);
-    sse_encode_opt_String(self.atomId, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
     sse_encode_String(self.message, serializer);
+    sse_encode_opt_String(self.atomId, serializer);
+    sse_encode_opt_String(self.recurrenceRule, serializer);
   }
 
   @protected
-  void sse_encode_entry_search_item(
-    EntrySearchItem self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_reminder_item(ReminderItem self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.reminderId, serializer);
     sse_encode_String(self.atomId, serializer);
-    sse_encode_String(self.kind, serializer);
-    sse_encode_String(self.snippet, serializer);
+    sse_encode_String(self.anchor, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.remindAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.offsetMs, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.ackedThrough, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.snoozedUntil, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.nextFireAt, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
   }
 
   @protected
-  void sse_encode_entry_search_response(
-    EntrySearchResponse self,
+  void sse_encode_reminder_list_response(
+    ReminderListResponse self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_bool(self.ok, serializer);
     sse_encode_opt_String(self.errorCode, serializer);
-    sse_encode_list_entry_search_item(self.items, serializer);
     sse_encode_String(self.message, serializer);
-    sse_encode_u_32(self.appliedLimit, serializer);
+    sse_encode_list_reminder_item(self.items, serializer);
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_reminder_response(
+    ReminderResponse self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_box_autoadd_reminder_item(self.reminder, serializer);
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_resource_link_item(
+    ResourceLinkItem self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
+    sse_encode_i_64(self.linkId, serializer);
+    sse_encode_String(self.atomId, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_String(self.scheme, serializer);
+    sse_encode_opt_String(self.label, serializer);
   }
 
   @protected
-  void sse_encode_list_atom_list_item(
-    List<AtomListItem> self,
+  void sse_encode_resource_links_response(
+    ResourceLinksResponse self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_atom_list_item(item, serializer);
-    }
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_resource_link_item(self.items, serializer);
   }
 
   @protected
-  void sse_encode_list_entry_search_item(
-    List<EntrySearchItem> self,
+  void sse_encode_subtask_response(
+    SubtaskResponse self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_entry_search_item(item, serializer);
-    }
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_String(self.atomId, serializer);
+    sse_encode_opt_String(self.parentId, serializer);
+    sse_encode_list_String(self.subtaskIds, serializer);
+    sse_encode_u_32(self.done, serializer);
+    sse_encode_u_32(self.total, serializer);
   }
 
   @protected
-  void sse_encode_list_note_item(
-    List<NoteItem> self,
+  void sse_encode_tag_edit_response(
+    TagEditResponse self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_note_item(item, serializer);
-    }
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_u_32(self.affectedAtoms, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_tag_usage_item(TagUsageItem self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_32(self.directCount, serializer);
+    sse_encode_u_32(self.totalCount, serializer);
+  }
+
+  @protected
+  void sse_encode_tags_list_response(
+    TagsListResponse self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_String(self.tags, serializer);
   }
 
   @protected
-  void sse_encode_list_workspace_node_item(
-    List<WorkspaceNodeItem> self,
+  void sse_encode_tags_usage_response(
+    TagsUsageResponse self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_workspace_node_item(item, serializer);
-    }
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_tag_usage_item(self.items, serializer);
   }
 
   @protected
-  void sse_encode_log_dart_event_response(
-    LogDartEventResponse self,
+  void sse_encode_task_dependency_response(
+    TaskDependencyResponse self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_bool(self.ok, serializer);
     sse_encode_opt_String(self.errorCode, serializer);
     sse_encode_String(self.message, serializer);
+    sse_encode_opt_String(self.atomId, serializer);
+    sse_encode_bool(self.blocked, serializer);
+    sse_encode_list_String(self.prerequisiteIds, serializer);
+    sse_encode_list_String(self.dependentIds, serializer);
   }
 
   @protected
-  void sse_encode_note_item(NoteItem self, SseSerializer serializer) {
+  void sse_encode_time_day_total_item(
+    TimeDayTotalItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.date, serializer);
+    sse_encode_i_64(self.totalMs, serializer);
+  }
+
+  @protected
+  void sse_encode_time_entry_item(
+    TimeEntryItem self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.entryId, serializer);
     sse_encode_String(self.atomId, serializer);
-    sse_encode_String(self.content, serializer);
-    sse_encode_opt_String(self.previewText, serializer);
-    sse_encode_opt_String(self.previewImage, serializer);
-    sse_encode_i_64(self.updatedAt, serializer);
-    sse_encode_list_String(self.tags, serializer);
+    sse_encode_String(self.deviceId, serializer);
+    sse_encode_i_64(self.startedAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.endedAt, serializer);
+    sse_encode_opt_String(self.note, serializer);
+    sse_encode_bool(self.running, serializer);
+    sse_encode_i_64(self.durationMs, serializer);
   }
 
   @protected
-  void sse_encode_note_response(NoteResponse self, SseSerializer serializer) {
+  void sse_encode_time_entry_list_response(
+    TimeEntryListResponse self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_bool(self.ok, serializer);
     sse_encode_opt_String(self.errorCode, serializer);
     sse_encode_String(self.message, serializer);
-    sse_encode_opt_box_autoadd_note_item(self.note, serializer);
+    sse_encode_list_time_entry_item(self.items, serializer);
+    sse_encode_i_64(self.totalMs, serializer);
   }
 
   @protected
-  void sse_encode_notes_list_response(
-    NotesListResponse self,
+  void sse_encode_time_entry_response(
+    TimeEntryResponse self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_bool(self.ok, serializer);
     sse_encode_opt_String(self.errorCode, serializer);
     sse_encode_String(self.message, serializer);
-    sse_encode_list_note_item(self.items, serializer);
-    sse_encode_u_32(self.appliedLimit, serializer);
+    sse_encode_opt_box_autoadd_time_entry_item(self.entry, serializer);
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_time_folder_total_item(
+    TimeFolderTotalItem self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+    sse_encode_String(self.folderId, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_i_64(self.totalMs, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_i_64(
-    PlatformInt64? self,
+  void sse_encode_time_report_response(
+    TimeReportResponse self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_64(self, serializer);
-    }
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_time_day_total_item(self.days, serializer);
+    sse_encode_list_time_tag_total_item(self.tags, serializer);
+    sse_encode_list_time_folder_total_item(self.folders, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_note_item(
-    NoteItem? self,
+  void sse_encode_time_tag_total_item(
+    TimeTagTotalItem self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_note_item(self, serializer);
-    }
+    sse_encode_String(self.tag, serializer);
+    sse_encode_i_64(self.totalMs, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+  void sse_encode_trash_list_item(
+    TrashListItem self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_32(self, serializer);
-    }
+    sse_encode_String(self.atomId, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_opt_String(self.previewText, serializer);
+    sse_encode_i_64(self.deletedAt, serializer);
+    sse_encode_bool(self.syncPending, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_workspace_node_item(
-    WorkspaceNodeItem? self,
+  void sse_encode_trash_list_response(
+    TrashListResponse self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_trash_list_item(self.items, serializer);
+    sse_encode_u_32(self.appliedLimit, serializer);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_workspace_node_item(self, serializer);
-    }
+  @protected
+  void sse_encode_trash_purge_response(
+    TrashPurgeResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_u_32(self.purged, serializer);
+    sse_encode_u_32(self.keptForSync, serializer);
   }
 
   @protected
-  void sse_encode_tags_list_response(
-    TagsListResponse self,
+  void sse_encode_trash_restore_response(
+    TrashRestoreResponse self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_bool(self.ok, serializer);
     sse_encode_opt_String(self.errorCode, serializer);
     sse_encode_String(self.message, serializer);
-    sse_encode_list_String(self.tags, serializer);
+    sse_encode_opt_String(self.atomId, serializer);
+    sse_encode_opt_String(self.noteRefNodeId, serializer);
   }
 
   @protected
@@ -2163,6 +8959,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint32(self);
   }
 
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2223,6 +9025,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_workspace_node_item(self.node, serializer);
   }
 
+  @protected
+  void sse_encode_workspace_repair_response(
+    WorkspaceRepairResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_u_32(self.renamedCount, serializer);
+  }
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
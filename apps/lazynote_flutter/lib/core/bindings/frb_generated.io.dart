@@ -22,384 +22,1663 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AtomConvertResponse dco_decode_atom_convert_response(dynamic raw);
+
   @protected
   AtomListItem dco_decode_atom_list_item(dynamic raw);
 
   @protected
   AtomListResponse dco_decode_atom_list_response(dynamic raw);
 
+  @protected
+  AtomScheduleResponse dco_decode_atom_schedule_response(dynamic raw);
+
+  @protected
+  AtomTaskMetaResponse dco_decode_atom_task_meta_response(dynamic raw);
+
+  @protected
+  AttachmentGcResponse dco_decode_attachment_gc_response(dynamic raw);
+
+  @protected
+  AttachmentImportResponse dco_decode_attachment_import_response(dynamic raw);
+
+  @protected
+  AttachmentItem dco_decode_attachment_item(dynamic raw);
+
+  @protected
+  AttachmentResolveResponse dco_decode_attachment_resolve_response(dynamic raw);
+
+  @protected
+  AttachmentsListResponse dco_decode_attachments_list_response(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  AttachmentItem dco_decode_box_autoadd_attachment_item(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
   @protected
   PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
   @protected
   NoteItem dco_decode_box_autoadd_note_item(dynamic raw);
 
+  @protected
+  ReminderItem dco_decode_box_autoadd_reminder_item(dynamic raw);
+
+  @protected
+  TimeEntryItem dco_decode_box_autoadd_time_entry_item(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
   @protected
   WorkspaceNodeItem dco_decode_box_autoadd_workspace_node_item(dynamic raw);
 
+  @protected
+  CalendarConflictItem dco_decode_calendar_conflict_item(dynamic raw);
+
+  @protected
+  CalendarConflictsResponse dco_decode_calendar_conflicts_response(dynamic raw);
+
+  @protected
+  CalendarDayItem dco_decode_calendar_day_item(dynamic raw);
+
+  @protected
+  CalendarDaysResponse dco_decode_calendar_days_response(dynamic raw);
+
+  @protected
+  CalendarEntryItem dco_decode_calendar_entry_item(dynamic raw);
+
+  @protected
+  CalendarFreeSlotItem dco_decode_calendar_free_slot_item(dynamic raw);
+
+  @protected
+  CalendarFreeSlotsResponse dco_decode_calendar_free_slots_response(
+    dynamic raw,
+  );
+
+  @protected
+  DueReminderItem dco_decode_due_reminder_item(dynamic raw);
+
+  @protected
+  DueRemindersResponse dco_decode_due_reminders_response(dynamic raw);
+
   @protected
   EntryActionResponse dco_decode_entry_action_response(dynamic raw);
 
+  @protected
+  EntryQuickAddResponse dco_decode_entry_quick_add_response(dynamic raw);
+
+  @protected
+  EntryQuickAddToken dco_decode_entry_quick_add_token(dynamic raw);
+
+  @protected
+  EntrySearchHighlight dco_decode_entry_search_highlight(dynamic raw);
+
+  @protected
+  EntrySearchHitItem dco_decode_entry_search_hit_item(dynamic raw);
+
   @protected
   EntrySearchItem dco_decode_entry_search_item(dynamic raw);
 
+  @protected
+  EntrySearchPageResponse dco_decode_entry_search_page_response(dynamic raw);
+
   @protected
   EntrySearchResponse dco_decode_entry_search_response(dynamic raw);
 
+  @protected
+  EntrySearchSyntaxResponse dco_decode_entry_search_syntax_response(
+    dynamic raw,
+  );
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw);
 
+  @protected
+  LaunchPlanItem dco_decode_launch_plan_item(dynamic raw);
+
+  @protected
+  LaunchPlanResponse dco_decode_launch_plan_response(dynamic raw);
+
+  @protected
+  LaunchRejectionItem dco_decode_launch_rejection_item(dynamic raw);
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
   @protected
   List<AtomListItem> dco_decode_list_atom_list_item(dynamic raw);
 
+  @protected
+  List<AttachmentItem> dco_decode_list_attachment_item(dynamic raw);
+
+  @protected
+  List<CalendarConflictItem> dco_decode_list_calendar_conflict_item(
+    dynamic raw,
+  );
+
+  @protected
+  List<CalendarDayItem> dco_decode_list_calendar_day_item(dynamic raw);
+
+  @protected
+  List<CalendarEntryItem> dco_decode_list_calendar_entry_item(dynamic raw);
+
+  @protected
+  List<CalendarFreeSlotItem> dco_decode_list_calendar_free_slot_item(
+    dynamic raw,
+  );
+
+  @protected
+  List<DueReminderItem> dco_decode_list_due_reminder_item(dynamic raw);
+
+  @protected
+  List<EntryQuickAddToken> dco_decode_list_entry_quick_add_token(dynamic raw);
+
+  @protected
+  List<EntrySearchHighlight> dco_decode_list_entry_search_highlight(
+    dynamic raw,
+  );
+
+  @protected
+  List<EntrySearchHitItem> dco_decode_list_entry_search_hit_item(dynamic raw);
+
   @protected
   List<EntrySearchItem> dco_decode_list_entry_search_item(dynamic raw);
 
   @protected
-  List<NoteItem> dco_decode_list_note_item(dynamic raw);
+  List<LaunchPlanItem> dco_decode_list_launch_plan_item(dynamic raw);
+
+  @protected
+  List<LaunchRejectionItem> dco_decode_list_launch_rejection_item(dynamic raw);
+
+  @protected
+  List<NoteDiffLineItem> dco_decode_list_note_diff_line_item(dynamic raw);
+
+  @protected
+  List<NoteItem> dco_decode_list_note_item(dynamic raw);
+
+  @protected
+  List<NoteLinkItem> dco_decode_list_note_link_item(dynamic raw);
+
+  @protected
+  List<NoteOutlineHeading> dco_decode_list_note_outline_heading(dynamic raw);
+
+  @protected
+  List<NotePropertyItem> dco_decode_list_note_property_item(dynamic raw);
+
+  @protected
+  List<NoteRevisionItem> dco_decode_list_note_revision_item(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<ReminderItem> dco_decode_list_reminder_item(dynamic raw);
+
+  @protected
+  List<ResourceLinkItem> dco_decode_list_resource_link_item(dynamic raw);
+
+  @protected
+  List<TagUsageItem> dco_decode_list_tag_usage_item(dynamic raw);
+
+  @protected
+  List<TimeDayTotalItem> dco_decode_list_time_day_total_item(dynamic raw);
+
+  @protected
+  List<TimeEntryItem> dco_decode_list_time_entry_item(dynamic raw);
+
+  @protected
+  List<TimeFolderTotalItem> dco_decode_list_time_folder_total_item(dynamic raw);
+
+  @protected
+  List<TimeTagTotalItem> dco_decode_list_time_tag_total_item(dynamic raw);
+
+  @protected
+  List<TrashListItem> dco_decode_list_trash_list_item(dynamic raw);
+
+  @protected
+  List<WorkspaceNodeItem> dco_decode_list_workspace_node_item(dynamic raw);
+
+  @protected
+  LocalTimeWindowsResponse dco_decode_local_time_windows_response(dynamic raw);
+
+  @protected
+  LogDartEventResponse dco_decode_log_dart_event_response(dynamic raw);
+
+  @protected
+  NoteDiffLineItem dco_decode_note_diff_line_item(dynamic raw);
+
+  @protected
+  NoteItem dco_decode_note_item(dynamic raw);
+
+  @protected
+  NoteLinkItem dco_decode_note_link_item(dynamic raw);
+
+  @protected
+  NoteLinksResponse dco_decode_note_links_response(dynamic raw);
+
+  @protected
+  NoteOutlineHeading dco_decode_note_outline_heading(dynamic raw);
+
+  @protected
+  NoteOutlineResponse dco_decode_note_outline_response(dynamic raw);
+
+  @protected
+  NotePropertiesResponse dco_decode_note_properties_response(dynamic raw);
+
+  @protected
+  NotePropertyItem dco_decode_note_property_item(dynamic raw);
+
+  @protected
+  NoteResponse dco_decode_note_response(dynamic raw);
+
+  @protected
+  NoteRevisionDiffResponse dco_decode_note_revision_diff_response(dynamic raw);
+
+  @protected
+  NoteRevisionItem dco_decode_note_revision_item(dynamic raw);
+
+  @protected
+  NoteRevisionsListResponse dco_decode_note_revisions_list_response(
+    dynamic raw,
+  );
+
+  @protected
+  NoteTagSourcesResponse dco_decode_note_tag_sources_response(dynamic raw);
+
+  @protected
+  NoteTitleResponse dco_decode_note_title_response(dynamic raw);
+
+  @protected
+  NotesListResponse dco_decode_notes_list_response(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  AttachmentItem? dco_decode_opt_box_autoadd_attachment_item(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  NoteItem? dco_decode_opt_box_autoadd_note_item(dynamic raw);
+
+  @protected
+  ReminderItem? dco_decode_opt_box_autoadd_reminder_item(dynamic raw);
+
+  @protected
+  TimeEntryItem? dco_decode_opt_box_autoadd_time_entry_item(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  WorkspaceNodeItem? dco_decode_opt_box_autoadd_workspace_node_item(
+    dynamic raw,
+  );
+
+  @protected
+  RecurrenceResponse dco_decode_recurrence_response(dynamic raw);
+
+  @protected
+  ReminderItem dco_decode_reminder_item(dynamic raw);
+
+  @protected
+  ReminderListResponse dco_decode_reminder_list_response(dynamic raw);
+
+  @protected
+  ReminderResponse dco_decode_reminder_response(dynamic raw);
+
+  @protected
+  ResourceLinkItem dco_decode_resource_link_item(dynamic raw);
+
+  @protected
+  ResourceLinksResponse dco_decode_resource_links_response(dynamic raw);
+
+  @protected
+  SubtaskResponse dco_decode_subtask_response(dynamic raw);
+
+  @protected
+  TagEditResponse dco_decode_tag_edit_response(dynamic raw);
+
+  @protected
+  TagUsageItem dco_decode_tag_usage_item(dynamic raw);
+
+  @protected
+  TagsListResponse dco_decode_tags_list_response(dynamic raw);
+
+  @protected
+  TagsUsageResponse dco_decode_tags_usage_response(dynamic raw);
+
+  @protected
+  TaskDependencyResponse dco_decode_task_dependency_response(dynamic raw);
+
+  @protected
+  TimeDayTotalItem dco_decode_time_day_total_item(dynamic raw);
+
+  @protected
+  TimeEntryItem dco_decode_time_entry_item(dynamic raw);
+
+  @protected
+  TimeEntryListResponse dco_decode_time_entry_list_response(dynamic raw);
+
+  @protected
+  TimeEntryResponse dco_decode_time_entry_response(dynamic raw);
+
+  @protected
+  TimeFolderTotalItem dco_decode_time_folder_total_item(dynamic raw);
+
+  @protected
+  TimeReportResponse dco_decode_time_report_response(dynamic raw);
+
+  @protected
+  TimeTagTotalItem dco_decode_time_tag_total_item(dynamic raw);
+
+  @protected
+  TrashListItem dco_decode_trash_list_item(dynamic raw);
+
+  @protected
+  TrashListResponse dco_decode_trash_list_response(dynamic raw);
+
+  @protected
+  TrashPurgeResponse dco_decode_trash_purge_response(dynamic raw);
+
+  @protected
+  TrashRestoreResponse dco_decode_trash_restore_response(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  WorkspaceActionResponse dco_decode_workspace_action_response(dynamic raw);
+
+  @protected
+  WorkspaceListChildrenResponse dco_decode_workspace_list_children_response(
+    dynamic raw,
+  );
+
+  @protected
+  WorkspaceNodeItem dco_decode_workspace_node_item(dynamic raw);
+
+  @protected
+  WorkspaceNodeResponse dco_decode_workspace_node_response(dynamic raw);
+
+  @protected
+  WorkspaceRepairResponse dco_decode_workspace_repair_response(dynamic raw);
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AtomConvertResponse sse_decode_atom_convert_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AtomListItem sse_decode_atom_list_item(SseDeserializer deserializer);
+
+  @protected
+  AtomListResponse sse_decode_atom_list_response(SseDeserializer deserializer);
+
+  @protected
+  AtomScheduleResponse sse_decode_atom_schedule_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AtomTaskMetaResponse sse_decode_atom_task_meta_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AttachmentGcResponse sse_decode_attachment_gc_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AttachmentImportResponse sse_decode_attachment_import_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AttachmentItem sse_decode_attachment_item(SseDeserializer deserializer);
+
+  @protected
+  AttachmentResolveResponse sse_decode_attachment_resolve_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AttachmentsListResponse sse_decode_attachments_list_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  AttachmentItem sse_decode_box_autoadd_attachment_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  NoteItem sse_decode_box_autoadd_note_item(SseDeserializer deserializer);
+
+  @protected
+  ReminderItem sse_decode_box_autoadd_reminder_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TimeEntryItem sse_decode_box_autoadd_time_entry_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  WorkspaceNodeItem sse_decode_box_autoadd_workspace_node_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CalendarConflictItem sse_decode_calendar_conflict_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CalendarConflictsResponse sse_decode_calendar_conflicts_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CalendarDayItem sse_decode_calendar_day_item(SseDeserializer deserializer);
+
+  @protected
+  CalendarDaysResponse sse_decode_calendar_days_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CalendarEntryItem sse_decode_calendar_entry_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CalendarFreeSlotItem sse_decode_calendar_free_slot_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CalendarFreeSlotsResponse sse_decode_calendar_free_slots_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DueReminderItem sse_decode_due_reminder_item(SseDeserializer deserializer);
+
+  @protected
+  DueRemindersResponse sse_decode_due_reminders_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EntryActionResponse sse_decode_entry_action_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EntryQuickAddResponse sse_decode_entry_quick_add_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EntryQuickAddToken sse_decode_entry_quick_add_token(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EntrySearchHighlight sse_decode_entry_search_highlight(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EntrySearchHitItem sse_decode_entry_search_hit_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EntrySearchItem sse_decode_entry_search_item(SseDeserializer deserializer);
+
+  @protected
+  EntrySearchPageResponse sse_decode_entry_search_page_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EntrySearchResponse sse_decode_entry_search_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EntrySearchSyntaxResponse sse_decode_entry_search_syntax_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  LaunchPlanItem sse_decode_launch_plan_item(SseDeserializer deserializer);
+
+  @protected
+  LaunchPlanResponse sse_decode_launch_plan_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LaunchRejectionItem sse_decode_launch_rejection_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AtomListItem> sse_decode_list_atom_list_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<AttachmentItem> sse_decode_list_attachment_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CalendarConflictItem> sse_decode_list_calendar_conflict_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CalendarDayItem> sse_decode_list_calendar_day_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CalendarEntryItem> sse_decode_list_calendar_entry_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CalendarFreeSlotItem> sse_decode_list_calendar_free_slot_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DueReminderItem> sse_decode_list_due_reminder_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EntryQuickAddToken> sse_decode_list_entry_quick_add_token(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EntrySearchHighlight> sse_decode_list_entry_search_highlight(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EntrySearchHitItem> sse_decode_list_entry_search_hit_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EntrySearchItem> sse_decode_list_entry_search_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LaunchPlanItem> sse_decode_list_launch_plan_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LaunchRejectionItem> sse_decode_list_launch_rejection_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<NoteDiffLineItem> sse_decode_list_note_diff_line_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<NoteItem> sse_decode_list_note_item(SseDeserializer deserializer);
+
+  @protected
+  List<NoteLinkItem> sse_decode_list_note_link_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<NoteOutlineHeading> sse_decode_list_note_outline_heading(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<NotePropertyItem> sse_decode_list_note_property_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<NoteRevisionItem> sse_decode_list_note_revision_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<ReminderItem> sse_decode_list_reminder_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ResourceLinkItem> sse_decode_list_resource_link_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TagUsageItem> sse_decode_list_tag_usage_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TimeDayTotalItem> sse_decode_list_time_day_total_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TimeEntryItem> sse_decode_list_time_entry_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TimeFolderTotalItem> sse_decode_list_time_folder_total_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TimeTagTotalItem> sse_decode_list_time_tag_total_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TrashListItem> sse_decode_list_trash_list_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WorkspaceNodeItem> sse_decode_list_workspace_node_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LocalTimeWindowsResponse sse_decode_local_time_windows_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LogDartEventResponse sse_decode_log_dart_event_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NoteDiffLineItem sse_decode_note_diff_line_item(SseDeserializer deserializer);
+
+  @protected
+  NoteItem sse_decode_note_item(SseDeserializer deserializer);
+
+  @protected
+  NoteLinkItem sse_decode_note_link_item(SseDeserializer deserializer);
+
+  @protected
+  NoteLinksResponse sse_decode_note_links_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NoteOutlineHeading sse_decode_note_outline_heading(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NoteOutlineResponse sse_decode_note_outline_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NotePropertiesResponse sse_decode_note_properties_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NotePropertyItem sse_decode_note_property_item(SseDeserializer deserializer);
+
+  @protected
+  NoteResponse sse_decode_note_response(SseDeserializer deserializer);
+
+  @protected
+  NoteRevisionDiffResponse sse_decode_note_revision_diff_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NoteRevisionItem sse_decode_note_revision_item(SseDeserializer deserializer);
+
+  @protected
+  NoteRevisionsListResponse sse_decode_note_revisions_list_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NoteTagSourcesResponse sse_decode_note_tag_sources_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NoteTitleResponse sse_decode_note_title_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NotesListResponse sse_decode_notes_list_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  AttachmentItem? sse_decode_opt_box_autoadd_attachment_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  NoteItem? sse_decode_opt_box_autoadd_note_item(SseDeserializer deserializer);
+
+  @protected
+  ReminderItem? sse_decode_opt_box_autoadd_reminder_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TimeEntryItem? sse_decode_opt_box_autoadd_time_entry_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  WorkspaceNodeItem? sse_decode_opt_box_autoadd_workspace_node_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RecurrenceResponse sse_decode_recurrence_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReminderItem sse_decode_reminder_item(SseDeserializer deserializer);
+
+  @protected
+  ReminderListResponse sse_decode_reminder_list_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReminderResponse sse_decode_reminder_response(SseDeserializer deserializer);
+
+  @protected
+  ResourceLinkItem sse_decode_resource_link_item(SseDeserializer deserializer);
+
+  @protected
+  ResourceLinksResponse sse_decode_resource_links_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SubtaskResponse sse_decode_subtask_response(SseDeserializer deserializer);
+
+  @protected
+  TagEditResponse sse_decode_tag_edit_response(SseDeserializer deserializer);
+
+  @protected
+  TagUsageItem sse_decode_tag_usage_item(SseDeserializer deserializer);
+
+  @protected
+  TagsListResponse sse_decode_tags_list_response(SseDeserializer deserializer);
+
+  @protected
+  TagsUsageResponse sse_decode_tags_usage_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TaskDependencyResponse sse_decode_task_dependency_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TimeDayTotalItem sse_decode_time_day_total_item(SseDeserializer deserializer);
+
+  @protected
+  TimeEntryItem sse_decode_time_entry_item(SseDeserializer deserializer);
+
+  @protected
+  TimeEntryListResponse sse_decode_time_entry_list_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TimeEntryResponse sse_decode_time_entry_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TimeFolderTotalItem sse_decode_time_folder_total_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TimeReportResponse sse_decode_time_report_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TimeTagTotalItem sse_decode_time_tag_total_item(SseDeserializer deserializer);
+
+  @protected
+  TrashListItem sse_decode_trash_list_item(SseDeserializer deserializer);
+
+  @protected
+  TrashListResponse sse_decode_trash_list_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TrashPurgeResponse sse_decode_trash_purge_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TrashRestoreResponse sse_decode_trash_restore_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  WorkspaceActionResponse sse_decode_workspace_action_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WorkspaceListChildrenResponse sse_decode_workspace_list_children_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WorkspaceNodeItem sse_decode_workspace_node_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WorkspaceNodeResponse sse_decode_workspace_node_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WorkspaceRepairResponse sse_decode_workspace_repair_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_atom_convert_response(
+    AtomConvertResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_atom_list_item(AtomListItem self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_atom_list_response(
+    AtomListResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_atom_schedule_response(
+    AtomScheduleResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_atom_task_meta_response(
+    AtomTaskMetaResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_attachment_gc_response(
+    AttachmentGcResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_attachment_import_response(
+    AttachmentImportResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_attachment_item(
+    AttachmentItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_attachment_resolve_response(
+    AttachmentResolveResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_attachments_list_response(
+    AttachmentsListResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_attachment_item(
+    AttachmentItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_note_item(
+    NoteItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_reminder_item(
+    ReminderItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_time_entry_item(
+    TimeEntryItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_workspace_node_item(
+    WorkspaceNodeItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_calendar_conflict_item(
+    CalendarConflictItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_calendar_conflicts_response(
+    CalendarConflictsResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_calendar_day_item(
+    CalendarDayItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_calendar_days_response(
+    CalendarDaysResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_calendar_entry_item(
+    CalendarEntryItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_calendar_free_slot_item(
+    CalendarFreeSlotItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_calendar_free_slots_response(
+    CalendarFreeSlotsResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_due_reminder_item(
+    DueReminderItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_due_reminders_response(
+    DueRemindersResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_entry_action_response(
+    EntryActionResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_entry_quick_add_response(
+    EntryQuickAddResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_entry_quick_add_token(
+    EntryQuickAddToken self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_entry_search_highlight(
+    EntrySearchHighlight self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_entry_search_hit_item(
+    EntrySearchHitItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_entry_search_item(
+    EntrySearchItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_entry_search_page_response(
+    EntrySearchPageResponse self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_entry_search_response(
+    EntrySearchResponse self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<WorkspaceNodeItem> dco_decode_list_workspace_node_item(dynamic raw);
+  void sse_encode_entry_search_syntax_response(
+    EntrySearchSyntaxResponse self,
+    SseSerializer serializer,
+  );
 
   @protected
-  LogDartEventResponse dco_decode_log_dart_event_response(dynamic raw);
+  void sse_encode_f_64(double self, SseSerializer serializer);
 
   @protected
-  NoteItem dco_decode_note_item(dynamic raw);
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
   @protected
-  NoteResponse dco_decode_note_response(dynamic raw);
+  void sse_encode_launch_plan_item(
+    LaunchPlanItem self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NotesListResponse dco_decode_notes_list_response(dynamic raw);
+  void sse_encode_launch_plan_response(
+    LaunchPlanResponse self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  void sse_encode_launch_rejection_item(
+    LaunchRejectionItem self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  NoteItem? dco_decode_opt_box_autoadd_note_item(dynamic raw);
+  void sse_encode_list_atom_list_item(
+    List<AtomListItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+  void sse_encode_list_attachment_item(
+    List<AttachmentItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WorkspaceNodeItem? dco_decode_opt_box_autoadd_workspace_node_item(
-    dynamic raw,
+  void sse_encode_list_calendar_conflict_item(
+    List<CalendarConflictItem> self,
+    SseSerializer serializer,
   );
 
   @protected
-  TagsListResponse dco_decode_tags_list_response(dynamic raw);
+  void sse_encode_list_calendar_day_item(
+    List<CalendarDayItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  void sse_encode_list_calendar_entry_item(
+    List<CalendarEntryItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_list_calendar_free_slot_item(
+    List<CalendarFreeSlotItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_list_due_reminder_item(
+    List<DueReminderItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WorkspaceActionResponse dco_decode_workspace_action_response(dynamic raw);
+  void sse_encode_list_entry_quick_add_token(
+    List<EntryQuickAddToken> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WorkspaceListChildrenResponse dco_decode_workspace_list_children_response(
-    dynamic raw,
+  void sse_encode_list_entry_search_highlight(
+    List<EntrySearchHighlight> self,
+    SseSerializer serializer,
   );
 
   @protected
-  WorkspaceNodeItem dco_decode_workspace_node_item(dynamic raw);
+  void sse_encode_list_entry_search_hit_item(
+    List<EntrySearchHitItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WorkspaceNodeResponse dco_decode_workspace_node_response(dynamic raw);
+  void sse_encode_list_entry_search_item(
+    List<EntrySearchItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_list_launch_plan_item(
+    List<LaunchPlanItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AtomListItem sse_decode_atom_list_item(SseDeserializer deserializer);
+  void sse_encode_list_launch_rejection_item(
+    List<LaunchRejectionItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AtomListResponse sse_decode_atom_list_response(SseDeserializer deserializer);
+  void sse_encode_list_note_diff_line_item(
+    List<NoteDiffLineItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_list_note_item(List<NoteItem> self, SseSerializer serializer);
 
   @protected
-  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+  void sse_encode_list_note_link_item(
+    List<NoteLinkItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NoteItem sse_decode_box_autoadd_note_item(SseDeserializer deserializer);
+  void sse_encode_list_note_outline_heading(
+    List<NoteOutlineHeading> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+  void sse_encode_list_note_property_item(
+    List<NotePropertyItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WorkspaceNodeItem sse_decode_box_autoadd_workspace_node_item(
-    SseDeserializer deserializer,
+  void sse_encode_list_note_revision_item(
+    List<NoteRevisionItem> self,
+    SseSerializer serializer,
   );
 
   @protected
-  EntryActionResponse sse_decode_entry_action_response(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
   );
 
   @protected
-  EntrySearchItem sse_decode_entry_search_item(SseDeserializer deserializer);
+  void sse_encode_list_reminder_item(
+    List<ReminderItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  EntrySearchResponse sse_decode_entry_search_response(
-    SseDeserializer deserializer,
+  void sse_encode_list_resource_link_item(
+    List<ResourceLinkItem> self,
+    SseSerializer serializer,
   );
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  void sse_encode_list_tag_usage_item(
+    List<TagUsageItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  void sse_encode_list_time_day_total_item(
+    List<TimeDayTotalItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<AtomListItem> sse_decode_list_atom_list_item(
-    SseDeserializer deserializer,
+  void sse_encode_list_time_entry_item(
+    List<TimeEntryItem> self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<EntrySearchItem> sse_decode_list_entry_search_item(
-    SseDeserializer deserializer,
+  void sse_encode_list_time_folder_total_item(
+    List<TimeFolderTotalItem> self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<NoteItem> sse_decode_list_note_item(SseDeserializer deserializer);
+  void sse_encode_list_time_tag_total_item(
+    List<TimeTagTotalItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_list_trash_list_item(
+    List<TrashListItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<WorkspaceNodeItem> sse_decode_list_workspace_node_item(
-    SseDeserializer deserializer,
+  void sse_encode_list_workspace_node_item(
+    List<WorkspaceNodeItem> self,
+    SseSerializer serializer,
   );
 
   @protected
-  LogDartEventResponse sse_decode_log_dart_event_response(
-    SseDeserializer deserializer,
+  void sse_encode_local_time_windows_response(
+    LocalTimeWindowsResponse self,
+    SseSerializer serializer,
   );
 
   @protected
-  NoteItem sse_decode_note_item(SseDeserializer deserializer);
+  void sse_encode_log_dart_event_response(
+    LogDartEventResponse self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NoteResponse sse_decode_note_response(SseDeserializer deserializer);
+  void sse_encode_note_diff_line_item(
+    NoteDiffLineItem self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NotesListResponse sse_decode_notes_list_response(
-    SseDeserializer deserializer,
-  );
+  void sse_encode_note_item(NoteItem self, SseSerializer serializer);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  void sse_encode_note_link_item(NoteLinkItem self, SseSerializer serializer);
 
   @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+  void sse_encode_note_links_response(
+    NoteLinksResponse self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NoteItem? sse_decode_opt_box_autoadd_note_item(SseDeserializer deserializer);
+  void sse_encode_note_outline_heading(
+    NoteOutlineHeading self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+  void sse_encode_note_outline_response(
+    NoteOutlineResponse self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WorkspaceNodeItem? sse_decode_opt_box_autoadd_workspace_node_item(
-    SseDeserializer deserializer,
+  void sse_encode_note_properties_response(
+    NotePropertiesResponse self,
+    SseSerializer serializer,
   );
 
   @protected
-  TagsListResponse sse_decode_tags_list_response(SseDeserializer deserializer);
+  void sse_encode_note_property_item(
+    NotePropertyItem self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  void sse_encode_note_response(NoteResponse self, SseSerializer serializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_note_revision_diff_response(
+    NoteRevisionDiffResponse self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_note_revision_item(
+    NoteRevisionItem self,
+    SseSerializer serializer,
+  );
 
   @protected
-  WorkspaceActionResponse sse_decode_workspace_action_response(
-    SseDeserializer deserializer,
+  void sse_encode_note_revisions_list_response(
+    NoteRevisionsListResponse self,
+    SseSerializer serializer,
   );
 
   @protected
-  WorkspaceListChildrenResponse sse_decode_workspace_list_children_response(
-    SseDeserializer deserializer,
+  void sse_encode_note_tag_sources_response(
+    NoteTagSourcesResponse self,
+    SseSerializer serializer,
   );
 
   @protected
-  WorkspaceNodeItem sse_decode_workspace_node_item(
-    SseDeserializer deserializer,
+  void sse_encode_note_title_response(
+    NoteTitleResponse self,
+    SseSerializer serializer,
   );
 
   @protected
-  WorkspaceNodeResponse sse_decode_workspace_node_response(
-    SseDeserializer deserializer,
+  void sse_encode_notes_list_response(
+    NotesListResponse self,
+    SseSerializer serializer,
   );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_attachment_item(
+    AttachmentItem? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_atom_list_item(AtomListItem self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_atom_list_response(
-    AtomListResponse self,
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_note_item(
+    NoteItem? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_i_64(
-    PlatformInt64 self,
+  void sse_encode_opt_box_autoadd_reminder_item(
+    ReminderItem? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_note_item(
-    NoteItem self,
+  void sse_encode_opt_box_autoadd_time_entry_item(
+    TimeEntryItem? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_workspace_node_item(
-    WorkspaceNodeItem self,
+  void sse_encode_opt_box_autoadd_workspace_node_item(
+    WorkspaceNodeItem? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_entry_action_response(
-    EntryActionResponse self,
+  void sse_encode_recurrence_response(
+    RecurrenceResponse self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_entry_search_item(
-    EntrySearchItem self,
+  void sse_encode_reminder_item(ReminderItem self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_reminder_list_response(
+    ReminderListResponse self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_entry_search_response(
-    EntrySearchResponse self,
+  void sse_encode_reminder_response(
+    ReminderResponse self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+  void sse_encode_resource_link_item(
+    ResourceLinkItem self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+  void sse_encode_resource_links_response(
+    ResourceLinksResponse self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_list_atom_list_item(
-    List<AtomListItem> self,
+  void sse_encode_subtask_response(
+    SubtaskResponse self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_entry_search_item(
-    List<EntrySearchItem> self,
+  void sse_encode_tag_edit_response(
+    TagEditResponse self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_note_item(List<NoteItem> self, SseSerializer serializer);
+  void sse_encode_tag_usage_item(TagUsageItem self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_tags_list_response(
+    TagsListResponse self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_workspace_node_item(
-    List<WorkspaceNodeItem> self,
+  void sse_encode_tags_usage_response(
+    TagsUsageResponse self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_log_dart_event_response(
-    LogDartEventResponse self,
+  void sse_encode_task_dependency_response(
+    TaskDependencyResponse self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_note_item(NoteItem self, SseSerializer serializer);
+  void sse_encode_time_day_total_item(
+    TimeDayTotalItem self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_note_response(NoteResponse self, SseSerializer serializer);
+  void sse_encode_time_entry_item(TimeEntryItem self, SseSerializer serializer);
 
   @protected
-  void sse_encode_notes_list_response(
-    NotesListResponse self,
+  void sse_encode_time_entry_list_response(
+    TimeEntryListResponse self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  void sse_encode_time_entry_response(
+    TimeEntryResponse self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_box_autoadd_i_64(
-    PlatformInt64? self,
+  void sse_encode_time_folder_total_item(
+    TimeFolderTotalItem self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_opt_box_autoadd_note_item(
-    NoteItem? self,
+  void sse_encode_time_report_response(
+    TimeReportResponse self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+  void sse_encode_time_tag_total_item(
+    TimeTagTotalItem self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_box_autoadd_workspace_node_item(
-    WorkspaceNodeItem? self,
+  void sse_encode_trash_list_item(TrashListItem self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_trash_list_response(
+    TrashListResponse self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_tags_list_response(
-    TagsListResponse self,
+  void sse_encode_trash_purge_response(
+    TrashPurgeResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_trash_restore_response(
+    TrashRestoreResponse self,
     SseSerializer serializer,
   );
 
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
@@ -430,6 +1709,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_workspace_repair_response(
+    WorkspaceRepairResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 }
@@ -0,0 +1,27 @@
+-- Migration: 0010_recurrence_exceptions.sql
+-- Purpose: store per-occurrence exceptions for recurring atoms (RFC 5545
+--          EXDATE and detached "this occurrence only" overrides).
+-- Invariants:
+-- - One exception row per (series_uuid, original_start_at).
+-- - override_uuid NULL means the occurrence is skipped (EXDATE).
+-- - override_uuid NOT NULL points to a standalone, non-recurring atom that
+--   replaces the occurrence in section/calendar projections.
+-- Backward compatibility:
+-- - additive schema update; atoms with NULL recurrence_rule are unaffected.
+
+CREATE TABLE recurrence_exceptions (
+    series_uuid TEXT NOT NULL,
+    original_start_at INTEGER NOT NULL,
+    override_uuid TEXT NULL,
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    PRIMARY KEY (series_uuid, original_start_at),
+    FOREIGN KEY (series_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE,
+    FOREIGN KEY (override_uuid) REFERENCES atoms(uuid) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_recurrence_exceptions_override
+    ON recurrence_exceptions(override_uuid);
+
+CREATE INDEX IF NOT EXISTS idx_atoms_recurring
+    ON atoms(start_at)
+    WHERE recurrence_rule IS NOT NULL;
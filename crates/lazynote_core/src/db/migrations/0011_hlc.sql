@@ -0,0 +1,22 @@
+-- Migration: 0011_hlc.sql
+-- Purpose: persist per-device Hybrid Logical Clock state and stamp workspace
+--          tree rows with HLC timestamps (atoms already carry hlc_timestamp).
+-- Invariants:
+-- - hlc_state holds exactly one row (id = 1).
+-- - hlc_state.node_id is generated once here and never rotated.
+-- - last_physical_ms/last_logical only move forward.
+-- Backward compatibility:
+-- - additive schema update; existing rows keep NULL hlc_timestamp until
+--   their next write.
+
+CREATE TABLE hlc_state (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    node_id TEXT NOT NULL,
+    last_physical_ms INTEGER NOT NULL DEFAULT 0,
+    last_logical INTEGER NOT NULL DEFAULT 0 CHECK (last_logical >= 0)
+);
+
+INSERT INTO hlc_state (id, node_id)
+VALUES (1, lower(hex(randomblob(8))));
+
+ALTER TABLE workspace_nodes ADD COLUMN hlc_timestamp TEXT NULL;
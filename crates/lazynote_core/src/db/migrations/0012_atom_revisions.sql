@@ -0,0 +1,30 @@
+-- Migration: 0012_atom_revisions.sql
+-- Purpose: keep prior atom content/metadata snapshots so overwriting updates
+--          (note autosave, task/event edits) can be diffed and restored.
+-- Invariants:
+-- - One row per captured snapshot; revision_id grows monotonically.
+-- - A snapshot holds the atom state *before* the write that captured it.
+-- - captured_at drives autosave coalescing (see repo/revision_repo.rs).
+-- Backward compatibility:
+-- - additive schema update; atoms written before this migration simply have
+--   no history until their next update.
+
+CREATE TABLE atom_revisions (
+    revision_id INTEGER PRIMARY KEY AUTOINCREMENT,
+    atom_uuid TEXT NOT NULL,
+    type TEXT NOT NULL CHECK (type IN ('note', 'task', 'event')),
+    content TEXT NOT NULL,
+    preview_text TEXT NULL,
+    preview_image TEXT NULL,
+    task_status TEXT NULL,
+    start_at INTEGER NULL,
+    end_at INTEGER NULL,
+    recurrence_rule TEXT NULL,
+    hlc_timestamp TEXT NULL,
+    source_updated_at INTEGER NOT NULL,
+    captured_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    FOREIGN KEY (atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_atom_revisions_atom
+    ON atom_revisions(atom_uuid, revision_id DESC);
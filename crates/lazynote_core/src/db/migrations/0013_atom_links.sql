@@ -0,0 +1,36 @@
+-- Migration: 0013_atom_links.sql
+-- Purpose: persist wiki-link edges (`[[Title]]`, `[[uuid]]`) parsed from atom
+--          content so outgoing links, backlinks and unresolved references can
+--          be queried without re-parsing content.
+-- Invariants:
+-- - One row per (source_uuid, target_key); rows are replaced on each save.
+-- - link_kind='uuid' rows always carry target_uuid (the written id).
+-- - link_kind='title' rows carry the resolved note atom or NULL; they are
+--   re-resolved when workspace note_ref display names change.
+-- - Whether a target is active is decided at read time.
+-- Backward compatibility:
+-- - additive schema update; content saved before this migration is indexed
+--   by its next save or an explicit links rebuild.
+
+CREATE TABLE atom_links (
+    source_uuid TEXT NOT NULL,
+    target_key TEXT NOT NULL,
+    target_text TEXT NOT NULL,
+    label TEXT NULL,
+    link_kind TEXT NOT NULL CHECK (link_kind IN ('title', 'uuid')),
+    target_uuid TEXT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (source_uuid, target_key),
+    FOREIGN KEY (source_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_atom_links_target
+    ON atom_links(target_uuid);
+
+CREATE INDEX IF NOT EXISTS idx_atom_links_title_key
+    ON atom_links(target_key)
+    WHERE link_kind = 'title';
+
+CREATE INDEX IF NOT EXISTS idx_workspace_nodes_note_ref_title
+    ON workspace_nodes(lower(trim(display_name)))
+    WHERE kind = 'note_ref' AND is_deleted = 0;
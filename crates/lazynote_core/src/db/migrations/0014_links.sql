@@ -0,0 +1,32 @@
+-- Migration: 0014_links.sql
+-- Purpose: index Markdown links and bare URLs (`http/https/file`) found in
+--          atom content so the UI can list, search and launch them without
+--          re-parsing content.
+-- Invariants:
+-- - Rows are derived from `atoms.content` and replaced on each save.
+-- - One row per (atom_uuid, target); `position` keeps document order.
+-- - `scheme` is stored lowercase; launch whitelisting happens in core
+--   use-cases, never at storage level.
+-- Backward compatibility:
+-- - additive schema update; content saved before this migration is indexed
+--   by its next save or an explicit links rebuild.
+
+CREATE TABLE links (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    atom_uuid TEXT NOT NULL,
+    scheme TEXT NOT NULL,
+    target TEXT NOT NULL,
+    label TEXT NULL,
+    source TEXT NOT NULL CHECK (source IN ('markdown', 'bare')),
+    position INTEGER NOT NULL,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    UNIQUE (atom_uuid, target),
+    FOREIGN KEY (atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_links_atom_position
+    ON links(atom_uuid, position);
+
+CREATE INDEX IF NOT EXISTS idx_links_scheme_target
+    ON links(scheme, target);
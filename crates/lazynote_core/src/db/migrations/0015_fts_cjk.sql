@@ -0,0 +1,75 @@
+-- Migration: 0015_fts_cjk.sql
+-- Purpose: add a trigram-tokenized FTS5 index so CJK text (no whitespace
+--          word boundaries) is searchable by substring, and rebuild the FTS
+--          sync triggers to maintain both indexes.
+-- Invariants:
+-- - `atoms_fts` (unicode61) stays the index for Latin/whitespace-delimited
+--   queries; `atoms_fts_trigram` serves queries containing CJK characters.
+-- - both indexes hold exactly the non-deleted atoms, keyed by atoms.rowid.
+-- - trigram matching is case-insensitive; terms shorter than 3 characters
+--   are matched by the search layer with LIKE over `atoms_fts_trigram`.
+-- Backward compatibility:
+-- - additive; existing `atoms_fts` rows are kept and re-synced from atoms.
+
+CREATE VIRTUAL TABLE atoms_fts_trigram USING fts5(
+    content,
+    uuid UNINDEXED,
+    type UNINDEXED,
+    tokenize = 'trigram'
+);
+
+DELETE FROM atoms_fts;
+
+INSERT INTO atoms_fts (rowid, content, uuid, type)
+SELECT rowid, content, uuid, type
+FROM atoms
+WHERE is_deleted = 0;
+
+INSERT INTO atoms_fts_trigram (rowid, content, uuid, type)
+SELECT rowid, content, uuid, type
+FROM atoms
+WHERE is_deleted = 0;
+
+DROP TRIGGER IF EXISTS atoms_ai_fts;
+DROP TRIGGER IF EXISTS atoms_ad_fts;
+DROP TRIGGER IF EXISTS atoms_au_fts;
+
+CREATE TRIGGER atoms_ai_fts
+AFTER INSERT ON atoms
+WHEN NEW.is_deleted = 0
+BEGIN
+    INSERT INTO atoms_fts (rowid, content, uuid, type)
+    VALUES (NEW.rowid, NEW.content, NEW.uuid, NEW.type);
+
+    INSERT INTO atoms_fts_trigram (rowid, content, uuid, type)
+    VALUES (NEW.rowid, NEW.content, NEW.uuid, NEW.type);
+END;
+
+CREATE TRIGGER atoms_ad_fts
+AFTER DELETE ON atoms
+WHEN OLD.is_deleted = 0
+BEGIN
+    DELETE FROM atoms_fts
+    WHERE rowid = OLD.rowid;
+
+    DELETE FROM atoms_fts_trigram
+    WHERE rowid = OLD.rowid;
+END;
+
+CREATE TRIGGER atoms_au_fts
+AFTER UPDATE ON atoms
+BEGIN
+    DELETE FROM atoms_fts
+    WHERE rowid = OLD.rowid;
+
+    DELETE FROM atoms_fts_trigram
+    WHERE rowid = OLD.rowid;
+
+    INSERT INTO atoms_fts (rowid, content, uuid, type)
+    SELECT NEW.rowid, NEW.content, NEW.uuid, NEW.type
+    WHERE NEW.is_deleted = 0;
+
+    INSERT INTO atoms_fts_trigram (rowid, content, uuid, type)
+    SELECT NEW.rowid, NEW.content, NEW.uuid, NEW.type
+    WHERE NEW.is_deleted = 0;
+END;
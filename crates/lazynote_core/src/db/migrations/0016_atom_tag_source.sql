@@ -0,0 +1,33 @@
+-- Migration: 0016_atom_tag_source.sql
+-- Purpose: record where each atom-tag link came from, so tags derived from
+--          inline `#hashtags` can be replaced on save without touching tags
+--          set explicitly through `note_set_tags`.
+-- Invariants:
+-- - atom_tags.source is `manual` (explicit tag set) or `inline` (derived
+--   from content).
+-- - atom_tags rows are unique by (atom_uuid, tag_id, source); one tag may be
+--   linked once per source.
+-- - inline rows are derived from `atoms.content` and replaced on each save.
+-- Backward compatibility:
+-- - table rebuild; existing links are kept as `manual`. Content saved before
+--   this migration gains inline tags on its next save or an explicit rebuild.
+
+CREATE TABLE atom_tags_v16 (
+    atom_uuid TEXT NOT NULL,
+    tag_id INTEGER NOT NULL,
+    source TEXT NOT NULL DEFAULT 'manual' CHECK (source IN ('manual', 'inline')),
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    PRIMARY KEY (atom_uuid, tag_id, source),
+    FOREIGN KEY (atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE,
+    FOREIGN KEY (tag_id) REFERENCES tags(id) ON DELETE CASCADE
+);
+
+INSERT INTO atom_tags_v16 (atom_uuid, tag_id, source, created_at)
+SELECT atom_uuid, tag_id, 'manual', created_at
+FROM atom_tags;
+
+DROP TABLE atom_tags;
+
+ALTER TABLE atom_tags_v16 RENAME TO atom_tags;
+
+CREATE INDEX IF NOT EXISTS idx_atom_tags_tag_id ON atom_tags(tag_id);
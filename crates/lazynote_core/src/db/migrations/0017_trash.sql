@@ -0,0 +1,17 @@
+-- Migration: 0017_trash.sql
+-- Purpose: support the trash lifecycle (list, restore, purge) of soft-deleted
+--          atoms while keeping sync tombstones that providers still need.
+-- Invariants:
+-- - trash = `is_deleted = 1 AND purged_at IS NULL`; the tombstone's
+--   `updated_at` is its deletion time.
+-- - `purged_at` is set only when a purge had to keep a scrubbed tombstone
+--   because an `external_mappings` row has not synced the deletion yet; such
+--   rows are neither listed nor restorable.
+-- Backward compatibility:
+-- - additive column and partial index; existing tombstones become trash.
+
+ALTER TABLE atoms ADD COLUMN purged_at INTEGER NULL;
+
+CREATE INDEX IF NOT EXISTS idx_atoms_trash
+    ON atoms(updated_at)
+    WHERE is_deleted = 1;
@@ -0,0 +1,34 @@
+-- Migration: 0018_attachments.sql
+-- Purpose: content-addressed attachment store. Blobs live next to the DB
+--          under `attachments/<sha256[0..2]>/<sha256>`; this migration adds
+--          their metadata and the atom -> attachment reference index.
+-- Invariants:
+-- - `attachments.sha256` is the lowercase hex SHA-256 of the blob bytes;
+--   identical files are stored once.
+-- - `atom_attachments` rows are derived from `attachment://<sha256>` URIs in
+--   `atoms.content` and replaced on each save. A reference may point to a
+--   digest whose blob is not imported on this device yet, so there is no
+--   foreign key to `attachments`.
+-- - Reference count = number of `atom_attachments` rows for a digest; only
+--   zero-reference attachments are garbage-collected.
+-- Backward compatibility:
+-- - additive schema update; existing content has no attachment URIs.
+
+CREATE TABLE attachments (
+    sha256 TEXT PRIMARY KEY CHECK (length(sha256) = 64),
+    byte_size INTEGER NOT NULL CHECK (byte_size >= 0),
+    mime_type TEXT NULL,
+    original_name TEXT NULL,
+    created_at INTEGER NOT NULL
+);
+
+CREATE TABLE atom_attachments (
+    atom_uuid TEXT NOT NULL,
+    sha256 TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (atom_uuid, sha256),
+    FOREIGN KEY (atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_atom_attachments_sha256
+    ON atom_attachments(sha256);
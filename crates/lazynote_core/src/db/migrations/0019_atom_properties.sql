@@ -0,0 +1,30 @@
+-- Migration: 0019_atom_properties.sql
+-- Purpose: index typed key/value properties parsed from a leading YAML
+--          frontmatter block so they can be listed and queried by search.
+-- Invariants:
+-- - Rows are derived from `atoms.content` and replaced on each save.
+-- - `ordinal` keeps declaration order; `item_index` is the element index of
+--   a `list` value (0 for scalars), so one list yields one row per element.
+-- - `value_text` holds the text form of every type (`true`/`false` for
+--   bools); `value_num` holds numbers, bools (1/0) and dates (epoch ms).
+-- Backward compatibility:
+-- - additive schema update; content saved before this migration is indexed
+--   by its next save or an explicit properties rebuild.
+
+CREATE TABLE atom_properties (
+    atom_uuid TEXT NOT NULL,
+    key TEXT NOT NULL,
+    item_index INTEGER NOT NULL DEFAULT 0,
+    ordinal INTEGER NOT NULL,
+    value_type TEXT NOT NULL CHECK (value_type IN ('string', 'number', 'date', 'bool', 'list')),
+    value_text TEXT NOT NULL,
+    value_num REAL NULL,
+    PRIMARY KEY (atom_uuid, key, item_index),
+    FOREIGN KEY (atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_atom_properties_key_text
+    ON atom_properties(key, value_text COLLATE NOCASE);
+
+CREATE INDEX IF NOT EXISTS idx_atom_properties_key_num
+    ON atom_properties(key, value_num);
@@ -0,0 +1,20 @@
+-- Migration: 0020_atom_content_stats.sql
+-- Purpose: cache word/character counts and checklist progress derived from
+--          atom content so list views can show them without re-parsing.
+-- Invariants:
+-- - One row per atom, replaced on every content write.
+-- - Values come from the CommonMark/GFM parse (markup and frontmatter
+--   excluded); `checklist_done <= checklist_total`.
+-- Backward compatibility:
+-- - additive schema update; atoms saved before this migration have no row
+--   and readers derive the values from content until the next save.
+
+CREATE TABLE atom_content_stats (
+    atom_uuid TEXT PRIMARY KEY NOT NULL,
+    word_count INTEGER NOT NULL DEFAULT 0,
+    char_count INTEGER NOT NULL DEFAULT 0,
+    checklist_done INTEGER NOT NULL DEFAULT 0,
+    checklist_total INTEGER NOT NULL DEFAULT 0,
+    FOREIGN KEY (atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE,
+    CHECK (checklist_done <= checklist_total)
+);
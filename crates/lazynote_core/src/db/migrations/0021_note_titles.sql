@@ -0,0 +1,31 @@
+-- Migration: 0021_note_titles.sql
+-- Purpose: add an atom-level title projection (derived from content, with an
+--          optional explicit override) and let workspace note_refs follow it.
+-- Invariants:
+-- - `derived_title` is replaced on every content write; NULL means the
+--   content has no text.
+-- - `title_override` is user data and survives content writes; the effective
+--   title is `COALESCE(title_override, derived_title, 'Untitled note')`.
+-- - `workspace_nodes.name_follows_title = 1` only for note_refs whose name
+--   was never set manually; renames clear it.
+-- Backward compatibility:
+-- - additive schema update; existing note_refs keep their names.
+-- - legacy note_refs backfilled as 'Untitled note' (0009, trash restore) are
+--   flagged to follow the title; the title repair rewrites their names.
+
+CREATE TABLE atom_titles (
+    atom_uuid TEXT PRIMARY KEY NOT NULL,
+    derived_title TEXT NULL,
+    title_override TEXT NULL,
+    FOREIGN KEY (atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE,
+    CHECK (title_override IS NULL OR length(trim(title_override)) > 0)
+);
+
+ALTER TABLE workspace_nodes
+    ADD COLUMN name_follows_title INTEGER NOT NULL DEFAULT 0
+    CHECK (name_follows_title IN (0, 1));
+
+UPDATE workspace_nodes
+SET name_follows_title = 1
+WHERE kind = 'note_ref'
+  AND display_name = 'Untitled note';
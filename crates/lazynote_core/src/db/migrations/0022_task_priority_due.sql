@@ -0,0 +1,20 @@
+-- Migration: 0022_task_priority_due.sql
+-- Purpose: add task priority and a deadline (`due_at`) separate from the
+--          scheduled start_at/end_at window; snapshot both in revisions.
+-- Invariants:
+-- - priority is NULL (unprioritized) or 1=low, 2=medium, 3=high.
+-- - due_at is epoch ms; it joins start_at/end_at in Today/Upcoming
+--   classification and drives the Overdue section.
+-- Backward compatibility:
+-- - additive; existing atoms and revisions get NULL priority/due_at.
+
+ALTER TABLE atoms ADD COLUMN priority INTEGER NULL
+    CHECK (priority IS NULL OR priority IN (1, 2, 3));
+ALTER TABLE atoms ADD COLUMN due_at INTEGER NULL;
+
+ALTER TABLE atom_revisions ADD COLUMN priority INTEGER NULL;
+ALTER TABLE atom_revisions ADD COLUMN due_at INTEGER NULL;
+
+CREATE INDEX IF NOT EXISTS idx_atoms_due_at
+    ON atoms(due_at)
+    WHERE due_at IS NOT NULL AND is_deleted = 0;
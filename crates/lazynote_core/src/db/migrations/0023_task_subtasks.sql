@@ -0,0 +1,23 @@
+-- Migration: 0023_task_subtasks.sql
+-- Purpose: parent/child hierarchy between task atoms (subtasks) with a
+--          per-parent sibling order.
+-- Invariants:
+-- - One row per child: a task has at most one parent.
+-- - parent_uuid <> child_uuid; deeper cycles are rejected by the service.
+-- - Read paths only surface active `type = 'task'` atoms on both ends; rows
+--   of deleted or converted atoms are kept so restore/convert-back reattaches.
+-- Backward compatibility:
+-- - additive schema update; existing tasks stay top-level.
+
+CREATE TABLE task_subtasks (
+    child_uuid TEXT PRIMARY KEY NOT NULL,
+    parent_uuid TEXT NOT NULL,
+    sort_order INTEGER NOT NULL DEFAULT 0,
+    hlc_timestamp TEXT NULL,
+    CHECK (parent_uuid <> child_uuid),
+    FOREIGN KEY (child_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE,
+    FOREIGN KEY (parent_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_subtasks_parent_order
+    ON task_subtasks(parent_uuid, sort_order, child_uuid);
@@ -0,0 +1,24 @@
+-- Migration: 0024_task_dependencies.sql
+-- Purpose: "task depends on prerequisite" edges between task atoms, used to
+--          compute the blocked state and a topological task order.
+-- Invariants:
+-- - One row per (task_uuid, depends_on_uuid) pair.
+-- - task_uuid <> depends_on_uuid; longer cycles are rejected by the service.
+-- - Edges of deleted or converted atoms are kept; only active unfinished
+--   task prerequisites block.
+-- Backward compatibility:
+-- - additive schema update; existing tasks have no prerequisites.
+
+CREATE TABLE task_dependencies (
+    task_uuid TEXT NOT NULL,
+    depends_on_uuid TEXT NOT NULL,
+    hlc_timestamp TEXT NULL,
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    PRIMARY KEY (task_uuid, depends_on_uuid),
+    CHECK (task_uuid <> depends_on_uuid),
+    FOREIGN KEY (task_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE,
+    FOREIGN KEY (depends_on_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_dependencies_depends_on
+    ON task_dependencies(depends_on_uuid, task_uuid);
@@ -0,0 +1,15 @@
+-- Migration: 0025_all_day.sql
+-- Purpose: mark atoms whose start_at/end_at are floating all-day dates
+--          instead of UTC instants; snapshot the flag in revisions.
+-- Invariants:
+-- - all_day = 1 requires start_at at 00:00 and end_at at 23:59:59.999 of
+--   floating (wall-clock, encoded as UTC) days; enforced by Atom::validate.
+-- - Floating bounds are compared with the floating half of a local window,
+--   never with instants.
+-- Backward compatibility:
+-- - additive; existing atoms and revisions stay timed (all_day = 0).
+
+ALTER TABLE atoms ADD COLUMN all_day INTEGER NOT NULL DEFAULT 0
+    CHECK (all_day IN (0, 1));
+
+ALTER TABLE atom_revisions ADD COLUMN all_day INTEGER NOT NULL DEFAULT 0;
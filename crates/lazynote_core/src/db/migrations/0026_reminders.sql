@@ -0,0 +1,37 @@
+-- Migration: 0026_reminders.sql
+-- Purpose: reminders attached to atoms, either at an absolute instant or at
+--          an offset relative to start_at/due_at (per occurrence for
+--          recurring atoms), with delivery acknowledgement and snooze state.
+-- Invariants:
+-- - anchor = 'absolute' stores remind_at; 'start'/'due' store offset_ms
+--   (negative means before the anchor).
+-- - Fire times are computed from the atom on read, never stored, so
+--   rescheduled and recurring atoms need no regeneration pass.
+-- - acked_through is the latest acknowledged scheduled fire time; firings
+--   at or before it (or before created_at) are never delivered.
+-- - snoozed_until and snoozed_fire_at are set together.
+-- Backward compatibility:
+-- - additive schema update; existing atoms have no reminders.
+
+CREATE TABLE reminders (
+    uuid TEXT PRIMARY KEY NOT NULL,
+    atom_uuid TEXT NOT NULL,
+    anchor TEXT NOT NULL CHECK (anchor IN ('absolute', 'start', 'due')),
+    remind_at INTEGER NULL,
+    offset_ms INTEGER NULL,
+    acked_through INTEGER NULL,
+    snoozed_until INTEGER NULL,
+    snoozed_fire_at INTEGER NULL,
+    hlc_timestamp TEXT NULL,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    CHECK (
+        (anchor = 'absolute' AND remind_at IS NOT NULL AND offset_ms IS NULL)
+        OR (anchor <> 'absolute' AND remind_at IS NULL AND offset_ms IS NOT NULL)
+    ),
+    CHECK ((snoozed_until IS NULL) = (snoozed_fire_at IS NULL)),
+    FOREIGN KEY (atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_reminders_atom
+    ON reminders(atom_uuid, created_at);
@@ -0,0 +1,34 @@
+-- Migration: 0027_time_entries.sql
+-- Purpose: time tracking sessions on task atoms, recorded by a running timer
+--          (start/stop) or entered manually, for per-day/tag/folder totals.
+-- Invariants:
+-- - ended_at IS NULL marks a running timer; each device_id (the local HLC
+--   node id of the recording device) has at most one running entry.
+-- - A stopped entry never ends before it starts.
+-- - Entries of trashed atoms are kept so restore brings the time back.
+-- Backward compatibility:
+-- - additive schema update; existing tasks have no tracked time.
+
+CREATE TABLE time_entries (
+    uuid TEXT PRIMARY KEY NOT NULL,
+    atom_uuid TEXT NOT NULL,
+    device_id TEXT NOT NULL,
+    started_at INTEGER NOT NULL,
+    ended_at INTEGER NULL,
+    note TEXT NULL,
+    hlc_timestamp TEXT NULL,
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    updated_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    CHECK (ended_at IS NULL OR ended_at >= started_at),
+    FOREIGN KEY (atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_time_entries_running_device
+    ON time_entries(device_id)
+    WHERE ended_at IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_time_entries_atom
+    ON time_entries(atom_uuid, started_at);
+
+CREATE INDEX IF NOT EXISTS idx_time_entries_started
+    ON time_entries(started_at);
@@ -7,11 +7,21 @@
 //! # Invariants
 //! - `version` values must remain monotonic.
 //! - Applied migration version is mirrored to `PRAGMA user_version`.
+//! - Data backfills that need Rust parsing (for example wiki-links) run once,
+//!   after every SQL step, when an upgrade crosses their version.
 //!
 //! # See also
 //! - docs/releases/v0.1/prs/PR-0005-sqlite-schema-migrations.md
 
 use crate::db::{DbError, DbResult};
+use crate::model::atom::AtomId;
+use crate::repo::attachment_repo::sync_attachment_refs;
+use crate::repo::content_index::{reindex_all_atoms, sync_content_stats};
+use crate::repo::link_repo::sync_atom_links;
+use crate::repo::note_repo::sync_inline_tags;
+use crate::repo::property_repo::sync_atom_properties;
+use crate::repo::resource_link_repo::sync_resource_links;
+use crate::repo::title_repo::sync_atom_title;
 use log::{error, info, warn};
 use rusqlite::Connection;
 use std::time::Instant;
@@ -22,6 +32,57 @@ struct Migration {
     sql: &'static str,
 }
 
+/// Content-derived backfill for a table introduced by migration `version`.
+///
+/// `sync` re-indexes one atom; it runs over every atom on the latest schema,
+/// inside the migration transaction, so it may read columns added by later
+/// steps. Entries run in version order, which keeps the title projection
+/// after the link index it reads.
+#[derive(Debug, Clone, Copy)]
+struct Backfill {
+    version: u32,
+    name: &'static str,
+    sync: fn(&Connection, AtomId, &str) -> rusqlite::Result<()>,
+}
+
+const BACKFILLS: &[Backfill] = &[
+    Backfill {
+        version: 13,
+        name: "atom_links",
+        sync: sync_atom_links,
+    },
+    Backfill {
+        version: 14,
+        name: "links",
+        sync: sync_resource_links,
+    },
+    Backfill {
+        version: 16,
+        name: "inline_tags",
+        sync: sync_inline_tags,
+    },
+    Backfill {
+        version: 18,
+        name: "atom_attachments",
+        sync: sync_attachment_refs,
+    },
+    Backfill {
+        version: 19,
+        name: "atom_properties",
+        sync: sync_atom_properties,
+    },
+    Backfill {
+        version: 20,
+        name: "atom_content_stats",
+        sync: sync_content_stats,
+    },
+    Backfill {
+        version: 21,
+        name: "atom_titles",
+        sync: sync_atom_title,
+    },
+];
+
 const MIGRATIONS: &[Migration] = &[
     Migration {
         version: 1,
@@ -59,6 +120,78 @@ const MIGRATIONS: &[Migration] = &[
         version: 9,
         sql: include_str!("0009_workspace_note_ref_backfill.sql"),
     },
+    Migration {
+        version: 10,
+        sql: include_str!("0010_recurrence_exceptions.sql"),
+    },
+    Migration {
+        version: 11,
+        sql: include_str!("0011_hlc.sql"),
+    },
+    Migration {
+        version: 12,
+        sql: include_str!("0012_atom_revisions.sql"),
+    },
+    Migration {
+        version: 13,
+        sql: include_str!("0013_atom_links.sql"),
+    },
+    Migration {
+        version: 14,
+        sql: include_str!("0014_links.sql"),
+    },
+    Migration {
+        version: 15,
+        sql: include_str!("0015_fts_cjk.sql"),
+    },
+    Migration {
+        version: 16,
+        sql: include_str!("0016_atom_tag_source.sql"),
+    },
+    Migration {
+        version: 17,
+        sql: include_str!("0017_trash.sql"),
+    },
+    Migration {
+        version: 18,
+        sql: include_str!("0018_attachments.sql"),
+    },
+    Migration {
+        version: 19,
+        sql: include_str!("0019_atom_properties.sql"),
+    },
+    Migration {
+        version: 20,
+        sql: include_str!("0020_atom_content_stats.sql"),
+    },
+    Migration {
+        version: 21,
+        sql: include_str!("0021_note_titles.sql"),
+    },
+    Migration {
+        version: 22,
+        sql: include_str!("0022_task_priority_due.sql"),
+    },
+    Migration {
+        version: 23,
+        sql: include_str!("0023_task_subtasks.sql"),
+    },
+    Migration {
+        version: 24,
+        sql: include_str!("0024_task_dependencies.sql"),
+    },
+    Migration {
+        version: 25,
+        sql: include_str!("0025_all_day.sql"),
+    },
+    Migration {
+        version: 26,
+        sql: include_str!("0026_reminders.sql"),
+    },
+    Migration {
+        version: 27,
+        sql: include_str!("0027_time_entries.sql"),
+    },
 ];
 
 /// Returns the latest migration version known by this binary.
@@ -76,6 +209,8 @@ pub fn latest_version() -> u32 {
 /// - Migrations run in strictly increasing version order.
 /// - `PRAGMA user_version` is updated after each successful migration step.
 /// - Migration execution is wrapped in one transaction.
+/// - Registered backfills run after the last step when `from_version` is
+///   below their version; fresh databases have nothing to backfill.
 ///
 /// # Errors
 /// - Returns [`DbError::UnsupportedSchemaVersion`] when DB schema is newer than
@@ -158,6 +293,28 @@ pub fn apply_migrations(conn: &mut Connection) -> DbResult<()> {
         );
     }
 
+    for backfill in BACKFILLS {
+        if backfill.version <= current_version {
+            continue;
+        }
+        let step_started_at = Instant::now();
+        let atom_count = reindex_all_atoms(&tx, backfill.sync).map_err(|err| {
+            error!(
+                "event=db_backfill_done module=db status=error backfill={} duration_ms={} error_code=backfill_failed error={}",
+                backfill.name,
+                step_started_at.elapsed().as_millis(),
+                err
+            );
+            DbError::Sqlite(err)
+        })?;
+        info!(
+            "event=db_backfill_done module=db status=ok backfill={} atom_count={} duration_ms={}",
+            backfill.name,
+            atom_count,
+            step_started_at.elapsed().as_millis()
+        );
+    }
+
     tx.commit().map_err(|err| {
         error!(
             "event=db_migrate_done module=db status=error from_version={} to_version={} applied_count={} duration_ms={} error_code=commit_failed error={}",
@@ -1,7 +1,10 @@
 //! Extension kernel registry contracts.
 
 use crate::extension::capability::RuntimeCapability;
-use crate::extension::manifest::{ExtensionManifest, ManifestEntrypoints, ManifestValidationError};
+use crate::extension::manifest::{
+    ExtensionManifest, ManifestEntrypoints, ManifestValidationError, CAPABILITY_PARSER,
+};
+use crate::extension::quick_add::QUICK_ADD_PARSER_ID;
 use std::collections::{BTreeMap, BTreeSet};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
@@ -93,6 +96,26 @@ impl FirstPartyExtensionAdapter {
             },
         })
     }
+
+    /// Built-in natural-language quick-add parser
+    /// ([`crate::extension::quick_add::QuickAddParser`]).
+    pub fn quick_add_parser() -> Self {
+        Self::new(ExtensionManifest {
+            id: QUICK_ADD_PARSER_ID.to_string(),
+            version: "0.1.0".to_string(),
+            capabilities: vec![CAPABILITY_PARSER.to_string()],
+            runtime_capabilities: vec![],
+            entrypoints: ManifestEntrypoints {
+                init: Some("builtin.quick_add.init".to_string()),
+                dispose: Some("builtin.quick_add.dispose".to_string()),
+                health: Some("builtin.quick_add.health".to_string()),
+                command_action: None,
+                input_parser: Some("builtin.quick_add.parse".to_string()),
+                provider_spi: None,
+                ui_slot: None,
+            },
+        })
+    }
 }
 
 impl ExtensionAdapter for FirstPartyExtensionAdapter {
@@ -159,6 +182,11 @@ impl ExtensionRegistry {
         self.register_adapter(&adapter)
     }
 
+    /// Registers the built-in quick-add `parser` adapter.
+    pub fn register_quick_add_parser(&mut self) -> Result<(), ExtensionKernelError> {
+        self.register_adapter(&FirstPartyExtensionAdapter::quick_add_parser())
+    }
+
     /// Returns number of registered extensions.
     pub fn len(&self) -> usize {
         self.entries.len()
@@ -323,6 +351,28 @@ mod tests {
         assert_eq!(command_extensions[0].manifest.id, "builtin.notes.shell");
     }
 
+    #[test]
+    fn quick_add_parser_registers_under_parser_capability() {
+        let mut registry = ExtensionRegistry::new();
+        registry
+            .register_first_party_baseline()
+            .expect("first-party baseline registration");
+        registry
+            .register_quick_add_parser()
+            .expect("quick-add parser registration");
+
+        let parsers: Vec<_> = registry
+            .list_by_capability("parser")
+            .into_iter()
+            .map(|entry| entry.manifest.id.as_str())
+            .collect();
+        assert_eq!(
+            parsers,
+            vec!["builtin.notes.shell", "builtin.quick_add.parser"]
+        );
+        assert_eq!(registry.list_by_capability("command").len(), 1);
+    }
+
     #[test]
     fn denies_undeclared_runtime_capability_by_default() {
         let mut registry = ExtensionRegistry::new();
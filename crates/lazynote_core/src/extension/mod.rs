@@ -2,8 +2,10 @@
 //!
 //! This module defines declaration-time contracts for extension manifests and
 //! in-process registry wiring. Runtime loading/sandbox execution is out of
-//! scope for v0.2 baseline.
+//! scope for v0.2 baseline; first-party capabilities (the quick-add
+//! `parser`) are plain in-process implementations.
 
 pub mod capability;
 pub mod kernel;
 pub mod manifest;
+pub mod quick_add;
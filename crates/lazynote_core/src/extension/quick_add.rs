@@ -0,0 +1,988 @@
+//! Natural-language quick-add parsing.
+//!
+//! # Responsibility
+//! - Turn one line of single-entry input, English or Chinese, into a typed
+//!   [`QuickAddDraft`]: kind, title, start/end, tags and priority.
+//! - Report the byte/char span of every recognized token for highlighting.
+//! - Act as the built-in implementation of the extension `parser`
+//!   capability ([`QUICK_ADD_PARSER_ID`]).
+//!
+//! # Invariants
+//! - Parsing is pure: the same input, [`LocalCalendar`] and `now_ms` always
+//!   yield the same draft. It never fails; unrecognized text is the title.
+//! - Kind prefix, date, time, duration and priority each take the earliest
+//!   match in the input (longest on ties); every `#tag` is kept.
+//! - Timed drafts resolve wall-clock times in the calendar's timezone;
+//!   date-only drafts are floating all-day spans.
+//! - A `note:` prefix disables date, time and priority recognition.
+//!
+//! # See also
+//! - docs/architecture/extension-kernel.md
+
+use crate::model::atom::{AtomType, TaskPriority};
+use crate::model::hashtag::hashtag_spans;
+use crate::model::local_time::{floating_span, LocalCalendar};
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Weekday};
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use std::cmp::Reverse;
+use std::ops::Range;
+
+/// Extension id of the built-in quick-add parser.
+pub const QUICK_ADD_PARSER_ID: &str = "builtin.quick_add.parser";
+
+const MINUTE_MS: i64 = 60_000;
+const DAY_MINUTES: u32 = 24 * 60;
+
+/// Category of one recognized quick-add token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuickAddTokenKind {
+    /// Leading kind prefix (`task:`, `日程：`).
+    Kind,
+    /// Date expression (`tomorrow`, `周五`, `5月4日`).
+    Date,
+    /// Time or time range (`3pm-4pm`, `下午三点`).
+    Time,
+    /// Duration (`for 30 min`, `一小时`).
+    Duration,
+    /// `#tag` marker.
+    Tag,
+    /// Priority marker (`!high`, `!高`).
+    Priority,
+}
+
+impl QuickAddTokenKind {
+    /// Stable lowercase label.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Kind => "kind",
+            Self::Date => "date",
+            Self::Time => "time",
+            Self::Duration => "duration",
+            Self::Tag => "tag",
+            Self::Priority => "priority",
+        }
+    }
+}
+
+/// Span of one recognized token inside the parsed input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuickAddToken {
+    /// Token category.
+    pub kind: QuickAddTokenKind,
+    /// Start byte offset (inclusive).
+    pub start: usize,
+    /// End byte offset (exclusive).
+    pub end: usize,
+    /// Start char offset (inclusive).
+    pub char_start: usize,
+    /// End char offset (exclusive).
+    pub char_end: usize,
+}
+
+/// Typed result of parsing one quick-add line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuickAddDraft {
+    /// Prefix kind, else `event` when a time was found, else `task`.
+    pub kind: AtomType,
+    /// Input with all recognized tokens removed.
+    pub title: String,
+    /// Epoch ms start, or floating date start when `all_day`.
+    pub start_at: Option<i64>,
+    /// Epoch ms end (equals `start_at` for point times), or floating date
+    /// end when `all_day`.
+    pub end_at: Option<i64>,
+    /// Whether a date without time was found.
+    pub all_day: bool,
+    /// Normalized tags in input order.
+    pub tags: Vec<String>,
+    /// Priority marker, if any.
+    pub priority: Option<TaskPriority>,
+    /// Recognized tokens ordered by position.
+    pub tokens: Vec<QuickAddToken>,
+}
+
+/// Deterministic quick-add parser bound to one local calendar.
+#[derive(Debug, Clone)]
+pub struct QuickAddParser {
+    calendar: LocalCalendar,
+}
+
+impl QuickAddParser {
+    /// Creates a parser resolving dates and times in `calendar`.
+    pub fn new(calendar: LocalCalendar) -> Self {
+        Self { calendar }
+    }
+
+    /// Parses `input` relative to the reference instant `now_ms`.
+    ///
+    /// Relative dates count from the local date of `now_ms`. A time without a
+    /// date is today, or tomorrow when that time has already passed.
+    pub fn parse(&self, input: &str, now_ms: i64) -> QuickAddDraft {
+        let today = self.calendar.local_date(now_ms);
+        let week_start = self.calendar.week_start();
+        let mut spans = Spans::new(input);
+
+        let kind_hint = KIND_RE.captures(input).and_then(|caps| {
+            let word = caps.get(2).or_else(|| caps.get(3))?;
+            spans.claim(caps.name("token")?.range(), QuickAddTokenKind::Kind);
+            Some(kind_from_prefix(word.as_str()))
+        });
+
+        let mut tags: Vec<String> = Vec::new();
+        for (range, tag) in hashtag_spans(input) {
+            if spans.is_free(&range) {
+                spans.claim(range, QuickAddTokenKind::Tag);
+                if !tags.contains(&tag) {
+                    tags.push(tag);
+                }
+            }
+        }
+
+        let mut draft = QuickAddDraft {
+            kind: kind_hint.unwrap_or(AtomType::Task),
+            title: String::new(),
+            start_at: None,
+            end_at: None,
+            all_day: false,
+            tags,
+            priority: None,
+            tokens: Vec::new(),
+        };
+        if kind_hint != Some(AtomType::Note) {
+            draft.priority =
+                spans.take_first(QuickAddTokenKind::Priority, priority_candidates(input));
+            let date = spans.take_first(
+                QuickAddTokenKind::Date,
+                date_candidates(input, today, week_start),
+            );
+            let time = spans.take_first(QuickAddTokenKind::Time, time_candidates(input));
+            if let Some(time) = time {
+                let duration =
+                    spans.take_first(QuickAddTokenKind::Duration, duration_candidates(input));
+                let (start_at, end_at) = self.resolve_time(today, now_ms, date, time, duration);
+                draft.start_at = Some(start_at);
+                draft.end_at = Some(end_at);
+                draft.kind = kind_hint.unwrap_or(AtomType::Event);
+            } else if let Some(date) = date {
+                let (start_at, end_at) = floating_span(date.date, date.date);
+                draft.start_at = Some(start_at);
+                draft.end_at = Some(end_at);
+                draft.all_day = true;
+            }
+        }
+
+        draft.title = spans.remainder();
+        draft.tokens = spans.tokens;
+        draft
+    }
+
+    fn resolve_time(
+        &self,
+        today: NaiveDate,
+        now_ms: i64,
+        date: Option<DateHit>,
+        time: TimeHit,
+        duration_minutes: Option<u32>,
+    ) -> (i64, i64) {
+        let evening = date.is_some_and(|hit| hit.evening);
+        let start_minutes = time.start.minutes(evening);
+        let end_minutes = time.end.map(|end| {
+            let end = end.minutes(evening);
+            if end <= start_minutes {
+                end + DAY_MINUTES
+            } else {
+                end
+            }
+        });
+
+        let mut day = date.map_or(today, |hit| hit.date);
+        let mut start_at = self.at_minutes(day, start_minutes);
+        if date.is_none() && start_at < now_ms {
+            day = day.succ_opt().unwrap_or(day);
+            start_at = self.at_minutes(day, start_minutes);
+        }
+        let end_at = match (end_minutes, duration_minutes) {
+            (Some(end), _) => self.at_minutes(day, end),
+            (None, Some(minutes)) => start_at + i64::from(minutes) * MINUTE_MS,
+            (None, None) => start_at,
+        };
+        (start_at, end_at)
+    }
+
+    fn at_minutes(&self, day: NaiveDate, minutes: u32) -> i64 {
+        self.calendar
+            .local_instant(day.and_time(NaiveTime::MIN) + Duration::minutes(i64::from(minutes)))
+    }
+}
+
+/// Recognized tokens over one input, kept ordered and non-overlapping.
+struct Spans<'a> {
+    input: &'a str,
+    tokens: Vec<QuickAddToken>,
+}
+
+impl<'a> Spans<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            tokens: Vec::new(),
+        }
+    }
+
+    fn is_free(&self, range: &Range<usize>) -> bool {
+        self.tokens
+            .iter()
+            .all(|token| range.end <= token.start || token.end <= range.start)
+    }
+
+    fn claim(&mut self, range: Range<usize>, kind: QuickAddTokenKind) {
+        let char_start = self.input[..range.start].chars().count();
+        let char_end = char_start + self.input[range.clone()].chars().count();
+        let index = self
+            .tokens
+            .partition_point(|token| token.start < range.start);
+        self.tokens.insert(
+            index,
+            QuickAddToken {
+                kind,
+                start: range.start,
+                end: range.end,
+                char_start,
+                char_end,
+            },
+        );
+    }
+
+    /// Claims the earliest free candidate (longest on ties).
+    fn take_first<T>(
+        &mut self,
+        kind: QuickAddTokenKind,
+        mut candidates: Vec<(Range<usize>, T)>,
+    ) -> Option<T> {
+        candidates.sort_by_key(|(range, _)| (range.start, Reverse(range.end)));
+        let (range, value) = candidates
+            .into_iter()
+            .find(|(range, _)| self.is_free(range))?;
+        self.claim(range, kind);
+        Some(value)
+    }
+
+    /// Input without tokens; whitespace collapsed, list punctuation trimmed.
+    fn remainder(&self) -> String {
+        let mut text = String::with_capacity(self.input.len());
+        let mut cursor = 0;
+        for token in &self.tokens {
+            text.push_str(&self.input[cursor..token.start]);
+            let before = self.input[..token.start].chars().next_back();
+            let after = self.input[token.end..].chars().next();
+            // CJK text has no spaces; joining its halves must not add one.
+            let glued =
+                matches!((before, after), (Some(b), Some(a)) if is_cjk_text(b) && is_cjk_text(a));
+            if !glued {
+                text.push(' ');
+            }
+            cursor = token.end;
+        }
+        text.push_str(&self.input[cursor..]);
+        text.split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .trim_matches(|ch| matches!(ch, ',' | '，' | '、' | ':' | '：' | ';' | '；' | ' '))
+            .to_string()
+    }
+}
+
+fn is_cjk_text(ch: char) -> bool {
+    !ch.is_ascii() && !ch.is_whitespace()
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DateHit {
+    date: NaiveDate,
+    /// `tonight` / `今晚`: bare hours mean evening.
+    evening: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Period {
+    Am,
+    Pm,
+    /// 24-hour clock (`15:30`).
+    Clock24,
+    /// 凌晨/早上/上午.
+    Morning,
+    /// 中午.
+    Noon,
+    /// 下午/傍晚.
+    Afternoon,
+    /// 晚上/夜里.
+    Evening,
+    /// Hour without a period (`三点`, `at 5`).
+    Bare,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ClockHit {
+    hour: u32,
+    minute: u32,
+    period: Period,
+}
+
+impl ClockHit {
+    /// Minutes after local midnight; may exceed one day (`晚上十二点`).
+    fn minutes(self, evening: bool) -> u32 {
+        let hour = match self.period {
+            Period::Am => self.hour % 12,
+            Period::Pm => self.hour % 12 + 12,
+            Period::Morning => self.hour,
+            Period::Noon if self.hour < 3 => self.hour + 12,
+            Period::Noon => self.hour,
+            Period::Afternoon if self.hour < 12 => self.hour + 12,
+            Period::Afternoon => self.hour,
+            Period::Evening if self.hour <= 12 => self.hour + 12,
+            Period::Evening => self.hour,
+            Period::Clock24 | Period::Bare if evening && self.hour < 12 => self.hour + 12,
+            Period::Clock24 => self.hour,
+            // Appointments at 1-7 a.m. are rare enough to need `凌晨`/`早上`.
+            Period::Bare if (1..=7).contains(&self.hour) => self.hour + 12,
+            Period::Bare => self.hour,
+        };
+        hour * 60 + self.minute
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TimeHit {
+    start: ClockHit,
+    end: Option<ClockHit>,
+}
+
+/// Builds a case-insensitive pattern whose `\b` is ASCII-only, so English
+/// words glued to CJK text still match.
+fn english(pattern: &str) -> Regex {
+    Regex::new(&format!("(?i){}", pattern.replace(r"\b", r"(?-u:\b)")))
+        .expect("valid quick-add regex")
+}
+
+const EN_PREP: &str = r"(?:(?:on|by)\s+)?";
+const EN_MONTHS: &str = "january|jan|february|feb|march|mar|april|apr|may|june|jun|july|jul|\
+                         august|aug|september|sept|sep|october|oct|november|nov|december|dec";
+const CN_NUM: &str = "[零一二两三四五六七八九十]{1,3}";
+const CN_PERIOD: &str = "早上|早晨|清晨|上午|凌晨|中午|下午|傍晚|晚上|夜里|夜间";
+
+static KIND_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\s*(?P<token>(?i:(note|task|todo|event)|(笔记|任务|待办|日程|事件))\s*[:：])")
+        .expect("valid quick-add regex")
+});
+static PRIORITY_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:^|\s)(?P<token>[!！](?i:(?P<level>high|medium|med|low|高|中|低)))(?:\s|$)")
+        .expect("valid quick-add regex")
+});
+static EN_KEYWORD_DATE_RE: Lazy<Regex> = Lazy::new(|| {
+    english(&format!(
+        r"\b{EN_PREP}(today|tonight|tomorrow|tmrw|tmr|day after tomorrow)\b"
+    ))
+});
+static EN_WEEKDAY_RE: Lazy<Regex> = Lazy::new(|| {
+    english(&format!(
+        r"\b{EN_PREP}(?:(next|this)\s+)?(monday|mon|tuesday|tues|tue|wednesday|wed|thursday|thurs|thur|thu|friday|fri|saturday|sunday)\b"
+    ))
+});
+static EN_RELATIVE_DATE_RE: Lazy<Regex> =
+    Lazy::new(|| english(r"\bin\s+(\d{1,3})\s+(days?|weeks?)\b"));
+static EN_ISO_DATE_RE: Lazy<Regex> =
+    Lazy::new(|| english(&format!(r"\b{EN_PREP}(\d{{4}})-(\d{{1,2}})-(\d{{1,2}})\b")));
+static EN_SLASH_DATE_RE: Lazy<Regex> = Lazy::new(|| {
+    english(&format!(
+        r"\b{EN_PREP}(\d{{1,2}})/(\d{{1,2}})(?:/(\d{{4}}))?\b"
+    ))
+});
+static EN_MONTH_DAY_RE: Lazy<Regex> = Lazy::new(|| {
+    english(&format!(
+        r"\b{EN_PREP}({EN_MONTHS})\.?\s+(\d{{1,2}})(?:st|nd|rd|th)?\b(?:,?\s+(\d{{4}})\b)?"
+    ))
+});
+static EN_DAY_MONTH_RE: Lazy<Regex> = Lazy::new(|| {
+    english(&format!(
+        r"\b{EN_PREP}(\d{{1,2}})(?:st|nd|rd|th)?\s+(?:of\s+)?({EN_MONTHS})\b(?:,?\s+(\d{{4}})\b)?"
+    ))
+});
+static CN_KEYWORD_DATE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new("大后天|后天|明天|明日|今天|今日|今晚").expect("valid quick-add regex")
+});
+static CN_WEEKDAY_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new("(下下|下|这|本|上)?个?(?:周|星期|礼拜)([一二三四五六日天1-7])")
+        .expect("valid quick-add regex")
+});
+static CN_MONTH_DAY_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r"(?:(\d{{4}})年)?(\d{{1,2}}|{CN_NUM})月(\d{{1,2}}|{CN_NUM})[日号]"
+    ))
+    .expect("valid quick-add regex")
+});
+static CN_RELATIVE_DATE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(r"(\d{{1,3}}|{CN_NUM})(天|周|个星期|星期)之?后"))
+        .expect("valid quick-add regex")
+});
+static EN_TIME_RANGE_RE: Lazy<Regex> = Lazy::new(|| {
+    english(
+        r"\b(?:(?:at|from)\s+)?(\d{1,2})(?::(\d{2}))?\s*(am|pm)?\s*(?:-|–|\bto\b|\buntil\b|\btill\b)\s*(\d{1,2})(?::(\d{2}))?\s*(am|pm)?\b",
+    )
+});
+static EN_MERIDIEM_TIME_RE: Lazy<Regex> =
+    Lazy::new(|| english(r"\b(?:at\s+)?(\d{1,2})(?::(\d{2}))?\s*(am|pm)\b"));
+static EN_CLOCK_TIME_RE: Lazy<Regex> = Lazy::new(|| english(r"\b(?:at\s+)?(\d{1,2}):(\d{2})\b"));
+static EN_AT_HOUR_RE: Lazy<Regex> = Lazy::new(|| english(r"\bat\s+(\d{1,2})\b"));
+static EN_NAMED_TIME_RE: Lazy<Regex> = Lazy::new(|| english(r"\b(?:at\s+)?(noon|midnight)\b"));
+static CN_TIME_RE: Lazy<Regex> = Lazy::new(|| {
+    let hour = format!(r"\d{{1,2}}|{CN_NUM}");
+    let minute = format!(
+        r"半|一刻|三刻|\d{{1,2}}分|\d{{2}}|{CN_NUM}分|[一二三四五]?十[一二三四五六七八九]?"
+    );
+    let clock = |n: u8| {
+        format!(
+            r"(?P<p{n}>{CN_PERIOD})?(?:(?P<h{n}>{hour})[点點时](?P<m{n}>{minute})?|(?P<hc{n}>\d{{1,2}})[:：](?P<mc{n}>\d{{2}}))"
+        )
+    };
+    Regex::new(&format!(
+        r"{}(?:\s*(?:到|至|-|–|~|～)\s*{})?",
+        clock(1),
+        clock(2)
+    ))
+    .expect("valid quick-add regex")
+});
+static CN_PERIOD_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(CN_PERIOD).expect("valid quick-add regex"));
+static EN_DURATION_RE: Lazy<Regex> = Lazy::new(|| {
+    english(
+        r"\bfor\s+(?:(\d{1,3})\s*(minutes|minute|mins|min|m|hours|hour|hrs|hr|h)|(an|one|half\s+an)\s+hour)\b",
+    )
+});
+static CN_DURATION_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r"(?:(?P<n>\d{{1,3}}|{CN_NUM})个?(?P<half>半)?|(?P<only_half>半)个?)(?P<unit>小时|钟头|分钟)"
+    ))
+    .expect("valid quick-add regex")
+});
+
+fn kind_from_prefix(prefix: &str) -> AtomType {
+    match prefix.to_ascii_lowercase().as_str() {
+        "note" | "笔记" => AtomType::Note,
+        "event" | "日程" | "事件" => AtomType::Event,
+        _ => AtomType::Task,
+    }
+}
+
+fn priority_candidates(input: &str) -> Vec<(Range<usize>, TaskPriority)> {
+    // Separators are consumed by each match, so scan from every token start.
+    let mut found = Vec::new();
+    let mut from = 0;
+    while let Some(caps) = PRIORITY_RE.captures_at(input, from) {
+        let token = caps.name("token").expect("token group");
+        let priority = match caps["level"].to_ascii_lowercase().as_str() {
+            "high" | "高" => TaskPriority::High,
+            "medium" | "med" | "中" => TaskPriority::Medium,
+            _ => TaskPriority::Low,
+        };
+        found.push((token.range(), priority));
+        from = token.end();
+    }
+    found
+}
+
+fn date_candidates(
+    input: &str,
+    today: NaiveDate,
+    week_start: Weekday,
+) -> Vec<(Range<usize>, DateHit)> {
+    let mut found = Vec::new();
+    let mut push = |range: Range<usize>, date: Option<NaiveDate>, evening: bool| {
+        if let Some(date) = date {
+            found.push((range, DateHit { date, evening }));
+        }
+    };
+
+    for caps in EN_KEYWORD_DATE_RE.captures_iter(input) {
+        let keyword = caps[1].to_ascii_lowercase();
+        let offset = match keyword.as_str() {
+            "today" | "tonight" => 0,
+            "day after tomorrow" => 2,
+            _ => 1,
+        };
+        push(whole(&caps), add_days(today, offset), keyword == "tonight");
+    }
+    for caps in EN_WEEKDAY_RE.captures_iter(input) {
+        let week_offset = caps
+            .get(1)
+            .map(|word| i64::from(word.as_str().eq_ignore_ascii_case("next")));
+        let weekday = english_weekday(&caps[2]);
+        push(
+            whole(&caps),
+            weekday_date(today, week_start, weekday, week_offset),
+            false,
+        );
+    }
+    for caps in EN_RELATIVE_DATE_RE.captures_iter(input) {
+        let count: i64 = caps[1].parse().unwrap_or(0);
+        let per_unit = if caps[2].to_ascii_lowercase().starts_with("week") {
+            7
+        } else {
+            1
+        };
+        push(whole(&caps), add_days(today, count * per_unit), false);
+    }
+    for caps in EN_ISO_DATE_RE.captures_iter(input) {
+        let date = ymd(number(&caps, 1), number(&caps, 2), number(&caps, 3));
+        push(whole(&caps), date, false);
+    }
+    for caps in EN_SLASH_DATE_RE.captures_iter(input) {
+        let date = month_day(today, caps.get(3), number(&caps, 1), number(&caps, 2));
+        push(whole(&caps), date, false);
+    }
+    for caps in EN_MONTH_DAY_RE.captures_iter(input) {
+        let date = month_day(
+            today,
+            caps.get(3),
+            english_month(&caps[1]),
+            number(&caps, 2),
+        );
+        push(whole(&caps), date, false);
+    }
+    for caps in EN_DAY_MONTH_RE.captures_iter(input) {
+        let date = month_day(
+            today,
+            caps.get(3),
+            english_month(&caps[2]),
+            number(&caps, 1),
+        );
+        push(whole(&caps), date, false);
+    }
+
+    for matched in CN_KEYWORD_DATE_RE.find_iter(input) {
+        let offset = match matched.as_str() {
+            "今天" | "今日" | "今晚" => 0,
+            "明天" | "明日" => 1,
+            "后天" => 2,
+            _ => 3,
+        };
+        push(
+            matched.range(),
+            add_days(today, offset),
+            matched.as_str() == "今晚",
+        );
+    }
+    for caps in CN_WEEKDAY_RE.captures_iter(input) {
+        let week_offset = caps.get(1).map(|prefix| match prefix.as_str() {
+            "下下" => 2,
+            "下" => 1,
+            "上" => -1,
+            _ => 0,
+        });
+        let weekday = match &caps[2] {
+            "一" | "1" => Weekday::Mon,
+            "二" | "2" => Weekday::Tue,
+            "三" | "3" => Weekday::Wed,
+            "四" | "4" => Weekday::Thu,
+            "五" | "5" => Weekday::Fri,
+            "六" | "6" => Weekday::Sat,
+            _ => Weekday::Sun,
+        };
+        push(
+            whole(&caps),
+            weekday_date(today, week_start, weekday, week_offset),
+            false,
+        );
+    }
+    for caps in CN_MONTH_DAY_RE.captures_iter(input) {
+        let month = cn_number(&caps[2]).unwrap_or(0);
+        let day = cn_number(&caps[3]).unwrap_or(0);
+        push(
+            whole(&caps),
+            month_day(today, caps.get(1), month, day),
+            false,
+        );
+    }
+    for caps in CN_RELATIVE_DATE_RE.captures_iter(input) {
+        let count = cn_number(&caps[1]).map(i64::from);
+        let per_unit = if &caps[2] == "天" { 1 } else { 7 };
+        push(
+            whole(&caps),
+            count.and_then(|count| add_days(today, count * per_unit)),
+            false,
+        );
+    }
+    found
+}
+
+fn time_candidates(input: &str) -> Vec<(Range<usize>, TimeHit)> {
+    let mut found = Vec::new();
+
+    for caps in EN_TIME_RANGE_RE.captures_iter(input) {
+        let meridiem = |index: usize| caps.get(index).map(|value| meridiem_period(value.as_str()));
+        let (start_period, end_period) = match (meridiem(3), meridiem(6)) {
+            (Some(start), Some(end)) => (start, end),
+            (None, Some(end)) => {
+                let start = clock(number(&caps, 1), minute(&caps, 2), end);
+                let ordered = match (start, clock(number(&caps, 4), minute(&caps, 5), end)) {
+                    (Some(start), Some(end)) => start.minutes(false) < end.minutes(false),
+                    _ => true,
+                };
+                (if ordered { end } else { flip(end) }, end)
+            }
+            (Some(start), None) => {
+                let ordered = match (
+                    clock(number(&caps, 1), minute(&caps, 2), start),
+                    clock(number(&caps, 4), minute(&caps, 5), start),
+                ) {
+                    (Some(first), Some(second)) => first.minutes(false) < second.minutes(false),
+                    _ => true,
+                };
+                (start, if ordered { start } else { flip(start) })
+            }
+            // Bare `3-4` is too ambiguous; only `15:00-16:30` is a range.
+            (None, None) if caps.get(2).is_some() && caps.get(5).is_some() => {
+                (Period::Clock24, Period::Clock24)
+            }
+            (None, None) => continue,
+        };
+        let start = clock(number(&caps, 1), minute(&caps, 2), start_period);
+        let end = clock(number(&caps, 4), minute(&caps, 5), end_period);
+        if let (Some(start), Some(end)) = (start, end) {
+            found.push((
+                whole(&caps),
+                TimeHit {
+                    start,
+                    end: Some(end),
+                },
+            ));
+        }
+    }
+    for caps in EN_MERIDIEM_TIME_RE.captures_iter(input) {
+        let period = meridiem_period(&caps[3]);
+        if let Some(start) = clock(number(&caps, 1), minute(&caps, 2), period) {
+            found.push((whole(&caps), TimeHit { start, end: None }));
+        }
+    }
+    for caps in EN_CLOCK_TIME_RE.captures_iter(input) {
+        if let Some(start) = clock(number(&caps, 1), minute(&caps, 2), Period::Clock24) {
+            found.push((whole(&caps), TimeHit { start, end: None }));
+        }
+    }
+    for caps in EN_AT_HOUR_RE.captures_iter(input) {
+        if let Some(start) = clock(number(&caps, 1), 0, Period::Bare) {
+            found.push((whole(&caps), TimeHit { start, end: None }));
+        }
+    }
+    for caps in EN_NAMED_TIME_RE.captures_iter(input) {
+        let hour = if caps[1].eq_ignore_ascii_case("noon") {
+            12
+        } else {
+            0
+        };
+        let start = ClockHit {
+            hour,
+            minute: 0,
+            period: Period::Clock24,
+        };
+        found.push((whole(&caps), TimeHit { start, end: None }));
+    }
+
+    for caps in CN_TIME_RE.captures_iter(input) {
+        let Some(start) = cn_clock(&caps, 1, None) else {
+            continue;
+        };
+        let end = if has_clock(&caps, 2) {
+            match cn_clock(&caps, 2, Some(start.period)) {
+                Some(end) => Some(end),
+                None => continue,
+            }
+        } else {
+            None
+        };
+        found.push((whole(&caps), TimeHit { start, end }));
+    }
+    // A period alone (`明天下午`) stands for a typical hour of that period.
+    for matched in CN_PERIOD_RE.find_iter(input) {
+        let hour = match matched.as_str() {
+            "早上" | "早晨" | "清晨" => 8,
+            "上午" => 9,
+            "凌晨" => 5,
+            "中午" => 12,
+            "下午" => 15,
+            "傍晚" => 18,
+            "晚上" => 20,
+            _ => 22,
+        };
+        let start = ClockHit {
+            hour,
+            minute: 0,
+            period: Period::Clock24,
+        };
+        found.push((matched.range(), TimeHit { start, end: None }));
+    }
+    found
+}
+
+fn duration_candidates(input: &str) -> Vec<(Range<usize>, u32)> {
+    let mut found = Vec::new();
+    for caps in EN_DURATION_RE.captures_iter(input) {
+        let minutes = match (caps.get(1), caps.get(2), caps.get(3)) {
+            (Some(count), Some(unit), _) => {
+                let count: u32 = count.as_str().parse().unwrap_or(0);
+                if unit.as_str().to_ascii_lowercase().starts_with('m') {
+                    count
+                } else {
+                    count * 60
+                }
+            }
+            (_, _, Some(article)) if article.as_str().to_ascii_lowercase().starts_with("half") => {
+                30
+            }
+            _ => 60,
+        };
+        if minutes > 0 {
+            found.push((whole(&caps), minutes));
+        }
+    }
+    for caps in CN_DURATION_RE.captures_iter(input) {
+        let hours = caps
+            .name("unit")
+            .is_some_and(|unit| unit.as_str() != "分钟");
+        let minutes = match caps.name("n") {
+            Some(count) => {
+                let Some(count) = cn_number(count.as_str()) else {
+                    continue;
+                };
+                match (hours, caps.name("half").is_some()) {
+                    (true, half) => count * 60 + if half { 30 } else { 0 },
+                    (false, false) => count,
+                    (false, true) => continue,
+                }
+            }
+            None if hours => 30,
+            None => continue,
+        };
+        if minutes > 0 {
+            found.push((whole(&caps), minutes));
+        }
+    }
+    found
+}
+
+fn whole(caps: &Captures<'_>) -> Range<usize> {
+    caps.get(0).expect("group 0 always matches").range()
+}
+
+fn number(caps: &Captures<'_>, index: usize) -> u32 {
+    caps.get(index)
+        .and_then(|value| value.as_str().parse().ok())
+        .unwrap_or(u32::MAX)
+}
+
+fn minute(caps: &Captures<'_>, index: usize) -> u32 {
+    caps.get(index)
+        .map_or(0, |value| value.as_str().parse().unwrap_or(u32::MAX))
+}
+
+fn meridiem_period(value: &str) -> Period {
+    if value.eq_ignore_ascii_case("am") {
+        Period::Am
+    } else {
+        Period::Pm
+    }
+}
+
+fn flip(period: Period) -> Period {
+    if period == Period::Am {
+        Period::Pm
+    } else {
+        Period::Am
+    }
+}
+
+fn clock(hour: u32, minute: u32, period: Period) -> Option<ClockHit> {
+    let valid_hour = match period {
+        Period::Am | Period::Pm => (1..=12).contains(&hour),
+        _ => hour <= 23,
+    };
+    (valid_hour && minute < 60).then_some(ClockHit {
+        hour,
+        minute,
+        period,
+    })
+}
+
+fn has_clock(caps: &Captures<'_>, n: u8) -> bool {
+    caps.name(&format!("h{n}")).is_some() || caps.name(&format!("hc{n}")).is_some()
+}
+
+/// Reads clock `n` of a [`CN_TIME_RE`] match; an end clock without its own
+/// period inherits the start's.
+fn cn_clock(caps: &Captures<'_>, n: u8, inherited: Option<Period>) -> Option<ClockHit> {
+    let period = match caps.name(&format!("p{n}")) {
+        Some(word) => match word.as_str() {
+            "中午" => Period::Noon,
+            "下午" | "傍晚" => Period::Afternoon,
+            "晚上" | "夜里" | "夜间" => Period::Evening,
+            _ => Period::Morning,
+        },
+        None => inherited.unwrap_or(Period::Bare),
+    };
+    if let Some(hour) = caps.name(&format!("h{n}")) {
+        let minute_text = caps.name(&format!("m{n}")).map(|value| value.as_str());
+        // A bare `一点` is far more often "a little" than 1 p.m.
+        if period == Period::Bare && hour.as_str() == "一" && minute_text.is_none() {
+            return None;
+        }
+        let minute = match minute_text {
+            None => 0,
+            Some("半") => 30,
+            Some("一刻") => 15,
+            Some("三刻") => 45,
+            Some(value) => cn_number(value.trim_end_matches('分'))?,
+        };
+        return clock(cn_number(hour.as_str())?, minute, period);
+    }
+    // Colon clocks are Chinese only with a period; bare ones are English.
+    if caps.name(&format!("p{n}")).is_none() && inherited.is_none() {
+        return None;
+    }
+    let hour = caps.name(&format!("hc{n}"))?.as_str().parse().ok()?;
+    let minute = caps.name(&format!("mc{n}"))?.as_str().parse().ok()?;
+    clock(hour, minute, period)
+}
+
+/// Parses ASCII digits or Chinese numerals up to 99 (`十五`, `二十三`).
+fn cn_number(value: &str) -> Option<u32> {
+    if let Ok(number) = value.parse() {
+        return Some(number);
+    }
+    let digit = |ch: char| match ch {
+        '零' => Some(0),
+        '一' => Some(1),
+        '二' | '两' => Some(2),
+        '三' => Some(3),
+        '四' => Some(4),
+        '五' => Some(5),
+        '六' => Some(6),
+        '七' => Some(7),
+        '八' => Some(8),
+        '九' => Some(9),
+        _ => None,
+    };
+    let value = value.trim_start_matches('零');
+    let mut parts = value.splitn(2, '十');
+    let head = parts.next()?;
+    match parts.next() {
+        Some(tail) => {
+            let tens = match head.chars().collect::<Vec<_>>().as_slice() {
+                [] => 1,
+                [ch] => digit(*ch)?,
+                _ => return None,
+            };
+            let ones = match tail.chars().collect::<Vec<_>>().as_slice() {
+                [] => 0,
+                [ch] => digit(*ch)?,
+                _ => return None,
+            };
+            Some(tens * 10 + ones)
+        }
+        None => match head.chars().collect::<Vec<_>>().as_slice() {
+            [] => Some(0),
+            [ch] => digit(*ch),
+            _ => None,
+        },
+    }
+}
+
+fn english_weekday(word: &str) -> Weekday {
+    match &word.to_ascii_lowercase()[..3] {
+        "mon" => Weekday::Mon,
+        "tue" => Weekday::Tue,
+        "wed" => Weekday::Wed,
+        "thu" => Weekday::Thu,
+        "fri" => Weekday::Fri,
+        "sat" => Weekday::Sat,
+        _ => Weekday::Sun,
+    }
+}
+
+fn english_month(word: &str) -> u32 {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    let prefix = word.to_ascii_lowercase();
+    MONTHS
+        .iter()
+        .position(|month| prefix.starts_with(month))
+        .map_or(0, |index| index as u32 + 1)
+}
+
+fn add_days(date: NaiveDate, days: i64) -> Option<NaiveDate> {
+    date.checked_add_signed(Duration::days(days))
+}
+
+fn ymd(year: u32, month: u32, day: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(i32::try_from(year).ok()?, month, day)
+}
+
+/// Month/day in `year`, else its next occurrence on or after `today`.
+fn month_day(
+    today: NaiveDate,
+    year: Option<regex::Match<'_>>,
+    month: u32,
+    day: u32,
+) -> Option<NaiveDate> {
+    if let Some(year) = year {
+        return ymd(year.as_str().parse().ok()?, month, day);
+    }
+    let this_year = NaiveDate::from_ymd_opt(today.year(), month, day);
+    match this_year {
+        Some(date) if date >= today => Some(date),
+        _ => NaiveDate::from_ymd_opt(today.year() + 1, month, day),
+    }
+}
+
+/// Plain weekdays are the next such day, today included; `week_offset`
+/// picks the day inside this (`0`), next (`1`) or another local week.
+fn weekday_date(
+    today: NaiveDate,
+    week_start: Weekday,
+    weekday: Weekday,
+    week_offset: Option<i64>,
+) -> Option<NaiveDate> {
+    let since = |day: Weekday, from: Weekday| {
+        i64::from((day.num_days_from_monday() + 7 - from.num_days_from_monday()) % 7)
+    };
+    match week_offset {
+        None => add_days(today, since(weekday, today.weekday())),
+        Some(offset) => {
+            let first = add_days(today, -since(today.weekday(), week_start))?;
+            add_days(first, offset * 7 + since(weekday, week_start))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cn_number;
+
+    #[test]
+    fn cn_number_reads_digits_and_numerals() {
+        let cases = [
+            ("15", Some(15)),
+            ("三", Some(3)),
+            ("两", Some(2)),
+            ("十", Some(10)),
+            ("十五", Some(15)),
+            ("二十", Some(20)),
+            ("三十一", Some(31)),
+            ("零五", Some(5)),
+            ("三四", None),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(cn_number(input), expected, "{input}");
+        }
+    }
+}
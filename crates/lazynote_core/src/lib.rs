@@ -34,20 +34,96 @@ pub use extension::manifest::{
     supported_capabilities, ExtensionManifest, ManifestEntrypoints, ManifestValidationError,
     CAPABILITY_COMMAND, CAPABILITY_PARSER, CAPABILITY_PROVIDER, CAPABILITY_UI_SLOT,
 };
+/// Re-export the built-in natural-language quick-add parser.
+pub use extension::quick_add::{
+    QuickAddDraft, QuickAddParser, QuickAddToken, QuickAddTokenKind, QUICK_ADD_PARSER_ID,
+};
 /// Re-export logging entry points for FFI/UI layers.
 pub use logging::{
     default_log_level, init_logging, log_dart_event, logging_status, LogDartEventError,
 };
 /// Re-export canonical Atom model types.
-pub use model::atom::{Atom, AtomId, AtomType, AtomValidationError, TaskStatus};
+pub use model::atom::{Atom, AtomId, AtomType, AtomValidationError, TaskPriority, TaskStatus};
+/// Re-export attachment model and `attachment://` URI helpers.
+pub use model::attachment::{
+    attachment_uri, guess_mime_type, parse_attachment_refs, parse_attachment_uri, Attachment,
+    ATTACHMENT_URI_SCHEME,
+};
+/// Re-export YAML frontmatter parsing and typed property models.
+pub use model::frontmatter::{
+    date_epoch_ms, parse_frontmatter, split_frontmatter, strip_frontmatter, AtomProperty,
+    Frontmatter, PropertyValue, ALIAS_TAG_PREFIX,
+};
+/// Re-export inline hashtag extraction.
+pub use model::hashtag::parse_hashtags;
+/// Re-export wiki-link parsing and link graph models.
+pub use model::link::{normalize_link_key, parse_wiki_links, AtomLink, WikiLinkKind, WikiLinkRef};
+/// Re-export timezone-aware local calendar types.
+pub use model::local_time::{
+    floating_date_ms, floating_dates, floating_span, LocalCalendar, LocalWindow, TimeZoneError,
+    DAY_MS,
+};
+/// Re-export CommonMark/GFM analysis (preview, title, outline, content stats, checklists).
+pub use model::markdown::{
+    analyze_markdown, checklist_items, content_stats, derive_note_title, markdown_outline,
+    ChecklistItem, ContentStats, MarkdownAnalysis, OutlineHeading, NOTE_TITLE_MAX_CHARS,
+    PREVIEW_TEXT_MAX_CHARS, UNTITLED_NOTE_TITLE,
+};
+/// Re-export recurrence rule model types.
+pub use model::recurrence::{
+    Frequency, RecurrenceError, RecurrenceRule, RecurrenceUntil, WeekdaySpec,
+};
+/// Re-export reminder models.
+pub use model::reminder::{DueReminder, Reminder, ReminderId, ReminderTrigger};
+/// Re-export resource link (Markdown link / bare URL) models.
+pub use model::resource_link::{
+    parse_resource_links, ResourceLink, ResourceLinkRef, ResourceLinkSource,
+};
+/// Re-export atom revision and line diff models.
+pub use model::revision::{diff_lines, AtomRevision, DiffLine, DiffLineKind, RevisionId};
+/// Re-export boolean tag expression model and parser.
+pub use model::tag_expr::{parse_tag_expr, TagExpr, TagExprParseError, TagExprParseErrorKind};
+/// Re-export time tracking entry models.
+pub use model::time_entry::{TimeEntry, TimeEntryId};
 /// Re-export repository contracts and SQLite implementation.
 pub use repo::atom_repo::{
-    AtomListQuery, AtomRepository, RepoError, RepoResult, SectionAtomRow, SqliteAtomRepository,
+    AtomListQuery, AtomRepository, RepoError, RepoResult, SectionAtomRow, SectionOptions,
+    SqliteAtomRepository,
 };
+/// Re-export attachment metadata repository contracts and implementation.
+pub use repo::attachment_repo::{AttachmentRepository, SqliteAttachmentRepository};
+/// Re-export wiki-link graph repository contracts and implementation.
+pub use repo::link_repo::{LinkRepository, SqliteLinkRepository};
 /// Re-export notes/tags repository models and implementation.
 pub use repo::note_repo::{
     load_tags_for_atoms, normalize_note_limit, normalize_tag, normalize_tags, NoteListQuery,
-    NoteRecord, NoteRepository, SqliteNoteRepository,
+    NoteRecord, NoteRepository, NoteTag, SqliteNoteRepository, TagEditOutcome, TagSource, TagUsage,
+};
+/// Re-export frontmatter property repository contracts and implementation.
+pub use repo::property_repo::{PropertyRepository, SqlitePropertyRepository};
+/// Re-export recurrence exception repository contracts and implementation.
+pub use repo::recurrence_repo::{
+    RecurrenceException, RecurrenceRepository, SqliteRecurrenceRepository,
+};
+/// Re-export reminder repository contracts and implementation.
+pub use repo::reminder_repo::{ReminderRepository, SqliteReminderRepository};
+/// Re-export resource link index repository contracts and implementation.
+pub use repo::resource_link_repo::{ResourceLinkRepository, SqliteResourceLinkRepository};
+/// Re-export atom revision repository contracts and implementation.
+pub use repo::revision_repo::{
+    normalize_revision_limit, RevisionRepository, SqliteRevisionRepository,
+    REVISION_COALESCE_WINDOW_MS,
+};
+/// Re-export subtask progress model.
+pub use repo::subtask_repo::SubtaskProgress;
+/// Re-export time entry repository contracts and implementation.
+pub use repo::time_entry_repo::{SqliteTimeEntryRepository, TimeEntryRepository};
+/// Re-export note title projection model.
+pub use repo::title_repo::NoteTitle;
+/// Re-export trash repository contracts and implementation.
+pub use repo::trash_repo::{
+    normalize_trash_limit, PurgeOutcome, PurgeSummary, SqliteTrashRepository, TrashItem,
+    TrashRepository,
 };
 /// Re-export workspace tree repository contracts and implementation.
 pub use repo::tree_repo::{
@@ -55,17 +131,71 @@ pub use repo::tree_repo::{
     WorkspaceNodeId, WorkspaceNodeKind,
 };
 /// Re-export search query/result models and search entry point.
-pub use search::fts::{search_all, SearchError, SearchHit, SearchQuery, SearchResult};
+pub use search::fts::{
+    search_all, search_page, select_search_strategy, HighlightRange, SearchBoost, SearchError,
+    SearchHit, SearchPage, SearchQuery, SearchResult, SearchStrategy,
+};
+/// Re-export structured search query grammar.
+pub use search::query::{
+    parse_search_query, FilterKind, ParsedQuery, PropertyComparison, QueryFilter, QueryParseError,
+    QueryParseErrorKind, TextTerm,
+};
 /// Re-export atom service facade.
-pub use service::atom_service::{AtomService, ScheduleEventRequest};
+pub use service::atom_service::{
+    AtomConvertError, AtomService, ConvertAtomRequest, ScheduleEventRequest,
+};
+/// Re-export attachment store service and models.
+pub use service::attachment_service::{
+    AttachmentGcSummary, AttachmentService, AttachmentServiceError, ImportedAttachment,
+    ATTACHMENT_GC_DEFAULT_GRACE_MS, ATTACHMENT_STORE_DIR_NAME,
+};
+/// Re-export calendar day buckets, overlap layout, conflicts and free slots.
+pub use service::calendar_service::{
+    CalendarConflict, CalendarDay, CalendarEntry, CalendarService, CalendarServiceError, FreeSlot,
+    CALENDAR_MAX_DAYS,
+};
+/// Re-export wiki-link graph service facade.
+pub use service::link_service::LinkService;
 /// Re-export notes service facade and models.
 pub use service::note_service::{
-    derive_markdown_preview, MarkdownPreview, NoteService, NoteServiceError, NotesListResult,
+    derive_markdown_preview, MarkdownPreview, NoteOutline, NoteService, NoteServiceError,
+    NotesListResult,
+};
+/// Re-export recurring occurrence edit service and models.
+pub use service::recurrence_service::{
+    OccurrenceEditScope, OccurrencePatch, RecurrenceService, RecurrenceServiceError,
+};
+/// Re-export reminder service and errors.
+pub use service::reminder_service::{ReminderService, ReminderServiceError};
+/// Re-export resource link search and launch planning service.
+pub use service::resource_link_service::{
+    build_launch_plan, LaunchPlan, LaunchPolicy, LaunchRejectReason, LaunchRejection, LaunchTarget,
+    LaunchTargetKind, ResourceLinkService, LAUNCH_DEFAULT_MAX_ITEMS, LAUNCH_DEFAULT_SCHEMES,
+    LAUNCH_MAX_ITEMS_LIMIT,
+};
+/// Re-export atom revision history service and models.
+pub use service::revision_service::{
+    RevisionDiff, RevisionListResult, RevisionService, RevisionServiceError,
 };
 /// Re-export task/section service facade and models.
-pub use service::task_service::{SectionAtom, TaskService, TaskServiceError};
+pub use service::task_service::{SectionAtom, StatusUpdateOptions, TaskService, TaskServiceError};
+/// Re-export time tracking service, report models and errors.
+pub use service::time_tracking_service::{
+    DayTimeTotal, FolderTimeTotal, TagTimeTotal, TimeEntryPatch, TimeTrackingService,
+    TimeTrackingServiceError, TIME_REPORT_MAX_DAYS,
+};
+/// Re-export trash lifecycle service and retention defaults.
+pub use service::trash_service::{
+    normalize_retention_days, TrashListResult, TrashService, TrashServiceError,
+    DEFAULT_TRASH_RETENTION_DAYS, MAX_TRASH_RETENTION_DAYS,
+};
 /// Re-export workspace tree service facade and errors.
 pub use service::tree_service::{FolderDeleteMode, TreeService, TreeServiceError};
+/// Re-export hybrid logical clock stamping and LWW helpers.
+pub use sync::hlc::{
+    compare_encoded, load_clock, local_node_id, observe_remote_hlc, resolve_lww, stamp_hlc,
+    HlcError, HlcResult, HlcTimestamp, HybridLogicalClock, LwwDecision, MAX_CLOCK_DRIFT_MS,
+};
 /// Re-export provider SPI and sync contract models.
 pub use sync::provider_registry::{ProviderRegistry, ProviderRegistryError};
 pub use sync::provider_spi::ProviderSpi;
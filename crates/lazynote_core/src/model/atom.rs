@@ -8,10 +8,14 @@
 //! - `uuid` is stable and never reused for another atom.
 //! - `is_deleted` is the source of truth for tombstone state.
 //! - `end_at` should not be earlier than `start_at` when both are set.
+//! - All-day atoms store floating dates (wall-clock midnight encoded as UTC
+//!   ms), never instants.
 //!
 //! # See also
 //! - docs/architecture/data-model.md
 
+use crate::model::local_time::{floating_dates, LocalCalendar};
+use crate::model::recurrence::{RecurrenceError, RecurrenceRule};
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
@@ -51,6 +55,18 @@ pub enum TaskStatus {
     Cancelled,
 }
 
+/// Task priority; atoms without one sort after `Low`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskPriority {
+    /// Nice to have.
+    Low,
+    /// Normal importance.
+    Medium,
+    /// Do first.
+    High,
+}
+
 /// Canonical domain record for note/task/event data.
 ///
 /// This model intentionally keeps task/event-specific fields optional, so
@@ -84,12 +100,30 @@ pub struct Atom {
     /// Meaningful only when `kind == AtomType::Task`.
     pub task_status: Option<TaskStatus>,
     /// Unix epoch milliseconds. Drives section classification (Inbox/Today/Upcoming).
+    ///
+    /// Floating (see `all_day`) when `all_day` is set.
     pub start_at: Option<i64>,
     /// Unix epoch milliseconds. Should be >= `start_at` when set.
     pub end_at: Option<i64>,
-    /// Reserved: RFC 5545 RRULE string for recurring atoms (v0.2+).
+    /// Whether `start_at`/`end_at` are floating dates rather than instants.
+    ///
+    /// All-day spans cover whole local days in every timezone: `start_at` is
+    /// the first date at `00:00` and `end_at` the last date at
+    /// `23:59:59.999`, both encoded as if UTC (see
+    /// [`crate::model::local_time::floating_span`]).
+    pub all_day: bool,
+    /// Explicit task priority; `None` means unprioritized.
+    pub priority: Option<TaskPriority>,
+    /// Unix epoch milliseconds. Deadline, independent of the scheduled
+    /// `start_at`/`end_at` window.
+    pub due_at: Option<i64>,
+    /// RFC 5545 RRULE value for recurring atoms (see [`RecurrenceRule`]).
+    ///
+    /// Requires `start_at`; the series anchor is the first occurrence.
     pub recurrence_rule: Option<String>,
-    /// Reserved for future CRDT/HLC merge strategy.
+    /// Encoded HLC stamp of the last local write (see `sync::hlc`).
+    ///
+    /// Assigned by repositories on every write; `None` for legacy rows.
     pub hlc_timestamp: Option<String>,
     /// Soft delete tombstone to preserve sync/recovery history.
     pub is_deleted: bool,
@@ -102,6 +136,15 @@ pub enum AtomValidationError {
     NilUuid,
     /// Event window is reversed (`end < start`).
     InvalidEventWindow { start: i64, end: i64 },
+    /// `recurrence_rule` cannot be parsed.
+    InvalidRecurrenceRule(RecurrenceError),
+    /// `recurrence_rule` is set but `start_at` anchor is missing.
+    RecurrenceWithoutStart,
+    /// `all_day` is set but `start_at`/`end_at` are not whole floating days.
+    InvalidAllDaySpan {
+        start: Option<i64>,
+        end: Option<i64>,
+    },
 }
 
 impl Display for AtomValidationError {
@@ -111,6 +154,14 @@ impl Display for AtomValidationError {
             Self::InvalidEventWindow { start, end } => {
                 write!(f, "end_at ({end}) must be >= start_at ({start})")
             }
+            Self::InvalidRecurrenceRule(err) => write!(f, "{err}"),
+            Self::RecurrenceWithoutStart => {
+                write!(f, "recurrence_rule requires start_at")
+            }
+            Self::InvalidAllDaySpan { start, end } => write!(
+                f,
+                "all-day span must start at 00:00 and end at 23:59:59.999 (start_at={start:?}, end_at={end:?})"
+            ),
         }
     }
 }
@@ -128,6 +179,10 @@ struct AtomDe {
     task_status: Option<TaskStatus>,
     start_at: Option<i64>,
     end_at: Option<i64>,
+    #[serde(default)]
+    all_day: bool,
+    priority: Option<TaskPriority>,
+    due_at: Option<i64>,
     recurrence_rule: Option<String>,
     hlc_timestamp: Option<String>,
     is_deleted: bool,
@@ -146,6 +201,9 @@ impl TryFrom<AtomDe> for Atom {
             task_status: value.task_status,
             start_at: value.start_at,
             end_at: value.end_at,
+            all_day: value.all_day,
+            priority: value.priority,
+            due_at: value.due_at,
             recurrence_rule: value.recurrence_rule,
             hlc_timestamp: value.hlc_timestamp,
             is_deleted: value.is_deleted,
@@ -171,6 +229,9 @@ impl Atom {
             task_status: None,
             start_at: None,
             end_at: None,
+            all_day: false,
+            priority: None,
+            due_at: None,
             recurrence_rule: None,
             hlc_timestamp: None,
             is_deleted: false,
@@ -208,6 +269,9 @@ impl Atom {
             task_status: None,
             start_at: None,
             end_at: None,
+            all_day: false,
+            priority: None,
+            due_at: None,
             recurrence_rule: None,
             hlc_timestamp: None,
             is_deleted: false,
@@ -242,6 +306,10 @@ impl Atom {
     /// - Returns [`AtomValidationError::NilUuid`] for nil IDs.
     /// - Returns [`AtomValidationError::InvalidEventWindow`] when event time
     ///   range is reversed.
+    /// - Returns [`AtomValidationError::InvalidRecurrenceRule`] or
+    ///   [`AtomValidationError::RecurrenceWithoutStart`] for unusable rules.
+    /// - Returns [`AtomValidationError::InvalidAllDaySpan`] when an all-day
+    ///   atom does not cover whole floating days.
     pub fn validate(&self) -> Result<(), AtomValidationError> {
         if self.uuid.is_nil() {
             return Err(AtomValidationError::NilUuid);
@@ -253,6 +321,48 @@ impl Atom {
             }
         }
 
+        if self.all_day && floating_dates(self.start_at, self.end_at).is_none() {
+            return Err(AtomValidationError::InvalidAllDaySpan {
+                start: self.start_at,
+                end: self.end_at,
+            });
+        }
+
+        if self.recurrence_rule.is_some() {
+            self.parsed_recurrence()?;
+            if self.start_at.is_none() {
+                return Err(AtomValidationError::RecurrenceWithoutStart);
+            }
+        }
+
         Ok(())
     }
+
+    /// Parses `recurrence_rule` when present.
+    ///
+    /// # Errors
+    /// - Returns [`AtomValidationError::InvalidRecurrenceRule`] when the rule
+    ///   text is malformed.
+    pub fn parsed_recurrence(&self) -> Result<Option<RecurrenceRule>, AtomValidationError> {
+        self.recurrence_rule
+            .as_deref()
+            .map(RecurrenceRule::parse)
+            .transpose()
+            .map_err(AtomValidationError::InvalidRecurrenceRule)
+    }
+
+    /// Returns whether this atom is a recurring series master.
+    pub fn is_recurring(&self) -> bool {
+        self.recurrence_rule.is_some()
+    }
+
+    /// Calendar the recurrence rule expands in: `local` for timed series,
+    /// UTC for floating all-day series.
+    pub fn recurrence_calendar(&self, local: LocalCalendar) -> LocalCalendar {
+        if self.all_day {
+            LocalCalendar::utc()
+        } else {
+            local
+        }
+    }
 }
@@ -0,0 +1,158 @@
+//! Content-addressed attachments and `attachment://` URIs.
+//!
+//! # Responsibility
+//! - Define the attachment read model and the stable URI format used in
+//!   markdown (`![](attachment://<sha256>)`, `[file](attachment://<sha256>)`).
+//! - Extract attachment references from atom content.
+//!
+//! # Invariants
+//! - An attachment is identified by the lowercase hex SHA-256 of its bytes;
+//!   identical files share one blob.
+//! - References inside fenced code blocks and inline code spans are ignored.
+//! - One reference per digest; first occurrence wins.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::resource_link::mask_code_spans;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashSet;
+
+/// URI scheme for stored attachments.
+pub const ATTACHMENT_URI_SCHEME: &str = "attachment";
+
+static ATTACHMENT_URI_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\battachment://([0-9a-f]{64})\b").expect("valid attachment uri regex")
+});
+
+/// One stored attachment blob.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attachment {
+    /// Lowercase hex SHA-256 of the file bytes; also the blob file name.
+    pub sha256: String,
+    /// Blob size in bytes.
+    pub byte_size: i64,
+    /// MIME type guessed from the first imported file name.
+    pub mime_type: Option<String>,
+    /// File name of the first import, for display only.
+    pub original_name: Option<String>,
+    /// Latest import timestamp in epoch milliseconds; GC grace counts from it.
+    pub created_at: i64,
+    /// Number of atoms (including trashed ones) whose content references
+    /// this attachment.
+    pub ref_count: u32,
+}
+
+impl Attachment {
+    /// Returns the markdown-usable `attachment://<sha256>` URI.
+    pub fn uri(&self) -> String {
+        attachment_uri(&self.sha256)
+    }
+}
+
+/// Builds the `attachment://<sha256>` URI for one digest.
+pub fn attachment_uri(sha256: &str) -> String {
+    format!("{ATTACHMENT_URI_SCHEME}://{sha256}")
+}
+
+/// Parses an `attachment://<sha256>` URI or a bare digest.
+///
+/// Returns the lowercase digest, or `None` when the value is not a SHA-256
+/// attachment reference.
+pub fn parse_attachment_uri(value: &str) -> Option<String> {
+    let trimmed = value.trim();
+    let digest = match trimmed.split_once("://") {
+        Some((scheme, rest)) if scheme.eq_ignore_ascii_case(ATTACHMENT_URI_SCHEME) => rest,
+        Some(_) => return None,
+        None => trimmed,
+    };
+    (digest.len() == 64 && digest.bytes().all(|b| b.is_ascii_hexdigit()))
+        .then(|| digest.to_ascii_lowercase())
+}
+
+/// Extracts referenced attachment digests from content in document order.
+pub fn parse_attachment_refs(content: &str) -> Vec<String> {
+    let mut refs = Vec::new();
+    let mut seen = HashSet::new();
+    let mut in_fence = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        let masked = mask_code_spans(line);
+        for captures in ATTACHMENT_URI_RE.captures_iter(&masked) {
+            let digest = captures[1].to_ascii_lowercase();
+            if seen.insert(digest.clone()) {
+                refs.push(digest);
+            }
+        }
+    }
+    refs
+}
+
+/// Guesses a MIME type from a file name extension.
+pub fn guess_mime_type(file_name: &str) -> Option<&'static str> {
+    let (_, extension) = file_name.rsplit_once('.')?;
+    let mime = match extension.to_ascii_lowercase().as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "bmp" => "image/bmp",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "json" => "application/json",
+        "txt" => "text/plain",
+        "md" | "markdown" => "text/markdown",
+        "csv" => "text/csv",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "mp4" => "video/mp4",
+        _ => return None,
+    };
+    Some(mime)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_attachment_refs, parse_attachment_uri};
+
+    #[test]
+    fn extracts_unique_refs_outside_code() {
+        let digest_a = "a".repeat(64);
+        let digest_b = "B".repeat(64);
+        let content = format!(
+            "![shot](attachment://{digest_a}) and [file](attachment://{digest_b})\n\
+             `attachment://{}`\n```\nattachment://{}\n```\nagain attachment://{digest_a}",
+            "c".repeat(64),
+            "d".repeat(64)
+        );
+        assert_eq!(
+            parse_attachment_refs(&content),
+            vec![digest_a, "b".repeat(64)]
+        );
+    }
+
+    #[test]
+    fn parses_uri_or_bare_digest() {
+        let digest = "0f".repeat(32);
+        assert_eq!(
+            parse_attachment_uri(&format!("attachment://{digest}")),
+            Some(digest.clone())
+        );
+        assert_eq!(
+            parse_attachment_uri(&digest.to_uppercase()),
+            Some(digest.clone())
+        );
+        assert_eq!(parse_attachment_uri(&format!("https://{digest}")), None);
+        assert_eq!(parse_attachment_uri("attachment://abc"), None);
+    }
+}
@@ -0,0 +1,469 @@
+//! YAML frontmatter parsing into typed atom properties.
+//!
+//! # Responsibility
+//! - Detect a leading `---` YAML block and split it from the markdown body.
+//! - Convert top-level keys into typed properties
+//!   (string/number/date/bool/list).
+//! - Derive tag names from `tags:` and `aliases:`.
+//!
+//! # Invariants
+//! - Frontmatter must start on the first line (`---`) and close with a line
+//!   that is exactly `---` or `...`; otherwise the content has none.
+//! - Keys are trimmed and lowercased; `null` values and nested mappings are
+//!   skipped.
+//! - Malformed YAML yields no properties, but the block is still split from
+//!   the body so previews never show it.
+//! - Aliases map to tags under the `alias/` namespace.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::tag_expr::normalize_tag_name;
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use serde_yaml::Value;
+
+/// Tag namespace used for `aliases:` entries.
+pub const ALIAS_TAG_PREFIX: &str = "alias";
+
+/// Typed frontmatter value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyValue {
+    /// Plain text.
+    String(String),
+    /// Integer or float.
+    Number(f64),
+    /// ISO date (`YYYY-MM-DD`) or date-time, kept as written.
+    Date(String),
+    /// `true` / `false`.
+    Bool(bool),
+    /// Sequence of scalars rendered as text.
+    List(Vec<String>),
+}
+
+impl PropertyValue {
+    /// Returns the storage label (`string|number|date|bool|list`).
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::String(_) => "string",
+            Self::Number(_) => "number",
+            Self::Date(_) => "date",
+            Self::Bool(_) => "bool",
+            Self::List(_) => "list",
+        }
+    }
+}
+
+/// One top-level frontmatter key with its typed value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AtomProperty {
+    /// Lowercase key.
+    pub key: String,
+    /// Typed value.
+    pub value: PropertyValue,
+}
+
+/// Parsed frontmatter block.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Frontmatter {
+    /// Properties in declaration order.
+    pub properties: Vec<AtomProperty>,
+    /// Normalized tag names from `tags:` and `aliases:`.
+    pub tags: Vec<String>,
+}
+
+/// Splits leading frontmatter from `content`.
+///
+/// Returns the YAML source (without delimiters) and the markdown body. When
+/// there is no frontmatter block the whole content is the body.
+pub fn split_frontmatter(content: &str) -> (Option<&str>, &str) {
+    let text = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let Some(first_line_end) = text.find('\n') else {
+        return (None, content);
+    };
+    if text[..first_line_end].trim_end() != "---" {
+        return (None, content);
+    }
+    let yaml_start = first_line_end + 1;
+    let mut offset = yaml_start;
+    for line in text[yaml_start..].split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if trimmed == "---" || trimmed == "..." {
+            return (
+                Some(&text[yaml_start..offset]),
+                &text[offset + line.len()..],
+            );
+        }
+        offset += line.len();
+    }
+    (None, content)
+}
+
+/// Returns the markdown body without frontmatter.
+pub fn strip_frontmatter(content: &str) -> &str {
+    split_frontmatter(content).1
+}
+
+/// Parses the leading frontmatter block of `content`.
+///
+/// Returns `None` when there is no block or it is not a YAML mapping.
+pub fn parse_frontmatter(content: &str) -> Option<Frontmatter> {
+    let (yaml, _) = split_frontmatter(content);
+    let mapping = match serde_yaml::from_str::<Value>(yaml?).ok()? {
+        Value::Mapping(mapping) => mapping,
+        _ => return None,
+    };
+
+    let mut frontmatter = Frontmatter::default();
+    for (key, value) in mapping {
+        let Some(key) = scalar_text(&key).map(|key| key.trim().to_lowercase()) else {
+            continue;
+        };
+        if key.is_empty() || frontmatter.properties.iter().any(|p| p.key == key) {
+            continue;
+        }
+        let Some(value) = convert_value(value) else {
+            continue;
+        };
+        match key.as_str() {
+            "tags" | "tag" => {
+                for name in value_items(&value) {
+                    push_tag(&mut frontmatter.tags, name.trim_start_matches('#'));
+                }
+            }
+            "aliases" | "alias" => {
+                for name in value_items(&value) {
+                    let alias = name.replace('/', "-");
+                    push_tag(
+                        &mut frontmatter.tags,
+                        &format!("{ALIAS_TAG_PREFIX}/{}", alias.trim()),
+                    );
+                }
+            }
+            _ => {}
+        }
+        frontmatter.properties.push(AtomProperty { key, value });
+    }
+    Some(frontmatter)
+}
+
+/// Rewrites the `tags:`/`aliases:` entries of the leading frontmatter.
+///
+/// `rename` receives each derived tag name (aliases with their `alias/`
+/// prefix) and returns `None` to keep the entry, `Some(None)` to drop it or
+/// `Some(Some(name))` to replace it. Only the touched keys are re-rendered,
+/// keeping their flow (`[a, b]`), block (`- a`) or comma style; a key left
+/// without entries is removed. An alias renamed outside the `alias/`
+/// namespace cannot stay an alias: it is dropped and its new name returned
+/// so the caller can keep the tag another way.
+///
+/// Returns `None` when nothing changes.
+pub(crate) fn rewrite_frontmatter_tags(
+    content: &str,
+    mut rename: impl FnMut(&str) -> Option<Option<String>>,
+) -> Option<(String, Vec<String>)> {
+    parse_frontmatter(content)?;
+    let yaml = split_frontmatter(content).0?;
+    let yaml_start = yaml.as_ptr() as usize - content.as_ptr() as usize;
+
+    let mut rewritten = String::with_capacity(yaml.len());
+    let mut detached = Vec::new();
+    let mut changed = false;
+    for block in top_level_blocks(yaml) {
+        let key = block.split(':').next().unwrap_or_default();
+        let alias = match key.trim().to_lowercase().as_str() {
+            "tags" | "tag" => false,
+            "aliases" | "alias" => true,
+            _ => {
+                rewritten.push_str(block);
+                continue;
+            }
+        };
+        let Some(items) = block_items(block) else {
+            rewritten.push_str(block);
+            continue;
+        };
+
+        let mut kept: Vec<String> = Vec::new();
+        let mut touched = false;
+        for item in items {
+            let derived = if alias {
+                normalize_tag_name(&format!(
+                    "{ALIAS_TAG_PREFIX}/{}",
+                    item.replace('/', "-").trim()
+                ))
+            } else {
+                normalize_tag_name(item.trim_start_matches('#'))
+            };
+            let next = match derived.as_deref().and_then(&mut rename) {
+                None => Some(item),
+                Some(target) => {
+                    touched = true;
+                    match (target, alias) {
+                        (None, _) => None,
+                        (Some(target), false) => Some(target),
+                        (Some(target), true) => {
+                            match target.strip_prefix(&format!("{ALIAS_TAG_PREFIX}/")) {
+                                Some(name) if !name.contains('/') => Some(name.to_string()),
+                                _ => {
+                                    detached.push(target);
+                                    None
+                                }
+                            }
+                        }
+                    }
+                }
+            };
+            if let Some(next) = next {
+                if !kept.contains(&next) {
+                    kept.push(next);
+                }
+            }
+        }
+        if touched {
+            changed = true;
+            rewritten.push_str(&render_tag_block(block, key, &kept));
+        } else {
+            rewritten.push_str(block);
+        }
+    }
+    if !changed {
+        return None;
+    }
+    Some((
+        format!(
+            "{}{rewritten}{}",
+            &content[..yaml_start],
+            &content[yaml_start + yaml.len()..]
+        ),
+        detached,
+    ))
+}
+
+/// Splits YAML source into top-level entries: a line starting at column 0
+/// plus its indented or `- ` continuation lines.
+fn top_level_blocks(yaml: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut start = 0;
+    let mut offset = 0;
+    for line in yaml.split_inclusive('\n') {
+        let continues = line.starts_with([' ', '\t', '-']) && !line.trim().is_empty();
+        if offset > start && !continues {
+            blocks.push(&yaml[start..offset]);
+            start = offset;
+        }
+        offset += line.len();
+    }
+    if offset > start {
+        blocks.push(&yaml[start..offset]);
+    }
+    blocks
+}
+
+/// Entries of one `key: value` block, as [`parse_frontmatter`] reads them.
+fn block_items(block: &str) -> Option<Vec<String>> {
+    let Value::Mapping(mapping) = serde_yaml::from_str::<Value>(block).ok()? else {
+        return None;
+    };
+    let (_, value) = mapping.into_iter().next()?;
+    Some(value_items(&convert_value(value)?))
+}
+
+/// Renders `key` with `items` in the style of the original `block`.
+fn render_tag_block(block: &str, key: &str, items: &[String]) -> String {
+    if items.is_empty() {
+        return String::new();
+    }
+    let first_line = block.lines().next().unwrap_or_default();
+    let value = first_line[key.len() + 1..].trim();
+    let quoted: Vec<String> = items.iter().map(|item| yaml_scalar(item)).collect();
+    if value.is_empty() {
+        let indent: String = block
+            .lines()
+            .nth(1)
+            .map(|line| {
+                line.chars()
+                    .take_while(|c| *c == ' ' || *c == '\t')
+                    .collect()
+            })
+            .unwrap_or_else(|| "  ".to_string());
+        let mut rendered = format!("{key}:\n");
+        for item in &quoted {
+            rendered.push_str(&format!("{indent}- {item}\n"));
+        }
+        return rendered;
+    }
+    let plain = quoted
+        .iter()
+        .zip(items)
+        .all(|(q, item)| q == item && !item.contains(','));
+    if !value.starts_with('[') && plain {
+        format!("{key}: {}\n", quoted.join(", "))
+    } else {
+        format!("{key}: [{}]\n", quoted.join(", "))
+    }
+}
+
+/// Writes `text` as a plain YAML scalar when that reads back unchanged,
+/// double-quoted otherwise.
+fn yaml_scalar(text: &str) -> String {
+    let plain = !text.is_empty()
+        && text.trim() == text
+        && !text.starts_with(['-', '?'])
+        && !text.contains(|c: char| ":#,[]{}&*!|>'\"%@`\\".contains(c));
+    if plain {
+        text.to_string()
+    } else {
+        format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+fn push_tag(tags: &mut Vec<String>, raw: &str) {
+    if let Some(name) = normalize_tag_name(raw) {
+        if !tags.contains(&name) {
+            tags.push(name);
+        }
+    }
+}
+
+/// Items of a list value; strings are split on commas (`tags: a, b`).
+fn value_items(value: &PropertyValue) -> Vec<String> {
+    match value {
+        PropertyValue::List(items) => items.clone(),
+        PropertyValue::String(text) => text
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn convert_value(value: Value) -> Option<PropertyValue> {
+    match value {
+        Value::Null | Value::Mapping(_) => None,
+        Value::Bool(flag) => Some(PropertyValue::Bool(flag)),
+        Value::Number(number) => number.as_f64().map(PropertyValue::Number),
+        Value::String(text) => {
+            let trimmed = text.trim();
+            if date_epoch_ms(trimmed).is_some() {
+                Some(PropertyValue::Date(trimmed.to_string()))
+            } else {
+                Some(PropertyValue::String(text))
+            }
+        }
+        Value::Sequence(items) => Some(PropertyValue::List(
+            items.iter().filter_map(scalar_text).collect(),
+        )),
+        Value::Tagged(tagged) => convert_value(tagged.value),
+    }
+}
+
+fn scalar_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(flag) => Some(flag.to_string()),
+        Value::Tagged(tagged) => scalar_text(&tagged.value),
+        _ => None,
+    }
+}
+
+/// Converts a date property to epoch milliseconds (UTC midnight for plain
+/// dates) for ordering and range queries.
+pub fn date_epoch_ms(value: &str) -> Option<i64> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Some(date.and_hms_opt(0, 0, 0)?.and_utc().timestamp_millis());
+    }
+    if let Ok(instant) = DateTime::parse_from_rfc3339(value) {
+        return Some(instant.timestamp_millis());
+    }
+    [
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+    .map(|naive| naive.and_utc().timestamp_millis())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_frontmatter, rewrite_frontmatter_tags, split_frontmatter, PropertyValue};
+
+    #[test]
+    fn parses_typed_properties_tags_and_aliases() {
+        let content = "---\ntitle: Weekly review\nrating: 4.5\ndue: 2026-03-01\n\
+                       draft: false\ntags: [Work/Client, \"#urgent\"]\naliases: Weekly, W/R\n\
+                       nested:\n  a: 1\nempty:\n---\n# Body\n";
+        let frontmatter = parse_frontmatter(content).expect("frontmatter");
+        let values: Vec<(&str, &PropertyValue)> = frontmatter
+            .properties
+            .iter()
+            .map(|p| (p.key.as_str(), &p.value))
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                ("title", &PropertyValue::String("Weekly review".into())),
+                ("rating", &PropertyValue::Number(4.5)),
+                ("due", &PropertyValue::Date("2026-03-01".into())),
+                ("draft", &PropertyValue::Bool(false)),
+                (
+                    "tags",
+                    &PropertyValue::List(vec!["Work/Client".into(), "#urgent".into()])
+                ),
+                ("aliases", &PropertyValue::String("Weekly, W/R".into())),
+            ]
+        );
+        assert_eq!(
+            frontmatter.tags,
+            vec!["work/client", "urgent", "alias/weekly", "alias/w-r"]
+        );
+    }
+
+    #[test]
+    fn requires_leading_delimited_block() {
+        assert_eq!(split_frontmatter("text\n---\na: 1\n---\n").0, None);
+        assert_eq!(split_frontmatter("---\na: 1\nno close").0, None);
+        let (yaml, body) = split_frontmatter("---\na: 1\n...\nbody");
+        assert_eq!(yaml, Some("a: 1\n"));
+        assert_eq!(body, "body");
+        assert!(parse_frontmatter("---\n: [broken\n---\nbody").is_none());
+        assert_eq!(split_frontmatter("---\n: [broken\n---\nbody").1, "body");
+    }
+
+    #[test]
+    fn rewrites_tag_entries_in_their_original_style() {
+        let rename = |tag: &str| match tag {
+            "old" => Some(Some("new/one".to_string())),
+            "gone" => Some(None),
+            "odd" => Some(Some("client: a".to_string())),
+            "alias/w" => Some(Some("moved".to_string())),
+            _ => None,
+        };
+        let content = "---\ntags: [Old, keep, gone]\naliases: W, Kept\n---\nbody #old\n";
+        let (rewritten, detached) = rewrite_frontmatter_tags(content, rename).unwrap();
+        assert_eq!(
+            rewritten,
+            "---\ntags: [new/one, keep]\naliases: Kept\n---\nbody #old\n"
+        );
+        assert_eq!(detached, vec!["moved"]);
+
+        let (rewritten, _) =
+            rewrite_frontmatter_tags("---\ntag: odd, keep\nx: 1\n---\n", rename).unwrap();
+        assert_eq!(rewritten, "---\ntag: [\"client: a\", keep]\nx: 1\n---\n");
+        assert_eq!(
+            parse_frontmatter(&rewritten).unwrap().tags,
+            vec!["client: a", "keep"]
+        );
+
+        let (rewritten, _) =
+            rewrite_frontmatter_tags("---\ntags:\n- gone\nx: 1\n---\n", rename).unwrap();
+        assert_eq!(rewritten, "---\nx: 1\n---\n");
+        assert!(rewrite_frontmatter_tags("---\ntags: keep\n---\n", rename).is_none());
+        assert!(rewrite_frontmatter_tags("no frontmatter #old", rename).is_none());
+    }
+}
@@ -0,0 +1,195 @@
+//! Inline `#hashtag` extraction.
+//!
+//! # Responsibility
+//! - Find `#tag` / `#parent/child` markers in markdown content so they can
+//!   be mirrored into the tag system as derived (`inline`) tags.
+//! - Rewrite those markers in place when a tag is renamed or deleted.
+//!
+//! # Invariants
+//! - Fenced code blocks, inline code spans, URLs, Markdown link targets and
+//!   wiki-links are ignored.
+//! - A marker starts at `#` that is not preceded by an ASCII letter/digit or
+//!   one of `#`, `&`, `/`, `\`, `_`; headings (`# Title`) never match.
+//! - Tag bodies are Unicode letters/digits (CJK included) plus `_`, `-` and
+//!   `/`; all-digit bodies (`#123`) are issue numbers, not tags.
+//! - Names are normalized like persisted tags and deduplicated; document
+//!   order is kept.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::resource_link::mask_code_spans;
+use crate::model::tag_expr::normalize_tag_name;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashSet;
+use std::ops::Range;
+
+static MASKED_SPAN_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)\b[a-z][a-z0-9+.\-]*://[^\s<>"'`]+|\]\([^)]*\)|\[\[[^\]]*\]\]|<[^>\s]+>"#)
+        .expect("valid hashtag mask regex")
+});
+
+/// Extracts normalized inline hashtags from markdown content.
+pub fn parse_hashtags(content: &str) -> Vec<String> {
+    let mut tags = Vec::new();
+    let mut seen = HashSet::new();
+    for (_, masked) in masked_lines(content) {
+        for tag in scan_line(&masked) {
+            if seen.insert(tag.clone()) {
+                tags.push(tag);
+            }
+        }
+    }
+    tags
+}
+
+/// Rewrites the `#tag` markers found by [`parse_hashtags`].
+///
+/// `replace` receives each marker as written (`#Work/Client`) with its
+/// normalized name and returns the replacement text for the whole marker,
+/// or `None` to keep it. Everything else is copied byte for byte.
+pub(crate) fn rewrite_hashtags(
+    content: &str,
+    mut replace: impl FnMut(&str, &str) -> Option<String>,
+) -> String {
+    let mut rewritten = String::with_capacity(content.len());
+    let mut copied = 0;
+    for (offset, masked) in masked_lines(content) {
+        for (range, tag) in hashtag_spans(&masked) {
+            let marker = &content[offset + range.start..offset + range.end];
+            if let Some(text) = replace(marker, &tag) {
+                rewritten.push_str(&content[copied..offset + range.start]);
+                rewritten.push_str(&text);
+                copied = offset + range.end;
+            }
+        }
+    }
+    rewritten.push_str(&content[copied..]);
+    rewritten
+}
+
+/// Byte offset and masked copy of every line outside fenced code blocks.
+///
+/// Code spans, URLs, link targets and wiki-links are blanked with spaces so
+/// offsets into the masked copy stay valid for the original line.
+fn masked_lines(content: &str) -> Vec<(usize, String)> {
+    let mut lines = Vec::new();
+    let mut in_fence = false;
+    let mut offset = 0;
+    for raw in content.split_inclusive('\n') {
+        let start = offset;
+        offset += raw.len();
+        let line = raw.trim_end_matches(['\n', '\r']);
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+
+        let mut masked = mask_code_spans(line);
+        let spans: Vec<_> = MASKED_SPAN_RE
+            .find_iter(&masked)
+            .map(|matched| matched.range())
+            .collect();
+        for range in spans {
+            masked.replace_range(range.clone(), &" ".repeat(range.len()));
+        }
+        lines.push((start, masked));
+    }
+    lines
+}
+
+fn scan_line(line: &str) -> Vec<String> {
+    hashtag_spans(line)
+        .into_iter()
+        .map(|(_, tag)| tag)
+        .collect()
+}
+
+/// Byte ranges and normalized names of `#tag` markers in one plain line.
+///
+/// Does not mask code, URLs or links; callers pass already-plain text.
+pub(crate) fn hashtag_spans(line: &str) -> Vec<(Range<usize>, String)> {
+    let chars: Vec<(usize, char)> = line.char_indices().collect();
+    let byte_at = |index: usize| chars.get(index).map_or(line.len(), |(byte, _)| *byte);
+    let mut found = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        if chars[index].1 != '#' || (index > 0 && !opens_hashtag(chars[index - 1].1)) {
+            index += 1;
+            continue;
+        }
+        let body_len = chars[index + 1..]
+            .iter()
+            .take_while(|(_, ch)| is_tag_char(*ch))
+            .count();
+        let body = &line[byte_at(index + 1)..byte_at(index + 1 + body_len)];
+        let body = body.trim_end_matches(['/', '-']);
+        if body.chars().any(|ch| !ch.is_ascii_digit() && ch != '/') {
+            if let Some(tag) = normalize_tag_name(body) {
+                let start = byte_at(index);
+                found.push((start..byte_at(index + 1) + body.len(), tag));
+            }
+        }
+        index += 1 + body_len.max(1);
+    }
+    found
+}
+
+fn opens_hashtag(previous: char) -> bool {
+    !(previous.is_ascii_alphanumeric() || matches!(previous, '#' | '&' | '/' | '\\' | '_'))
+}
+
+fn is_tag_char(ch: char) -> bool {
+    ch.is_alphanumeric() || matches!(ch, '_' | '-' | '/')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hashtag_spans, parse_hashtags, rewrite_hashtags};
+
+    #[test]
+    fn extracts_latin_cjk_and_nested_tags_in_order() {
+        let tags = parse_hashtags("Plan #Meeting, then #项目/二期。\n今天#周报 #meeting again");
+        assert_eq!(tags, vec!["meeting", "项目/二期", "周报"]);
+    }
+
+    #[test]
+    fn ignores_headings_numbers_code_urls_and_links() {
+        let content = "# Heading\n## Sub\nissue #42 and a#b and &#123;\n\
+                       `#inline` https://example.com/page#anchor\n\
+                       [docs](https://example.com/#frag) [[Note#Section]]\n\
+                       ```\n#fenced\n```\n\\#escaped #kept-";
+        assert_eq!(parse_hashtags(content), vec!["kept"]);
+    }
+
+    #[test]
+    fn spans_cover_marker_and_trimmed_body() {
+        let line = "开会#工作 and #Ops/- ok";
+        let spans = hashtag_spans(line);
+        let covered: Vec<_> = spans
+            .iter()
+            .map(|(range, tag)| (&line[range.clone()], tag.as_str()))
+            .collect();
+        assert_eq!(covered, vec![("#工作", "工作"), ("#Ops", "ops")]);
+    }
+
+    #[test]
+    fn rewrite_touches_only_real_markers() {
+        let content =
+            "Plan #Legacy/Old\r\n`#legacy` [[Legacy#legacy]]\n```\n#legacy\n```\n#keep #legacy";
+        let rewritten = rewrite_hashtags(content, |marker, tag| match tag {
+            "legacy/old" => Some("#archive/old".to_string()),
+            "legacy" => Some(marker[1..].to_string()),
+            _ => None,
+        });
+        assert_eq!(
+            rewritten,
+            "Plan #archive/old\r\n`#legacy` [[Legacy#legacy]]\n```\n#legacy\n```\n#keep legacy"
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! Wiki-link references between atoms.
+//!
+//! # Responsibility
+//! - Parse `[[Title]]`, `[[Title|label]]` and `[[uuid]]` references from
+//!   markdown content.
+//! - Define the read model for persisted atom links.
+//!
+//! # Invariants
+//! - References inside fenced code blocks and inline code spans are ignored.
+//! - One reference per normalized target key and source; first wins.
+//! - Title keys are trimmed and ASCII-lowercased so they compare equal to
+//!   SQLite `lower(trim(...))` on the storage side.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::AtomId;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// How a wiki-link addresses its target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WikiLinkKind {
+    /// `[[Title]]`: resolved through workspace note display names.
+    Title,
+    /// `[[uuid]]`: addresses one atom directly.
+    Uuid,
+}
+
+/// One wiki-link reference parsed from content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WikiLinkRef {
+    /// Target text as written, trimmed, without the `|label` suffix.
+    pub target: String,
+    /// Optional display label (`[[target|label]]`).
+    pub label: Option<String>,
+    /// Target addressing mode.
+    pub kind: WikiLinkKind,
+    /// Normalized key used for dedup and title resolution.
+    pub key: String,
+}
+
+/// One persisted link edge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomLink {
+    /// Atom whose content contains the reference.
+    pub source_id: AtomId,
+    /// Target text as written.
+    pub target: String,
+    /// Optional display label.
+    pub label: Option<String>,
+    /// Target addressing mode.
+    pub kind: WikiLinkKind,
+    /// Resolved active target atom; `None` when unresolved.
+    pub target_id: Option<AtomId>,
+}
+
+impl AtomLink {
+    /// Returns whether the link currently points at an active atom.
+    pub fn is_resolved(&self) -> bool {
+        self.target_id.is_some()
+    }
+}
+
+/// Normalizes a title into its resolution key.
+pub fn normalize_link_key(title: &str) -> String {
+    title.trim().to_ascii_lowercase()
+}
+
+/// Extracts wiki-link references from markdown content in document order.
+pub fn parse_wiki_links(content: &str) -> Vec<WikiLinkRef> {
+    let mut links = Vec::new();
+    let mut seen = HashSet::new();
+    let mut in_fence = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        for raw in scan_line(line) {
+            if let Some(link) = parse_reference(raw) {
+                if seen.insert(link.key.clone()) {
+                    links.push(link);
+                }
+            }
+        }
+    }
+    links
+}
+
+/// Returns the raw inner text of every `[[...]]` outside inline code.
+fn scan_line(line: &str) -> Vec<&str> {
+    let bytes = line.as_bytes();
+    let mut found = Vec::new();
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'`' => {
+                let ticks = bytes[index..].iter().take_while(|b| **b == b'`').count();
+                let fence = &line[index..index + ticks];
+                match line[index + ticks..].find(fence) {
+                    Some(close) => index += ticks + close + ticks,
+                    None => index += ticks,
+                }
+            }
+            b'[' if bytes.get(index + 1) == Some(&b'[') => {
+                let start = index + 2;
+                match line[start..].find("]]") {
+                    Some(end) if !line[start..start + end].contains('[') => {
+                        found.push(&line[start..start + end]);
+                        index = start + end + 2;
+                    }
+                    _ => index += 1,
+                }
+            }
+            _ => index += 1,
+        }
+    }
+    found
+}
+
+fn parse_reference(raw: &str) -> Option<WikiLinkRef> {
+    let (target, label) = match raw.split_once('|') {
+        Some((target, label)) => (target.trim(), Some(label.trim())),
+        None => (raw.trim(), None),
+    };
+    if target.is_empty() {
+        return None;
+    }
+    let label = label.filter(|value| !value.is_empty()).map(str::to_string);
+
+    match Uuid::parse_str(target) {
+        Ok(uuid) => Some(WikiLinkRef {
+            target: target.to_string(),
+            label,
+            kind: WikiLinkKind::Uuid,
+            key: uuid.to_string(),
+        }),
+        Err(_) => Some(WikiLinkRef {
+            target: target.to_string(),
+            label,
+            kind: WikiLinkKind::Title,
+            key: normalize_link_key(target),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_wiki_links, WikiLinkKind};
+
+    #[test]
+    fn parses_title_label_and_uuid_links_in_order() {
+        let id = "4f9c2a7e-1d3b-4c5a-9e8f-0a1b2c3d4e5f";
+        let content = format!("See [[Project Plan]] and [[{id}|spec]].\n[[ Inbox ]]");
+        let links = parse_wiki_links(&content);
+        assert_eq!(links.len(), 3);
+        assert_eq!(links[0].target, "Project Plan");
+        assert_eq!(links[0].key, "project plan");
+        assert_eq!(links[0].kind, WikiLinkKind::Title);
+        assert_eq!(links[1].kind, WikiLinkKind::Uuid);
+        assert_eq!(links[1].label.as_deref(), Some("spec"));
+        assert_eq!(links[2].target, "Inbox");
+    }
+
+    #[test]
+    fn deduplicates_by_normalized_key() {
+        let links = parse_wiki_links("[[Plan]] [[plan|again]] [[PLAN]]");
+        assert_eq!(links.len(), 1);
+        assert!(links[0].label.is_none());
+    }
+
+    #[test]
+    fn ignores_code_spans_fences_and_empty_targets() {
+        let content = "`[[inline]]` [[]] [[ |label]]\n```\n[[fenced]]\n```\n[[real]]";
+        let links = parse_wiki_links(content);
+        let targets: Vec<&str> = links.iter().map(|link| link.target.as_str()).collect();
+        assert_eq!(targets, vec!["real"]);
+    }
+
+    #[test]
+    fn keeps_cjk_titles() {
+        let links = parse_wiki_links("参考 [[项目计划]]");
+        assert_eq!(links[0].key, "项目计划");
+    }
+}
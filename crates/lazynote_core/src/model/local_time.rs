@@ -0,0 +1,321 @@
+//! Timezone-aware local calendar math.
+//!
+//! # Responsibility
+//! - Resolve IANA timezone names against the tz database bundled into the
+//!   binary (no system zoneinfo, no network).
+//! - Compute local day/week/month windows as UTC instants, DST included.
+//! - Encode all-day dates as floating values that mean the same calendar
+//!   date in every timezone.
+//!
+//! # Invariants
+//! - Windows are inclusive: `end_ms` is one millisecond before the next local
+//!   boundary, matching the `bod_ms`/`eod_ms` section contract.
+//! - A local midnight skipped by a DST gap resolves to the first instant
+//!   after the gap; an ambiguous midnight resolves to its earlier instant.
+//! - Floating values are wall-clock times encoded as if UTC. They are only
+//!   compared with floating bounds, never with instants.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Milliseconds in one floating (wall-clock) day.
+pub const DAY_MS: i64 = 86_400_000;
+
+/// Longest DST gap probed when a local midnight does not exist.
+const MAX_GAP_MINUTES: i64 = 48 * 60;
+
+/// Errors for local calendar configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeZoneError {
+    /// Name is not an IANA timezone known to the bundled database.
+    UnknownTimeZone(String),
+}
+
+impl Display for TimeZoneError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownTimeZone(name) => write!(f, "unknown IANA timezone `{name}`"),
+        }
+    }
+}
+
+impl Error for TimeZoneError {}
+
+/// Inclusive query window in both instant and floating time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalWindow {
+    /// First instant of the window, epoch ms.
+    pub start_ms: i64,
+    /// Last instant of the window, epoch ms.
+    pub end_ms: i64,
+    /// First floating value of the window (all-day comparisons).
+    pub floating_start_ms: i64,
+    /// Last floating value of the window (all-day comparisons).
+    pub floating_end_ms: i64,
+}
+
+impl LocalWindow {
+    /// Window from caller-computed instants.
+    ///
+    /// Without a timezone, all-day dates are compared as UTC dates.
+    pub fn from_instants(start_ms: i64, end_ms: i64) -> Self {
+        Self {
+            start_ms,
+            end_ms,
+            floating_start_ms: start_ms,
+            floating_end_ms: end_ms,
+        }
+    }
+
+    /// UTC offset at the end of the window, in ms (`floating - instant`).
+    pub fn end_offset_ms(&self) -> i64 {
+        self.floating_end_ms - self.end_ms
+    }
+}
+
+/// Local calendar settings: IANA timezone and first day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalCalendar {
+    time_zone: Tz,
+    week_start: Weekday,
+}
+
+impl LocalCalendar {
+    /// Creates a calendar for IANA `time_zone` (for example `Europe/Berlin`).
+    ///
+    /// # Errors
+    /// - Returns [`TimeZoneError::UnknownTimeZone`] for names missing from
+    ///   the bundled database.
+    pub fn new(time_zone: &str, week_start: Weekday) -> Result<Self, TimeZoneError> {
+        let name = time_zone.trim();
+        let time_zone = name
+            .parse::<Tz>()
+            .map_err(|_| TimeZoneError::UnknownTimeZone(name.to_string()))?;
+        Ok(Self {
+            time_zone,
+            week_start,
+        })
+    }
+
+    /// UTC calendar with Monday weeks.
+    pub const fn utc() -> Self {
+        Self {
+            time_zone: Tz::UTC,
+            week_start: Weekday::Mon,
+        }
+    }
+
+    /// Canonical IANA name of the timezone.
+    pub fn time_zone_name(&self) -> &'static str {
+        self.time_zone.name()
+    }
+
+    /// First day of the week used by [`LocalCalendar::week_window`].
+    pub fn week_start(&self) -> Weekday {
+        self.week_start
+    }
+
+    /// Local calendar date of `instant_ms`.
+    pub fn local_date(&self, instant_ms: i64) -> NaiveDate {
+        self.local_date_time(instant_ms).date()
+    }
+
+    /// Local wall-clock time of `instant_ms` as a floating value.
+    pub fn to_floating(&self, instant_ms: i64) -> i64 {
+        self.local_date_time(instant_ms)
+            .and_utc()
+            .timestamp_millis()
+    }
+
+    /// Instant of local midnight at the start of `date`.
+    pub fn local_midnight(&self, date: NaiveDate) -> i64 {
+        self.local_instant(date.and_time(chrono::NaiveTime::MIN))
+    }
+
+    /// UTC instant of local wall-clock time `local`, resolved like
+    /// [`LocalCalendar::local_midnight`] inside DST gaps and overlaps.
+    pub fn local_instant(&self, local: NaiveDateTime) -> i64 {
+        if let Some(instant) = self.resolve_local(local) {
+            return instant;
+        }
+        (1..=MAX_GAP_MINUTES)
+            .find_map(|minutes| self.resolve_local(local + Duration::minutes(minutes)))
+            .unwrap_or_else(|| local.and_utc().timestamp_millis())
+    }
+
+    /// Window covering local dates `first..=last`.
+    pub fn dates_window(&self, first: NaiveDate, last: NaiveDate) -> LocalWindow {
+        let after_last = last.succ_opt().unwrap_or(last);
+        let (floating_start_ms, floating_end_ms) = floating_span(first, last);
+        LocalWindow {
+            start_ms: self.local_midnight(first),
+            end_ms: self.local_midnight(after_last) - 1,
+            floating_start_ms,
+            floating_end_ms,
+        }
+    }
+
+    /// Local day containing `instant_ms`.
+    pub fn day_window(&self, instant_ms: i64) -> LocalWindow {
+        let day = self.local_date(instant_ms);
+        self.dates_window(day, day)
+    }
+
+    /// Local week (starting on [`LocalCalendar::week_start`]) containing
+    /// `instant_ms`.
+    pub fn week_window(&self, instant_ms: i64) -> LocalWindow {
+        let day = self.local_date(instant_ms);
+        let days_into_week =
+            (day.weekday().num_days_from_monday() + 7 - self.week_start.num_days_from_monday()) % 7;
+        let first = day - Duration::days(i64::from(days_into_week));
+        self.dates_window(first, first + Duration::days(6))
+    }
+
+    /// Local calendar month containing `instant_ms`.
+    pub fn month_window(&self, instant_ms: i64) -> LocalWindow {
+        let day = self.local_date(instant_ms);
+        let first = day.with_day(1).unwrap_or(day);
+        let next_month = if first.month() == 12 {
+            NaiveDate::from_ymd_opt(first.year() + 1, 1, 1)
+        } else {
+            NaiveDate::from_ymd_opt(first.year(), first.month() + 1, 1)
+        };
+        let last = next_month.and_then(|date| date.pred_opt()).unwrap_or(first);
+        self.dates_window(first, last)
+    }
+
+    fn local_date_time(&self, instant_ms: i64) -> NaiveDateTime {
+        self.time_zone
+            .from_utc_datetime(&utc_date_time(instant_ms).naive_utc())
+            .naive_local()
+    }
+
+    fn resolve_local(&self, local: NaiveDateTime) -> Option<i64> {
+        self.time_zone
+            .from_local_datetime(&local)
+            .earliest()
+            .map(|instant| instant.timestamp_millis())
+    }
+}
+
+impl Default for LocalCalendar {
+    fn default() -> Self {
+        Self::utc()
+    }
+}
+
+/// Floating value of `date` at `00:00`.
+pub fn floating_date_ms(date: NaiveDate) -> i64 {
+    date.and_time(chrono::NaiveTime::MIN)
+        .and_utc()
+        .timestamp_millis()
+}
+
+/// Floating `(start_at, end_at)` of an all-day span over `first..=last`.
+pub fn floating_span(first: NaiveDate, last: NaiveDate) -> (i64, i64) {
+    (floating_date_ms(first), floating_date_ms(last) + DAY_MS - 1)
+}
+
+/// Dates covered by a floating all-day span; `None` unless both bounds are
+/// set, day-aligned and ordered.
+pub fn floating_dates(
+    start_at: Option<i64>,
+    end_at: Option<i64>,
+) -> Option<(NaiveDate, NaiveDate)> {
+    let (start, end) = (start_at?, end_at?);
+    if start.rem_euclid(DAY_MS) != 0 || end.rem_euclid(DAY_MS) != DAY_MS - 1 || end < start {
+        return None;
+    }
+    let first = DateTime::from_timestamp_millis(start)?.date_naive();
+    let last = DateTime::from_timestamp_millis(end)?.date_naive();
+    Some((first, last))
+}
+
+fn utc_date_time(instant_ms: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp_millis(instant_ms).unwrap_or(if instant_ms < 0 {
+        DateTime::<Utc>::MIN_UTC
+    } else {
+        DateTime::<Utc>::MAX_UTC
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{floating_dates, floating_span, LocalCalendar, TimeZoneError, DAY_MS};
+    use chrono::{NaiveDate, TimeZone, Utc, Weekday};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn utc_ms(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> i64 {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+            .timestamp_millis()
+    }
+
+    #[test]
+    fn day_windows_follow_dst_transitions() {
+        let new_york = LocalCalendar::new("America/New_York", Weekday::Sun).unwrap();
+        let spring = new_york.day_window(utc_ms(2026, 3, 8, 16, 0));
+        assert_eq!(spring.start_ms, utc_ms(2026, 3, 8, 5, 0));
+        assert_eq!(spring.end_ms + 1 - spring.start_ms, 23 * 3_600_000);
+        let fall = new_york.day_window(utc_ms(2026, 11, 1, 16, 0));
+        assert_eq!(fall.start_ms, utc_ms(2026, 11, 1, 4, 0));
+        assert_eq!(fall.end_ms + 1 - fall.start_ms, 25 * 3_600_000);
+        assert_eq!((fall.floating_start_ms, fall.floating_end_ms), {
+            floating_span(date(2026, 11, 1), date(2026, 11, 1))
+        });
+    }
+
+    #[test]
+    fn skipped_midnight_resolves_to_first_instant_after_gap() {
+        // Chile springs forward at local midnight on the first Sunday after
+        // 1 September.
+        let santiago = LocalCalendar::new("America/Santiago", Weekday::Mon).unwrap();
+        let day = santiago.day_window(utc_ms(2026, 9, 6, 15, 0));
+        assert_eq!(day.start_ms, utc_ms(2026, 9, 6, 4, 0));
+        assert_eq!(santiago.local_date(day.start_ms), date(2026, 9, 6));
+    }
+
+    #[test]
+    fn week_and_month_windows_use_local_dates() {
+        let berlin = LocalCalendar::new("Europe/Berlin", Weekday::Mon).unwrap();
+        // Sunday 2026-03-29 01:30 UTC is after the 02:00 CET -> CEST switch.
+        let now = utc_ms(2026, 3, 29, 1, 30);
+        let week = berlin.week_window(now);
+        assert_eq!(week.start_ms, utc_ms(2026, 3, 22, 23, 0));
+        assert_eq!(week.end_ms + 1, utc_ms(2026, 3, 29, 22, 0));
+        let sunday_weeks = LocalCalendar::new("Europe/Berlin", Weekday::Sun).unwrap();
+        assert_eq!(
+            sunday_weeks.local_date(sunday_weeks.week_window(now).start_ms),
+            date(2026, 3, 29)
+        );
+
+        let month = berlin.month_window(now);
+        assert_eq!(month.start_ms, utc_ms(2026, 2, 28, 23, 0));
+        assert_eq!(month.end_ms + 1, utc_ms(2026, 3, 31, 22, 0));
+        assert_eq!(
+            floating_dates(Some(month.floating_start_ms), Some(month.floating_end_ms)),
+            Some((date(2026, 3, 1), date(2026, 3, 31)))
+        );
+    }
+
+    #[test]
+    fn floating_dates_require_whole_days_and_known_zones() {
+        let (start, end) = floating_span(date(2026, 1, 5), date(2026, 1, 6));
+        assert_eq!(end - start + 1, 2 * DAY_MS);
+        assert_eq!(floating_dates(Some(start), Some(end - 1)), None);
+        assert_eq!(floating_dates(Some(start + 1), Some(end)), None);
+        assert_eq!(floating_dates(Some(start), None), None);
+        assert_eq!(
+            LocalCalendar::new("Mars/Olympus", Weekday::Mon),
+            Err(TimeZoneError::UnknownTimeZone("Mars/Olympus".to_string()))
+        );
+    }
+}
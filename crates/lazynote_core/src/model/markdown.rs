@@ -0,0 +1,435 @@
+//! CommonMark/GFM analysis for note projections.
+//!
+//! # Responsibility
+//! - Parse markdown once with a real CommonMark/GFM parser and derive the
+//!   preview text/image, title, heading outline and content statistics from
+//!   it.
+//!
+//! # Invariants
+//! - Leading YAML frontmatter is never part of any projection.
+//! - Outline byte offsets index into the full stored content (frontmatter
+//!   included), so editors can jump to them directly.
+//! - Preview text excludes code blocks, raw HTML and image alt text; word
+//!   and character counts include code but exclude markup.
+//! - Each CJK character counts as one word.
+//! - The title is the first heading, else the first rendered line with a
+//!   letter or digit.
+//! - Checklist extraction only returns top-level GFM task items; nested
+//!   content stays inside its item.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::frontmatter::strip_frontmatter;
+use crate::search::fts::is_cjk_char;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+/// Maximum preview length in characters.
+pub const PREVIEW_TEXT_MAX_CHARS: usize = 100;
+
+/// Maximum derived title length in characters.
+pub const NOTE_TITLE_MAX_CHARS: usize = 80;
+
+/// Title used when content yields none and no override is set.
+pub const UNTITLED_NOTE_TITLE: &str = "Untitled note";
+
+/// One heading with its nested sub-headings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineHeading {
+    /// Heading level, 1..=6.
+    pub level: u8,
+    /// Plain heading text without markup.
+    pub text: String,
+    /// Byte offset of the heading start in the stored content.
+    pub byte_start: usize,
+    /// Byte offset just past the heading (end-exclusive).
+    pub byte_end: usize,
+    /// Deeper headings until the next heading of the same or higher level.
+    pub children: Vec<OutlineHeading>,
+}
+
+/// Cached per-atom content statistics.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ContentStats {
+    /// Words in rendered text.
+    pub word_count: u32,
+    /// Non-whitespace characters in rendered text.
+    pub char_count: u32,
+    /// Checked GFM task list items (`- [x]`).
+    pub checklist_done: u32,
+    /// All GFM task list items.
+    pub checklist_total: u32,
+}
+
+/// One top-level GFM task list item (`- [ ] text`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecklistItem {
+    /// Whether the box is checked (`[x]`).
+    pub checked: bool,
+    /// Item markdown without list/task markers; nested lines are dedented.
+    pub content: String,
+    /// Byte offset of the item start in the stored content.
+    pub byte_start: usize,
+    /// Byte offset just past the item, including its line break.
+    pub byte_end: usize,
+}
+
+/// Everything derived from one markdown parse.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MarkdownAnalysis {
+    /// Whitespace-normalized summary text, at most 100 chars.
+    pub preview_text: Option<String>,
+    /// Destination of the first image (inline or reference-style).
+    pub preview_image: Option<String>,
+    /// First heading text, else the first non-empty line, at most 80 chars.
+    pub title: Option<String>,
+    /// Top-level headings with nested children.
+    pub outline: Vec<OutlineHeading>,
+    /// Word/character counts and checklist progress.
+    pub stats: ContentStats,
+}
+
+/// Parses `content` and derives preview, outline and statistics.
+pub fn analyze_markdown(content: &str) -> MarkdownAnalysis {
+    let body = strip_frontmatter(content);
+    let base = content.len() - body.len();
+
+    let mut preview = String::new();
+    let mut plain = String::new();
+    let mut preview_image = None;
+    let mut headings = Vec::new();
+    let mut heading: Option<(u8, usize, String)> = None;
+    let mut code_block_depth = 0_usize;
+    let mut image_depth = 0_usize;
+    let mut stats = ContentStats::default();
+
+    for (event, range) in Parser::new_ext(body, parser_options()).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                heading = Some((level as u8, range.start, String::new()));
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some((level, start, text)) = heading.take() {
+                    headings.push(OutlineHeading {
+                        level,
+                        text: normalize_whitespace(&text),
+                        byte_start: base + start,
+                        byte_end: base + range.end,
+                        children: Vec::new(),
+                    });
+                }
+                push_break(&mut preview, &mut plain);
+            }
+            Event::Start(Tag::CodeBlock(_)) => code_block_depth += 1,
+            Event::End(TagEnd::CodeBlock) => {
+                code_block_depth = code_block_depth.saturating_sub(1);
+                push_break(&mut preview, &mut plain);
+            }
+            Event::Start(Tag::Image { dest_url, .. }) => {
+                image_depth += 1;
+                let dest = dest_url.trim();
+                if preview_image.is_none() && !dest.is_empty() {
+                    preview_image = Some(dest.to_string());
+                }
+            }
+            Event::End(TagEnd::Image) => {
+                image_depth = image_depth.saturating_sub(1);
+                push_break(&mut preview, &mut plain);
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if image_depth > 0 {
+                    continue;
+                }
+                plain.push_str(&text);
+                if code_block_depth == 0 {
+                    preview.push_str(&text);
+                    if let Some((_, _, heading_text)) = heading.as_mut() {
+                        heading_text.push_str(&text);
+                    }
+                }
+            }
+            Event::TaskListMarker(checked) => {
+                stats.checklist_total += 1;
+                if checked {
+                    stats.checklist_done += 1;
+                }
+            }
+            Event::SoftBreak | Event::HardBreak => {
+                push_break(&mut preview, &mut plain);
+                if let Some((_, _, heading_text)) = heading.as_mut() {
+                    heading_text.push(' ');
+                }
+            }
+            Event::End(
+                TagEnd::Paragraph
+                | TagEnd::Item
+                | TagEnd::TableCell
+                | TagEnd::BlockQuote(_)
+                | TagEnd::FootnoteDefinition,
+            ) => push_break(&mut preview, &mut plain),
+            _ => {}
+        }
+    }
+
+    let (word_count, char_count) = count_words_and_chars(&plain);
+    stats.word_count = word_count;
+    stats.char_count = char_count;
+
+    let title = headings
+        .first()
+        .map(|heading| heading.text.clone())
+        .into_iter()
+        .chain(preview.lines().map(normalize_whitespace))
+        .find(|line| has_alphanumeric(line))
+        .map(|line| line.chars().take(NOTE_TITLE_MAX_CHARS).collect());
+
+    let normalized = normalize_whitespace(&preview);
+    // Why: unmatched markup (`***`, stray backticks) stays literal text after
+    // parsing; a preview without any letter or digit carries no summary.
+    let preview_text = has_alphanumeric(&normalized)
+        .then(|| normalized.chars().take(PREVIEW_TEXT_MAX_CHARS).collect());
+
+    MarkdownAnalysis {
+        preview_text,
+        preview_image,
+        title,
+        outline: nest_headings(headings),
+        stats,
+    }
+}
+
+/// Returns the heading tree of `content`.
+pub fn markdown_outline(content: &str) -> Vec<OutlineHeading> {
+    analyze_markdown(content).outline
+}
+
+/// Returns the title derived from `content`, if it has any text.
+pub fn derive_note_title(content: &str) -> Option<String> {
+    analyze_markdown(content).title
+}
+
+/// Returns word/character counts and checklist progress of `content`.
+pub fn content_stats(content: &str) -> ContentStats {
+    analyze_markdown(content).stats
+}
+
+/// Returns the top-level task list items of `content` in document order.
+pub fn checklist_items(content: &str) -> Vec<ChecklistItem> {
+    let body = strip_frontmatter(content);
+    let base = content.len() - body.len();
+
+    let mut items = Vec::new();
+    let mut list_depth = 0_usize;
+    let mut open_item: Option<(usize, usize, Option<bool>)> = None;
+    for (event, range) in Parser::new_ext(body, parser_options()).into_offset_iter() {
+        match event {
+            Event::Start(Tag::List(_)) => list_depth += 1,
+            Event::End(TagEnd::List(_)) => list_depth = list_depth.saturating_sub(1),
+            Event::Start(Tag::Item) if list_depth == 1 => {
+                open_item = Some((range.start, range.end, None));
+            }
+            Event::TaskListMarker(checked) if list_depth == 1 => {
+                if let Some((_, _, marker @ None)) = open_item.as_mut() {
+                    *marker = Some(checked);
+                }
+            }
+            Event::End(TagEnd::Item) if list_depth == 1 => {
+                if let Some((start, end, Some(checked))) = open_item.take() {
+                    let end = line_end(body, end);
+                    items.push(ChecklistItem {
+                        checked,
+                        content: checklist_item_content(&body[start..end]),
+                        byte_start: base + start,
+                        byte_end: base + end,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    items
+}
+
+/// Extends `end` past the rest of its line and the line break.
+fn line_end(text: &str, end: usize) -> usize {
+    if end > 0 && text.as_bytes()[end - 1] == b'\n' {
+        return end;
+    }
+    match text[end..].find('\n') {
+        Some(offset) => end + offset + 1,
+        None => text.len(),
+    }
+}
+
+/// Strips the list and task markers from an item and dedents nested lines.
+fn checklist_item_content(source: &str) -> String {
+    let mut lines = source.lines();
+    let first = lines.next().unwrap_or_default().trim_start();
+    let first = first
+        .strip_prefix(['-', '*', '+'])
+        .unwrap_or_else(|| first.trim_start_matches(|c: char| c.is_ascii_digit()))
+        .trim_start_matches(['.', ')'])
+        .trim_start();
+    let first = ["[ ]", "[x]", "[X]"]
+        .iter()
+        .find_map(|marker| first.strip_prefix(marker))
+        .unwrap_or(first)
+        .trim();
+
+    let rest: Vec<&str> = lines.collect();
+    let indent = rest
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let mut content = first.to_string();
+    for line in rest {
+        content.push('\n');
+        content.push_str(line.get(indent..).unwrap_or_else(|| line.trim_start()));
+    }
+    content.trim_end().to_string()
+}
+
+fn parser_options() -> Options {
+    Options::ENABLE_TABLES
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_FOOTNOTES
+}
+
+fn push_break(preview: &mut String, plain: &mut String) {
+    preview.push('\n');
+    plain.push('\n');
+}
+
+fn has_alphanumeric(text: &str) -> bool {
+    text.chars().any(char::is_alphanumeric)
+}
+
+fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Counts words (letter/digit runs; each CJK char alone) and non-whitespace
+/// characters.
+fn count_words_and_chars(text: &str) -> (u32, u32) {
+    let mut words = 0_u32;
+    let mut chars = 0_u32;
+    let mut in_word = false;
+    for ch in text.chars() {
+        if !ch.is_whitespace() {
+            chars += 1;
+        }
+        if is_cjk_char(ch) {
+            words += 1;
+            in_word = false;
+        } else if ch.is_alphanumeric() {
+            if !in_word {
+                words += 1;
+                in_word = true;
+            }
+        } else if !(in_word && matches!(ch, '\'' | '’' | '-' | '_')) {
+            in_word = false;
+        }
+    }
+    (words, chars)
+}
+
+/// Nests a flat heading list: each heading owns the following deeper ones.
+fn nest_headings(flat: Vec<OutlineHeading>) -> Vec<OutlineHeading> {
+    fn attach(stack: &mut [OutlineHeading], roots: &mut Vec<OutlineHeading>, node: OutlineHeading) {
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => roots.push(node),
+        }
+    }
+
+    let mut roots = Vec::new();
+    let mut stack: Vec<OutlineHeading> = Vec::new();
+    for heading in flat {
+        while stack.last().is_some_and(|top| top.level >= heading.level) {
+            let done = stack.pop().expect("stack top checked above");
+            attach(&mut stack, &mut roots, done);
+        }
+        stack.push(heading);
+    }
+    while let Some(done) = stack.pop() {
+        attach(&mut stack, &mut roots, done);
+    }
+    roots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{analyze_markdown, checklist_items, content_stats, derive_note_title};
+
+    #[test]
+    fn outline_nests_headings_with_content_offsets() {
+        let content = "---\na: 1\n---\n# Plan\n## Goals\n### Detail\n## Risks\n# Later\n```\n# not heading\n```\n";
+        let outline = analyze_markdown(content).outline;
+        let summary: Vec<(u8, &str, usize)> = outline
+            .iter()
+            .map(|h| (h.level, h.text.as_str(), h.children.len()))
+            .collect();
+        assert_eq!(summary, vec![(1, "Plan", 2), (1, "Later", 0)]);
+        assert_eq!(outline[0].children[0].children[0].text, "Detail");
+        let plan = &outline[0];
+        assert_eq!(&content[plan.byte_start..plan.byte_end], "# Plan\n");
+    }
+
+    #[test]
+    fn stats_count_words_cjk_and_checklist_items() {
+        let stats =
+            content_stats("# Trip\n- [x] book **hotel**\n- [ ] 订票\n- plain\n\n`code` don't");
+        assert_eq!(stats.checklist_done, 1);
+        assert_eq!(stats.checklist_total, 2);
+        // Trip, book, hotel, 订, 票, plain, code, don't
+        assert_eq!(stats.word_count, 8);
+        assert_eq!(stats.char_count, 29);
+    }
+
+    #[test]
+    fn preview_skips_code_blocks_and_resolves_reference_images() {
+        let analysis = analyze_markdown(
+            "Intro text\n\n```rust\nfn main() {}\n```\n\n| a | b |\n|---|---|\n| c | d |\n\n![logo][img]\n\n[img]: logo.png\n",
+        );
+        assert_eq!(analysis.preview_image.as_deref(), Some("logo.png"));
+        assert_eq!(analysis.preview_text.as_deref(), Some("Intro text a b c d"));
+    }
+
+    #[test]
+    fn title_prefers_first_heading_then_first_text_line() {
+        assert_eq!(
+            derive_note_title("---\ntitle: x\n---\nIntro *line*\n\n## Real **title**\n").as_deref(),
+            Some("Real title")
+        );
+        assert_eq!(
+            derive_note_title("```\ncode\n```\n\n***\nFirst line\nsecond line").as_deref(),
+            Some("First line")
+        );
+        assert_eq!(derive_note_title("  \n---\n"), None);
+    }
+
+    #[test]
+    fn checklist_items_cover_top_level_task_items_only() {
+        let content = "Intro\n\n- [ ] Buy *milk*\n- plain item\n- [x] Call Ana\n  - [ ] nested step\n  details\n1. [ ] numbered\n";
+        let items = checklist_items(content);
+        let summary: Vec<(bool, &str)> = items
+            .iter()
+            .map(|item| (item.checked, item.content.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (false, "Buy *milk*"),
+                (true, "Call Ana\n- [ ] nested step\ndetails"),
+                (false, "numbered"),
+            ]
+        );
+        assert_eq!(
+            &content[items[1].byte_start..items[1].byte_end],
+            "- [x] Call Ana\n  - [ ] nested step\n  details\n"
+        );
+    }
+}
@@ -12,3 +12,15 @@
 //! - docs/architecture/data-model.md
 
 pub mod atom;
+pub mod attachment;
+pub mod frontmatter;
+pub mod hashtag;
+pub mod link;
+pub mod local_time;
+pub mod markdown;
+pub mod recurrence;
+pub mod reminder;
+pub mod resource_link;
+pub mod revision;
+pub mod tag_expr;
+pub mod time_entry;
@@ -0,0 +1,940 @@
+//! RFC 5545 recurrence rule parsing and expansion.
+//!
+//! # Responsibility
+//! - Parse the RRULE subset used by recurring atoms.
+//! - Expand one series anchor (`start_at`) into concrete occurrence starts.
+//!
+//! # Invariants
+//! - Expansion is deterministic and evaluated in the wall-clock time of a
+//!   [`LocalCalendar`]: occurrences keep the anchor's local time of day
+//!   across DST changes and `BYDAY`/`BYMONTHDAY` match local dates. A local
+//!   time skipped by a DST gap resolves to the first instant after the gap.
+//! - Floating (all-day) anchors expand in [`LocalCalendar::utc`].
+//! - The series anchor is always the first occurrence when it matches the rule.
+//! - `COUNT` counts occurrences from the anchor, including skipped (EXDATE)
+//!   instances, matching RFC 5545 semantics.
+//! - `UNTIL` ending in `Z` bounds occurrence instants; a date or a date-time
+//!   without `Z` is floating and bounds the local start in the series zone
+//!   (a date includes that whole local day).
+//! - Weekly periods start on `WKST` (Monday by default), which matters for
+//!   `INTERVAL > 1` with several `BYDAY` weekdays.
+//!
+//! # Supported subset
+//! - `FREQ=DAILY|WEEKLY|MONTHLY|YEARLY`, `INTERVAL`, `COUNT`, `UNTIL`.
+//! - `BYDAY` (with optional ordinal for monthly/yearly, e.g. `-1FR`).
+//! - `BYMONTHDAY` (negative values count from month end), `BYMONTH`, `WKST`.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::local_time::LocalCalendar;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Weekday};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Upper bound of recurrence periods evaluated per expansion call.
+///
+/// Guards against rules that can never match (for example `BYMONTHDAY=31`
+/// with a 12-month interval starting in February).
+const MAX_EXPANSION_PERIODS: u32 = 50_000;
+
+/// Recurrence frequency (`FREQ`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// One `BYDAY` entry, optionally constrained to the n-th weekday of a period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekdaySpec {
+    /// Ordinal within the month/year (`1` = first, `-1` = last). `None` = every.
+    pub ordinal: Option<i8>,
+    /// Target weekday.
+    pub weekday: Weekday,
+}
+
+/// Inclusive `UNTIL` bound for occurrence starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecurrenceUntil {
+    /// `YYYYMMDDTHHMMSSZ`: last allowed instant, epoch milliseconds.
+    Instant(i64),
+    /// `YYYYMMDDTHHMMSS`: last allowed local start in the series zone.
+    Local(NaiveDateTime),
+    /// `YYYYMMDD`: last allowed local date in the series zone.
+    Date(NaiveDate),
+}
+
+impl RecurrenceUntil {
+    /// Returns whether an occurrence at `instant` (local start `local`)
+    /// falls after this bound.
+    fn excludes(self, instant: i64, local: NaiveDateTime) -> bool {
+        match self {
+            Self::Instant(until) => instant > until,
+            Self::Local(until) => local > until,
+            Self::Date(until) => local.date() > until,
+        }
+    }
+}
+
+/// Parsed recurrence rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    /// Base frequency.
+    pub frequency: Frequency,
+    /// Period multiplier, always >= 1.
+    pub interval: u32,
+    /// Maximum number of occurrences, counted from the anchor.
+    pub count: Option<u32>,
+    /// Inclusive upper bound for occurrence starts.
+    pub until: Option<RecurrenceUntil>,
+    /// `BYDAY` entries.
+    pub by_day: Vec<WeekdaySpec>,
+    /// `BYMONTHDAY` entries (1..=31 or -31..=-1).
+    pub by_month_day: Vec<i8>,
+    /// `BYMONTH` entries (1..=12).
+    pub by_month: Vec<u32>,
+    /// First day of weekly periods (`WKST`), Monday by default.
+    pub week_start: Weekday,
+}
+
+/// Errors for recurrence rule parsing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecurrenceError {
+    /// Rule text is blank.
+    Empty,
+    /// `FREQ` part is missing.
+    MissingFrequency,
+    /// A `KEY=VALUE` part is malformed or has an unsupported value.
+    InvalidPart { part: String, message: String },
+    /// Both `COUNT` and `UNTIL` are set (forbidden by RFC 5545).
+    CountAndUntil,
+}
+
+impl Display for RecurrenceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "recurrence rule must not be blank"),
+            Self::MissingFrequency => write!(f, "recurrence rule requires FREQ"),
+            Self::InvalidPart { part, message } => {
+                write!(f, "invalid recurrence rule part `{part}`: {message}")
+            }
+            Self::CountAndUntil => {
+                write!(f, "recurrence rule must not combine COUNT and UNTIL")
+            }
+        }
+    }
+}
+
+impl Error for RecurrenceError {}
+
+impl RecurrenceRule {
+    /// Parses an RRULE value such as `FREQ=WEEKLY;BYDAY=MO,WE;COUNT=10`.
+    ///
+    /// A leading `RRULE:` property prefix is accepted and ignored.
+    ///
+    /// # Errors
+    /// - Returns [`RecurrenceError`] for blank input, missing `FREQ`,
+    ///   unsupported parts or invalid values.
+    pub fn parse(raw: &str) -> Result<Self, RecurrenceError> {
+        let trimmed = raw.trim();
+        let body = trimmed
+            .strip_prefix("RRULE:")
+            .or_else(|| trimmed.strip_prefix("rrule:"))
+            .unwrap_or(trimmed)
+            .trim();
+        if body.is_empty() {
+            return Err(RecurrenceError::Empty);
+        }
+
+        let mut frequency = None;
+        let mut interval = 1u32;
+        let mut count = None;
+        let mut until = None;
+        let mut by_day = Vec::new();
+        let mut by_month_day = Vec::new();
+        let mut by_month = Vec::new();
+        let mut week_start = Weekday::Mon;
+
+        for part in body.split(';').filter(|part| !part.trim().is_empty()) {
+            let part = part.trim();
+            let invalid = |message: &str| RecurrenceError::InvalidPart {
+                part: part.to_string(),
+                message: message.to_string(),
+            };
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| invalid("expected KEY=VALUE"))?;
+            let value = value.trim();
+            match key.trim().to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(invalid("expected DAILY|WEEKLY|MONTHLY|YEARLY")),
+                    });
+                }
+                "INTERVAL" => {
+                    interval = value
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|value| *value >= 1)
+                        .ok_or_else(|| invalid("expected positive integer"))?;
+                }
+                "COUNT" => {
+                    count = Some(
+                        value
+                            .parse::<u32>()
+                            .ok()
+                            .filter(|value| *value >= 1)
+                            .ok_or_else(|| invalid("expected positive integer"))?,
+                    );
+                }
+                "UNTIL" => {
+                    until = Some(parse_until(value).ok_or_else(|| {
+                        invalid("expected YYYYMMDD, YYYYMMDDTHHMMSS or YYYYMMDDTHHMMSSZ")
+                    })?);
+                }
+                "BYDAY" => {
+                    for item in value.split(',') {
+                        by_day.push(
+                            parse_weekday_spec(item.trim())
+                                .ok_or_else(|| invalid("expected weekday list like MO,-1FR"))?,
+                        );
+                    }
+                }
+                "BYMONTHDAY" => {
+                    for item in value.split(',') {
+                        let day = item
+                            .trim()
+                            .parse::<i8>()
+                            .ok()
+                            .filter(|day| *day != 0 && (-31..=31).contains(day))
+                            .ok_or_else(|| invalid("expected day in 1..=31 or -31..=-1"))?;
+                        by_month_day.push(day);
+                    }
+                }
+                "BYMONTH" => {
+                    for item in value.split(',') {
+                        let month = item
+                            .trim()
+                            .parse::<u32>()
+                            .ok()
+                            .filter(|month| (1..=12).contains(month))
+                            .ok_or_else(|| invalid("expected month in 1..=12"))?;
+                        by_month.push(month);
+                    }
+                }
+                "WKST" => {
+                    week_start = parse_weekday(value).ok_or_else(|| invalid("expected weekday"))?;
+                }
+                _ => return Err(invalid("unsupported recurrence part")),
+            }
+        }
+
+        let frequency = frequency.ok_or(RecurrenceError::MissingFrequency)?;
+        if count.is_some() && until.is_some() {
+            return Err(RecurrenceError::CountAndUntil);
+        }
+        by_month_day.sort_unstable();
+        by_month_day.dedup();
+        by_month.sort_unstable();
+        by_month.dedup();
+
+        Ok(Self {
+            frequency,
+            interval,
+            count,
+            until,
+            by_day,
+            by_month_day,
+            by_month,
+            week_start,
+        })
+    }
+
+    /// Serializes the rule back into canonical RRULE value form.
+    pub fn to_rrule_string(&self) -> String {
+        let mut parts = vec![format!(
+            "FREQ={}",
+            match self.frequency {
+                Frequency::Daily => "DAILY",
+                Frequency::Weekly => "WEEKLY",
+                Frequency::Monthly => "MONTHLY",
+                Frequency::Yearly => "YEARLY",
+            }
+        )];
+        if self.interval > 1 {
+            parts.push(format!("INTERVAL={}", self.interval));
+        }
+        if let Some(count) = self.count {
+            parts.push(format!("COUNT={count}"));
+        }
+        if let Some(until) = self.until {
+            parts.push(format!("UNTIL={}", format_until(until)));
+        }
+        if !self.by_day.is_empty() {
+            let days = self
+                .by_day
+                .iter()
+                .map(|spec| match spec.ordinal {
+                    Some(ordinal) => format!("{ordinal}{}", weekday_code(spec.weekday)),
+                    None => weekday_code(spec.weekday).to_string(),
+                })
+                .collect::<Vec<_>>();
+            parts.push(format!("BYDAY={}", days.join(",")));
+        }
+        if !self.by_month_day.is_empty() {
+            let days = self
+                .by_month_day
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            parts.push(format!("BYMONTHDAY={}", days.join(",")));
+        }
+        if !self.by_month.is_empty() {
+            let months = self
+                .by_month
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            parts.push(format!("BYMONTH={}", months.join(",")));
+        }
+        if self.week_start != Weekday::Mon {
+            parts.push(format!("WKST={}", weekday_code(self.week_start)));
+        }
+        parts.join(";")
+    }
+
+    /// Expands occurrence starts of one series.
+    ///
+    /// Returns occurrence starts `s` with `window_start <= s <= window_end`,
+    /// ascending, at most `max_results` items. `anchor_ms` is the series
+    /// `start_at` (DTSTART); `calendar` is the zone the rule is evaluated in.
+    pub fn occurrences_between(
+        &self,
+        anchor_ms: i64,
+        calendar: &LocalCalendar,
+        window_start: i64,
+        window_end: i64,
+        max_results: usize,
+    ) -> Vec<i64> {
+        let mut result = Vec::new();
+        if window_end < window_start || max_results == 0 {
+            return result;
+        }
+        self.for_each_occurrence(anchor_ms, calendar, |occurrence| {
+            if occurrence > window_end {
+                return false;
+            }
+            if occurrence >= window_start {
+                result.push(occurrence);
+            }
+            result.len() < max_results
+        });
+        result
+    }
+
+    /// Returns the first occurrence start strictly after `after_ms`.
+    pub fn next_occurrence_after(
+        &self,
+        anchor_ms: i64,
+        calendar: &LocalCalendar,
+        after_ms: i64,
+    ) -> Option<i64> {
+        let mut found = None;
+        self.for_each_occurrence(anchor_ms, calendar, |occurrence| {
+            if occurrence > after_ms {
+                found = Some(occurrence);
+                return false;
+            }
+            true
+        });
+        found
+    }
+
+    /// Returns how many occurrences start strictly before `before_ms`.
+    pub fn count_before(&self, anchor_ms: i64, calendar: &LocalCalendar, before_ms: i64) -> u32 {
+        let mut counted = 0u32;
+        self.for_each_occurrence(anchor_ms, calendar, |occurrence| {
+            if occurrence >= before_ms {
+                return false;
+            }
+            counted += 1;
+            true
+        });
+        counted
+    }
+
+    /// Returns whether `candidate_ms` is one generated occurrence start.
+    pub fn is_occurrence(
+        &self,
+        anchor_ms: i64,
+        calendar: &LocalCalendar,
+        candidate_ms: i64,
+    ) -> bool {
+        let mut matched = false;
+        self.for_each_occurrence(anchor_ms, calendar, |occurrence| {
+            if occurrence == candidate_ms {
+                matched = true;
+            }
+            occurrence < candidate_ms
+        });
+        matched
+    }
+
+    /// Walks occurrences in ascending order until `visit` returns `false`,
+    /// the rule terminates (`COUNT`/`UNTIL`) or the period cap is reached.
+    fn for_each_occurrence(
+        &self,
+        anchor_ms: i64,
+        calendar: &LocalCalendar,
+        mut visit: impl FnMut(i64) -> bool,
+    ) {
+        let Some(anchor) = DateTime::from_timestamp_millis(calendar.to_floating(anchor_ms))
+            .map(|dt| dt.naive_utc())
+        else {
+            return;
+        };
+        let time_of_day = anchor.time();
+        let anchor_date = anchor.date();
+        let mut emitted = 0u32;
+
+        for period in 0..MAX_EXPANSION_PERIODS {
+            let Some(dates) = self.period_dates(anchor_date, period) else {
+                return;
+            };
+            for date in dates {
+                let local = NaiveDateTime::new(date, time_of_day);
+                let occurrence = calendar.local_instant(local);
+                if occurrence < anchor_ms {
+                    continue;
+                }
+                if self
+                    .until
+                    .is_some_and(|until| until.excludes(occurrence, local))
+                {
+                    return;
+                }
+                if let Some(count) = self.count {
+                    if emitted >= count {
+                        return;
+                    }
+                }
+                emitted += 1;
+                if !visit(occurrence) {
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Returns sorted candidate dates of the `period`-th interval step.
+    ///
+    /// Returns `None` once calendar arithmetic leaves the supported range.
+    fn period_dates(&self, anchor: NaiveDate, period: u32) -> Option<Vec<NaiveDate>> {
+        let step = i64::from(period) * i64::from(self.interval);
+        let mut dates = match self.frequency {
+            Frequency::Daily => {
+                let day = anchor.checked_add_signed(Duration::try_days(step)?)?;
+                if self.matches_day_filters(day) {
+                    vec![day]
+                } else {
+                    Vec::new()
+                }
+            }
+            Frequency::Weekly => {
+                let week_start = anchor
+                    - Duration::days(i64::from(anchor.weekday().days_since(self.week_start)));
+                let week_start = week_start.checked_add_signed(Duration::try_weeks(step)?)?;
+                let weekdays: Vec<Weekday> = if self.by_day.is_empty() {
+                    vec![anchor.weekday()]
+                } else {
+                    self.by_day.iter().map(|spec| spec.weekday).collect()
+                };
+                (0..7)
+                    .filter_map(|offset| week_start.checked_add_signed(Duration::days(offset)))
+                    .filter(|day| weekdays.contains(&day.weekday()))
+                    .filter(|day| self.matches_month_filter(*day))
+                    .collect()
+            }
+            Frequency::Monthly => {
+                let (year, month) = add_months(anchor.year(), anchor.month(), step)?;
+                if !self.by_month.is_empty() && !self.by_month.contains(&month) {
+                    Vec::new()
+                } else {
+                    self.month_dates(year, month, anchor.day())
+                }
+            }
+            Frequency::Yearly => {
+                let year = i32::try_from(i64::from(anchor.year()) + step).ok()?;
+                NaiveDate::from_ymd_opt(year, 1, 1)?;
+                if self.by_month.is_empty()
+                    && self.by_month_day.is_empty()
+                    && !self.by_day.is_empty()
+                {
+                    let first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+                    let last = NaiveDate::from_ymd_opt(year, 12, 31)?;
+                    weekday_dates_in_span(first, last, &self.by_day)
+                } else {
+                    let months = if self.by_month.is_empty() {
+                        vec![anchor.month()]
+                    } else {
+                        self.by_month.clone()
+                    };
+                    months
+                        .into_iter()
+                        .flat_map(|month| self.month_dates(year, month, anchor.day()))
+                        .collect()
+                }
+            }
+        };
+        dates.sort_unstable();
+        dates.dedup();
+        Some(dates)
+    }
+
+    /// Candidate dates inside one month for monthly/yearly expansion.
+    fn month_dates(&self, year: i32, month: u32, anchor_day: u32) -> Vec<NaiveDate> {
+        let Some(first) = NaiveDate::from_ymd_opt(year, month, 1) else {
+            return Vec::new();
+        };
+        let last = last_day_of_month(first);
+
+        let by_month_day: Vec<NaiveDate> = self
+            .by_month_day
+            .iter()
+            .filter_map(|day| resolve_month_day(first, last, *day))
+            .collect();
+
+        match (self.by_day.is_empty(), self.by_month_day.is_empty()) {
+            (true, true) => NaiveDate::from_ymd_opt(year, month, anchor_day)
+                .into_iter()
+                .collect(),
+            (true, false) => by_month_day,
+            (false, true) => weekday_dates_in_span(first, last, &self.by_day),
+            (false, false) => {
+                let by_day = weekday_dates_in_span(first, last, &self.by_day);
+                by_month_day
+                    .into_iter()
+                    .filter(|day| by_day.contains(day))
+                    .collect()
+            }
+        }
+    }
+
+    fn matches_day_filters(&self, day: NaiveDate) -> bool {
+        if !self.by_day.is_empty() && !self.by_day.iter().any(|spec| spec.weekday == day.weekday())
+        {
+            return false;
+        }
+        if !self.by_month_day.is_empty() {
+            let first = day.with_day(1).unwrap_or(day);
+            let last = last_day_of_month(first);
+            if !self
+                .by_month_day
+                .iter()
+                .any(|value| resolve_month_day(first, last, *value) == Some(day))
+            {
+                return false;
+            }
+        }
+        self.matches_month_filter(day)
+    }
+
+    fn matches_month_filter(&self, day: NaiveDate) -> bool {
+        self.by_month.is_empty() || self.by_month.contains(&day.month())
+    }
+}
+
+fn weekday_dates_in_span(
+    first: NaiveDate,
+    last: NaiveDate,
+    specs: &[WeekdaySpec],
+) -> Vec<NaiveDate> {
+    let mut result = Vec::new();
+    for spec in specs {
+        let matching: Vec<NaiveDate> = first
+            .iter_days()
+            .take_while(|day| *day <= last)
+            .filter(|day| day.weekday() == spec.weekday)
+            .collect();
+        match spec.ordinal {
+            None => result.extend(matching),
+            Some(ordinal) if ordinal > 0 => {
+                if let Some(day) = matching.get(ordinal as usize - 1) {
+                    result.push(*day);
+                }
+            }
+            Some(ordinal) => {
+                let from_end = ordinal.unsigned_abs() as usize;
+                if from_end <= matching.len() {
+                    result.push(matching[matching.len() - from_end]);
+                }
+            }
+        }
+    }
+    result
+}
+
+fn resolve_month_day(first: NaiveDate, last: NaiveDate, day: i8) -> Option<NaiveDate> {
+    if day > 0 {
+        first.with_day(day as u32)
+    } else {
+        let back = i64::from(day.unsigned_abs()) - 1;
+        let resolved = last - Duration::days(back);
+        (resolved.month() == first.month()).then_some(resolved)
+    }
+}
+
+fn last_day_of_month(first: NaiveDate) -> NaiveDate {
+    let (year, month) = if first.month() == 12 {
+        (first.year() + 1, 1)
+    } else {
+        (first.year(), first.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .map(|next| next - Duration::days(1))
+        .unwrap_or(first)
+}
+
+fn add_months(year: i32, month: u32, delta: i64) -> Option<(i32, u32)> {
+    let zero_based = i64::from(year) * 12 + i64::from(month) - 1 + delta;
+    let year = i32::try_from(zero_based.div_euclid(12)).ok()?;
+    let month = u32::try_from(zero_based.rem_euclid(12)).ok()? + 1;
+    Some((year, month))
+}
+
+fn parse_until(value: &str) -> Option<RecurrenceUntil> {
+    let value = value.trim();
+    if value.len() == 8 {
+        return NaiveDate::parse_from_str(value, "%Y%m%d")
+            .ok()
+            .map(RecurrenceUntil::Date);
+    }
+    match value.strip_suffix('Z') {
+        Some(utc) => NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S")
+            .ok()
+            .map(|dt| RecurrenceUntil::Instant(dt.and_utc().timestamp_millis())),
+        None => NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+            .ok()
+            .map(RecurrenceUntil::Local),
+    }
+}
+
+fn format_until(until: RecurrenceUntil) -> String {
+    match until {
+        RecurrenceUntil::Instant(until) => DateTime::from_timestamp_millis(until)
+            .map(|dt| dt.format("%Y%m%dT%H%M%SZ").to_string())
+            .unwrap_or_default(),
+        RecurrenceUntil::Local(until) => until.format("%Y%m%dT%H%M%S").to_string(),
+        RecurrenceUntil::Date(until) => until.format("%Y%m%d").to_string(),
+    }
+}
+
+fn parse_weekday_spec(value: &str) -> Option<WeekdaySpec> {
+    // Why: weekday codes are two ASCII letters; a split inside a multi-byte
+    // character means the value cannot be one.
+    let split = value
+        .len()
+        .checked_sub(2)
+        .filter(|split| value.is_char_boundary(*split))?;
+    let weekday = parse_weekday(&value[split..])?;
+    let ordinal_text = &value[..split];
+    let ordinal = if ordinal_text.is_empty() {
+        None
+    } else {
+        let parsed = ordinal_text
+            .strip_prefix('+')
+            .unwrap_or(ordinal_text)
+            .parse::<i8>()
+            .ok()?;
+        if parsed == 0 || !(-53..=53).contains(&parsed) {
+            return None;
+        }
+        Some(parsed)
+    };
+    Some(WeekdaySpec { ordinal, weekday })
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    match value.trim().to_ascii_uppercase().as_str() {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Frequency, RecurrenceError, RecurrenceRule, RecurrenceUntil};
+    use crate::model::local_time::LocalCalendar;
+    use chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday};
+
+    const UTC: LocalCalendar = LocalCalendar::utc();
+
+    fn ms(year: i32, month: u32, day: u32, hour: u32) -> i64 {
+        NaiveDateTime::new(
+            NaiveDate::from_ymd_opt(year, month, day).unwrap(),
+            chrono::NaiveTime::from_hms_opt(hour, 0, 0).unwrap(),
+        )
+        .and_utc()
+        .timestamp_millis()
+    }
+
+    #[test]
+    fn parses_and_round_trips_canonical_form() {
+        let rule = RecurrenceRule::parse("RRULE:FREQ=MONTHLY;INTERVAL=2;BYDAY=-1FR;COUNT=4")
+            .expect("rule parses");
+        assert_eq!(rule.frequency, Frequency::Monthly);
+        assert_eq!(rule.interval, 2);
+        assert_eq!(
+            rule.to_rrule_string(),
+            "FREQ=MONTHLY;INTERVAL=2;COUNT=4;BYDAY=-1FR"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_rules() {
+        assert_eq!(RecurrenceRule::parse("  "), Err(RecurrenceError::Empty));
+        assert_eq!(
+            RecurrenceRule::parse("COUNT=3"),
+            Err(RecurrenceError::MissingFrequency)
+        );
+        assert_eq!(
+            RecurrenceRule::parse("FREQ=DAILY;COUNT=2;UNTIL=20260101"),
+            Err(RecurrenceError::CountAndUntil)
+        );
+        assert!(matches!(
+            RecurrenceRule::parse("FREQ=HOURLY"),
+            Err(RecurrenceError::InvalidPart { .. })
+        ));
+        assert!(matches!(
+            RecurrenceRule::parse("FREQ=WEEKLY;BYDAY=XX"),
+            Err(RecurrenceError::InvalidPart { .. })
+        ));
+        for by_day in ["éX", "中", "1中X", "中MO"] {
+            assert!(matches!(
+                RecurrenceRule::parse(&format!("FREQ=WEEKLY;BYDAY={by_day}")),
+                Err(RecurrenceError::InvalidPart { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn weekly_byday_expands_within_each_week() {
+        // 2026-03-02 is a Monday.
+        let anchor = ms(2026, 3, 2, 9);
+        let rule = RecurrenceRule::parse("FREQ=WEEKLY;BYDAY=MO,WE;COUNT=5").unwrap();
+        let all = rule.occurrences_between(anchor, &UTC, anchor, i64::MAX, 100);
+        assert_eq!(
+            all,
+            vec![
+                ms(2026, 3, 2, 9),
+                ms(2026, 3, 4, 9),
+                ms(2026, 3, 9, 9),
+                ms(2026, 3, 11, 9),
+                ms(2026, 3, 16, 9),
+            ]
+        );
+    }
+
+    #[test]
+    fn monthly_by_month_day_skips_short_months() {
+        let anchor = ms(2026, 1, 31, 8);
+        let rule = RecurrenceRule::parse("FREQ=MONTHLY;COUNT=3").unwrap();
+        let all = rule.occurrences_between(anchor, &UTC, anchor, i64::MAX, 100);
+        assert_eq!(
+            all,
+            vec![ms(2026, 1, 31, 8), ms(2026, 3, 31, 8), ms(2026, 5, 31, 8)]
+        );
+
+        let last_day = RecurrenceRule::parse("FREQ=MONTHLY;BYMONTHDAY=-1;COUNT=2").unwrap();
+        let all = last_day.occurrences_between(anchor, &UTC, anchor, i64::MAX, 100);
+        assert_eq!(all, vec![ms(2026, 1, 31, 8), ms(2026, 2, 28, 8)]);
+    }
+
+    #[test]
+    fn until_is_inclusive_and_window_is_respected() {
+        let anchor = ms(2026, 3, 1, 10);
+        let rule = RecurrenceRule::parse("FREQ=DAILY;UNTIL=20260305").unwrap();
+        let all = rule.occurrences_between(anchor, &UTC, anchor, i64::MAX, 100);
+        assert_eq!(all.len(), 5);
+
+        let windowed =
+            rule.occurrences_between(anchor, &UTC, ms(2026, 3, 3, 0), ms(2026, 3, 4, 0), 100);
+        assert_eq!(windowed, vec![ms(2026, 3, 3, 10)]);
+        assert_eq!(
+            rule.next_occurrence_after(anchor, &UTC, ms(2026, 3, 5, 10)),
+            None
+        );
+        assert_eq!(rule.count_before(anchor, &UTC, ms(2026, 3, 3, 10)), 2);
+        assert!(rule.is_occurrence(anchor, &UTC, ms(2026, 3, 4, 10)));
+        assert!(!rule.is_occurrence(anchor, &UTC, ms(2026, 3, 4, 11)));
+    }
+
+    #[test]
+    fn yearly_nth_weekday_of_month() {
+        // US Thanksgiving: fourth Thursday of November.
+        let anchor = ms(2025, 11, 27, 12);
+        let rule = RecurrenceRule::parse("FREQ=YEARLY;BYMONTH=11;BYDAY=4TH;COUNT=3").unwrap();
+        let all = rule.occurrences_between(anchor, &UTC, anchor, i64::MAX, 100);
+        assert_eq!(
+            all,
+            vec![
+                ms(2025, 11, 27, 12),
+                ms(2026, 11, 26, 12),
+                ms(2027, 11, 25, 12)
+            ]
+        );
+    }
+
+    #[test]
+    fn unmatchable_rule_terminates() {
+        let anchor = ms(2026, 2, 1, 0);
+        let rule = RecurrenceRule::parse("FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30").unwrap();
+        assert!(rule
+            .occurrences_between(anchor, &UTC, anchor, i64::MAX, 10)
+            .is_empty());
+    }
+
+    #[test]
+    fn timed_series_keep_local_time_and_weekday_across_dst() {
+        let utc_ms = |month, day, hour, minute| {
+            Utc.with_ymd_and_hms(2026, month, day, hour, minute, 0)
+                .unwrap()
+                .timestamp_millis()
+        };
+        // Monday 2026-03-23 09:00 CET; Berlin switches to CEST on 2026-03-29.
+        let berlin = LocalCalendar::new("Europe/Berlin", Weekday::Mon).unwrap();
+        let anchor = utc_ms(3, 23, 8, 0);
+        let weekly = RecurrenceRule::parse("FREQ=WEEKLY;COUNT=3").unwrap();
+        assert_eq!(
+            weekly.occurrences_between(anchor, &berlin, anchor, i64::MAX, 10),
+            vec![anchor, utc_ms(3, 30, 7, 0), utc_ms(4, 6, 7, 0)]
+        );
+        assert!(weekly.is_occurrence(anchor, &berlin, utc_ms(3, 30, 7, 0)));
+        assert!(!weekly.is_occurrence(anchor, &UTC, utc_ms(3, 30, 7, 0)));
+
+        // Tuesday 00:30 in Berlin is still Monday in UTC.
+        let anchor = utc_ms(3, 2, 23, 30);
+        let tuesdays = RecurrenceRule::parse("FREQ=WEEKLY;BYDAY=TU;COUNT=2").unwrap();
+        assert_eq!(
+            tuesdays.occurrences_between(anchor, &berlin, anchor, i64::MAX, 10),
+            vec![anchor, utc_ms(3, 9, 23, 30)]
+        );
+
+        // 02:30 does not exist on 2026-03-29 and moves past the gap.
+        let anchor = utc_ms(3, 28, 1, 30);
+        let daily = RecurrenceRule::parse("FREQ=DAILY;COUNT=3").unwrap();
+        assert_eq!(
+            daily.occurrences_between(anchor, &berlin, anchor, i64::MAX, 10),
+            vec![anchor, utc_ms(3, 29, 1, 0), utc_ms(3, 30, 0, 30)]
+        );
+    }
+
+    #[test]
+    fn wkst_decides_weekly_periods_with_interval() {
+        // RFC 5545 example: DTSTART 1997-08-05 (Tuesday) 09:00.
+        let anchor = ms(1997, 8, 5, 9);
+        let monday =
+            RecurrenceRule::parse("FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO").unwrap();
+        assert_eq!(
+            monday.occurrences_between(anchor, &UTC, anchor, i64::MAX, 10),
+            vec![
+                anchor,
+                ms(1997, 8, 10, 9),
+                ms(1997, 8, 19, 9),
+                ms(1997, 8, 24, 9)
+            ]
+        );
+        assert_eq!(
+            monday.to_rrule_string(),
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU"
+        );
+
+        let sunday =
+            RecurrenceRule::parse("FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU").unwrap();
+        assert_eq!(sunday.week_start, Weekday::Sun);
+        assert_eq!(
+            sunday.occurrences_between(anchor, &UTC, anchor, i64::MAX, 10),
+            vec![
+                anchor,
+                ms(1997, 8, 17, 9),
+                ms(1997, 8, 19, 9),
+                ms(1997, 8, 31, 9)
+            ]
+        );
+        assert_eq!(
+            sunday.to_rrule_string(),
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU"
+        );
+    }
+
+    #[test]
+    fn floating_until_bounds_local_starts_in_series_zone() {
+        let berlin = LocalCalendar::new("Europe/Berlin", Weekday::Mon).unwrap();
+        // Daily at 00:30 Berlin (23:30 UTC the evening before).
+        let anchor = Utc
+            .with_ymd_and_hms(2026, 3, 1, 23, 30, 0)
+            .unwrap()
+            .timestamp_millis();
+        let day = 86_400_000;
+
+        let by_date = RecurrenceRule::parse("FREQ=DAILY;UNTIL=20260304").unwrap();
+        assert_eq!(
+            by_date.until,
+            Some(RecurrenceUntil::Date(
+                NaiveDate::from_ymd_opt(2026, 3, 4).unwrap()
+            ))
+        );
+        assert_eq!(by_date.to_rrule_string(), "FREQ=DAILY;UNTIL=20260304");
+        // 2026-03-04 00:30 Berlin is the last one, even though 2026-03-05
+        // 00:30 Berlin is still 2026-03-04 in UTC.
+        assert_eq!(
+            by_date.occurrences_between(anchor, &berlin, anchor, i64::MAX, 10),
+            vec![anchor, anchor + day, anchor + 2 * day]
+        );
+
+        let by_local = RecurrenceRule::parse("FREQ=DAILY;UNTIL=20260303T003000").unwrap();
+        assert_eq!(
+            by_local.to_rrule_string(),
+            "FREQ=DAILY;UNTIL=20260303T003000"
+        );
+        assert_eq!(
+            by_local.occurrences_between(anchor, &berlin, anchor, i64::MAX, 10),
+            vec![anchor, anchor + day]
+        );
+
+        let by_instant = RecurrenceRule::parse("FREQ=DAILY;UNTIL=20260302T233000Z").unwrap();
+        assert_eq!(
+            by_instant.until,
+            Some(RecurrenceUntil::Instant(anchor + day))
+        );
+        assert_eq!(
+            by_instant.occurrences_between(anchor, &berlin, anchor, i64::MAX, 10),
+            vec![anchor, anchor + day]
+        );
+    }
+}
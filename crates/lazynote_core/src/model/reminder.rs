@@ -0,0 +1,244 @@
+//! Atom reminders and their computed fire schedule.
+//!
+//! # Responsibility
+//! - Define the reminder model: an absolute instant, or an offset relative
+//!   to the atom `start_at`/`due_at`.
+//! - Compute fire times from the owning atom, expanding recurring atoms
+//!   into one firing per occurrence.
+//!
+//! # Invariants
+//! - Fire times are derived from the atom on every read; rescheduling an
+//!   atom or editing its rule moves pending firings without a rewrite.
+//! - Firings at or before `acked_through`, or before `created_at`, are never
+//!   delivered.
+//! - Skipped and detached occurrences of a series do not fire.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::{Atom, AtomId, TaskStatus};
+use crate::model::local_time::LocalCalendar;
+use crate::model::recurrence::RecurrenceRule;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// Stable reminder identifier.
+pub type ReminderId = Uuid;
+
+/// Occurrences expanded per batch while searching the latest due firing.
+const EXPANSION_BATCH: usize = 512;
+
+/// When a reminder fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderTrigger {
+    /// Fires once at an absolute epoch ms instant.
+    Absolute { remind_at: i64 },
+    /// Fires `offset_ms` after each (occurrence) `start_at`; negative is before.
+    Start { offset_ms: i64 },
+    /// Fires `offset_ms` after each (occurrence) `due_at`; negative is before.
+    Due { offset_ms: i64 },
+}
+
+impl ReminderTrigger {
+    /// Returns the stored anchor label (`absolute|start|due`).
+    pub fn anchor(&self) -> &'static str {
+        match self {
+            Self::Absolute { .. } => "absolute",
+            Self::Start { .. } => "start",
+            Self::Due { .. } => "due",
+        }
+    }
+}
+
+/// One reminder attached to an atom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reminder {
+    /// Reminder ID.
+    pub id: ReminderId,
+    /// Owning atom ID.
+    pub atom_id: AtomId,
+    /// Fire rule.
+    pub trigger: ReminderTrigger,
+    /// Latest acknowledged scheduled fire time, epoch ms.
+    pub acked_through: Option<i64>,
+    /// Snoozed firing redelivery time, epoch ms.
+    pub snoozed_until: Option<i64>,
+    /// Scheduled fire time the pending snooze replaces, epoch ms.
+    pub snoozed_fire_at: Option<i64>,
+    /// Creation time, epoch ms; earlier firings are never delivered.
+    pub created_at: i64,
+    /// Next pending fire time (snooze included); computed on read, `None`
+    /// once exhausted or while the atom is finished/deleted.
+    pub next_fire_at: Option<i64>,
+}
+
+impl Reminder {
+    /// Creates a fresh, unacknowledged reminder.
+    pub fn new(atom_id: AtomId, trigger: ReminderTrigger, created_at: i64) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            atom_id,
+            trigger,
+            acked_through: None,
+            snoozed_until: None,
+            snoozed_fire_at: None,
+            created_at,
+            next_fire_at: None,
+        }
+    }
+
+    /// Exclusive lower bound of deliverable scheduled firings.
+    pub(crate) fn delivered_through(&self) -> i64 {
+        let before_creation = self.created_at.saturating_sub(1);
+        self.acked_through
+            .map_or(before_creation, |acked| acked.max(before_creation))
+    }
+}
+
+/// One reminder firing that is due for delivery.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueReminder {
+    /// The reminder.
+    pub reminder: Reminder,
+    /// The owning atom.
+    pub atom: Atom,
+    /// Delivery time: the scheduled fire time, or `snoozed_until`.
+    pub fire_at: i64,
+    /// Occurrence start the firing belongs to for recurring atoms.
+    pub occurrence_start_at: Option<i64>,
+    /// Whether this is a snoozed firing being redelivered.
+    pub snoozed: bool,
+}
+
+/// Scheduled firings of one reminder over its atom.
+pub(crate) enum ReminderSchedule {
+    /// No firing (missing anchor, finished or deleted atom).
+    Never,
+    /// A single firing.
+    Once { fire_at: i64 },
+    /// One firing per non-excepted series occurrence, `shift` after its start.
+    Recurring {
+        rule: RecurrenceRule,
+        anchor: i64,
+        calendar: LocalCalendar,
+        shift: i64,
+        skipped: HashSet<i64>,
+    },
+}
+
+impl ReminderSchedule {
+    /// Builds the schedule of `trigger` over `atom`.
+    ///
+    /// `skipped` holds the original starts of excepted series occurrences;
+    /// timed series expand in `calendar`.
+    pub(crate) fn new(
+        trigger: ReminderTrigger,
+        atom: &Atom,
+        skipped: HashSet<i64>,
+        calendar: LocalCalendar,
+    ) -> Self {
+        if !atom.is_active()
+            || matches!(
+                atom.task_status,
+                Some(TaskStatus::Done | TaskStatus::Cancelled)
+            )
+        {
+            return Self::Never;
+        }
+        let (base, offset_ms) = match trigger {
+            ReminderTrigger::Absolute { remind_at } => return Self::Once { fire_at: remind_at },
+            ReminderTrigger::Start { offset_ms } => (atom.start_at, offset_ms),
+            ReminderTrigger::Due { offset_ms } => (atom.due_at, offset_ms),
+        };
+        let Some(base) = base else {
+            return Self::Never;
+        };
+        match (atom.parsed_recurrence().ok().flatten(), atom.start_at) {
+            (Some(rule), Some(anchor)) => Self::Recurring {
+                rule,
+                anchor,
+                calendar: atom.recurrence_calendar(calendar),
+                shift: base.saturating_sub(anchor).saturating_add(offset_ms),
+                skipped,
+            },
+            _ => Self::Once {
+                fire_at: base.saturating_add(offset_ms),
+            },
+        }
+    }
+
+    /// Returns whether the schedule can never fire.
+    pub(crate) fn is_never(&self) -> bool {
+        matches!(self, Self::Never)
+    }
+
+    /// Returns the first firing strictly after `after_ms`.
+    pub(crate) fn first_after(&self, after_ms: i64) -> Option<i64> {
+        match self {
+            Self::Never => None,
+            Self::Once { fire_at } => (*fire_at > after_ms).then_some(*fire_at),
+            Self::Recurring {
+                rule,
+                anchor,
+                calendar,
+                shift,
+                skipped,
+            } => {
+                let mut cursor = after_ms.saturating_sub(*shift);
+                while let Some(start) = rule.next_occurrence_after(*anchor, calendar, cursor) {
+                    if !skipped.contains(&start) {
+                        return Some(start.saturating_add(*shift));
+                    }
+                    cursor = start;
+                }
+                None
+            }
+        }
+    }
+
+    /// Returns the latest firing in `(after_ms, until_ms]`; missed earlier
+    /// firings collapse into it.
+    pub(crate) fn last_between(&self, after_ms: i64, until_ms: i64) -> Option<i64> {
+        match self {
+            Self::Never => None,
+            Self::Once { fire_at } => {
+                (*fire_at > after_ms && *fire_at <= until_ms).then_some(*fire_at)
+            }
+            Self::Recurring {
+                rule,
+                anchor,
+                calendar,
+                shift,
+                skipped,
+            } => {
+                let window_end = until_ms.saturating_sub(*shift);
+                let mut window_start = after_ms.saturating_sub(*shift).saturating_add(1);
+                let mut latest = None;
+                loop {
+                    let batch = rule.occurrences_between(
+                        *anchor,
+                        calendar,
+                        window_start,
+                        window_end,
+                        EXPANSION_BATCH,
+                    );
+                    if let Some(start) = batch.iter().rev().find(|start| !skipped.contains(start)) {
+                        latest = Some(start.saturating_add(*shift));
+                    }
+                    match batch.last() {
+                        Some(last) if batch.len() == EXPANSION_BATCH => window_start = last + 1,
+                        _ => return latest,
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the occurrence start a scheduled fire time belongs to.
+    pub(crate) fn occurrence_of(&self, fire_at: i64) -> Option<i64> {
+        match self {
+            Self::Recurring { shift, .. } => Some(fire_at.saturating_sub(*shift)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,283 @@
+//! External resource links (Markdown links and bare URLs).
+//!
+//! # Responsibility
+//! - Extract `[label](scheme:...)` Markdown links and bare
+//!   `http/https/file` URLs from atom content.
+//! - Define the read model for the persisted `links` index.
+//!
+//! # Invariants
+//! - Only absolute targets with a scheme are extracted; relative paths and
+//!   `#anchors` are ignored.
+//! - Image embeds, fenced code blocks and inline code spans are ignored.
+//! - Bare URLs are limited to `http`, `https` and `file`.
+//! - One entry per exact target; first occurrence wins.
+//!
+//! # See also
+//! - docs/architecture/links-and-launcher.md
+
+use crate::model::atom::AtomId;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashSet;
+
+static MARKDOWN_TARGET_LINK_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(!?)\[([^\]]*)\]\(\s*<?([^\s()<>]+)>?(?:\s+"[^"]*")?\s*\)"#)
+        .expect("valid markdown target link regex")
+});
+static BARE_URL_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)\b(?:https?|file)://[^\s<>"'`]+"#).expect("valid bare url regex")
+});
+static SCHEME_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^([A-Za-z][A-Za-z0-9+.\-]+):").expect("valid scheme regex"));
+
+/// Where a resource link was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceLinkSource {
+    /// `[label](target)` Markdown link.
+    Markdown,
+    /// Bare `http/https/file` URL in prose.
+    Bare,
+}
+
+impl ResourceLinkSource {
+    /// Returns the storage label (`markdown|bare`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Markdown => "markdown",
+            Self::Bare => "bare",
+        }
+    }
+
+    /// Parses a storage label.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "markdown" => Some(Self::Markdown),
+            "bare" => Some(Self::Bare),
+            _ => None,
+        }
+    }
+}
+
+/// One resource link parsed from content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceLinkRef {
+    /// Target as written (URL or `file://` URI).
+    pub target: String,
+    /// Lowercase scheme without `:`.
+    pub scheme: String,
+    /// Markdown label; `None` for bare URLs and empty labels.
+    pub label: Option<String>,
+    /// Syntax the link was written in.
+    pub source: ResourceLinkSource,
+}
+
+/// One persisted resource link row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceLink {
+    /// Stable row id; kept across saves while the target stays in content.
+    pub id: i64,
+    /// Atom whose content contains the link.
+    pub atom_id: AtomId,
+    /// Target as written.
+    pub target: String,
+    /// Lowercase scheme without `:`.
+    pub scheme: String,
+    /// Optional Markdown label.
+    pub label: Option<String>,
+    /// Syntax the link was written in.
+    pub source: ResourceLinkSource,
+    /// Zero-based document order within the atom.
+    pub position: u32,
+}
+
+/// Extracts resource links from markdown content in document order.
+pub fn parse_resource_links(content: &str) -> Vec<ResourceLinkRef> {
+    let mut links = Vec::new();
+    let mut seen = HashSet::new();
+    let mut in_fence = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+
+        let mut masked = mask_code_spans(line);
+        let mut found: Vec<(usize, ResourceLinkRef)> = Vec::new();
+
+        let mut consumed = Vec::new();
+        for captures in MARKDOWN_TARGET_LINK_RE.captures_iter(&masked) {
+            let whole = captures.get(0).expect("regex match has group 0");
+            consumed.push(whole.range());
+            if !captures[1].is_empty() {
+                continue;
+            }
+            let target = &captures[3];
+            let Some(scheme) = parse_scheme(target) else {
+                continue;
+            };
+            let label = captures[2].trim();
+            found.push((
+                whole.start(),
+                ResourceLinkRef {
+                    target: target.to_string(),
+                    scheme,
+                    label: (!label.is_empty()).then(|| label.to_string()),
+                    source: ResourceLinkSource::Markdown,
+                },
+            ));
+        }
+        // Why: a Markdown link target must not be indexed a second time as a
+        // bare URL, so its whole span is blanked before the bare scan.
+        for range in consumed {
+            masked.replace_range(range.clone(), &" ".repeat(range.len()));
+        }
+
+        for matched in BARE_URL_RE.find_iter(&masked) {
+            let target = trim_url_tail(matched.as_str());
+            let Some(scheme) = parse_scheme(target) else {
+                continue;
+            };
+            found.push((
+                matched.start(),
+                ResourceLinkRef {
+                    target: target.to_string(),
+                    scheme,
+                    label: None,
+                    source: ResourceLinkSource::Bare,
+                },
+            ));
+        }
+
+        found.sort_by_key(|(offset, _)| *offset);
+        for (_, link) in found {
+            if seen.insert(link.target.clone()) {
+                links.push(link);
+            }
+        }
+    }
+    links
+}
+
+/// Replaces inline code spans with spaces, keeping byte offsets stable.
+pub(crate) fn mask_code_spans(line: &str) -> String {
+    let bytes = line.as_bytes();
+    let mut masked = bytes.to_vec();
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] != b'`' {
+            index += 1;
+            continue;
+        }
+        let ticks = bytes[index..].iter().take_while(|b| **b == b'`').count();
+        let fence = &line[index..index + ticks];
+        match line[index + ticks..].find(fence) {
+            Some(close) => {
+                let end = index + ticks + close + ticks;
+                masked[index..end].fill(b' ');
+                index = end;
+            }
+            None => index += ticks,
+        }
+    }
+    // Whole code spans (including multi-byte chars) are blanked, so the
+    // result stays valid UTF-8.
+    String::from_utf8(masked).expect("masking keeps utf-8 boundaries")
+}
+
+/// Drops trailing sentence punctuation and unbalanced closing brackets.
+fn trim_url_tail(raw: &str) -> &str {
+    let mut end = raw.len();
+    loop {
+        let candidate = &raw[..end];
+        let Some(last) = candidate.chars().last() else {
+            break;
+        };
+        let strip = match last {
+            '.' | ',' | ';' | ':' | '!' | '?' | '*' | '_' => true,
+            ')' => candidate.matches('(').count() < candidate.matches(')').count(),
+            ']' => candidate.matches('[').count() < candidate.matches(']').count(),
+            _ => false,
+        };
+        if !strip {
+            break;
+        }
+        end -= last.len_utf8();
+    }
+    &raw[..end]
+}
+
+/// Returns the lowercase scheme of an absolute target.
+///
+/// Single-letter schemes are rejected so Windows drive paths (`C:\Work`)
+/// are not mistaken for URLs.
+fn parse_scheme(target: &str) -> Option<String> {
+    let captures = SCHEME_RE.captures(target)?;
+    let rest = &target[captures[0].len()..];
+    if rest.is_empty() {
+        return None;
+    }
+    Some(captures[1].to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_resource_links, ResourceLinkSource};
+
+    #[test]
+    fn extracts_markdown_and_bare_links_in_order() {
+        let content = "Open https://example.com/a, then [Work](file:///C:/Work).\n\
+                       Docs: <https://docs.rs/url> [spec](vscode://file/x)";
+        let links = parse_resource_links(content);
+        let targets: Vec<&str> = links.iter().map(|link| link.target.as_str()).collect();
+        assert_eq!(
+            targets,
+            vec![
+                "https://example.com/a",
+                "file:///C:/Work",
+                "https://docs.rs/url",
+                "vscode://file/x"
+            ]
+        );
+        assert_eq!(links[1].source, ResourceLinkSource::Markdown);
+        assert_eq!(links[1].label.as_deref(), Some("Work"));
+        assert_eq!(links[1].scheme, "file");
+        assert_eq!(links[3].scheme, "vscode");
+    }
+
+    #[test]
+    fn skips_images_relative_targets_and_code() {
+        let content = "![shot](https://img.example/a.png) [rel](./notes.md) [top](#top)\n\
+                       `https://inline.example` [drive](C:\\Work)\n\
+                       ```\nhttps://fenced.example\n```";
+        assert!(parse_resource_links(content).is_empty());
+    }
+
+    #[test]
+    fn trims_trailing_punctuation_and_keeps_balanced_parens() {
+        let content = "(see https://en.wikipedia.org/wiki/Rust_(language)). \
+                       Also https://example.com/end?";
+        let targets: Vec<String> = parse_resource_links(content)
+            .into_iter()
+            .map(|link| link.target)
+            .collect();
+        assert_eq!(
+            targets,
+            vec![
+                "https://en.wikipedia.org/wiki/Rust_(language)",
+                "https://example.com/end"
+            ]
+        );
+    }
+
+    #[test]
+    fn deduplicates_identical_targets() {
+        let links = parse_resource_links("https://a.example [A](https://a.example)");
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].source, ResourceLinkSource::Bare);
+    }
+}
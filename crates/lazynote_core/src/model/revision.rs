@@ -0,0 +1,153 @@
+//! Atom revision snapshots and line-level diffs.
+//!
+//! # Responsibility
+//! - Define the read model for captured atom revisions.
+//! - Compute deterministic line-level diffs between two content versions.
+//!
+//! # Invariants
+//! - A revision stores the atom state *before* the write that captured it.
+//! - Diff output covers every line of both inputs exactly once, in order.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::{AtomId, AtomType, TaskPriority, TaskStatus};
+use similar::{ChangeTag, TextDiff};
+
+/// Stable revision identifier (monotonic per database).
+pub type RevisionId = i64;
+
+/// One captured snapshot of an atom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomRevision {
+    /// Revision identifier.
+    pub revision_id: RevisionId,
+    /// Atom the snapshot belongs to.
+    pub atom_id: AtomId,
+    /// Atom type at capture time.
+    pub kind: AtomType,
+    /// Content at capture time.
+    pub content: String,
+    /// Preview text at capture time.
+    pub preview_text: Option<String>,
+    /// Preview image at capture time.
+    pub preview_image: Option<String>,
+    /// Task status at capture time.
+    pub task_status: Option<TaskStatus>,
+    /// Start time at capture time, epoch ms.
+    pub start_at: Option<i64>,
+    /// End time at capture time, epoch ms.
+    pub end_at: Option<i64>,
+    /// Whether start/end were floating all-day dates at capture time.
+    pub all_day: bool,
+    /// Priority at capture time.
+    pub priority: Option<TaskPriority>,
+    /// Deadline at capture time, epoch ms.
+    pub due_at: Option<i64>,
+    /// Recurrence rule at capture time.
+    pub recurrence_rule: Option<String>,
+    /// HLC stamp of the write that produced the snapshotted state.
+    pub hlc_timestamp: Option<String>,
+    /// `atoms.updated_at` of the snapshotted state, epoch ms.
+    pub source_updated_at: i64,
+    /// When the snapshot was captured, epoch ms.
+    pub captured_at: i64,
+}
+
+/// Line classification inside a diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    /// Line present in both versions.
+    Unchanged,
+    /// Line only present in the newer version.
+    Added,
+    /// Line only present in the older version.
+    Removed,
+}
+
+impl DiffLineKind {
+    /// Stable lowercase label used by FFI payloads.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Unchanged => "unchanged",
+            Self::Added => "added",
+            Self::Removed => "removed",
+        }
+    }
+}
+
+/// One line of a line-level diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    /// Line classification.
+    pub kind: DiffLineKind,
+    /// Line text without the trailing newline.
+    pub text: String,
+    /// 1-based line number in the older version.
+    pub old_line: Option<u32>,
+    /// 1-based line number in the newer version.
+    pub new_line: Option<u32>,
+}
+
+/// Computes a line-level diff from `old` to `new`.
+///
+/// Uses the Myers algorithm; trailing newlines do not produce empty lines.
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let diff = TextDiff::from_lines(old, new);
+    diff.iter_all_changes()
+        .map(|change| DiffLine {
+            kind: match change.tag() {
+                ChangeTag::Equal => DiffLineKind::Unchanged,
+                ChangeTag::Insert => DiffLineKind::Added,
+                ChangeTag::Delete => DiffLineKind::Removed,
+            },
+            text: change
+                .value()
+                .trim_end_matches('\n')
+                .trim_end_matches('\r')
+                .to_string(),
+            old_line: change.old_index().map(to_line_number),
+            new_line: change.new_index().map(to_line_number),
+        })
+        .collect()
+}
+
+fn to_line_number(index: usize) -> u32 {
+    u32::try_from(index + 1).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff_lines, DiffLineKind};
+
+    #[test]
+    fn diff_marks_added_removed_and_unchanged_lines() {
+        let lines = diff_lines("alpha\nbeta\ngamma\n", "alpha\ngamma\ndelta\n");
+        let summary: Vec<(DiffLineKind, &str)> = lines
+            .iter()
+            .map(|line| (line.kind, line.text.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (DiffLineKind::Unchanged, "alpha"),
+                (DiffLineKind::Removed, "beta"),
+                (DiffLineKind::Unchanged, "gamma"),
+                (DiffLineKind::Added, "delta"),
+            ]
+        );
+        assert_eq!(lines[1].old_line, Some(2));
+        assert_eq!(lines[1].new_line, None);
+        assert_eq!(lines[3].new_line, Some(3));
+    }
+
+    #[test]
+    fn diff_of_identical_content_is_all_unchanged() {
+        let lines = diff_lines("one\r\ntwo", "one\r\ntwo");
+        assert_eq!(lines.len(), 2);
+        assert!(lines
+            .iter()
+            .all(|line| line.kind == DiffLineKind::Unchanged));
+        assert_eq!(lines[0].text, "one");
+    }
+}
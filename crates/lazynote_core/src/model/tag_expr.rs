@@ -0,0 +1,364 @@
+//! Boolean tag expressions.
+//!
+//! # Responsibility
+//! - Represent `AND` / `OR` / `NOT` combinations of tag predicates.
+//! - Parse user-written expressions such as `(work OR client) AND NOT archived`.
+//!
+//! # Invariants
+//! - Leaf tag names are normalized like persisted tags (trimmed, lowercase,
+//!   `/`-separated path segments without empty parts).
+//! - A leaf matches the tag itself and every descendant (`work` matches
+//!   `work/client`).
+//! - Precedence: `NOT` > `AND` > `OR`; adjacent operands without an operator
+//!   are joined with `AND`.
+//! - `And(vec![])` matches every atom and `Or(vec![])` matches none.
+//! - Parse error offsets are `char` indices into the input, end-exclusive.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Boolean expression over atom tags.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagExpr {
+    /// Atom carries this tag or one of its `/` descendants (case-insensitive).
+    Tag(String),
+    /// Negation of the inner expression.
+    Not(Box<TagExpr>),
+    /// Every operand matches; empty means "always".
+    And(Vec<TagExpr>),
+    /// At least one operand matches; empty means "never".
+    Or(Vec<TagExpr>),
+}
+
+impl TagExpr {
+    /// Builds a normalized single-tag leaf; `None` for blank names.
+    pub fn tag(name: &str) -> Option<Self> {
+        normalize_tag_name(name).map(Self::Tag)
+    }
+
+    /// Wraps `self` in [`TagExpr::Not`].
+    pub fn negate(self) -> Self {
+        Self::Not(Box::new(self))
+    }
+}
+
+/// Tag expression parse failure category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagExprParseErrorKind {
+    /// Input holds no operand.
+    Empty,
+    /// `(` without a matching `)`.
+    UnclosedParen,
+    /// `)` without a matching `(`.
+    UnexpectedCloseParen,
+    /// Operator without a following operand (`work AND`, `NOT`).
+    MissingOperand,
+    /// `"` without a closing quote.
+    UnterminatedQuote,
+}
+
+/// Tag expression parse error with the offending `char` span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagExprParseError {
+    /// Failure category.
+    pub kind: TagExprParseErrorKind,
+    /// Start `char` offset (inclusive).
+    pub start: usize,
+    /// End `char` offset (exclusive).
+    pub end: usize,
+}
+
+impl Display for TagExprParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let message = match self.kind {
+            TagExprParseErrorKind::Empty => "tag expression is empty",
+            TagExprParseErrorKind::UnclosedParen => "unclosed `(`",
+            TagExprParseErrorKind::UnexpectedCloseParen => "unexpected `)`",
+            TagExprParseErrorKind::MissingOperand => "operator requires a tag operand",
+            TagExprParseErrorKind::UnterminatedQuote => "unterminated quote",
+        };
+        write!(f, "{message} at {}..{}", self.start, self.end)
+    }
+}
+
+impl Error for TagExprParseError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    Tag(String),
+}
+
+/// Parses a boolean tag expression.
+///
+/// Keywords `AND`, `OR`, `NOT` are case-insensitive; `-tag` is shorthand for
+/// `NOT tag`. Quote a tag (`"or"`) to use a keyword as a tag name.
+///
+/// # Errors
+/// - Returns [`TagExprParseError`] with the offending `char` span.
+pub fn parse_tag_expr(input: &str) -> Result<TagExpr, TagExprParseError> {
+    let tokens = tokenize(input)?;
+    if tokens.is_empty() {
+        return Err(TagExprParseError {
+            kind: TagExprParseErrorKind::Empty,
+            start: 0,
+            end: input.chars().count(),
+        });
+    }
+    let mut parser = Parser {
+        tokens,
+        index: 0,
+        input_len: input.chars().count(),
+    };
+    let expr = parser.parse_or()?;
+    if let Some((_, start, end)) = parser.tokens.get(parser.index) {
+        return Err(TagExprParseError {
+            kind: TagExprParseErrorKind::UnexpectedCloseParen,
+            start: *start,
+            end: *end,
+        });
+    }
+    Ok(expr)
+}
+
+fn tokenize(input: &str) -> Result<Vec<(Token, usize, usize)>, TagExprParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        let ch = chars[index];
+        let start = index;
+        match ch {
+            _ if ch.is_whitespace() => index += 1,
+            '(' => {
+                tokens.push((Token::Open, start, start + 1));
+                index += 1;
+            }
+            ')' => {
+                tokens.push((Token::Close, start, start + 1));
+                index += 1;
+            }
+            '-' => {
+                tokens.push((Token::Not, start, start + 1));
+                index += 1;
+            }
+            '"' => {
+                let Some(len) = chars[start + 1..].iter().position(|c| *c == '"') else {
+                    return Err(TagExprParseError {
+                        kind: TagExprParseErrorKind::UnterminatedQuote,
+                        start,
+                        end: chars.len(),
+                    });
+                };
+                let name: String = chars[start + 1..start + 1 + len].iter().collect();
+                index = start + len + 2;
+                if let Some(tag) = normalize_tag_name(&name) {
+                    tokens.push((Token::Tag(tag), start, index));
+                }
+            }
+            _ => {
+                let len = chars[start..]
+                    .iter()
+                    .take_while(|c| !c.is_whitespace() && !matches!(c, '(' | ')' | '"'))
+                    .count();
+                index = start + len;
+                let word: String = chars[start..index].iter().collect();
+                let token = match word.to_ascii_uppercase().as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    _ => match normalize_tag_name(&word) {
+                        Some(tag) => Token::Tag(tag),
+                        // Bare separators such as `/` carry no tag.
+                        None => continue,
+                    },
+                };
+                tokens.push((token, start, index));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize, usize)>,
+    index: usize,
+    input_len: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index).map(|(token, _, _)| token)
+    }
+
+    fn parse_or(&mut self) -> Result<TagExpr, TagExprParseError> {
+        let mut operands = vec![self.parse_and()?];
+        while self.peek() == Some(&Token::Or) {
+            self.index += 1;
+            operands.push(self.parse_and()?);
+        }
+        Ok(flatten(operands, TagExpr::Or))
+    }
+
+    fn parse_and(&mut self) -> Result<TagExpr, TagExprParseError> {
+        let mut operands = vec![self.parse_unary()?];
+        loop {
+            match self.peek() {
+                Some(Token::And) => {
+                    self.index += 1;
+                    operands.push(self.parse_unary()?);
+                }
+                // Implicit AND between adjacent operands.
+                Some(Token::Tag(_) | Token::Not | Token::Open) => {
+                    operands.push(self.parse_unary()?);
+                }
+                _ => break,
+            }
+        }
+        Ok(flatten(operands, TagExpr::And))
+    }
+
+    fn parse_unary(&mut self) -> Result<TagExpr, TagExprParseError> {
+        let Some((token, start, end)) = self.tokens.get(self.index).cloned() else {
+            return Err(self.missing_operand());
+        };
+        self.index += 1;
+        match token {
+            Token::Not => Ok(self.parse_unary()?.negate()),
+            Token::Tag(name) => Ok(TagExpr::Tag(name)),
+            Token::Open => {
+                if self.peek() == Some(&Token::Close) {
+                    return Err(TagExprParseError {
+                        kind: TagExprParseErrorKind::MissingOperand,
+                        start,
+                        end: end + 1,
+                    });
+                }
+                let inner = self.parse_or()?;
+                if self.peek() != Some(&Token::Close) {
+                    return Err(TagExprParseError {
+                        kind: TagExprParseErrorKind::UnclosedParen,
+                        start,
+                        end,
+                    });
+                }
+                self.index += 1;
+                Ok(inner)
+            }
+            Token::Close => Err(TagExprParseError {
+                kind: TagExprParseErrorKind::UnexpectedCloseParen,
+                start,
+                end,
+            }),
+            Token::And | Token::Or => Err(TagExprParseError {
+                kind: TagExprParseErrorKind::MissingOperand,
+                start,
+                end,
+            }),
+        }
+    }
+
+    fn missing_operand(&self) -> TagExprParseError {
+        let start = self
+            .tokens
+            .last()
+            .map_or(self.input_len, |(_, start, _)| *start);
+        TagExprParseError {
+            kind: TagExprParseErrorKind::MissingOperand,
+            start,
+            end: self.input_len,
+        }
+    }
+}
+
+/// Same normalization as `repo::note_repo::normalize_tag`.
+pub(crate) fn normalize_tag_name(name: &str) -> Option<String> {
+    let segments: Vec<String> = name
+        .split('/')
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    (!segments.is_empty()).then(|| segments.join("/"))
+}
+
+fn flatten(mut operands: Vec<TagExpr>, build: fn(Vec<TagExpr>) -> TagExpr) -> TagExpr {
+    if operands.len() == 1 {
+        operands.remove(0)
+    } else {
+        build(operands)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_tag_expr, TagExpr, TagExprParseErrorKind};
+
+    fn tag(name: &str) -> TagExpr {
+        TagExpr::Tag(name.to_string())
+    }
+
+    #[test]
+    fn not_binds_tighter_than_and_which_binds_tighter_than_or() {
+        assert_eq!(
+            parse_tag_expr("a OR b AND NOT c").unwrap(),
+            TagExpr::Or(vec![
+                tag("a"),
+                TagExpr::And(vec![tag("b"), tag("c").negate()])
+            ])
+        );
+        assert_eq!(
+            parse_tag_expr("(Work or CLIENT) and not archived").unwrap(),
+            TagExpr::And(vec![
+                TagExpr::Or(vec![tag("work"), tag("client")]),
+                tag("archived").negate()
+            ])
+        );
+        assert_eq!(
+            parse_tag_expr("work -archived \"or\"").unwrap(),
+            TagExpr::And(vec![tag("work"), tag("archived").negate(), tag("or")])
+        );
+    }
+
+    #[test]
+    fn hierarchical_leaves_are_normalized_per_segment() {
+        assert_eq!(
+            parse_tag_expr("Work//Client/ OR \" area / Home \"").unwrap(),
+            TagExpr::Or(vec![tag("work/client"), tag("area/home")])
+        );
+        assert_eq!(TagExpr::tag(" / "), None);
+    }
+
+    #[test]
+    fn malformed_expressions_report_offsets() {
+        let err = parse_tag_expr("  ").unwrap_err();
+        assert_eq!(err.kind, TagExprParseErrorKind::Empty);
+
+        let err = parse_tag_expr("(work OR client").unwrap_err();
+        assert_eq!(err.kind, TagExprParseErrorKind::UnclosedParen);
+        assert_eq!((err.start, err.end), (0, 1));
+
+        let err = parse_tag_expr("work)").unwrap_err();
+        assert_eq!(err.kind, TagExprParseErrorKind::UnexpectedCloseParen);
+        assert_eq!((err.start, err.end), (4, 5));
+
+        let err = parse_tag_expr("work AND").unwrap_err();
+        assert_eq!(err.kind, TagExprParseErrorKind::MissingOperand);
+
+        let err = parse_tag_expr("OR work").unwrap_err();
+        assert_eq!(
+            (err.kind, err.start, err.end),
+            (TagExprParseErrorKind::MissingOperand, 0, 2)
+        );
+
+        let err = parse_tag_expr("()").unwrap_err();
+        assert_eq!(err.kind, TagExprParseErrorKind::MissingOperand);
+    }
+}
@@ -0,0 +1,71 @@
+//! Time tracking sessions recorded against task atoms.
+//!
+//! # Responsibility
+//! - Define the time entry model: a running timer (open end) or a stopped
+//!   session with a fixed `[started_at, ended_at)` range.
+//! - Measure entry durations, optionally clipped to a query window.
+//!
+//! # Invariants
+//! - A stopped entry never ends before it starts.
+//! - A running entry lasts until "now"; durations are computed on read.
+//! - `device_id` is the local HLC node id of the recording device.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::AtomId;
+use uuid::Uuid;
+
+/// Stable time entry identifier.
+pub type TimeEntryId = Uuid;
+
+/// One tracked session on a task atom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeEntry {
+    /// Entry ID.
+    pub id: TimeEntryId,
+    /// Tracked task atom ID.
+    pub atom_id: AtomId,
+    /// Device that recorded the entry.
+    pub device_id: String,
+    /// Session start, epoch ms.
+    pub started_at: i64,
+    /// Session end (exclusive), epoch ms; `None` while the timer runs.
+    pub ended_at: Option<i64>,
+    /// Optional free-text note (for example an invoice line).
+    pub note: Option<String>,
+}
+
+impl TimeEntry {
+    /// Creates a running timer entry.
+    pub fn start(atom_id: AtomId, device_id: impl Into<String>, started_at: i64) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            atom_id,
+            device_id: device_id.into(),
+            started_at,
+            ended_at: None,
+            note: None,
+        }
+    }
+
+    /// Returns whether the timer is still running.
+    pub fn is_running(&self) -> bool {
+        self.ended_at.is_none()
+    }
+
+    /// Effective end, counting a running timer up to `now_ms`.
+    pub fn end_at(&self, now_ms: i64) -> i64 {
+        self.ended_at.unwrap_or(now_ms).max(self.started_at)
+    }
+
+    /// Tracked duration in ms, counting a running timer up to `now_ms`.
+    pub fn duration_ms(&self, now_ms: i64) -> i64 {
+        self.end_at(now_ms) - self.started_at
+    }
+
+    /// Tracked ms inside `[start_ms, end_ms)`.
+    pub fn overlap_ms(&self, start_ms: i64, end_ms: i64, now_ms: i64) -> i64 {
+        (self.end_at(now_ms).min(end_ms) - self.started_at.max(start_ms)).max(0)
+    }
+}
@@ -6,17 +6,33 @@
 //!
 //! # Invariants
 //! - Write paths must call `Atom::validate()` before SQL mutations.
+//! - Every write stamps a fresh local HLC into `hlc_timestamp`; caller
+//!   supplied `Atom::hlc_timestamp` values are ignored.
+//! - Update paths snapshot the prior state into `atom_revisions` first.
+//! - Create/update re-index content-derived tables (`atom_links`, `links`).
+//! - Updates that change `type` or `is_deleted` re-resolve title links;
+//!   workspace note_refs are kept (hybrid policy, migration 0008).
 //! - Read paths must reject invalid persisted state instead of masking it.
+//! - Recurring timed series expand in the repository calendar (UTC unless
+//!   set); all-day series always expand as floating dates.
 //!
 //! # See also
 //! - docs/releases/v0.1/prs/PR-0006-core-crud.md
 
 use crate::db::migrations::latest_version;
 use crate::db::DbError;
-use crate::model::atom::{Atom, AtomId, AtomType, AtomValidationError, TaskStatus};
+use crate::model::atom::{Atom, AtomId, AtomType, AtomValidationError, TaskPriority, TaskStatus};
+use crate::model::local_time::{LocalCalendar, LocalWindow};
+use crate::repo::content_index::index_atom_content;
+use crate::repo::dependency_repo::{load_blocked_ids, NOT_BLOCKED_SQL};
+use crate::repo::link_repo::reresolve_title_links;
+use crate::repo::revision_repo::{capture_revision, CaptureMode};
+use crate::sync::hlc::{stamp_hlc, HlcError};
 use log::{error, info, warn};
 use rusqlite::types::Value;
-use rusqlite::{params, params_from_iter, Connection, Row};
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Params, Row};
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashSet;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::time::Instant;
@@ -31,6 +47,9 @@ const ATOM_SELECT_SQL: &str = "SELECT
     task_status,
     start_at,
     end_at,
+    all_day,
+    priority,
+    due_at,
     recurrence_rule,
     hlc_timestamp,
     is_deleted
@@ -121,6 +140,15 @@ impl From<rusqlite::Error> for RepoError {
     }
 }
 
+impl From<HlcError> for RepoError {
+    fn from(value: HlcError) -> Self {
+        match value {
+            HlcError::Db(err) => Self::Db(err),
+            other => Self::InvalidData(other.to_string()),
+        }
+    }
+}
+
 /// A row returned by section queries, wrapping the parsed `Atom` with `updated_at`
 /// which is not part of the domain model but is needed for FFI list items.
 #[derive(Debug, Clone)]
@@ -129,10 +157,15 @@ pub struct SectionAtomRow {
     pub atom: Atom,
     /// Epoch ms timestamp from the `updated_at` column.
     pub updated_at: i64,
+    /// Original start of a virtual recurring occurrence.
+    ///
+    /// `None` for stored rows. For expanded occurrences `atom.uuid` is the
+    /// series ID and `atom.start_at`/`atom.end_at` are the occurrence window.
+    pub occurrence_start_at: Option<i64>,
 }
 
 /// SELECT columns for section queries (adds `updated_at` on top of ATOM_SELECT_SQL).
-const SECTION_SELECT_SQL: &str = "SELECT
+pub(crate) const SECTION_SELECT_SQL: &str = "SELECT
     uuid,
     type,
     content,
@@ -141,12 +174,22 @@ const SECTION_SELECT_SQL: &str = "SELECT
     task_status,
     start_at,
     end_at,
+    all_day,
+    priority,
+    due_at,
     recurrence_rule,
     hlc_timestamp,
     is_deleted,
     updated_at
 FROM atoms";
 
+/// Filters shared by the Inbox/Today section queries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SectionOptions {
+    /// Leave out tasks with an unfinished prerequisite (see `task_dependencies`).
+    pub hide_blocked: bool,
+}
+
 /// Query options for listing atoms.
 #[derive(Debug, Clone, Default)]
 pub struct AtomListQuery {
@@ -162,6 +205,8 @@ pub struct AtomListQuery {
 
 /// Repository interface for atom CRUD operations.
 pub trait AtomRepository {
+    /// Calendar recurring timed series are expanded in.
+    fn calendar(&self) -> LocalCalendar;
     /// Inserts a new atom and returns its stable ID.
     fn create_atom(&self, atom: &Atom) -> RepoResult<AtomId>;
     /// Updates an existing atom by ID.
@@ -182,26 +227,91 @@ pub trait AtomRepository {
 
     /// Returns atoms with both `start_at` and `end_at` NULL (timeless).
     /// Excludes done/cancelled atoms.
-    fn fetch_inbox(&self, limit: u32, offset: u32) -> RepoResult<Vec<SectionAtomRow>>;
+    fn fetch_inbox(&self, limit: u32, offset: u32) -> RepoResult<Vec<SectionAtomRow>> {
+        self.fetch_inbox_with(limit, offset, SectionOptions::default())
+    }
+
+    /// [`AtomRepository::fetch_inbox`] with explicit section filters.
+    fn fetch_inbox_with(
+        &self,
+        limit: u32,
+        offset: u32,
+        options: SectionOptions,
+    ) -> RepoResult<Vec<SectionAtomRow>>;
 
     /// Returns atoms "active today" based on time-matrix rules.
     /// `bod_ms` and `eod_ms` are device-local day boundaries in epoch ms.
     /// Excludes done/cancelled atoms.
+    ///
+    /// Recurring series contribute virtual occurrences whose window overlaps
+    /// `[bod_ms, eod_ms]`; past occurrences are not carried over. All-day
+    /// dates are compared as UTC dates; use
+    /// [`AtomRepository::fetch_today_with`] with a [`LocalWindow`] from
+    /// [`crate::LocalCalendar`] for timezone-correct all-day matching.
     fn fetch_today(
         &self,
         bod_ms: i64,
         eod_ms: i64,
         limit: u32,
         offset: u32,
+    ) -> RepoResult<Vec<SectionAtomRow>> {
+        self.fetch_today_with(
+            LocalWindow::from_instants(bod_ms, eod_ms),
+            limit,
+            offset,
+            SectionOptions::default(),
+        )
+    }
+
+    /// [`AtomRepository::fetch_today`] over a local day window with explicit
+    /// section filters.
+    ///
+    /// Timed atoms match the instant bounds; all-day atoms match the
+    /// floating bounds and sort before timed atoms.
+    fn fetch_today_with(
+        &self,
+        day: LocalWindow,
+        limit: u32,
+        offset: u32,
+        options: SectionOptions,
     ) -> RepoResult<Vec<SectionAtomRow>>;
 
     /// Returns atoms anchored entirely in the future (after `eod_ms`).
     /// Excludes done/cancelled atoms.
+    ///
+    /// Recurring series contribute only their next occurrence after `eod_ms`.
+    /// All-day dates are compared as UTC dates.
     fn fetch_upcoming(
         &self,
         eod_ms: i64,
         limit: u32,
         offset: u32,
+    ) -> RepoResult<Vec<SectionAtomRow>> {
+        self.fetch_upcoming_with(LocalWindow::from_instants(eod_ms, eod_ms), limit, offset)
+    }
+
+    /// [`AtomRepository::fetch_upcoming`] for atoms after the local day
+    /// window `today`.
+    ///
+    /// Timed atoms start after `today.end_ms`; all-day atoms start after
+    /// `today.floating_end_ms`. All-day atoms sort at their local midnight.
+    fn fetch_upcoming_with(
+        &self,
+        today: LocalWindow,
+        limit: u32,
+        offset: u32,
+    ) -> RepoResult<Vec<SectionAtomRow>>;
+
+    /// Returns incomplete atoms whose deadline is before `now_ms`.
+    ///
+    /// The deadline is `due_at`, else `end_at` of a deadline-only atom
+    /// (`start_at` NULL). Sorted by priority (high first, unprioritized
+    /// last), then earliest deadline. Recurring series are excluded.
+    fn fetch_overdue(
+        &self,
+        now_ms: i64,
+        limit: u32,
+        offset: u32,
     ) -> RepoResult<Vec<SectionAtomRow>>;
 
     /// Updates `task_status` for any atom type (universal completion).
@@ -209,8 +319,16 @@ pub trait AtomRepository {
     /// Idempotent: setting the same status twice succeeds.
     fn update_atom_status(&self, id: AtomId, status: Option<TaskStatus>) -> RepoResult<()>;
 
+    /// Sets or clears (`None`) the priority of an active atom.
+    fn update_atom_priority(&self, id: AtomId, priority: Option<TaskPriority>) -> RepoResult<()>;
+
+    /// Sets or clears (`None`) the deadline of an active atom.
+    fn update_atom_due_at(&self, id: AtomId, due_at: Option<i64>) -> RepoResult<()>;
+
     /// Returns atoms with both `start_at` and `end_at` set that overlap the given time range.
     /// Includes all statuses (done/cancelled shown on calendar).
+    ///
+    /// Recurring series are expanded into every overlapping occurrence.
     fn fetch_by_time_range(
         &self,
         range_start_ms: i64,
@@ -219,7 +337,21 @@ pub trait AtomRepository {
         offset: u32,
     ) -> RepoResult<Vec<SectionAtomRow>>;
 
-    /// Updates only `start_at` and `end_at` for a calendar event.
+    /// Returns atoms with both `start_at` and `end_at` set that touch the
+    /// inclusive local window `range`. Includes all statuses.
+    ///
+    /// Timed atoms match the instant bounds and all-day atoms the floating
+    /// bounds; recurring series are expanded into every touching occurrence.
+    /// Sorted all-day first, then by `start_at`, `end_at`.
+    fn fetch_calendar_window(
+        &self,
+        range: LocalWindow,
+        limit: u32,
+        offset: u32,
+    ) -> RepoResult<Vec<SectionAtomRow>>;
+
+    /// Updates only `start_at` and `end_at` for a calendar event; the new
+    /// bounds are instants, so the event becomes timed (`all_day` cleared).
     /// Validates `end_at >= start_at`; returns `RepoError::Validation(InvalidEventWindow)` on failure.
     fn update_event_times(&self, id: AtomId, start_at: i64, end_at: i64) -> RepoResult<()>;
 }
@@ -227,6 +359,7 @@ pub trait AtomRepository {
 /// SQLite-backed atom repository.
 pub struct SqliteAtomRepository<'conn> {
     conn: &'conn Connection,
+    calendar: LocalCalendar,
 }
 
 impl<'conn> SqliteAtomRepository<'conn> {
@@ -239,12 +372,28 @@ impl<'conn> SqliteAtomRepository<'conn> {
     ///   [`RepoError::MissingRequiredColumn`] when required schema shape is
     ///   incomplete.
     pub fn try_new(conn: &'conn Connection) -> RepoResult<Self> {
+        Self::try_new_with_calendar(conn, LocalCalendar::utc())
+    }
+
+    /// Constructs a repository expanding recurring timed series in
+    /// `calendar`, so occurrences keep their local time across DST changes.
+    ///
+    /// # Errors
+    /// - Same as [`SqliteAtomRepository::try_new`].
+    pub fn try_new_with_calendar(
+        conn: &'conn Connection,
+        calendar: LocalCalendar,
+    ) -> RepoResult<Self> {
         ensure_connection_ready(conn)?;
-        Ok(Self { conn })
+        Ok(Self { conn, calendar })
     }
 }
 
 impl AtomRepository for SqliteAtomRepository<'_> {
+    fn calendar(&self) -> LocalCalendar {
+        self.calendar
+    }
+
     fn create_atom(&self, atom: &Atom) -> RepoResult<AtomId> {
         let started_at = Instant::now();
         if let Err(err) = atom.validate() {
@@ -257,6 +406,7 @@ impl AtomRepository for SqliteAtomRepository<'_> {
             return Err(err.into());
         }
 
+        let hlc = stamp_hlc(self.conn)?.to_string();
         if let Err(err) = self.conn.execute(
             "INSERT INTO atoms (
                 uuid,
@@ -267,10 +417,13 @@ impl AtomRepository for SqliteAtomRepository<'_> {
                 task_status,
                 start_at,
                 end_at,
+                all_day,
+                priority,
+                due_at,
                 recurrence_rule,
                 hlc_timestamp,
                 is_deleted
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11);",
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14);",
             params![
                 atom.uuid.to_string(),
                 atom_type_to_db(atom.kind),
@@ -280,8 +433,11 @@ impl AtomRepository for SqliteAtomRepository<'_> {
                 atom.task_status.map(task_status_to_db),
                 atom.start_at,
                 atom.end_at,
+                bool_to_int(atom.all_day),
+                atom.priority.map(task_priority_to_db),
+                atom.due_at,
                 atom.recurrence_rule.as_deref(),
-                atom.hlc_timestamp.as_deref(),
+                hlc.as_str(),
                 bool_to_int(atom.is_deleted),
             ],
         ) {
@@ -294,6 +450,7 @@ impl AtomRepository for SqliteAtomRepository<'_> {
             );
             return Err(err.into());
         }
+        index_atom_content(self.conn, atom.uuid, &atom.content)?;
 
         info!(
             "event=atom_create module=repo status=ok atom_id={} atom_type={} duration_ms={}",
@@ -317,6 +474,16 @@ impl AtomRepository for SqliteAtomRepository<'_> {
             return Err(err.into());
         }
 
+        let previous: Option<(String, bool)> = self
+            .conn
+            .query_row(
+                "SELECT type, is_deleted FROM atoms WHERE uuid = ?1;",
+                [atom.uuid.to_string()],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        capture_revision(self.conn, atom.uuid, CaptureMode::Coalesce)?;
+        let hlc = stamp_hlc(self.conn)?.to_string();
         let changed = match self.conn.execute(
             "UPDATE atoms
              SET
@@ -327,11 +494,14 @@ impl AtomRepository for SqliteAtomRepository<'_> {
                 task_status = ?5,
                 start_at = ?6,
                 end_at = ?7,
-                recurrence_rule = ?8,
-                hlc_timestamp = ?9,
-                is_deleted = ?10,
+                all_day = ?8,
+                priority = ?9,
+                due_at = ?10,
+                recurrence_rule = ?11,
+                hlc_timestamp = ?12,
+                is_deleted = ?13,
                 updated_at = (strftime('%s', 'now') * 1000)
-             WHERE uuid = ?11;",
+             WHERE uuid = ?14;",
             params![
                 atom_type_to_db(atom.kind),
                 atom.content.as_str(),
@@ -340,8 +510,11 @@ impl AtomRepository for SqliteAtomRepository<'_> {
                 atom.task_status.map(task_status_to_db),
                 atom.start_at,
                 atom.end_at,
+                bool_to_int(atom.all_day),
+                atom.priority.map(task_priority_to_db),
+                atom.due_at,
                 atom.recurrence_rule.as_deref(),
-                atom.hlc_timestamp.as_deref(),
+                hlc.as_str(),
                 bool_to_int(atom.is_deleted),
                 atom.uuid.to_string(),
             ],
@@ -368,6 +541,13 @@ impl AtomRepository for SqliteAtomRepository<'_> {
             );
             return Err(RepoError::NotFound(atom.uuid));
         }
+        index_atom_content(self.conn, atom.uuid, &atom.content)?;
+        let (previous_kind, previously_deleted) = previous.unwrap_or_default();
+        if previous_kind != atom_type_to_db(atom.kind) || previously_deleted != atom.is_deleted {
+            // Why: title links only target live notes; a kind or tombstone
+            // change adds or removes a candidate target.
+            reresolve_title_links(self.conn)?;
+        }
 
         info!(
             "event=atom_update module=repo status=ok atom_id={} atom_type={} duration_ms={}",
@@ -434,14 +614,16 @@ impl AtomRepository for SqliteAtomRepository<'_> {
 
     fn soft_delete_atom(&self, id: AtomId) -> RepoResult<()> {
         let started_at = Instant::now();
+        let hlc = stamp_hlc(self.conn)?.to_string();
         let changed = match self.conn.execute(
             "UPDATE atoms
              SET
                 is_deleted = 1,
+                hlc_timestamp = ?2,
                 updated_at = (strftime('%s', 'now') * 1000)
              WHERE uuid = ?1
                AND is_deleted = 0;",
-            [id.to_string()],
+            params![id.to_string(), hlc],
         ) {
             Ok(changed) => changed,
             Err(err) => {
@@ -456,6 +638,9 @@ impl AtomRepository for SqliteAtomRepository<'_> {
         };
 
         if changed > 0 {
+            // Why: a deleted note frees its title; `[[Title]]` links may now
+            // resolve to another note with the same display name.
+            reresolve_title_links(self.conn)?;
             info!(
                 "event=atom_soft_delete module=repo status=ok atom_id={} already_deleted=false duration_ms={}",
                 id,
@@ -481,13 +666,21 @@ impl AtomRepository for SqliteAtomRepository<'_> {
         Err(RepoError::NotFound(id))
     }
 
-    fn fetch_inbox(&self, limit: u32, offset: u32) -> RepoResult<Vec<SectionAtomRow>> {
+    fn fetch_inbox_with(
+        &self,
+        limit: u32,
+        offset: u32,
+        options: SectionOptions,
+    ) -> RepoResult<Vec<SectionAtomRow>> {
+        let blocked_filter = section_blocked_filter(options);
         let sql = format!(
             "{SECTION_SELECT_SQL}
              WHERE start_at IS NULL
                AND end_at IS NULL
+               AND due_at IS NULL
                AND (task_status IS NULL OR task_status NOT IN ('done', 'cancelled'))
                AND is_deleted = 0
+               {blocked_filter}
              ORDER BY updated_at DESC, uuid ASC
              LIMIT ?1 OFFSET ?2"
         );
@@ -500,73 +693,180 @@ impl AtomRepository for SqliteAtomRepository<'_> {
         Ok(result)
     }
 
-    fn fetch_today(
+    fn fetch_today_with(
         &self,
-        bod_ms: i64,
-        eod_ms: i64,
+        day: LocalWindow,
         limit: u32,
         offset: u32,
+        options: SectionOptions,
     ) -> RepoResult<Vec<SectionAtomRow>> {
+        let blocked_filter = section_blocked_filter(options);
         let sql = format!(
             "{SECTION_SELECT_SQL}
              WHERE is_deleted = 0
+               AND recurrence_rule IS NULL
                AND (task_status IS NULL OR task_status NOT IN ('done', 'cancelled'))
+               {blocked_filter}
                AND (
                  (end_at IS NOT NULL AND end_at <= ?1 AND start_at IS NULL)
                  OR (start_at IS NOT NULL AND end_at IS NULL AND start_at <= ?1)
-                 OR (start_at IS NOT NULL AND end_at IS NOT NULL
+                 OR (start_at IS NOT NULL AND end_at IS NOT NULL AND all_day = 0
                      AND start_at <= ?1 AND end_at >= ?2)
+                 OR (start_at IS NOT NULL AND end_at IS NOT NULL AND all_day = 1
+                     AND start_at <= ?5 AND end_at >= ?6)
+                 OR (due_at IS NOT NULL AND due_at <= ?1)
                )
-             ORDER BY COALESCE(start_at, end_at) ASC, updated_at DESC
+             ORDER BY all_day DESC, COALESCE(start_at, end_at, due_at) ASC, updated_at DESC
              LIMIT ?3 OFFSET ?4"
         );
-        let mut stmt = self.conn.prepare(&sql)?;
-        let mut rows = stmt.query(params![eod_ms, bod_ms, limit, offset])?;
-        let mut result = Vec::new();
-        while let Some(row) = rows.next()? {
-            result.push(parse_section_atom_row(row)?);
+        let (bod_ms, eod_ms) = (day.start_ms, day.end_ms);
+        let (floating_bod, floating_eod) = (day.floating_start_ms, day.floating_end_ms);
+        let mut series = self.load_recurring_series(false, eod_ms.max(floating_eod))?;
+        if options.hide_blocked {
+            let ids: Vec<AtomId> = series.iter().map(|row| row.atom.uuid).collect();
+            let blocked = load_blocked_ids(self.conn, &ids)?;
+            series.retain(|row| !blocked.contains(&row.atom.uuid));
         }
-        Ok(result)
+        if series.is_empty() {
+            return query_section_rows(
+                self.conn,
+                &sql,
+                params![eod_ms, bod_ms, limit, offset, floating_eod, floating_bod],
+            );
+        }
+
+        let window = page_window(limit, offset);
+        let stored = query_section_rows(
+            self.conn,
+            &sql,
+            params![eod_ms, bod_ms, window as i64, 0, floating_eod, floating_bod],
+        )?;
+        let mut occurrences = Vec::new();
+        for row in &series {
+            let duration = series_duration(&row.atom);
+            let (start, end) = if row.atom.all_day {
+                (floating_bod, floating_eod)
+            } else {
+                (bod_ms, eod_ms)
+            };
+            let starts =
+                self.expand_series_starts(row, start.saturating_sub(duration), end, window)?;
+            occurrences.extend(
+                starts
+                    .into_iter()
+                    .map(|start| virtual_occurrence(row, start)),
+            );
+        }
+        Ok(merge_section_pages(
+            stored,
+            occurrences,
+            compare_all_day_first,
+            limit,
+            offset,
+        ))
     }
 
-    fn fetch_upcoming(
+    fn fetch_upcoming_with(
         &self,
-        eod_ms: i64,
+        today: LocalWindow,
         limit: u32,
         offset: u32,
     ) -> RepoResult<Vec<SectionAtomRow>> {
         let sql = format!(
             "{SECTION_SELECT_SQL}
              WHERE is_deleted = 0
+               AND recurrence_rule IS NULL
                AND (task_status IS NULL OR task_status NOT IN ('done', 'cancelled'))
+               AND (due_at IS NULL OR due_at > ?1)
                AND (
                  (end_at IS NOT NULL AND end_at > ?1 AND start_at IS NULL)
                  OR (start_at IS NOT NULL AND end_at IS NULL AND start_at > ?1)
-                 OR (start_at IS NOT NULL AND end_at IS NOT NULL AND start_at > ?1)
+                 OR (start_at IS NOT NULL AND end_at IS NOT NULL AND all_day = 0
+                     AND start_at > ?1)
+                 OR (start_at IS NOT NULL AND end_at IS NOT NULL AND all_day = 1
+                     AND start_at > ?4)
+                 OR (start_at IS NULL AND end_at IS NULL AND due_at IS NOT NULL)
                )
-             ORDER BY COALESCE(start_at, end_at) ASC, updated_at DESC
+             ORDER BY
+               COALESCE(CASE WHEN all_day = 1 THEN start_at - ?5 ELSE start_at END,
+                        end_at, due_at) ASC,
+               updated_at DESC
              LIMIT ?2 OFFSET ?3"
         );
-        let mut stmt = self.conn.prepare(&sql)?;
-        let mut rows = stmt.query(params![eod_ms, limit, offset])?;
-        let mut result = Vec::new();
-        while let Some(row) = rows.next()? {
-            result.push(parse_section_atom_row(row)?);
+        let eod_ms = today.end_ms;
+        let floating_eod = today.floating_end_ms;
+        let offset_ms = today.end_offset_ms();
+        let series = self.load_recurring_series(false, i64::MAX)?;
+        if series.is_empty() {
+            return query_section_rows(
+                self.conn,
+                &sql,
+                params![eod_ms, limit, offset, floating_eod, offset_ms],
+            );
         }
-        Ok(result)
+
+        let window = page_window(limit, offset);
+        let stored = query_section_rows(
+            self.conn,
+            &sql,
+            params![eod_ms, window as i64, 0, floating_eod, offset_ms],
+        )?;
+        let mut occurrences = Vec::new();
+        for row in &series {
+            let after = if row.atom.all_day {
+                floating_eod
+            } else {
+                eod_ms
+            };
+            if let Some(start) = self.next_series_start_after(row, after)? {
+                occurrences.push(virtual_occurrence(row, start));
+            }
+        }
+        Ok(merge_section_pages(
+            stored,
+            occurrences,
+            |left, right| compare_by_local_anchor(left, right, offset_ms),
+            limit,
+            offset,
+        ))
+    }
+
+    fn fetch_overdue(
+        &self,
+        now_ms: i64,
+        limit: u32,
+        offset: u32,
+    ) -> RepoResult<Vec<SectionAtomRow>> {
+        let sql = format!(
+            "{SECTION_SELECT_SQL}
+             WHERE is_deleted = 0
+               AND recurrence_rule IS NULL
+               AND (task_status IS NULL OR task_status NOT IN ('done', 'cancelled'))
+               AND COALESCE(due_at, CASE WHEN start_at IS NULL THEN end_at END) < ?1
+             ORDER BY
+               COALESCE(priority, 0) DESC,
+               COALESCE(due_at, end_at) ASC,
+               updated_at DESC,
+               uuid ASC
+             LIMIT ?2 OFFSET ?3"
+        );
+        query_section_rows(self.conn, &sql, params![now_ms, limit, offset])
     }
 
     fn update_atom_status(&self, id: AtomId, status: Option<TaskStatus>) -> RepoResult<()> {
         let started_at = Instant::now();
         let status_db = status.map(task_status_to_db);
+        capture_revision(self.conn, id, CaptureMode::Coalesce)?;
+        let hlc = stamp_hlc(self.conn)?.to_string();
 
         let changed = match self.conn.execute(
             "UPDATE atoms
              SET task_status = ?1,
+                 hlc_timestamp = ?3,
                  updated_at = (strftime('%s', 'now') * 1000)
              WHERE uuid = ?2
                AND is_deleted = 0;",
-            params![status_db, id.to_string()],
+            params![status_db, id.to_string(), hlc],
         ) {
             Ok(changed) => changed,
             Err(err) => {
@@ -599,6 +899,25 @@ impl AtomRepository for SqliteAtomRepository<'_> {
         Ok(())
     }
 
+    fn update_atom_priority(&self, id: AtomId, priority: Option<TaskPriority>) -> RepoResult<()> {
+        let value = priority.map(task_priority_to_db);
+        self.update_task_field(
+            id,
+            "atom_update_priority",
+            "priority",
+            value.map_or(Value::Null, Value::Integer),
+        )
+    }
+
+    fn update_atom_due_at(&self, id: AtomId, due_at: Option<i64>) -> RepoResult<()> {
+        self.update_task_field(
+            id,
+            "atom_update_due_at",
+            "due_at",
+            due_at.map_or(Value::Null, Value::Integer),
+        )
+    }
+
     fn fetch_by_time_range(
         &self,
         range_start_ms: i64,
@@ -610,19 +929,125 @@ impl AtomRepository for SqliteAtomRepository<'_> {
             "{SECTION_SELECT_SQL}
              WHERE start_at IS NOT NULL
                AND end_at IS NOT NULL
+               AND recurrence_rule IS NULL
                AND start_at < ?1
                AND end_at > ?2
                AND is_deleted = 0
              ORDER BY start_at ASC, end_at ASC
              LIMIT ?3 OFFSET ?4"
         );
-        let mut stmt = self.conn.prepare(&sql)?;
-        let mut rows = stmt.query(params![range_end_ms, range_start_ms, limit, offset])?;
-        let mut result = Vec::new();
-        while let Some(row) = rows.next()? {
-            result.push(parse_section_atom_row(row)?);
+        let series: Vec<SectionAtomRow> = self
+            .load_recurring_series(true, range_end_ms)?
+            .into_iter()
+            .filter(|row| row.atom.end_at.is_some())
+            .collect();
+        if series.is_empty() {
+            return query_section_rows(
+                self.conn,
+                &sql,
+                params![range_end_ms, range_start_ms, limit, offset],
+            );
         }
-        Ok(result)
+
+        let window = page_window(limit, offset);
+        let stored = query_section_rows(
+            self.conn,
+            &sql,
+            params![range_end_ms, range_start_ms, window as i64, 0],
+        )?;
+        let mut occurrences = Vec::new();
+        for row in &series {
+            let duration = series_duration(&row.atom);
+            // Why: overlap requires `start < range_end` and `end > range_start`.
+            let starts = self.expand_series_starts(
+                row,
+                range_start_ms.saturating_sub(duration).saturating_add(1),
+                range_end_ms.saturating_sub(1),
+                window,
+            )?;
+            occurrences.extend(
+                starts
+                    .into_iter()
+                    .map(|start| virtual_occurrence(row, start)),
+            );
+        }
+        Ok(merge_section_pages(
+            stored,
+            occurrences,
+            compare_by_calendar_window,
+            limit,
+            offset,
+        ))
+    }
+
+    fn fetch_calendar_window(
+        &self,
+        range: LocalWindow,
+        limit: u32,
+        offset: u32,
+    ) -> RepoResult<Vec<SectionAtomRow>> {
+        let sql = format!(
+            "{SECTION_SELECT_SQL}
+             WHERE start_at IS NOT NULL
+               AND end_at IS NOT NULL
+               AND recurrence_rule IS NULL
+               AND is_deleted = 0
+               AND (
+                 (all_day = 0 AND start_at <= ?1 AND end_at >= ?2)
+                 OR (all_day = 1 AND start_at <= ?5 AND end_at >= ?6)
+               )
+             ORDER BY all_day DESC, start_at ASC, end_at ASC
+             LIMIT ?3 OFFSET ?4"
+        );
+        let anchor_max = range.end_ms.max(range.floating_end_ms);
+        let series: Vec<SectionAtomRow> = self
+            .load_recurring_series(true, anchor_max)?
+            .into_iter()
+            .filter(|row| row.atom.end_at.is_some())
+            .collect();
+        let (bod, eod) = (range.start_ms, range.end_ms);
+        let (floating_bod, floating_eod) = (range.floating_start_ms, range.floating_end_ms);
+        if series.is_empty() {
+            return query_section_rows(
+                self.conn,
+                &sql,
+                params![eod, bod, limit, offset, floating_eod, floating_bod],
+            );
+        }
+
+        let window = page_window(limit, offset);
+        let stored = query_section_rows(
+            self.conn,
+            &sql,
+            params![eod, bod, window as i64, 0, floating_eod, floating_bod],
+        )?;
+        let mut occurrences = Vec::new();
+        for row in &series {
+            let duration = series_duration(&row.atom);
+            let (start, end) = if row.atom.all_day {
+                (range.floating_start_ms, range.floating_end_ms)
+            } else {
+                (range.start_ms, range.end_ms)
+            };
+            let starts =
+                self.expand_series_starts(row, start.saturating_sub(duration), end, window)?;
+            occurrences.extend(
+                starts
+                    .into_iter()
+                    .map(|start| virtual_occurrence(row, start)),
+            );
+        }
+        Ok(merge_section_pages(
+            stored,
+            occurrences,
+            |left, right| {
+                Reverse(left.atom.all_day)
+                    .cmp(&Reverse(right.atom.all_day))
+                    .then_with(|| compare_by_calendar_window(left, right))
+            },
+            limit,
+            offset,
+        ))
     }
 
     fn update_event_times(&self, id: AtomId, start_at: i64, end_at: i64) -> RepoResult<()> {
@@ -642,14 +1067,18 @@ impl AtomRepository for SqliteAtomRepository<'_> {
             ));
         }
 
+        capture_revision(self.conn, id, CaptureMode::Coalesce)?;
+        let hlc = stamp_hlc(self.conn)?.to_string();
         let changed = match self.conn.execute(
             "UPDATE atoms
              SET start_at = ?1,
                  end_at = ?2,
+                 all_day = 0,
+                 hlc_timestamp = ?4,
                  updated_at = (strftime('%s', 'now') * 1000)
              WHERE uuid = ?3
                AND is_deleted = 0;",
-            params![start_at, end_at, id.to_string()],
+            params![start_at, end_at, id.to_string(), hlc],
         ) {
             Ok(changed) => changed,
             Err(err) => {
@@ -684,10 +1113,267 @@ impl AtomRepository for SqliteAtomRepository<'_> {
     }
 }
 
+impl SqliteAtomRepository<'_> {
+    /// Writes one task metadata column of an active atom with revision
+    /// capture and a fresh HLC stamp.
+    fn update_task_field(
+        &self,
+        id: AtomId,
+        event: &str,
+        column: &'static str,
+        value: Value,
+    ) -> RepoResult<()> {
+        let started_at = Instant::now();
+        capture_revision(self.conn, id, CaptureMode::Coalesce)?;
+        let hlc = stamp_hlc(self.conn)?.to_string();
+        let changed = match self.conn.execute(
+            &format!(
+                "UPDATE atoms
+                 SET {column} = ?1,
+                     hlc_timestamp = ?3,
+                     updated_at = (strftime('%s', 'now') * 1000)
+                 WHERE uuid = ?2
+                   AND is_deleted = 0;"
+            ),
+            params![value, id.to_string(), hlc],
+        ) {
+            Ok(changed) => changed,
+            Err(err) => {
+                error!(
+                    "event={} module=repo status=error atom_id={} duration_ms={} error_code=db_write_failed error={}",
+                    event,
+                    id,
+                    started_at.elapsed().as_millis(),
+                    err
+                );
+                return Err(err.into());
+            }
+        };
+
+        if changed == 0 {
+            warn!(
+                "event={} module=repo status=error atom_id={} duration_ms={} error_code=not_found",
+                event,
+                id,
+                started_at.elapsed().as_millis()
+            );
+            return Err(RepoError::NotFound(id));
+        }
+
+        info!(
+            "event={} module=repo status=ok atom_id={} duration_ms={}",
+            event,
+            id,
+            started_at.elapsed().as_millis()
+        );
+        Ok(())
+    }
+
+    /// Loads active recurring series anchored at or before `anchor_max_ms`.
+    fn load_recurring_series(
+        &self,
+        include_closed: bool,
+        anchor_max_ms: i64,
+    ) -> RepoResult<Vec<SectionAtomRow>> {
+        let status_filter = if include_closed {
+            ""
+        } else {
+            "AND (task_status IS NULL OR task_status NOT IN ('done', 'cancelled'))"
+        };
+        let sql = format!(
+            "{SECTION_SELECT_SQL}
+             WHERE is_deleted = 0
+               AND recurrence_rule IS NOT NULL
+               AND start_at IS NOT NULL
+               AND start_at <= ?1
+               {status_filter}
+             ORDER BY start_at ASC, uuid ASC"
+        );
+        query_section_rows(self.conn, &sql, params![anchor_max_ms])
+    }
+
+    /// Expands occurrence starts in `[window_start, window_end]`, skipping exceptions.
+    fn expand_series_starts(
+        &self,
+        series: &SectionAtomRow,
+        window_start: i64,
+        window_end: i64,
+        max_results: usize,
+    ) -> RepoResult<Vec<i64>> {
+        let (Some(rule), Some(anchor)) = (series.atom.parsed_recurrence()?, series.atom.start_at)
+        else {
+            return Ok(Vec::new());
+        };
+        let calendar = series.atom.recurrence_calendar(self.calendar);
+        let exceptions = load_exception_starts(self.conn, series.atom.uuid)?;
+        let starts = rule
+            .occurrences_between(
+                anchor,
+                &calendar,
+                window_start,
+                window_end,
+                max_results.saturating_add(exceptions.len()),
+            )
+            .into_iter()
+            .filter(|start| !exceptions.contains(start))
+            .take(max_results)
+            .collect();
+        Ok(starts)
+    }
+
+    /// Returns the first non-excepted occurrence start strictly after `after_ms`.
+    fn next_series_start_after(
+        &self,
+        series: &SectionAtomRow,
+        after_ms: i64,
+    ) -> RepoResult<Option<i64>> {
+        let (Some(rule), Some(anchor)) = (series.atom.parsed_recurrence()?, series.atom.start_at)
+        else {
+            return Ok(None);
+        };
+        let calendar = series.atom.recurrence_calendar(self.calendar);
+        let exceptions = load_exception_starts(self.conn, series.atom.uuid)?;
+        let mut cursor = after_ms;
+        while let Some(start) = rule.next_occurrence_after(anchor, &calendar, cursor) {
+            if !exceptions.contains(&start) {
+                return Ok(Some(start));
+            }
+            cursor = start;
+        }
+        Ok(None)
+    }
+}
+
+/// Loads original starts of all exceptions (skipped or detached) of one series.
+pub(crate) fn load_exception_starts(
+    conn: &Connection,
+    series_id: AtomId,
+) -> RepoResult<HashSet<i64>> {
+    let mut stmt = conn.prepare(
+        "SELECT original_start_at
+         FROM recurrence_exceptions
+         WHERE series_uuid = ?1;",
+    )?;
+    let mut rows = stmt.query([series_id.to_string()])?;
+    let mut starts = HashSet::new();
+    while let Some(row) = rows.next()? {
+        starts.insert(row.get::<_, i64>(0)?);
+    }
+    Ok(starts)
+}
+
+pub(crate) fn query_section_rows(
+    conn: &Connection,
+    sql: &str,
+    params: impl Params,
+) -> RepoResult<Vec<SectionAtomRow>> {
+    let mut stmt = conn.prepare(sql)?;
+    let mut rows = stmt.query(params)?;
+    let mut result = Vec::new();
+    while let Some(row) = rows.next()? {
+        result.push(parse_section_atom_row(row)?);
+    }
+    Ok(result)
+}
+
+/// Extra `WHERE` clause for [`SectionOptions`] (empty when nothing is hidden).
+fn section_blocked_filter(options: SectionOptions) -> String {
+    if options.hide_blocked {
+        format!("AND {NOT_BLOCKED_SQL}")
+    } else {
+        String::new()
+    }
+}
+
+/// Number of leading rows each source must provide to serve one merged page.
+fn page_window(limit: u32, offset: u32) -> usize {
+    limit as usize + offset as usize
+}
+
+/// Length of one series occurrence in milliseconds (`0` for point-in-time atoms).
+fn series_duration(atom: &Atom) -> i64 {
+    match (atom.start_at, atom.end_at) {
+        (Some(start), Some(end)) => end - start,
+        _ => 0,
+    }
+}
+
+fn virtual_occurrence(series: &SectionAtomRow, start: i64) -> SectionAtomRow {
+    let mut atom = series.atom.clone();
+    atom.end_at = atom.end_at.map(|_| start + series_duration(&series.atom));
+    atom.start_at = Some(start);
+    SectionAtomRow {
+        atom,
+        updated_at: series.updated_at,
+        occurrence_start_at: Some(start),
+    }
+}
+
+/// Mirrors `ORDER BY COALESCE(start_at, end_at, due_at) ASC, updated_at DESC`.
+fn compare_by_section_anchor(left: &SectionAtomRow, right: &SectionAtomRow) -> Ordering {
+    let anchor = |row: &SectionAtomRow| row.atom.start_at.or(row.atom.end_at).or(row.atom.due_at);
+    (anchor(left), Reverse(left.updated_at)).cmp(&(anchor(right), Reverse(right.updated_at)))
+}
+
+/// Mirrors `ORDER BY all_day DESC, COALESCE(start_at, end_at, due_at) ASC,
+/// updated_at DESC`.
+fn compare_all_day_first(left: &SectionAtomRow, right: &SectionAtomRow) -> Ordering {
+    Reverse(left.atom.all_day)
+        .cmp(&Reverse(right.atom.all_day))
+        .then_with(|| compare_by_section_anchor(left, right))
+}
+
+/// [`compare_by_section_anchor`] with all-day starts shifted from floating
+/// to instant time by `offset_ms`.
+fn compare_by_local_anchor(
+    left: &SectionAtomRow,
+    right: &SectionAtomRow,
+    offset_ms: i64,
+) -> Ordering {
+    let anchor = |row: &SectionAtomRow| {
+        let start = if row.atom.all_day {
+            row.atom.start_at.map(|start| start - offset_ms)
+        } else {
+            row.atom.start_at
+        };
+        start.or(row.atom.end_at).or(row.atom.due_at)
+    };
+    (anchor(left), Reverse(left.updated_at)).cmp(&(anchor(right), Reverse(right.updated_at)))
+}
+
+/// Mirrors `ORDER BY start_at ASC, end_at ASC`.
+fn compare_by_calendar_window(left: &SectionAtomRow, right: &SectionAtomRow) -> Ordering {
+    (left.atom.start_at, left.atom.end_at).cmp(&(right.atom.start_at, right.atom.end_at))
+}
+
+/// Merges stored rows with virtual occurrences and applies pagination.
+///
+/// Both inputs must already contain the first `limit + offset` rows of
+/// their source; stable sorting keeps SQL order for ties.
+fn merge_section_pages(
+    mut stored: Vec<SectionAtomRow>,
+    occurrences: Vec<SectionAtomRow>,
+    compare: impl FnMut(&SectionAtomRow, &SectionAtomRow) -> Ordering,
+    limit: u32,
+    offset: u32,
+) -> Vec<SectionAtomRow> {
+    stored.extend(occurrences);
+    stored.sort_by(compare);
+    stored
+        .into_iter()
+        .skip(offset as usize)
+        .take(limit as usize)
+        .collect()
+}
+
 fn parse_section_atom_row(row: &Row<'_>) -> RepoResult<SectionAtomRow> {
     let atom = parse_atom_row(row)?;
     let updated_at: i64 = row.get("updated_at")?;
-    Ok(SectionAtomRow { atom, updated_at })
+    Ok(SectionAtomRow {
+        atom,
+        updated_at,
+        occurrence_start_at: None,
+    })
 }
 
 fn parse_atom_row(row: &Row<'_>) -> RepoResult<Atom> {
@@ -710,6 +1396,13 @@ fn parse_atom_row(row: &Row<'_>) -> RepoResult<Atom> {
         None => None,
     };
 
+    let priority = match row.get::<_, Option<i64>>("priority")? {
+        Some(value) => Some(parse_task_priority(value).ok_or_else(|| {
+            RepoError::InvalidData(format!("invalid priority `{value}` in atoms.priority"))
+        })?),
+        None => None,
+    };
+
     let is_deleted = match row.get::<_, i64>("is_deleted")? {
         0 => false,
         1 => true,
@@ -729,6 +1422,9 @@ fn parse_atom_row(row: &Row<'_>) -> RepoResult<Atom> {
         task_status,
         start_at: row.get("start_at")?,
         end_at: row.get("end_at")?,
+        all_day: row.get::<_, i64>("all_day")? == 1,
+        priority,
+        due_at: row.get("due_at")?,
         recurrence_rule: row.get("recurrence_rule")?,
         hlc_timestamp: row.get("hlc_timestamp")?,
         is_deleted,
@@ -745,7 +1441,7 @@ pub(crate) fn atom_type_to_db(kind: AtomType) -> &'static str {
     }
 }
 
-fn parse_atom_type(value: &str) -> Option<AtomType> {
+pub(crate) fn parse_atom_type(value: &str) -> Option<AtomType> {
     match value {
         "note" => Some(AtomType::Note),
         "task" => Some(AtomType::Task),
@@ -773,6 +1469,23 @@ pub(crate) fn parse_task_status(value: &str) -> Option<TaskStatus> {
     }
 }
 
+pub(crate) fn task_priority_to_db(priority: TaskPriority) -> i64 {
+    match priority {
+        TaskPriority::Low => 1,
+        TaskPriority::Medium => 2,
+        TaskPriority::High => 3,
+    }
+}
+
+pub(crate) fn parse_task_priority(value: i64) -> Option<TaskPriority> {
+    match value {
+        1 => Some(TaskPriority::Low),
+        2 => Some(TaskPriority::Medium),
+        3 => Some(TaskPriority::High),
+        _ => None,
+    }
+}
+
 fn bool_to_int(value: bool) -> i64 {
     if value {
         1
@@ -815,6 +1528,9 @@ fn ensure_connection_ready(conn: &Connection) -> RepoResult<()> {
         "task_status",
         "start_at",
         "end_at",
+        "all_day",
+        "priority",
+        "due_at",
         "recurrence_rule",
         "is_deleted",
         "updated_at",
@@ -0,0 +1,232 @@
+//! Attachment metadata and reference index persistence.
+//!
+//! # Responsibility
+//! - Record imported attachment blobs (`attachments`).
+//! - Refresh the atom -> attachment reference index on content writes.
+//! - Report reference counts and garbage-collection candidates.
+//!
+//! # Invariants
+//! - `atom_attachments` rows are derived data; `atoms.content` stays the
+//!   source of truth.
+//! - Trashed atoms keep their references so restored notes find their files;
+//!   purge drops them.
+//! - Blob files are owned by the service layer; this module never touches
+//!   the filesystem.
+//!
+//! # See also
+//! - crates/lazynote_core/src/service/attachment_service.rs
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::AtomId;
+use crate::model::attachment::{parse_attachment_refs, Attachment};
+use crate::repo::atom_repo::{RepoError, RepoResult};
+use log::info;
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection, Row, Transaction, TransactionBehavior};
+use std::time::Instant;
+
+const ATTACHMENT_SELECT_SQL: &str = "SELECT
+    t.sha256 AS sha256,
+    t.byte_size AS byte_size,
+    t.mime_type AS mime_type,
+    t.original_name AS original_name,
+    t.created_at AS created_at,
+    (SELECT COUNT(*) FROM atom_attachments r WHERE r.sha256 = t.sha256) AS ref_count
+FROM attachments t";
+
+/// Repository interface for attachment metadata.
+pub trait AttachmentRepository {
+    /// Records one imported blob.
+    ///
+    /// Returns the stored row and whether it already existed (dedup hit).
+    /// Existing rows keep their first `original_name`/`mime_type`; their
+    /// `created_at` moves forward to `now_ms` so GC grace restarts.
+    fn upsert_attachment(
+        &self,
+        sha256: &str,
+        byte_size: i64,
+        mime_type: Option<&str>,
+        original_name: Option<&str>,
+        now_ms: i64,
+    ) -> RepoResult<(Attachment, bool)>;
+    /// Loads one attachment by digest.
+    fn get_attachment(&self, sha256: &str) -> RepoResult<Option<Attachment>>;
+    /// Lists attachments referenced by one atom, in document order.
+    ///
+    /// Digests without an imported blob on this device are skipped.
+    fn list_atom_attachments(&self, atom_id: AtomId) -> RepoResult<Vec<Attachment>>;
+    /// Lists every recorded attachment digest.
+    fn list_attachment_digests(&self) -> RepoResult<Vec<String>>;
+    /// Deletes zero-reference rows created before `created_before_ms`.
+    ///
+    /// Returns the deleted rows so the caller can remove their blobs.
+    fn delete_unreferenced(&self, created_before_ms: i64) -> RepoResult<Vec<Attachment>>;
+}
+
+/// SQLite-backed attachment repository.
+pub struct SqliteAttachmentRepository<'conn> {
+    conn: &'conn Connection,
+}
+
+impl<'conn> SqliteAttachmentRepository<'conn> {
+    /// Constructs a repository from an existing SQLite connection.
+    ///
+    /// # Errors
+    /// - Returns [`RepoError::MissingRequiredTable`] when the `attachments`
+    ///   table is absent.
+    pub fn try_new(conn: &'conn Connection) -> RepoResult<Self> {
+        let exists: i64 = conn.query_row(
+            "SELECT EXISTS(
+                SELECT 1
+                FROM sqlite_master
+                WHERE type = 'table' AND name = 'attachments'
+            );",
+            [],
+            |row| row.get(0),
+        )?;
+        if exists != 1 {
+            return Err(RepoError::MissingRequiredTable("attachments"));
+        }
+        Ok(Self { conn })
+    }
+}
+
+impl AttachmentRepository for SqliteAttachmentRepository<'_> {
+    fn upsert_attachment(
+        &self,
+        sha256: &str,
+        byte_size: i64,
+        mime_type: Option<&str>,
+        original_name: Option<&str>,
+        now_ms: i64,
+    ) -> RepoResult<(Attachment, bool)> {
+        let existed = self.get_attachment(sha256)?.is_some();
+        // Why: a re-import may be about to reference a zero-ref row that is
+        // past its grace window; refreshing `created_at` keeps GC off it.
+        self.conn.execute(
+            "INSERT INTO attachments (
+                sha256,
+                byte_size,
+                mime_type,
+                original_name,
+                created_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT(sha256) DO UPDATE SET
+                created_at = MAX(created_at, excluded.created_at);",
+            params![sha256, byte_size, mime_type, original_name, now_ms],
+        )?;
+        let attachment = self.get_attachment(sha256)?.ok_or_else(|| {
+            RepoError::InvalidData(format!("attachment `{sha256}` missing after insert"))
+        })?;
+        Ok((attachment, existed))
+    }
+
+    fn get_attachment(&self, sha256: &str) -> RepoResult<Option<Attachment>> {
+        let sql = format!("{ATTACHMENT_SELECT_SQL} WHERE t.sha256 = ?1;");
+        let mut stmt = self.conn.prepare(&sql)?;
+        let mut rows = stmt.query([sha256])?;
+        match rows.next()? {
+            Some(row) => Ok(Some(parse_attachment_row(row)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn list_atom_attachments(&self, atom_id: AtomId) -> RepoResult<Vec<Attachment>> {
+        let sql = format!(
+            "{ATTACHMENT_SELECT_SQL}
+             JOIN atom_attachments a ON a.sha256 = t.sha256
+             WHERE a.atom_uuid = ?1
+             ORDER BY a.position ASC;"
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let mut rows = stmt.query([atom_id.to_string()])?;
+        let mut attachments = Vec::new();
+        while let Some(row) = rows.next()? {
+            attachments.push(parse_attachment_row(row)?);
+        }
+        Ok(attachments)
+    }
+
+    fn list_attachment_digests(&self) -> RepoResult<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT sha256 FROM attachments ORDER BY sha256 ASC;")?;
+        let rows = stmt.query_map([], |row| row.get(0))?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    fn delete_unreferenced(&self, created_before_ms: i64) -> RepoResult<Vec<Attachment>> {
+        let started_at = Instant::now();
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let candidates: Vec<Attachment> = {
+            let sql = format!(
+                "{ATTACHMENT_SELECT_SQL}
+                 WHERE t.created_at < ?1
+                   AND NOT EXISTS (
+                       SELECT 1 FROM atom_attachments r WHERE r.sha256 = t.sha256
+                   )
+                 ORDER BY t.sha256 ASC;"
+            );
+            let mut stmt = tx.prepare(&sql)?;
+            let mut rows = stmt.query([created_before_ms])?;
+            let mut candidates = Vec::new();
+            while let Some(row) = rows.next()? {
+                candidates.push(parse_attachment_row(row)?);
+            }
+            candidates
+        };
+        if !candidates.is_empty() {
+            let placeholders = vec!["?"; candidates.len()].join(", ");
+            let values = candidates
+                .iter()
+                .map(|attachment| Value::Text(attachment.sha256.clone()));
+            tx.execute(
+                &format!("DELETE FROM attachments WHERE sha256 IN ({placeholders});"),
+                params_from_iter(values),
+            )?;
+        }
+        tx.commit()?;
+
+        info!(
+            "event=attachments_gc_rows module=repo status=ok deleted={} duration_ms={}",
+            candidates.len(),
+            started_at.elapsed().as_millis()
+        );
+        Ok(candidates)
+    }
+}
+
+/// Replaces the attachment references of `atom_id` with those in `content`.
+pub(crate) fn sync_attachment_refs(
+    conn: &Connection,
+    atom_id: AtomId,
+    content: &str,
+) -> rusqlite::Result<()> {
+    let uuid = atom_id.to_string();
+    conn.execute(
+        "DELETE FROM atom_attachments WHERE atom_uuid = ?1;",
+        [&uuid],
+    )?;
+    for (position, sha256) in parse_attachment_refs(content).iter().enumerate() {
+        conn.execute(
+            "INSERT INTO atom_attachments (atom_uuid, sha256, position)
+             VALUES (?1, ?2, ?3);",
+            params![uuid, sha256, position as i64],
+        )?;
+    }
+    Ok(())
+}
+
+fn parse_attachment_row(row: &Row<'_>) -> RepoResult<Attachment> {
+    let ref_count: i64 = row.get("ref_count")?;
+    Ok(Attachment {
+        sha256: row.get("sha256")?,
+        byte_size: row.get("byte_size")?,
+        mime_type: row.get("mime_type")?,
+        original_name: row.get("original_name")?,
+        created_at: row.get("created_at")?,
+        ref_count: u32::try_from(ref_count).map_err(|_| {
+            RepoError::InvalidData(format!("invalid attachment ref count `{ref_count}`"))
+        })?,
+    })
+}
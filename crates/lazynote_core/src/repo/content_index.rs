@@ -0,0 +1,108 @@
+//! Derived indexes refreshed on every atom content write.
+//!
+//! # Responsibility
+//! - Give repository write paths one hook that keeps every content-derived
+//!   table in step with `atoms.content`.
+//!
+//! # Invariants
+//! - Must run on the same connection/transaction as the content write.
+//! - Indexes are replaceable: rebuilding from content yields the same rows.
+//! - `atom_content_stats` caches [`ContentStats`] for list views.
+//! - The title projection runs last so note_ref renames see fresh links.
+//!
+//! # See also
+//! - crates/lazynote_core/src/repo/attachment_repo.rs
+//! - crates/lazynote_core/src/repo/link_repo.rs
+//! - crates/lazynote_core/src/repo/resource_link_repo.rs
+//! - crates/lazynote_core/src/repo/note_repo.rs
+//! - crates/lazynote_core/src/repo/property_repo.rs
+//! - crates/lazynote_core/src/repo/title_repo.rs
+
+use crate::model::atom::AtomId;
+use crate::model::markdown::{content_stats, ContentStats};
+use crate::repo::attachment_repo::sync_attachment_refs;
+use crate::repo::link_repo::sync_atom_links;
+use crate::repo::note_repo::sync_inline_tags;
+use crate::repo::property_repo::sync_atom_properties;
+use crate::repo::resource_link_repo::sync_resource_links;
+use crate::repo::title_repo::sync_atom_title;
+use rusqlite::types::Type;
+use rusqlite::{params, Connection};
+use uuid::Uuid;
+
+/// Re-indexes wiki-links, resource links, inline hashtags, attachment
+/// references, frontmatter properties, content statistics and the title of
+/// one atom from `content`.
+pub(crate) fn index_atom_content(
+    conn: &Connection,
+    atom_id: AtomId,
+    content: &str,
+) -> rusqlite::Result<()> {
+    sync_atom_links(conn, atom_id, content)?;
+    sync_resource_links(conn, atom_id, content)?;
+    sync_inline_tags(conn, atom_id, content)?;
+    sync_attachment_refs(conn, atom_id, content)?;
+    sync_atom_properties(conn, atom_id, content)?;
+    sync_content_stats(conn, atom_id, content)?;
+    sync_atom_title(conn, atom_id, content)?;
+    Ok(())
+}
+
+/// Runs one per-atom index `sync` over every stored atom; returns the number
+/// of atoms indexed.
+///
+/// Used by manual rebuilds and by the migration executor to backfill a table
+/// for content saved before the table existed.
+pub(crate) fn reindex_all_atoms(
+    conn: &Connection,
+    sync: fn(&Connection, AtomId, &str) -> rusqlite::Result<()>,
+) -> rusqlite::Result<u32> {
+    let sources: Vec<(String, String)> = {
+        let mut stmt = conn.prepare("SELECT uuid, content FROM atoms;")?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect::<Result<_, _>>()?
+    };
+    for (uuid, content) in &sources {
+        let atom_id = Uuid::parse_str(uuid).map_err(|err| {
+            rusqlite::Error::FromSqlConversionFailure(0, Type::Text, Box::new(err))
+        })?;
+        sync(conn, atom_id, content)?;
+    }
+    Ok(u32::try_from(sources.len()).unwrap_or(u32::MAX))
+}
+
+/// Replaces the cached [`ContentStats`] row of `atom_id`.
+pub(crate) fn sync_content_stats(
+    conn: &Connection,
+    atom_id: AtomId,
+    content: &str,
+) -> rusqlite::Result<()> {
+    let ContentStats {
+        word_count,
+        char_count,
+        checklist_done,
+        checklist_total,
+    } = content_stats(content);
+    conn.execute(
+        "INSERT INTO atom_content_stats (
+            atom_uuid,
+            word_count,
+            char_count,
+            checklist_done,
+            checklist_total
+        ) VALUES (?1, ?2, ?3, ?4, ?5)
+        ON CONFLICT(atom_uuid) DO UPDATE SET
+            word_count = excluded.word_count,
+            char_count = excluded.char_count,
+            checklist_done = excluded.checklist_done,
+            checklist_total = excluded.checklist_total;",
+        params![
+            atom_id.to_string(),
+            word_count,
+            char_count,
+            checklist_done,
+            checklist_total
+        ],
+    )?;
+    Ok(())
+}
@@ -0,0 +1,200 @@
+//! Task dependency persistence (`task_dependencies`).
+//!
+//! # Responsibility
+//! - Store "task depends on prerequisite" edges between task atoms.
+//! - Answer blocked-state and edge reads used by `TaskService` and section
+//!   queries.
+//!
+//! # Invariants
+//! - A task is blocked when it is an active task with at least one active,
+//!   unfinished (`done`/`cancelled` excluded) task prerequisite.
+//! - Edges of deleted or converted atoms are kept (same hybrid policy as
+//!   subtasks); reachability checks walk every stored edge so a restore can
+//!   never close a cycle.
+//! - Cycle rejection lives in the service layer; this module only persists.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::AtomId;
+use crate::repo::atom_repo::{
+    query_section_rows, RepoError, RepoResult, SectionAtomRow, SECTION_SELECT_SQL,
+};
+use crate::sync::hlc::stamp_hlc;
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// Section-query predicate (over an unaliased `atoms` row) that keeps
+/// everything except blocked tasks.
+pub(crate) const NOT_BLOCKED_SQL: &str = "(type <> 'task' OR NOT EXISTS (
+    SELECT 1
+    FROM task_dependencies d
+    JOIN atoms p ON p.uuid = d.depends_on_uuid
+    WHERE d.task_uuid = atoms.uuid
+      AND p.is_deleted = 0
+      AND p.type = 'task'
+      AND (p.task_status IS NULL OR p.task_status NOT IN ('done', 'cancelled'))
+))";
+
+/// Adds the edge `task_id -> depends_on_id`.
+///
+/// Returns `false` when the edge already existed.
+pub(crate) fn add_dependency(
+    conn: &Connection,
+    task_id: AtomId,
+    depends_on_id: AtomId,
+) -> RepoResult<bool> {
+    let hlc = stamp_hlc(conn)?.to_string();
+    let inserted = conn.execute(
+        "INSERT OR IGNORE INTO task_dependencies (task_uuid, depends_on_uuid, hlc_timestamp)
+         VALUES (?1, ?2, ?3);",
+        params![task_id.to_string(), depends_on_id.to_string(), hlc],
+    )?;
+    Ok(inserted > 0)
+}
+
+/// Removes the edge `task_id -> depends_on_id`.
+///
+/// Returns `false` when no such edge existed.
+pub(crate) fn remove_dependency(
+    conn: &Connection,
+    task_id: AtomId,
+    depends_on_id: AtomId,
+) -> RepoResult<bool> {
+    let removed = conn.execute(
+        "DELETE FROM task_dependencies WHERE task_uuid = ?1 AND depends_on_uuid = ?2;",
+        params![task_id.to_string(), depends_on_id.to_string()],
+    )?;
+    Ok(removed > 0)
+}
+
+/// Lists active task prerequisites of `task_id`, oldest edge first.
+pub(crate) fn list_prerequisite_rows(
+    conn: &Connection,
+    task_id: AtomId,
+) -> RepoResult<Vec<SectionAtomRow>> {
+    let sql = format!(
+        "{SECTION_SELECT_SQL}
+         WHERE is_deleted = 0
+           AND type = 'task'
+           AND uuid IN (SELECT depends_on_uuid FROM task_dependencies WHERE task_uuid = ?1)
+         ORDER BY
+           (SELECT created_at FROM task_dependencies
+            WHERE task_uuid = ?1 AND depends_on_uuid = atoms.uuid) ASC,
+           uuid ASC"
+    );
+    query_section_rows(conn, &sql, params![task_id.to_string()])
+}
+
+/// Lists active tasks that depend on `task_id`, oldest edge first.
+pub(crate) fn list_dependent_rows(
+    conn: &Connection,
+    task_id: AtomId,
+) -> RepoResult<Vec<SectionAtomRow>> {
+    let sql = format!(
+        "{SECTION_SELECT_SQL}
+         WHERE is_deleted = 0
+           AND type = 'task'
+           AND uuid IN (SELECT task_uuid FROM task_dependencies WHERE depends_on_uuid = ?1)
+         ORDER BY
+           (SELECT created_at FROM task_dependencies
+            WHERE task_uuid = atoms.uuid AND depends_on_uuid = ?1) ASC,
+           uuid ASC"
+    );
+    query_section_rows(conn, &sql, params![task_id.to_string()])
+}
+
+/// Returns whether `from_id` reaches `target_id` by following stored
+/// prerequisite edges (including `from_id == target_id`).
+pub(crate) fn depends_transitively(
+    conn: &Connection,
+    from_id: AtomId,
+    target_id: AtomId,
+) -> RepoResult<bool> {
+    let reachable: bool = conn.query_row(
+        "WITH RECURSIVE reach(uuid) AS (
+            SELECT ?1
+            UNION
+            SELECT d.depends_on_uuid
+            FROM task_dependencies d
+            JOIN reach r ON d.task_uuid = r.uuid
+         )
+         SELECT EXISTS(SELECT 1 FROM reach WHERE uuid = ?2);",
+        params![from_id.to_string(), target_id.to_string()],
+        |row| row.get(0),
+    )?;
+    Ok(reachable)
+}
+
+/// Returns the blocked subset of `atom_ids`.
+pub(crate) fn load_blocked_ids(
+    conn: &Connection,
+    atom_ids: &[AtomId],
+) -> RepoResult<HashSet<AtomId>> {
+    if atom_ids.is_empty() {
+        return Ok(HashSet::new());
+    }
+    let placeholders = vec!["?"; atom_ids.len()].join(", ");
+    let sql = format!(
+        "SELECT uuid
+         FROM atoms
+         WHERE uuid IN ({placeholders})
+           AND is_deleted = 0
+           AND NOT {NOT_BLOCKED_SQL};"
+    );
+    let bind_values: Vec<Value> = atom_ids
+        .iter()
+        .map(|id| Value::Text(id.to_string()))
+        .collect();
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(params_from_iter(bind_values))?;
+    let mut blocked = HashSet::new();
+    while let Some(row) = rows.next()? {
+        let uuid: String = row.get(0)?;
+        blocked.insert(parse_dependency_uuid(&uuid)?);
+    }
+    Ok(blocked)
+}
+
+/// Lists `(task, prerequisite)` edges with both ends inside `atom_ids`.
+pub(crate) fn load_edges_among(
+    conn: &Connection,
+    atom_ids: &[AtomId],
+) -> RepoResult<Vec<(AtomId, AtomId)>> {
+    if atom_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let placeholders = vec!["?"; atom_ids.len()].join(", ");
+    let sql = format!(
+        "SELECT task_uuid, depends_on_uuid
+         FROM task_dependencies
+         WHERE task_uuid IN ({placeholders})
+           AND depends_on_uuid IN ({placeholders})
+         ORDER BY created_at ASC, task_uuid ASC, depends_on_uuid ASC;"
+    );
+    let ids: Vec<Value> = atom_ids
+        .iter()
+        .map(|id| Value::Text(id.to_string()))
+        .collect();
+    let bind_values = ids.iter().chain(ids.iter()).cloned();
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(params_from_iter(bind_values))?;
+    let mut edges = Vec::new();
+    while let Some(row) = rows.next()? {
+        let task: String = row.get(0)?;
+        let depends_on: String = row.get(1)?;
+        edges.push((
+            parse_dependency_uuid(&task)?,
+            parse_dependency_uuid(&depends_on)?,
+        ));
+    }
+    Ok(edges)
+}
+
+fn parse_dependency_uuid(value: &str) -> RepoResult<AtomId> {
+    Uuid::parse_str(value).map_err(|_| {
+        RepoError::InvalidData(format!("invalid uuid value `{value}` in task_dependencies"))
+    })
+}
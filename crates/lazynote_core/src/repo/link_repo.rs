@@ -0,0 +1,248 @@
+//! Wiki-link graph persistence (`atom_links`).
+//!
+//! # Responsibility
+//! - Replace the link set of an atom whenever its content is saved.
+//! - Resolve `[[Title]]` references against workspace note display names.
+//! - Serve outgoing-link, backlink and unresolved-link queries.
+//!
+//! # Invariants
+//! - Link rows are derived data; `atoms.content` stays the source of truth.
+//! - Title links resolve to the oldest active note_ref whose trimmed,
+//!   ASCII-lowercased `display_name` equals the link key.
+//! - Soft-deleted sources are hidden; soft-deleted targets read as unresolved.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::AtomId;
+use crate::model::link::{parse_wiki_links, AtomLink, WikiLinkKind};
+use crate::repo::atom_repo::{RepoError, RepoResult};
+use crate::repo::content_index::reindex_all_atoms;
+use log::info;
+use rusqlite::{params, Connection, Row, Transaction, TransactionBehavior};
+use std::time::Instant;
+use uuid::Uuid;
+
+/// Resolves one title key (bound as `?1`) to an active note atom.
+const TITLE_RESOLUTION_SQL: &str = "SELECT n.atom_uuid
+    FROM workspace_nodes n
+    JOIN atoms a ON a.uuid = n.atom_uuid
+    WHERE n.kind = 'note_ref'
+      AND n.is_deleted = 0
+      AND a.type = 'note'
+      AND a.is_deleted = 0
+      AND lower(trim(n.display_name)) = ?1
+    ORDER BY n.created_at ASC, n.node_uuid ASC
+    LIMIT 1";
+
+/// Link columns plus a read-time active-target check.
+const LINK_SELECT_SQL: &str = "SELECT
+    l.source_uuid AS source_uuid,
+    l.target_text AS target_text,
+    l.label AS label,
+    l.link_kind AS link_kind,
+    CASE
+        WHEN EXISTS(
+            SELECT 1 FROM atoms t
+            WHERE t.uuid = l.target_uuid AND t.is_deleted = 0
+        ) THEN l.target_uuid
+        ELSE NULL
+    END AS target_uuid
+FROM atom_links l
+JOIN atoms s ON s.uuid = l.source_uuid AND s.is_deleted = 0";
+
+/// Repository interface for the wiki-link graph.
+pub trait LinkRepository {
+    /// Lists links written in one atom, in document order.
+    fn list_outgoing_links(&self, source_id: AtomId) -> RepoResult<Vec<AtomLink>>;
+    /// Lists active atoms linking to `target_id`, newest source first.
+    ///
+    /// Self-references are excluded.
+    fn list_backlinks(&self, target_id: AtomId) -> RepoResult<Vec<AtomLink>>;
+    /// Lists links whose target is missing or deleted, grouped by target.
+    fn list_unresolved_links(&self, limit: u32, offset: u32) -> RepoResult<Vec<AtomLink>>;
+    /// Re-parses every atom and rebuilds the link table.
+    ///
+    /// Returns the number of atoms indexed. Used to backfill content saved
+    /// before link indexing existed.
+    fn rebuild_all_links(&self) -> RepoResult<u32>;
+}
+
+/// SQLite-backed link repository.
+pub struct SqliteLinkRepository<'conn> {
+    conn: &'conn Connection,
+}
+
+impl<'conn> SqliteLinkRepository<'conn> {
+    /// Constructs a repository from an existing SQLite connection.
+    ///
+    /// # Errors
+    /// - Returns [`RepoError::MissingRequiredTable`] when the `atom_links`
+    ///   table is absent.
+    pub fn try_new(conn: &'conn Connection) -> RepoResult<Self> {
+        let exists: i64 = conn.query_row(
+            "SELECT EXISTS(
+                SELECT 1
+                FROM sqlite_master
+                WHERE type = 'table' AND name = 'atom_links'
+            );",
+            [],
+            |row| row.get(0),
+        )?;
+        if exists != 1 {
+            return Err(RepoError::MissingRequiredTable("atom_links"));
+        }
+        Ok(Self { conn })
+    }
+
+    fn query_links(&self, sql: &str, params: impl rusqlite::Params) -> RepoResult<Vec<AtomLink>> {
+        let mut stmt = self.conn.prepare(sql)?;
+        let mut rows = stmt.query(params)?;
+        let mut links = Vec::new();
+        while let Some(row) = rows.next()? {
+            links.push(parse_link_row(row)?);
+        }
+        Ok(links)
+    }
+}
+
+impl LinkRepository for SqliteLinkRepository<'_> {
+    fn list_outgoing_links(&self, source_id: AtomId) -> RepoResult<Vec<AtomLink>> {
+        let sql = format!(
+            "{LINK_SELECT_SQL}
+             WHERE l.source_uuid = ?1
+             ORDER BY l.position ASC;"
+        );
+        self.query_links(&sql, [source_id.to_string()])
+    }
+
+    fn list_backlinks(&self, target_id: AtomId) -> RepoResult<Vec<AtomLink>> {
+        let sql = format!(
+            "{LINK_SELECT_SQL}
+             WHERE l.target_uuid = ?1
+               AND l.source_uuid <> ?1
+             ORDER BY s.updated_at DESC, l.source_uuid ASC;"
+        );
+        self.query_links(&sql, [target_id.to_string()])
+    }
+
+    fn list_unresolved_links(&self, limit: u32, offset: u32) -> RepoResult<Vec<AtomLink>> {
+        let sql = format!(
+            "{LINK_SELECT_SQL}
+             WHERE NOT EXISTS(
+                 SELECT 1 FROM atoms t
+                 WHERE t.uuid = l.target_uuid AND t.is_deleted = 0
+             )
+             ORDER BY l.target_key ASC, l.source_uuid ASC
+             LIMIT ?1 OFFSET ?2;"
+        );
+        self.query_links(&sql, params![i64::from(limit), i64::from(offset)])
+    }
+
+    fn rebuild_all_links(&self) -> RepoResult<u32> {
+        let started_at = Instant::now();
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let atom_count = reindex_all_atoms(&tx, sync_atom_links)?;
+        tx.commit()?;
+
+        info!(
+            "event=links_rebuild module=repo status=ok atom_count={} duration_ms={}",
+            atom_count,
+            started_at.elapsed().as_millis()
+        );
+        Ok(atom_count)
+    }
+}
+
+/// Replaces the link set of `source_id` with the references in `content`.
+pub(crate) fn sync_atom_links(
+    conn: &Connection,
+    source_id: AtomId,
+    content: &str,
+) -> rusqlite::Result<()> {
+    let source = source_id.to_string();
+    conn.execute("DELETE FROM atom_links WHERE source_uuid = ?1;", [&source])?;
+
+    for (position, link) in parse_wiki_links(content).into_iter().enumerate() {
+        let kind = match link.kind {
+            WikiLinkKind::Title => "title",
+            WikiLinkKind::Uuid => "uuid",
+        };
+        let target_sql = match link.kind {
+            WikiLinkKind::Title => format!("({TITLE_RESOLUTION_SQL})"),
+            WikiLinkKind::Uuid => "?1".to_string(),
+        };
+        conn.execute(
+            &format!(
+                "INSERT INTO atom_links (
+                    source_uuid,
+                    target_key,
+                    target_text,
+                    label,
+                    link_kind,
+                    target_uuid,
+                    position
+                ) VALUES (?2, ?1, ?3, ?4, ?5, {target_sql}, ?6);"
+            ),
+            params![
+                link.key,
+                source,
+                link.target,
+                link.label,
+                kind,
+                position as i64
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+/// Re-resolves every title link against current workspace display names.
+///
+/// Called after note_ref renames/creates/deletes so `[[Title]]` references
+/// follow the workspace.
+pub(crate) fn reresolve_title_links(conn: &Connection) -> rusqlite::Result<usize> {
+    let resolution = TITLE_RESOLUTION_SQL.replace("?1", "atom_links.target_key");
+    conn.execute(
+        &format!(
+            "UPDATE atom_links
+             SET target_uuid = ({resolution})
+             WHERE link_kind = 'title';"
+        ),
+        [],
+    )
+}
+
+fn parse_link_row(row: &Row<'_>) -> RepoResult<AtomLink> {
+    let source_text: String = row.get("source_uuid")?;
+    let source_id = parse_link_uuid(&source_text, "source_uuid")?;
+    let target_id = match row.get::<_, Option<String>>("target_uuid")? {
+        Some(value) => Some(parse_link_uuid(&value, "target_uuid")?),
+        None => None,
+    };
+    let kind = match row.get::<_, String>("link_kind")?.as_str() {
+        "title" => WikiLinkKind::Title,
+        "uuid" => WikiLinkKind::Uuid,
+        other => {
+            return Err(RepoError::InvalidData(format!(
+                "invalid link kind `{other}` in atom_links.link_kind"
+            )))
+        }
+    };
+
+    Ok(AtomLink {
+        source_id,
+        target: row.get("target_text")?,
+        label: row.get("label")?,
+        kind,
+        target_id,
+    })
+}
+
+fn parse_link_uuid(value: &str, column: &str) -> RepoResult<AtomId> {
+    Uuid::parse_str(value).map_err(|_| {
+        RepoError::InvalidData(format!(
+            "invalid uuid value `{value}` in atom_links.{column}"
+        ))
+    })
+}
@@ -13,5 +13,18 @@
 //! - docs/releases/v0.1/prs/PR-0006-core-crud.md
 
 pub mod atom_repo;
+pub mod attachment_repo;
+pub mod content_index;
+pub mod dependency_repo;
+pub mod link_repo;
 pub mod note_repo;
+pub mod property_repo;
+pub mod recurrence_repo;
+pub mod reminder_repo;
+pub mod resource_link_repo;
+pub mod revision_repo;
+pub mod subtask_repo;
+pub mod time_entry_repo;
+pub mod title_repo;
+pub mod trash_repo;
 pub mod tree_repo;
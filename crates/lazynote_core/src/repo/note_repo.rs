@@ -3,20 +3,46 @@
 //! # Responsibility
 //! - Provide note-only persistence APIs on top of canonical `atoms`.
 //! - Own tag-link replacement logic (`note_set_tags`) with atomic semantics.
+//! - Own tag dictionary edits (rename/merge/delete) and usage counts.
+//! - Mirror inline `#hashtags` from content into `inline` tag links.
+//! - Expose the note title projection and its explicit override.
 //!
 //! # Invariants
 //! - All note queries are constrained to `type='note'` and `is_deleted=0`.
-//! - `note_set_tags` replaces the whole tag set in a single transaction.
+//! - `note_set_tags` replaces the whole `manual` tag set in a single
+//!   transaction; `inline` links are owned by content saves.
+//! - A note's tag list is the union of both sources.
 //! - Tag names are normalized to lowercase before persistence.
+//! - Tags are `/`-separated paths; a tag filter matches the tag itself and
+//!   every descendant (`work` matches `work/client`).
+//! - Tag rename/merge/delete rewrite `atom_tags` in one transaction and stamp
+//!   a fresh HLC on every affected atom; body `#hashtags` of the edited tags
+//!   are rewritten in the same transaction.
+//! - Note writes (content and tag-set changes) stamp a fresh HLC.
+//! - Content replacement snapshots the prior note into `atom_revisions` and
+//!   re-indexes its wiki-links and resource links.
 //!
 //! # See also
 //! - docs/releases/v0.1/prs/PR-0010B-notes-tags-core-ffi.md
 
 use crate::model::atom::{Atom, AtomId, AtomType};
+use crate::model::frontmatter::{
+    parse_frontmatter, rewrite_frontmatter_tags, strip_frontmatter, AtomProperty,
+};
+use crate::model::hashtag::{parse_hashtags, rewrite_hashtags};
+use crate::model::markdown::{
+    analyze_markdown, content_stats, derive_note_title, ContentStats, UNTITLED_NOTE_TITLE,
+};
+use crate::model::tag_expr::{normalize_tag_name, TagExpr};
 use crate::repo::atom_repo::{AtomRepository, RepoError, RepoResult, SqliteAtomRepository};
+use crate::repo::content_index::index_atom_content;
+use crate::repo::property_repo::{PropertyRepository, SqlitePropertyRepository};
+use crate::repo::revision_repo::{capture_revision, CaptureMode};
+use crate::repo::title_repo::{load_note_title, set_title_override, NoteTitle};
+use crate::sync::hlc::stamp_hlc;
 use rusqlite::types::Value;
 use rusqlite::{params, params_from_iter, Connection, Transaction, TransactionBehavior};
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use uuid::Uuid;
 
 const NOTES_DEFAULT_LIMIT: u32 = 10;
@@ -29,6 +55,9 @@ pub struct NoteRecord {
     pub atom_id: AtomId,
     /// Raw markdown source text.
     pub content: String,
+    /// Effective title: override, else first heading/line, else
+    /// `Untitled note`.
+    pub title: String,
     /// Derived plain-text preview (nullable).
     pub preview_text: Option<String>,
     /// Derived first markdown image path (nullable).
@@ -37,13 +66,76 @@ pub struct NoteRecord {
     pub updated_at: i64,
     /// Note tags, normalized to lowercase.
     pub tags: Vec<String>,
+    /// Cached word/character counts and checklist progress.
+    pub stats: ContentStats,
+}
+
+/// Usage counters for one tag path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagUsage {
+    /// Normalized tag path, e.g. `work/client`.
+    pub name: String,
+    /// Live atoms carrying exactly this tag.
+    pub direct_count: u32,
+    /// Live atoms carrying this tag or any descendant.
+    pub total_count: u32,
+}
+
+/// Where an atom-tag link came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagSource {
+    /// Set explicitly through `set_note_tags`.
+    Manual,
+    /// Derived from an inline `#hashtag` in content.
+    Inline,
+}
+
+impl TagSource {
+    /// Returns the storage label (`manual|inline`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Manual => "manual",
+            Self::Inline => "inline",
+        }
+    }
+
+    /// Parses a storage label.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "manual" => Some(Self::Manual),
+            "inline" => Some(Self::Inline),
+            _ => None,
+        }
+    }
+}
+
+/// One tag link of a note together with its origin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteTag {
+    /// Normalized tag path.
+    pub name: String,
+    /// Link origin; a tag may appear once per source.
+    pub source: TagSource,
+}
+
+/// Result of a tag rename/merge/delete.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagEditOutcome {
+    /// Edit committed; carries the number of atoms whose tag set changed.
+    Applied(u32),
+    /// Source tag has no row for itself or any descendant.
+    SourceNotFound,
+    /// Rename target (or one of its renamed descendants) already exists.
+    TargetExists,
 }
 
 /// Query options for note list use-cases.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct NoteListQuery {
-    /// Optional single-tag exact match filter.
+    /// Optional single-tag filter; matches the tag and its descendants.
     pub tag: Option<String>,
+    /// Optional boolean tag expression; combined with `tag` by `AND`.
+    pub tag_expr: Option<TagExpr>,
     /// Maximum rows to return. Defaults to 10 and clamps to 50.
     pub limit: Option<u32>,
     /// Number of rows to skip.
@@ -64,12 +156,34 @@ pub trait NoteRepository {
     ) -> RepoResult<()>;
     /// Gets one note by id.
     fn get_note(&self, atom_id: AtomId) -> RepoResult<Option<NoteRecord>>;
-    /// Lists notes using single-tag filter + pagination.
+    /// Lists notes using single-tag / tag-expression filters + pagination.
     fn list_notes(&self, query: &NoteListQuery) -> RepoResult<Vec<NoteRecord>>;
-    /// Replaces all tags for the given note atom in one transaction.
+    /// Replaces all manual tags for the given note atom in one transaction.
+    ///
+    /// A manual tag is stored even when the content also carries it as an
+    /// inline `#hashtag`, so removing the hashtag later keeps the tag.
     fn set_note_tags(&mut self, atom_id: AtomId, tags: &[String]) -> RepoResult<()>;
+    /// Lists one note's tag links with their sources.
+    fn list_note_tags(&self, atom_id: AtomId) -> RepoResult<Vec<NoteTag>>;
+    /// Lists one note's frontmatter properties in declaration order.
+    fn list_note_properties(&self, atom_id: AtomId) -> RepoResult<Vec<AtomProperty>>;
+    /// Loads one note's title projection.
+    fn get_note_title(&self, atom_id: AtomId) -> RepoResult<NoteTitle>;
+    /// Sets (`Some`) or clears (`None`) one note's title override and
+    /// renames note_refs that follow the title.
+    fn set_note_title(&mut self, atom_id: AtomId, title: Option<&str>) -> RepoResult<NoteTitle>;
+    /// Re-derives inline tags for every atom from stored content.
+    fn rebuild_inline_tags(&mut self) -> RepoResult<u32>;
     /// Returns all known tags sorted by name.
     fn list_tags(&self) -> RepoResult<Vec<String>>;
+    /// Renames `from` and its descendants to `to`, keeping atom links.
+    fn rename_tag(&mut self, from: &str, to: &str) -> RepoResult<TagEditOutcome>;
+    /// Moves every atom link of `sources` (and descendants) onto `target`.
+    fn merge_tags(&mut self, sources: &[String], target: &str) -> RepoResult<TagEditOutcome>;
+    /// Removes `name` and its descendants from every atom.
+    fn delete_tag(&mut self, name: &str) -> RepoResult<TagEditOutcome>;
+    /// Returns usage counters for every tag path, including implicit parents.
+    fn list_tag_usage(&self) -> RepoResult<Vec<TagUsage>>;
 }
 
 /// SQLite-backed notes/tags repository.
@@ -105,22 +219,32 @@ impl NoteRepository for SqliteNoteRepository<'_> {
         preview_text: Option<&str>,
         preview_image: Option<&str>,
     ) -> RepoResult<()> {
+        capture_revision(self.conn, atom_id, CaptureMode::Coalesce)?;
+        let hlc = stamp_hlc(self.conn)?.to_string();
         let changed = self.conn.execute(
             "UPDATE atoms
              SET
                 content = ?2,
                 preview_text = ?3,
                 preview_image = ?4,
+                hlc_timestamp = ?5,
                 updated_at = (strftime('%s', 'now') * 1000)
              WHERE uuid = ?1
                AND type = 'note'
                AND is_deleted = 0;",
-            params![atom_id.to_string(), content, preview_text, preview_image,],
+            params![
+                atom_id.to_string(),
+                content,
+                preview_text,
+                preview_image,
+                hlc
+            ],
         )?;
 
         if changed == 0 {
             return Err(RepoError::NotFound(atom_id));
         }
+        index_atom_content(self.conn, atom_id, content)?;
 
         Ok(())
     }
@@ -133,8 +257,17 @@ impl NoteRepository for SqliteNoteRepository<'_> {
                 content,
                 preview_text,
                 preview_image,
-                updated_at
+                updated_at,
+                s.word_count AS word_count,
+                s.char_count AS char_count,
+                s.checklist_done AS checklist_done,
+                s.checklist_total AS checklist_total,
+                t.atom_uuid AS title_atom_uuid,
+                t.derived_title AS derived_title,
+                t.title_override AS title_override
              FROM atoms
+             LEFT JOIN atom_content_stats s ON s.atom_uuid = atoms.uuid
+             LEFT JOIN atom_titles t ON t.atom_uuid = atoms.uuid
              WHERE uuid = ?1
                AND type = 'note'
                AND is_deleted = 0;",
@@ -145,9 +278,12 @@ impl NoteRepository for SqliteNoteRepository<'_> {
             let uuid_text: String = row.get("uuid")?;
             let parsed_id = parse_uuid(&uuid_text)?;
             let tags = load_tags_for_note(self.conn, &uuid_text)?;
+            let content: String = row.get("content")?;
             return Ok(Some(NoteRecord {
                 atom_id: parsed_id,
-                content: row.get("content")?,
+                stats: read_note_stats(row, &content)?,
+                title: read_note_title(row, &content)?,
+                content,
                 preview_text: row.get("preview_text")?,
                 preview_image: row.get("preview_image")?,
                 updated_at: row.get("updated_at")?,
@@ -165,24 +301,33 @@ impl NoteRepository for SqliteNoteRepository<'_> {
                 content,
                 preview_text,
                 preview_image,
-                updated_at
+                updated_at,
+                s.word_count AS word_count,
+                s.char_count AS char_count,
+                s.checklist_done AS checklist_done,
+                s.checklist_total AS checklist_total,
+                t.atom_uuid AS title_atom_uuid,
+                t.derived_title AS derived_title,
+                t.title_override AS title_override
              FROM atoms
+             LEFT JOIN atom_content_stats s ON s.atom_uuid = atoms.uuid
+             LEFT JOIN atom_titles t ON t.atom_uuid = atoms.uuid
              WHERE type = 'note'
                AND is_deleted = 0",
         );
         let mut bind_values: Vec<Value> = Vec::new();
 
         if let Some(tag) = query.tag.as_ref() {
-            sql.push_str(
-                " AND EXISTS (
-                    SELECT 1
-                    FROM atom_tags at
-                    INNER JOIN tags t ON t.id = at.tag_id
-                    WHERE at.atom_uuid = atoms.uuid
-                      AND t.name = ? COLLATE NOCASE
-                )",
-            );
-            bind_values.push(Value::Text(tag.clone()));
+            sql.push_str(" AND ");
+            sql.push_str(&tag_expr_predicate(
+                &TagExpr::Tag(tag.clone()),
+                "atoms.uuid",
+                &mut bind_values,
+            ));
+        }
+        if let Some(expr) = query.tag_expr.as_ref() {
+            sql.push_str(" AND ");
+            sql.push_str(&tag_expr_predicate(expr, "atoms.uuid", &mut bind_values));
         }
 
         sql.push_str(" ORDER BY updated_at DESC, uuid ASC");
@@ -201,9 +346,12 @@ impl NoteRepository for SqliteNoteRepository<'_> {
             let uuid_text: String = row.get("uuid")?;
             let parsed_id = parse_uuid(&uuid_text)?;
             let tags = load_tags_for_note(self.conn, &uuid_text)?;
+            let content: String = row.get("content")?;
             notes.push(NoteRecord {
                 atom_id: parsed_id,
-                content: row.get("content")?,
+                stats: read_note_stats(row, &content)?,
+                title: read_note_title(row, &content)?,
+                content,
                 preview_text: row.get("preview_text")?,
                 preview_image: row.get("preview_image")?,
                 updated_at: row.get("updated_at")?,
@@ -224,7 +372,7 @@ impl NoteRepository for SqliteNoteRepository<'_> {
         }
 
         tx.execute(
-            "DELETE FROM atom_tags WHERE atom_uuid = ?1;",
+            "DELETE FROM atom_tags WHERE atom_uuid = ?1 AND source = 'manual';",
             [atom_id_text.as_str()],
         )?;
 
@@ -234,29 +382,25 @@ impl NoteRepository for SqliteNoteRepository<'_> {
                 [tag.as_str()],
             )?;
             tx.execute(
-                "INSERT INTO atom_tags (atom_uuid, tag_id)
-                 SELECT ?1, id
-                 FROM tags
-                 WHERE name = ?2 COLLATE NOCASE;",
+                "INSERT INTO atom_tags (atom_uuid, tag_id, source)
+                 SELECT ?1, t.id, 'manual'
+                 FROM tags t
+                 WHERE t.name = ?2 COLLATE NOCASE;",
                 params![atom_id_text.as_str(), tag.as_str()],
             )?;
         }
 
-        tx.execute(
-            // Why: v0.1 filter chips should represent tags that still have at
-            // least one note reference; prune detached dictionary rows here.
-            "DELETE FROM tags
-             WHERE id NOT IN (SELECT DISTINCT tag_id FROM atom_tags);",
-            [],
-        )?;
+        prune_unused_tags(&tx)?;
 
+        let hlc = stamp_hlc(&tx)?.to_string();
         tx.execute(
             "UPDATE atoms
-             SET updated_at = (strftime('%s', 'now') * 1000)
+             SET hlc_timestamp = ?2,
+                 updated_at = (strftime('%s', 'now') * 1000)
              WHERE uuid = ?1
                AND type = 'note'
                AND is_deleted = 0;",
-            [atom_id_text.as_str()],
+            params![atom_id_text.as_str(), hlc],
         )?;
 
         tx.commit()?;
@@ -275,6 +419,206 @@ impl NoteRepository for SqliteNoteRepository<'_> {
         }
         Ok(tags)
     }
+
+    fn list_note_properties(&self, atom_id: AtomId) -> RepoResult<Vec<AtomProperty>> {
+        if self.get_note(atom_id)?.is_none() {
+            return Err(RepoError::NotFound(atom_id));
+        }
+        SqlitePropertyRepository::try_new(self.conn)?.list_properties(atom_id)
+    }
+
+    fn get_note_title(&self, atom_id: AtomId) -> RepoResult<NoteTitle> {
+        if self.get_note(atom_id)?.is_none() {
+            return Err(RepoError::NotFound(atom_id));
+        }
+        load_note_title(self.conn, atom_id)?.ok_or(RepoError::NotFound(atom_id))
+    }
+
+    fn set_note_title(&mut self, atom_id: AtomId, title: Option<&str>) -> RepoResult<NoteTitle> {
+        let atom_id_text = atom_id.to_string();
+        let tx = self
+            .conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)?;
+        if !note_exists_in_tx(&tx, atom_id_text.as_str())? {
+            return Err(RepoError::NotFound(atom_id));
+        }
+
+        let hlc = stamp_hlc(&tx)?.to_string();
+        tx.execute(
+            "UPDATE atoms
+             SET hlc_timestamp = ?2,
+                 updated_at = (strftime('%s', 'now') * 1000)
+             WHERE uuid = ?1;",
+            params![atom_id_text.as_str(), hlc],
+        )?;
+        let updated =
+            set_title_override(&tx, atom_id, title, &hlc)?.ok_or(RepoError::NotFound(atom_id))?;
+
+        tx.commit()?;
+        Ok(updated)
+    }
+
+    fn list_note_tags(&self, atom_id: AtomId) -> RepoResult<Vec<NoteTag>> {
+        if self.get_note(atom_id)?.is_none() {
+            return Err(RepoError::NotFound(atom_id));
+        }
+        let mut stmt = self.conn.prepare(
+            "SELECT t.name, at.source
+             FROM atom_tags at
+             INNER JOIN tags t ON t.id = at.tag_id
+             WHERE at.atom_uuid = ?1
+             ORDER BY t.name COLLATE NOCASE ASC, at.source ASC;",
+        )?;
+        let mut rows = stmt.query([atom_id.to_string()])?;
+        let mut tags = Vec::new();
+        while let Some(row) = rows.next()? {
+            let name: String = row.get(0)?;
+            let source: String = row.get(1)?;
+            let source = TagSource::parse(&source).ok_or_else(|| {
+                RepoError::InvalidData(format!("invalid atom_tags.source value `{source}`"))
+            })?;
+            tags.push(NoteTag {
+                name: name.to_lowercase(),
+                source,
+            });
+        }
+        Ok(tags)
+    }
+
+    fn rebuild_inline_tags(&mut self) -> RepoResult<u32> {
+        let tx = self
+            .conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let sources: Vec<(String, String)> = {
+            let mut stmt = tx.prepare("SELECT uuid, content FROM atoms;")?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            rows.collect::<Result<_, _>>()?
+        };
+        for (uuid, content) in &sources {
+            sync_inline_tags(&tx, parse_uuid(uuid)?, content)?;
+        }
+        tx.commit()?;
+        Ok(u32::try_from(sources.len()).unwrap_or(u32::MAX))
+    }
+
+    fn rename_tag(&mut self, from: &str, to: &str) -> RepoResult<TagEditOutcome> {
+        let tx = self
+            .conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let rows = load_tag_rows(&tx)?;
+        let mut remap = Vec::new();
+        for (tag_id, name) in &rows {
+            if let Some(suffix) = tag_path_suffix(name, from) {
+                remap.push((*tag_id, Some(join_tag_path(to, suffix))));
+            }
+        }
+        if remap.is_empty() {
+            return Ok(TagEditOutcome::SourceNotFound);
+        }
+        if from == to {
+            return Ok(TagEditOutcome::Applied(0));
+        }
+        let moved: BTreeSet<i64> = remap.iter().map(|(tag_id, _)| *tag_id).collect();
+        let collides = rows.iter().any(|(tag_id, name)| {
+            !moved.contains(tag_id)
+                && remap
+                    .iter()
+                    .any(|(_, target)| target.as_deref() == Some(name.as_str()))
+        });
+        if collides {
+            return Ok(TagEditOutcome::TargetExists);
+        }
+
+        let affected = remap_atom_tags(&tx, &remap)?;
+        tx.commit()?;
+        Ok(TagEditOutcome::Applied(affected))
+    }
+
+    fn merge_tags(&mut self, sources: &[String], target: &str) -> RepoResult<TagEditOutcome> {
+        let tx = self
+            .conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let rows = load_tag_rows(&tx)?;
+        let mut remap = Vec::new();
+        for (tag_id, name) in &rows {
+            // Why: with nested sources (`a`, `a/b`) the most specific one
+            // decides where a row lands, so `a/b` maps onto `target` itself.
+            let best = sources
+                .iter()
+                .filter(|source| source.as_str() != target)
+                .filter_map(|source| {
+                    tag_path_suffix(name, source).map(|suffix| (source.len(), suffix))
+                })
+                .max_by_key(|(len, _)| *len);
+            if let Some((_, suffix)) = best {
+                remap.push((*tag_id, Some(join_tag_path(target, suffix))));
+            }
+        }
+        if remap.is_empty() {
+            return Ok(TagEditOutcome::SourceNotFound);
+        }
+
+        let affected = remap_atom_tags(&tx, &remap)?;
+        tx.commit()?;
+        Ok(TagEditOutcome::Applied(affected))
+    }
+
+    fn delete_tag(&mut self, name: &str) -> RepoResult<TagEditOutcome> {
+        let tx = self
+            .conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let remap: Vec<(i64, Option<String>)> = load_tag_rows(&tx)?
+            .into_iter()
+            .filter(|(_, row_name)| tag_path_suffix(row_name, name).is_some())
+            .map(|(tag_id, _)| (tag_id, None))
+            .collect();
+        if remap.is_empty() {
+            return Ok(TagEditOutcome::SourceNotFound);
+        }
+
+        let affected = remap_atom_tags(&tx, &remap)?;
+        tx.commit()?;
+        Ok(TagEditOutcome::Applied(affected))
+    }
+
+    fn list_tag_usage(&self) -> RepoResult<Vec<TagUsage>> {
+        let mut usage: BTreeMap<String, (BTreeSet<String>, BTreeSet<String>)> = BTreeMap::new();
+        for (_, name) in load_tag_rows(self.conn)? {
+            usage.entry(name.to_lowercase()).or_default();
+        }
+
+        let mut stmt = self.conn.prepare(
+            "SELECT at.atom_uuid, t.name
+             FROM atom_tags at
+             INNER JOIN tags t ON t.id = at.tag_id
+             INNER JOIN atoms a ON a.uuid = at.atom_uuid
+             WHERE a.is_deleted = 0;",
+        )?;
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            let atom_uuid: String = row.get(0)?;
+            let name: String = row.get::<_, String>(1)?.to_lowercase();
+            for (index, _) in name.match_indices('/') {
+                usage
+                    .entry(name[..index].to_string())
+                    .or_default()
+                    .1
+                    .insert(atom_uuid.clone());
+            }
+            let (direct, total) = usage.entry(name).or_default();
+            direct.insert(atom_uuid.clone());
+            total.insert(atom_uuid);
+        }
+
+        Ok(usage
+            .into_iter()
+            .map(|(name, (direct, total))| TagUsage {
+                name,
+                direct_count: u32::try_from(direct.len()).unwrap_or(u32::MAX),
+                total_count: u32::try_from(total.len()).unwrap_or(u32::MAX),
+            })
+            .collect())
+    }
 }
 
 /// Normalizes list limit according to notes contract.
@@ -288,13 +632,321 @@ pub fn normalize_note_limit(limit: Option<u32>) -> u32 {
 }
 
 /// Normalizes one tag value according to notes contract.
+///
+/// Tags are `/`-separated paths: each segment is trimmed and lowercased, and
+/// empty segments are dropped (`" Work / Client/ "` -> `work/client`).
 pub fn normalize_tag(tag: &str) -> Option<String> {
-    let trimmed = tag.trim();
-    if trimmed.is_empty() {
-        None
+    normalize_tag_name(tag)
+}
+
+/// Compiles `expr` into a SQL boolean over `atom_tags` for the atom whose
+/// uuid column is `atom_uuid_sql`; bind values are appended in SQL order.
+///
+/// `OR` groups of plain tags collapse into one `EXISTS ... IN (...)` probe,
+/// and empty groups compile to constants (`And([])` = true, `Or([])` = false).
+pub(crate) fn tag_expr_predicate(
+    expr: &TagExpr,
+    atom_uuid_sql: &str,
+    bind_values: &mut Vec<Value>,
+) -> String {
+    match expr {
+        TagExpr::Tag(name) => {
+            tag_exists_sql(std::slice::from_ref(name), atom_uuid_sql, bind_values)
+        }
+        TagExpr::Not(inner) => format!(
+            "NOT ({})",
+            tag_expr_predicate(inner, atom_uuid_sql, bind_values)
+        ),
+        TagExpr::And(operands) if operands.is_empty() => "1".to_string(),
+        TagExpr::Or(operands) if operands.is_empty() => "0".to_string(),
+        TagExpr::And(operands) => {
+            let parts: Vec<String> = operands
+                .iter()
+                .map(|operand| tag_expr_predicate(operand, atom_uuid_sql, bind_values))
+                .collect();
+            format!("({})", parts.join(" AND "))
+        }
+        TagExpr::Or(operands) => {
+            let (leaves, others): (Vec<&TagExpr>, Vec<&TagExpr>) = operands
+                .iter()
+                .partition(|operand| matches!(operand, TagExpr::Tag(_)));
+            let names: Vec<String> = leaves
+                .iter()
+                .filter_map(|leaf| match leaf {
+                    TagExpr::Tag(name) => Some(name.clone()),
+                    _ => None,
+                })
+                .collect();
+            let mut parts = Vec::new();
+            if !names.is_empty() {
+                parts.push(tag_exists_sql(&names, atom_uuid_sql, bind_values));
+            }
+            for operand in others {
+                parts.push(tag_expr_predicate(operand, atom_uuid_sql, bind_values));
+            }
+            format!("({})", parts.join(" OR "))
+        }
+    }
+}
+
+fn tag_exists_sql(names: &[String], atom_uuid_sql: &str, bind_values: &mut Vec<Value>) -> String {
+    let placeholders = vec!["?"; names.len()].join(", ");
+    let descendant_probes = vec!["expr_t.name LIKE ? ESCAPE '\\'"; names.len()].join(" OR ");
+    bind_values.extend(names.iter().map(|name| Value::Text(name.clone())));
+    bind_values.extend(
+        names
+            .iter()
+            .map(|name| Value::Text(format!("{}/%", escape_like(name)))),
+    );
+    format!(
+        "EXISTS (
+            SELECT 1
+            FROM atom_tags expr_at
+            INNER JOIN tags expr_t ON expr_t.id = expr_at.tag_id
+            WHERE expr_at.atom_uuid = {atom_uuid_sql}
+              AND (expr_t.name COLLATE NOCASE IN ({placeholders}) OR {descendant_probes})
+        )"
+    )
+}
+
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Returns the part of `name` below `ancestor` (`""` for the tag itself), or
+/// `None` when `name` is outside the `ancestor` subtree.
+fn tag_path_suffix<'a>(name: &'a str, ancestor: &str) -> Option<&'a str> {
+    if name.eq_ignore_ascii_case(ancestor) {
+        return Some("");
+    }
+    let rest = name.get(ancestor.len()..)?;
+    (name[..ancestor.len()].eq_ignore_ascii_case(ancestor) && rest.starts_with('/'))
+        .then(|| &rest[1..])
+}
+
+fn join_tag_path(base: &str, suffix: &str) -> String {
+    if suffix.is_empty() {
+        base.to_string()
+    } else {
+        format!("{base}/{suffix}")
+    }
+}
+
+/// Replaces the `inline` tag links of `atom_id` with the hashtags found in
+/// `content`; `manual` links are left untouched.
+pub(crate) fn sync_inline_tags(
+    conn: &Connection,
+    atom_id: AtomId,
+    content: &str,
+) -> rusqlite::Result<()> {
+    let uuid = atom_id.to_string();
+    let removed = conn.execute(
+        "DELETE FROM atom_tags WHERE atom_uuid = ?1 AND source = 'inline';",
+        [&uuid],
+    )?;
+    // Why: frontmatter `tags:`/`aliases:` are content-derived like hashtags,
+    // so they share the `inline` source and are replaced on every save.
+    let mut tags = parse_hashtags(strip_frontmatter(content));
+    for tag in parse_frontmatter(content)
+        .map(|fm| fm.tags)
+        .unwrap_or_default()
+    {
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    for tag in tags {
+        conn.execute(
+            "INSERT OR IGNORE INTO tags (name) VALUES (?1);",
+            [tag.as_str()],
+        )?;
+        conn.execute(
+            "INSERT OR IGNORE INTO atom_tags (atom_uuid, tag_id, source)
+             SELECT ?1, id, 'inline'
+             FROM tags
+             WHERE name = ?2 COLLATE NOCASE;",
+            params![uuid, tag],
+        )?;
+    }
+    if removed > 0 {
+        prune_unused_tags(conn)?;
+    }
+    Ok(())
+}
+
+/// Drops `tags` rows no atom links to anymore.
+pub(crate) fn prune_unused_tags(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute(
+        // Why: v0.1 filter chips should represent tags that still have at
+        // least one note reference; prune detached dictionary rows here.
+        "DELETE FROM tags
+         WHERE id NOT IN (SELECT DISTINCT tag_id FROM atom_tags);",
+        [],
+    )?;
+    Ok(())
+}
+
+fn load_tag_rows(conn: &Connection) -> RepoResult<Vec<(i64, String)>> {
+    let mut stmt = conn.prepare("SELECT id, name FROM tags;")?;
+    let mut rows = stmt.query([])?;
+    let mut tags = Vec::new();
+    while let Some(row) = rows.next()? {
+        tags.push((row.get(0)?, row.get(1)?));
+    }
+    Ok(tags)
+}
+
+/// Re-points every `atom_tags` link of the remapped tag ids onto the new
+/// names (`None` drops the link), prunes orphaned tags and stamps the
+/// affected live atoms. Returns the number of distinct atoms touched.
+///
+/// Links are collected before any write so chained mappings (a source row
+/// whose new name is another source's old name) cannot cascade.
+///
+/// `inline` links follow their content: the `#hashtags` and frontmatter
+/// `tags:` are rewritten in place and the links re-derived, so the next save
+/// cannot bring the old name back. See [`rewrite_inline_tags`].
+fn remap_atom_tags(tx: &Transaction<'_>, remap: &[(i64, Option<String>)]) -> RepoResult<u32> {
+    let mut renamed = HashMap::new();
+    let mut links = Vec::new();
+    let mut atoms = BTreeSet::new();
+    let mut inline_atoms = BTreeSet::new();
+    {
+        let mut name_stmt = tx.prepare("SELECT name FROM tags WHERE id = ?1;")?;
+        let mut stmt = tx.prepare("SELECT atom_uuid, source FROM atom_tags WHERE tag_id = ?1;")?;
+        for (tag_id, target) in remap {
+            let name: String = name_stmt.query_row([tag_id], |row| row.get(0))?;
+            renamed.insert(name.to_lowercase(), target.clone());
+            let mut rows = stmt.query([tag_id])?;
+            while let Some(row) = rows.next()? {
+                let atom_uuid: String = row.get(0)?;
+                let source: String = row.get(1)?;
+                atoms.insert(atom_uuid.clone());
+                if source == TagSource::Inline.as_str() {
+                    inline_atoms.insert(atom_uuid);
+                } else if let Some(target) = target {
+                    links.push((atom_uuid, source, target.clone()));
+                }
+            }
+        }
+    }
+
+    for (tag_id, _) in remap {
+        tx.execute("DELETE FROM atom_tags WHERE tag_id = ?1;", [tag_id])?;
+    }
+    for (_, target) in remap {
+        if let Some(target) = target {
+            tx.execute(
+                "INSERT OR IGNORE INTO tags (name) VALUES (?1);",
+                [target.as_str()],
+            )?;
+        }
+    }
+    for (atom_uuid, source, target) in &links {
+        tx.execute(
+            "INSERT OR IGNORE INTO atom_tags (atom_uuid, tag_id, source)
+             SELECT ?1, id, ?3
+             FROM tags
+             WHERE name = ?2 COLLATE NOCASE;",
+            params![atom_uuid, target, source],
+        )?;
+    }
+    for atom_uuid in &inline_atoms {
+        rewrite_inline_tags(tx, parse_uuid(atom_uuid)?, &renamed)?;
+    }
+
+    prune_unused_tags(tx)?;
+
+    for atom_uuid in &atoms {
+        let hlc = stamp_hlc(tx)?.to_string();
+        tx.execute(
+            "UPDATE atoms
+             SET hlc_timestamp = ?2,
+                 updated_at = (strftime('%s', 'now') * 1000)
+             WHERE uuid = ?1
+               AND is_deleted = 0;",
+            params![atom_uuid, hlc],
+        )?;
+    }
+
+    Ok(u32::try_from(atoms.len()).unwrap_or(u32::MAX))
+}
+
+/// Rewrites the body `#hashtags` and frontmatter `tags:`/`aliases:` of one
+/// atom per `renamed` (old name -> new name, `None` = deleted) and
+/// re-derives its content indexes.
+///
+/// A renamed marker becomes `#new`; a deleted one loses its `#` and stays
+/// as plain text. When the new name cannot be written as a hashtag (for
+/// example it contains a space) the marker loses its `#` and the tag is
+/// kept as a `manual` link instead. Frontmatter entries are renamed or
+/// removed; an alias renamed outside `alias/` also becomes a `manual` link.
+fn rewrite_inline_tags(
+    tx: &Transaction<'_>,
+    atom_id: AtomId,
+    renamed: &HashMap<String, Option<String>>,
+) -> RepoResult<()> {
+    let uuid = atom_id.to_string();
+    let content: String = tx.query_row(
+        "SELECT content FROM atoms WHERE uuid = ?1;",
+        [&uuid],
+        |row| row.get(0),
+    )?;
+    let (source, detached) = rewrite_frontmatter_tags(&content, |tag| renamed.get(tag).cloned())
+        .unwrap_or_else(|| (content.clone(), Vec::new()));
+    let body = strip_frontmatter(&source);
+    let frontmatter = &source[..source.len() - body.len()];
+    let mut manual: BTreeSet<String> = detached.into_iter().collect();
+    let body = rewrite_hashtags(body, |marker, tag| {
+        let target = renamed.get(tag)?;
+        let plain = marker[1..].to_string();
+        let Some(target) = target else {
+            return Some(plain);
+        };
+        let marker = format!("#{target}");
+        if parse_hashtags(&marker) == [target.as_str()] {
+            Some(marker)
+        } else {
+            manual.insert(target.clone());
+            Some(plain)
+        }
+    });
+    let rewritten = format!("{frontmatter}{body}");
+    // Why: link before re-indexing, whose prune would drop the bare tag row.
+    for target in &manual {
+        tx.execute(
+            "INSERT OR IGNORE INTO atom_tags (atom_uuid, tag_id, source)
+             SELECT ?1, id, 'manual'
+             FROM tags
+             WHERE name = ?2 COLLATE NOCASE;",
+            params![uuid, target],
+        )?;
+    }
+
+    if rewritten == content {
+        sync_inline_tags(tx, atom_id, &content)?;
     } else {
-        Some(trimmed.to_lowercase())
+        capture_revision(tx, atom_id, CaptureMode::Always)?;
+        let analysis = analyze_markdown(&rewritten);
+        tx.execute(
+            "UPDATE atoms
+             SET content = ?2,
+                 preview_text = ?3,
+                 preview_image = ?4
+             WHERE uuid = ?1;",
+            params![
+                uuid,
+                rewritten,
+                analysis.preview_text,
+                analysis.preview_image
+            ],
+        )?;
+        index_atom_content(tx, atom_id, &rewritten)?;
     }
+    Ok(())
 }
 
 /// Normalizes and deduplicates tag values.
@@ -308,6 +960,36 @@ pub fn normalize_tags(tags: &[String]) -> Vec<String> {
     unique.into_iter().collect()
 }
 
+/// Reads cached stats from a `LEFT JOIN atom_content_stats` row, deriving
+/// them from content for atoms saved before the cache existed.
+fn read_note_stats(row: &rusqlite::Row<'_>, content: &str) -> RepoResult<ContentStats> {
+    let word_count: Option<u32> = row.get("word_count")?;
+    let Some(word_count) = word_count else {
+        return Ok(content_stats(content));
+    };
+    Ok(ContentStats {
+        word_count,
+        char_count: row.get("char_count")?,
+        checklist_done: row.get("checklist_done")?,
+        checklist_total: row.get("checklist_total")?,
+    })
+}
+
+/// Reads the effective title from a `LEFT JOIN atom_titles` row, deriving
+/// it from content for atoms saved before the projection existed.
+fn read_note_title(row: &rusqlite::Row<'_>, content: &str) -> RepoResult<String> {
+    let indexed: Option<String> = row.get("title_atom_uuid")?;
+    let title_override: Option<String> = row.get("title_override")?;
+    let derived_title: Option<String> = if indexed.is_some() {
+        row.get("derived_title")?
+    } else {
+        derive_note_title(content)
+    };
+    Ok(title_override
+        .or(derived_title)
+        .unwrap_or_else(|| UNTITLED_NOTE_TITLE.to_string()))
+}
+
 fn parse_uuid(value: &str) -> RepoResult<AtomId> {
     Uuid::parse_str(value)
         .map_err(|_| RepoError::InvalidData(format!("invalid uuid value `{value}` in atoms.uuid")))
@@ -315,7 +997,7 @@ fn parse_uuid(value: &str) -> RepoResult<AtomId> {
 
 fn load_tags_for_note(conn: &Connection, atom_uuid: &str) -> RepoResult<Vec<String>> {
     let mut stmt = conn.prepare(
-        "SELECT t.name
+        "SELECT DISTINCT t.name
          FROM atom_tags at
          INNER JOIN tags t ON t.id = at.tag_id
          WHERE at.atom_uuid = ?1
@@ -345,7 +1027,7 @@ pub fn load_tags_for_atoms(
 
     let placeholders: Vec<&str> = atom_uuids.iter().map(|_| "?").collect();
     let sql = format!(
-        "SELECT at.atom_uuid, t.name
+        "SELECT DISTINCT at.atom_uuid, t.name
          FROM atom_tags at
          INNER JOIN tags t ON t.id = at.tag_id
          WHERE at.atom_uuid IN ({})
@@ -398,7 +1080,7 @@ fn ensure_note_connection_ready(conn: &Connection) -> RepoResult<()> {
         }
     }
 
-    for column in ["atom_uuid", "tag_id"] {
+    for column in ["atom_uuid", "tag_id", "source"] {
         if !table_has_column(conn, "atom_tags", column)? {
             return Err(RepoError::MissingRequiredColumn {
                 table: "atom_tags",
@@ -0,0 +1,184 @@
+//! Frontmatter property index persistence (`atom_properties`).
+//!
+//! # Responsibility
+//! - Refresh the typed property rows of an atom on content writes.
+//! - Load an atom's properties back in declaration order.
+//!
+//! # Invariants
+//! - Property rows are derived data; `atoms.content` stays the source of
+//!   truth.
+//! - A `list` value is stored as one row per element (`item_index`), so
+//!   search can match any element with a plain key/value predicate.
+//! - Empty lists store no rows and read back as absent.
+//!
+//! # See also
+//! - crates/lazynote_core/src/model/frontmatter.rs
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::AtomId;
+use crate::model::frontmatter::{date_epoch_ms, parse_frontmatter, AtomProperty, PropertyValue};
+use crate::repo::atom_repo::{RepoError, RepoResult};
+use log::info;
+use rusqlite::{params, Connection, Row, Transaction, TransactionBehavior};
+use std::time::Instant;
+use uuid::Uuid;
+
+/// Repository interface for frontmatter properties.
+pub trait PropertyRepository {
+    /// Lists the properties of one atom in frontmatter declaration order.
+    fn list_properties(&self, atom_id: AtomId) -> RepoResult<Vec<AtomProperty>>;
+    /// Re-parses every atom and rebuilds the property index.
+    ///
+    /// Returns the number of atoms indexed.
+    fn rebuild_all_properties(&self) -> RepoResult<u32>;
+}
+
+/// SQLite-backed property repository.
+pub struct SqlitePropertyRepository<'conn> {
+    conn: &'conn Connection,
+}
+
+impl<'conn> SqlitePropertyRepository<'conn> {
+    /// Constructs a repository from an existing SQLite connection.
+    ///
+    /// # Errors
+    /// - Returns [`RepoError::MissingRequiredTable`] when the
+    ///   `atom_properties` table is absent.
+    pub fn try_new(conn: &'conn Connection) -> RepoResult<Self> {
+        let exists: i64 = conn.query_row(
+            "SELECT EXISTS(
+                SELECT 1
+                FROM sqlite_master
+                WHERE type = 'table' AND name = 'atom_properties'
+            );",
+            [],
+            |row| row.get(0),
+        )?;
+        if exists != 1 {
+            return Err(RepoError::MissingRequiredTable("atom_properties"));
+        }
+        Ok(Self { conn })
+    }
+}
+
+impl PropertyRepository for SqlitePropertyRepository<'_> {
+    fn list_properties(&self, atom_id: AtomId) -> RepoResult<Vec<AtomProperty>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT key, value_type, value_text, value_num
+             FROM atom_properties
+             WHERE atom_uuid = ?1
+             ORDER BY ordinal ASC, item_index ASC;",
+        )?;
+        let mut rows = stmt.query([atom_id.to_string()])?;
+        let mut properties: Vec<AtomProperty> = Vec::new();
+        while let Some(row) = rows.next()? {
+            let (key, value) = parse_property_row(row)?;
+            match (properties.last_mut(), value) {
+                (
+                    Some(AtomProperty {
+                        key: last_key,
+                        value: PropertyValue::List(items),
+                    }),
+                    PropertyValue::List(mut next),
+                ) if *last_key == key => items.append(&mut next),
+                (_, value) => properties.push(AtomProperty { key, value }),
+            }
+        }
+        Ok(properties)
+    }
+
+    fn rebuild_all_properties(&self) -> RepoResult<u32> {
+        let started_at = Instant::now();
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let sources: Vec<(String, String)> = {
+            let mut stmt = tx.prepare("SELECT uuid, content FROM atoms;")?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            rows.collect::<Result<_, _>>()?
+        };
+        for (uuid, content) in &sources {
+            let atom_id = Uuid::parse_str(uuid)
+                .map_err(|_| RepoError::InvalidData(format!("invalid atom uuid `{uuid}`")))?;
+            sync_atom_properties(&tx, atom_id, content)?;
+        }
+        tx.commit()?;
+
+        info!(
+            "event=properties_rebuild module=repo status=ok atom_count={} duration_ms={}",
+            sources.len(),
+            started_at.elapsed().as_millis()
+        );
+        Ok(sources.len() as u32)
+    }
+}
+
+/// Replaces the property rows of `atom_id` with the frontmatter in `content`.
+pub(crate) fn sync_atom_properties(
+    conn: &Connection,
+    atom_id: AtomId,
+    content: &str,
+) -> rusqlite::Result<()> {
+    let uuid = atom_id.to_string();
+    conn.execute("DELETE FROM atom_properties WHERE atom_uuid = ?1;", [&uuid])?;
+    let Some(frontmatter) = parse_frontmatter(content) else {
+        return Ok(());
+    };
+    for (ordinal, property) in frontmatter.properties.iter().enumerate() {
+        let rows: Vec<(String, Option<f64>)> = match &property.value {
+            PropertyValue::String(text) => vec![(text.clone(), None)],
+            PropertyValue::Number(number) => vec![(number.to_string(), Some(*number))],
+            PropertyValue::Date(text) => vec![(
+                text.clone(),
+                date_epoch_ms(text).map(|epoch_ms| epoch_ms as f64),
+            )],
+            PropertyValue::Bool(flag) => {
+                vec![(flag.to_string(), Some(if *flag { 1.0 } else { 0.0 }))]
+            }
+            PropertyValue::List(items) => items.iter().map(|item| (item.clone(), None)).collect(),
+        };
+        for (item_index, (value_text, value_num)) in rows.iter().enumerate() {
+            conn.execute(
+                "INSERT INTO atom_properties (
+                    atom_uuid,
+                    key,
+                    item_index,
+                    ordinal,
+                    value_type,
+                    value_text,
+                    value_num
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7);",
+                params![
+                    uuid,
+                    property.key,
+                    item_index as i64,
+                    ordinal as i64,
+                    property.value.type_name(),
+                    value_text,
+                    value_num
+                ],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn parse_property_row(row: &Row<'_>) -> RepoResult<(String, PropertyValue)> {
+    let key: String = row.get("key")?;
+    let value_type: String = row.get("value_type")?;
+    let value_text: String = row.get("value_text")?;
+    let value_num: Option<f64> = row.get("value_num")?;
+    let value = match value_type.as_str() {
+        "string" => PropertyValue::String(value_text),
+        "number" => PropertyValue::Number(value_num.ok_or_else(|| {
+            RepoError::InvalidData(format!("number property `{key}` has no value"))
+        })?),
+        "date" => PropertyValue::Date(value_text),
+        "bool" => PropertyValue::Bool(value_text == "true"),
+        "list" => PropertyValue::List(vec![value_text]),
+        other => {
+            return Err(RepoError::InvalidData(format!(
+                "invalid property value_type `{other}`"
+            )))
+        }
+    };
+    Ok((key, value))
+}
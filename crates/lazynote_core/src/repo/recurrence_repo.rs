@@ -0,0 +1,246 @@
+//! Recurrence exception persistence for recurring atoms.
+//!
+//! # Responsibility
+//! - Persist EXDATE-style skipped occurrences and detached overrides.
+//! - Apply multi-row series edits (detach, split) atomically.
+//!
+//! # Invariants
+//! - Exceptions are keyed by `(series_uuid, original_start_at)`.
+//! - Detached overrides and split-off series inherit the series tags and
+//!   start/due-relative reminders.
+//! - Series rule/time computation stays in the service layer; this module
+//!   only persists already-validated atoms.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::{Atom, AtomId};
+use crate::repo::atom_repo::{AtomRepository, RepoError, RepoResult, SqliteAtomRepository};
+use crate::repo::reminder_repo::copy_relative_reminders;
+use log::{error, info};
+use rusqlite::{params, Connection, Transaction, TransactionBehavior};
+use std::time::Instant;
+use uuid::Uuid;
+
+/// One persisted exception of a recurring series.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceException {
+    /// Recurring series atom ID.
+    pub series_id: AtomId,
+    /// Occurrence start the exception replaces, epoch ms.
+    pub original_start_at: i64,
+    /// Standalone atom replacing the occurrence; `None` when skipped.
+    pub override_id: Option<AtomId>,
+}
+
+/// Repository interface for recurrence exceptions and series edits.
+pub trait RecurrenceRepository {
+    /// Lists exceptions of one series ordered by `original_start_at`.
+    fn list_exceptions(&self, series_id: AtomId) -> RepoResult<Vec<RecurrenceException>>;
+
+    /// Records one occurrence as skipped (EXDATE).
+    ///
+    /// Idempotent for occurrences that are already skipped.
+    fn skip_occurrence(&self, series_id: AtomId, original_start_at: i64) -> RepoResult<()>;
+
+    /// Inserts `detached` as a standalone atom replacing one occurrence.
+    ///
+    /// Returns the detached atom ID.
+    fn detach_occurrence(
+        &self,
+        series_id: AtomId,
+        original_start_at: i64,
+        detached: &Atom,
+    ) -> RepoResult<AtomId>;
+
+    /// Ends `truncated` before `split_at` and inserts `following` as new series.
+    ///
+    /// Exceptions at or after `split_at` move to the new series.
+    /// Returns the new series ID.
+    fn split_series(&self, truncated: &Atom, split_at: i64, following: &Atom)
+        -> RepoResult<AtomId>;
+}
+
+/// SQLite-backed recurrence repository.
+pub struct SqliteRecurrenceRepository<'conn> {
+    conn: &'conn Connection,
+}
+
+impl<'conn> SqliteRecurrenceRepository<'conn> {
+    /// Constructs a repository from an existing SQLite connection.
+    ///
+    /// # Errors
+    /// - Returns [`RepoError::MissingRequiredTable`] when the
+    ///   `recurrence_exceptions` table is absent.
+    pub fn try_new(conn: &'conn Connection) -> RepoResult<Self> {
+        let exists: i64 = conn.query_row(
+            "SELECT EXISTS(
+                SELECT 1
+                FROM sqlite_master
+                WHERE type = 'table' AND name = 'recurrence_exceptions'
+            );",
+            [],
+            |row| row.get(0),
+        )?;
+        if exists != 1 {
+            return Err(RepoError::MissingRequiredTable("recurrence_exceptions"));
+        }
+        Ok(Self { conn })
+    }
+}
+
+impl RecurrenceRepository for SqliteRecurrenceRepository<'_> {
+    fn list_exceptions(&self, series_id: AtomId) -> RepoResult<Vec<RecurrenceException>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT original_start_at, override_uuid
+             FROM recurrence_exceptions
+             WHERE series_uuid = ?1
+             ORDER BY original_start_at ASC;",
+        )?;
+        let mut rows = stmt.query([series_id.to_string()])?;
+        let mut result = Vec::new();
+        while let Some(row) = rows.next()? {
+            let override_id = match row.get::<_, Option<String>>(1)? {
+                Some(value) => Some(Uuid::parse_str(&value).map_err(|_| {
+                    RepoError::InvalidData(format!(
+                        "invalid uuid value `{value}` in recurrence_exceptions.override_uuid"
+                    ))
+                })?),
+                None => None,
+            };
+            result.push(RecurrenceException {
+                series_id,
+                original_start_at: row.get(0)?,
+                override_id,
+            });
+        }
+        Ok(result)
+    }
+
+    fn skip_occurrence(&self, series_id: AtomId, original_start_at: i64) -> RepoResult<()> {
+        let started_at = Instant::now();
+        if let Err(err) = self.conn.execute(
+            "INSERT INTO recurrence_exceptions (series_uuid, original_start_at, override_uuid)
+             VALUES (?1, ?2, NULL)
+             ON CONFLICT(series_uuid, original_start_at) DO NOTHING;",
+            params![series_id.to_string(), original_start_at],
+        ) {
+            error!(
+                "event=recurrence_skip module=repo status=error atom_id={} duration_ms={} error_code=db_write_failed error={}",
+                series_id,
+                started_at.elapsed().as_millis(),
+                err
+            );
+            return Err(err.into());
+        }
+
+        info!(
+            "event=recurrence_skip module=repo status=ok atom_id={} original_start_at={} duration_ms={}",
+            series_id,
+            original_start_at,
+            started_at.elapsed().as_millis()
+        );
+        Ok(())
+    }
+
+    fn detach_occurrence(
+        &self,
+        series_id: AtomId,
+        original_start_at: i64,
+        detached: &Atom,
+    ) -> RepoResult<AtomId> {
+        let started_at = Instant::now();
+        let result = (|| -> RepoResult<AtomId> {
+            let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+            SqliteAtomRepository::try_new(&tx)?.create_atom(detached)?;
+            copy_atom_tags(&tx, series_id, detached.uuid)?;
+            copy_relative_reminders(&tx, series_id, detached.uuid)?;
+            tx.execute(
+                "INSERT INTO recurrence_exceptions (series_uuid, original_start_at, override_uuid)
+                 VALUES (?1, ?2, ?3);",
+                params![
+                    series_id.to_string(),
+                    original_start_at,
+                    detached.uuid.to_string()
+                ],
+            )?;
+            tx.commit()?;
+            Ok(detached.uuid)
+        })();
+
+        log_series_write("recurrence_detach", series_id, &result, started_at);
+        result
+    }
+
+    fn split_series(
+        &self,
+        truncated: &Atom,
+        split_at: i64,
+        following: &Atom,
+    ) -> RepoResult<AtomId> {
+        let started_at = Instant::now();
+        let result = (|| -> RepoResult<AtomId> {
+            let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+            let atoms = SqliteAtomRepository::try_new(&tx)?;
+            atoms.update_atom(truncated)?;
+            atoms.create_atom(following)?;
+            copy_atom_tags(&tx, truncated.uuid, following.uuid)?;
+            copy_relative_reminders(&tx, truncated.uuid, following.uuid)?;
+            tx.execute(
+                "UPDATE recurrence_exceptions
+                 SET series_uuid = ?2
+                 WHERE series_uuid = ?1
+                   AND original_start_at >= ?3;",
+                params![
+                    truncated.uuid.to_string(),
+                    following.uuid.to_string(),
+                    split_at
+                ],
+            )?;
+            tx.commit()?;
+            Ok(following.uuid)
+        })();
+
+        log_series_write("recurrence_split", truncated.uuid, &result, started_at);
+        result
+    }
+}
+
+fn copy_atom_tags(conn: &Connection, from: AtomId, to: AtomId) -> RepoResult<()> {
+    conn.execute(
+        "INSERT OR IGNORE INTO atom_tags (atom_uuid, tag_id, source)
+         SELECT ?2, tag_id, source
+         FROM atom_tags
+         WHERE atom_uuid = ?1;",
+        params![from.to_string(), to.to_string()],
+    )?;
+    Ok(())
+}
+
+fn log_series_write(
+    event: &str,
+    series_id: AtomId,
+    result: &RepoResult<AtomId>,
+    started_at: Instant,
+) {
+    match result {
+        Ok(new_id) => info!(
+            "event={} module=repo status=ok atom_id={} new_atom_id={} duration_ms={}",
+            event,
+            series_id,
+            new_id,
+            started_at.elapsed().as_millis()
+        ),
+        Err(err) => error!(
+            "event={} module=repo status=error atom_id={} duration_ms={} error_code={}",
+            event,
+            series_id,
+            started_at.elapsed().as_millis(),
+            match err {
+                RepoError::Validation(_) => "validation_error",
+                RepoError::NotFound(_) => "not_found",
+                _ => "db_write_failed",
+            }
+        ),
+    }
+}
@@ -0,0 +1,440 @@
+//! Reminder persistence and due-firing queries (`reminders`).
+//!
+//! # Responsibility
+//! - Store reminders and their acknowledgement/snooze state.
+//! - Answer due-firing and next-wakeup queries for schedulers and platform
+//!   notifiers.
+//!
+//! # Invariants
+//! - Fire times are computed from the owning atom on read (see
+//!   `model::reminder`); only delivery state is written back.
+//! - Reminders of trashed or finished (`done`/`cancelled`) atoms stay stored
+//!   but never fire.
+//! - Argument validation (anchors, snooze windows) lives in the service layer.
+//!
+//! # See also
+//! - crates/lazynote_core/src/service/reminder_service.rs
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::{Atom, AtomId};
+use crate::model::local_time::LocalCalendar;
+use crate::model::reminder::{
+    DueReminder, Reminder, ReminderId, ReminderSchedule, ReminderTrigger,
+};
+use crate::repo::atom_repo::{
+    load_exception_starts, AtomRepository, RepoError, RepoResult, SqliteAtomRepository,
+};
+use crate::sync::hlc::stamp_hlc;
+use log::{error, info};
+use rusqlite::{params, Connection, Row};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::time::Instant;
+use uuid::Uuid;
+
+const REMINDER_SELECT_SQL: &str = "SELECT
+    uuid,
+    atom_uuid,
+    anchor,
+    remind_at,
+    offset_ms,
+    acked_through,
+    snoozed_until,
+    snoozed_fire_at,
+    created_at
+FROM reminders";
+
+/// Repository interface for atom reminders.
+pub trait ReminderRepository {
+    /// Inserts one reminder.
+    fn create_reminder(&self, reminder: &Reminder) -> RepoResult<()>;
+    /// Loads one reminder with its computed `next_fire_at`.
+    fn get_reminder(&self, id: ReminderId) -> RepoResult<Option<Reminder>>;
+    /// Lists reminders of one atom, oldest first.
+    fn list_atom_reminders(&self, atom_id: AtomId) -> RepoResult<Vec<Reminder>>;
+    /// Deletes one reminder.
+    ///
+    /// Returns `false` when no such reminder existed.
+    fn delete_reminder(&self, id: ReminderId) -> RepoResult<bool>;
+    /// Writes `acked_through` and snooze state of an existing reminder.
+    fn save_delivery_state(&self, reminder: &Reminder) -> RepoResult<()>;
+    /// Lists firings due at `now_ms`, ordered by `fire_at`.
+    ///
+    /// Each reminder yields at most its latest due scheduled firing plus a
+    /// due snoozed firing.
+    fn list_due_reminders(&self, now_ms: i64) -> RepoResult<Vec<DueReminder>>;
+    /// Returns the earliest pending fire time strictly after `after_ms`.
+    fn next_fire_after(&self, after_ms: i64) -> RepoResult<Option<i64>>;
+}
+
+/// SQLite-backed reminder repository.
+pub struct SqliteReminderRepository<'conn> {
+    conn: &'conn Connection,
+    calendar: LocalCalendar,
+}
+
+impl<'conn> SqliteReminderRepository<'conn> {
+    /// Constructs a repository from an existing SQLite connection.
+    ///
+    /// # Errors
+    /// - Returns [`RepoError::MissingRequiredTable`] when the `reminders`
+    ///   table is absent.
+    pub fn try_new(conn: &'conn Connection) -> RepoResult<Self> {
+        Self::try_new_with_calendar(conn, LocalCalendar::utc())
+    }
+
+    /// Constructs a repository firing recurring timed atoms per occurrence
+    /// of their series expanded in `calendar`.
+    ///
+    /// # Errors
+    /// - Same as [`SqliteReminderRepository::try_new`].
+    pub fn try_new_with_calendar(
+        conn: &'conn Connection,
+        calendar: LocalCalendar,
+    ) -> RepoResult<Self> {
+        let exists: i64 = conn.query_row(
+            "SELECT EXISTS(
+                SELECT 1
+                FROM sqlite_master
+                WHERE type = 'table' AND name = 'reminders'
+            );",
+            [],
+            |row| row.get(0),
+        )?;
+        if exists != 1 {
+            return Err(RepoError::MissingRequiredTable("reminders"));
+        }
+        Ok(Self { conn, calendar })
+    }
+
+    fn query_reminders(
+        &self,
+        sql: &str,
+        params: impl rusqlite::Params,
+    ) -> RepoResult<Vec<Reminder>> {
+        let mut stmt = self.conn.prepare(sql)?;
+        let mut rows = stmt.query(params)?;
+        let mut reminders = Vec::new();
+        while let Some(row) = rows.next()? {
+            reminders.push(parse_reminder_row(row)?);
+        }
+        Ok(reminders)
+    }
+
+    /// Loads reminders of active atoms together with their schedules.
+    fn load_armed(&self) -> RepoResult<Vec<(Reminder, Atom, ReminderSchedule)>> {
+        let sql = format!(
+            "{REMINDER_SELECT_SQL}
+             WHERE atom_uuid IN (SELECT uuid FROM atoms WHERE is_deleted = 0)
+             ORDER BY created_at ASC, rowid ASC;"
+        );
+        let reminders = self.query_reminders(&sql, [])?;
+        let mut atoms: HashMap<AtomId, Option<Atom>> = HashMap::new();
+        let mut armed = Vec::new();
+        for reminder in reminders {
+            let atom = match atoms.entry(reminder.atom_id) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(self.load_atom(reminder.atom_id)?),
+            };
+            let Some(atom) = atom else {
+                continue;
+            };
+            let schedule = self.schedule(&reminder, atom)?;
+            armed.push((reminder, atom.clone(), schedule));
+        }
+        Ok(armed)
+    }
+
+    fn load_atom(&self, atom_id: AtomId) -> RepoResult<Option<Atom>> {
+        SqliteAtomRepository::try_new_with_calendar(self.conn, self.calendar)?
+            .get_atom(atom_id, false)
+    }
+
+    fn schedule(&self, reminder: &Reminder, atom: &Atom) -> RepoResult<ReminderSchedule> {
+        let skipped = if atom.is_recurring() {
+            load_exception_starts(self.conn, atom.uuid)?
+        } else {
+            Default::default()
+        };
+        Ok(ReminderSchedule::new(
+            reminder.trigger,
+            atom,
+            skipped,
+            self.calendar,
+        ))
+    }
+
+    /// Fills `next_fire_at` from the owning atom.
+    fn with_next_fire(&self, mut reminder: Reminder) -> RepoResult<Reminder> {
+        reminder.next_fire_at = match self.load_atom(reminder.atom_id)? {
+            Some(atom) => next_pending(
+                &reminder,
+                &self.schedule(&reminder, &atom)?,
+                reminder.delivered_through(),
+            ),
+            None => None,
+        };
+        Ok(reminder)
+    }
+}
+
+impl ReminderRepository for SqliteReminderRepository<'_> {
+    fn create_reminder(&self, reminder: &Reminder) -> RepoResult<()> {
+        let started_at = Instant::now();
+        let (remind_at, offset_ms) = trigger_columns(reminder.trigger);
+        let result = (|| -> RepoResult<()> {
+            let hlc = stamp_hlc(self.conn)?.to_string();
+            self.conn.execute(
+                "INSERT INTO reminders (
+                    uuid,
+                    atom_uuid,
+                    anchor,
+                    remind_at,
+                    offset_ms,
+                    acked_through,
+                    snoozed_until,
+                    snoozed_fire_at,
+                    hlc_timestamp,
+                    created_at,
+                    updated_at
+                 ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?10);",
+                params![
+                    reminder.id.to_string(),
+                    reminder.atom_id.to_string(),
+                    reminder.trigger.anchor(),
+                    remind_at,
+                    offset_ms,
+                    reminder.acked_through,
+                    reminder.snoozed_until,
+                    reminder.snoozed_fire_at,
+                    hlc,
+                    reminder.created_at,
+                ],
+            )?;
+            Ok(())
+        })();
+        log_reminder_write("reminder_create", reminder, &result, started_at);
+        result
+    }
+
+    fn get_reminder(&self, id: ReminderId) -> RepoResult<Option<Reminder>> {
+        let sql = format!("{REMINDER_SELECT_SQL} WHERE uuid = ?1;");
+        match self.query_reminders(&sql, [id.to_string()])?.pop() {
+            Some(reminder) => Ok(Some(self.with_next_fire(reminder)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn list_atom_reminders(&self, atom_id: AtomId) -> RepoResult<Vec<Reminder>> {
+        let sql = format!(
+            "{REMINDER_SELECT_SQL}
+             WHERE atom_uuid = ?1
+             ORDER BY created_at ASC, rowid ASC;"
+        );
+        self.query_reminders(&sql, [atom_id.to_string()])?
+            .into_iter()
+            .map(|reminder| self.with_next_fire(reminder))
+            .collect()
+    }
+
+    fn delete_reminder(&self, id: ReminderId) -> RepoResult<bool> {
+        let removed = self
+            .conn
+            .execute("DELETE FROM reminders WHERE uuid = ?1;", [id.to_string()])?;
+        info!(
+            "event=reminder_delete module=repo status=ok reminder_id={} removed={}",
+            id,
+            removed > 0
+        );
+        Ok(removed > 0)
+    }
+
+    fn save_delivery_state(&self, reminder: &Reminder) -> RepoResult<()> {
+        let started_at = Instant::now();
+        let result = (|| -> RepoResult<()> {
+            let hlc = stamp_hlc(self.conn)?.to_string();
+            let updated = self.conn.execute(
+                "UPDATE reminders
+                 SET acked_through = ?2,
+                     snoozed_until = ?3,
+                     snoozed_fire_at = ?4,
+                     hlc_timestamp = ?5,
+                     updated_at = (strftime('%s', 'now') * 1000)
+                 WHERE uuid = ?1;",
+                params![
+                    reminder.id.to_string(),
+                    reminder.acked_through,
+                    reminder.snoozed_until,
+                    reminder.snoozed_fire_at,
+                    hlc
+                ],
+            )?;
+            if updated == 0 {
+                return Err(RepoError::NotFound(reminder.id));
+            }
+            Ok(())
+        })();
+        log_reminder_write("reminder_delivery_state", reminder, &result, started_at);
+        result
+    }
+
+    fn list_due_reminders(&self, now_ms: i64) -> RepoResult<Vec<DueReminder>> {
+        let mut due = Vec::new();
+        for (mut reminder, atom, schedule) in self.load_armed()? {
+            let scheduled = schedule.last_between(reminder.delivered_through(), now_ms);
+            let snoozed = match (reminder.snoozed_until, reminder.snoozed_fire_at) {
+                (Some(until), Some(fire_at)) if until <= now_ms && !schedule.is_never() => {
+                    Some((until, fire_at))
+                }
+                _ => None,
+            };
+            reminder.next_fire_at =
+                next_pending(&reminder, &schedule, reminder.delivered_through());
+            if let Some((until, fire_at)) = snoozed {
+                due.push(DueReminder {
+                    reminder: reminder.clone(),
+                    atom: atom.clone(),
+                    fire_at: until,
+                    occurrence_start_at: schedule.occurrence_of(fire_at),
+                    snoozed: true,
+                });
+            }
+            if let Some(fire_at) = scheduled {
+                due.push(DueReminder {
+                    reminder,
+                    atom,
+                    fire_at,
+                    occurrence_start_at: schedule.occurrence_of(fire_at),
+                    snoozed: false,
+                });
+            }
+        }
+        due.sort_by_key(|item| (item.fire_at, item.reminder.created_at, item.reminder.id));
+        Ok(due)
+    }
+
+    fn next_fire_after(&self, after_ms: i64) -> RepoResult<Option<i64>> {
+        Ok(self
+            .load_armed()?
+            .iter()
+            .filter_map(|(reminder, _, schedule)| {
+                let scheduled = schedule.first_after(reminder.delivered_through().max(after_ms));
+                let snoozed = reminder
+                    .snoozed_until
+                    .filter(|until| *until > after_ms && !schedule.is_never());
+                scheduled.into_iter().chain(snoozed).min()
+            })
+            .min())
+    }
+}
+
+/// Copies start/due-relative reminders of `from` onto `to` with fresh IDs.
+///
+/// Used when a series occurrence is detached or a series is split, so the
+/// new atom keeps reminding; delivery progress is kept, snoozes are not.
+pub(crate) fn copy_relative_reminders(
+    conn: &Connection,
+    from: AtomId,
+    to: AtomId,
+) -> RepoResult<()> {
+    let mut stmt = conn.prepare(
+        "SELECT uuid
+         FROM reminders
+         WHERE atom_uuid = ?1 AND anchor <> 'absolute'
+         ORDER BY created_at ASC, rowid ASC;",
+    )?;
+    let sources = stmt
+        .query_map([from.to_string()], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    for source in sources {
+        let hlc = stamp_hlc(conn)?.to_string();
+        conn.execute(
+            "INSERT INTO reminders (
+                uuid, atom_uuid, anchor, remind_at, offset_ms, acked_through,
+                hlc_timestamp, created_at
+             )
+             SELECT ?2, ?3, anchor, remind_at, offset_ms, acked_through, ?4, created_at
+             FROM reminders
+             WHERE uuid = ?1;",
+            params![source, Uuid::new_v4().to_string(), to.to_string(), hlc],
+        )?;
+    }
+    Ok(())
+}
+
+/// Next pending fire time after `after_ms`, counting a pending snooze.
+fn next_pending(reminder: &Reminder, schedule: &ReminderSchedule, after_ms: i64) -> Option<i64> {
+    let scheduled = schedule.first_after(after_ms);
+    let snoozed = reminder.snoozed_until.filter(|_| !schedule.is_never());
+    scheduled.into_iter().chain(snoozed).min()
+}
+
+fn trigger_columns(trigger: ReminderTrigger) -> (Option<i64>, Option<i64>) {
+    match trigger {
+        ReminderTrigger::Absolute { remind_at } => (Some(remind_at), None),
+        ReminderTrigger::Start { offset_ms } | ReminderTrigger::Due { offset_ms } => {
+            (None, Some(offset_ms))
+        }
+    }
+}
+
+fn parse_reminder_row(row: &Row<'_>) -> RepoResult<Reminder> {
+    let id: String = row.get(0)?;
+    let atom_id: String = row.get(1)?;
+    let anchor: String = row.get(2)?;
+    let remind_at: Option<i64> = row.get(3)?;
+    let offset_ms: Option<i64> = row.get(4)?;
+    let trigger = match (anchor.as_str(), remind_at, offset_ms) {
+        ("absolute", Some(remind_at), _) => ReminderTrigger::Absolute { remind_at },
+        ("start", _, Some(offset_ms)) => ReminderTrigger::Start { offset_ms },
+        ("due", _, Some(offset_ms)) => ReminderTrigger::Due { offset_ms },
+        _ => {
+            return Err(RepoError::InvalidData(format!(
+                "invalid reminder trigger `{anchor}` in reminders row `{id}`"
+            )))
+        }
+    };
+    Ok(Reminder {
+        id: parse_reminder_uuid(&id)?,
+        atom_id: parse_reminder_uuid(&atom_id)?,
+        trigger,
+        acked_through: row.get(5)?,
+        snoozed_until: row.get(6)?,
+        snoozed_fire_at: row.get(7)?,
+        created_at: row.get(8)?,
+        next_fire_at: None,
+    })
+}
+
+fn parse_reminder_uuid(value: &str) -> RepoResult<Uuid> {
+    Uuid::parse_str(value)
+        .map_err(|_| RepoError::InvalidData(format!("invalid uuid value `{value}` in reminders")))
+}
+
+fn log_reminder_write(
+    event: &str,
+    reminder: &Reminder,
+    result: &RepoResult<()>,
+    started_at: Instant,
+) {
+    match result {
+        Ok(()) => info!(
+            "event={} module=repo status=ok reminder_id={} atom_id={} duration_ms={}",
+            event,
+            reminder.id,
+            reminder.atom_id,
+            started_at.elapsed().as_millis()
+        ),
+        Err(err) => error!(
+            "event={} module=repo status=error reminder_id={} atom_id={} duration_ms={} error_code={}",
+            event,
+            reminder.id,
+            reminder.atom_id,
+            started_at.elapsed().as_millis(),
+            match err {
+                RepoError::NotFound(_) => "not_found",
+                _ => "db_write_failed",
+            }
+        ),
+    }
+}
@@ -0,0 +1,250 @@
+//! Resource link index persistence (`links`).
+//!
+//! # Responsibility
+//! - Refresh the Markdown/bare-URL link index of an atom on content writes.
+//! - Serve per-atom listing and keyword search over indexed links.
+//!
+//! # Invariants
+//! - Link rows are derived data; `atoms.content` stays the source of truth.
+//! - Row ids and `created_at` survive re-saves while the target remains in
+//!   content, so UI selections stay valid across autosaves.
+//! - Links of soft-deleted atoms are hidden from reads.
+//!
+//! # See also
+//! - docs/architecture/links-and-launcher.md
+
+use crate::model::atom::AtomId;
+use crate::model::resource_link::{parse_resource_links, ResourceLink, ResourceLinkSource};
+use crate::repo::atom_repo::{RepoError, RepoResult};
+use crate::sync::provider_types::now_epoch_ms;
+use log::info;
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection, Row, Transaction, TransactionBehavior};
+use std::time::Instant;
+use uuid::Uuid;
+
+const RESOURCE_LINK_SELECT_SQL: &str = "SELECT
+    l.id AS id,
+    l.atom_uuid AS atom_uuid,
+    l.scheme AS scheme,
+    l.target AS target,
+    l.label AS label,
+    l.source AS source,
+    l.position AS position
+FROM links l
+JOIN atoms a ON a.uuid = l.atom_uuid AND a.is_deleted = 0";
+
+/// Repository interface for the resource link index.
+pub trait ResourceLinkRepository {
+    /// Lists links written in one active atom, in document order.
+    fn list_links(&self, atom_id: AtomId) -> RepoResult<Vec<ResourceLink>>;
+    /// Case-insensitive substring search over link targets and labels.
+    ///
+    /// Results are ordered by owning atom `updated_at DESC`, then document
+    /// order.
+    fn search_links(&self, query: &str, limit: u32) -> RepoResult<Vec<ResourceLink>>;
+    /// Loads indexed links by row id, skipping unknown or hidden rows.
+    fn get_links(&self, ids: &[i64]) -> RepoResult<Vec<ResourceLink>>;
+    /// Re-parses every atom and rebuilds the link index.
+    ///
+    /// Returns the number of atoms indexed.
+    fn rebuild_all_resource_links(&self) -> RepoResult<u32>;
+}
+
+/// SQLite-backed resource link repository.
+pub struct SqliteResourceLinkRepository<'conn> {
+    conn: &'conn Connection,
+}
+
+impl<'conn> SqliteResourceLinkRepository<'conn> {
+    /// Constructs a repository from an existing SQLite connection.
+    ///
+    /// # Errors
+    /// - Returns [`RepoError::MissingRequiredTable`] when the `links` table
+    ///   is absent.
+    pub fn try_new(conn: &'conn Connection) -> RepoResult<Self> {
+        let exists: i64 = conn.query_row(
+            "SELECT EXISTS(
+                SELECT 1
+                FROM sqlite_master
+                WHERE type = 'table' AND name = 'links'
+            );",
+            [],
+            |row| row.get(0),
+        )?;
+        if exists != 1 {
+            return Err(RepoError::MissingRequiredTable("links"));
+        }
+        Ok(Self { conn })
+    }
+
+    fn query_links(
+        &self,
+        sql: &str,
+        params: impl rusqlite::Params,
+    ) -> RepoResult<Vec<ResourceLink>> {
+        let mut stmt = self.conn.prepare(sql)?;
+        let mut rows = stmt.query(params)?;
+        let mut links = Vec::new();
+        while let Some(row) = rows.next()? {
+            links.push(parse_resource_link_row(row)?);
+        }
+        Ok(links)
+    }
+}
+
+impl ResourceLinkRepository for SqliteResourceLinkRepository<'_> {
+    fn list_links(&self, atom_id: AtomId) -> RepoResult<Vec<ResourceLink>> {
+        let sql = format!(
+            "{RESOURCE_LINK_SELECT_SQL}
+             WHERE l.atom_uuid = ?1
+             ORDER BY l.position ASC;"
+        );
+        self.query_links(&sql, [atom_id.to_string()])
+    }
+
+    fn search_links(&self, query: &str, limit: u32) -> RepoResult<Vec<ResourceLink>> {
+        let pattern = format!("%{}%", escape_like(query));
+        let sql = format!(
+            "{RESOURCE_LINK_SELECT_SQL}
+             WHERE l.target LIKE ?1 ESCAPE '\\'
+                OR l.label LIKE ?1 ESCAPE '\\'
+             ORDER BY a.updated_at DESC, a.uuid ASC, l.position ASC
+             LIMIT ?2;"
+        );
+        self.query_links(&sql, params![pattern, i64::from(limit)])
+    }
+
+    fn get_links(&self, ids: &[i64]) -> RepoResult<Vec<ResourceLink>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let placeholders = vec!["?"; ids.len()].join(", ");
+        let sql = format!(
+            "{RESOURCE_LINK_SELECT_SQL}
+             WHERE l.id IN ({placeholders})
+             ORDER BY l.id ASC;"
+        );
+        let values = ids.iter().map(|id| Value::Integer(*id));
+        self.query_links(&sql, params_from_iter(values))
+    }
+
+    fn rebuild_all_resource_links(&self) -> RepoResult<u32> {
+        let started_at = Instant::now();
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let sources: Vec<(String, String)> = {
+            let mut stmt = tx.prepare("SELECT uuid, content FROM atoms;")?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            rows.collect::<Result<_, _>>()?
+        };
+        for (uuid, content) in &sources {
+            let atom_id = parse_link_atom_uuid(uuid)?;
+            sync_resource_links(&tx, atom_id, content)?;
+        }
+        tx.commit()?;
+
+        info!(
+            "event=resource_links_rebuild module=repo status=ok atom_count={} duration_ms={}",
+            sources.len(),
+            started_at.elapsed().as_millis()
+        );
+        Ok(sources.len() as u32)
+    }
+}
+
+/// Replaces the resource link set of `atom_id` with the links in `content`.
+///
+/// Rows whose target is still present are updated in place.
+pub(crate) fn sync_resource_links(
+    conn: &Connection,
+    atom_id: AtomId,
+    content: &str,
+) -> rusqlite::Result<()> {
+    let uuid = atom_id.to_string();
+    let parsed = parse_resource_links(content);
+    let now = now_epoch_ms();
+
+    let existing: Vec<(i64, String)> = {
+        let mut stmt = conn.prepare("SELECT id, target FROM links WHERE atom_uuid = ?1;")?;
+        let rows = stmt.query_map([&uuid], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect::<Result<_, _>>()?
+    };
+    for (id, target) in existing {
+        if !parsed.iter().any(|link| link.target == target) {
+            conn.execute("DELETE FROM links WHERE id = ?1;", [id])?;
+        }
+    }
+
+    for (position, link) in parsed.iter().enumerate() {
+        conn.execute(
+            "INSERT INTO links (
+                atom_uuid,
+                scheme,
+                target,
+                label,
+                source,
+                position,
+                created_at,
+                updated_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)
+            ON CONFLICT(atom_uuid, target) DO UPDATE SET
+                scheme = excluded.scheme,
+                label = excluded.label,
+                source = excluded.source,
+                position = excluded.position,
+                updated_at = excluded.updated_at;",
+            params![
+                uuid,
+                link.scheme,
+                link.target,
+                link.label,
+                link.source.as_str(),
+                position as i64,
+                now
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+fn escape_like(raw: &str) -> String {
+    let mut escaped = String::with_capacity(raw.len());
+    for ch in raw.chars() {
+        if matches!(ch, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+fn parse_resource_link_row(row: &Row<'_>) -> RepoResult<ResourceLink> {
+    let uuid_text: String = row.get("atom_uuid")?;
+    let source_text: String = row.get("source")?;
+    let source = ResourceLinkSource::parse(&source_text).ok_or_else(|| {
+        RepoError::InvalidData(format!(
+            "invalid link source `{source_text}` in links.source"
+        ))
+    })?;
+    let position: i64 = row.get("position")?;
+
+    Ok(ResourceLink {
+        id: row.get("id")?,
+        atom_id: parse_link_atom_uuid(&uuid_text)?,
+        target: row.get("target")?,
+        scheme: row.get("scheme")?,
+        label: row.get("label")?,
+        source,
+        position: u32::try_from(position).map_err(|_| {
+            RepoError::InvalidData(format!(
+                "invalid link position `{position}` in links.position"
+            ))
+        })?,
+    })
+}
+
+fn parse_link_atom_uuid(value: &str) -> RepoResult<AtomId> {
+    Uuid::parse_str(value).map_err(|_| {
+        RepoError::InvalidData(format!("invalid uuid value `{value}` in links.atom_uuid"))
+    })
+}
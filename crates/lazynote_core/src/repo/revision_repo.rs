@@ -0,0 +1,398 @@
+//! Atom revision persistence (capture, list, restore).
+//!
+//! # Responsibility
+//! - Snapshot the pre-write state of an atom into `atom_revisions`.
+//! - Coalesce rapid autosave bursts into one revision.
+//! - Restore one snapshot back onto its atom.
+//!
+//! # Invariants
+//! - Snapshots are captured *before* the overwriting write.
+//! - Coalesced capture is skipped when the latest revision of the same atom
+//!   is younger than [`REVISION_COALESCE_WINDOW_MS`].
+//! - Consecutive identical snapshots are never stored twice.
+//! - Restore always captures the current state first, so it can be undone.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::AtomId;
+use crate::model::revision::{AtomRevision, RevisionId};
+use crate::repo::atom_repo::{
+    parse_atom_type, parse_task_priority, parse_task_status, RepoError, RepoResult,
+};
+use crate::repo::content_index::index_atom_content;
+use crate::sync::hlc::stamp_hlc;
+use crate::sync::provider_types::now_epoch_ms;
+use log::{error, info};
+use rusqlite::{params, Connection, OptionalExtension, Row, Transaction, TransactionBehavior};
+use std::time::Instant;
+use uuid::Uuid;
+
+/// Autosave bursts within this window collapse into one revision.
+pub const REVISION_COALESCE_WINDOW_MS: i64 = 60_000;
+
+const REVISIONS_DEFAULT_LIMIT: u32 = 20;
+const REVISIONS_LIMIT_MAX: u32 = 100;
+
+const REVISION_SELECT_SQL: &str = "SELECT
+    revision_id,
+    atom_uuid,
+    type,
+    content,
+    preview_text,
+    preview_image,
+    task_status,
+    start_at,
+    end_at,
+    all_day,
+    priority,
+    due_at,
+    recurrence_rule,
+    hlc_timestamp,
+    source_updated_at,
+    captured_at
+FROM atom_revisions";
+
+/// How [`capture_revision`] treats a recent prior revision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CaptureMode {
+    /// Skip capture inside the coalescing window (autosave path).
+    Coalesce,
+    /// Always capture unless the state is unchanged (restore path).
+    Always,
+}
+
+/// Repository interface for atom revision history.
+pub trait RevisionRepository {
+    /// Lists revisions of one atom, newest first.
+    ///
+    /// Returns [`RepoError::NotFound`] when the atom does not exist.
+    fn list_revisions(
+        &self,
+        atom_id: AtomId,
+        limit: u32,
+        offset: u32,
+    ) -> RepoResult<Vec<AtomRevision>>;
+
+    /// Loads one revision scoped to its atom.
+    fn get_revision(
+        &self,
+        atom_id: AtomId,
+        revision_id: RevisionId,
+    ) -> RepoResult<Option<AtomRevision>>;
+
+    /// Loads the current content of an active atom.
+    fn current_content(&self, atom_id: AtomId) -> RepoResult<Option<String>>;
+
+    /// Writes one revision back onto its active atom.
+    ///
+    /// The pre-restore state is captured as a new revision first.
+    fn restore_revision(&self, atom_id: AtomId, revision_id: RevisionId) -> RepoResult<()>;
+}
+
+/// SQLite-backed revision repository.
+pub struct SqliteRevisionRepository<'conn> {
+    conn: &'conn Connection,
+}
+
+impl<'conn> SqliteRevisionRepository<'conn> {
+    /// Constructs a repository from an existing SQLite connection.
+    ///
+    /// # Errors
+    /// - Returns [`RepoError::MissingRequiredTable`] when the
+    ///   `atom_revisions` table is absent.
+    pub fn try_new(conn: &'conn Connection) -> RepoResult<Self> {
+        let exists: i64 = conn.query_row(
+            "SELECT EXISTS(
+                SELECT 1
+                FROM sqlite_master
+                WHERE type = 'table' AND name = 'atom_revisions'
+            );",
+            [],
+            |row| row.get(0),
+        )?;
+        if exists != 1 {
+            return Err(RepoError::MissingRequiredTable("atom_revisions"));
+        }
+        Ok(Self { conn })
+    }
+}
+
+impl RevisionRepository for SqliteRevisionRepository<'_> {
+    fn list_revisions(
+        &self,
+        atom_id: AtomId,
+        limit: u32,
+        offset: u32,
+    ) -> RepoResult<Vec<AtomRevision>> {
+        let exists: i64 = self.conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM atoms WHERE uuid = ?1);",
+            [atom_id.to_string()],
+            |row| row.get(0),
+        )?;
+        if exists != 1 {
+            return Err(RepoError::NotFound(atom_id));
+        }
+
+        let sql = format!(
+            "{REVISION_SELECT_SQL}
+             WHERE atom_uuid = ?1
+             ORDER BY revision_id DESC
+             LIMIT ?2 OFFSET ?3;"
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let mut rows = stmt.query(params![
+            atom_id.to_string(),
+            i64::from(limit),
+            i64::from(offset)
+        ])?;
+        let mut revisions = Vec::new();
+        while let Some(row) = rows.next()? {
+            revisions.push(parse_revision_row(row)?);
+        }
+        Ok(revisions)
+    }
+
+    fn get_revision(
+        &self,
+        atom_id: AtomId,
+        revision_id: RevisionId,
+    ) -> RepoResult<Option<AtomRevision>> {
+        let sql = format!("{REVISION_SELECT_SQL} WHERE atom_uuid = ?1 AND revision_id = ?2;");
+        let mut stmt = self.conn.prepare(&sql)?;
+        let mut rows = stmt.query(params![atom_id.to_string(), revision_id])?;
+        match rows.next()? {
+            Some(row) => Ok(Some(parse_revision_row(row)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn current_content(&self, atom_id: AtomId) -> RepoResult<Option<String>> {
+        let content = self
+            .conn
+            .query_row(
+                "SELECT content FROM atoms WHERE uuid = ?1 AND is_deleted = 0;",
+                [atom_id.to_string()],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(content)
+    }
+
+    fn restore_revision(&self, atom_id: AtomId, revision_id: RevisionId) -> RepoResult<()> {
+        let started_at = Instant::now();
+        let result = (|| -> RepoResult<()> {
+            let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+            capture_revision(&tx, atom_id, CaptureMode::Always)?;
+            let hlc = stamp_hlc(&tx)?.to_string();
+            let changed = tx.execute(
+                "UPDATE atoms
+                 SET
+                    type = r.type,
+                    content = r.content,
+                    preview_text = r.preview_text,
+                    preview_image = r.preview_image,
+                    task_status = r.task_status,
+                    start_at = r.start_at,
+                    end_at = r.end_at,
+                    all_day = r.all_day,
+                    priority = r.priority,
+                    due_at = r.due_at,
+                    recurrence_rule = r.recurrence_rule,
+                    hlc_timestamp = ?3,
+                    updated_at = (strftime('%s', 'now') * 1000)
+                 FROM (
+                    SELECT *
+                    FROM atom_revisions
+                    WHERE atom_uuid = ?1 AND revision_id = ?2
+                 ) AS r
+                 WHERE atoms.uuid = ?1
+                   AND atoms.is_deleted = 0;",
+                params![atom_id.to_string(), revision_id, hlc],
+            )?;
+            if changed == 0 {
+                return Err(RepoError::NotFound(atom_id));
+            }
+            let content: String = tx.query_row(
+                "SELECT content FROM atoms WHERE uuid = ?1;",
+                [atom_id.to_string()],
+                |row| row.get(0),
+            )?;
+            index_atom_content(&tx, atom_id, &content)?;
+            tx.commit()?;
+            Ok(())
+        })();
+
+        match &result {
+            Ok(()) => info!(
+                "event=revision_restore module=repo status=ok atom_id={} revision_id={} duration_ms={}",
+                atom_id,
+                revision_id,
+                started_at.elapsed().as_millis()
+            ),
+            Err(err) => error!(
+                "event=revision_restore module=repo status=error atom_id={} revision_id={} duration_ms={} error_code={}",
+                atom_id,
+                revision_id,
+                started_at.elapsed().as_millis(),
+                match err {
+                    RepoError::NotFound(_) => "not_found",
+                    _ => "db_write_failed",
+                }
+            ),
+        }
+        result
+    }
+}
+
+/// Normalizes revision list limit. Defaults to 20 and clamps to 100.
+pub fn normalize_revision_limit(limit: Option<u32>) -> u32 {
+    match limit {
+        Some(0) | None => REVISIONS_DEFAULT_LIMIT,
+        Some(value) => value.min(REVISIONS_LIMIT_MAX),
+    }
+}
+
+/// Snapshots the current state of `atom_id` ahead of an overwriting write.
+///
+/// Returns the new revision id, or `None` when nothing was captured (atom
+/// missing, state unchanged since the latest revision, or coalesced).
+pub(crate) fn capture_revision(
+    conn: &Connection,
+    atom_id: AtomId,
+    mode: CaptureMode,
+) -> RepoResult<Option<RevisionId>> {
+    let uuid = atom_id.to_string();
+    let latest: Option<(i64, bool)> = conn
+        .query_row(
+            "SELECT
+                r.captured_at,
+                (r.type = a.type
+                    AND r.content = a.content
+                    AND r.preview_text IS a.preview_text
+                    AND r.preview_image IS a.preview_image
+                    AND r.task_status IS a.task_status
+                    AND r.start_at IS a.start_at
+                    AND r.end_at IS a.end_at
+                    AND r.all_day = a.all_day
+                    AND r.priority IS a.priority
+                    AND r.due_at IS a.due_at
+                    AND r.recurrence_rule IS a.recurrence_rule)
+             FROM atom_revisions r
+             JOIN atoms a ON a.uuid = r.atom_uuid
+             WHERE r.atom_uuid = ?1
+             ORDER BY r.revision_id DESC
+             LIMIT 1;",
+            [uuid.as_str()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+
+    let now = now_epoch_ms();
+    if let Some((captured_at, unchanged)) = latest {
+        if unchanged {
+            return Ok(None);
+        }
+        // Why: autosave fires every few seconds while typing; keeping only
+        // the state before each burst preserves history without flooding it.
+        if mode == CaptureMode::Coalesce && now - captured_at < REVISION_COALESCE_WINDOW_MS {
+            return Ok(None);
+        }
+    }
+
+    let inserted = conn.execute(
+        "INSERT INTO atom_revisions (
+            atom_uuid,
+            type,
+            content,
+            preview_text,
+            preview_image,
+            task_status,
+            start_at,
+            end_at,
+            all_day,
+            priority,
+            due_at,
+            recurrence_rule,
+            hlc_timestamp,
+            source_updated_at,
+            captured_at
+        )
+        SELECT
+            uuid,
+            type,
+            content,
+            preview_text,
+            preview_image,
+            task_status,
+            start_at,
+            end_at,
+            all_day,
+            priority,
+            due_at,
+            recurrence_rule,
+            hlc_timestamp,
+            updated_at,
+            ?2
+        FROM atoms
+        WHERE uuid = ?1;",
+        params![uuid, now],
+    )?;
+    if inserted == 0 {
+        return Ok(None);
+    }
+    Ok(Some(conn.last_insert_rowid()))
+}
+
+fn parse_revision_row(row: &Row<'_>) -> RepoResult<AtomRevision> {
+    let uuid_text: String = row.get("atom_uuid")?;
+    let atom_id = Uuid::parse_str(&uuid_text).map_err(|_| {
+        RepoError::InvalidData(format!(
+            "invalid uuid value `{uuid_text}` in atom_revisions.atom_uuid"
+        ))
+    })?;
+
+    let type_text: String = row.get("type")?;
+    let kind = parse_atom_type(&type_text).ok_or_else(|| {
+        RepoError::InvalidData(format!(
+            "invalid atom type `{type_text}` in atom_revisions.type"
+        ))
+    })?;
+
+    let task_status = match row.get::<_, Option<String>>("task_status")? {
+        Some(value) => Some(parse_task_status(&value).ok_or_else(|| {
+            RepoError::InvalidData(format!(
+                "invalid task status `{value}` in atom_revisions.task_status"
+            ))
+        })?),
+        None => None,
+    };
+
+    let priority = match row.get::<_, Option<i64>>("priority")? {
+        Some(value) => Some(parse_task_priority(value).ok_or_else(|| {
+            RepoError::InvalidData(format!(
+                "invalid priority `{value}` in atom_revisions.priority"
+            ))
+        })?),
+        None => None,
+    };
+
+    Ok(AtomRevision {
+        revision_id: row.get("revision_id")?,
+        atom_id,
+        kind,
+        content: row.get("content")?,
+        preview_text: row.get("preview_text")?,
+        preview_image: row.get("preview_image")?,
+        task_status,
+        start_at: row.get("start_at")?,
+        end_at: row.get("end_at")?,
+        all_day: row.get::<_, i64>("all_day")? == 1,
+        priority,
+        due_at: row.get("due_at")?,
+        recurrence_rule: row.get("recurrence_rule")?,
+        hlc_timestamp: row.get("hlc_timestamp")?,
+        source_updated_at: row.get("source_updated_at")?,
+        captured_at: row.get("captured_at")?,
+    })
+}
@@ -0,0 +1,163 @@
+//! Subtask hierarchy persistence (`task_subtasks`).
+//!
+//! # Responsibility
+//! - Store the parent of each subtask and the order of siblings.
+//! - Answer hierarchy reads (parent, ordered children, progress) used by
+//!   `TaskService`.
+//!
+//! # Invariants
+//! - Reads only surface active `type = 'task'` atoms; hierarchy rows of
+//!   deleted or converted atoms are kept (same hybrid policy as note_refs).
+//! - Sibling `sort_order` is rewritten densely (`0..n`) on every attach or
+//!   reorder, stamped with one HLC.
+//! - Cycle checks live in the service layer; this module only persists.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::AtomId;
+use crate::repo::atom_repo::{
+    query_section_rows, RepoError, RepoResult, SectionAtomRow, SECTION_SELECT_SQL,
+};
+use crate::sync::hlc::stamp_hlc;
+use rusqlite::{params, Connection, OptionalExtension};
+use uuid::Uuid;
+
+/// Completion summary of one parent's active subtasks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SubtaskProgress {
+    /// Subtasks with status `done`.
+    pub done: u32,
+    /// Subtasks that are not `cancelled`.
+    pub total: u32,
+}
+
+/// Returns the parent of `child_id`, if it has one.
+pub(crate) fn load_parent(conn: &Connection, child_id: AtomId) -> RepoResult<Option<AtomId>> {
+    let parent: Option<String> = conn
+        .query_row(
+            "SELECT parent_uuid FROM task_subtasks WHERE child_uuid = ?1;",
+            [child_id.to_string()],
+            |row| row.get(0),
+        )
+        .optional()?;
+    parent.as_deref().map(parse_subtask_uuid).transpose()
+}
+
+/// Lists active task children of `parent_id` in sibling order.
+pub(crate) fn list_child_rows(
+    conn: &Connection,
+    parent_id: AtomId,
+) -> RepoResult<Vec<SectionAtomRow>> {
+    let sql = format!(
+        "{SECTION_SELECT_SQL}
+         WHERE is_deleted = 0
+           AND type = 'task'
+           AND uuid IN (SELECT child_uuid FROM task_subtasks WHERE parent_uuid = ?1)
+         ORDER BY
+           (SELECT sort_order FROM task_subtasks WHERE child_uuid = atoms.uuid) ASC,
+           uuid ASC"
+    );
+    query_section_rows(conn, &sql, params![parent_id.to_string()])
+}
+
+/// Lists active task child IDs of `parent_id` in sibling order.
+pub(crate) fn list_child_ids(conn: &Connection, parent_id: AtomId) -> RepoResult<Vec<AtomId>> {
+    Ok(list_child_rows(conn, parent_id)?
+        .into_iter()
+        .map(|row| row.atom.uuid)
+        .collect())
+}
+
+/// Attaches `child_id` under `parent_id` at `target_index` (default: last),
+/// or detaches it when `parent_id` is `None`.
+pub(crate) fn set_parent(
+    conn: &Connection,
+    child_id: AtomId,
+    parent_id: Option<AtomId>,
+    target_index: Option<usize>,
+) -> RepoResult<()> {
+    let Some(parent_id) = parent_id else {
+        conn.execute(
+            "DELETE FROM task_subtasks WHERE child_uuid = ?1;",
+            [child_id.to_string()],
+        )?;
+        return Ok(());
+    };
+
+    let mut siblings = list_child_ids(conn, parent_id)?;
+    siblings.retain(|id| *id != child_id);
+    let index = target_index.unwrap_or(siblings.len()).min(siblings.len());
+    siblings.insert(index, child_id);
+    conn.execute(
+        "INSERT INTO task_subtasks (child_uuid, parent_uuid)
+         VALUES (?1, ?2)
+         ON CONFLICT(child_uuid) DO UPDATE SET parent_uuid = excluded.parent_uuid;",
+        params![child_id.to_string(), parent_id.to_string()],
+    )?;
+    write_sibling_order(conn, &siblings)
+}
+
+/// Rewrites sibling order to match `ordered` (`0..n`).
+pub(crate) fn write_sibling_order(conn: &Connection, ordered: &[AtomId]) -> RepoResult<()> {
+    let hlc = stamp_hlc(conn)?.to_string();
+    for (index, id) in ordered.iter().enumerate() {
+        conn.execute(
+            "UPDATE task_subtasks
+             SET sort_order = ?2,
+                 hlc_timestamp = ?3
+             WHERE child_uuid = ?1;",
+            params![id.to_string(), index as i64, hlc],
+        )?;
+    }
+    Ok(())
+}
+
+/// Counts done and non-cancelled active subtasks of `parent_id`.
+pub(crate) fn load_progress(conn: &Connection, parent_id: AtomId) -> RepoResult<SubtaskProgress> {
+    let (done, total) = conn.query_row(
+        "SELECT
+            COALESCE(SUM(a.task_status = 'done'), 0),
+            COALESCE(SUM(a.task_status IS NULL OR a.task_status <> 'cancelled'), 0)
+         FROM task_subtasks s
+         JOIN atoms a ON a.uuid = s.child_uuid
+         WHERE s.parent_uuid = ?1
+           AND a.is_deleted = 0
+           AND a.type = 'task';",
+        [parent_id.to_string()],
+        |row| Ok((row.get::<_, u32>(0)?, row.get::<_, u32>(1)?)),
+    )?;
+    Ok(SubtaskProgress { done, total })
+}
+
+/// Returns the parent of `child_id` when it is an open active task whose
+/// subtasks are all finished (done or cancelled, at least one done).
+pub(crate) fn completable_parent(
+    conn: &Connection,
+    child_id: AtomId,
+) -> RepoResult<Option<AtomId>> {
+    let Some(parent_id) = load_parent(conn, child_id)? else {
+        return Ok(None);
+    };
+    let parent_open: bool = conn
+        .query_row(
+            "SELECT task_status IS NULL OR task_status NOT IN ('done', 'cancelled')
+             FROM atoms
+             WHERE uuid = ?1 AND is_deleted = 0 AND type = 'task';",
+            [parent_id.to_string()],
+            |row| row.get(0),
+        )
+        .optional()?
+        .unwrap_or(false);
+    if !parent_open {
+        return Ok(None);
+    }
+    let progress = load_progress(conn, parent_id)?;
+    Ok((progress.total > 0 && progress.done == progress.total).then_some(parent_id))
+}
+
+fn parse_subtask_uuid(value: &str) -> RepoResult<AtomId> {
+    Uuid::parse_str(value).map_err(|_| {
+        RepoError::InvalidData(format!("invalid uuid value `{value}` in task_subtasks"))
+    })
+}
@@ -0,0 +1,400 @@
+//! Time entry persistence and aggregation inputs (`time_entries`).
+//!
+//! # Responsibility
+//! - Store running and stopped time tracking sessions of task atoms.
+//! - Load entries overlapping a time window together with the tags and
+//!   workspace folders their atoms are attributed to.
+//!
+//! # Invariants
+//! - At most one running entry per device (unique partial index).
+//! - Window queries only surface entries of active atoms; entries of trashed
+//!   atoms stay stored.
+//! - A task belongs to every folder (and its ancestors) holding a note_ref of
+//!   an active note that links to the task or one of its active parent tasks.
+//! - Argument validation (ranges, atom types) lives in the service layer.
+//!
+//! # See also
+//! - crates/lazynote_core/src/service/time_tracking_service.rs
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::AtomId;
+use crate::model::time_entry::{TimeEntry, TimeEntryId};
+use crate::repo::atom_repo::{RepoError, RepoResult};
+use crate::repo::note_repo::load_tags_for_atoms;
+use crate::repo::tree_repo::WorkspaceNodeId;
+use crate::sync::hlc::{local_node_id, stamp_hlc};
+use log::{error, info};
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection, Row};
+use std::collections::HashMap;
+use std::time::Instant;
+use uuid::Uuid;
+
+const TIME_ENTRY_SELECT_SQL: &str = "SELECT
+    uuid,
+    atom_uuid,
+    device_id,
+    started_at,
+    ended_at,
+    note
+FROM time_entries";
+
+/// Repository interface for task time entries.
+pub trait TimeEntryRepository {
+    /// Device whose timer `start`/`stop` operations act on.
+    fn device_id(&self) -> &str;
+    /// Inserts one entry.
+    fn create_entry(&self, entry: &TimeEntry) -> RepoResult<()>;
+    /// Loads one entry.
+    fn get_entry(&self, id: TimeEntryId) -> RepoResult<Option<TimeEntry>>;
+    /// Writes atom, `started_at`, `ended_at` and `note` of an existing entry.
+    fn update_entry(&self, entry: &TimeEntry) -> RepoResult<()>;
+    /// Deletes one entry.
+    ///
+    /// Returns `false` when no such entry existed.
+    fn delete_entry(&self, id: TimeEntryId) -> RepoResult<bool>;
+    /// Loads the running entry of `device_id`, if any.
+    fn running_entry(&self, device_id: &str) -> RepoResult<Option<TimeEntry>>;
+    /// Lists entries of one atom, oldest start first.
+    fn list_atom_entries(&self, atom_id: AtomId) -> RepoResult<Vec<TimeEntry>>;
+    /// Lists entries of active atoms overlapping `[start_ms, end_ms)`,
+    /// oldest start first. Running entries overlap from their start on.
+    fn list_entries_between(&self, start_ms: i64, end_ms: i64) -> RepoResult<Vec<TimeEntry>>;
+    /// Loads normalized tags per atom.
+    fn load_atom_tags(&self, atom_ids: &[AtomId]) -> RepoResult<HashMap<AtomId, Vec<String>>>;
+    /// Loads attributed workspace folders per atom as `(folder, path)`,
+    /// ancestors included; `path` joins display names with `/`.
+    fn load_atom_folders(
+        &self,
+        atom_ids: &[AtomId],
+    ) -> RepoResult<HashMap<AtomId, Vec<(WorkspaceNodeId, String)>>>;
+}
+
+/// SQLite-backed time entry repository.
+pub struct SqliteTimeEntryRepository<'conn> {
+    conn: &'conn Connection,
+    device_id: String,
+}
+
+impl<'conn> SqliteTimeEntryRepository<'conn> {
+    /// Constructs a repository recording timers for the local device.
+    ///
+    /// # Errors
+    /// - Returns [`RepoError::MissingRequiredTable`] when the `time_entries`
+    ///   table is absent.
+    pub fn try_new(conn: &'conn Connection) -> RepoResult<Self> {
+        let device_id = local_node_id(conn)?;
+        Self::try_new_for_device(conn, device_id)
+    }
+
+    /// Constructs a repository recording timers for `device_id`, e.g. when
+    /// applying entries received from another device.
+    ///
+    /// # Errors
+    /// - Returns [`RepoError::MissingRequiredTable`] when the `time_entries`
+    ///   table is absent.
+    pub fn try_new_for_device(
+        conn: &'conn Connection,
+        device_id: impl Into<String>,
+    ) -> RepoResult<Self> {
+        let exists: i64 = conn.query_row(
+            "SELECT EXISTS(
+                SELECT 1
+                FROM sqlite_master
+                WHERE type = 'table' AND name = 'time_entries'
+            );",
+            [],
+            |row| row.get(0),
+        )?;
+        if exists != 1 {
+            return Err(RepoError::MissingRequiredTable("time_entries"));
+        }
+        Ok(Self {
+            conn,
+            device_id: device_id.into(),
+        })
+    }
+
+    fn query_entries(
+        &self,
+        sql: &str,
+        params: impl rusqlite::Params,
+    ) -> RepoResult<Vec<TimeEntry>> {
+        let mut stmt = self.conn.prepare(sql)?;
+        let mut rows = stmt.query(params)?;
+        let mut entries = Vec::new();
+        while let Some(row) = rows.next()? {
+            entries.push(parse_time_entry_row(row)?);
+        }
+        Ok(entries)
+    }
+}
+
+impl TimeEntryRepository for SqliteTimeEntryRepository<'_> {
+    fn device_id(&self) -> &str {
+        &self.device_id
+    }
+
+    fn create_entry(&self, entry: &TimeEntry) -> RepoResult<()> {
+        let started_at = Instant::now();
+        let result = (|| -> RepoResult<()> {
+            let hlc = stamp_hlc(self.conn)?.to_string();
+            self.conn.execute(
+                "INSERT INTO time_entries (
+                    uuid,
+                    atom_uuid,
+                    device_id,
+                    started_at,
+                    ended_at,
+                    note,
+                    hlc_timestamp
+                 ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7);",
+                params![
+                    entry.id.to_string(),
+                    entry.atom_id.to_string(),
+                    entry.device_id,
+                    entry.started_at,
+                    entry.ended_at,
+                    entry.note,
+                    hlc,
+                ],
+            )?;
+            Ok(())
+        })();
+        log_time_entry_write("time_entry_create", entry, &result, started_at);
+        result
+    }
+
+    fn get_entry(&self, id: TimeEntryId) -> RepoResult<Option<TimeEntry>> {
+        let sql = format!("{TIME_ENTRY_SELECT_SQL} WHERE uuid = ?1;");
+        Ok(self.query_entries(&sql, [id.to_string()])?.pop())
+    }
+
+    fn update_entry(&self, entry: &TimeEntry) -> RepoResult<()> {
+        let started_at = Instant::now();
+        let result = (|| -> RepoResult<()> {
+            let hlc = stamp_hlc(self.conn)?.to_string();
+            let updated = self.conn.execute(
+                "UPDATE time_entries
+                 SET atom_uuid = ?2,
+                     started_at = ?3,
+                     ended_at = ?4,
+                     note = ?5,
+                     hlc_timestamp = ?6,
+                     updated_at = (strftime('%s', 'now') * 1000)
+                 WHERE uuid = ?1;",
+                params![
+                    entry.id.to_string(),
+                    entry.atom_id.to_string(),
+                    entry.started_at,
+                    entry.ended_at,
+                    entry.note,
+                    hlc
+                ],
+            )?;
+            if updated == 0 {
+                return Err(RepoError::NotFound(entry.id));
+            }
+            Ok(())
+        })();
+        log_time_entry_write("time_entry_update", entry, &result, started_at);
+        result
+    }
+
+    fn delete_entry(&self, id: TimeEntryId) -> RepoResult<bool> {
+        let removed = self.conn.execute(
+            "DELETE FROM time_entries WHERE uuid = ?1;",
+            [id.to_string()],
+        )?;
+        info!(
+            "event=time_entry_delete module=repo status=ok entry_id={} removed={}",
+            id,
+            removed > 0
+        );
+        Ok(removed > 0)
+    }
+
+    fn running_entry(&self, device_id: &str) -> RepoResult<Option<TimeEntry>> {
+        let sql = format!("{TIME_ENTRY_SELECT_SQL} WHERE device_id = ?1 AND ended_at IS NULL;");
+        Ok(self.query_entries(&sql, [device_id])?.pop())
+    }
+
+    fn list_atom_entries(&self, atom_id: AtomId) -> RepoResult<Vec<TimeEntry>> {
+        let sql = format!(
+            "{TIME_ENTRY_SELECT_SQL}
+             WHERE atom_uuid = ?1
+             ORDER BY started_at ASC, uuid ASC;"
+        );
+        self.query_entries(&sql, [atom_id.to_string()])
+    }
+
+    fn list_entries_between(&self, start_ms: i64, end_ms: i64) -> RepoResult<Vec<TimeEntry>> {
+        let sql = format!(
+            "{TIME_ENTRY_SELECT_SQL}
+             WHERE started_at < ?2
+               AND (ended_at IS NULL OR ended_at > ?1)
+               AND atom_uuid IN (SELECT uuid FROM atoms WHERE is_deleted = 0)
+             ORDER BY started_at ASC, uuid ASC;"
+        );
+        self.query_entries(&sql, params![start_ms, end_ms])
+    }
+
+    fn load_atom_tags(&self, atom_ids: &[AtomId]) -> RepoResult<HashMap<AtomId, Vec<String>>> {
+        let uuids: Vec<String> = atom_ids.iter().map(ToString::to_string).collect();
+        load_tags_for_atoms(self.conn, &uuids)?
+            .into_iter()
+            .map(|(uuid, tags)| Ok((parse_time_entry_uuid(&uuid)?, tags)))
+            .collect()
+    }
+
+    fn load_atom_folders(
+        &self,
+        atom_ids: &[AtomId],
+    ) -> RepoResult<HashMap<AtomId, Vec<(WorkspaceNodeId, String)>>> {
+        if atom_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let placeholders: Vec<&str> = atom_ids.iter().map(|_| "?").collect();
+        let sql = format!(
+            "WITH RECURSIVE
+             folder_paths(node_uuid, path) AS (
+                 SELECT node_uuid, display_name
+                 FROM workspace_nodes
+                 WHERE kind = 'folder' AND parent_uuid IS NULL AND is_deleted = 0
+                 UNION ALL
+                 SELECT child.node_uuid, folder_paths.path || '/' || child.display_name
+                 FROM workspace_nodes child
+                 JOIN folder_paths ON child.parent_uuid = folder_paths.node_uuid
+                 WHERE child.kind = 'folder' AND child.is_deleted = 0
+             ),
+             task_owners(task_uuid, owner_uuid) AS (
+                 SELECT uuid, uuid FROM atoms WHERE uuid IN ({})
+                 UNION
+                 SELECT task_owners.task_uuid, task_subtasks.parent_uuid
+                 FROM task_subtasks
+                 JOIN task_owners ON task_subtasks.child_uuid = task_owners.owner_uuid
+                 JOIN atoms parents ON parents.uuid = task_subtasks.parent_uuid
+                     AND parents.type = 'task'
+                     AND parents.is_deleted = 0
+             ),
+             task_folders(task_uuid, folder_uuid) AS (
+                 SELECT task_owners.task_uuid, refs.parent_uuid
+                 FROM task_owners
+                 JOIN atom_links ON atom_links.target_uuid = task_owners.owner_uuid
+                 JOIN atoms notes ON notes.uuid = atom_links.source_uuid
+                     AND notes.type = 'note'
+                     AND notes.is_deleted = 0
+                 JOIN workspace_nodes refs ON refs.atom_uuid = notes.uuid
+                     AND refs.kind = 'note_ref'
+                     AND refs.is_deleted = 0
+                 WHERE refs.parent_uuid IS NOT NULL
+                 UNION
+                 SELECT task_folders.task_uuid, folders.parent_uuid
+                 FROM task_folders
+                 JOIN workspace_nodes folders ON folders.node_uuid = task_folders.folder_uuid
+                 WHERE folders.parent_uuid IS NOT NULL
+             )
+             SELECT task_folders.task_uuid, folder_paths.node_uuid, folder_paths.path
+             FROM task_folders
+             JOIN folder_paths ON folder_paths.node_uuid = task_folders.folder_uuid
+             ORDER BY task_folders.task_uuid, folder_paths.path COLLATE NOCASE ASC;",
+            placeholders.join(", ")
+        );
+        let bind_values: Vec<Value> = atom_ids
+            .iter()
+            .map(|id| Value::Text(id.to_string()))
+            .collect();
+        let mut stmt = self.conn.prepare(&sql)?;
+        let mut rows = stmt.query(params_from_iter(bind_values))?;
+        let mut folders: HashMap<AtomId, Vec<(WorkspaceNodeId, String)>> = HashMap::new();
+        while let Some(row) = rows.next()? {
+            let task: String = row.get(0)?;
+            let folder: String = row.get(1)?;
+            folders
+                .entry(parse_time_entry_uuid(&task)?)
+                .or_default()
+                .push((parse_time_entry_uuid(&folder)?, row.get(2)?));
+        }
+        Ok(folders)
+    }
+}
+
+/// Stops the running entries of the current device on `atom_ids` at
+/// `ended_at` (never before their start).
+///
+/// Used by task status updates that finish tasks; returns the number of
+/// stopped entries.
+pub(crate) fn stop_atom_timers(
+    conn: &Connection,
+    atom_ids: &[AtomId],
+    ended_at: i64,
+) -> RepoResult<usize> {
+    let device_id = local_node_id(conn)?;
+    let mut stopped = 0;
+    for atom_id in atom_ids {
+        let hlc = stamp_hlc(conn)?.to_string();
+        stopped += conn.execute(
+            "UPDATE time_entries
+             SET ended_at = MAX(started_at, ?3),
+                 hlc_timestamp = ?4,
+                 updated_at = (strftime('%s', 'now') * 1000)
+             WHERE atom_uuid = ?1 AND device_id = ?2 AND ended_at IS NULL;",
+            params![atom_id.to_string(), device_id, ended_at, hlc],
+        )?;
+    }
+    if stopped > 0 {
+        info!(
+            "event=time_entry_auto_stop module=repo status=ok stopped={} ended_at={}",
+            stopped, ended_at
+        );
+    }
+    Ok(stopped)
+}
+
+fn parse_time_entry_row(row: &Row<'_>) -> RepoResult<TimeEntry> {
+    let id: String = row.get(0)?;
+    let atom_id: String = row.get(1)?;
+    Ok(TimeEntry {
+        id: parse_time_entry_uuid(&id)?,
+        atom_id: parse_time_entry_uuid(&atom_id)?,
+        device_id: row.get(2)?,
+        started_at: row.get(3)?,
+        ended_at: row.get(4)?,
+        note: row.get(5)?,
+    })
+}
+
+fn parse_time_entry_uuid(value: &str) -> RepoResult<Uuid> {
+    Uuid::parse_str(value).map_err(|_| {
+        RepoError::InvalidData(format!("invalid uuid value `{value}` in time_entries"))
+    })
+}
+
+fn log_time_entry_write(
+    event: &str,
+    entry: &TimeEntry,
+    result: &RepoResult<()>,
+    started_at: Instant,
+) {
+    match result {
+        Ok(()) => info!(
+            "event={} module=repo status=ok entry_id={} atom_id={} running={} duration_ms={}",
+            event,
+            entry.id,
+            entry.atom_id,
+            entry.is_running(),
+            started_at.elapsed().as_millis()
+        ),
+        Err(err) => error!(
+            "event={} module=repo status=error entry_id={} atom_id={} duration_ms={} error_code={}",
+            event,
+            entry.id,
+            entry.atom_id,
+            started_at.elapsed().as_millis(),
+            match err {
+                RepoError::NotFound(_) => "not_found",
+                _ => "db_write_failed",
+            }
+        ),
+    }
+}
@@ -0,0 +1,184 @@
+//! Note title projection persistence (`atom_titles`).
+//!
+//! # Responsibility
+//! - Refresh the content-derived title of an atom on content writes.
+//! - Store and clear explicit title overrides.
+//! - Push the effective title into workspace note_refs that follow it.
+//!
+//! # Invariants
+//! - Effective title = override, else derived title, else `Untitled note`.
+//! - Content writes never touch `title_override`.
+//! - Only active note_refs with `name_follows_title = 1` are renamed; the
+//!   rename reuses the atom's current HLC stamp and re-resolves `[[Title]]`
+//!   links.
+//!
+//! # See also
+//! - crates/lazynote_core/src/model/markdown.rs
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::AtomId;
+use crate::model::markdown::{derive_note_title, UNTITLED_NOTE_TITLE};
+use crate::repo::link_repo::reresolve_title_links;
+use rusqlite::{params, Connection, OptionalExtension};
+
+/// Effective title over an `atom_titles t` join.
+pub(crate) const EFFECTIVE_TITLE_SQL: &str =
+    "COALESCE(t.title_override, t.derived_title, 'Untitled note')";
+
+/// Title projection of one atom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteTitle {
+    /// Title shown for the atom: override, else derived, else `Untitled note`.
+    pub title: String,
+    /// First heading or first text line of the content, if any.
+    pub derived_title: Option<String>,
+    /// Explicit title set by the user, if any.
+    pub title_override: Option<String>,
+}
+
+impl NoteTitle {
+    fn new(derived_title: Option<String>, title_override: Option<String>) -> Self {
+        let title = title_override
+            .clone()
+            .or_else(|| derived_title.clone())
+            .unwrap_or_else(|| UNTITLED_NOTE_TITLE.to_string());
+        Self {
+            title,
+            derived_title,
+            title_override,
+        }
+    }
+}
+
+/// Refreshes the derived title of `atom_id` from `content` and renames the
+/// note_refs that follow it.
+pub(crate) fn sync_atom_title(
+    conn: &Connection,
+    atom_id: AtomId,
+    content: &str,
+) -> rusqlite::Result<()> {
+    upsert_derived_title(conn, atom_id, content)?;
+    let Some(title) = load_note_title(conn, atom_id)? else {
+        return Ok(());
+    };
+    let hlc: Option<String> = conn.query_row(
+        "SELECT hlc_timestamp FROM atoms WHERE uuid = ?1;",
+        [atom_id.to_string()],
+        |row| row.get(0),
+    )?;
+    apply_title_to_note_refs(conn, atom_id, &title.title, hlc.as_deref())?;
+    Ok(())
+}
+
+/// Writes the derived title of `atom_id`, keeping any override.
+pub(crate) fn upsert_derived_title(
+    conn: &Connection,
+    atom_id: AtomId,
+    content: &str,
+) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO atom_titles (atom_uuid, derived_title)
+         VALUES (?1, ?2)
+         ON CONFLICT(atom_uuid) DO UPDATE SET
+            derived_title = excluded.derived_title;",
+        params![atom_id.to_string(), derive_note_title(content)],
+    )?;
+    Ok(())
+}
+
+/// Loads the title projection of one atom; `None` when the atom is absent.
+///
+/// Atoms saved before the projection existed derive their title from
+/// content.
+pub(crate) fn load_note_title(
+    conn: &Connection,
+    atom_id: AtomId,
+) -> rusqlite::Result<Option<NoteTitle>> {
+    let row: Option<(String, bool, Option<String>, Option<String>)> = conn
+        .query_row(
+            "SELECT
+                a.content,
+                t.atom_uuid IS NOT NULL,
+                t.derived_title,
+                t.title_override
+             FROM atoms a
+             LEFT JOIN atom_titles t ON t.atom_uuid = a.uuid
+             WHERE a.uuid = ?1;",
+            [atom_id.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .optional()?;
+    Ok(
+        row.map(|(content, indexed, derived_title, title_override)| {
+            let derived_title = if indexed {
+                derived_title
+            } else {
+                derive_note_title(&content)
+            };
+            NoteTitle::new(derived_title, title_override)
+        }),
+    )
+}
+
+/// Sets (`Some`) or clears (`None`) the title override of `atom_id`, then
+/// renames following note_refs with `hlc`.
+pub(crate) fn set_title_override(
+    conn: &Connection,
+    atom_id: AtomId,
+    title_override: Option<&str>,
+    hlc: &str,
+) -> rusqlite::Result<Option<NoteTitle>> {
+    let content: Option<String> = conn
+        .query_row(
+            "SELECT content FROM atoms WHERE uuid = ?1;",
+            [atom_id.to_string()],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let Some(content) = content else {
+        return Ok(None);
+    };
+    conn.execute(
+        "INSERT INTO atom_titles (atom_uuid, derived_title, title_override)
+         VALUES (?1, ?2, ?3)
+         ON CONFLICT(atom_uuid) DO UPDATE SET
+            title_override = excluded.title_override;",
+        params![
+            atom_id.to_string(),
+            derive_note_title(&content),
+            title_override
+        ],
+    )?;
+    let title = load_note_title(conn, atom_id)?;
+    if let Some(title) = title.as_ref() {
+        apply_title_to_note_refs(conn, atom_id, &title.title, Some(hlc))?;
+    }
+    Ok(title)
+}
+
+/// Renames active title-following note_refs of `atom_id` to `title`.
+///
+/// Returns the number of renamed nodes.
+fn apply_title_to_note_refs(
+    conn: &Connection,
+    atom_id: AtomId,
+    title: &str,
+    hlc: Option<&str>,
+) -> rusqlite::Result<usize> {
+    let renamed = conn.execute(
+        "UPDATE workspace_nodes
+         SET display_name = ?2,
+             hlc_timestamp = COALESCE(?3, hlc_timestamp),
+             updated_at = (strftime('%s', 'now') * 1000)
+         WHERE atom_uuid = ?1
+           AND kind = 'note_ref'
+           AND name_follows_title = 1
+           AND is_deleted = 0
+           AND display_name <> ?2;",
+        params![atom_id.to_string(), title, hlc],
+    )?;
+    if renamed > 0 {
+        reresolve_title_links(conn)?;
+    }
+    Ok(renamed)
+}
@@ -0,0 +1,516 @@
+//! Trash lifecycle persistence (list, restore, purge) for soft-deleted atoms.
+//!
+//! # Responsibility
+//! - List soft-deleted atoms that can still be restored.
+//! - Restore one atom, re-creating its workspace `note_ref` when needed.
+//! - Permanently purge atoms while keeping tombstones sync still needs.
+//!
+//! # Invariants
+//! - Trash is `is_deleted = 1 AND purged_at IS NULL`; the tombstone's
+//!   `updated_at` is its deletion time.
+//! - Restore stamps a fresh HLC; a restored note always ends up with one
+//!   active `note_ref` (existing, revived, or new at root level).
+//! - Purge hard-deletes only when every `external_mappings` row synced the
+//!   tombstone (`last_synced_at >= updated_at`). Otherwise content and
+//!   derived rows are scrubbed and the row stays with `purged_at` set.
+//! - Hard deletes remove workspace nodes first because
+//!   `workspace_nodes.atom_uuid` does not cascade.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+//! - docs/architecture/adr/ADR-0002-trash-retention-purge.md
+
+use crate::model::atom::{AtomId, AtomType};
+use crate::model::markdown::UNTITLED_NOTE_TITLE;
+use crate::repo::atom_repo::{atom_type_to_db, parse_atom_type, RepoError, RepoResult};
+use crate::repo::link_repo::reresolve_title_links;
+use crate::repo::note_repo::prune_unused_tags;
+use crate::repo::title_repo::load_note_title;
+use crate::repo::tree_repo::WorkspaceNodeId;
+use crate::sync::hlc::stamp_hlc;
+use crate::sync::provider_types::now_epoch_ms;
+use log::{error, info};
+use rusqlite::types::Value;
+use rusqlite::{
+    params, params_from_iter, Connection, OptionalExtension, Transaction, TransactionBehavior,
+};
+use std::time::Instant;
+use uuid::Uuid;
+
+const TRASH_DEFAULT_LIMIT: u32 = 20;
+const TRASH_LIMIT_MAX: u32 = 100;
+
+/// True when some provider mapping has not synced the tombstone yet.
+const SYNC_PENDING_SQL: &str = "EXISTS (
+    SELECT 1
+    FROM external_mappings m
+    WHERE m.atom_uuid = atoms.uuid
+      AND (m.last_synced_at IS NULL OR m.last_synced_at < atoms.updated_at)
+)";
+
+/// One restorable soft-deleted atom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashItem {
+    /// Stable atom id.
+    pub atom_id: AtomId,
+    /// Atom kind.
+    pub kind: AtomType,
+    /// Raw content at deletion time.
+    pub content: String,
+    /// Derived preview text (notes only).
+    pub preview_text: Option<String>,
+    /// Deletion timestamp in epoch milliseconds.
+    pub deleted_at: i64,
+    /// Whether a provider mapping still has to sync the deletion; purging
+    /// such an atom keeps a scrubbed tombstone.
+    pub sync_pending: bool,
+}
+
+/// Result of purging one trashed atom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PurgeOutcome {
+    /// Row and all dependent rows were removed.
+    Purged,
+    /// Content was scrubbed; the tombstone row stays until sync catches up.
+    KeptForSync,
+}
+
+/// Counters for a batch purge.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PurgeSummary {
+    /// Atoms removed permanently.
+    pub purged: u32,
+    /// Atoms kept as scrubbed tombstones for pending sync.
+    pub kept_for_sync: u32,
+}
+
+/// Repository interface for trash use-cases.
+pub trait TrashRepository {
+    /// Lists restorable trashed atoms, most recently deleted first.
+    fn list_trash(
+        &self,
+        kind: Option<AtomType>,
+        limit: u32,
+        offset: u32,
+    ) -> RepoResult<Vec<TrashItem>>;
+    /// Restores one trashed atom.
+    ///
+    /// Returns the `note_ref` node that was revived or created for a note,
+    /// or `None` when an active reference already existed (or for
+    /// tasks/events). Returns [`RepoError::NotFound`] when the atom is not
+    /// in the trash.
+    fn restore_atom(&self, atom_id: AtomId) -> RepoResult<Option<WorkspaceNodeId>>;
+    /// Permanently purges one soft-deleted atom.
+    ///
+    /// Returns [`RepoError::NotFound`] for missing or active atoms.
+    fn purge_atom(&self, atom_id: AtomId) -> RepoResult<PurgeOutcome>;
+    /// Purges every soft-deleted atom deleted before `cutoff_ms`.
+    ///
+    /// Tombstones kept by an earlier purge are hard-deleted once their
+    /// mappings synced and skipped (not counted again) while still pending.
+    fn purge_deleted_before(&self, cutoff_ms: i64) -> RepoResult<PurgeSummary>;
+}
+
+/// SQLite-backed trash repository.
+pub struct SqliteTrashRepository<'conn> {
+    conn: &'conn Connection,
+}
+
+impl<'conn> SqliteTrashRepository<'conn> {
+    /// Constructs a repository from an existing SQLite connection.
+    ///
+    /// # Errors
+    /// - Returns [`RepoError::MissingRequiredColumn`] when `atoms.purged_at`
+    ///   is absent (connection not migrated).
+    pub fn try_new(conn: &'conn Connection) -> RepoResult<Self> {
+        let exists: i64 = conn.query_row(
+            "SELECT EXISTS(
+                SELECT 1
+                FROM pragma_table_info('atoms')
+                WHERE name = 'purged_at'
+            );",
+            [],
+            |row| row.get(0),
+        )?;
+        if exists != 1 {
+            return Err(RepoError::MissingRequiredColumn {
+                table: "atoms",
+                column: "purged_at",
+            });
+        }
+        Ok(Self { conn })
+    }
+}
+
+impl TrashRepository for SqliteTrashRepository<'_> {
+    fn list_trash(
+        &self,
+        kind: Option<AtomType>,
+        limit: u32,
+        offset: u32,
+    ) -> RepoResult<Vec<TrashItem>> {
+        let mut sql = format!(
+            "SELECT uuid, type, content, preview_text, updated_at, {SYNC_PENDING_SQL} AS sync_pending
+             FROM atoms
+             WHERE is_deleted = 1
+               AND purged_at IS NULL"
+        );
+        let mut bind_values = Vec::new();
+        if let Some(kind) = kind {
+            sql.push_str(" AND type = ?");
+            bind_values.push(Value::Text(atom_type_to_db(kind).to_string()));
+        }
+        sql.push_str(" ORDER BY updated_at DESC, uuid ASC LIMIT ? OFFSET ?;");
+        bind_values.push(Value::Integer(i64::from(limit)));
+        bind_values.push(Value::Integer(i64::from(offset)));
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let mut rows = stmt.query(params_from_iter(bind_values))?;
+        let mut items = Vec::new();
+        while let Some(row) = rows.next()? {
+            let uuid_text: String = row.get(0)?;
+            let type_text: String = row.get(1)?;
+            items.push(TrashItem {
+                atom_id: parse_trash_uuid(&uuid_text)?,
+                kind: parse_atom_type(&type_text).ok_or_else(|| {
+                    RepoError::InvalidData(format!("invalid atom type `{type_text}` in atoms.type"))
+                })?,
+                content: row.get(2)?,
+                preview_text: row.get(3)?,
+                deleted_at: row.get(4)?,
+                sync_pending: row.get(5)?,
+            });
+        }
+        Ok(items)
+    }
+
+    fn restore_atom(&self, atom_id: AtomId) -> RepoResult<Option<WorkspaceNodeId>> {
+        let started_at = Instant::now();
+        let result = (|| -> RepoResult<Option<WorkspaceNodeId>> {
+            let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+            let uuid = atom_id.to_string();
+            let kind: Option<String> = tx
+                .query_row(
+                    "SELECT type
+                     FROM atoms
+                     WHERE uuid = ?1
+                       AND is_deleted = 1
+                       AND purged_at IS NULL;",
+                    [&uuid],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            let Some(kind) = kind else {
+                return Err(RepoError::NotFound(atom_id));
+            };
+
+            let hlc = stamp_hlc(&tx)?.to_string();
+            tx.execute(
+                "UPDATE atoms
+                 SET is_deleted = 0,
+                     hlc_timestamp = ?2,
+                     updated_at = (strftime('%s', 'now') * 1000)
+                 WHERE uuid = ?1;",
+                params![uuid, hlc],
+            )?;
+            let note_ref = if parse_atom_type(&kind) == Some(AtomType::Note) {
+                ensure_active_note_ref(&tx, &uuid, &hlc)?
+            } else {
+                None
+            };
+            // Why: a restored note reclaims its title, so `[[Title]]` links
+            // may resolve back to it.
+            reresolve_title_links(&tx)?;
+            tx.commit()?;
+            Ok(note_ref)
+        })();
+
+        match &result {
+            Ok(note_ref) => info!(
+                "event=trash_restore module=repo status=ok atom_id={} note_ref_restored={} duration_ms={}",
+                atom_id,
+                note_ref.is_some(),
+                started_at.elapsed().as_millis()
+            ),
+            Err(err) => error!(
+                "event=trash_restore module=repo status=error atom_id={} duration_ms={} error_code={}",
+                atom_id,
+                started_at.elapsed().as_millis(),
+                error_code(err)
+            ),
+        }
+        result
+    }
+
+    fn purge_atom(&self, atom_id: AtomId) -> RepoResult<PurgeOutcome> {
+        let started_at = Instant::now();
+        let result = (|| -> RepoResult<PurgeOutcome> {
+            let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+            let uuid = atom_id.to_string();
+            let trashed: i64 = tx.query_row(
+                "SELECT EXISTS(SELECT 1 FROM atoms WHERE uuid = ?1 AND is_deleted = 1);",
+                [&uuid],
+                |row| row.get(0),
+            )?;
+            if trashed != 1 {
+                return Err(RepoError::NotFound(atom_id));
+            }
+            let outcome = purge_one(&tx, &uuid)?;
+            prune_unused_tags(&tx)?;
+            reresolve_title_links(&tx)?;
+            tx.commit()?;
+            Ok(outcome)
+        })();
+
+        match &result {
+            Ok(outcome) => info!(
+                "event=trash_purge module=repo status=ok atom_id={} kept_for_sync={} duration_ms={}",
+                atom_id,
+                *outcome == PurgeOutcome::KeptForSync,
+                started_at.elapsed().as_millis()
+            ),
+            Err(err) => error!(
+                "event=trash_purge module=repo status=error atom_id={} duration_ms={} error_code={}",
+                atom_id,
+                started_at.elapsed().as_millis(),
+                error_code(err)
+            ),
+        }
+        result
+    }
+
+    fn purge_deleted_before(&self, cutoff_ms: i64) -> RepoResult<PurgeSummary> {
+        let started_at = Instant::now();
+        let result = (|| -> RepoResult<PurgeSummary> {
+            let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+            let candidates: Vec<String> = {
+                let mut stmt = tx.prepare(&format!(
+                    "SELECT uuid
+                     FROM atoms
+                     WHERE is_deleted = 1
+                       AND updated_at < ?1
+                       AND (purged_at IS NULL OR NOT {SYNC_PENDING_SQL})
+                     ORDER BY updated_at ASC, uuid ASC;"
+                ))?;
+                let rows = stmt.query_map([cutoff_ms], |row| row.get(0))?;
+                rows.collect::<Result<_, _>>()?
+            };
+
+            let mut summary = PurgeSummary::default();
+            for uuid in &candidates {
+                match purge_one(&tx, uuid)? {
+                    PurgeOutcome::Purged => summary.purged += 1,
+                    PurgeOutcome::KeptForSync => summary.kept_for_sync += 1,
+                }
+            }
+            if !candidates.is_empty() {
+                prune_unused_tags(&tx)?;
+                reresolve_title_links(&tx)?;
+            }
+            tx.commit()?;
+            Ok(summary)
+        })();
+
+        match &result {
+            Ok(summary) => info!(
+                "event=trash_purge_expired module=repo status=ok purged={} kept_for_sync={} duration_ms={}",
+                summary.purged,
+                summary.kept_for_sync,
+                started_at.elapsed().as_millis()
+            ),
+            Err(err) => error!(
+                "event=trash_purge_expired module=repo status=error duration_ms={} error_code={}",
+                started_at.elapsed().as_millis(),
+                error_code(err)
+            ),
+        }
+        result
+    }
+}
+
+/// Normalizes trash list limit. Defaults to 20 and clamps to 100.
+pub fn normalize_trash_limit(limit: Option<u32>) -> u32 {
+    match limit {
+        Some(0) | None => TRASH_DEFAULT_LIMIT,
+        Some(value) => value.min(TRASH_LIMIT_MAX),
+    }
+}
+
+/// Makes sure the just-restored note `uuid` has one active note_ref.
+///
+/// Under the hybrid delete policy a note delete leaves its refs untouched,
+/// so usually nothing is needed. Refs removed by a folder `delete_all` are
+/// revived (moved to root when their folder is gone); a note that never had
+/// a ref gets a new root-level one named after (and following) its title.
+fn ensure_active_note_ref(
+    conn: &Connection,
+    uuid: &str,
+    hlc: &str,
+) -> RepoResult<Option<WorkspaceNodeId>> {
+    let active: i64 = conn.query_row(
+        "SELECT EXISTS(
+            SELECT 1
+            FROM workspace_nodes
+            WHERE kind = 'note_ref'
+              AND atom_uuid = ?1
+              AND is_deleted = 0
+        );",
+        [uuid],
+        |row| row.get(0),
+    )?;
+    if active == 1 {
+        return Ok(None);
+    }
+
+    let latest: Option<(String, bool)> = conn
+        .query_row(
+            "SELECT
+                n.node_uuid,
+                EXISTS(
+                    SELECT 1
+                    FROM workspace_nodes p
+                    WHERE p.node_uuid = n.parent_uuid
+                      AND p.kind = 'folder'
+                      AND p.is_deleted = 0
+                ) AS parent_active
+             FROM workspace_nodes n
+             WHERE n.kind = 'note_ref'
+               AND n.atom_uuid = ?1
+             ORDER BY n.updated_at DESC, n.node_uuid ASC
+             LIMIT 1;",
+            [uuid],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+
+    match latest {
+        Some((node_uuid, true)) => {
+            conn.execute(
+                "UPDATE workspace_nodes
+                 SET is_deleted = 0,
+                     hlc_timestamp = ?2,
+                     updated_at = (strftime('%s', 'now') * 1000)
+                 WHERE node_uuid = ?1;",
+                params![node_uuid, hlc],
+            )?;
+            Ok(Some(parse_trash_uuid(&node_uuid)?))
+        }
+        Some((node_uuid, false)) => {
+            conn.execute(
+                "UPDATE workspace_nodes
+                 SET is_deleted = 0,
+                     parent_uuid = NULL,
+                     sort_order = (
+                         SELECT COALESCE(MAX(sort_order) + 1, 0)
+                         FROM workspace_nodes
+                         WHERE parent_uuid IS NULL
+                           AND is_deleted = 0
+                     ),
+                     hlc_timestamp = ?2,
+                     updated_at = (strftime('%s', 'now') * 1000)
+                 WHERE node_uuid = ?1;",
+                params![node_uuid, hlc],
+            )?;
+            Ok(Some(parse_trash_uuid(&node_uuid)?))
+        }
+        None => {
+            let node_uuid = Uuid::new_v4();
+            let title = load_note_title(conn, parse_trash_uuid(uuid)?)?
+                .map(|title| title.title)
+                .unwrap_or_else(|| UNTITLED_NOTE_TITLE.to_string());
+            conn.execute(
+                "INSERT INTO workspace_nodes (
+                    node_uuid,
+                    kind,
+                    parent_uuid,
+                    atom_uuid,
+                    display_name,
+                    sort_order,
+                    is_deleted,
+                    hlc_timestamp,
+                    name_follows_title
+                )
+                SELECT ?1, 'note_ref', NULL, ?2, ?3, COALESCE(MAX(sort_order) + 1, 0), 0, ?4, 1
+                FROM workspace_nodes
+                WHERE parent_uuid IS NULL
+                  AND is_deleted = 0;",
+                params![node_uuid.to_string(), uuid, title, hlc],
+            )?;
+            Ok(Some(node_uuid))
+        }
+    }
+}
+
+/// Purges one soft-deleted atom, keeping a scrubbed tombstone when a
+/// provider mapping has not synced the deletion yet.
+fn purge_one(conn: &Connection, uuid: &str) -> RepoResult<PurgeOutcome> {
+    let sync_pending: bool = conn.query_row(
+        &format!("SELECT {SYNC_PENDING_SQL} FROM atoms WHERE uuid = ?1;"),
+        [uuid],
+        |row| row.get(0),
+    )?;
+
+    // Derived rows go in both cases; the tombstone only needs its identity.
+    conn.execute("DELETE FROM workspace_nodes WHERE atom_uuid = ?1;", [uuid])?;
+    conn.execute("DELETE FROM atom_revisions WHERE atom_uuid = ?1;", [uuid])?;
+    conn.execute("DELETE FROM atom_tags WHERE atom_uuid = ?1;", [uuid])?;
+    conn.execute("DELETE FROM atom_links WHERE source_uuid = ?1;", [uuid])?;
+    conn.execute("DELETE FROM links WHERE atom_uuid = ?1;", [uuid])?;
+    conn.execute("DELETE FROM atom_attachments WHERE atom_uuid = ?1;", [uuid])?;
+    conn.execute("DELETE FROM atom_properties WHERE atom_uuid = ?1;", [uuid])?;
+    conn.execute(
+        "DELETE FROM atom_content_stats WHERE atom_uuid = ?1;",
+        [uuid],
+    )?;
+    conn.execute("DELETE FROM atom_titles WHERE atom_uuid = ?1;", [uuid])?;
+    conn.execute(
+        "DELETE FROM task_subtasks WHERE child_uuid = ?1 OR parent_uuid = ?1;",
+        [uuid],
+    )?;
+    conn.execute(
+        "DELETE FROM task_dependencies WHERE task_uuid = ?1 OR depends_on_uuid = ?1;",
+        [uuid],
+    )?;
+    conn.execute("DELETE FROM reminders WHERE atom_uuid = ?1;", [uuid])?;
+    conn.execute("DELETE FROM time_entries WHERE atom_uuid = ?1;", [uuid])?;
+    conn.execute(
+        "DELETE FROM recurrence_exceptions WHERE series_uuid = ?1;",
+        [uuid],
+    )?;
+    // Why: mirrors the `ON DELETE SET NULL` foreign key, so a tombstone that
+    // keeps its row still leaves the replaced occurrence skipped.
+    conn.execute(
+        "UPDATE recurrence_exceptions SET override_uuid = NULL WHERE override_uuid = ?1;",
+        [uuid],
+    )?;
+
+    if sync_pending {
+        // Why: `updated_at` is left alone so the tombstone still compares as
+        // newer than the mapping's `last_synced_at` until sync pushes it.
+        conn.execute(
+            "UPDATE atoms
+             SET content = '',
+                 preview_text = NULL,
+                 preview_image = NULL,
+                 purged_at = COALESCE(purged_at, ?2)
+             WHERE uuid = ?1;",
+            params![uuid, now_epoch_ms()],
+        )?;
+        return Ok(PurgeOutcome::KeptForSync);
+    }
+
+    conn.execute("DELETE FROM atoms WHERE uuid = ?1;", [uuid])?;
+    Ok(PurgeOutcome::Purged)
+}
+
+fn parse_trash_uuid(value: &str) -> RepoResult<Uuid> {
+    Uuid::parse_str(value)
+        .map_err(|_| RepoError::InvalidData(format!("invalid uuid value `{value}`")))
+}
+
+fn error_code(err: &RepoError) -> &'static str {
+    match err {
+        RepoError::NotFound(_) => "not_found",
+        _ => "db_write_failed",
+    }
+}
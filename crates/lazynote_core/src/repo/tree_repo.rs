@@ -8,10 +8,17 @@
 //! - Only active (`is_deleted=0`) nodes are returned by default.
 //! - Child listing is deterministic: `sort_order ASC, node_uuid ASC`.
 //! - `note_ref` targets must point to active note atoms.
+//! - Every write stamps one HLC per operation on all rows it touches.
+//! - note_ref name changes re-resolve `[[Title]]` links (`atom_links`).
+//! - note_refs created without a name follow the note title until renamed.
 
 use crate::db::migrations::latest_version;
 use crate::db::DbError;
 use crate::model::atom::{AtomId, AtomType};
+use crate::model::markdown::UNTITLED_NOTE_TITLE;
+use crate::repo::link_repo::reresolve_title_links;
+use crate::repo::title_repo::{load_note_title, upsert_derived_title, EFFECTIVE_TITLE_SQL};
+use crate::sync::hlc::{stamp_hlc, HlcError};
 use rusqlite::{params, Connection, OptionalExtension, Row, Transaction, TransactionBehavior};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
@@ -99,6 +106,15 @@ impl From<rusqlite::Error> for TreeRepoError {
     }
 }
 
+impl From<HlcError> for TreeRepoError {
+    fn from(value: HlcError) -> Self {
+        match value {
+            HlcError::Db(err) => Self::Db(err),
+            other => Self::InvalidData(other.to_string()),
+        }
+    }
+}
+
 /// Workspace tree node kind.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WorkspaceNodeKind {
@@ -129,6 +145,10 @@ pub struct WorkspaceNode {
     pub created_at: i64,
     /// Epoch ms update timestamp.
     pub updated_at: i64,
+    /// Encoded HLC stamp of the last write; `None` for legacy rows.
+    pub hlc_timestamp: Option<String>,
+    /// note_ref name tracks the note title until renamed manually.
+    pub name_follows_title: bool,
 }
 
 /// Repository interface for workspace tree operations.
@@ -139,12 +159,13 @@ pub trait TreeRepository {
         parent_uuid: Option<WorkspaceNodeId>,
         display_name: &str,
     ) -> TreeRepoResult<WorkspaceNode>;
-    /// Creates one note_ref node.
+    /// Creates one note_ref node; `None` names it after the note title and
+    /// keeps it following title changes.
     fn create_note_ref(
         &self,
         parent_uuid: Option<WorkspaceNodeId>,
         atom_uuid: AtomId,
-        display_name: &str,
+        display_name: Option<&str>,
     ) -> TreeRepoResult<WorkspaceNode>;
     /// Loads one node by id.
     fn get_node(
@@ -158,7 +179,7 @@ pub trait TreeRepository {
         parent_uuid: Option<WorkspaceNodeId>,
         include_deleted: bool,
     ) -> TreeRepoResult<Vec<WorkspaceNode>>;
-    /// Renames one node.
+    /// Renames one node; a renamed note_ref stops following its note title.
     fn rename_node(&self, node_uuid: WorkspaceNodeId, display_name: &str) -> TreeRepoResult<()>;
     /// Moves one node to another parent and optional target order.
     fn move_node(
@@ -173,6 +194,11 @@ pub trait TreeRepository {
     fn delete_folder_delete_all(&self, folder_uuid: WorkspaceNodeId) -> TreeRepoResult<()>;
     /// Loads atom type for active atom, if present.
     fn atom_kind(&self, atom_uuid: AtomId) -> TreeRepoResult<Option<AtomType>>;
+    /// Backfills missing title projections and renames every title-following
+    /// note_ref whose name is stale (legacy `Untitled note` rows).
+    ///
+    /// Returns the number of renamed nodes.
+    fn repair_note_titles(&self) -> TreeRepoResult<u32>;
 }
 
 /// SQLite-backed workspace tree repository.
@@ -204,13 +230,15 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                 atom_uuid,
                 display_name,
                 sort_order,
-                is_deleted
-            ) VALUES (?1, 'folder', ?2, NULL, ?3, ?4, 0);",
+                is_deleted,
+                hlc_timestamp
+            ) VALUES (?1, 'folder', ?2, NULL, ?3, ?4, 0, ?5);",
             params![
                 node_uuid.to_string(),
                 parent_uuid.map(|value| value.to_string()),
                 display_name,
                 sort_order,
+                stamp_hlc(self.conn)?.to_string(),
             ],
         )?;
         load_required_node(self.conn, node_uuid)
@@ -220,10 +248,19 @@ impl TreeRepository for SqliteTreeRepository<'_> {
         &self,
         parent_uuid: Option<WorkspaceNodeId>,
         atom_uuid: AtomId,
-        display_name: &str,
+        display_name: Option<&str>,
     ) -> TreeRepoResult<WorkspaceNode> {
         let node_uuid = Uuid::new_v4();
         let sort_order = next_sort_order(self.conn, parent_uuid)?;
+        let (display_name, follows_title) = match display_name {
+            Some(name) => (name.to_string(), false),
+            None => (
+                load_note_title(self.conn, atom_uuid)?
+                    .map(|title| title.title)
+                    .unwrap_or_else(|| UNTITLED_NOTE_TITLE.to_string()),
+                true,
+            ),
+        };
         self.conn.execute(
             "INSERT INTO workspace_nodes (
                 node_uuid,
@@ -232,16 +269,21 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                 atom_uuid,
                 display_name,
                 sort_order,
-                is_deleted
-            ) VALUES (?1, 'note_ref', ?2, ?3, ?4, ?5, 0);",
+                is_deleted,
+                hlc_timestamp,
+                name_follows_title
+            ) VALUES (?1, 'note_ref', ?2, ?3, ?4, ?5, 0, ?6, ?7);",
             params![
                 node_uuid.to_string(),
                 parent_uuid.map(|value| value.to_string()),
                 atom_uuid.to_string(),
                 display_name,
                 sort_order,
+                stamp_hlc(self.conn)?.to_string(),
+                follows_title,
             ],
         )?;
+        reresolve_title_links(self.conn)?;
         load_required_node(self.conn, node_uuid)
     }
 
@@ -260,7 +302,9 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                 sort_order,
                 is_deleted,
                 created_at,
-                updated_at
+                updated_at,
+                hlc_timestamp,
+                name_follows_title
              FROM workspace_nodes
              WHERE node_uuid = ?1;"
         } else {
@@ -273,7 +317,9 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                 n.sort_order AS sort_order,
                 n.is_deleted AS is_deleted,
                 n.created_at AS created_at,
-                n.updated_at AS updated_at
+                n.updated_at AS updated_at,
+                n.hlc_timestamp AS hlc_timestamp,
+                n.name_follows_title AS name_follows_title
              FROM workspace_nodes n
              LEFT JOIN atoms a ON a.uuid = n.atom_uuid
              WHERE n.node_uuid = ?1
@@ -307,7 +353,9 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                     sort_order,
                     is_deleted,
                     created_at,
-                    updated_at
+                    updated_at,
+                    hlc_timestamp,
+                    name_follows_title
                  FROM workspace_nodes
                  WHERE parent_uuid = ?1
                  ORDER BY sort_order ASC, node_uuid ASC;"
@@ -322,7 +370,9 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                     sort_order,
                     is_deleted,
                     created_at,
-                    updated_at
+                    updated_at,
+                    hlc_timestamp,
+                    name_follows_title
                  FROM workspace_nodes
                  WHERE parent_uuid IS NULL
                  ORDER BY sort_order ASC, node_uuid ASC;"
@@ -337,7 +387,9 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                     n.sort_order AS sort_order,
                     n.is_deleted AS is_deleted,
                     n.created_at AS created_at,
-                    n.updated_at AS updated_at
+                    n.updated_at AS updated_at,
+                    n.hlc_timestamp AS hlc_timestamp,
+                    n.name_follows_title AS name_follows_title
                  FROM workspace_nodes n
                  LEFT JOIN atoms a ON a.uuid = n.atom_uuid
                  WHERE n.parent_uuid = ?1
@@ -358,7 +410,9 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                     n.sort_order AS sort_order,
                     n.is_deleted AS is_deleted,
                     n.created_at AS created_at,
-                    n.updated_at AS updated_at
+                    n.updated_at AS updated_at,
+                    n.hlc_timestamp AS hlc_timestamp,
+                    n.name_follows_title AS name_follows_title
                  FROM workspace_nodes n
                  LEFT JOIN atoms a ON a.uuid = n.atom_uuid
                  WHERE n.parent_uuid IS NULL
@@ -385,17 +439,21 @@ impl TreeRepository for SqliteTreeRepository<'_> {
     }
 
     fn rename_node(&self, node_uuid: WorkspaceNodeId, display_name: &str) -> TreeRepoResult<()> {
+        let hlc = stamp_hlc(self.conn)?.to_string();
         let changed = self.conn.execute(
             "UPDATE workspace_nodes
              SET display_name = ?2,
+                 name_follows_title = 0,
+                 hlc_timestamp = ?3,
                  updated_at = (strftime('%s', 'now') * 1000)
              WHERE node_uuid = ?1
                AND is_deleted = 0;",
-            params![node_uuid.to_string(), display_name],
+            params![node_uuid.to_string(), display_name, hlc],
         )?;
         if changed == 0 {
             return Err(TreeRepoError::NodeNotFound(node_uuid));
         }
+        reresolve_title_links(self.conn)?;
         Ok(())
     }
 
@@ -417,16 +475,19 @@ impl TreeRepository for SqliteTreeRepository<'_> {
             .unwrap_or(sibling_ids.len() as i64)
             .clamp(0, sibling_ids.len() as i64) as usize;
         sibling_ids.insert(target_index, node_uuid);
+        let hlc = stamp_hlc(&tx)?.to_string();
 
         tx.execute(
             "UPDATE workspace_nodes
              SET parent_uuid = ?2,
+                 hlc_timestamp = ?3,
                  updated_at = (strftime('%s', 'now') * 1000)
              WHERE node_uuid = ?1
                AND is_deleted = 0;",
             params![
                 node_uuid.to_string(),
                 new_parent_uuid.map(|value| value.to_string()),
+                hlc,
             ],
         )?;
 
@@ -434,10 +495,11 @@ impl TreeRepository for SqliteTreeRepository<'_> {
             tx.execute(
                 "UPDATE workspace_nodes
                  SET sort_order = ?2,
+                     hlc_timestamp = ?3,
                      updated_at = (strftime('%s', 'now') * 1000)
                  WHERE node_uuid = ?1
                    AND is_deleted = 0;",
-                params![id.to_string(), index as i64],
+                params![id.to_string(), index as i64, hlc],
             )?;
         }
 
@@ -451,26 +513,29 @@ impl TreeRepository for SqliteTreeRepository<'_> {
 
         let children = list_active_child_ids(&tx, Some(folder_uuid))?;
         let base_order = next_sort_order(&tx, None)?;
+        let hlc = stamp_hlc(&tx)?.to_string();
         for (index, child_uuid) in children.into_iter().enumerate() {
             tx.execute(
                 "UPDATE workspace_nodes
                  SET parent_uuid = NULL,
                      sort_order = ?2,
+                     hlc_timestamp = ?3,
                      updated_at = (strftime('%s', 'now') * 1000)
                  WHERE node_uuid = ?1
                    AND is_deleted = 0;",
-                params![child_uuid.to_string(), base_order + index as i64],
+                params![child_uuid.to_string(), base_order + index as i64, hlc],
             )?;
         }
 
         tx.execute(
             "UPDATE workspace_nodes
              SET is_deleted = 1,
+                 hlc_timestamp = ?2,
                  updated_at = (strftime('%s', 'now') * 1000)
              WHERE node_uuid = ?1
                AND kind = 'folder'
                AND is_deleted = 0;",
-            [folder_uuid.to_string()],
+            params![folder_uuid.to_string(), hlc],
         )?;
 
         tx.commit()?;
@@ -482,7 +547,8 @@ impl TreeRepository for SqliteTreeRepository<'_> {
         ensure_active_folder_exists(&tx, folder_uuid)?;
 
         let referenced_atoms = list_referenced_note_atoms_in_subtree(&tx, folder_uuid)?;
-        soft_delete_workspace_subtree(&tx, folder_uuid)?;
+        let hlc = stamp_hlc(&tx)?.to_string();
+        soft_delete_workspace_subtree(&tx, folder_uuid, &hlc)?;
 
         for atom_uuid in referenced_atoms {
             let has_other_active_refs: i64 = tx.query_row(
@@ -503,13 +569,15 @@ impl TreeRepository for SqliteTreeRepository<'_> {
             tx.execute(
                 "UPDATE atoms
                  SET is_deleted = 1,
+                     hlc_timestamp = ?2,
                      updated_at = (strftime('%s', 'now') * 1000)
                  WHERE uuid = ?1
                    AND type = 'note'
                    AND is_deleted = 0;",
-                [atom_uuid.to_string()],
+                params![atom_uuid.to_string(), hlc],
             )?;
         }
+        reresolve_title_links(&tx)?;
 
         tx.commit()?;
         Ok(())
@@ -538,6 +606,53 @@ impl TreeRepository for SqliteTreeRepository<'_> {
             ))),
         }
     }
+
+    fn repair_note_titles(&self) -> TreeRepoResult<u32> {
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let missing: Vec<(String, String)> = {
+            let mut stmt = tx.prepare(
+                "SELECT a.uuid, a.content
+                 FROM atoms a
+                 LEFT JOIN atom_titles t ON t.atom_uuid = a.uuid
+                 WHERE t.atom_uuid IS NULL;",
+            )?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            rows.collect::<Result<_, _>>()?
+        };
+        for (uuid, content) in &missing {
+            upsert_derived_title(&tx, parse_uuid(uuid, "atoms.uuid")?, content)?;
+        }
+
+        let stale: Vec<(String, String)> = {
+            let mut stmt = tx.prepare(&format!(
+                "SELECT n.node_uuid, {EFFECTIVE_TITLE_SQL}
+                 FROM workspace_nodes n
+                 INNER JOIN atom_titles t ON t.atom_uuid = n.atom_uuid
+                 WHERE n.kind = 'note_ref'
+                   AND n.name_follows_title = 1
+                   AND n.is_deleted = 0
+                   AND n.display_name <> {EFFECTIVE_TITLE_SQL};"
+            ))?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            rows.collect::<Result<_, _>>()?
+        };
+        if !stale.is_empty() {
+            let hlc = stamp_hlc(&tx)?.to_string();
+            for (node_uuid, title) in &stale {
+                tx.execute(
+                    "UPDATE workspace_nodes
+                     SET display_name = ?2,
+                         hlc_timestamp = ?3,
+                         updated_at = (strftime('%s', 'now') * 1000)
+                     WHERE node_uuid = ?1;",
+                    params![node_uuid, title, hlc],
+                )?;
+            }
+            reresolve_title_links(&tx)?;
+        }
+        tx.commit()?;
+        Ok(stale.len() as u32)
+    }
 }
 
 fn load_required_node(
@@ -554,7 +669,9 @@ fn load_required_node(
             sort_order,
             is_deleted,
             created_at,
-            updated_at
+            updated_at,
+            hlc_timestamp,
+            name_follows_title
          FROM workspace_nodes
          WHERE node_uuid = ?1
            AND is_deleted = 0;",
@@ -727,6 +844,7 @@ fn list_referenced_note_atoms_in_subtree(
 fn soft_delete_workspace_subtree(
     conn: &Connection,
     folder_uuid: WorkspaceNodeId,
+    hlc: &str,
 ) -> TreeRepoResult<()> {
     conn.execute(
         "WITH RECURSIVE subtree(node_uuid) AS (
@@ -742,10 +860,11 @@ fn soft_delete_workspace_subtree(
         )
         UPDATE workspace_nodes
         SET is_deleted = 1,
+            hlc_timestamp = ?2,
             updated_at = (strftime('%s', 'now') * 1000)
         WHERE node_uuid IN (SELECT node_uuid FROM subtree)
           AND is_deleted = 0;",
-        [folder_uuid.to_string()],
+        params![folder_uuid.to_string(), hlc],
     )?;
     Ok(())
 }
@@ -790,6 +909,8 @@ fn parse_workspace_node_row(row: &Row<'_>) -> TreeRepoResult<WorkspaceNode> {
         is_deleted,
         created_at: row.get("created_at")?,
         updated_at: row.get("updated_at")?,
+        hlc_timestamp: row.get("hlc_timestamp")?,
+        name_follows_title: row.get("name_follows_title")?,
     })
 }
 
@@ -830,6 +951,8 @@ fn ensure_tree_connection_ready(conn: &Connection) -> TreeRepoResult<()> {
         "is_deleted",
         "created_at",
         "updated_at",
+        "hlc_timestamp",
+        "name_follows_title",
     ] {
         if !table_has_column(conn, "workspace_nodes", column)? {
             return Err(TreeRepoError::MissingRequiredColumn {
@@ -4,12 +4,31 @@
 //! - Provide keyword search over atom content.
 //! - Return typed hits with stable IDs.
 //!
+//! - Pick a match strategy per query so CJK text is searchable by
+//!   substring (see [`select_search_strategy`]).
+//! - Compile the structured query grammar ([`super::query`]) into FTS
+//!   MATCH plus SQL predicates.
+//! - Page results with opaque keyset cursors and expose match ranges as
+//!   structured offsets instead of inline markers.
+//!
 //! # Invariants
 //! - Only non-deleted atoms are returned.
-//! - Result ordering is deterministic by rank and `updated_at`.
-
+//! - Result ordering is deterministic: `score DESC, updated_at DESC, uuid ASC`.
+//! - With default [`SearchBoost`] the score is `-bm25`, so ranking matches
+//!   plain bm25 order.
+//! - Snippets are plain text; highlights are offsets into the snippet.
+//! - Queries without CJK characters keep unicode61 token semantics.
+
+use super::query::{
+    parse_search_query, FilterKind, ParsedQuery, PropertyComparison, QueryFilter, QueryParseError,
+};
 use crate::db::DbError;
 use crate::model::atom::{AtomId, AtomType};
+use crate::model::frontmatter::date_epoch_ms;
+use crate::model::tag_expr::TagExpr;
+use crate::repo::atom_repo::{atom_type_to_db, parse_atom_type, task_status_to_db};
+use crate::repo::note_repo::tag_expr_predicate;
+use crate::sync::provider_types::now_epoch_ms;
 use log::{error, info};
 use rusqlite::types::Value;
 use rusqlite::{params_from_iter, Connection, Row};
@@ -29,6 +48,10 @@ pub enum SearchError {
         query: String,
         message: String,
     },
+    /// Structured query grammar rejected the input; carries char offsets.
+    InvalidSyntax(QueryParseError),
+    /// Pagination cursor is malformed.
+    InvalidCursor(String),
     Db(DbError),
     InvalidData(String),
 }
@@ -39,6 +62,8 @@ impl Display for SearchError {
             Self::InvalidQuery { query, message } => {
                 write!(f, "invalid full-text query `{query}`: {message}")
             }
+            Self::InvalidSyntax(err) => write!(f, "invalid search syntax: {err}"),
+            Self::InvalidCursor(cursor) => write!(f, "invalid search cursor `{cursor}`"),
             Self::Db(err) => write!(f, "{err}"),
             Self::InvalidData(message) => write!(f, "invalid search row: {message}"),
         }
@@ -49,6 +74,8 @@ impl Error for SearchError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::InvalidQuery { .. } => None,
+            Self::InvalidSyntax(err) => Some(err),
+            Self::InvalidCursor(_) => None,
             Self::Db(err) => Some(err),
             Self::InvalidData(_) => None,
         }
@@ -78,8 +105,20 @@ pub struct SearchQuery {
     pub limit: u32,
     /// Whether to pass text directly as raw FTS5 expression.
     ///
-    /// Default is `false` to protect type-as-you-search UX from syntax errors.
+    /// Default is `false`: text is parsed with the structured query grammar
+    /// (`tag:`, `type:`, `status:`, `before:`, `after:`, `in:`, `prop:`, `-term`,
+    /// `"phrase"`) and every term is escaped before reaching FTS5.
     pub raw_fts_syntax: bool,
+    /// Opaque cursor from [`SearchPage::next_cursor`]; `None` starts at the
+    /// first page.
+    ///
+    /// A cursor is only meaningful for the query text, filters and boost it
+    /// was issued for.
+    pub cursor: Option<String>,
+    /// Ranking knobs applied on top of bm25.
+    pub boost: SearchBoost,
+    /// Optional boolean tag filter; ANDed with any `tag:`/`tags:` filters.
+    pub tag_expr: Option<TagExpr>,
 }
 
 impl SearchQuery {
@@ -90,37 +129,206 @@ impl SearchQuery {
             kind: None,
             limit: 20,
             raw_fts_syntax: false,
+            cursor: None,
+            boost: SearchBoost::default(),
+            tag_expr: None,
+        }
+    }
+}
+
+/// Ranking knobs for [`SearchQuery`].
+///
+/// `score = relevance * type_weight * (1 + recency_weight * h / (h + age_days))`
+/// where `relevance` is `-bm25` for MATCH queries and `1.0` otherwise, and
+/// `h` is [`Self::recency_half_life_days`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SearchBoost {
+    /// Extra weight for recently updated atoms; `0` disables recency boost.
+    pub recency_weight: f64,
+    /// Age (days) at which the recency boost has halved.
+    pub recency_half_life_days: f64,
+    /// Score multiplier for notes.
+    pub note_weight: f64,
+    /// Score multiplier for tasks.
+    pub task_weight: f64,
+    /// Score multiplier for events.
+    pub event_weight: f64,
+}
+
+impl Default for SearchBoost {
+    fn default() -> Self {
+        Self {
+            recency_weight: 0.0,
+            recency_half_life_days: DEFAULT_RECENCY_HALF_LIFE_DAYS,
+            note_weight: 1.0,
+            task_weight: 1.0,
+            event_weight: 1.0,
+        }
+    }
+}
+
+impl SearchBoost {
+    /// Replaces non-finite or out-of-range knobs with safe values.
+    ///
+    /// Weights clamp to `[0, MAX_BOOST_WEIGHT]`; type weights of `0` fall
+    /// back to `1` so no type silently disappears from results.
+    fn normalized(self) -> Self {
+        let clamp = |value: f64, fallback: f64| {
+            if value.is_finite() {
+                value.clamp(0.0, MAX_BOOST_WEIGHT)
+            } else {
+                fallback
+            }
+        };
+        let type_weight = |value: f64| match clamp(value, 1.0) {
+            weight if weight > 0.0 => weight,
+            _ => 1.0,
+        };
+        Self {
+            recency_weight: clamp(self.recency_weight, 0.0),
+            recency_half_life_days: match self.recency_half_life_days {
+                days if days.is_finite() && days > 0.0 => days,
+                _ => DEFAULT_RECENCY_HALF_LIFE_DAYS,
+            },
+            note_weight: type_weight(self.note_weight),
+            task_weight: type_weight(self.task_weight),
+            event_weight: type_weight(self.event_weight),
+        }
+    }
+}
+
+/// Default half-life for [`SearchBoost::recency_half_life_days`].
+const DEFAULT_RECENCY_HALF_LIFE_DAYS: f64 = 30.0;
+/// Upper bound for any [`SearchBoost`] weight.
+const MAX_BOOST_WEIGHT: f64 = 100.0;
+/// Private-use chars FTS5 wraps matches with; stripped before returning.
+const HIGHLIGHT_OPEN: char = '\u{E000}';
+const HIGHLIGHT_CLOSE: char = '\u{E001}';
+
+/// Minimum term length (in chars) the trigram tokenizer can match.
+const TRIGRAM_MIN_CHARS: usize = 3;
+/// Context chars kept on each side of a substring snippet match.
+const SUBSTRING_SNIPPET_CONTEXT_CHARS: usize = 10;
+
+/// Match strategy selected per query by [`select_search_strategy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchStrategy {
+    /// unicode61 token match over `atoms_fts`.
+    ///
+    /// Used for raw FTS syntax and queries without CJK characters.
+    Token,
+    /// Trigram substring match over `atoms_fts_trigram`; every term has at
+    /// least 3 characters.
+    Trigram,
+    /// Trigram match for long terms plus `LIKE` for terms shorter than
+    /// 3 characters (e.g. 2-character Chinese words).
+    Substring,
+}
+
+impl SearchStrategy {
+    /// Returns the stable label used in logs.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Token => "token",
+            Self::Trigram => "trigram",
+            Self::Substring => "substring",
         }
     }
 }
 
+/// Matched span inside [`SearchHit::snippet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HighlightRange {
+    /// Start byte offset (inclusive).
+    pub start: usize,
+    /// End byte offset (exclusive).
+    pub end: usize,
+    /// Start char offset (inclusive).
+    pub char_start: usize,
+    /// End char offset (exclusive).
+    pub char_end: usize,
+}
+
 /// Single search hit returned by [`search_all`].
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SearchHit {
     pub atom_id: AtomId,
     pub kind: AtomType,
+    /// Plain-text excerpt around the first match.
     pub snippet: String,
+    /// Matched spans inside `snippet`, in order.
+    pub highlights: Vec<HighlightRange>,
+    /// Raw FTS5 bm25 value (lower is better); `None` without a MATCH term.
+    pub bm25: Option<f64>,
+    /// Final ranking score after boosts (higher is better).
+    pub score: f64,
+}
+
+/// One page of search hits.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SearchPage {
+    /// Hits in ranking order.
+    pub hits: Vec<SearchHit>,
+    /// Cursor for the following page; `None` on the last page.
+    pub next_cursor: Option<String>,
 }
 
 /// Searches atoms via FTS5 and returns ranked results.
 ///
-/// Returns an empty list for blank queries.
+/// Returns an empty list for blank queries. Equivalent to
+/// [`search_page`] without the next-page cursor.
+///
+/// # Errors
+/// - Same as [`search_page`].
+pub fn search_all(conn: &Connection, query: &SearchQuery) -> SearchResult<Vec<SearchHit>> {
+    search_page(conn, query).map(|page| page.hits)
+}
+
+/// Searches atoms and returns one page plus the cursor for the next one.
+///
+/// Returns an empty page for blank queries.
 ///
 /// # Privacy
 /// - Query text content is never written to logs.
 /// - Logging emits metadata only (`query_len`, `query_terms`, flags, duration).
 ///
 /// # Errors
+/// - Returns [`SearchError::InvalidSyntax`] when the structured grammar
+///   rejects the text (not raised for raw FTS syntax).
+/// - Returns [`SearchError::InvalidCursor`] for malformed cursors.
 /// - Returns [`SearchError::InvalidQuery`] for malformed raw FTS syntax.
 /// - Returns [`SearchError::Db`] for SQLite execution failures.
-pub fn search_all(conn: &Connection, query: &SearchQuery) -> SearchResult<Vec<SearchHit>> {
+pub fn search_page(conn: &Connection, query: &SearchQuery) -> SearchResult<SearchPage> {
     let started_at = Instant::now();
     // Why: only log search metadata to match privacy policy.
     let query_len = query.text.chars().count();
     let query_terms = query.text.split_whitespace().count();
     let has_kind_filter = query.kind.is_some();
 
-    let Some(match_expr) = build_match_expression(query)? else {
+    let parsed = if query.raw_fts_syntax {
+        ParsedQuery::default()
+    } else {
+        parse_search_query(&query.text).map_err(|err| {
+            error!(
+                "event=search module=search status=error duration_ms={} query_len={} query_terms={} has_kind_filter={} raw_fts=false error_code=invalid_syntax error_start={} error_end={}",
+                started_at.elapsed().as_millis(),
+                query_len,
+                query_terms,
+                has_kind_filter,
+                err.start,
+                err.end
+            );
+            SearchError::InvalidSyntax(err)
+        })?
+    };
+    let is_empty = if query.raw_fts_syntax {
+        query.text.trim().is_empty()
+    } else {
+        // A structured tag filter alone is enough to list matching atoms.
+        parsed.is_empty() && query.tag_expr.is_none()
+    };
+
+    if is_empty {
         info!(
             "event=search module=search status=ok hits=0 duration_ms={} query_len={} query_terms={} has_kind_filter={} raw_fts={} reason=empty_query",
             started_at.elapsed().as_millis(),
@@ -129,8 +337,8 @@ pub fn search_all(conn: &Connection, query: &SearchQuery) -> SearchResult<Vec<Se
             has_kind_filter,
             query.raw_fts_syntax
         );
-        return Ok(Vec::new());
-    };
+        return Ok(SearchPage::default());
+    }
 
     if query.limit == 0 {
         info!(
@@ -141,128 +349,617 @@ pub fn search_all(conn: &Connection, query: &SearchQuery) -> SearchResult<Vec<Se
             has_kind_filter,
             query.raw_fts_syntax
         );
-        return Ok(Vec::new());
+        return Ok(SearchPage::default());
     }
 
-    let mut sql = String::from(
-        "SELECT
-            atoms.uuid AS uuid,
-            atoms.type AS type,
-            snippet(atoms_fts, 0, '[', ']', ' ... ', 10) AS snippet
-         FROM atoms_fts
-         JOIN atoms ON atoms.rowid = atoms_fts.rowid
-         WHERE atoms_fts MATCH ?
-           AND atoms.is_deleted = 0",
-    );
-    let mut bind_values: Vec<Value> = vec![Value::Text(match_expr.clone())];
-
-    if let Some(kind) = query.kind {
-        sql.push_str(" AND atoms.type = ?");
-        bind_values.push(Value::Text(atom_type_to_db(kind).to_string()));
-    }
-
-    sql.push_str(" ORDER BY bm25(atoms_fts), atoms.updated_at DESC, atoms.uuid ASC LIMIT ?");
-    bind_values.push(Value::Integer(i64::from(query.limit)));
+    let cursor = query
+        .cursor
+        .as_deref()
+        .map(SearchCursor::decode)
+        .transpose()?;
+    let anchor_ms = cursor
+        .as_ref()
+        .map_or_else(now_epoch_ms, |value| value.anchor_ms);
+
+    let strategy = select_search_strategy(query);
+    let plan = if query.raw_fts_syntax {
+        build_token_plan(query.text.trim().to_string())
+    } else {
+        let positive_terms: Vec<&str> = parsed
+            .positive_terms()
+            .map(|term| term.text.as_str())
+            .collect();
+        match strategy {
+            SearchStrategy::Token if positive_terms.is_empty() => build_filter_only_plan(),
+            SearchStrategy::Token => build_token_plan(
+                positive_terms
+                    .iter()
+                    .map(|term| escape_fts_term(term))
+                    .collect::<Vec<_>>()
+                    .join(" AND "),
+            ),
+            SearchStrategy::Trigram | SearchStrategy::Substring => {
+                build_trigram_plan(&positive_terms)
+            }
+        }
+    };
+    let SearchPlan {
+        sql,
+        bind_values,
+        match_expr,
+        substring_terms,
+        ..
+    } = finish_plan(plan, query, &parsed, anchor_ms, cursor.as_ref());
 
     let mut stmt = conn.prepare(&sql)?;
     let mut rows = stmt.query(params_from_iter(bind_values)).map_err(|err| {
         let mapped = map_query_error(err, &match_expr);
         error!(
-            "event=search module=search status=error duration_ms={} query_len={} query_terms={} has_kind_filter={} raw_fts={} error_code={}",
+            "event=search module=search status=error duration_ms={} query_len={} query_terms={} has_kind_filter={} raw_fts={} strategy={} error_code={}",
             started_at.elapsed().as_millis(),
             query_len,
             query_terms,
             has_kind_filter,
             query.raw_fts_syntax,
+            strategy.as_str(),
             search_error_code(&mapped)
         );
         mapped
     })?;
     let mut hits = Vec::new();
+    let mut last_updated_at = 0;
+    let mut has_more = false;
 
     while let Some(row) = rows.next().map_err(|err| {
         let mapped = map_query_error(err, &match_expr);
         error!(
-            "event=search module=search status=error duration_ms={} query_len={} query_terms={} has_kind_filter={} raw_fts={} error_code={}",
+            "event=search module=search status=error duration_ms={} query_len={} query_terms={} has_kind_filter={} raw_fts={} strategy={} error_code={}",
             started_at.elapsed().as_millis(),
             query_len,
             query_terms,
             has_kind_filter,
             query.raw_fts_syntax,
+            strategy.as_str(),
             search_error_code(&mapped)
         );
         mapped
     })? {
-        hits.push(parse_search_hit(row)?);
+        // Why: plans fetch `limit + 1` rows, so a full page only gets a
+        // cursor when another row actually exists.
+        if hits.len() == query.limit as usize {
+            has_more = true;
+            break;
+        }
+        let (hit, updated_at) = parse_search_hit(row, &substring_terms)?;
+        last_updated_at = updated_at;
+        hits.push(hit);
     }
+    let next_cursor = match hits.last() {
+        Some(last) if has_more => Some(
+            SearchCursor {
+                anchor_ms,
+                score: last.score,
+                updated_at: last_updated_at,
+                uuid: last.atom_id.to_string(),
+            }
+            .encode(),
+        ),
+        _ => None,
+    };
 
     info!(
-        "event=search module=search status=ok hits={} duration_ms={} query_len={} query_terms={} has_kind_filter={} raw_fts={}",
+        "event=search module=search status=ok hits={} duration_ms={} query_len={} query_terms={} has_kind_filter={} raw_fts={} strategy={} field_filters={} has_cursor={} has_more={}",
         hits.len(),
         started_at.elapsed().as_millis(),
         query_len,
         query_terms,
         has_kind_filter,
-        query.raw_fts_syntax
+        query.raw_fts_syntax,
+        strategy.as_str(),
+        parsed.filters.len(),
+        cursor.is_some(),
+        has_more
     );
 
-    Ok(hits)
+    Ok(SearchPage { hits, next_cursor })
 }
 
-fn parse_search_hit(row: &Row<'_>) -> SearchResult<SearchHit> {
-    let uuid_text: String = row.get("uuid")?;
-    let atom_id = Uuid::parse_str(&uuid_text)
-        .map_err(|_| SearchError::InvalidData(format!("invalid uuid `{uuid_text}`")))?;
+/// Chooses how `query` is matched.
+///
+/// - Raw FTS syntax and queries without CJK characters use
+///   [`SearchStrategy::Token`].
+/// - CJK queries use the trigram index; when any term is shorter than
+///   3 characters, [`SearchStrategy::Substring`] adds `LIKE` filters.
+/// - Only positive free-text terms count; field filters and `-term`
+///   exclusions never change the strategy.
+pub fn select_search_strategy(query: &SearchQuery) -> SearchStrategy {
+    if query.raw_fts_syntax {
+        return SearchStrategy::Token;
+    }
+    let terms: Vec<String> = match parse_search_query(&query.text) {
+        Ok(parsed) => parsed
+            .positive_terms()
+            .map(|term| term.text.clone())
+            .collect(),
+        Err(_) => query.text.split_whitespace().map(str::to_string).collect(),
+    };
+    if !terms.iter().any(|term| term.chars().any(is_cjk_char)) {
+        return SearchStrategy::Token;
+    }
+    let has_short_term = terms
+        .iter()
+        .any(|term| term.chars().count() < TRIGRAM_MIN_CHARS);
+    if has_short_term {
+        SearchStrategy::Substring
+    } else {
+        SearchStrategy::Trigram
+    }
+}
 
-    let type_text: String = row.get("type")?;
-    let kind = parse_atom_type(&type_text)
-        .ok_or_else(|| SearchError::InvalidData(format!("invalid type `{type_text}`")))?;
+/// Returns whether `ch` belongs to a script written without word spacing
+/// (Han ideographs, kana, Hangul syllables).
+pub(crate) fn is_cjk_char(ch: char) -> bool {
+    matches!(
+        ch as u32,
+        0x3040..=0x30FF     // Hiragana, Katakana
+            | 0x3400..=0x4DBF // CJK Extension A
+            | 0x4E00..=0x9FFF // CJK Unified Ideographs
+            | 0xAC00..=0xD7AF // Hangul syllables
+            | 0xF900..=0xFAFF // CJK Compatibility Ideographs
+            | 0x20000..=0x2FA1F // CJK Extensions B-F, compatibility supplement
+    )
+}
 
-    Ok(SearchHit {
-        atom_id,
-        kind,
-        snippet: row.get("snippet")?,
-    })
+/// SQL plus bind values for one strategy.
+///
+/// `sql` is the inner row source; [`finish_plan`] appends predicates and
+/// wraps it with scoring, keyset paging and ordering.
+struct SearchPlan {
+    sql: String,
+    bind_values: Vec<Value>,
+    /// MATCH expression used for error mapping (empty when `LIKE`-only).
+    match_expr: String,
+    /// Short terms matched via `LIKE`; drive the Rust-side snippet.
+    substring_terms: Vec<String>,
 }
 
-fn parse_atom_type(value: &str) -> Option<AtomType> {
-    match value {
-        "note" => Some(AtomType::Note),
-        "task" => Some(AtomType::Task),
-        "event" => Some(AtomType::Event),
-        _ => None,
+fn build_token_plan(match_expr: String) -> SearchPlan {
+    SearchPlan {
+        sql: String::from(
+            "SELECT
+                atoms.uuid AS uuid,
+                atoms.type AS type,
+                atoms.updated_at AS updated_at,
+                atoms_fts.content AS content,
+                snippet(atoms_fts, 0, char(57344), char(57345), ' ... ', 10) AS snippet,
+                bm25(atoms_fts) AS bm25
+             FROM atoms_fts
+             JOIN atoms ON atoms.rowid = atoms_fts.rowid
+             WHERE atoms_fts MATCH ?
+               AND atoms.is_deleted = 0",
+        ),
+        bind_values: vec![Value::Text(match_expr.clone())],
+        match_expr,
+        substring_terms: Vec::new(),
     }
 }
 
-fn atom_type_to_db(kind: AtomType) -> &'static str {
-    match kind {
-        AtomType::Note => "note",
-        AtomType::Task => "task",
-        AtomType::Event => "event",
+fn build_trigram_plan(terms: &[&str]) -> SearchPlan {
+    let (long_terms, short_terms): (Vec<&str>, Vec<&str>) = terms
+        .iter()
+        .partition(|term| term.chars().count() >= TRIGRAM_MIN_CHARS);
+    let match_expr = long_terms
+        .iter()
+        .map(|term| escape_fts_term(term))
+        .collect::<Vec<_>>()
+        .join(" AND ");
+    let has_match = !match_expr.is_empty();
+
+    let (snippet_expr, bm25_expr) = if has_match {
+        (
+            "snippet(atoms_fts_trigram, 0, char(57344), char(57345), ' ... ', 10)",
+            "bm25(atoms_fts_trigram)",
+        )
+    } else {
+        ("NULL", "NULL")
+    };
+    let mut sql = format!(
+        "SELECT
+            atoms.uuid AS uuid,
+            atoms.type AS type,
+            atoms.updated_at AS updated_at,
+            atoms_fts_trigram.content AS content,
+            {snippet_expr} AS snippet,
+            {bm25_expr} AS bm25
+         FROM atoms_fts_trigram
+         JOIN atoms ON atoms.rowid = atoms_fts_trigram.rowid
+         WHERE atoms.is_deleted = 0"
+    );
+    let mut bind_values: Vec<Value> = Vec::new();
+
+    if has_match {
+        sql.push_str(" AND atoms_fts_trigram MATCH ?");
+        bind_values.push(Value::Text(match_expr.clone()));
+    }
+    for term in &short_terms {
+        sql.push_str(" AND atoms_fts_trigram.content LIKE ? ESCAPE '\\'");
+        bind_values.push(Value::Text(format!("%{}%", escape_like(term))));
+    }
+
+    SearchPlan {
+        sql,
+        bind_values,
+        match_expr,
+        substring_terms: if has_match {
+            Vec::new()
+        } else {
+            short_terms.into_iter().map(str::to_string).collect()
+        },
     }
 }
 
-fn build_match_expression(query: &SearchQuery) -> SearchResult<Option<String>> {
-    let text = query.text.trim();
-    if text.is_empty() {
-        return Ok(None);
+/// Plan for queries made only of field filters and exclusions.
+fn build_filter_only_plan() -> SearchPlan {
+    SearchPlan {
+        sql: String::from(
+            "SELECT
+                atoms.uuid AS uuid,
+                atoms.type AS type,
+                atoms.updated_at AS updated_at,
+                atoms.content AS content,
+                NULL AS snippet,
+                NULL AS bm25
+             FROM atoms
+             WHERE atoms.is_deleted = 0",
+        ),
+        bind_values: Vec::new(),
+        match_expr: String::new(),
+        substring_terms: Vec::new(),
     }
+}
 
-    if query.raw_fts_syntax {
-        return Ok(Some(text.to_string()));
+/// Appends exclusions, field filters and the type option, then wraps the
+/// row source with scoring, keyset paging, ordering and `limit + 1`.
+fn finish_plan(
+    mut plan: SearchPlan,
+    query: &SearchQuery,
+    parsed: &ParsedQuery,
+    anchor_ms: i64,
+    cursor: Option<&SearchCursor>,
+) -> SearchPlan {
+    for term in parsed.negated_terms() {
+        if term.text.chars().any(is_cjk_char) {
+            plan.sql
+                .push_str(" AND atoms.content NOT LIKE ? ESCAPE '\\'");
+            plan.bind_values
+                .push(Value::Text(format!("%{}%", escape_like(&term.text))));
+        } else {
+            plan.sql.push_str(
+                " AND atoms.rowid NOT IN (SELECT rowid FROM atoms_fts WHERE atoms_fts MATCH ?)",
+            );
+            plan.bind_values
+                .push(Value::Text(escape_fts_term(&term.text)));
+        }
+    }
+    for filter in &parsed.filters {
+        push_filter_predicate(&mut plan, filter);
+    }
+    if let Some(kind) = query.kind {
+        plan.sql.push_str(" AND atoms.type = ?");
+        plan.bind_values
+            .push(Value::Text(atom_type_to_db(kind).to_string()));
+    }
+    if let Some(expr) = query.tag_expr.as_ref() {
+        plan.sql.push_str(" AND ");
+        plan.sql.push_str(&tag_expr_predicate(
+            expr,
+            "atoms.uuid",
+            &mut plan.bind_values,
+        ));
+    }
+
+    // Why: boost knobs are normalized finite numbers, so they are inlined as
+    // literals; this keeps bind order identical to SQL text order.
+    let boost = query.boost.normalized();
+    let score_expr = format!(
+        "COALESCE(-bm25, 1.0)
+            * (CASE type WHEN 'note' THEN {note:?} WHEN 'task' THEN {task:?} ELSE {event:?} END)
+            * (1.0 + {weight:?} * {half_life:?}
+                / ({half_life:?} + MAX(0, {anchor_ms} - updated_at) / 86400000.0))",
+        note = boost.note_weight,
+        task = boost.task_weight,
+        event = boost.event_weight,
+        weight = boost.recency_weight,
+        half_life = boost.recency_half_life_days,
+    );
+    let mut sql = format!(
+        "SELECT * FROM (
+            SELECT hits.*, {score_expr} AS score
+            FROM ({inner}) AS hits
+         )",
+        inner = plan.sql
+    );
+    if let Some(cursor) = cursor {
+        sql.push_str(
+            " WHERE score < ?
+                OR (score = ? AND (updated_at < ? OR (updated_at = ? AND uuid > ?)))",
+        );
+        plan.bind_values.extend([
+            Value::Real(cursor.score),
+            Value::Real(cursor.score),
+            Value::Integer(cursor.updated_at),
+            Value::Integer(cursor.updated_at),
+            Value::Text(cursor.uuid.clone()),
+        ]);
+    }
+    sql.push_str(" ORDER BY score DESC, updated_at DESC, uuid ASC LIMIT ?");
+    plan.bind_values
+        .push(Value::Integer(i64::from(query.limit) + 1));
+    plan.sql = sql;
+    plan
+}
+
+/// Keyset position after the last hit of a page.
+///
+/// Encoded as `anchor_ms:score_bits:updated_at:uuid`; `score_bits` keeps the
+/// exact `f64` so equality comparisons in SQL stay stable, and `anchor_ms`
+/// pins the recency boost so scores do not drift between pages.
+#[derive(Debug, Clone, PartialEq)]
+struct SearchCursor {
+    anchor_ms: i64,
+    score: f64,
+    updated_at: i64,
+    uuid: String,
+}
+
+impl SearchCursor {
+    fn encode(&self) -> String {
+        format!(
+            "{}:{:016x}:{}:{}",
+            self.anchor_ms,
+            self.score.to_bits(),
+            self.updated_at,
+            self.uuid
+        )
+    }
+
+    fn decode(raw: &str) -> SearchResult<Self> {
+        let invalid = || SearchError::InvalidCursor(raw.to_string());
+        let mut parts = raw.splitn(4, ':');
+        let mut next = || parts.next().ok_or_else(invalid);
+        let anchor_ms = next()?.parse::<i64>().map_err(|_| invalid())?;
+        let score_bits = u64::from_str_radix(next()?, 16).map_err(|_| invalid())?;
+        let updated_at = next()?.parse::<i64>().map_err(|_| invalid())?;
+        let uuid = Uuid::parse_str(next()?).map_err(|_| invalid())?;
+        let score = f64::from_bits(score_bits);
+        if !score.is_finite() {
+            return Err(invalid());
+        }
+        Ok(Self {
+            anchor_ms,
+            score,
+            updated_at,
+            uuid: uuid.to_string(),
+        })
+    }
+}
+
+/// Folder paths (`A/B`) for every active workspace folder.
+const FOLDER_PATHS_CTE: &str = "WITH RECURSIVE folder_paths(node_uuid, path) AS (
+        SELECT node_uuid, display_name
+        FROM workspace_nodes
+        WHERE kind = 'folder' AND parent_uuid IS NULL AND is_deleted = 0
+        UNION ALL
+        SELECT child.node_uuid, folder_paths.path || '/' || child.display_name
+        FROM workspace_nodes child
+        JOIN folder_paths ON child.parent_uuid = folder_paths.node_uuid
+        WHERE child.kind = 'folder' AND child.is_deleted = 0
+    )";
+
+fn push_filter_predicate(plan: &mut SearchPlan, filter: &QueryFilter) {
+    let predicate = match &filter.kind {
+        FilterKind::Tag(name) => tag_expr_predicate(
+            &TagExpr::Tag(name.clone()),
+            "atoms.uuid",
+            &mut plan.bind_values,
+        ),
+        FilterKind::TagExpr(expr) => tag_expr_predicate(expr, "atoms.uuid", &mut plan.bind_values),
+        FilterKind::Type(kind) => {
+            plan.bind_values
+                .push(Value::Text(atom_type_to_db(*kind).to_string()));
+            String::from("atoms.type = ?")
+        }
+        FilterKind::Status(status) => {
+            plan.bind_values
+                .push(Value::Text(task_status_to_db(*status).to_string()));
+            String::from("atoms.task_status = ?")
+        }
+        FilterKind::Before(epoch_ms) => {
+            plan.bind_values.push(Value::Integer(*epoch_ms));
+            String::from("COALESCE(atoms.start_at, atoms.end_at) < ?")
+        }
+        FilterKind::After(epoch_ms) => {
+            plan.bind_values.push(Value::Integer(*epoch_ms));
+            String::from("COALESCE(atoms.start_at, atoms.end_at) >= ?")
+        }
+        FilterKind::In(path) => {
+            plan.bind_values.push(Value::Text(path.clone()));
+            plan.bind_values
+                .push(Value::Text(format!("{}/%", escape_like(path))));
+            format!(
+                "atoms.uuid IN (
+                    {FOLDER_PATHS_CTE}
+                    SELECT refs.atom_uuid
+                    FROM workspace_nodes refs
+                    JOIN folder_paths ON refs.parent_uuid = folder_paths.node_uuid
+                    WHERE refs.kind = 'note_ref'
+                      AND refs.is_deleted = 0
+                      AND (folder_paths.path = ? COLLATE NOCASE
+                           OR folder_paths.path LIKE ? ESCAPE '\\')
+                )"
+            )
+        }
+        FilterKind::Property { key, predicate } => {
+            property_predicate(key, predicate.as_ref(), &mut plan.bind_values)
+        }
+    };
+
+    // Why: NULL columns (e.g. notes without task_status) must count as
+    // "not matching" so `-status:done` keeps them.
+    if filter.negated {
+        plan.sql.push_str(" AND NOT IFNULL((");
+        plan.sql.push_str(&predicate);
+        plan.sql.push_str("), 0)");
+    } else {
+        plan.sql.push_str(" AND ");
+        plan.sql.push_str(&predicate);
     }
+}
 
-    let terms = text
-        .split_whitespace()
-        .filter(|term| !term.is_empty())
-        .map(escape_fts_term)
-        .collect::<Vec<_>>();
+/// Compiles a `prop:` filter into an `atom_properties` membership test.
+///
+/// Numeric and date operands compare `value_num`; anything else compares
+/// text case-insensitively. List properties match when any element does.
+fn property_predicate(
+    key: &str,
+    predicate: Option<&(PropertyComparison, String)>,
+    bind_values: &mut Vec<Value>,
+) -> String {
+    bind_values.push(Value::Text(key.to_string()));
+    let comparison = match predicate {
+        None => String::new(),
+        Some((comparison, operand)) => {
+            let op = comparison.as_sql();
+            if let Ok(number) = operand.parse::<f64>() {
+                bind_values.push(Value::Real(number));
+                format!(" AND p.value_type IN ('number', 'bool') AND p.value_num {op} ?")
+            } else if let Some(epoch_ms) = date_epoch_ms(operand) {
+                bind_values.push(Value::Real(epoch_ms as f64));
+                format!(" AND p.value_type = 'date' AND p.value_num {op} ?")
+            } else {
+                bind_values.push(Value::Text(operand.clone()));
+                format!(" AND p.value_text {op} ? COLLATE NOCASE")
+            }
+        }
+    };
+    format!(
+        "atoms.uuid IN (
+            SELECT p.atom_uuid
+            FROM atom_properties p
+            WHERE p.key = ?{comparison}
+        )"
+    )
+}
 
-    if terms.is_empty() {
-        return Ok(None);
+fn escape_like(raw: &str) -> String {
+    let mut escaped = String::with_capacity(raw.len());
+    for ch in raw.chars() {
+        if matches!(ch, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
     }
+    escaped
+}
+
+/// Builds a plain snippet around the first occurrence of any term.
+fn substring_snippet(content: &str, terms: &[String]) -> (String, Vec<HighlightRange>) {
+    let lowered = content.to_lowercase();
+    let found = terms.iter().find_map(|term| {
+        let needle = term.to_lowercase();
+        lowered
+            .find(&needle)
+            .filter(|_| lowered.len() == content.len())
+            .map(|start| (start, start + needle.len()))
+    });
+    let Some((start, end)) = found else {
+        let preview = content
+            .chars()
+            .take(SUBSTRING_SNIPPET_CONTEXT_CHARS * 2)
+            .collect();
+        return (preview, Vec::new());
+    };
+
+    let before: Vec<char> = content[..start].chars().collect();
+    let after: Vec<char> = content[end..].chars().collect();
+    let head_from = before.len().saturating_sub(SUBSTRING_SNIPPET_CONTEXT_CHARS);
+    let tail_to = after.len().min(SUBSTRING_SNIPPET_CONTEXT_CHARS);
+
+    let mut marked = String::new();
+    if head_from > 0 {
+        marked.push_str(" ... ");
+    }
+    marked.extend(&before[head_from..]);
+    marked.push(HIGHLIGHT_OPEN);
+    marked.push_str(&content[start..end]);
+    marked.push(HIGHLIGHT_CLOSE);
+    marked.extend(&after[..tail_to]);
+    if tail_to < after.len() {
+        marked.push_str(" ... ");
+    }
+    extract_highlights(&marked)
+}
+
+/// Strips highlight marker chars and returns the matched spans.
+///
+/// Unpaired markers are dropped without producing a range.
+fn extract_highlights(marked: &str) -> (String, Vec<HighlightRange>) {
+    let mut snippet = String::with_capacity(marked.len());
+    let mut highlights = Vec::new();
+    let mut open: Option<(usize, usize)> = None;
+    let mut char_len = 0;
+    for ch in marked.chars() {
+        match ch {
+            HIGHLIGHT_OPEN => open = Some((snippet.len(), char_len)),
+            HIGHLIGHT_CLOSE => {
+                if let Some((start, char_start)) = open.take() {
+                    if start < snippet.len() {
+                        highlights.push(HighlightRange {
+                            start,
+                            end: snippet.len(),
+                            char_start,
+                            char_end: char_len,
+                        });
+                    }
+                }
+            }
+            _ => {
+                snippet.push(ch);
+                char_len += 1;
+            }
+        }
+    }
+    (snippet, highlights)
+}
+
+fn parse_search_hit(row: &Row<'_>, substring_terms: &[String]) -> SearchResult<(SearchHit, i64)> {
+    let uuid_text: String = row.get("uuid")?;
+    let atom_id = Uuid::parse_str(&uuid_text)
+        .map_err(|_| SearchError::InvalidData(format!("invalid uuid `{uuid_text}`")))?;
+
+    let type_text: String = row.get("type")?;
+    let kind = parse_atom_type(&type_text)
+        .ok_or_else(|| SearchError::InvalidData(format!("invalid type `{type_text}`")))?;
+
+    let (snippet, highlights) = match row.get::<_, Option<String>>("snippet")? {
+        Some(value) => extract_highlights(&value),
+        None => {
+            let content: String = row.get("content")?;
+            substring_snippet(&content, substring_terms)
+        }
+    };
 
-    Ok(Some(terms.join(" AND ")))
+    Ok((
+        SearchHit {
+            atom_id,
+            kind,
+            snippet,
+            highlights,
+            bm25: row.get("bm25")?,
+            score: row.get("score")?,
+        },
+        row.get("updated_at")?,
+    ))
 }
 
 fn escape_fts_term(raw: &str) -> String {
@@ -296,6 +993,8 @@ fn is_match_syntax_error(err: &rusqlite::Error) -> bool {
 fn search_error_code(err: &SearchError) -> &'static str {
     match err {
         SearchError::InvalidQuery { .. } => "invalid_query",
+        SearchError::InvalidSyntax(_) => "invalid_syntax",
+        SearchError::InvalidCursor(_) => "invalid_cursor",
         SearchError::Db(_) => "db_error",
         SearchError::InvalidData(_) => "invalid_data",
     }
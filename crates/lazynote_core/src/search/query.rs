@@ -0,0 +1,533 @@
+//! Structured search query grammar.
+//!
+//! # Responsibility
+//! - Parse single-entry search input into free-text terms and field filters.
+//! - Report malformed input with character offsets for UI underlining.
+//!
+//! # Invariants
+//! - Offsets are `char` indices into the original input, end-exclusive.
+//! - Unknown `field:value` tokens are kept as plain text terms, so pasted
+//!   URLs and `a:b` text never fail to parse.
+//! - Date filters are interpreted as UTC calendar days.
+//!
+//! # Grammar
+//! ```text
+//! query   := item*
+//! item    := ["-"] (filter | phrase | word)
+//! filter  := field ":" (phrase | word)
+//! field   := "tag" | "tags" | "type" | "status" | "before" | "after" | "in"
+//!          | "prop"
+//! prop    := key [("=" | "<" | "<=" | ">" | ">=") value]
+//! phrase  := '"' <any char except '"'>* '"'
+//! ```
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::{AtomType, TaskStatus};
+use crate::model::tag_expr::{normalize_tag_name, parse_tag_expr, TagExpr};
+use chrono::NaiveDate;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+const DAY_MS: i64 = 86_400_000;
+
+/// One free-text term.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextTerm {
+    /// Term text without quotes.
+    pub text: String,
+    /// Whether the term was written as a `"quoted phrase"`.
+    pub phrase: bool,
+    /// Whether the term was prefixed with `-`.
+    pub negated: bool,
+}
+
+/// One field predicate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterKind {
+    /// `tag:<name>`; normalized tag name, matching the tag and its descendants.
+    Tag(String),
+    /// `tags:"(work OR client) AND NOT archived"`; boolean tag expression.
+    TagExpr(TagExpr),
+    /// `type:note|task|event`.
+    Type(AtomType),
+    /// `status:todo|in_progress|done|cancelled`.
+    Status(TaskStatus),
+    /// `before:YYYY-MM-DD`; time anchor earlier than that day (epoch ms).
+    Before(i64),
+    /// `after:YYYY-MM-DD`; time anchor on or after the following day.
+    After(i64),
+    /// `in:<folder path>`; note_ref under that workspace folder (any depth).
+    In(String),
+    /// `prop:<key>` or `prop:<key><op><value>`; frontmatter property test.
+    Property {
+        /// Lowercase property key.
+        key: String,
+        /// Comparison; `None` only requires the key to be present.
+        predicate: Option<(PropertyComparison, String)>,
+    },
+}
+
+/// Comparison operator of a `prop:` filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyComparison {
+    /// `=`; case-insensitive text, numeric or date equality.
+    Eq,
+    /// `<`.
+    Lt,
+    /// `<=`.
+    Le,
+    /// `>`.
+    Gt,
+    /// `>=`.
+    Ge,
+}
+
+impl PropertyComparison {
+    /// Returns the SQL operator.
+    pub fn as_sql(self) -> &'static str {
+        match self {
+            Self::Eq => "=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+        }
+    }
+}
+
+/// One field filter with its negation flag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryFilter {
+    /// Predicate.
+    pub kind: FilterKind,
+    /// Whether the filter was prefixed with `-`.
+    pub negated: bool,
+}
+
+/// Parsed search input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedQuery {
+    /// Free-text terms in input order.
+    pub terms: Vec<TextTerm>,
+    /// Field filters in input order.
+    pub filters: Vec<QueryFilter>,
+}
+
+impl ParsedQuery {
+    /// Returns whether the query constrains nothing.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty() && self.filters.is_empty()
+    }
+
+    /// Iterates non-negated text terms.
+    pub fn positive_terms(&self) -> impl Iterator<Item = &TextTerm> {
+        self.terms.iter().filter(|term| !term.negated)
+    }
+
+    /// Iterates negated text terms.
+    pub fn negated_terms(&self) -> impl Iterator<Item = &TextTerm> {
+        self.terms.iter().filter(|term| term.negated)
+    }
+}
+
+/// Parse failure category.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryParseErrorKind {
+    /// `"` without a closing quote.
+    UnterminatedQuote,
+    /// `-` not followed by a term.
+    DanglingNegation,
+    /// Known field without a value (`tag:`).
+    MissingValue {
+        /// Field name as written, lowercase.
+        field: String,
+    },
+    /// Known field with a value outside its domain.
+    InvalidValue {
+        /// Field name as written, lowercase.
+        field: String,
+        /// Human-readable accepted values.
+        expected: &'static str,
+    },
+}
+
+/// Parse error with the offending `char` span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryParseError {
+    /// Failure category.
+    pub kind: QueryParseErrorKind,
+    /// Start `char` offset (inclusive).
+    pub start: usize,
+    /// End `char` offset (exclusive).
+    pub end: usize,
+}
+
+impl QueryParseError {
+    /// Returns a stable machine-readable code.
+    pub fn code(&self) -> &'static str {
+        match self.kind {
+            QueryParseErrorKind::UnterminatedQuote => "unterminated_quote",
+            QueryParseErrorKind::DanglingNegation => "dangling_negation",
+            QueryParseErrorKind::MissingValue { .. } => "missing_value",
+            QueryParseErrorKind::InvalidValue { .. } => "invalid_value",
+        }
+    }
+}
+
+impl Display for QueryParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            QueryParseErrorKind::UnterminatedQuote => write!(f, "unterminated quote"),
+            QueryParseErrorKind::DanglingNegation => write!(f, "`-` must precede a term"),
+            QueryParseErrorKind::MissingValue { field } => {
+                write!(f, "`{field}:` requires a value")
+            }
+            QueryParseErrorKind::InvalidValue { field, expected } => {
+                write!(f, "invalid `{field}:` value; expected {expected}")
+            }
+        }?;
+        write!(f, " at {}..{}", self.start, self.end)
+    }
+}
+
+impl Error for QueryParseError {}
+
+/// Parses structured search input.
+///
+/// # Errors
+/// - Returns [`QueryParseError`] with the offending `char` span.
+pub fn parse_search_query(input: &str) -> Result<ParsedQuery, QueryParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut parsed = ParsedQuery::default();
+    let mut index = 0;
+
+    while index < chars.len() {
+        if chars[index].is_whitespace() {
+            index += 1;
+            continue;
+        }
+
+        let item_start = index;
+        let negated = chars[index] == '-';
+        if negated {
+            index += 1;
+            if index >= chars.len() || chars[index].is_whitespace() {
+                return Err(QueryParseError {
+                    kind: QueryParseErrorKind::DanglingNegation,
+                    start: item_start,
+                    end: item_start + 1,
+                });
+            }
+        }
+
+        if chars[index] == '"' {
+            let (text, next) = read_phrase(&chars, index)?;
+            index = next;
+            if !text.trim().is_empty() {
+                parsed.terms.push(TextTerm {
+                    text,
+                    phrase: true,
+                    negated,
+                });
+            }
+            continue;
+        }
+
+        if let Some((field, colon)) = read_field(&chars, index) {
+            let value_start = colon + 1;
+            let (value, next) = if chars.get(value_start) == Some(&'"') {
+                read_phrase(&chars, value_start)?
+            } else {
+                read_word(&chars, value_start)
+            };
+            let value = value.trim().to_string();
+            if value.is_empty() {
+                return Err(QueryParseError {
+                    kind: QueryParseErrorKind::MissingValue {
+                        field: field.to_string(),
+                    },
+                    start: index,
+                    end: next.max(value_start),
+                });
+            }
+            let kind = if field == "tags" {
+                // Why: point at the offending token inside the expression,
+                // not the whole value, so the UI underline is precise.
+                let inner_start = value_start + usize::from(chars[value_start] == '"');
+                let leading_ws = chars[inner_start..]
+                    .iter()
+                    .take_while(|ch| ch.is_whitespace())
+                    .count();
+                let base = inner_start + leading_ws;
+                parse_tag_expr(&value)
+                    .map(FilterKind::TagExpr)
+                    .map_err(|err| QueryParseError {
+                        kind: QueryParseErrorKind::InvalidValue {
+                            field: field.to_string(),
+                            expected: expected_values(field),
+                        },
+                        start: base + err.start,
+                        end: base + err.end.max(err.start + 1),
+                    })?
+            } else {
+                parse_filter_value(field, &value).ok_or_else(|| QueryParseError {
+                    kind: QueryParseErrorKind::InvalidValue {
+                        field: field.to_string(),
+                        expected: expected_values(field),
+                    },
+                    start: value_start,
+                    end: next,
+                })?
+            };
+            parsed.filters.push(QueryFilter { kind, negated });
+            index = next;
+            continue;
+        }
+
+        let (text, next) = read_word(&chars, index);
+        index = next;
+        parsed.terms.push(TextTerm {
+            text,
+            phrase: false,
+            negated,
+        });
+    }
+
+    Ok(parsed)
+}
+
+/// Reads `"..."` starting at the opening quote; returns inner text and the
+/// offset after the closing quote.
+fn read_phrase(chars: &[char], open: usize) -> Result<(String, usize), QueryParseError> {
+    match chars[open + 1..].iter().position(|ch| *ch == '"') {
+        Some(len) => Ok((
+            chars[open + 1..open + 1 + len].iter().collect(),
+            open + len + 2,
+        )),
+        None => Err(QueryParseError {
+            kind: QueryParseErrorKind::UnterminatedQuote,
+            start: open,
+            end: chars.len(),
+        }),
+    }
+}
+
+fn read_word(chars: &[char], start: usize) -> (String, usize) {
+    let len = chars[start..]
+        .iter()
+        .take_while(|ch| !ch.is_whitespace())
+        .count();
+    (chars[start..start + len].iter().collect(), start + len)
+}
+
+/// Recognizes a known `field:` prefix; returns the canonical field name and
+/// the colon offset.
+fn read_field(chars: &[char], start: usize) -> Option<(&'static str, usize)> {
+    let len = chars[start..]
+        .iter()
+        .take_while(|ch| ch.is_ascii_alphabetic())
+        .count();
+    let colon = start + len;
+    if len == 0 || chars.get(colon) != Some(&':') {
+        return None;
+    }
+    let name: String = chars[start..colon].iter().collect();
+    let field = match name.to_ascii_lowercase().as_str() {
+        "tag" => "tag",
+        "tags" => "tags",
+        "type" => "type",
+        "status" => "status",
+        "before" => "before",
+        "after" => "after",
+        "in" => "in",
+        "prop" => "prop",
+        _ => return None,
+    };
+    Some((field, colon))
+}
+
+fn parse_filter_value(field: &str, value: &str) -> Option<FilterKind> {
+    let lowered = value.to_ascii_lowercase();
+    match field {
+        "tag" => normalize_tag_name(value).map(FilterKind::Tag),
+        "type" => match lowered.as_str() {
+            "note" => Some(FilterKind::Type(AtomType::Note)),
+            "task" => Some(FilterKind::Type(AtomType::Task)),
+            "event" => Some(FilterKind::Type(AtomType::Event)),
+            _ => None,
+        },
+        "status" => match lowered.as_str() {
+            "todo" => Some(FilterKind::Status(TaskStatus::Todo)),
+            "in_progress" => Some(FilterKind::Status(TaskStatus::InProgress)),
+            "done" => Some(FilterKind::Status(TaskStatus::Done)),
+            "cancelled" => Some(FilterKind::Status(TaskStatus::Cancelled)),
+            _ => None,
+        },
+        "before" => parse_day_start_ms(value).map(FilterKind::Before),
+        "after" => parse_day_start_ms(value).map(|start| FilterKind::After(start + DAY_MS)),
+        "in" => {
+            let path = value.trim_matches('/');
+            (!path.is_empty()).then(|| FilterKind::In(path.to_string()))
+        }
+        "prop" => parse_property_filter(value),
+        _ => None,
+    }
+}
+
+fn parse_property_filter(value: &str) -> Option<FilterKind> {
+    let Some(op_start) = value.find(['=', '<', '>']) else {
+        let key = value.trim().to_lowercase();
+        return (!key.is_empty()).then_some(FilterKind::Property {
+            key,
+            predicate: None,
+        });
+    };
+    let key = value[..op_start].trim().to_lowercase();
+    let rest = &value[op_start..];
+    let (comparison, operand) = if let Some(operand) = rest.strip_prefix("<=") {
+        (PropertyComparison::Le, operand)
+    } else if let Some(operand) = rest.strip_prefix(">=") {
+        (PropertyComparison::Ge, operand)
+    } else if let Some(operand) = rest.strip_prefix('<') {
+        (PropertyComparison::Lt, operand)
+    } else if let Some(operand) = rest.strip_prefix('>') {
+        (PropertyComparison::Gt, operand)
+    } else {
+        (PropertyComparison::Eq, &rest[1..])
+    };
+    let operand = operand.trim();
+    if key.is_empty() || operand.is_empty() {
+        return None;
+    }
+    Some(FilterKind::Property {
+        key,
+        predicate: Some((comparison, operand.to_string())),
+    })
+}
+
+fn expected_values(field: &str) -> &'static str {
+    match field {
+        "type" => "note|task|event",
+        "status" => "todo|in_progress|done|cancelled",
+        "before" | "after" => "a YYYY-MM-DD date",
+        "in" => "a folder path",
+        "tag" => "a tag name",
+        "tags" => "a tag expression such as `(work OR client) AND NOT archived`",
+        "prop" => "`key` or `key=value` (also <, <=, >, >=)",
+        _ => "a value",
+    }
+}
+
+fn parse_day_start_ms(value: &str) -> Option<i64> {
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?;
+    Some(date.and_hms_opt(0, 0, 0)?.and_utc().timestamp_millis())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_search_query, FilterKind, PropertyComparison, QueryParseErrorKind};
+    use crate::model::atom::{AtomType, TaskStatus};
+    use crate::model::tag_expr::TagExpr;
+
+    #[test]
+    fn parses_filters_phrases_and_negation() {
+        let parsed = parse_search_query(
+            "tag:work type:task status:todo before:2026-11-01 in:\"Projects/Q4\" -archived \"exact phrase\"",
+        )
+        .unwrap();
+
+        let kinds: Vec<&FilterKind> = parsed.filters.iter().map(|filter| &filter.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                &FilterKind::Tag("work".to_string()),
+                &FilterKind::Type(AtomType::Task),
+                &FilterKind::Status(TaskStatus::Todo),
+                &FilterKind::Before(1_793_491_200_000),
+                &FilterKind::In("Projects/Q4".to_string()),
+            ]
+        );
+        assert_eq!(parsed.terms.len(), 2);
+        assert!(parsed.terms[0].negated);
+        assert_eq!(parsed.terms[0].text, "archived");
+        assert!(parsed.terms[1].phrase);
+        assert_eq!(parsed.terms[1].text, "exact phrase");
+    }
+
+    #[test]
+    fn tags_field_parses_boolean_expression_with_inner_offsets() {
+        let parsed = parse_search_query("plan tags:\"(work OR client) -archived\"").unwrap();
+        assert_eq!(
+            parsed.filters[0].kind,
+            FilterKind::TagExpr(TagExpr::And(vec![
+                TagExpr::Or(vec![
+                    TagExpr::Tag("work".to_string()),
+                    TagExpr::Tag("client".to_string())
+                ]),
+                TagExpr::Tag("archived".to_string()).negate()
+            ]))
+        );
+
+        let err = parse_search_query("tags:\"work OR\"").unwrap_err();
+        assert!(matches!(err.kind, QueryParseErrorKind::InvalidValue { .. }));
+        assert_eq!((err.start, err.end), (11, 13));
+    }
+
+    #[test]
+    fn prop_field_parses_key_and_comparison() {
+        let parsed =
+            parse_search_query("prop:Status=draft prop:\"due >= 2026-01-01\" -prop:pinned")
+                .unwrap();
+        let kinds: Vec<&FilterKind> = parsed.filters.iter().map(|filter| &filter.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                &FilterKind::Property {
+                    key: "status".to_string(),
+                    predicate: Some((PropertyComparison::Eq, "draft".to_string())),
+                },
+                &FilterKind::Property {
+                    key: "due".to_string(),
+                    predicate: Some((PropertyComparison::Ge, "2026-01-01".to_string())),
+                },
+                &FilterKind::Property {
+                    key: "pinned".to_string(),
+                    predicate: None,
+                },
+            ]
+        );
+        assert!(parsed.filters[2].negated);
+
+        let err = parse_search_query("prop:rating>").unwrap_err();
+        assert!(matches!(err.kind, QueryParseErrorKind::InvalidValue { .. }));
+    }
+
+    #[test]
+    fn unknown_fields_stay_plain_text() {
+        let parsed = parse_search_query("a:b https://example.com").unwrap();
+        assert!(parsed.filters.is_empty());
+        assert_eq!(parsed.terms[0].text, "a:b");
+        assert_eq!(parsed.terms[1].text, "https://example.com");
+    }
+
+    #[test]
+    fn errors_carry_char_offsets() {
+        let err = parse_search_query("项目 type:memo").unwrap_err();
+        assert!(matches!(err.kind, QueryParseErrorKind::InvalidValue { .. }));
+        assert_eq!((err.start, err.end), (8, 12));
+
+        let err = parse_search_query("plan \"open").unwrap_err();
+        assert_eq!(err.kind, QueryParseErrorKind::UnterminatedQuote);
+        assert_eq!((err.start, err.end), (5, 10));
+
+        let err = parse_search_query("tag: x").unwrap_err();
+        assert_eq!(err.code(), "missing_value");
+        assert_eq!((err.start, err.end), (0, 4));
+
+        let err = parse_search_query("a - b").unwrap_err();
+        assert_eq!(err.kind, QueryParseErrorKind::DanglingNegation);
+        assert_eq!((err.start, err.end), (2, 3));
+    }
+}
@@ -7,10 +7,14 @@
 //! # Invariants
 //! - Service APIs never bypass repository validation/persistence contracts.
 //! - Service layer remains storage-agnostic.
+//! - Kind conversion keeps the atom UUID, content, tags and workspace
+//!   references; only `type`, `task_status` and the time fields change.
 
 use crate::model::atom::{Atom, AtomId, AtomType, TaskStatus};
-use crate::repo::atom_repo::{AtomListQuery, AtomRepository, RepoResult};
+use crate::repo::atom_repo::{AtomListQuery, AtomRepository, RepoError, RepoResult};
 use crate::service::note_service::derive_markdown_preview;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 
 /// Use-case service wrapper for atom CRUD operations.
 pub struct AtomService<R: AtomRepository> {
@@ -28,6 +32,58 @@ pub struct ScheduleEventRequest {
     pub end_epoch_ms: Option<i64>,
 }
 
+/// Request model for converting an atom to another kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConvertAtomRequest {
+    /// Kind the atom should become.
+    pub target: AtomType,
+    /// Optional new `start_at`; ignored when converting to a note.
+    pub start_epoch_ms: Option<i64>,
+    /// Optional new `end_at`; ignored when converting to a note.
+    pub end_epoch_ms: Option<i64>,
+}
+
+/// Errors from atom kind conversion.
+#[derive(Debug)]
+pub enum AtomConvertError {
+    /// Target atom does not exist or is soft-deleted.
+    AtomNotFound(AtomId),
+    /// Event conversion has no start: none requested and none derivable.
+    EventStartRequired(AtomId),
+    /// Repository-level error (including time-window validation).
+    Repo(RepoError),
+}
+
+impl Display for AtomConvertError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AtomNotFound(id) => write!(f, "atom not found: {id}"),
+            Self::EventStartRequired(id) => {
+                write!(f, "converting {id} to an event requires a start time")
+            }
+            Self::Repo(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for AtomConvertError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Repo(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<RepoError> for AtomConvertError {
+    fn from(err: RepoError) -> Self {
+        match err {
+            RepoError::NotFound(id) => Self::AtomNotFound(id),
+            other => Self::Repo(other),
+        }
+    }
+}
+
 impl<R: AtomRepository> AtomService<R> {
     /// Creates a service using the provided repository implementation.
     pub fn new(repo: R) -> Self {
@@ -100,4 +156,82 @@ impl<R: AtomRepository> AtomService<R> {
     pub fn soft_delete_atom(&self, id: AtomId) -> RepoResult<()> {
         self.repo.soft_delete_atom(id)
     }
+
+    /// Converts one live atom to another kind in place.
+    ///
+    /// # Contract
+    /// - Note: clears `task_status`, `start_at`, `end_at`, `all_day`,
+    ///   `priority`, `due_at` and the recurrence rule (notes are timeless).
+    /// - Task: keeps the current status, defaulting to `todo`; requested
+    ///   times replace the current ones.
+    /// - Event: keeps the current status (universal completion). Start is the
+    ///   requested start, else the current `start_at`, else a deadline-only
+    ///   `end_at` (which then becomes a point event). End is the requested
+    ///   end, else the current `end_at` when the start was kept.
+    /// - Requested times are instants: they clear `all_day`. Without them an
+    ///   all-day task or event keeps its floating dates.
+    /// - Converting to the current kind with no requested times is a no-op.
+    /// - Workspace note_refs are kept: they hide while the atom is not a note
+    ///   and reappear when it converts back (hybrid policy, migration 0008).
+    ///
+    /// # Errors
+    /// - [`AtomConvertError::EventStartRequired`] when an event start cannot
+    ///   be determined.
+    /// - [`AtomConvertError::Repo`] with a validation error when the
+    ///   resulting window has `end < start`.
+    pub fn convert_atom(
+        &self,
+        id: AtomId,
+        request: &ConvertAtomRequest,
+    ) -> Result<Atom, AtomConvertError> {
+        let current = self
+            .repo
+            .get_atom(id, false)?
+            .ok_or(AtomConvertError::AtomNotFound(id))?;
+        if current.kind == request.target
+            && request.start_epoch_ms.is_none()
+            && request.end_epoch_ms.is_none()
+        {
+            return Ok(current);
+        }
+
+        let mut atom = current.clone();
+        atom.kind = request.target;
+        if request.start_epoch_ms.is_some() || request.end_epoch_ms.is_some() {
+            atom.all_day = false;
+        }
+        match request.target {
+            AtomType::Note => {
+                atom.task_status = None;
+                atom.start_at = None;
+                atom.end_at = None;
+                atom.all_day = false;
+                atom.priority = None;
+                atom.due_at = None;
+                atom.recurrence_rule = None;
+            }
+            AtomType::Task => {
+                atom.task_status = current.task_status.or(Some(TaskStatus::Todo));
+                atom.start_at = request.start_epoch_ms.or(current.start_at);
+                atom.end_at = request.end_epoch_ms.or(current.end_at);
+            }
+            AtomType::Event => {
+                let kept_start = request.start_epoch_ms.or(current.start_at);
+                atom.start_at = kept_start.or(current.end_at);
+                if atom.start_at.is_none() {
+                    return Err(AtomConvertError::EventStartRequired(id));
+                }
+                atom.end_at = match request.end_epoch_ms {
+                    Some(end) => Some(end),
+                    None if kept_start.is_some() => current.end_at,
+                    None => None,
+                };
+            }
+        }
+
+        self.repo.update_atom(&atom)?;
+        self.repo
+            .get_atom(id, false)?
+            .ok_or(AtomConvertError::AtomNotFound(id))
+    }
 }
@@ -0,0 +1,351 @@
+//! Attachment use-cases: import, resolve, list and garbage collection.
+//!
+//! # Responsibility
+//! - Copy files into the content-addressed blob store next to the DB and
+//!   record them in `attachments`.
+//! - Resolve `attachment://` URIs to blob paths for rendering/opening.
+//! - Remove blobs that no atom references anymore.
+//!
+//! # Invariants
+//! - Blob path = `<store_root>/<sha256[0..2]>/<sha256>`; a blob is written
+//!   to a temporary file and renamed into place, so a visible blob is always
+//!   complete.
+//! - Importing identical bytes twice reuses the existing blob (dedup).
+//! - GC only removes attachments older than the grace window, so a file
+//!   imported but not yet saved into content survives.
+//!
+//! # See also
+//! - crates/lazynote_core/src/repo/attachment_repo.rs
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::AtomId;
+use crate::model::attachment::{guess_mime_type, parse_attachment_uri, Attachment};
+use crate::repo::atom_repo::RepoError;
+use crate::repo::attachment_repo::AttachmentRepository;
+use crate::sync::provider_types::now_epoch_ms;
+use log::{info, warn};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+use uuid::Uuid;
+
+/// Store directory name created next to the database file.
+pub const ATTACHMENT_STORE_DIR_NAME: &str = "attachments";
+/// Default GC grace window (24 hours) for freshly imported attachments.
+pub const ATTACHMENT_GC_DEFAULT_GRACE_MS: i64 = 86_400_000;
+
+const TEMP_FILE_SUFFIX: &str = ".tmp";
+
+/// Errors from attachment service operations.
+#[derive(Debug)]
+pub enum AttachmentServiceError {
+    /// Import source does not exist or is not a regular file.
+    SourceNotFound(PathBuf),
+    /// Value is not an `attachment://<sha256>` URI or digest.
+    InvalidUri(String),
+    /// No attachment (row or blob) exists for the digest.
+    AttachmentNotFound(String),
+    /// Filesystem failure in the blob store.
+    Io(io::Error),
+    /// Repository-level error.
+    Repo(RepoError),
+}
+
+impl Display for AttachmentServiceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SourceNotFound(path) => {
+                write!(f, "attachment source not found: {}", path.display())
+            }
+            Self::InvalidUri(value) => write!(f, "invalid attachment uri: {value}"),
+            Self::AttachmentNotFound(sha256) => write!(f, "attachment not found: {sha256}"),
+            Self::Io(err) => write!(f, "attachment store io failed: {err}"),
+            Self::Repo(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for AttachmentServiceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Repo(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<RepoError> for AttachmentServiceError {
+    fn from(err: RepoError) -> Self {
+        Self::Repo(err)
+    }
+}
+
+impl From<io::Error> for AttachmentServiceError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Result of one import.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedAttachment {
+    /// Stored attachment metadata.
+    pub attachment: Attachment,
+    /// Markdown-usable `attachment://<sha256>` URI.
+    pub uri: String,
+    /// Whether identical bytes were already stored.
+    pub deduplicated: bool,
+}
+
+/// Counters for one GC run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AttachmentGcSummary {
+    /// Zero-reference metadata rows removed.
+    pub removed_rows: u32,
+    /// Blob files removed (including stray files without a row).
+    pub removed_blobs: u32,
+    /// Bytes freed on disk.
+    pub freed_bytes: u64,
+}
+
+/// Attachment service over a repository and a blob store directory.
+pub struct AttachmentService<R: AttachmentRepository> {
+    repo: R,
+    store_root: PathBuf,
+}
+
+impl<R: AttachmentRepository> AttachmentService<R> {
+    /// Creates a service storing blobs under `store_root`.
+    pub fn new(repo: R, store_root: impl Into<PathBuf>) -> Self {
+        Self {
+            repo,
+            store_root: store_root.into(),
+        }
+    }
+
+    /// Returns the blob store directory used for `db_path`.
+    pub fn store_root_for_db(db_path: &Path) -> PathBuf {
+        db_path
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join(ATTACHMENT_STORE_DIR_NAME)
+    }
+
+    /// Copies one file into the store and records it.
+    pub fn import_file(&self, source: &Path) -> Result<ImportedAttachment, AttachmentServiceError> {
+        if !source.is_file() {
+            return Err(AttachmentServiceError::SourceNotFound(source.to_path_buf()));
+        }
+        let file = File::open(source)?;
+        let name = source.file_name().and_then(|name| name.to_str());
+        self.import_reader(file, name)
+    }
+
+    /// Stores in-memory bytes (e.g. a pasted image) and records them.
+    pub fn import_bytes(
+        &self,
+        bytes: &[u8],
+        original_name: Option<&str>,
+    ) -> Result<ImportedAttachment, AttachmentServiceError> {
+        self.import_reader(bytes, original_name)
+    }
+
+    /// Resolves an `attachment://` URI (or bare digest) to its blob path.
+    pub fn resolve(&self, uri: &str) -> Result<(Attachment, PathBuf), AttachmentServiceError> {
+        let sha256 = parse_attachment_uri(uri)
+            .ok_or_else(|| AttachmentServiceError::InvalidUri(uri.to_string()))?;
+        let attachment = self
+            .repo
+            .get_attachment(&sha256)?
+            .ok_or_else(|| AttachmentServiceError::AttachmentNotFound(sha256.clone()))?;
+        let path = self.blob_path(&sha256);
+        if !path.is_file() {
+            return Err(AttachmentServiceError::AttachmentNotFound(sha256));
+        }
+        Ok((attachment, path))
+    }
+
+    /// Lists attachments referenced by one atom, in document order.
+    pub fn list_for_atom(
+        &self,
+        atom_id: AtomId,
+    ) -> Result<Vec<Attachment>, AttachmentServiceError> {
+        Ok(self.repo.list_atom_attachments(atom_id)?)
+    }
+
+    /// Removes unreferenced attachments older than `grace_ms` and stray blob
+    /// files that have no metadata row.
+    pub fn collect_garbage(
+        &self,
+        now_ms: i64,
+        grace_ms: i64,
+    ) -> Result<AttachmentGcSummary, AttachmentServiceError> {
+        let started_at = Instant::now();
+        let cutoff_ms = now_ms.saturating_sub(grace_ms.max(0));
+        let mut summary = AttachmentGcSummary::default();
+
+        for attachment in self.repo.delete_unreferenced(cutoff_ms)? {
+            summary.removed_rows += 1;
+            // Why: a concurrent import may have re-recorded the digest after
+            // the row delete; its blob must stay.
+            if self.repo.get_attachment(&attachment.sha256)?.is_some() {
+                continue;
+            }
+            if let Some(freed) = remove_file_if_present(&self.blob_path(&attachment.sha256))? {
+                summary.removed_blobs += 1;
+                summary.freed_bytes += freed;
+            }
+        }
+
+        let known: HashSet<String> = self.repo.list_attachment_digests()?.into_iter().collect();
+        let cutoff = SystemTime::UNIX_EPOCH + Duration::from_millis(cutoff_ms.max(0) as u64);
+        for path in self.list_store_files()? {
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            let stray = match parse_attachment_uri(name) {
+                Some(sha256) => !known.contains(&sha256),
+                None => name.ends_with(TEMP_FILE_SUFFIX),
+            };
+            if !stray || fs::metadata(&path)?.modified()? >= cutoff {
+                continue;
+            }
+            if let Some(freed) = remove_file_if_present(&path)? {
+                summary.removed_blobs += 1;
+                summary.freed_bytes += freed;
+            }
+        }
+
+        info!(
+            "event=attachments_gc module=service status=ok removed_rows={} removed_blobs={} freed_bytes={} duration_ms={}",
+            summary.removed_rows,
+            summary.removed_blobs,
+            summary.freed_bytes,
+            started_at.elapsed().as_millis()
+        );
+        Ok(summary)
+    }
+
+    fn import_reader(
+        &self,
+        mut reader: impl Read,
+        original_name: Option<&str>,
+    ) -> Result<ImportedAttachment, AttachmentServiceError> {
+        let started_at = Instant::now();
+        fs::create_dir_all(&self.store_root)?;
+        let temp_path = self
+            .store_root
+            .join(format!("{}{TEMP_FILE_SUFFIX}", Uuid::new_v4()));
+
+        let written = (|| -> io::Result<(String, u64)> {
+            let mut temp = File::create(&temp_path)?;
+            let mut hasher = Sha256::new();
+            let mut buffer = [0_u8; 64 * 1024];
+            let mut total = 0_u64;
+            loop {
+                let read = reader.read(&mut buffer)?;
+                if read == 0 {
+                    break;
+                }
+                hasher.update(&buffer[..read]);
+                temp.write_all(&buffer[..read])?;
+                total += read as u64;
+            }
+            temp.sync_all()?;
+            Ok((format!("{:x}", hasher.finalize()), total))
+        })();
+        let (sha256, byte_size) = match written {
+            Ok(value) => value,
+            Err(err) => {
+                let _ = fs::remove_file(&temp_path);
+                return Err(err.into());
+            }
+        };
+
+        let blob_path = self.blob_path(&sha256);
+        if blob_path.is_file() {
+            fs::remove_file(&temp_path)?;
+        } else {
+            if let Some(parent) = blob_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            if let Err(err) = fs::rename(&temp_path, &blob_path) {
+                let _ = fs::remove_file(&temp_path);
+                return Err(err.into());
+            }
+        }
+
+        let original_name = original_name.map(str::trim).filter(|name| !name.is_empty());
+        let mime_type = original_name.and_then(guess_mime_type);
+        let (attachment, deduplicated) = self.repo.upsert_attachment(
+            &sha256,
+            i64::try_from(byte_size).unwrap_or(i64::MAX),
+            mime_type,
+            original_name,
+            now_epoch_ms(),
+        )?;
+
+        info!(
+            "event=attachment_import module=service status=ok byte_size={} deduplicated={} duration_ms={}",
+            byte_size,
+            deduplicated,
+            started_at.elapsed().as_millis()
+        );
+        Ok(ImportedAttachment {
+            uri: attachment.uri(),
+            attachment,
+            deduplicated,
+        })
+    }
+
+    fn blob_path(&self, sha256: &str) -> PathBuf {
+        self.store_root.join(&sha256[..2]).join(sha256)
+    }
+
+    /// Lists files in the store root and its fan-out directories.
+    fn list_store_files(&self) -> io::Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        let entries = match fs::read_dir(&self.store_root) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(files),
+            Err(err) => return Err(err),
+        };
+        for entry in entries {
+            let path = entry?.path();
+            if path.is_dir() {
+                for nested in fs::read_dir(&path)? {
+                    let nested = nested?.path();
+                    if nested.is_file() {
+                        files.push(nested);
+                    }
+                }
+            } else if path.is_file() {
+                files.push(path);
+            }
+        }
+        Ok(files)
+    }
+}
+
+/// Removes one file, returning its size, or `None` when already gone.
+fn remove_file_if_present(path: &Path) -> io::Result<Option<u64>> {
+    let size = match fs::metadata(path) {
+        Ok(metadata) => metadata.len(),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    match fs::remove_file(path) {
+        Ok(()) => Ok(Some(size)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => {
+            warn!("event=attachments_gc module=service status=error error_code=blob_remove_failed");
+            Err(err)
+        }
+    }
+}
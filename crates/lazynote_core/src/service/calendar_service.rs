@@ -0,0 +1,463 @@
+//! Calendar aggregation use-cases for day/week/month views.
+//!
+//! # Responsibility
+//! - Bucket timed and all-day atoms into local days, including spans that
+//!   cross midnight or cover several dates.
+//! - Lay out overlapping timed entries of one day into columns.
+//! - Detect double-booked events and find free slots of a minimum length.
+//!
+//! # Invariants
+//! - Days follow the service's [`LocalCalendar`]: DST days are 23h or 25h.
+//! - Timed atoms occupy `[start_at, end_at)`; a point-in-time atom
+//!   (`start_at == end_at`) occupies its start instant only.
+//! - All-day atoms are listed per local date and never block time.
+//! - Cancelled atoms are listed but never conflict or block time.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::{Atom, AtomType, TaskStatus};
+use crate::model::local_time::{floating_dates, LocalCalendar, LocalWindow, DAY_MS};
+use crate::repo::atom_repo::{AtomRepository, RepoError, SectionAtomRow};
+use chrono::{Duration, NaiveDate};
+use std::cmp::Reverse;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Longest range, in days, one calendar query may cover.
+pub const CALENDAR_MAX_DAYS: i64 = 93;
+
+/// Rows (occurrences included) loaded per repository page.
+const CALENDAR_PAGE_SIZE: u32 = 10_000;
+
+/// One atom (or recurring occurrence) placed on one local day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEntry {
+    /// The atom; virtual occurrences carry shifted `start_at`/`end_at`.
+    pub atom: Atom,
+    /// Original start for virtual recurring occurrences; `None` for stored rows.
+    pub occurrence_start_at: Option<i64>,
+    /// Start of the part shown on this day, epoch ms.
+    pub visible_start_ms: i64,
+    /// End (exclusive) of the part shown on this day, epoch ms.
+    pub visible_end_ms: i64,
+    /// Whether the atom started on an earlier day.
+    pub continues_before: bool,
+    /// Whether the atom ends on a later day.
+    pub continues_after: bool,
+    /// Zero-based layout column inside its overlap group (`0` for all-day).
+    pub column: u32,
+    /// Columns used by its overlap group (`1` for all-day).
+    pub column_count: u32,
+}
+
+/// Atoms of one local day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarDay {
+    /// Local date.
+    pub date: NaiveDate,
+    /// First instant of the day, epoch ms.
+    pub start_ms: i64,
+    /// Last instant of the day, epoch ms (inclusive).
+    pub end_ms: i64,
+    /// All-day atoms covering the date, ordered by first date.
+    pub all_day: Vec<CalendarEntry>,
+    /// Timed atoms touching the day, ordered by visible start.
+    pub timed: Vec<CalendarEntry>,
+}
+
+/// Two overlapping events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarConflict {
+    /// Event that starts first.
+    pub first: Atom,
+    /// Occurrence start of `first` when it is a recurring occurrence.
+    pub first_occurrence_start_at: Option<i64>,
+    /// Event overlapping `first`.
+    pub second: Atom,
+    /// Occurrence start of `second` when it is a recurring occurrence.
+    pub second_occurrence_start_at: Option<i64>,
+    /// Start of the shared time, epoch ms.
+    pub overlap_start_ms: i64,
+    /// End (exclusive) of the shared time, epoch ms.
+    pub overlap_end_ms: i64,
+}
+
+/// A gap without busy timed atoms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FreeSlot {
+    /// Start of the gap, epoch ms.
+    pub start_ms: i64,
+    /// End (exclusive) of the gap, epoch ms.
+    pub end_ms: i64,
+}
+
+/// Errors from calendar service operations.
+#[derive(Debug)]
+pub enum CalendarServiceError {
+    /// Last date is before the first date.
+    InvalidDateRange { first: NaiveDate, last: NaiveDate },
+    /// Range end is not after its start.
+    InvalidTimeRange { start_ms: i64, end_ms: i64 },
+    /// Range covers more than [`CALENDAR_MAX_DAYS`].
+    RangeTooLarge { days: i64 },
+    /// Free-slot length must be at least one minute.
+    InvalidSlotDuration(u32),
+    /// Repository-level error.
+    Repo(RepoError),
+}
+
+impl Display for CalendarServiceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidDateRange { first, last } => {
+                write!(f, "last date {last} is before first date {first}")
+            }
+            Self::InvalidTimeRange { start_ms, end_ms } => {
+                write!(f, "range end ({end_ms}) must be after start ({start_ms})")
+            }
+            Self::RangeTooLarge { days } => write!(
+                f,
+                "calendar range of {days} days exceeds {CALENDAR_MAX_DAYS} days"
+            ),
+            Self::InvalidSlotDuration(minutes) => {
+                write!(f, "slot duration must be at least 1 minute, got {minutes}")
+            }
+            Self::Repo(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for CalendarServiceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Repo(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<RepoError> for CalendarServiceError {
+    fn from(err: RepoError) -> Self {
+        Self::Repo(err)
+    }
+}
+
+/// Service for calendar views over one local calendar.
+pub struct CalendarService<'conn, R: AtomRepository> {
+    repo: &'conn R,
+    calendar: LocalCalendar,
+}
+
+impl<'conn, R: AtomRepository> CalendarService<'conn, R> {
+    /// Creates a service computing days in `calendar`.
+    pub fn new(repo: &'conn R, calendar: LocalCalendar) -> Self {
+        Self { repo, calendar }
+    }
+
+    /// Returns one bucket per local date in `first..=last`.
+    ///
+    /// Atoms spanning several days appear in every day they touch, with
+    /// `continues_before`/`continues_after` set. Timed entries carry overlap
+    /// columns.
+    ///
+    /// # Errors
+    /// - [`CalendarServiceError::InvalidDateRange`] when `last < first`.
+    /// - [`CalendarServiceError::RangeTooLarge`] above [`CALENDAR_MAX_DAYS`].
+    pub fn days(
+        &self,
+        first: NaiveDate,
+        last: NaiveDate,
+    ) -> Result<Vec<CalendarDay>, CalendarServiceError> {
+        let rows = self.load_dates(first, last)?;
+        let mut days = Vec::new();
+        let mut date = first;
+        while date <= last {
+            days.push(self.bucket_day(date, &rows));
+            date += Duration::days(1);
+        }
+        Ok(days)
+    }
+
+    /// Returns the days of the local week containing `now_ms`.
+    pub fn week_days(&self, now_ms: i64) -> Result<Vec<CalendarDay>, CalendarServiceError> {
+        let (first, last) = self.window_dates(self.calendar.week_window(now_ms));
+        self.days(first, last)
+    }
+
+    /// Returns the days of the local month containing `now_ms`.
+    pub fn month_days(&self, now_ms: i64) -> Result<Vec<CalendarDay>, CalendarServiceError> {
+        let (first, last) = self.window_dates(self.calendar.month_window(now_ms));
+        self.days(first, last)
+    }
+
+    /// Returns every pair of overlapping events touching `first..=last`,
+    /// ordered by overlap start.
+    ///
+    /// Only timed `event` atoms with a positive duration and a status other
+    /// than `cancelled` are considered.
+    pub fn conflicts(
+        &self,
+        first: NaiveDate,
+        last: NaiveDate,
+    ) -> Result<Vec<CalendarConflict>, CalendarServiceError> {
+        let mut events: Vec<SectionAtomRow> = self
+            .load_dates(first, last)?
+            .into_iter()
+            .filter(|row| row.atom.kind == AtomType::Event && busy_interval(&row.atom).is_some())
+            .collect();
+        events.sort_by_key(|row| (row.atom.start_at, row.atom.end_at, row.atom.uuid));
+
+        let mut conflicts = Vec::new();
+        for (index, first) in events.iter().enumerate() {
+            let Some((first_start, first_end)) = busy_interval(&first.atom) else {
+                continue;
+            };
+            for second in &events[index + 1..] {
+                let Some((second_start, second_end)) = busy_interval(&second.atom) else {
+                    continue;
+                };
+                if second_start >= first_end {
+                    break;
+                }
+                conflicts.push(CalendarConflict {
+                    first: first.atom.clone(),
+                    first_occurrence_start_at: first.occurrence_start_at,
+                    second: second.atom.clone(),
+                    second_occurrence_start_at: second.occurrence_start_at,
+                    overlap_start_ms: first_start.max(second_start),
+                    overlap_end_ms: first_end.min(second_end),
+                });
+            }
+        }
+        conflicts.sort_by_key(|conflict| (conflict.overlap_start_ms, conflict.overlap_end_ms));
+        Ok(conflicts)
+    }
+
+    /// Returns maximal gaps of at least `min_minutes` inside
+    /// `[range_start_ms, range_end_ms)`.
+    ///
+    /// Busy time is every timed atom (any kind) with a positive duration
+    /// whose status is not `cancelled`; all-day atoms never block time.
+    ///
+    /// # Errors
+    /// - [`CalendarServiceError::InvalidTimeRange`] when the range is empty.
+    /// - [`CalendarServiceError::RangeTooLarge`] above [`CALENDAR_MAX_DAYS`].
+    /// - [`CalendarServiceError::InvalidSlotDuration`] for `0` minutes.
+    pub fn free_slots(
+        &self,
+        range_start_ms: i64,
+        range_end_ms: i64,
+        min_minutes: u32,
+    ) -> Result<Vec<FreeSlot>, CalendarServiceError> {
+        if range_end_ms <= range_start_ms {
+            return Err(CalendarServiceError::InvalidTimeRange {
+                start_ms: range_start_ms,
+                end_ms: range_end_ms,
+            });
+        }
+        let days = (range_end_ms - range_start_ms + DAY_MS - 1) / DAY_MS;
+        if days > CALENDAR_MAX_DAYS {
+            return Err(CalendarServiceError::RangeTooLarge { days });
+        }
+        if min_minutes == 0 {
+            return Err(CalendarServiceError::InvalidSlotDuration(min_minutes));
+        }
+        let min_ms = i64::from(min_minutes) * 60_000;
+
+        let window = LocalWindow::from_instants(range_start_ms, range_end_ms - 1);
+        let mut busy: Vec<(i64, i64)> = self
+            .fetch_window(window)?
+            .iter()
+            .filter_map(|row| busy_interval(&row.atom))
+            .map(|(start, end)| (start.max(range_start_ms), end.min(range_end_ms)))
+            .filter(|(start, end)| start < end)
+            .collect();
+        busy.sort_unstable();
+
+        let mut slots = Vec::new();
+        let mut cursor = range_start_ms;
+        for (start, end) in busy {
+            if start - cursor >= min_ms {
+                slots.push(FreeSlot {
+                    start_ms: cursor,
+                    end_ms: start,
+                });
+            }
+            cursor = cursor.max(end);
+        }
+        if range_end_ms - cursor >= min_ms {
+            slots.push(FreeSlot {
+                start_ms: cursor,
+                end_ms: range_end_ms,
+            });
+        }
+        Ok(slots)
+    }
+
+    fn load_dates(
+        &self,
+        first: NaiveDate,
+        last: NaiveDate,
+    ) -> Result<Vec<SectionAtomRow>, CalendarServiceError> {
+        if last < first {
+            return Err(CalendarServiceError::InvalidDateRange { first, last });
+        }
+        let days = (last - first).num_days() + 1;
+        if days > CALENDAR_MAX_DAYS {
+            return Err(CalendarServiceError::RangeTooLarge { days });
+        }
+        self.fetch_window(self.calendar.dates_window(first, last))
+    }
+
+    /// Loads every row touching `window`, paging until the repository
+    /// returns a short page.
+    fn fetch_window(
+        &self,
+        window: LocalWindow,
+    ) -> Result<Vec<SectionAtomRow>, CalendarServiceError> {
+        let mut rows = Vec::new();
+        loop {
+            let offset = u32::try_from(rows.len()).unwrap_or(u32::MAX);
+            let page = self
+                .repo
+                .fetch_calendar_window(window, CALENDAR_PAGE_SIZE, offset)?;
+            let exhausted = page.len() < CALENDAR_PAGE_SIZE as usize;
+            rows.extend(page);
+            if exhausted {
+                return Ok(rows);
+            }
+        }
+    }
+
+    fn window_dates(&self, window: LocalWindow) -> (NaiveDate, NaiveDate) {
+        (
+            self.calendar.local_date(window.start_ms),
+            self.calendar.local_date(window.end_ms),
+        )
+    }
+
+    fn bucket_day(&self, date: NaiveDate, rows: &[SectionAtomRow]) -> CalendarDay {
+        let window = self.calendar.dates_window(date, date);
+        let day_end = window.end_ms + 1;
+        let mut all_day = Vec::new();
+        let mut timed = Vec::new();
+        for row in rows {
+            let (Some(start), Some(end)) = (row.atom.start_at, row.atom.end_at) else {
+                continue;
+            };
+            if row.atom.all_day {
+                let Some((first, last)) = floating_dates(Some(start), Some(end)) else {
+                    continue;
+                };
+                if first <= date && date <= last {
+                    all_day.push(day_entry(
+                        row,
+                        (window.start_ms, day_end),
+                        first < date,
+                        last > date,
+                    ));
+                }
+                continue;
+            }
+            // Why: a point-in-time atom still occupies its start instant.
+            let occupied_end = end.max(start + 1);
+            if start < day_end && occupied_end > window.start_ms {
+                timed.push(day_entry(
+                    row,
+                    (start.max(window.start_ms), end.min(day_end)),
+                    start < window.start_ms,
+                    end > day_end,
+                ));
+            }
+        }
+        layout_columns(&mut timed);
+        CalendarDay {
+            date,
+            start_ms: window.start_ms,
+            end_ms: window.end_ms,
+            all_day,
+            timed,
+        }
+    }
+}
+
+fn day_entry(
+    row: &SectionAtomRow,
+    (visible_start_ms, visible_end_ms): (i64, i64),
+    continues_before: bool,
+    continues_after: bool,
+) -> CalendarEntry {
+    CalendarEntry {
+        atom: row.atom.clone(),
+        occurrence_start_at: row.occurrence_start_at,
+        visible_start_ms,
+        visible_end_ms,
+        continues_before,
+        continues_after,
+        column: 0,
+        column_count: 1,
+    }
+}
+
+/// Time an atom blocks, `None` for all-day, cancelled or zero-length atoms.
+fn busy_interval(atom: &Atom) -> Option<(i64, i64)> {
+    if atom.all_day || atom.task_status == Some(TaskStatus::Cancelled) {
+        return None;
+    }
+    match (atom.start_at, atom.end_at) {
+        (Some(start), Some(end)) if end > start => Some((start, end)),
+        _ => None,
+    }
+}
+
+/// Sorts timed entries and assigns columns per group of transitively
+/// overlapping entries (first free column wins, longer entries first).
+fn layout_columns(entries: &mut [CalendarEntry]) {
+    entries.sort_by_key(|entry| {
+        (
+            entry.visible_start_ms,
+            Reverse(entry.visible_end_ms),
+            entry.atom.uuid,
+        )
+    });
+    let mut group_start = 0;
+    let mut group_end = i64::MIN;
+    let mut column_ends: Vec<i64> = Vec::new();
+    for index in 0..entries.len() {
+        let start = entries[index].visible_start_ms;
+        let end = entries[index].visible_end_ms.max(start + 1);
+        if start >= group_end {
+            close_group(&mut entries[group_start..index], column_ends.len());
+            group_start = index;
+            column_ends.clear();
+        }
+        let column = match column_ends
+            .iter()
+            .position(|column_end| *column_end <= start)
+        {
+            Some(column) => {
+                column_ends[column] = end;
+                column
+            }
+            None => {
+                column_ends.push(end);
+                column_ends.len() - 1
+            }
+        };
+        entries[index].column = column as u32;
+        group_end = if index == group_start {
+            end
+        } else {
+            group_end.max(end)
+        };
+    }
+    let len = entries.len();
+    close_group(&mut entries[group_start..len], column_ends.len());
+}
+
+fn close_group(group: &mut [CalendarEntry], column_count: usize) {
+    for entry in group {
+        entry.column_count = column_count as u32;
+    }
+}
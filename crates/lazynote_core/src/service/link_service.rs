@@ -0,0 +1,61 @@
+//! Wiki-link graph use-cases.
+//!
+//! # Responsibility
+//! - Expose outgoing links, backlinks and unresolved references.
+//! - Offer an explicit rebuild for content saved before link indexing.
+//!
+//! # Invariants
+//! - Link extraction happens in repository write paths; this service only
+//!   reads the derived graph (and triggers full rebuilds).
+//! - Unresolved list limit defaults to 50 and clamps to 200.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::AtomId;
+use crate::model::link::AtomLink;
+use crate::repo::atom_repo::RepoResult;
+use crate::repo::link_repo::LinkRepository;
+
+const UNRESOLVED_DEFAULT_LIMIT: u32 = 50;
+const UNRESOLVED_LIMIT_MAX: u32 = 200;
+
+/// Link graph service facade over repository implementations.
+pub struct LinkService<R: LinkRepository> {
+    repo: R,
+}
+
+impl<R: LinkRepository> LinkService<R> {
+    /// Creates a service using the provided repository implementation.
+    pub fn new(repo: R) -> Self {
+        Self { repo }
+    }
+
+    /// Lists `[[...]]` references written in one atom, in document order.
+    pub fn list_outgoing_links(&self, atom_id: AtomId) -> RepoResult<Vec<AtomLink>> {
+        self.repo.list_outgoing_links(atom_id)
+    }
+
+    /// Lists links from other active atoms that resolve to `atom_id`.
+    pub fn list_backlinks(&self, atom_id: AtomId) -> RepoResult<Vec<AtomLink>> {
+        self.repo.list_backlinks(atom_id)
+    }
+
+    /// Lists references whose target is missing or deleted.
+    pub fn list_unresolved_links(
+        &self,
+        limit: Option<u32>,
+        offset: u32,
+    ) -> RepoResult<Vec<AtomLink>> {
+        let limit = match limit {
+            Some(0) | None => UNRESOLVED_DEFAULT_LIMIT,
+            Some(value) => value.min(UNRESOLVED_LIMIT_MAX),
+        };
+        self.repo.list_unresolved_links(limit, offset)
+    }
+
+    /// Re-indexes links for every atom; returns the number of atoms indexed.
+    pub fn rebuild_links(&self) -> RepoResult<u32> {
+        self.repo.rebuild_all_links()
+    }
+}
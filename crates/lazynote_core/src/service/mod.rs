@@ -8,6 +8,15 @@
 //! - docs/releases/v0.1/prs/PR-0006-core-crud.md
 
 pub mod atom_service;
+pub mod attachment_service;
+pub mod calendar_service;
+pub mod link_service;
 pub mod note_service;
+pub mod recurrence_service;
+pub mod reminder_service;
+pub mod resource_link_service;
+pub mod revision_service;
 pub mod task_service;
+pub mod time_tracking_service;
+pub mod trash_service;
 pub mod tree_service;
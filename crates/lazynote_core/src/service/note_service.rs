@@ -2,37 +2,36 @@
 //!
 //! # Responsibility
 //! - Provide note-specific create/update/get/list APIs.
-//! - Derive markdown preview projections (`preview_text`, `preview_image`).
+//! - Derive markdown preview projections (`preview_text`, `preview_image`)
+//!   and the heading outline from a CommonMark/GFM parse.
 //! - Normalize and atomically replace note tags.
+//! - Expose which tags are manual and which come from inline `#hashtags`.
+//! - Rename, merge and delete hierarchical tags; report per-tag usage.
 //!
 //! # Invariants
 //! - `note_update` uses full content replacement semantics.
 //! - Note list is always sorted by `updated_at DESC, uuid ASC`.
 //! - Tag names are normalized to lowercase and deduplicated.
+//! - Tag edits apply to the whole `/` subtree of the named tag.
 //!
 //! # See also
 //! - docs/architecture/note-schema.md
 
 use crate::model::atom::{Atom, AtomId, AtomType};
+use crate::model::frontmatter::AtomProperty;
+use crate::model::markdown::{analyze_markdown, ContentStats, OutlineHeading};
+use crate::model::tag_expr::TagExpr;
 use crate::repo::atom_repo::{RepoError, RepoResult};
 use crate::repo::note_repo::{
     normalize_note_limit, normalize_tag, normalize_tags, NoteListQuery, NoteRecord, NoteRepository,
+    NoteTag, TagEditOutcome, TagUsage,
 };
+use crate::repo::title_repo::NoteTitle;
 use log::{error, info};
-use once_cell::sync::Lazy;
-use regex::Regex;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::time::Instant;
 
-static MARKDOWN_IMAGE_RE: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"!\[[^\]]*]\(([^)]+)\)").expect("valid image regex"));
-static MARKDOWN_LINK_RE: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"\[([^\]]+)\]\(([^)]+)\)").expect("valid link regex"));
-static MARKDOWN_SYMBOL_RE: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r#"[\*_`#>~\-\[\]\(\)!]+"#).expect("valid markdown symbol regex"));
-static WHITESPACE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+").expect("valid ws regex"));
-
 /// Service error for note use-cases.
 #[derive(Debug)]
 pub enum NoteServiceError {
@@ -40,6 +39,10 @@ pub enum NoteServiceError {
     InvalidTag(String),
     /// Target note does not exist.
     NoteNotFound(AtomId),
+    /// Tag (and every descendant) is unknown to storage.
+    TagNotFound(String),
+    /// Rename target already exists; merge instead.
+    TagConflict(String),
     /// Persistence-layer failure.
     Repo(RepoError),
     /// Internal consistency mismatch between write and read-back.
@@ -51,6 +54,8 @@ impl Display for NoteServiceError {
         match self {
             Self::InvalidTag(value) => write!(f, "invalid tag: `{value}`"),
             Self::NoteNotFound(atom_id) => write!(f, "note not found: {atom_id}"),
+            Self::TagNotFound(value) => write!(f, "tag not found: `{value}`"),
+            Self::TagConflict(value) => write!(f, "tag already exists: `{value}`"),
             Self::Repo(err) => write!(f, "{err}"),
             Self::InconsistentState(details) => write!(f, "inconsistent note state: {details}"),
         }
@@ -93,6 +98,15 @@ pub struct MarkdownPreview {
     pub preview_image: Option<String>,
 }
 
+/// Heading tree plus statistics of one note.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteOutline {
+    /// Top-level headings with nested children.
+    pub headings: Vec<OutlineHeading>,
+    /// Word/character counts and checklist progress.
+    pub stats: ContentStats,
+}
+
 /// Note service facade over repository implementations.
 pub struct NoteService<R: NoteRepository> {
     repo: R,
@@ -228,6 +242,7 @@ impl<R: NoteRepository> NoteService<R> {
         let applied_limit = normalize_note_limit(limit);
         let query = NoteListQuery {
             tag: normalized_tag,
+            tag_expr: None,
             limit: Some(applied_limit),
             offset,
         };
@@ -238,7 +253,33 @@ impl<R: NoteRepository> NoteService<R> {
         })
     }
 
-    /// Atomically replaces the full tag set for one note.
+    /// Lists notes matching a boolean tag expression with pagination.
+    ///
+    /// Untagged notes match `NOT x` expressions.
+    pub fn list_notes_by_tags(
+        &self,
+        tag_expr: TagExpr,
+        limit: Option<u32>,
+        offset: u32,
+    ) -> Result<NotesListResult, NoteServiceError> {
+        let applied_limit = normalize_note_limit(limit);
+        let query = NoteListQuery {
+            tag: None,
+            tag_expr: Some(tag_expr),
+            limit: Some(applied_limit),
+            offset,
+        };
+        let items = self.repo.list_notes(&query)?;
+        Ok(NotesListResult {
+            items,
+            applied_limit,
+        })
+    }
+
+    /// Atomically replaces the full manual tag set for one note.
+    ///
+    /// Inline `#hashtag` tags stay owned by the content; passing them here
+    /// does not pin them as manual tags.
     pub fn set_note_tags(
         &mut self,
         atom_id: AtomId,
@@ -259,38 +300,176 @@ impl<R: NoteRepository> NoteService<R> {
             ))
     }
 
+    /// Lists one note's tag links with their `manual` / `inline` source.
+    pub fn list_note_tags(&self, atom_id: AtomId) -> Result<Vec<NoteTag>, NoteServiceError> {
+        Ok(self.repo.list_note_tags(atom_id)?)
+    }
+
+    /// Returns one note's heading tree and cached content statistics.
+    pub fn note_outline(&self, atom_id: AtomId) -> Result<NoteOutline, NoteServiceError> {
+        let note = self
+            .repo
+            .get_note(atom_id)?
+            .ok_or(NoteServiceError::NoteNotFound(atom_id))?;
+        Ok(NoteOutline {
+            headings: analyze_markdown(&note.content).outline,
+            stats: note.stats,
+        })
+    }
+
+    /// Lists one note's typed frontmatter properties.
+    pub fn list_note_properties(
+        &self,
+        atom_id: AtomId,
+    ) -> Result<Vec<AtomProperty>, NoteServiceError> {
+        Ok(self.repo.list_note_properties(atom_id)?)
+    }
+
+    /// Returns one note's title projection.
+    pub fn note_title(&self, atom_id: AtomId) -> Result<NoteTitle, NoteServiceError> {
+        Ok(self.repo.get_note_title(atom_id)?)
+    }
+
+    /// Sets an explicit title, or clears it with `None`/blank input so the
+    /// title follows content again.
+    ///
+    /// Whitespace runs collapse to one space. note_refs that were never
+    /// renamed manually take the new title.
+    pub fn set_note_title(
+        &mut self,
+        atom_id: AtomId,
+        title: Option<String>,
+    ) -> Result<NoteTitle, NoteServiceError> {
+        let normalized = title
+            .map(|value| value.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|value| !value.is_empty());
+        Ok(self.repo.set_note_title(atom_id, normalized.as_deref())?)
+    }
+
+    /// Re-derives inline hashtag tags for all stored atoms.
+    ///
+    /// Used once after upgrading databases whose content predates inline
+    /// tag extraction; returns the number of atoms scanned.
+    pub fn rebuild_inline_tags(&mut self) -> RepoResult<u32> {
+        self.repo.rebuild_inline_tags()
+    }
+
     /// Lists normalized tags known by storage.
     pub fn list_tags(&self) -> RepoResult<Vec<String>> {
         self.repo.list_tags()
     }
+
+    /// Renames one tag and its descendants (`work` -> `job` also moves
+    /// `work/client` to `job/client`). Returns the number of atoms touched.
+    ///
+    /// # Errors
+    /// - [`NoteServiceError::TagConflict`] when a renamed path already exists.
+    pub fn rename_tag(&mut self, from: &str, to: &str) -> Result<u32, NoteServiceError> {
+        let started_at = Instant::now();
+        let from = require_tag(from)?;
+        let to = require_tag(to)?;
+        let outcome = self.repo.rename_tag(&from, &to);
+        finish_tag_edit("tag_rename", started_at, outcome, &from, &to)
+    }
+
+    /// Merges `sources` (and their descendants) into `target`, deduplicating
+    /// links on atoms that already carry the target. Returns atoms touched.
+    ///
+    /// # Errors
+    /// - [`NoteServiceError::InvalidTag`] when `target` sits below a source.
+    /// - [`NoteServiceError::TagNotFound`] when no source exists.
+    pub fn merge_tags(
+        &mut self,
+        sources: Vec<String>,
+        target: &str,
+    ) -> Result<u32, NoteServiceError> {
+        let started_at = Instant::now();
+        let target = require_tag(target)?;
+        let mut normalized = Vec::with_capacity(sources.len());
+        for source in &sources {
+            let source = require_tag(source)?;
+            if target.starts_with(&format!("{source}/")) {
+                return Err(NoteServiceError::InvalidTag(target));
+            }
+            normalized.push(source);
+        }
+        if normalized.is_empty() {
+            return Err(NoteServiceError::InvalidTag(String::new()));
+        }
+        let outcome = self.repo.merge_tags(&normalized, &target);
+        finish_tag_edit(
+            "tag_merge",
+            started_at,
+            outcome,
+            &normalized.join(", "),
+            &target,
+        )
+    }
+
+    /// Removes one tag and its descendants from every atom. Returns the
+    /// number of atoms touched.
+    pub fn delete_tag(&mut self, name: &str) -> Result<u32, NoteServiceError> {
+        let started_at = Instant::now();
+        let name = require_tag(name)?;
+        let outcome = self.repo.delete_tag(&name);
+        finish_tag_edit("tag_delete", started_at, outcome, &name, &name)
+    }
+
+    /// Lists usage counters for every tag path sorted by name; implicit
+    /// parents (`work` for a lone `work/client`) report `direct_count = 0`.
+    pub fn list_tag_usage(&self) -> RepoResult<Vec<TagUsage>> {
+        self.repo.list_tag_usage()
+    }
+}
+
+fn require_tag(value: &str) -> Result<String, NoteServiceError> {
+    normalize_tag(value).ok_or_else(|| NoteServiceError::InvalidTag(value.to_string()))
+}
+
+fn finish_tag_edit(
+    event: &str,
+    started_at: Instant,
+    outcome: RepoResult<TagEditOutcome>,
+    source: &str,
+    target: &str,
+) -> Result<u32, NoteServiceError> {
+    let err = match outcome {
+        Ok(TagEditOutcome::Applied(affected)) => {
+            info!(
+                "event={event} module=service status=ok duration_ms={} affected_atoms={affected}",
+                started_at.elapsed().as_millis()
+            );
+            return Ok(affected);
+        }
+        Ok(TagEditOutcome::SourceNotFound) => NoteServiceError::TagNotFound(source.to_string()),
+        Ok(TagEditOutcome::TargetExists) => NoteServiceError::TagConflict(target.to_string()),
+        Err(err) => NoteServiceError::from(err),
+    };
+    let error_code = match &err {
+        NoteServiceError::TagNotFound(_) => "tag_not_found",
+        NoteServiceError::TagConflict(_) => "tag_conflict",
+        _ => "repo_write_failed",
+    };
+    error!(
+        "event={event} module=service status=error duration_ms={} error_code={error_code}",
+        started_at.elapsed().as_millis()
+    );
+    Err(err)
 }
 
 /// Derives note preview fields from markdown content.
 ///
-/// Rules:
-/// - `preview_image`: first markdown image path matched by regex.
-/// - `preview_text`: markdown symbols removed, whitespace normalized, first
-///   100 chars retained.
+/// Rules (CommonMark/GFM parse, see [`analyze_markdown`]):
+/// - Leading YAML frontmatter is skipped.
+/// - `preview_image`: destination of the first image, inline or
+///   reference-style.
+/// - `preview_text`: rendered text without code blocks, HTML or image alt
+///   text, whitespace normalized, first 100 chars retained.
 pub fn derive_markdown_preview(content: &str) -> MarkdownPreview {
-    let preview_image = MARKDOWN_IMAGE_RE
-        .captures(content)
-        .and_then(|caps| caps.get(1).map(|m| m.as_str().trim().to_string()))
-        .filter(|value| !value.is_empty());
-
-    let without_images = MARKDOWN_IMAGE_RE.replace_all(content, " ");
-    let without_links = MARKDOWN_LINK_RE.replace_all(&without_images, "$1");
-    let without_symbols = MARKDOWN_SYMBOL_RE.replace_all(&without_links, " ");
-    let normalized = WHITESPACE_RE.replace_all(&without_symbols, " ");
-    let trimmed = normalized.trim();
-    let preview_text = if trimmed.is_empty() {
-        None
-    } else {
-        Some(trimmed.chars().take(100).collect())
-    };
-
+    let analysis = analyze_markdown(content);
     MarkdownPreview {
-        preview_text,
-        preview_image,
+        preview_text: analysis.preview_text,
+        preview_image: analysis.preview_image,
     }
 }
 
@@ -339,6 +518,12 @@ Paragraph with [ref](https://example.com/path?q=1) and **bold** + `code`.
         assert!(text.len() <= 100);
     }
 
+    #[test]
+    fn preview_skips_yaml_frontmatter() {
+        let preview = derive_markdown_preview("---\ntitle: Plan\ntags: [a]\n---\n# Agenda\nitems");
+        assert_eq!(preview.preview_text.as_deref(), Some("Agenda items"));
+    }
+
     #[test]
     fn preview_returns_none_for_symbol_only_content() {
         let preview = derive_markdown_preview("### *** ``` ~~ []() ![]()");
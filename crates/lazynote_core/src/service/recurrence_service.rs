@@ -0,0 +1,342 @@
+//! Recurring atom occurrence editing use-cases.
+//!
+//! # Responsibility
+//! - Edit or delete one occurrence ("this occurrence only").
+//! - Edit or delete an occurrence and the rest of its series
+//!   ("this and following").
+//! - Set or clear the rule of one atom.
+//!
+//! # Invariants
+//! - Occurrences are addressed by `(series_id, occurrence_start_at)` as
+//!   returned in `SectionAtomRow::occurrence_start_at`, expanded in the
+//!   atom repository calendar.
+//! - Editing the first occurrence with "this and following" edits the
+//!   series in place instead of splitting it.
+//! - Truncated series keep RFC 5545 semantics: `COUNT` rules are shortened,
+//!   open/`UNTIL` rules get an `UNTIL` just before the split point.
+
+use crate::model::atom::{Atom, AtomId, AtomValidationError, TaskStatus};
+use crate::model::local_time::LocalCalendar;
+use crate::model::recurrence::{RecurrenceRule, RecurrenceUntil};
+use crate::repo::atom_repo::{AtomRepository, RepoError};
+use crate::repo::recurrence_repo::RecurrenceRepository;
+use crate::service::note_service::derive_markdown_preview;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// Which occurrences of a series an edit/delete applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OccurrenceEditScope {
+    /// Only the addressed occurrence.
+    ThisOccurrence,
+    /// The addressed occurrence and every later one.
+    ThisAndFollowing,
+}
+
+/// Field changes applied to an occurrence.
+///
+/// `None` keeps the series value. When only `start_at` changes the
+/// occurrence duration is preserved.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OccurrencePatch {
+    /// Replacement content.
+    pub content: Option<String>,
+    /// Replacement occurrence start, epoch ms.
+    pub start_at: Option<i64>,
+    /// Replacement occurrence end, epoch ms.
+    pub end_at: Option<i64>,
+    /// Replacement status.
+    pub task_status: Option<TaskStatus>,
+    /// Replacement rule; honored only for [`OccurrenceEditScope::ThisAndFollowing`].
+    pub recurrence_rule: Option<String>,
+}
+
+/// Errors from recurrence service operations.
+#[derive(Debug)]
+pub enum RecurrenceServiceError {
+    /// Series atom does not exist or is soft-deleted.
+    SeriesNotFound(AtomId),
+    /// Target atom has no recurrence rule.
+    NotRecurring(AtomId),
+    /// Occurrence start is not generated by the rule or already excepted.
+    OccurrenceNotFound {
+        series_id: AtomId,
+        occurrence_start_at: i64,
+    },
+    /// Repository-level error.
+    Repo(RepoError),
+}
+
+impl Display for RecurrenceServiceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SeriesNotFound(id) => write!(f, "recurring series not found: {id}"),
+            Self::NotRecurring(id) => write!(f, "atom is not recurring: {id}"),
+            Self::OccurrenceNotFound {
+                series_id,
+                occurrence_start_at,
+            } => write!(
+                f,
+                "occurrence {occurrence_start_at} not found in series {series_id}"
+            ),
+            Self::Repo(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for RecurrenceServiceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Repo(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<RepoError> for RecurrenceServiceError {
+    fn from(err: RepoError) -> Self {
+        match err {
+            RepoError::NotFound(id) => Self::SeriesNotFound(id),
+            other => Self::Repo(other),
+        }
+    }
+}
+
+/// Service for occurrence-level edits of recurring atoms.
+pub struct RecurrenceService<'conn, A: AtomRepository, R: RecurrenceRepository> {
+    atoms: &'conn A,
+    recurrences: &'conn R,
+}
+
+/// Loaded series state shared by edit/delete flows.
+struct SeriesContext {
+    series: Atom,
+    rule: RecurrenceRule,
+    anchor: i64,
+    calendar: LocalCalendar,
+}
+
+impl<'conn, A: AtomRepository, R: RecurrenceRepository> RecurrenceService<'conn, A, R> {
+    /// Creates a service from existing repository references.
+    pub fn new(atoms: &'conn A, recurrences: &'conn R) -> Self {
+        Self { atoms, recurrences }
+    }
+
+    /// Sets or clears the rule of one atom and returns the updated atom.
+    ///
+    /// A rule turns the atom into a series anchored at its `start_at`;
+    /// `None` makes it a single atom again. Rules are stored in canonical
+    /// form. Existing exceptions are kept, so skipped and detached
+    /// occurrences stay excepted while the new rule still generates them.
+    ///
+    /// # Errors
+    /// - [`RecurrenceServiceError::SeriesNotFound`] for missing atoms.
+    /// - [`RecurrenceServiceError::Repo`] with a validation error for
+    ///   malformed rules or atoms without `start_at`.
+    pub fn set_rule(
+        &self,
+        atom_id: AtomId,
+        rule: Option<&str>,
+    ) -> Result<Atom, RecurrenceServiceError> {
+        let mut atom = self
+            .atoms
+            .get_atom(atom_id, false)?
+            .ok_or(RecurrenceServiceError::SeriesNotFound(atom_id))?;
+        atom.recurrence_rule = rule
+            .map(RecurrenceRule::parse)
+            .transpose()
+            .map_err(|err| RepoError::Validation(AtomValidationError::InvalidRecurrenceRule(err)))?
+            .map(|rule| rule.to_rrule_string());
+        self.atoms.update_atom(&atom)?;
+        Ok(atom)
+    }
+
+    /// Edits one occurrence and returns the atom ID now holding it.
+    ///
+    /// - `ThisOccurrence` detaches a standalone atom and returns its ID.
+    /// - `ThisAndFollowing` returns the series ID when editing the first
+    ///   occurrence, otherwise the ID of the newly split-off series.
+    pub fn edit_occurrence(
+        &self,
+        series_id: AtomId,
+        occurrence_start_at: i64,
+        scope: OccurrenceEditScope,
+        patch: &OccurrencePatch,
+    ) -> Result<AtomId, RecurrenceServiceError> {
+        let context = self.load_occurrence(series_id, occurrence_start_at)?;
+        let (start_at, end_at) = patched_window(&context.series, occurrence_start_at, patch);
+
+        match scope {
+            OccurrenceEditScope::ThisOccurrence => {
+                let mut detached = patched_copy(&context.series, patch);
+                detached.start_at = Some(start_at);
+                detached.end_at = end_at;
+                detached.recurrence_rule = None;
+                let id = self.recurrences.detach_occurrence(
+                    series_id,
+                    occurrence_start_at,
+                    &detached,
+                )?;
+                Ok(id)
+            }
+            OccurrenceEditScope::ThisAndFollowing if occurrence_start_at == context.anchor => {
+                let mut series = patched_copy(&context.series, patch);
+                series.uuid = series_id;
+                series.start_at = Some(start_at);
+                series.end_at = end_at;
+                if let Some(rule) = &patch.recurrence_rule {
+                    series.recurrence_rule = Some(rule.clone());
+                }
+                self.atoms.update_atom(&series)?;
+                Ok(series_id)
+            }
+            OccurrenceEditScope::ThisAndFollowing => {
+                let elapsed = context.rule.count_before(
+                    context.anchor,
+                    &context.calendar,
+                    occurrence_start_at,
+                );
+                let truncated = truncate_series(&context, occurrence_start_at, elapsed);
+
+                let mut following_rule = context.rule.clone();
+                following_rule.count = following_rule
+                    .count
+                    .map(|count| count.saturating_sub(elapsed).max(1));
+                let mut following = patched_copy(&context.series, patch);
+                following.start_at = Some(start_at);
+                following.end_at = end_at;
+                following.recurrence_rule = Some(
+                    patch
+                        .recurrence_rule
+                        .clone()
+                        .unwrap_or_else(|| following_rule.to_rrule_string()),
+                );
+
+                let id =
+                    self.recurrences
+                        .split_series(&truncated, occurrence_start_at, &following)?;
+                Ok(id)
+            }
+        }
+    }
+
+    /// Deletes one occurrence or the occurrence and all later ones.
+    ///
+    /// Deleting from the first occurrence with `ThisAndFollowing`
+    /// soft-deletes the whole series.
+    pub fn delete_occurrence(
+        &self,
+        series_id: AtomId,
+        occurrence_start_at: i64,
+        scope: OccurrenceEditScope,
+    ) -> Result<(), RecurrenceServiceError> {
+        let context = self.load_occurrence(series_id, occurrence_start_at)?;
+        match scope {
+            OccurrenceEditScope::ThisOccurrence => {
+                self.recurrences
+                    .skip_occurrence(series_id, occurrence_start_at)?;
+            }
+            OccurrenceEditScope::ThisAndFollowing if occurrence_start_at == context.anchor => {
+                self.atoms.soft_delete_atom(series_id)?;
+            }
+            OccurrenceEditScope::ThisAndFollowing => {
+                let elapsed = context.rule.count_before(
+                    context.anchor,
+                    &context.calendar,
+                    occurrence_start_at,
+                );
+                let truncated = truncate_series(&context, occurrence_start_at, elapsed);
+                self.atoms.update_atom(&truncated)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn load_occurrence(
+        &self,
+        series_id: AtomId,
+        occurrence_start_at: i64,
+    ) -> Result<SeriesContext, RecurrenceServiceError> {
+        let series = self
+            .atoms
+            .get_atom(series_id, false)?
+            .ok_or(RecurrenceServiceError::SeriesNotFound(series_id))?;
+        let rule = series
+            .parsed_recurrence()
+            .map_err(RepoError::from)?
+            .ok_or(RecurrenceServiceError::NotRecurring(series_id))?;
+        let anchor = series
+            .start_at
+            .ok_or(RecurrenceServiceError::NotRecurring(series_id))?;
+
+        let already_excepted = self
+            .recurrences
+            .list_exceptions(series_id)?
+            .iter()
+            .any(|exception| exception.original_start_at == occurrence_start_at);
+        let calendar = series.recurrence_calendar(self.atoms.calendar());
+        if already_excepted || !rule.is_occurrence(anchor, &calendar, occurrence_start_at) {
+            return Err(RecurrenceServiceError::OccurrenceNotFound {
+                series_id,
+                occurrence_start_at,
+            });
+        }
+
+        Ok(SeriesContext {
+            series,
+            rule,
+            anchor,
+            calendar,
+        })
+    }
+}
+
+/// Clones the series under a fresh identity and applies content/status changes.
+///
+/// Every other series field (priority, deadline, `all_day`, ...) carries over;
+/// callers overwrite the window and rule.
+fn patched_copy(series: &Atom, patch: &OccurrencePatch) -> Atom {
+    let mut atom = series.clone();
+    atom.uuid = Uuid::new_v4();
+    atom.hlc_timestamp = None;
+    atom.is_deleted = false;
+    atom.task_status = patch.task_status.or(series.task_status);
+    if let Some(content) = &patch.content {
+        let preview = derive_markdown_preview(content);
+        atom.content = content.clone();
+        atom.preview_text = preview.preview_text;
+        atom.preview_image = preview.preview_image;
+    }
+    atom
+}
+
+/// Resolves the edited occurrence window, preserving duration by default.
+fn patched_window(
+    series: &Atom,
+    occurrence_start_at: i64,
+    patch: &OccurrencePatch,
+) -> (i64, Option<i64>) {
+    let start_at = patch.start_at.unwrap_or(occurrence_start_at);
+    let duration = match (series.start_at, series.end_at) {
+        (Some(start), Some(end)) => Some(end - start),
+        _ => None,
+    };
+    let end_at = patch
+        .end_at
+        .or_else(|| duration.map(|duration| start_at + duration));
+    (start_at, end_at)
+}
+
+/// Returns the series ending right before `split_at`.
+fn truncate_series(context: &SeriesContext, split_at: i64, elapsed: u32) -> Atom {
+    let mut rule = context.rule.clone();
+    if rule.count.is_some() {
+        rule.count = Some(elapsed);
+    } else {
+        rule.until = Some(RecurrenceUntil::Instant(split_at - 1));
+    }
+    let mut truncated = context.series.clone();
+    truncated.recurrence_rule = Some(rule.to_rrule_string());
+    truncated
+}
@@ -0,0 +1,214 @@
+//! Reminder use-cases: attach, snooze, acknowledge and due polling.
+//!
+//! # Responsibility
+//! - Attach absolute or start/due-relative reminders to atoms.
+//! - Serve `next_due_reminders` / `next_wakeup_at` polling for scheduler
+//!   loops and platform notifiers.
+//! - Record delivery progress (`ack_reminder`) and snoozes.
+//!
+//! # Invariants
+//! - Relative reminders need the anchor (`start_at`/`due_at`) at creation;
+//!   clearing it later silences the reminder instead of failing.
+//! - Recurring atoms fire once per occurrence; missed firings collapse into
+//!   the latest one, so a poller that was offline delivers one item.
+//! - Acknowledging a scheduled firing also acknowledges every earlier one;
+//!   a reminder holds at most one pending snooze.
+//!
+//! # See also
+//! - crates/lazynote_core/src/repo/reminder_repo.rs
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::AtomId;
+use crate::model::reminder::{DueReminder, Reminder, ReminderId, ReminderTrigger};
+use crate::repo::atom_repo::{AtomRepository, RepoError};
+use crate::repo::reminder_repo::ReminderRepository;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Errors from reminder service operations.
+#[derive(Debug)]
+pub enum ReminderServiceError {
+    /// Target atom does not exist or is soft-deleted.
+    AtomNotFound(AtomId),
+    /// Reminder does not exist.
+    ReminderNotFound(ReminderId),
+    /// Relative reminder on an atom without `start_at`/`due_at`.
+    MissingAnchor {
+        atom_id: AtomId,
+        anchor: &'static str,
+    },
+    /// Snooze target is not after the snoozed firing.
+    InvalidSnooze { fire_at: i64, until_ms: i64 },
+    /// Repository-level error.
+    Repo(RepoError),
+}
+
+impl Display for ReminderServiceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AtomNotFound(id) => write!(f, "atom not found: {id}"),
+            Self::ReminderNotFound(id) => write!(f, "reminder not found: {id}"),
+            Self::MissingAnchor { atom_id, anchor } => {
+                write!(
+                    f,
+                    "atom {atom_id} has no {anchor} time to remind relative to"
+                )
+            }
+            Self::InvalidSnooze { fire_at, until_ms } => {
+                write!(f, "snooze until {until_ms} must be after firing {fire_at}")
+            }
+            Self::Repo(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for ReminderServiceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Repo(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<RepoError> for ReminderServiceError {
+    fn from(err: RepoError) -> Self {
+        match err {
+            RepoError::NotFound(id) => Self::AtomNotFound(id),
+            other => Self::Repo(other),
+        }
+    }
+}
+
+/// Service for atom reminders and due-reminder polling.
+pub struct ReminderService<'conn, A: AtomRepository, R: ReminderRepository> {
+    atoms: &'conn A,
+    reminders: &'conn R,
+}
+
+impl<'conn, A: AtomRepository, R: ReminderRepository> ReminderService<'conn, A, R> {
+    /// Creates a service from existing repository references.
+    pub fn new(atoms: &'conn A, reminders: &'conn R) -> Self {
+        Self { atoms, reminders }
+    }
+
+    /// Attaches a reminder to an active atom.
+    ///
+    /// Firings scheduled before `now_ms` are never delivered, so a reminder
+    /// added late does not fire for occurrences already past.
+    pub fn add_reminder(
+        &self,
+        atom_id: AtomId,
+        trigger: ReminderTrigger,
+        now_ms: i64,
+    ) -> Result<Reminder, ReminderServiceError> {
+        let atom = self
+            .atoms
+            .get_atom(atom_id, false)?
+            .ok_or(ReminderServiceError::AtomNotFound(atom_id))?;
+        let anchor_missing = match trigger {
+            ReminderTrigger::Absolute { .. } => false,
+            ReminderTrigger::Start { .. } => atom.start_at.is_none(),
+            ReminderTrigger::Due { .. } => atom.due_at.is_none(),
+        };
+        if anchor_missing {
+            return Err(ReminderServiceError::MissingAnchor {
+                atom_id,
+                anchor: trigger.anchor(),
+            });
+        }
+
+        let reminder = Reminder::new(atom_id, trigger, now_ms);
+        self.reminders.create_reminder(&reminder)?;
+        self.get_reminder(reminder.id)
+    }
+
+    /// Loads one reminder with its next pending fire time.
+    pub fn get_reminder(&self, id: ReminderId) -> Result<Reminder, ReminderServiceError> {
+        self.reminders
+            .get_reminder(id)?
+            .ok_or(ReminderServiceError::ReminderNotFound(id))
+    }
+
+    /// Lists reminders of an active atom, oldest first.
+    pub fn list_reminders(&self, atom_id: AtomId) -> Result<Vec<Reminder>, ReminderServiceError> {
+        if self.atoms.get_atom(atom_id, false)?.is_none() {
+            return Err(ReminderServiceError::AtomNotFound(atom_id));
+        }
+        Ok(self.reminders.list_atom_reminders(atom_id)?)
+    }
+
+    /// Removes one reminder.
+    ///
+    /// Returns `false` when it did not exist (idempotent).
+    pub fn remove_reminder(&self, id: ReminderId) -> Result<bool, ReminderServiceError> {
+        Ok(self.reminders.delete_reminder(id)?)
+    }
+
+    /// Returns firings due at `now_ms` (latest missed firing per reminder,
+    /// plus due snoozes), ordered by `fire_at`.
+    pub fn next_due_reminders(
+        &self,
+        now_ms: i64,
+    ) -> Result<Vec<DueReminder>, ReminderServiceError> {
+        Ok(self.reminders.list_due_reminders(now_ms)?)
+    }
+
+    /// Returns the earliest pending fire time after `now_ms`, i.e. when a
+    /// scheduler loop should poll next.
+    pub fn next_wakeup_at(&self, now_ms: i64) -> Result<Option<i64>, ReminderServiceError> {
+        Ok(self.reminders.next_fire_after(now_ms)?)
+    }
+
+    /// Acknowledges one delivered firing (`DueReminder::fire_at`).
+    ///
+    /// A snoozed firing clears the snooze; a scheduled firing marks it and
+    /// every earlier scheduled firing as delivered.
+    pub fn ack_reminder(
+        &self,
+        id: ReminderId,
+        fire_at: i64,
+    ) -> Result<Reminder, ReminderServiceError> {
+        let mut reminder = self.get_reminder(id)?;
+        if reminder.snoozed_until == Some(fire_at) {
+            reminder.snoozed_until = None;
+            reminder.snoozed_fire_at = None;
+        } else {
+            reminder.acked_through = Some(
+                reminder
+                    .acked_through
+                    .map_or(fire_at, |acked| acked.max(fire_at)),
+            );
+        }
+        self.reminders.save_delivery_state(&reminder)?;
+        self.get_reminder(id)
+    }
+
+    /// Snoozes one delivered firing until `until_ms`.
+    ///
+    /// The firing counts as delivered; it is redelivered once at `until_ms`.
+    /// Snoozing a snoozed firing moves that snooze; otherwise any earlier
+    /// pending snooze is replaced.
+    pub fn snooze_reminder(
+        &self,
+        id: ReminderId,
+        fire_at: i64,
+        until_ms: i64,
+    ) -> Result<Reminder, ReminderServiceError> {
+        if until_ms <= fire_at {
+            return Err(ReminderServiceError::InvalidSnooze { fire_at, until_ms });
+        }
+        let mut reminder = self.get_reminder(id)?;
+        if reminder.snoozed_until != Some(fire_at) {
+            reminder.acked_through = Some(
+                reminder
+                    .acked_through
+                    .map_or(fire_at, |acked| acked.max(fire_at)),
+            );
+            reminder.snoozed_fire_at = Some(fire_at);
+        }
+        reminder.snoozed_until = Some(until_ms);
+        self.reminders.save_delivery_state(&reminder)?;
+        self.get_reminder(id)
+    }
+}
@@ -0,0 +1,318 @@
+//! Resource link listing, search and launch planning.
+//!
+//! # Responsibility
+//! - Expose the per-atom link index and keyword search over it.
+//! - Turn indexed links into a vetted launch plan the UI can execute as-is.
+//!
+//! # Invariants
+//! - Core never opens anything itself; it only decides what may be opened.
+//! - Only whitelisted schemes are planned (default `http`, `https`, `file`).
+//! - `file://` targets must resolve to an existing local absolute path.
+//! - A plan never holds more than [`LaunchPolicy::max_items`] targets
+//!   (clamped to [`LAUNCH_MAX_ITEMS_LIMIT`]); the overflow is only counted.
+//! - Plan order: folders, then files, then URLs; document order within each.
+//!
+//! # See also
+//! - docs/architecture/links-and-launcher.md
+
+use crate::model::atom::AtomId;
+use crate::model::resource_link::ResourceLink;
+use crate::repo::atom_repo::RepoResult;
+use crate::repo::resource_link_repo::ResourceLinkRepository;
+use log::info;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::time::Instant;
+use url::Url;
+
+const SEARCH_DEFAULT_LIMIT: u32 = 20;
+const SEARCH_LIMIT_MAX: u32 = 100;
+
+/// Default number of targets one launch plan may open.
+pub const LAUNCH_DEFAULT_MAX_ITEMS: u32 = 10;
+/// Hard upper bound for [`LaunchPolicy::max_items`].
+pub const LAUNCH_MAX_ITEMS_LIMIT: u32 = 50;
+/// Schemes allowed by [`LaunchPolicy::default`].
+pub const LAUNCH_DEFAULT_SCHEMES: [&str; 3] = ["http", "https", "file"];
+
+/// Launch planning options.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchPolicy {
+    /// Lowercase schemes that may be opened.
+    pub allowed_schemes: Vec<String>,
+    /// Safety cap for the number of planned targets.
+    pub max_items: u32,
+}
+
+impl Default for LaunchPolicy {
+    fn default() -> Self {
+        Self {
+            allowed_schemes: LAUNCH_DEFAULT_SCHEMES
+                .iter()
+                .map(|scheme| scheme.to_string())
+                .collect(),
+            max_items: LAUNCH_DEFAULT_MAX_ITEMS,
+        }
+    }
+}
+
+/// What a planned target opens as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LaunchTargetKind {
+    /// Existing local directory.
+    Folder,
+    /// Existing local file.
+    File,
+    /// Web or whitelisted app URL.
+    Url,
+}
+
+impl LaunchTargetKind {
+    /// Returns the stable label (`folder|file|url`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Folder => "folder",
+            Self::File => "file",
+            Self::Url => "url",
+        }
+    }
+}
+
+/// Why a link was left out of a launch plan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LaunchRejectReason {
+    /// Scheme is not in the whitelist.
+    SchemeNotAllowed,
+    /// Target is not a well-formed URL.
+    InvalidTarget,
+    /// `file://` target points at a remote host or non-absolute path.
+    NotLocalPath,
+    /// `file://` target does not exist on this device.
+    PathNotFound,
+}
+
+impl LaunchRejectReason {
+    /// Returns the stable label used by FFI payloads.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::SchemeNotAllowed => "scheme_not_allowed",
+            Self::InvalidTarget => "invalid_target",
+            Self::NotLocalPath => "not_local_path",
+            Self::PathNotFound => "path_not_found",
+        }
+    }
+}
+
+/// One vetted target the UI may open.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchTarget {
+    /// Source link row id.
+    pub link_id: i64,
+    /// How the target opens.
+    pub kind: LaunchTargetKind,
+    /// Target as written in content.
+    pub target: String,
+    /// Optional Markdown label.
+    pub label: Option<String>,
+    /// Resolved local path for folder/file targets.
+    pub local_path: Option<PathBuf>,
+}
+
+/// One link excluded from a launch plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchRejection {
+    /// Source link row id.
+    pub link_id: i64,
+    /// Target as written in content.
+    pub target: String,
+    /// Rejection reason.
+    pub reason: LaunchRejectReason,
+}
+
+/// Vetted launch plan.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LaunchPlan {
+    /// Targets to open, in launch order.
+    pub items: Vec<LaunchTarget>,
+    /// Links that failed validation.
+    pub rejected: Vec<LaunchRejection>,
+    /// Valid targets dropped by the safety cap.
+    pub capped_count: u32,
+    /// Effective cap after normalization.
+    pub applied_max_items: u32,
+}
+
+/// Resource link service facade over repository implementations.
+pub struct ResourceLinkService<R: ResourceLinkRepository> {
+    repo: R,
+}
+
+impl<R: ResourceLinkRepository> ResourceLinkService<R> {
+    /// Creates a service using the provided repository implementation.
+    pub fn new(repo: R) -> Self {
+        Self { repo }
+    }
+
+    /// Lists links written in one atom, in document order.
+    pub fn list_links(&self, atom_id: AtomId) -> RepoResult<Vec<ResourceLink>> {
+        self.repo.list_links(atom_id)
+    }
+
+    /// Searches link targets and labels; blank queries return no rows.
+    ///
+    /// Limit defaults to 20 and clamps to 100.
+    pub fn search_links(&self, query: &str, limit: Option<u32>) -> RepoResult<Vec<ResourceLink>> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+        let limit = match limit {
+            Some(0) | None => SEARCH_DEFAULT_LIMIT,
+            Some(value) => value.min(SEARCH_LIMIT_MAX),
+        };
+        self.repo.search_links(query, limit)
+    }
+
+    /// Plans an "open all" for the links of one atom.
+    pub fn plan_atom_launch(
+        &self,
+        atom_id: AtomId,
+        policy: &LaunchPolicy,
+    ) -> RepoResult<LaunchPlan> {
+        let started_at = Instant::now();
+        let links = self.repo.list_links(atom_id)?;
+        let plan = build_launch_plan(&links, policy);
+        info!(
+            "event=link_launch_plan module=service status=ok atom_id={} item_count={} rejected_count={} capped_count={} duration_ms={}",
+            atom_id,
+            plan.items.len(),
+            plan.rejected.len(),
+            plan.capped_count,
+            started_at.elapsed().as_millis()
+        );
+        Ok(plan)
+    }
+
+    /// Plans a launch for explicitly selected link ids.
+    ///
+    /// Unknown ids and links of deleted atoms are skipped.
+    pub fn plan_links_launch(
+        &self,
+        link_ids: &[i64],
+        policy: &LaunchPolicy,
+    ) -> RepoResult<LaunchPlan> {
+        let started_at = Instant::now();
+        let mut links = self.repo.get_links(link_ids)?;
+        // Keep caller selection order.
+        links.sort_by_key(|link| {
+            link_ids
+                .iter()
+                .position(|id| *id == link.id)
+                .unwrap_or(usize::MAX)
+        });
+        let plan = build_launch_plan(&links, policy);
+        info!(
+            "event=link_launch_plan module=service status=ok item_count={} rejected_count={} capped_count={} duration_ms={}",
+            plan.items.len(),
+            plan.rejected.len(),
+            plan.capped_count,
+            started_at.elapsed().as_millis()
+        );
+        Ok(plan)
+    }
+
+    /// Re-indexes resource links for every atom; returns atoms indexed.
+    pub fn rebuild_links(&self) -> RepoResult<u32> {
+        self.repo.rebuild_all_resource_links()
+    }
+}
+
+/// Validates links against `policy` and orders the survivors for launch.
+pub fn build_launch_plan(links: &[ResourceLink], policy: &LaunchPolicy) -> LaunchPlan {
+    let applied_max_items = match policy.max_items {
+        0 => LAUNCH_DEFAULT_MAX_ITEMS,
+        value => value.min(LAUNCH_MAX_ITEMS_LIMIT),
+    };
+    let allowed: HashSet<String> = policy
+        .allowed_schemes
+        .iter()
+        .map(|scheme| scheme.trim().to_ascii_lowercase())
+        .collect();
+
+    let mut plan = LaunchPlan {
+        applied_max_items,
+        ..LaunchPlan::default()
+    };
+    let mut seen = HashSet::new();
+    let mut accepted = Vec::new();
+    for link in links {
+        if !seen.insert(link.target.as_str()) {
+            continue;
+        }
+        match vet_link(link, &allowed) {
+            Ok(target) => accepted.push(target),
+            Err(reason) => plan.rejected.push(LaunchRejection {
+                link_id: link.id,
+                target: link.target.clone(),
+                reason,
+            }),
+        }
+    }
+
+    // Stable sort keeps document/selection order inside each kind.
+    accepted.sort_by_key(|target| target.kind);
+    let cap = applied_max_items as usize;
+    plan.capped_count = accepted.len().saturating_sub(cap) as u32;
+    accepted.truncate(cap);
+    plan.items = accepted;
+    plan
+}
+
+fn vet_link(
+    link: &ResourceLink,
+    allowed: &HashSet<String>,
+) -> Result<LaunchTarget, LaunchRejectReason> {
+    if !allowed.contains(&link.scheme) {
+        return Err(LaunchRejectReason::SchemeNotAllowed);
+    }
+    let url = Url::parse(&link.target).map_err(|_| LaunchRejectReason::InvalidTarget)?;
+
+    let (kind, local_path) = if url.scheme() == "file" {
+        let path = local_file_path(&url)?;
+        let metadata = std::fs::metadata(&path).map_err(|_| LaunchRejectReason::PathNotFound)?;
+        let kind = if metadata.is_dir() {
+            LaunchTargetKind::Folder
+        } else {
+            LaunchTargetKind::File
+        };
+        (kind, Some(path))
+    } else {
+        if matches!(url.scheme(), "http" | "https") && url.host_str().is_none() {
+            return Err(LaunchRejectReason::InvalidTarget);
+        }
+        (LaunchTargetKind::Url, None)
+    };
+
+    Ok(LaunchTarget {
+        link_id: link.id,
+        kind,
+        target: link.target.clone(),
+        label: link.label.clone(),
+        local_path,
+    })
+}
+
+/// Converts a `file://` URL into a local absolute path.
+fn local_file_path(url: &Url) -> Result<PathBuf, LaunchRejectReason> {
+    match url.host_str() {
+        None | Some("") | Some("localhost") => {}
+        Some(_) => return Err(LaunchRejectReason::NotLocalPath),
+    }
+    let path = url
+        .to_file_path()
+        .map_err(|_| LaunchRejectReason::NotLocalPath)?;
+    if !path.is_absolute() {
+        return Err(LaunchRejectReason::NotLocalPath);
+    }
+    Ok(path)
+}
@@ -0,0 +1,168 @@
+//! Atom revision history use-cases.
+//!
+//! # Responsibility
+//! - List captured revisions of one atom.
+//! - Diff two revisions (or one revision against current content).
+//! - Restore a revision onto its atom.
+//!
+//! # Invariants
+//! - Revisions are always addressed together with their atom ID; a revision
+//!   of another atom is reported as not found.
+//! - Restoring is itself undoable: the pre-restore state becomes a revision.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::AtomId;
+use crate::model::revision::{diff_lines, AtomRevision, DiffLine, RevisionId};
+use crate::repo::atom_repo::RepoError;
+use crate::repo::revision_repo::{normalize_revision_limit, RevisionRepository};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Errors from revision service operations.
+#[derive(Debug)]
+pub enum RevisionServiceError {
+    /// Target atom does not exist or is soft-deleted.
+    AtomNotFound(AtomId),
+    /// Revision does not exist for the target atom.
+    RevisionNotFound {
+        atom_id: AtomId,
+        revision_id: RevisionId,
+    },
+    /// Repository-level error.
+    Repo(RepoError),
+}
+
+impl Display for RevisionServiceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AtomNotFound(id) => write!(f, "atom not found: {id}"),
+            Self::RevisionNotFound {
+                atom_id,
+                revision_id,
+            } => write!(f, "revision {revision_id} not found for atom {atom_id}"),
+            Self::Repo(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for RevisionServiceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Repo(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<RepoError> for RevisionServiceError {
+    fn from(err: RepoError) -> Self {
+        match err {
+            RepoError::NotFound(id) => Self::AtomNotFound(id),
+            other => Self::Repo(other),
+        }
+    }
+}
+
+/// Revision list result envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevisionListResult {
+    /// Revisions sorted newest first.
+    pub items: Vec<AtomRevision>,
+    /// Effective normalized limit used by the query.
+    pub applied_limit: u32,
+}
+
+/// Line-level diff between two versions of one atom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevisionDiff {
+    /// Atom both versions belong to.
+    pub atom_id: AtomId,
+    /// Older side of the diff.
+    pub from_revision_id: RevisionId,
+    /// Newer side of the diff; `None` means the current atom content.
+    pub to_revision_id: Option<RevisionId>,
+    /// Diff lines in document order.
+    pub lines: Vec<DiffLine>,
+}
+
+/// Revision service facade over repository implementations.
+pub struct RevisionService<R: RevisionRepository> {
+    repo: R,
+}
+
+impl<R: RevisionRepository> RevisionService<R> {
+    /// Creates a service using the provided repository implementation.
+    pub fn new(repo: R) -> Self {
+        Self { repo }
+    }
+
+    /// Lists revisions of one atom, newest first.
+    ///
+    /// Limit defaults to 20 and clamps to 100.
+    pub fn list_revisions(
+        &self,
+        atom_id: AtomId,
+        limit: Option<u32>,
+        offset: u32,
+    ) -> Result<RevisionListResult, RevisionServiceError> {
+        let applied_limit = normalize_revision_limit(limit);
+        let items = self.repo.list_revisions(atom_id, applied_limit, offset)?;
+        Ok(RevisionListResult {
+            items,
+            applied_limit,
+        })
+    }
+
+    /// Loads one revision of an atom.
+    pub fn get_revision(
+        &self,
+        atom_id: AtomId,
+        revision_id: RevisionId,
+    ) -> Result<AtomRevision, RevisionServiceError> {
+        self.repo.get_revision(atom_id, revision_id)?.ok_or(
+            RevisionServiceError::RevisionNotFound {
+                atom_id,
+                revision_id,
+            },
+        )
+    }
+
+    /// Diffs `from_revision_id` against `to_revision_id`.
+    ///
+    /// `to_revision_id = None` diffs against the current atom content.
+    pub fn diff_revisions(
+        &self,
+        atom_id: AtomId,
+        from_revision_id: RevisionId,
+        to_revision_id: Option<RevisionId>,
+    ) -> Result<RevisionDiff, RevisionServiceError> {
+        let from = self.get_revision(atom_id, from_revision_id)?;
+        let to_content = match to_revision_id {
+            Some(revision_id) => self.get_revision(atom_id, revision_id)?.content,
+            None => self
+                .repo
+                .current_content(atom_id)?
+                .ok_or(RevisionServiceError::AtomNotFound(atom_id))?,
+        };
+
+        Ok(RevisionDiff {
+            atom_id,
+            from_revision_id,
+            to_revision_id,
+            lines: diff_lines(&from.content, &to_content),
+        })
+    }
+
+    /// Restores one revision onto its atom.
+    pub fn restore_revision(
+        &self,
+        atom_id: AtomId,
+        revision_id: RevisionId,
+    ) -> Result<(), RevisionServiceError> {
+        self.get_revision(atom_id, revision_id)?;
+        self.repo.restore_revision(atom_id, revision_id)?;
+        Ok(())
+    }
+}
@@ -1,17 +1,40 @@
 //! Task/section use-case service.
 //!
 //! # Responsibility
-//! - Provide section-based list queries (Inbox/Today/Upcoming) with tag enrichment.
-//! - Provide universal status update for any atom type.
+//! - Provide section-based list queries (Inbox/Today/Upcoming/Overdue) with
+//!   tag enrichment, over caller-computed or timezone-computed day windows.
+//! - Provide universal status, priority and deadline updates for any atom type,
+//!   optionally stopping running timers of tasks that become `done`.
+//! - Manage subtask hierarchy: attach/detach, ordering, completion roll-up and
+//!   checklist promotion.
+//! - Manage task dependencies: prerequisite edges, blocked state and
+//!   dependency-respecting project order.
 //!
 //! # Invariants
 //! - Section classification is driven by `start_at`/`end_at` nullability, not `type`.
 //! - `update_status(None)` clears task_status (demote to statusless).
+//! - A `due_at` deadline places an atom in Today once due and keeps it out of
+//!   Inbox; Overdue sorts by priority before deadline.
+//! - Subtask parent and child are both active `AtomType::Task` atoms and the
+//!   hierarchy never contains a cycle.
+//! - Completing the last open subtask completes the parent, recursively;
+//!   reopening a subtask does not reopen the parent.
+//! - Dependency edges join active `AtomType::Task` atoms and never form a
+//!   cycle; a task is blocked while any prerequisite is unfinished.
 
-use crate::model::atom::{Atom, AtomId, TaskStatus};
-use crate::repo::atom_repo::{AtomRepository, RepoError, SectionAtomRow};
+use crate::model::atom::{Atom, AtomId, AtomType, TaskPriority, TaskStatus};
+use crate::model::local_time::{floating_span, LocalCalendar, LocalWindow};
+use crate::model::markdown::checklist_items;
+use crate::repo::atom_repo::{AtomRepository, RepoError, SectionAtomRow, SectionOptions};
+use crate::repo::dependency_repo;
 use crate::repo::note_repo::load_tags_for_atoms;
-use rusqlite::Connection;
+use crate::repo::subtask_repo::{self, SubtaskProgress};
+use crate::repo::time_entry_repo;
+use crate::service::note_service::derive_markdown_preview;
+use chrono::NaiveDate;
+use rusqlite::{Connection, Transaction, TransactionBehavior};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
@@ -24,6 +47,17 @@ pub struct SectionAtom {
     pub tags: Vec<String>,
     /// Epoch ms from `updated_at` column.
     pub updated_at: i64,
+    /// Original start for virtual recurring occurrences; `None` for stored rows.
+    pub occurrence_start_at: Option<i64>,
+    /// Whether the atom is a task with an unfinished prerequisite.
+    pub blocked: bool,
+}
+
+/// Optional side effects of [`TaskService::update_status_with`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatusUpdateOptions {
+    /// Stop running timers of tasks that become `done` at this epoch ms.
+    pub stop_timer_at: Option<i64>,
 }
 
 /// Errors from task/section service operations.
@@ -31,6 +65,17 @@ pub struct SectionAtom {
 pub enum TaskServiceError {
     /// Target atom does not exist or is soft-deleted.
     AtomNotFound(AtomId),
+    /// Subtask operation target is not a task atom.
+    NotATask(AtomId),
+    /// Attaching would make a task its own ancestor.
+    SubtaskCycle { child_id: AtomId, parent_id: AtomId },
+    /// Reorder input is not a permutation of the current subtasks.
+    InvalidSubtaskOrder(AtomId),
+    /// Adding the prerequisite would make a task depend on itself.
+    DependencyCycle {
+        task_id: AtomId,
+        depends_on_id: AtomId,
+    },
     /// Repository-level error.
     Repo(RepoError),
 }
@@ -39,6 +84,24 @@ impl Display for TaskServiceError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::AtomNotFound(id) => write!(f, "atom not found: {id}"),
+            Self::NotATask(id) => write!(f, "atom is not a task: {id}"),
+            Self::SubtaskCycle {
+                child_id,
+                parent_id,
+            } => write!(
+                f,
+                "subtask would create cycle: task {child_id} under parent {parent_id}"
+            ),
+            Self::InvalidSubtaskOrder(id) => {
+                write!(f, "order must list every subtask of {id} exactly once")
+            }
+            Self::DependencyCycle {
+                task_id,
+                depends_on_id,
+            } => write!(
+                f,
+                "dependency would create cycle: task {task_id} on {depends_on_id}"
+            ),
             Self::Repo(err) => write!(f, "{err}"),
         }
     }
@@ -47,8 +110,8 @@ impl Display for TaskServiceError {
 impl Error for TaskServiceError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
-            Self::AtomNotFound(_) => None,
             Self::Repo(err) => Some(err),
+            _ => None,
         }
     }
 }
@@ -74,13 +137,24 @@ impl<'conn, R: AtomRepository> TaskService<'conn, R> {
         Self { repo, conn }
     }
 
-    /// Returns timeless atoms (both `start_at` and `end_at` NULL).
+    /// Returns timeless atoms (`start_at`, `end_at` and `due_at` NULL).
     pub fn fetch_inbox(
         &self,
         limit: u32,
         offset: u32,
     ) -> Result<Vec<SectionAtom>, TaskServiceError> {
-        let rows = self.repo.fetch_inbox(limit, offset)?;
+        self.fetch_inbox_with(limit, offset, SectionOptions::default())
+    }
+
+    /// [`TaskService::fetch_inbox`] with section filters, e.g. to hide
+    /// blocked tasks.
+    pub fn fetch_inbox_with(
+        &self,
+        limit: u32,
+        offset: u32,
+        options: SectionOptions,
+    ) -> Result<Vec<SectionAtom>, TaskServiceError> {
+        let rows = self.repo.fetch_inbox_with(limit, offset, options)?;
         self.enrich_with_tags(rows)
     }
 
@@ -92,7 +166,43 @@ impl<'conn, R: AtomRepository> TaskService<'conn, R> {
         limit: u32,
         offset: u32,
     ) -> Result<Vec<SectionAtom>, TaskServiceError> {
-        let rows = self.repo.fetch_today(bod_ms, eod_ms, limit, offset)?;
+        self.fetch_today_with(bod_ms, eod_ms, limit, offset, SectionOptions::default())
+    }
+
+    /// [`TaskService::fetch_today`] with section filters, e.g. to hide
+    /// blocked tasks.
+    pub fn fetch_today_with(
+        &self,
+        bod_ms: i64,
+        eod_ms: i64,
+        limit: u32,
+        offset: u32,
+        options: SectionOptions,
+    ) -> Result<Vec<SectionAtom>, TaskServiceError> {
+        let rows = self.repo.fetch_today_with(
+            LocalWindow::from_instants(bod_ms, eod_ms),
+            limit,
+            offset,
+            options,
+        )?;
+        self.enrich_with_tags(rows)
+    }
+
+    /// Returns atoms active on the local day of `now_ms` in `calendar`.
+    ///
+    /// Day boundaries follow the calendar's timezone (DST included) and
+    /// all-day atoms match by local date.
+    pub fn fetch_today_in(
+        &self,
+        calendar: &LocalCalendar,
+        now_ms: i64,
+        limit: u32,
+        offset: u32,
+        options: SectionOptions,
+    ) -> Result<Vec<SectionAtom>, TaskServiceError> {
+        let rows =
+            self.repo
+                .fetch_today_with(calendar.day_window(now_ms), limit, offset, options)?;
         self.enrich_with_tags(rows)
     }
 
@@ -107,17 +217,365 @@ impl<'conn, R: AtomRepository> TaskService<'conn, R> {
         self.enrich_with_tags(rows)
     }
 
+    /// Returns atoms anchored after the local day of `now_ms` in `calendar`.
+    pub fn fetch_upcoming_in(
+        &self,
+        calendar: &LocalCalendar,
+        now_ms: i64,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<SectionAtom>, TaskServiceError> {
+        let rows = self
+            .repo
+            .fetch_upcoming_with(calendar.day_window(now_ms), limit, offset)?;
+        self.enrich_with_tags(rows)
+    }
+
+    /// Makes an active atom all-day over the local dates `first..=last`.
+    ///
+    /// `start_at`/`end_at` become floating values, so the atom covers the
+    /// same dates in every timezone. Use
+    /// [`AtomRepository::update_event_times`] to make it timed again.
+    ///
+    /// # Errors
+    /// - [`TaskServiceError::AtomNotFound`] when the atom is missing.
+    /// - [`TaskServiceError::Repo`] with a validation error when
+    ///   `last < first`.
+    pub fn set_all_day(
+        &self,
+        atom_id: AtomId,
+        first: NaiveDate,
+        last: NaiveDate,
+    ) -> Result<Atom, TaskServiceError> {
+        let mut atom = self.get_atom(atom_id)?;
+        let (start_at, end_at) = floating_span(first, last);
+        atom.start_at = Some(start_at);
+        atom.end_at = Some(end_at);
+        atom.all_day = true;
+        self.repo.update_atom(&atom)?;
+        self.get_atom(atom_id)
+    }
+
+    /// Returns incomplete atoms past their deadline at `now_ms`, highest
+    /// priority first.
+    pub fn fetch_overdue(
+        &self,
+        now_ms: i64,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<SectionAtom>, TaskServiceError> {
+        let rows = self.repo.fetch_overdue(now_ms, limit, offset)?;
+        self.enrich_with_tags(rows)
+    }
+
+    /// Loads one active atom, e.g. to read its priority and deadline.
+    pub fn get_atom(&self, id: AtomId) -> Result<Atom, TaskServiceError> {
+        self.repo
+            .get_atom(id, false)?
+            .ok_or(TaskServiceError::AtomNotFound(id))
+    }
+
+    /// Sets or clears (`None`) the priority of any atom type.
+    pub fn set_priority(
+        &self,
+        id: AtomId,
+        priority: Option<TaskPriority>,
+    ) -> Result<(), TaskServiceError> {
+        self.repo.update_atom_priority(id, priority)?;
+        Ok(())
+    }
+
+    /// Sets or clears (`None`) the `due_at` deadline of any atom type.
+    pub fn set_due_at(&self, id: AtomId, due_at: Option<i64>) -> Result<(), TaskServiceError> {
+        self.repo.update_atom_due_at(id, due_at)?;
+        Ok(())
+    }
+
     /// Updates `task_status` for any atom type (universal completion).
     /// Pass `None` to clear status (demote).
+    ///
+    /// Finishing a subtask (`done`/`cancelled`) completes each ancestor
+    /// whose subtasks are then all finished.
     pub fn update_status(
         &self,
         id: AtomId,
         status: Option<TaskStatus>,
+    ) -> Result<(), TaskServiceError> {
+        self.update_status_with(id, status, StatusUpdateOptions::default())
+    }
+
+    /// Like [`TaskService::update_status`], with side effects chosen by
+    /// `options`.
+    ///
+    /// With `stop_timer_at`, running timers of this device on every atom
+    /// that becomes `done` (auto-completed ancestors included) are stopped.
+    pub fn update_status_with(
+        &self,
+        id: AtomId,
+        status: Option<TaskStatus>,
+        options: StatusUpdateOptions,
     ) -> Result<(), TaskServiceError> {
         self.repo.update_atom_status(id, status)?;
+        let mut completed = Vec::new();
+        if status == Some(TaskStatus::Done) {
+            completed.push(id);
+        }
+        if matches!(status, Some(TaskStatus::Done | TaskStatus::Cancelled)) {
+            let mut cursor = id;
+            while let Some(parent_id) = subtask_repo::completable_parent(self.conn, cursor)? {
+                self.repo
+                    .update_atom_status(parent_id, Some(TaskStatus::Done))?;
+                completed.push(parent_id);
+                cursor = parent_id;
+            }
+        }
+        if let Some(stopped_at) = options.stop_timer_at {
+            time_entry_repo::stop_atom_timers(self.conn, &completed, stopped_at)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the parent task of `id`, if any.
+    pub fn parent_task(&self, id: AtomId) -> Result<Option<AtomId>, TaskServiceError> {
+        Ok(subtask_repo::load_parent(self.conn, id)?)
+    }
+
+    /// Lists active subtasks of `parent_id` in sibling order.
+    pub fn list_subtasks(&self, parent_id: AtomId) -> Result<Vec<SectionAtom>, TaskServiceError> {
+        self.require_task(parent_id)?;
+        let rows = subtask_repo::list_child_rows(self.conn, parent_id)?;
+        self.enrich_with_tags(rows)
+    }
+
+    /// Returns done/total counts of the subtasks of `parent_id`.
+    pub fn subtask_progress(&self, parent_id: AtomId) -> Result<SubtaskProgress, TaskServiceError> {
+        self.require_task(parent_id)?;
+        Ok(subtask_repo::load_progress(self.conn, parent_id)?)
+    }
+
+    /// Attaches `child_id` under `parent_id` at `target_index` (default:
+    /// last), or makes it top-level when `parent_id` is `None`.
+    ///
+    /// # Errors
+    /// - [`TaskServiceError::NotATask`] when either side is not a task.
+    /// - [`TaskServiceError::SubtaskCycle`] when `parent_id` is `child_id`
+    ///   or one of its descendants.
+    pub fn set_parent(
+        &self,
+        child_id: AtomId,
+        parent_id: Option<AtomId>,
+        target_index: Option<u32>,
+    ) -> Result<(), TaskServiceError> {
+        self.require_task(child_id)?;
+        if let Some(parent_id) = parent_id {
+            self.require_task(parent_id)?;
+            if self.would_create_cycle(child_id, parent_id)? {
+                return Err(TaskServiceError::SubtaskCycle {
+                    child_id,
+                    parent_id,
+                });
+            }
+        }
+
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)
+            .map_err(RepoError::from)?;
+        subtask_repo::set_parent(
+            &tx,
+            child_id,
+            parent_id,
+            target_index.map(|index| index as usize),
+        )?;
+        tx.commit().map_err(RepoError::from)?;
         Ok(())
     }
 
+    /// Reorders the subtasks of `parent_id` to match `ordered`.
+    ///
+    /// `ordered` must contain every active subtask exactly once.
+    pub fn reorder_subtasks(
+        &self,
+        parent_id: AtomId,
+        ordered: &[AtomId],
+    ) -> Result<(), TaskServiceError> {
+        self.require_task(parent_id)?;
+        let current: HashSet<AtomId> = subtask_repo::list_child_ids(self.conn, parent_id)?
+            .into_iter()
+            .collect();
+        let requested: HashSet<AtomId> = ordered.iter().copied().collect();
+        if requested.len() != ordered.len() || requested != current {
+            return Err(TaskServiceError::InvalidSubtaskOrder(parent_id));
+        }
+
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)
+            .map_err(RepoError::from)?;
+        subtask_repo::write_sibling_order(&tx, ordered)?;
+        tx.commit().map_err(RepoError::from)?;
+        Ok(())
+    }
+
+    /// Moves the top-level `- [ ]` checklist items of task `parent_id` into
+    /// new subtasks, appended after existing ones.
+    ///
+    /// Checked items become `done` subtasks; the items are removed from the
+    /// parent content. Returns the new subtask IDs in document order.
+    pub fn promote_checklist(&self, parent_id: AtomId) -> Result<Vec<AtomId>, TaskServiceError> {
+        let mut parent = self.require_task(parent_id)?;
+        let items = checklist_items(&parent.content);
+        if items.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)
+            .map_err(RepoError::from)?;
+        let mut created = Vec::with_capacity(items.len());
+        for item in &items {
+            let mut child = Atom::new(AtomType::Task, item.content.clone());
+            child.task_status = Some(if item.checked {
+                TaskStatus::Done
+            } else {
+                TaskStatus::Todo
+            });
+            let preview = derive_markdown_preview(&child.content);
+            child.preview_text = preview.preview_text;
+            child.preview_image = preview.preview_image;
+            self.repo.create_atom(&child)?;
+            subtask_repo::set_parent(&tx, child.uuid, Some(parent_id), None)?;
+            created.push(child.uuid);
+        }
+
+        for item in items.iter().rev() {
+            parent
+                .content
+                .replace_range(item.byte_start..item.byte_end, "");
+        }
+        let preview = derive_markdown_preview(&parent.content);
+        parent.preview_text = preview.preview_text;
+        parent.preview_image = preview.preview_image;
+        self.repo.update_atom(&parent)?;
+        tx.commit().map_err(RepoError::from)?;
+        Ok(created)
+    }
+
+    /// Makes task `task_id` depend on task `depends_on_id`.
+    ///
+    /// Returns `false` when the dependency already existed.
+    ///
+    /// # Errors
+    /// - [`TaskServiceError::NotATask`] when either side is not a task.
+    /// - [`TaskServiceError::DependencyCycle`] when `depends_on_id` is
+    ///   `task_id` or already depends on it, directly or transitively.
+    pub fn add_dependency(
+        &self,
+        task_id: AtomId,
+        depends_on_id: AtomId,
+    ) -> Result<bool, TaskServiceError> {
+        self.require_task(task_id)?;
+        self.require_task(depends_on_id)?;
+        if dependency_repo::depends_transitively(self.conn, depends_on_id, task_id)? {
+            return Err(TaskServiceError::DependencyCycle {
+                task_id,
+                depends_on_id,
+            });
+        }
+        Ok(dependency_repo::add_dependency(
+            self.conn,
+            task_id,
+            depends_on_id,
+        )?)
+    }
+
+    /// Removes the dependency of `task_id` on `depends_on_id`.
+    ///
+    /// Returns `false` when there was no such dependency.
+    pub fn remove_dependency(
+        &self,
+        task_id: AtomId,
+        depends_on_id: AtomId,
+    ) -> Result<bool, TaskServiceError> {
+        Ok(dependency_repo::remove_dependency(
+            self.conn,
+            task_id,
+            depends_on_id,
+        )?)
+    }
+
+    /// Lists active prerequisites of `task_id`, oldest dependency first.
+    pub fn list_prerequisites(
+        &self,
+        task_id: AtomId,
+    ) -> Result<Vec<SectionAtom>, TaskServiceError> {
+        self.require_task(task_id)?;
+        let rows = dependency_repo::list_prerequisite_rows(self.conn, task_id)?;
+        self.enrich_with_tags(rows)
+    }
+
+    /// Lists active tasks that depend on `task_id`, oldest dependency first.
+    pub fn list_dependents(&self, task_id: AtomId) -> Result<Vec<SectionAtom>, TaskServiceError> {
+        self.require_task(task_id)?;
+        let rows = dependency_repo::list_dependent_rows(self.conn, task_id)?;
+        self.enrich_with_tags(rows)
+    }
+
+    /// Returns whether `id` is a task with an unfinished prerequisite.
+    pub fn is_blocked(&self, id: AtomId) -> Result<bool, TaskServiceError> {
+        self.get_atom(id)?;
+        Ok(dependency_repo::load_blocked_ids(self.conn, &[id])?.contains(&id))
+    }
+
+    /// Returns every active task below `project_id` (its subtask tree) with
+    /// prerequisites before the tasks that depend on them.
+    ///
+    /// Ties keep outline order (depth-first, sibling order). Dependencies on
+    /// tasks outside the project do not affect the order.
+    pub fn topological_order(
+        &self,
+        project_id: AtomId,
+    ) -> Result<Vec<SectionAtom>, TaskServiceError> {
+        self.require_task(project_id)?;
+        let mut outline = Vec::new();
+        let mut stack = subtask_repo::list_child_rows(self.conn, project_id)?;
+        stack.reverse();
+        while let Some(row) = stack.pop() {
+            let mut children = subtask_repo::list_child_rows(self.conn, row.atom.uuid)?;
+            children.reverse();
+            stack.extend(children);
+            outline.push(row);
+        }
+
+        let ids: Vec<AtomId> = outline.iter().map(|row| row.atom.uuid).collect();
+        let position: HashMap<AtomId, usize> =
+            ids.iter().enumerate().map(|(pos, id)| (*id, pos)).collect();
+        let mut pending = vec![0usize; ids.len()];
+        let mut unlocks: Vec<Vec<usize>> = vec![Vec::new(); ids.len()];
+        for (task_id, depends_on_id) in dependency_repo::load_edges_among(self.conn, &ids)? {
+            let (task, prerequisite) = (position[&task_id], position[&depends_on_id]);
+            pending[task] += 1;
+            unlocks[prerequisite].push(task);
+        }
+
+        let mut ready: BinaryHeap<Reverse<usize>> = (0..ids.len())
+            .filter(|pos| pending[*pos] == 0)
+            .map(Reverse)
+            .collect();
+        let mut order = Vec::with_capacity(ids.len());
+        while let Some(Reverse(pos)) = ready.pop() {
+            order.push(pos);
+            for &next in &unlocks[pos] {
+                pending[next] -= 1;
+                if pending[next] == 0 {
+                    ready.push(Reverse(next));
+                }
+            }
+        }
+
+        let mut slots: Vec<Option<SectionAtomRow>> = outline.into_iter().map(Some).collect();
+        let rows = order
+            .into_iter()
+            .filter_map(|pos| slots[pos].take())
+            .collect();
+        self.enrich_with_tags(rows)
+    }
+
     /// Returns atoms with both `start_at` and `end_at` set that overlap the given time range.
     /// Includes all statuses (done/cancelled shown on calendar).
     pub fn fetch_by_time_range(
@@ -144,6 +602,30 @@ impl<'conn, R: AtomRepository> TaskService<'conn, R> {
         Ok(())
     }
 
+    fn require_task(&self, id: AtomId) -> Result<Atom, TaskServiceError> {
+        let atom = self.get_atom(id)?;
+        if atom.kind != AtomType::Task {
+            return Err(TaskServiceError::NotATask(id));
+        }
+        Ok(atom)
+    }
+
+    fn would_create_cycle(
+        &self,
+        child_id: AtomId,
+        candidate_parent_id: AtomId,
+    ) -> Result<bool, TaskServiceError> {
+        let mut visited = HashSet::new();
+        let mut cursor = Some(candidate_parent_id);
+        while let Some(current) = cursor {
+            if current == child_id || !visited.insert(current) {
+                return Ok(true);
+            }
+            cursor = subtask_repo::load_parent(self.conn, current)?;
+        }
+        Ok(false)
+    }
+
     fn enrich_with_tags(
         &self,
         rows: Vec<SectionAtomRow>,
@@ -154,16 +636,21 @@ impl<'conn, R: AtomRepository> TaskService<'conn, R> {
 
         let uuids: Vec<String> = rows.iter().map(|r| r.atom.uuid.to_string()).collect();
         let tag_map = load_tags_for_atoms(self.conn, &uuids).map_err(TaskServiceError::Repo)?;
+        let ids: Vec<AtomId> = rows.iter().map(|r| r.atom.uuid).collect();
+        let blocked_ids = dependency_repo::load_blocked_ids(self.conn, &ids)?;
 
         let result = rows
             .into_iter()
             .map(|row| {
                 let uuid_str = row.atom.uuid.to_string();
                 let tags = tag_map.get(&uuid_str).cloned().unwrap_or_default();
+                let blocked = blocked_ids.contains(&row.atom.uuid);
                 SectionAtom {
                     atom: row.atom,
                     tags,
                     updated_at: row.updated_at,
+                    occurrence_start_at: row.occurrence_start_at,
+                    blocked,
                 }
             })
             .collect();
@@ -0,0 +1,444 @@
+//! Time tracking use-cases: timers, manual entries and time reports.
+//!
+//! # Responsibility
+//! - Start/stop the timer of the current device on task atoms.
+//! - Add, edit and delete tracked sessions manually.
+//! - Report tracked time per local day, per tag and per workspace folder.
+//!
+//! # Invariants
+//! - Time is tracked on active `AtomType::Task` atoms only.
+//! - A device runs at most one timer; starting another one stops it.
+//! - Reports clip entries to the requested local dates and count running
+//!   timers up to "now"; an entry counts once per tag and once per folder.
+//!
+//! # See also
+//! - crates/lazynote_core/src/repo/time_entry_repo.rs
+//! - docs/architecture/data-model.md
+
+use crate::model::atom::{AtomId, AtomType};
+use crate::model::local_time::LocalCalendar;
+use crate::model::time_entry::{TimeEntry, TimeEntryId};
+use crate::repo::atom_repo::{AtomRepository, RepoError};
+use crate::repo::time_entry_repo::TimeEntryRepository;
+use crate::repo::tree_repo::WorkspaceNodeId;
+use chrono::{Duration, NaiveDate};
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Longest range, in days, one time report may cover.
+pub const TIME_REPORT_MAX_DAYS: i64 = 366;
+
+/// Manual edit of one time entry; `None` keeps the stored value.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TimeEntryPatch {
+    /// Replacement task atom.
+    pub atom_id: Option<AtomId>,
+    /// Replacement start, epoch ms.
+    pub started_at: Option<i64>,
+    /// Replacement end, epoch ms; setting it stops a running entry.
+    pub ended_at: Option<i64>,
+    /// Replacement note; blank clears it.
+    pub note: Option<String>,
+}
+
+/// Tracked time of one local day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayTimeTotal {
+    /// Local date.
+    pub date: NaiveDate,
+    /// First instant of the day, epoch ms.
+    pub start_ms: i64,
+    /// First instant of the next day, epoch ms (exclusive).
+    pub end_ms: i64,
+    /// Tracked ms inside the day.
+    pub total_ms: i64,
+}
+
+/// Tracked time of atoms carrying one tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagTimeTotal {
+    /// Normalized lowercase tag.
+    pub tag: String,
+    /// Tracked ms inside the report range.
+    pub total_ms: i64,
+}
+
+/// Tracked time attributed to one workspace folder, subfolders included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FolderTimeTotal {
+    /// Folder node ID.
+    pub folder_id: WorkspaceNodeId,
+    /// Display path from the root (`A/B`).
+    pub path: String,
+    /// Tracked ms inside the report range.
+    pub total_ms: i64,
+}
+
+/// Errors from time tracking service operations.
+#[derive(Debug)]
+pub enum TimeTrackingServiceError {
+    /// Target atom does not exist or is soft-deleted.
+    AtomNotFound(AtomId),
+    /// Target atom is not a task.
+    NotATask(AtomId),
+    /// Time entry does not exist.
+    EntryNotFound(TimeEntryId),
+    /// Entry end is not after its start.
+    InvalidTimeRange { started_at: i64, ended_at: i64 },
+    /// Last date is before the first date.
+    InvalidDateRange { first: NaiveDate, last: NaiveDate },
+    /// Range covers more than [`TIME_REPORT_MAX_DAYS`].
+    RangeTooLarge { days: i64 },
+    /// Repository-level error.
+    Repo(RepoError),
+}
+
+impl Display for TimeTrackingServiceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AtomNotFound(id) => write!(f, "atom not found: {id}"),
+            Self::NotATask(id) => write!(f, "atom is not a task: {id}"),
+            Self::EntryNotFound(id) => write!(f, "time entry not found: {id}"),
+            Self::InvalidTimeRange {
+                started_at,
+                ended_at,
+            } => write!(
+                f,
+                "time entry end ({ended_at}) must be after start ({started_at})"
+            ),
+            Self::InvalidDateRange { first, last } => {
+                write!(f, "last date {last} is before first date {first}")
+            }
+            Self::RangeTooLarge { days } => write!(
+                f,
+                "time report range of {days} days exceeds {TIME_REPORT_MAX_DAYS} days"
+            ),
+            Self::Repo(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for TimeTrackingServiceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Repo(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<RepoError> for TimeTrackingServiceError {
+    fn from(err: RepoError) -> Self {
+        match err {
+            RepoError::NotFound(id) => Self::AtomNotFound(id),
+            other => Self::Repo(other),
+        }
+    }
+}
+
+/// Service for task time tracking over one local calendar.
+pub struct TimeTrackingService<'conn, A: AtomRepository, T: TimeEntryRepository> {
+    atoms: &'conn A,
+    entries: &'conn T,
+    calendar: LocalCalendar,
+}
+
+impl<'conn, A: AtomRepository, T: TimeEntryRepository> TimeTrackingService<'conn, A, T> {
+    /// Creates a service reporting days in `calendar`.
+    pub fn new(atoms: &'conn A, entries: &'conn T, calendar: LocalCalendar) -> Self {
+        Self {
+            atoms,
+            entries,
+            calendar,
+        }
+    }
+
+    /// Starts the device timer on `atom_id` at `now_ms`.
+    ///
+    /// A timer already running on the same task is returned unchanged; one
+    /// running on another task is stopped at `now_ms` first.
+    pub fn start_timer(
+        &self,
+        atom_id: AtomId,
+        now_ms: i64,
+    ) -> Result<TimeEntry, TimeTrackingServiceError> {
+        self.require_task(atom_id)?;
+        if let Some(running) = self.running_timer()? {
+            if running.atom_id == atom_id {
+                return Ok(running);
+            }
+            self.stop_entry(running, now_ms)?;
+        }
+        let entry = TimeEntry::start(atom_id, self.entries.device_id(), now_ms);
+        self.entries.create_entry(&entry)?;
+        self.get_entry(entry.id)
+    }
+
+    /// Stops the device timer at `now_ms` (never before its start).
+    ///
+    /// Returns `None` when no timer was running (idempotent).
+    pub fn stop_timer(&self, now_ms: i64) -> Result<Option<TimeEntry>, TimeTrackingServiceError> {
+        match self.running_timer()? {
+            Some(running) => Ok(Some(self.stop_entry(running, now_ms)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the running timer of this device, if any.
+    pub fn running_timer(&self) -> Result<Option<TimeEntry>, TimeTrackingServiceError> {
+        Ok(self.entries.running_entry(self.entries.device_id())?)
+    }
+
+    /// Records a finished session on a task.
+    ///
+    /// # Errors
+    /// - [`TimeTrackingServiceError::InvalidTimeRange`] unless
+    ///   `ended_at > started_at`.
+    pub fn add_entry(
+        &self,
+        atom_id: AtomId,
+        started_at: i64,
+        ended_at: i64,
+        note: Option<String>,
+    ) -> Result<TimeEntry, TimeTrackingServiceError> {
+        validate_range(started_at, ended_at)?;
+        self.require_task(atom_id)?;
+        let entry = TimeEntry {
+            ended_at: Some(ended_at),
+            note: normalize_note(note),
+            ..TimeEntry::start(atom_id, self.entries.device_id(), started_at)
+        };
+        self.entries.create_entry(&entry)?;
+        self.get_entry(entry.id)
+    }
+
+    /// Loads one entry.
+    pub fn get_entry(&self, id: TimeEntryId) -> Result<TimeEntry, TimeTrackingServiceError> {
+        self.entries
+            .get_entry(id)?
+            .ok_or(TimeTrackingServiceError::EntryNotFound(id))
+    }
+
+    /// Applies a manual edit to one entry.
+    ///
+    /// A running entry stays running unless the patch sets `ended_at`.
+    ///
+    /// # Errors
+    /// - [`TimeTrackingServiceError::InvalidTimeRange`] when a stopped entry
+    ///   would not end after its start.
+    /// - [`TimeTrackingServiceError::NotATask`] when moving to a non-task.
+    pub fn update_entry(
+        &self,
+        id: TimeEntryId,
+        patch: &TimeEntryPatch,
+    ) -> Result<TimeEntry, TimeTrackingServiceError> {
+        let mut entry = self.get_entry(id)?;
+        if let Some(atom_id) = patch.atom_id {
+            self.require_task(atom_id)?;
+            entry.atom_id = atom_id;
+        }
+        if let Some(started_at) = patch.started_at {
+            entry.started_at = started_at;
+        }
+        if let Some(ended_at) = patch.ended_at {
+            entry.ended_at = Some(ended_at);
+        }
+        if let Some(ended_at) = entry.ended_at {
+            validate_range(entry.started_at, ended_at)?;
+        }
+        if let Some(note) = &patch.note {
+            entry.note = normalize_note(Some(note.clone()));
+        }
+        self.entries.update_entry(&entry)?;
+        self.get_entry(id)
+    }
+
+    /// Deletes one entry.
+    ///
+    /// Returns `false` when it did not exist (idempotent).
+    pub fn delete_entry(&self, id: TimeEntryId) -> Result<bool, TimeTrackingServiceError> {
+        Ok(self.entries.delete_entry(id)?)
+    }
+
+    /// Lists entries of an active atom, oldest start first.
+    pub fn list_entries(
+        &self,
+        atom_id: AtomId,
+    ) -> Result<Vec<TimeEntry>, TimeTrackingServiceError> {
+        if self.atoms.get_atom(atom_id, false)?.is_none() {
+            return Err(TimeTrackingServiceError::AtomNotFound(atom_id));
+        }
+        Ok(self.entries.list_atom_entries(atom_id)?)
+    }
+
+    /// Returns tracked time per local date in `first..=last`, zero days
+    /// included. Entries crossing midnight are split between days.
+    ///
+    /// # Errors
+    /// - [`TimeTrackingServiceError::InvalidDateRange`] when `last < first`.
+    /// - [`TimeTrackingServiceError::RangeTooLarge`] above
+    ///   [`TIME_REPORT_MAX_DAYS`].
+    pub fn totals_by_day(
+        &self,
+        first: NaiveDate,
+        last: NaiveDate,
+        now_ms: i64,
+    ) -> Result<Vec<DayTimeTotal>, TimeTrackingServiceError> {
+        let entries = self.load_range(first, last)?;
+        let mut days = Vec::new();
+        let mut date = first;
+        while date <= last {
+            let start_ms = self.calendar.local_midnight(date);
+            let end_ms = self.calendar.local_midnight(date + Duration::days(1));
+            let total_ms = entries
+                .iter()
+                .map(|entry| entry.overlap_ms(start_ms, end_ms, now_ms))
+                .sum();
+            days.push(DayTimeTotal {
+                date,
+                start_ms,
+                end_ms,
+                total_ms,
+            });
+            date += Duration::days(1);
+        }
+        Ok(days)
+    }
+
+    /// Returns tracked time per tag in `first..=last`, largest first.
+    ///
+    /// Entries of untagged atoms are not listed.
+    pub fn totals_by_tag(
+        &self,
+        first: NaiveDate,
+        last: NaiveDate,
+        now_ms: i64,
+    ) -> Result<Vec<TagTimeTotal>, TimeTrackingServiceError> {
+        let (start_ms, end_ms) = self.range_instants(first, last)?;
+        let entries = self.entries.list_entries_between(start_ms, end_ms)?;
+        let tags = self.entries.load_atom_tags(&entry_atoms(&entries))?;
+        let mut totals: BTreeMap<String, i64> = BTreeMap::new();
+        for entry in &entries {
+            let tracked = entry.overlap_ms(start_ms, end_ms, now_ms);
+            for tag in tags.get(&entry.atom_id).into_iter().flatten() {
+                *totals.entry(tag.clone()).or_default() += tracked;
+            }
+        }
+        let mut totals: Vec<TagTimeTotal> = totals
+            .into_iter()
+            .map(|(tag, total_ms)| TagTimeTotal { tag, total_ms })
+            .collect();
+        totals.sort_by(|a, b| b.total_ms.cmp(&a.total_ms).then_with(|| a.tag.cmp(&b.tag)));
+        Ok(totals)
+    }
+
+    /// Returns tracked time per workspace folder in `first..=last`, ordered
+    /// by path; folder totals include their subfolders.
+    ///
+    /// A task is attributed to the folders holding notes that link to it (or
+    /// to one of its parent tasks); unattributed time is not listed.
+    pub fn totals_by_folder(
+        &self,
+        first: NaiveDate,
+        last: NaiveDate,
+        now_ms: i64,
+    ) -> Result<Vec<FolderTimeTotal>, TimeTrackingServiceError> {
+        let (start_ms, end_ms) = self.range_instants(first, last)?;
+        let entries = self.entries.list_entries_between(start_ms, end_ms)?;
+        let folders = self.entries.load_atom_folders(&entry_atoms(&entries))?;
+        let mut totals: HashMap<WorkspaceNodeId, FolderTimeTotal> = HashMap::new();
+        for entry in &entries {
+            let tracked = entry.overlap_ms(start_ms, end_ms, now_ms);
+            for (folder_id, path) in folders.get(&entry.atom_id).into_iter().flatten() {
+                totals
+                    .entry(*folder_id)
+                    .or_insert_with(|| FolderTimeTotal {
+                        folder_id: *folder_id,
+                        path: path.clone(),
+                        total_ms: 0,
+                    })
+                    .total_ms += tracked;
+            }
+        }
+        let mut totals: Vec<FolderTimeTotal> = totals.into_values().collect();
+        totals.sort_by(|a, b| {
+            a.path
+                .to_lowercase()
+                .cmp(&b.path.to_lowercase())
+                .then_with(|| a.folder_id.cmp(&b.folder_id))
+        });
+        Ok(totals)
+    }
+
+    fn stop_entry(
+        &self,
+        mut entry: TimeEntry,
+        now_ms: i64,
+    ) -> Result<TimeEntry, TimeTrackingServiceError> {
+        entry.ended_at = Some(now_ms.max(entry.started_at));
+        self.entries.update_entry(&entry)?;
+        Ok(entry)
+    }
+
+    fn require_task(&self, atom_id: AtomId) -> Result<(), TimeTrackingServiceError> {
+        let atom = self
+            .atoms
+            .get_atom(atom_id, false)?
+            .ok_or(TimeTrackingServiceError::AtomNotFound(atom_id))?;
+        if atom.kind != AtomType::Task {
+            return Err(TimeTrackingServiceError::NotATask(atom_id));
+        }
+        Ok(())
+    }
+
+    fn load_range(
+        &self,
+        first: NaiveDate,
+        last: NaiveDate,
+    ) -> Result<Vec<TimeEntry>, TimeTrackingServiceError> {
+        let (start_ms, end_ms) = self.range_instants(first, last)?;
+        Ok(self.entries.list_entries_between(start_ms, end_ms)?)
+    }
+
+    /// Validates `first..=last` and returns its `[start, end)` instants.
+    fn range_instants(
+        &self,
+        first: NaiveDate,
+        last: NaiveDate,
+    ) -> Result<(i64, i64), TimeTrackingServiceError> {
+        if last < first {
+            return Err(TimeTrackingServiceError::InvalidDateRange { first, last });
+        }
+        let days = (last - first).num_days() + 1;
+        if days > TIME_REPORT_MAX_DAYS {
+            return Err(TimeTrackingServiceError::RangeTooLarge { days });
+        }
+        Ok((
+            self.calendar.local_midnight(first),
+            self.calendar.local_midnight(last + Duration::days(1)),
+        ))
+    }
+}
+
+fn validate_range(started_at: i64, ended_at: i64) -> Result<(), TimeTrackingServiceError> {
+    if ended_at <= started_at {
+        return Err(TimeTrackingServiceError::InvalidTimeRange {
+            started_at,
+            ended_at,
+        });
+    }
+    Ok(())
+}
+
+fn normalize_note(note: Option<String>) -> Option<String> {
+    note.map(|note| note.trim().to_string())
+        .filter(|note| !note.is_empty())
+}
+
+fn entry_atoms(entries: &[TimeEntry]) -> Vec<AtomId> {
+    let mut atoms: Vec<AtomId> = entries.iter().map(|entry| entry.atom_id).collect();
+    atoms.sort();
+    atoms.dedup();
+    atoms
+}
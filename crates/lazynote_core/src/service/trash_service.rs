@@ -0,0 +1,139 @@
+//! Trash use-cases: list, restore, purge and retention auto-purge.
+//!
+//! # Responsibility
+//! - Expose the trash lifecycle of soft-deleted atoms to FFI callers.
+//! - Turn a retention window into a purge cutoff.
+//!
+//! # Invariants
+//! - Retention is passed in by the caller (settings are owned by the UI);
+//!   `None` means [`DEFAULT_TRASH_RETENTION_DAYS`] and `Some(0)` disables
+//!   auto-purge.
+//! - Purge never drops a tombstone that a provider mapping still needs.
+//!
+//! # See also
+//! - docs/architecture/adr/ADR-0002-trash-retention-purge.md
+
+use crate::model::atom::{AtomId, AtomType};
+use crate::repo::atom_repo::RepoError;
+use crate::repo::trash_repo::{
+    normalize_trash_limit, PurgeOutcome, PurgeSummary, TrashItem, TrashRepository,
+};
+use crate::repo::tree_repo::WorkspaceNodeId;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Retention applied when the caller does not configure one.
+pub const DEFAULT_TRASH_RETENTION_DAYS: u32 = 30;
+/// Upper bound for configured retention (10 years).
+pub const MAX_TRASH_RETENTION_DAYS: u32 = 3650;
+
+const DAY_MS: i64 = 86_400_000;
+
+/// Errors from trash service operations.
+#[derive(Debug)]
+pub enum TrashServiceError {
+    /// Atom does not exist or is not in the trash.
+    AtomNotFound(AtomId),
+    /// Repository-level error.
+    Repo(RepoError),
+}
+
+impl Display for TrashServiceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AtomNotFound(id) => write!(f, "trashed atom not found: {id}"),
+            Self::Repo(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for TrashServiceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Repo(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<RepoError> for TrashServiceError {
+    fn from(err: RepoError) -> Self {
+        match err {
+            RepoError::NotFound(id) => Self::AtomNotFound(id),
+            other => Self::Repo(other),
+        }
+    }
+}
+
+/// Trash list result envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashListResult {
+    /// Items sorted by deletion time, newest first.
+    pub items: Vec<TrashItem>,
+    /// Effective normalized limit used by the query.
+    pub applied_limit: u32,
+}
+
+/// Trash service facade over repository implementations.
+pub struct TrashService<R: TrashRepository> {
+    repo: R,
+}
+
+impl<R: TrashRepository> TrashService<R> {
+    /// Creates a service using the provided repository implementation.
+    pub fn new(repo: R) -> Self {
+        Self { repo }
+    }
+
+    /// Lists restorable trashed atoms. Limit defaults to 20 and clamps to 100.
+    pub fn list_trash(
+        &self,
+        kind: Option<AtomType>,
+        limit: Option<u32>,
+        offset: u32,
+    ) -> Result<TrashListResult, TrashServiceError> {
+        let applied_limit = normalize_trash_limit(limit);
+        let items = self.repo.list_trash(kind, applied_limit, offset)?;
+        Ok(TrashListResult {
+            items,
+            applied_limit,
+        })
+    }
+
+    /// Restores one trashed atom; returns the note_ref revived or created
+    /// for a note that had no active workspace reference.
+    pub fn restore_atom(
+        &self,
+        atom_id: AtomId,
+    ) -> Result<Option<WorkspaceNodeId>, TrashServiceError> {
+        Ok(self.repo.restore_atom(atom_id)?)
+    }
+
+    /// Permanently purges one trashed atom.
+    pub fn purge_atom(&self, atom_id: AtomId) -> Result<PurgeOutcome, TrashServiceError> {
+        Ok(self.repo.purge_atom(atom_id)?)
+    }
+
+    /// Purges atoms deleted more than `retention_days` before `now_ms`.
+    pub fn purge_expired(
+        &self,
+        now_ms: i64,
+        retention_days: Option<u32>,
+    ) -> Result<PurgeSummary, TrashServiceError> {
+        let retention_days = normalize_retention_days(retention_days);
+        if retention_days == 0 {
+            return Ok(PurgeSummary::default());
+        }
+        let cutoff_ms = now_ms.saturating_sub(i64::from(retention_days) * DAY_MS);
+        Ok(self.repo.purge_deleted_before(cutoff_ms)?)
+    }
+}
+
+/// Normalizes retention days: `None` -> 30, `Some(0)` disables auto-purge,
+/// larger values clamp to 3650.
+pub fn normalize_retention_days(retention_days: Option<u32>) -> u32 {
+    match retention_days {
+        None => DEFAULT_TRASH_RETENTION_DAYS,
+        Some(value) => value.min(MAX_TRASH_RETENTION_DAYS),
+    }
+}
@@ -122,6 +122,9 @@ impl<R: TreeRepository> TreeService<R> {
     }
 
     /// Creates one note_ref under optional parent.
+    ///
+    /// Without `display_name` the node is named after the note title and
+    /// keeps following it until renamed.
     pub fn create_note_ref(
         &self,
         parent_uuid: Option<WorkspaceNodeId>,
@@ -133,16 +136,19 @@ impl<R: TreeRepository> TreeService<R> {
         }
         self.ensure_atom_is_note(atom_uuid)?;
 
-        let normalized = match display_name {
-            Some(value) => normalize_display_name(value)?,
-            None => "Untitled note".to_string(),
-        };
+        let normalized = display_name.map(normalize_display_name).transpose()?;
 
         self.repo
-            .create_note_ref(parent_uuid, atom_uuid, normalized.as_str())
+            .create_note_ref(parent_uuid, atom_uuid, normalized.as_deref())
             .map_err(Into::into)
     }
 
+    /// Renames title-following note_refs whose name is stale, including
+    /// legacy `Untitled note` rows. Idempotent; returns renamed node count.
+    pub fn repair_note_titles(&self) -> Result<u32, TreeServiceError> {
+        self.repo.repair_note_titles().map_err(Into::into)
+    }
+
     /// Lists child nodes under optional parent.
     pub fn list_children(
         &self,
@@ -0,0 +1,448 @@
+//! Hybrid Logical Clock (HLC) for local write stamping and LWW merge.
+//!
+//! # Responsibility
+//! - Generate monotonic, causally ordered timestamps for local writes.
+//! - Persist per-device clock state (`hlc_state`) across restarts.
+//! - Provide deterministic last-writer-wins comparison helpers for sync.
+//!
+//! # Invariants
+//! - Stamps issued by one device are strictly increasing, even when the wall
+//!   clock moves backwards.
+//! - Encoded stamps sort lexicographically in the same order as
+//!   [`HlcTimestamp`]'s `Ord` (`physical_ms`, `logical`, `node_id`).
+//! - `node_id` is generated once by migration `0011` and never rotated.
+//!
+//! # See also
+//! - docs/architecture/data-model.md
+
+use crate::db::DbError;
+use crate::sync::provider_types::now_epoch_ms;
+use rusqlite::{params, Connection};
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Maximum tolerated lead of a remote physical clock over the local one.
+pub const MAX_CLOCK_DRIFT_MS: i64 = 5 * 60 * 1000;
+
+/// Attempts for the compare-and-swap state update before giving up.
+const MAX_PERSIST_ATTEMPTS: usize = 8;
+
+/// Result type for HLC APIs.
+pub type HlcResult<T> = Result<T, HlcError>;
+
+/// Errors from HLC parsing, clock advancement and persistence.
+#[derive(Debug)]
+pub enum HlcError {
+    /// Encoded timestamp text is malformed.
+    InvalidTimestamp(String),
+    /// Remote physical time is too far ahead of the local wall clock.
+    ClockDrift { remote_ms: i64, local_ms: i64 },
+    /// Persisted `hlc_state` row is missing or invalid.
+    InvalidState(String),
+    /// Underlying SQLite error.
+    Db(DbError),
+}
+
+impl Display for HlcError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidTimestamp(value) => write!(f, "invalid hlc timestamp `{value}`"),
+            Self::ClockDrift {
+                remote_ms,
+                local_ms,
+            } => write!(
+                f,
+                "remote hlc physical time {remote_ms} exceeds local time {local_ms} by more than {MAX_CLOCK_DRIFT_MS} ms"
+            ),
+            Self::InvalidState(message) => write!(f, "invalid hlc state: {message}"),
+            Self::Db(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for HlcError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Db(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<rusqlite::Error> for HlcError {
+    fn from(value: rusqlite::Error) -> Self {
+        Self::Db(DbError::Sqlite(value))
+    }
+}
+
+/// One hybrid logical clock reading.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HlcTimestamp {
+    /// Wall-clock component, epoch milliseconds.
+    pub physical_ms: i64,
+    /// Counter disambiguating events within one `physical_ms`.
+    pub logical: u32,
+    /// Issuing device id; final tie-breaker.
+    pub node_id: String,
+}
+
+impl HlcTimestamp {
+    /// Parses the canonical encoded form produced by `Display`.
+    ///
+    /// # Errors
+    /// - Returns [`HlcError::InvalidTimestamp`] for malformed text.
+    pub fn parse(value: &str) -> HlcResult<Self> {
+        let invalid = || HlcError::InvalidTimestamp(value.to_string());
+        let mut parts = value.splitn(3, '-');
+        let (Some(physical), Some(logical), Some(node_id)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        if physical.len() != 15 || logical.len() != 8 || !is_valid_node_id(node_id) {
+            return Err(invalid());
+        }
+        let physical_ms = physical.parse::<i64>().map_err(|_| invalid())?;
+        let logical = u32::from_str_radix(logical, 16).map_err(|_| invalid())?;
+        Ok(Self {
+            physical_ms,
+            logical,
+            node_id: node_id.to_string(),
+        })
+    }
+}
+
+impl Display for HlcTimestamp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:015}-{:08x}-{}",
+            self.physical_ms, self.logical, self.node_id
+        )
+    }
+}
+
+impl FromStr for HlcTimestamp {
+    type Err = HlcError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl Ord for HlcTimestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.physical_ms
+            .cmp(&other.physical_ms)
+            .then(self.logical.cmp(&other.logical))
+            .then_with(|| self.node_id.cmp(&other.node_id))
+    }
+}
+
+impl PartialOrd for HlcTimestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Outcome of a last-writer-wins comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LwwDecision {
+    /// Local version is newer or identical; ignore the remote write.
+    KeepLocal,
+    /// Remote version is newer; apply it.
+    TakeRemote,
+}
+
+/// Decides which version wins under last-writer-wins.
+///
+/// A missing local stamp (legacy row) always loses to a remote stamp.
+pub fn resolve_lww(local: Option<&HlcTimestamp>, remote: &HlcTimestamp) -> LwwDecision {
+    match local {
+        Some(local) if local >= remote => LwwDecision::KeepLocal,
+        _ => LwwDecision::TakeRemote,
+    }
+}
+
+/// Compares two optional encoded stamps; `None` sorts before any stamp.
+///
+/// # Errors
+/// - Returns [`HlcError::InvalidTimestamp`] when either value is malformed.
+pub fn compare_encoded(left: Option<&str>, right: Option<&str>) -> HlcResult<Ordering> {
+    let left = left.map(HlcTimestamp::parse).transpose()?;
+    let right = right.map(HlcTimestamp::parse).transpose()?;
+    Ok(left.cmp(&right))
+}
+
+/// In-memory hybrid logical clock for one device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HybridLogicalClock {
+    node_id: String,
+    last_physical_ms: i64,
+    last_logical: u32,
+}
+
+impl HybridLogicalClock {
+    /// Restores a clock from persisted state.
+    pub fn new(node_id: impl Into<String>, last_physical_ms: i64, last_logical: u32) -> Self {
+        Self {
+            node_id: node_id.into(),
+            last_physical_ms,
+            last_logical,
+        }
+    }
+
+    /// Device id used as final tie-breaker.
+    pub fn node_id(&self) -> &str {
+        &self.node_id
+    }
+
+    /// Issues the next stamp for a local event at wall time `now_ms`.
+    pub fn tick(&mut self, now_ms: i64) -> HlcTimestamp {
+        if now_ms > self.last_physical_ms {
+            self.last_physical_ms = now_ms;
+            self.last_logical = 0;
+        } else {
+            self.advance_logical();
+        }
+        self.current()
+    }
+
+    /// Merges a remote stamp and issues the next local stamp.
+    ///
+    /// # Errors
+    /// - Returns [`HlcError::ClockDrift`] when `remote` is more than
+    ///   [`MAX_CLOCK_DRIFT_MS`] ahead of `now_ms`; the clock is unchanged.
+    pub fn observe(&mut self, remote: &HlcTimestamp, now_ms: i64) -> HlcResult<HlcTimestamp> {
+        if remote.physical_ms.saturating_sub(now_ms) > MAX_CLOCK_DRIFT_MS {
+            return Err(HlcError::ClockDrift {
+                remote_ms: remote.physical_ms,
+                local_ms: now_ms,
+            });
+        }
+
+        let physical = now_ms.max(self.last_physical_ms).max(remote.physical_ms);
+        let base_logical = match (
+            physical == self.last_physical_ms,
+            physical == remote.physical_ms,
+        ) {
+            (true, true) => Some(self.last_logical.max(remote.logical)),
+            (true, false) => Some(self.last_logical),
+            (false, true) => Some(remote.logical),
+            (false, false) => None,
+        };
+
+        self.last_physical_ms = physical;
+        match base_logical {
+            Some(logical) => {
+                self.last_logical = logical;
+                self.advance_logical();
+            }
+            None => self.last_logical = 0,
+        }
+        Ok(self.current())
+    }
+
+    fn advance_logical(&mut self) {
+        match self.last_logical.checked_add(1) {
+            Some(next) => self.last_logical = next,
+            None => {
+                // Why: borrow one millisecond instead of wrapping the counter.
+                self.last_physical_ms += 1;
+                self.last_logical = 0;
+            }
+        }
+    }
+
+    fn current(&self) -> HlcTimestamp {
+        HlcTimestamp {
+            physical_ms: self.last_physical_ms,
+            logical: self.last_logical,
+            node_id: self.node_id.clone(),
+        }
+    }
+}
+
+/// Loads the persisted device clock.
+///
+/// # Errors
+/// - Returns [`HlcError::InvalidState`] when the singleton row is missing
+///   or malformed.
+pub fn load_clock(conn: &Connection) -> HlcResult<HybridLogicalClock> {
+    let (node_id, last_physical_ms, last_logical): (String, i64, i64) = conn
+        .query_row(
+            "SELECT node_id, last_physical_ms, last_logical
+             FROM hlc_state
+             WHERE id = 1;",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .map_err(|err| match err {
+            rusqlite::Error::QueryReturnedNoRows => {
+                HlcError::InvalidState("missing hlc_state row".to_string())
+            }
+            other => other.into(),
+        })?;
+
+    if !is_valid_node_id(&node_id) {
+        return Err(HlcError::InvalidState(format!(
+            "invalid node id `{node_id}`"
+        )));
+    }
+    let last_logical = u32::try_from(last_logical)
+        .map_err(|_| HlcError::InvalidState(format!("invalid logical counter {last_logical}")))?;
+    Ok(HybridLogicalClock::new(
+        node_id,
+        last_physical_ms,
+        last_logical,
+    ))
+}
+
+/// Returns the persisted device node id.
+pub fn local_node_id(conn: &Connection) -> HlcResult<String> {
+    Ok(load_clock(conn)?.node_id)
+}
+
+/// Issues and persists the next local stamp for a write.
+pub fn stamp_hlc(conn: &Connection) -> HlcResult<HlcTimestamp> {
+    advance_persisted(conn, |clock| Ok(clock.tick(now_epoch_ms())))
+}
+
+/// Merges a remote stamp into the persisted clock (sync receive path).
+pub fn observe_remote_hlc(conn: &Connection, remote: &HlcTimestamp) -> HlcResult<HlcTimestamp> {
+    advance_persisted(conn, |clock| clock.observe(remote, now_epoch_ms()))
+}
+
+/// Advances persisted state with compare-and-swap so concurrent
+/// connections never issue the same stamp.
+fn advance_persisted(
+    conn: &Connection,
+    mut advance: impl FnMut(&mut HybridLogicalClock) -> HlcResult<HlcTimestamp>,
+) -> HlcResult<HlcTimestamp> {
+    for _ in 0..MAX_PERSIST_ATTEMPTS {
+        let mut clock = load_clock(conn)?;
+        let previous = (clock.last_physical_ms, clock.last_logical);
+        let stamp = advance(&mut clock)?;
+        let changed = conn.execute(
+            "UPDATE hlc_state
+             SET last_physical_ms = ?1,
+                 last_logical = ?2
+             WHERE id = 1
+               AND last_physical_ms = ?3
+               AND last_logical = ?4;",
+            params![
+                stamp.physical_ms,
+                i64::from(stamp.logical),
+                previous.0,
+                i64::from(previous.1)
+            ],
+        )?;
+        if changed == 1 {
+            return Ok(stamp);
+        }
+    }
+    Err(HlcError::InvalidState(
+        "concurrent hlc updates did not settle".to_string(),
+    ))
+}
+
+fn is_valid_node_id(value: &str) -> bool {
+    !value.is_empty() && value.chars().all(|ch| ch.is_ascii_alphanumeric())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        compare_encoded, resolve_lww, HlcError, HlcTimestamp, HybridLogicalClock, LwwDecision,
+        MAX_CLOCK_DRIFT_MS,
+    };
+    use std::cmp::Ordering;
+
+    fn stamp(physical_ms: i64, logical: u32, node_id: &str) -> HlcTimestamp {
+        HlcTimestamp {
+            physical_ms,
+            logical,
+            node_id: node_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn tick_is_monotonic_when_wall_clock_stalls_or_goes_back() {
+        let mut clock = HybridLogicalClock::new("a1", 0, 0);
+        let first = clock.tick(1_000);
+        let second = clock.tick(1_000);
+        let third = clock.tick(900);
+        assert_eq!(first, stamp(1_000, 0, "a1"));
+        assert_eq!(second, stamp(1_000, 1, "a1"));
+        assert_eq!(third, stamp(1_000, 2, "a1"));
+        assert_eq!(clock.tick(1_001), stamp(1_001, 0, "a1"));
+    }
+
+    #[test]
+    fn logical_overflow_borrows_one_millisecond() {
+        let mut clock = HybridLogicalClock::new("a1", 5, u32::MAX);
+        assert_eq!(clock.tick(5), stamp(6, 0, "a1"));
+    }
+
+    #[test]
+    fn observe_moves_past_remote_stamp() {
+        let mut clock = HybridLogicalClock::new("a1", 1_000, 3);
+        let merged = clock.observe(&stamp(2_000, 7, "b2"), 1_500).unwrap();
+        assert_eq!(merged, stamp(2_000, 8, "a1"));
+
+        let merged = clock.observe(&stamp(2_000, 2, "b2"), 1_500).unwrap();
+        assert_eq!(merged, stamp(2_000, 9, "a1"));
+
+        let merged = clock.observe(&stamp(10, 0, "b2"), 3_000).unwrap();
+        assert_eq!(merged, stamp(3_000, 0, "a1"));
+    }
+
+    #[test]
+    fn observe_rejects_excessive_drift() {
+        let mut clock = HybridLogicalClock::new("a1", 0, 0);
+        let err = clock
+            .observe(&stamp(MAX_CLOCK_DRIFT_MS + 2, 0, "b2"), 1)
+            .unwrap_err();
+        assert!(matches!(err, HlcError::ClockDrift { .. }));
+        assert_eq!(clock.tick(1), stamp(1, 0, "a1"));
+    }
+
+    #[test]
+    fn encoding_round_trips_and_sorts_like_ord() {
+        let values = [
+            stamp(999, 5, "ff"),
+            stamp(1_000, 0, "aa"),
+            stamp(1_000, 0, "bb"),
+            stamp(1_000, 16, "aa"),
+        ];
+        for pair in values.windows(2) {
+            assert!(pair[0] < pair[1]);
+            assert!(pair[0].to_string() < pair[1].to_string());
+        }
+        for value in &values {
+            assert_eq!(&HlcTimestamp::parse(&value.to_string()).unwrap(), value);
+        }
+        assert!(HlcTimestamp::parse("12-00000000-aa").is_err());
+        assert!(HlcTimestamp::parse("000000000001000-0000000g-aa").is_err());
+        assert!(HlcTimestamp::parse("000000000001000-00000000-").is_err());
+    }
+
+    #[test]
+    fn lww_helpers_are_deterministic() {
+        let older = stamp(1_000, 0, "aa");
+        let newer = stamp(1_000, 0, "bb");
+        assert_eq!(resolve_lww(Some(&newer), &older), LwwDecision::KeepLocal);
+        assert_eq!(resolve_lww(Some(&older), &newer), LwwDecision::TakeRemote);
+        assert_eq!(resolve_lww(Some(&older), &older), LwwDecision::KeepLocal);
+        assert_eq!(resolve_lww(None, &older), LwwDecision::TakeRemote);
+
+        let encoded = older.to_string();
+        assert_eq!(
+            compare_encoded(None, Some(encoded.as_str())).unwrap(),
+            Ordering::Less
+        );
+        assert!(compare_encoded(Some("bad"), None).is_err());
+    }
+}
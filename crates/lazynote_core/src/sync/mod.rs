@@ -3,7 +3,11 @@
 //! v0.2 scope is declaration-level contracts plus in-process provider
 //! registry/selection hooks. Concrete provider implementations are out of
 //! scope.
+//!
+//! Local writes are stamped by the Hybrid Logical Clock in [`hlc`], which
+//! also provides last-writer-wins comparison helpers for merge.
 
+pub mod hlc;
 pub mod provider_registry;
 pub mod provider_spi;
 pub mod provider_types;
@@ -0,0 +1,116 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    Atom, AtomConvertError, AtomRepository, AtomService, AtomType, AtomValidationError,
+    ConvertAtomRequest, LinkService, NoteService, RepoError, SqliteAtomRepository,
+    SqliteLinkRepository, SqliteNoteRepository, SqliteTreeRepository, TaskStatus, TreeService,
+};
+use rusqlite::Connection;
+use uuid::Uuid;
+
+fn atoms(conn: &Connection) -> AtomService<SqliteAtomRepository<'_>> {
+    AtomService::new(SqliteAtomRepository::try_new(conn).unwrap())
+}
+
+fn to(target: AtomType, start: Option<i64>, end: Option<i64>) -> ConvertAtomRequest {
+    ConvertAtomRequest {
+        target,
+        start_epoch_ms: start,
+        end_epoch_ms: end,
+    }
+}
+
+fn plan_link_target(conn: &Connection, source: Uuid) -> Option<Uuid> {
+    LinkService::new(SqliteLinkRepository::try_new(conn).unwrap())
+        .list_outgoing_links(source)
+        .unwrap()[0]
+        .target_id
+}
+
+#[test]
+fn conversion_round_trip_keeps_identity_tags_and_note_refs() {
+    let mut conn = open_db_in_memory().unwrap();
+    let (note_id, source) = {
+        let mut notes = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+        let note_id = notes.create_note("# Plan\n#work").unwrap().atom_id;
+        notes
+            .set_note_tags(note_id, vec!["manual".to_string()])
+            .unwrap();
+        let source = notes.create_note("see [[Plan]]").unwrap().atom_id;
+        (note_id, source)
+    };
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let node = tree.create_note_ref(None, note_id, None).unwrap();
+    assert_eq!(plan_link_target(&conn, source), Some(note_id));
+
+    let task = atoms(&conn)
+        .convert_atom(note_id, &to(AtomType::Task, None, None))
+        .unwrap();
+    assert_eq!(task.uuid, note_id);
+    assert_eq!(task.kind, AtomType::Task);
+    assert_eq!(task.task_status, Some(TaskStatus::Todo));
+    assert!(tree.list_children(None).unwrap().is_empty());
+    assert_eq!(plan_link_target(&conn, source), None);
+
+    let event = atoms(&conn)
+        .convert_atom(note_id, &to(AtomType::Event, Some(1_000), Some(5_000)))
+        .unwrap();
+    assert_eq!((event.start_at, event.end_at), (Some(1_000), Some(5_000)));
+    assert_eq!(event.task_status, Some(TaskStatus::Todo));
+
+    let note = atoms(&conn)
+        .convert_atom(note_id, &to(AtomType::Note, None, None))
+        .unwrap();
+    assert_eq!(
+        (note.task_status, note.start_at, note.end_at),
+        (None, None, None)
+    );
+    let children = tree.list_children(None).unwrap();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].node_uuid, node.node_uuid);
+    assert_eq!(plan_link_target(&conn, source), Some(note_id));
+    let tags = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap())
+        .get_note(note_id)
+        .unwrap()
+        .unwrap()
+        .tags;
+    assert_eq!(tags, vec!["manual".to_string(), "work".to_string()]);
+}
+
+#[test]
+fn event_conversion_derives_start_or_rejects() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let mut deadline = Atom::new(AtomType::Task, "file taxes");
+    deadline.task_status = Some(TaskStatus::InProgress);
+    deadline.end_at = Some(9_000);
+    repo.create_atom(&deadline).unwrap();
+    let inbox = Atom::new(AtomType::Task, "someday");
+    repo.create_atom(&inbox).unwrap();
+
+    let service = atoms(&conn);
+    let event = service
+        .convert_atom(deadline.uuid, &to(AtomType::Event, None, None))
+        .unwrap();
+    assert_eq!((event.start_at, event.end_at), (Some(9_000), None));
+    assert_eq!(event.task_status, Some(TaskStatus::InProgress));
+
+    let err = service
+        .convert_atom(inbox.uuid, &to(AtomType::Event, None, None))
+        .unwrap_err();
+    assert!(matches!(err, AtomConvertError::EventStartRequired(id) if id == inbox.uuid));
+
+    let err = service
+        .convert_atom(inbox.uuid, &to(AtomType::Event, Some(5_000), Some(1_000)))
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        AtomConvertError::Repo(RepoError::Validation(
+            AtomValidationError::InvalidEventWindow { .. }
+        ))
+    ));
+
+    let err = service
+        .convert_atom(Uuid::new_v4(), &to(AtomType::Note, None, None))
+        .unwrap_err();
+    assert!(matches!(err, AtomConvertError::AtomNotFound(_)));
+}
@@ -0,0 +1,204 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    now_epoch_ms, AtomService, AttachmentService, AttachmentServiceError, NoteService,
+    SqliteAtomRepository, SqliteAttachmentRepository, SqliteNoteRepository, SqliteTrashRepository,
+    TrashService,
+};
+use rusqlite::Connection;
+use std::fs;
+use std::path::Path;
+
+fn blob_count(store_root: &Path) -> usize {
+    if !store_root.exists() {
+        return 0;
+    }
+    fs::read_dir(store_root)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .map(|dir| fs::read_dir(dir).unwrap().count())
+        .sum()
+}
+
+fn attachment_service<'conn>(
+    conn: &'conn Connection,
+    store_root: &Path,
+) -> AttachmentService<SqliteAttachmentRepository<'conn>> {
+    AttachmentService::new(
+        SqliteAttachmentRepository::try_new(conn).unwrap(),
+        store_root,
+    )
+}
+
+#[test]
+fn import_deduplicates_identical_bytes_and_resolves_uri() {
+    let conn = open_db_in_memory().unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let store_root = dir.path().join("attachments");
+    let source = dir.path().join("Diagram.PNG");
+    fs::write(&source, b"png-bytes").unwrap();
+    let service = attachment_service(&conn, &store_root);
+
+    let first = service.import_file(&source).unwrap();
+    assert!(!first.deduplicated);
+    assert_eq!(
+        first.attachment.sha256,
+        "ea80334363eed145dfeee51ebae7dc3f1cd7d0c7879f8bfd2070c061d3c33f56"
+    );
+    assert_eq!(
+        first.uri,
+        format!("attachment://{}", first.attachment.sha256)
+    );
+    assert_eq!(first.attachment.mime_type.as_deref(), Some("image/png"));
+    assert_eq!(
+        first.attachment.original_name.as_deref(),
+        Some("Diagram.PNG")
+    );
+    assert_eq!(first.attachment.byte_size, 9);
+
+    let second = service
+        .import_bytes(b"png-bytes", Some("copy.png"))
+        .unwrap();
+    assert!(second.deduplicated);
+    assert_eq!(second.attachment.sha256, first.attachment.sha256);
+    assert_eq!(
+        second.attachment.original_name.as_deref(),
+        Some("Diagram.PNG")
+    );
+    assert_eq!(blob_count(&store_root), 1);
+
+    let (resolved, path) = service.resolve(&first.uri).unwrap();
+    assert_eq!(resolved.sha256, first.attachment.sha256);
+    assert_eq!(fs::read(path).unwrap(), b"png-bytes");
+
+    assert!(matches!(
+        service.resolve("attachment://nope"),
+        Err(AttachmentServiceError::InvalidUri(_))
+    ));
+    assert!(matches!(
+        service.resolve(&"0".repeat(64)),
+        Err(AttachmentServiceError::AttachmentNotFound(_))
+    ));
+    assert!(matches!(
+        service.import_file(&dir.path().join("missing.bin")),
+        Err(AttachmentServiceError::SourceNotFound(_))
+    ));
+}
+
+#[test]
+fn note_content_references_drive_ref_counts() {
+    let mut conn = open_db_in_memory().unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let store_root = dir.path().join("attachments");
+    let imported = attachment_service(&conn, &store_root)
+        .import_bytes(b"report", Some("report.pdf"))
+        .unwrap();
+
+    let note_id = {
+        let notes = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+        let note = notes
+            .create_note(format!(
+                "See [report]({}) and `{}`",
+                imported.uri, imported.uri
+            ))
+            .unwrap();
+        note.atom_id
+    };
+    {
+        let atoms = AtomService::new(SqliteAtomRepository::try_new(&conn).unwrap());
+        atoms
+            .create_note(format!("Also ![cover]({})", imported.uri))
+            .unwrap();
+    }
+
+    let service = attachment_service(&conn, &store_root);
+    let listed = service.list_for_atom(note_id).unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].ref_count, 2);
+    assert_eq!(listed[0].mime_type.as_deref(), Some("application/pdf"));
+
+    {
+        let notes = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+        notes.update_note(note_id, "no attachment anymore").unwrap();
+    }
+    let service = attachment_service(&conn, &store_root);
+    assert!(service.list_for_atom(note_id).unwrap().is_empty());
+    let (resolved, _) = service.resolve(&imported.uri).unwrap();
+    assert_eq!(resolved.ref_count, 1);
+}
+
+#[test]
+fn gc_removes_unreferenced_blobs_after_grace_and_keeps_trashed_refs() {
+    let mut conn = open_db_in_memory().unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let store_root = dir.path().join("attachments");
+    let (kept, orphan) = {
+        let service = attachment_service(&conn, &store_root);
+        (
+            service.import_bytes(b"kept", Some("kept.txt")).unwrap(),
+            service.import_bytes(b"orphan", Some("orphan.txt")).unwrap(),
+        )
+    };
+    let note_id = {
+        let notes = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+        notes
+            .create_note(format!("[kept]({})", kept.uri))
+            .unwrap()
+            .atom_id
+    };
+    {
+        let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+        AtomService::new(atoms).soft_delete_atom(note_id).unwrap();
+    }
+    let stray_dir = store_root.join("ab");
+    fs::create_dir_all(&stray_dir).unwrap();
+    fs::write(stray_dir.join("ab".repeat(32)), b"stray").unwrap();
+
+    let now = now_epoch_ms();
+    let service = attachment_service(&conn, &store_root);
+    let fresh = service.collect_garbage(now, 60_000).unwrap();
+    assert_eq!(fresh.removed_rows, 0);
+    assert_eq!(fresh.removed_blobs, 0);
+    assert_eq!(blob_count(&store_root), 3);
+
+    let later = now + 120_000;
+    let summary = service.collect_garbage(later, 60_000).unwrap();
+    assert_eq!(summary.removed_rows, 1);
+    assert_eq!(summary.removed_blobs, 2);
+    assert_eq!(summary.freed_bytes, 11);
+    assert!(service.resolve(&kept.uri).is_ok());
+    assert!(matches!(
+        service.resolve(&orphan.uri),
+        Err(AttachmentServiceError::AttachmentNotFound(_))
+    ));
+
+    let trash = TrashService::new(SqliteTrashRepository::try_new(&conn).unwrap());
+    trash.purge_atom(note_id).unwrap();
+    let summary = service.collect_garbage(later, 60_000).unwrap();
+    assert_eq!(summary.removed_blobs, 1);
+    assert_eq!(blob_count(&store_root), 0);
+}
+
+#[test]
+fn reimport_restarts_gc_grace_for_orphaned_blob() {
+    let conn = open_db_in_memory().unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let store_root = dir.path().join("attachments");
+    let service = attachment_service(&conn, &store_root);
+    let first = service.import_bytes(b"scan", Some("scan.pdf")).unwrap();
+    // The orphaned row was imported long ago and is past its grace window.
+    conn.execute(
+        "UPDATE attachments SET created_at = 1000 WHERE sha256 = ?1;",
+        [first.attachment.sha256.as_str()],
+    )
+    .unwrap();
+
+    let again = service.import_bytes(b"scan", Some("copy.pdf")).unwrap();
+    assert!(again.deduplicated);
+    assert!(again.attachment.created_at > 1000);
+    assert_eq!(again.attachment.original_name.as_deref(), Some("scan.pdf"));
+
+    let summary = service.collect_garbage(now_epoch_ms(), 60_000).unwrap();
+    assert_eq!(summary.removed_rows, 0);
+    assert!(service.resolve(&again.uri).is_ok());
+}
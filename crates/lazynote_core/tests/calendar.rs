@@ -0,0 +1,296 @@
+use chrono::{NaiveDate, Weekday};
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    floating_span, Atom, AtomRepository, AtomType, CalendarService, CalendarServiceError, FreeSlot,
+    LocalCalendar, SqliteAtomRepository, TaskStatus,
+};
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn at(day: u32, hour: u32, minute: u32) -> i64 {
+    date(2026, 5, day)
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+        .and_utc()
+        .timestamp_millis()
+}
+
+fn insert(
+    repo: &SqliteAtomRepository<'_>,
+    kind: AtomType,
+    content: &str,
+    start: i64,
+    end: i64,
+) -> Atom {
+    let mut atom = Atom::new(kind, content);
+    atom.start_at = Some(start);
+    atom.end_at = Some(end);
+    repo.create_atom(&atom).unwrap();
+    atom
+}
+
+fn insert_all_day(repo: &SqliteAtomRepository<'_>, first: NaiveDate, last: NaiveDate) -> Atom {
+    let (start, end) = floating_span(first, last);
+    let mut atom = Atom::new(AtomType::Event, "trip");
+    atom.start_at = Some(start);
+    atom.end_at = Some(end);
+    atom.all_day = true;
+    repo.create_atom(&atom).unwrap();
+    atom
+}
+
+#[test]
+fn days_bucket_spanning_all_day_and_point_atoms() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let overnight = insert(
+        &repo,
+        AtomType::Event,
+        "overnight",
+        at(4, 22, 0),
+        at(5, 2, 0),
+    );
+    let until_midnight = insert(&repo, AtomType::Event, "late", at(5, 23, 0), at(6, 0, 0));
+    let point = insert(&repo, AtomType::Event, "alarm", at(6, 9, 0), at(6, 9, 0));
+    let trip = insert_all_day(&repo, date(2026, 5, 5), date(2026, 5, 6));
+
+    let svc = CalendarService::new(&repo, LocalCalendar::utc());
+    let days = svc.days(date(2026, 5, 4), date(2026, 5, 6)).unwrap();
+    assert_eq!(days.len(), 3);
+    let timed_ids =
+        |index: usize| -> Vec<_> { days[index].timed.iter().map(|e| e.atom.uuid).collect() };
+    assert_eq!(timed_ids(0), vec![overnight.uuid]);
+    assert_eq!(timed_ids(1), vec![overnight.uuid, until_midnight.uuid]);
+    assert_eq!(timed_ids(2), vec![point.uuid]);
+
+    let first_part = &days[0].timed[0];
+    assert_eq!(
+        (first_part.visible_start_ms, first_part.visible_end_ms),
+        (at(4, 22, 0), at(5, 0, 0))
+    );
+    assert!(!first_part.continues_before && first_part.continues_after);
+    let second_part = &days[1].timed[0];
+    assert_eq!(
+        (second_part.visible_start_ms, second_part.visible_end_ms),
+        (at(5, 0, 0), at(5, 2, 0))
+    );
+    assert!(second_part.continues_before && !second_part.continues_after);
+    assert!(!days[1].timed[1].continues_after);
+    assert_eq!(
+        days[2].timed[0].visible_start_ms,
+        days[2].timed[0].visible_end_ms
+    );
+
+    assert!(days[0].all_day.is_empty());
+    assert_eq!(days[1].all_day[0].atom.uuid, trip.uuid);
+    assert!(!days[1].all_day[0].continues_before && days[1].all_day[0].continues_after);
+    assert!(days[2].all_day[0].continues_before && !days[2].all_day[0].continues_after);
+}
+
+#[test]
+fn overlapping_timed_entries_share_columns() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let a = insert(&repo, AtomType::Event, "a", at(4, 9, 0), at(4, 11, 0));
+    let b = insert(&repo, AtomType::Event, "b", at(4, 10, 0), at(4, 12, 0));
+    let c = insert(&repo, AtomType::Task, "c", at(4, 11, 0), at(4, 12, 30));
+    let d = insert(&repo, AtomType::Event, "d", at(4, 13, 0), at(4, 14, 0));
+
+    let svc = CalendarService::new(&repo, LocalCalendar::utc());
+    let day = &svc.days(date(2026, 5, 4), date(2026, 5, 4)).unwrap()[0];
+    let layout: Vec<_> = day
+        .timed
+        .iter()
+        .map(|entry| (entry.atom.uuid, entry.column, entry.column_count))
+        .collect();
+    assert_eq!(
+        layout,
+        vec![
+            (a.uuid, 0, 2),
+            (b.uuid, 1, 2),
+            (c.uuid, 0, 2),
+            (d.uuid, 0, 1)
+        ]
+    );
+}
+
+#[test]
+fn conflicts_report_overlapping_events_including_occurrences() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let first = insert(&repo, AtomType::Event, "review", at(4, 9, 0), at(4, 10, 0));
+    let second = insert(&repo, AtomType::Event, "call", at(4, 9, 30), at(4, 10, 0));
+    let touching = insert(
+        &repo,
+        AtomType::Event,
+        "touching",
+        at(4, 10, 0),
+        at(4, 11, 0),
+    );
+    insert(
+        &repo,
+        AtomType::Task,
+        "focus block",
+        at(4, 9, 0),
+        at(4, 10, 0),
+    );
+    let mut cancelled = Atom::new(AtomType::Event, "cancelled");
+    cancelled.start_at = Some(at(4, 9, 0));
+    cancelled.end_at = Some(at(4, 10, 0));
+    cancelled.task_status = Some(TaskStatus::Cancelled);
+    repo.create_atom(&cancelled).unwrap();
+    let mut standup = Atom::new(AtomType::Event, "standup");
+    standup.start_at = Some(at(3, 9, 45));
+    standup.end_at = Some(at(3, 10, 15));
+    standup.recurrence_rule = Some("FREQ=DAILY".to_string());
+    repo.create_atom(&standup).unwrap();
+
+    let svc = CalendarService::new(&repo, LocalCalendar::utc());
+    let conflicts = svc.conflicts(date(2026, 5, 4), date(2026, 5, 4)).unwrap();
+    let pairs: Vec<_> = conflicts
+        .iter()
+        .map(|c| {
+            (
+                c.first.uuid,
+                c.second.uuid,
+                c.overlap_start_ms,
+                c.overlap_end_ms,
+            )
+        })
+        .collect();
+    assert_eq!(
+        pairs,
+        vec![
+            (first.uuid, second.uuid, at(4, 9, 30), at(4, 10, 0)),
+            (first.uuid, standup.uuid, at(4, 9, 45), at(4, 10, 0)),
+            (second.uuid, standup.uuid, at(4, 9, 45), at(4, 10, 0)),
+            (standup.uuid, touching.uuid, at(4, 10, 0), at(4, 10, 15)),
+        ]
+    );
+    assert_eq!(conflicts[1].second_occurrence_start_at, Some(at(4, 9, 45)));
+    assert!(conflicts.iter().all(|c| c.first.kind == AtomType::Event));
+}
+
+#[test]
+fn free_slots_skip_busy_time_but_not_all_day_or_cancelled() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    insert(&repo, AtomType::Event, "a", at(4, 9, 0), at(4, 10, 0));
+    insert(&repo, AtomType::Task, "b", at(4, 9, 30), at(4, 11, 0));
+    insert(&repo, AtomType::Event, "c", at(4, 14, 0), at(4, 14, 20));
+    let mut cancelled = Atom::new(AtomType::Event, "cancelled");
+    cancelled.start_at = Some(at(4, 12, 0));
+    cancelled.end_at = Some(at(4, 13, 0));
+    cancelled.task_status = Some(TaskStatus::Cancelled);
+    repo.create_atom(&cancelled).unwrap();
+    insert_all_day(&repo, date(2026, 5, 4), date(2026, 5, 4));
+
+    let svc = CalendarService::new(&repo, LocalCalendar::utc());
+    let slot = |start, end| FreeSlot {
+        start_ms: start,
+        end_ms: end,
+    };
+    assert_eq!(
+        svc.free_slots(at(4, 8, 0), at(4, 18, 0), 60).unwrap(),
+        vec![
+            slot(at(4, 8, 0), at(4, 9, 0)),
+            slot(at(4, 11, 0), at(4, 14, 0)),
+            slot(at(4, 14, 20), at(4, 18, 0)),
+        ]
+    );
+    assert_eq!(
+        svc.free_slots(at(4, 8, 0), at(4, 18, 0), 200).unwrap(),
+        vec![slot(at(4, 14, 20), at(4, 18, 0))]
+    );
+
+    assert!(matches!(
+        svc.free_slots(at(4, 8, 0), at(4, 18, 0), 0),
+        Err(CalendarServiceError::InvalidSlotDuration(0))
+    ));
+    assert!(matches!(
+        svc.free_slots(at(4, 18, 0), at(4, 8, 0), 30),
+        Err(CalendarServiceError::InvalidTimeRange { .. })
+    ));
+}
+
+#[test]
+fn views_page_past_the_repository_page_size() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    conn.execute_batch("BEGIN;").unwrap();
+    for _ in 0..10_000 {
+        insert(&repo, AtomType::Event, "crowd", at(4, 8, 0), at(4, 8, 1));
+    }
+    conn.execute_batch("COMMIT;").unwrap();
+    let late = insert(&repo, AtomType::Event, "late", at(4, 20, 0), at(4, 21, 0));
+
+    let svc = CalendarService::new(&repo, LocalCalendar::utc());
+    let days = svc.days(date(2026, 5, 4), date(2026, 5, 4)).unwrap();
+    assert_eq!(days[0].timed.len(), 10_001);
+    assert!(days[0]
+        .timed
+        .iter()
+        .any(|entry| entry.atom.uuid == late.uuid));
+
+    let slot = |start, end| FreeSlot {
+        start_ms: start,
+        end_ms: end,
+    };
+    assert_eq!(
+        svc.free_slots(at(4, 8, 0), at(4, 22, 0), 60).unwrap(),
+        vec![
+            slot(at(4, 8, 1), at(4, 20, 0)),
+            slot(at(4, 21, 0), at(4, 22, 0)),
+        ]
+    );
+}
+
+#[test]
+fn days_follow_local_calendar_across_dst() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    // 23:30 EDT on 2026-03-08, the 23h spring-forward day in New York.
+    let late = insert(
+        &repo,
+        AtomType::Event,
+        "late",
+        date(2026, 3, 9)
+            .and_hms_opt(3, 30, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_millis(),
+        date(2026, 3, 9)
+            .and_hms_opt(3, 45, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_millis(),
+    );
+
+    let ny = LocalCalendar::new("America/New_York", Weekday::Sun).unwrap();
+    let svc = CalendarService::new(&repo, ny);
+    let days = svc.days(date(2026, 3, 8), date(2026, 3, 9)).unwrap();
+    assert_eq!(days[0].end_ms - days[0].start_ms + 1, 23 * 3_600_000);
+    assert_eq!(days[0].timed[0].atom.uuid, late.uuid);
+    assert!(days[1].timed.is_empty());
+
+    let now = date(2026, 3, 11)
+        .and_hms_opt(12, 0, 0)
+        .unwrap()
+        .and_utc()
+        .timestamp_millis();
+    let week = svc.week_days(now).unwrap();
+    assert_eq!(week.len(), 7);
+    assert_eq!(week[0].date, date(2026, 3, 8));
+    assert_eq!(svc.month_days(now).unwrap().len(), 31);
+
+    assert!(matches!(
+        svc.days(date(2026, 3, 9), date(2026, 3, 8)),
+        Err(CalendarServiceError::InvalidDateRange { .. })
+    ));
+    assert!(matches!(
+        svc.days(date(2026, 1, 1), date(2026, 12, 31)),
+        Err(CalendarServiceError::RangeTooLarge { days: 365 })
+    ));
+}
@@ -12,11 +12,35 @@ fn open_db_in_memory_applies_all_migrations() {
     assert_table_exists(&conn, "tags");
     assert_table_exists(&conn, "atom_tags");
     assert_table_exists(&conn, "external_mappings");
+    assert_table_exists(&conn, "recurrence_exceptions");
+    assert_table_exists(&conn, "hlc_state");
+    assert_table_exists(&conn, "atom_revisions");
+    assert_table_exists(&conn, "atom_links");
+    assert_table_exists(&conn, "links");
+    assert_table_exists(&conn, "atoms_fts_trigram");
+    assert_table_exists(&conn, "attachments");
+    assert_table_exists(&conn, "atom_attachments");
+    assert_table_exists(&conn, "atom_properties");
+    assert_table_exists(&conn, "atom_content_stats");
+    assert_table_exists(&conn, "atom_titles");
+    assert_table_exists(&conn, "task_subtasks");
+    assert_table_exists(&conn, "task_dependencies");
+    assert_table_exists(&conn, "reminders");
+    assert_table_exists(&conn, "time_entries");
+    assert_column_exists(&conn, "workspace_nodes", "hlc_timestamp");
+    assert_column_exists(&conn, "workspace_nodes", "name_follows_title");
+    assert_column_exists(&conn, "atom_tags", "source");
+    assert_column_exists(&conn, "atoms", "purged_at");
     assert_column_exists(&conn, "atoms", "preview_text");
     assert_column_exists(&conn, "atoms", "preview_image");
     assert_column_exists(&conn, "atoms", "start_at");
     assert_column_exists(&conn, "atoms", "end_at");
     assert_column_exists(&conn, "atoms", "recurrence_rule");
+    assert_column_exists(&conn, "atoms", "priority");
+    assert_column_exists(&conn, "atoms", "due_at");
+    assert_column_exists(&conn, "atom_revisions", "due_at");
+    assert_column_exists(&conn, "atoms", "all_day");
+    assert_column_exists(&conn, "atom_revisions", "all_day");
 }
 
 #[test]
@@ -337,6 +361,110 @@ fn migration_9_backfill_sql_is_idempotent_on_replay() {
     assert_eq!(count_after_replay, 1);
 }
 
+#[test]
+fn upgrade_across_migration_13_backfills_atom_links() {
+    let mut conn = Connection::open_in_memory().unwrap();
+    migrate_to_v8(&conn);
+
+    let target = Uuid::new_v4().to_string();
+    let source = Uuid::new_v4().to_string();
+    conn.execute(
+        "INSERT INTO atoms (uuid, type, content) VALUES (?1, 'note', 'target');",
+        [target.as_str()],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO atoms (uuid, type, content) VALUES (?1, 'note', ?2);",
+        [
+            source.as_str(),
+            &format!("see [[{target}]] and [[Missing]]"),
+        ],
+    )
+    .unwrap();
+
+    apply_migrations(&mut conn).unwrap();
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT target_key, target_uuid
+             FROM atom_links
+             WHERE source_uuid = ?1
+             ORDER BY position;",
+        )
+        .unwrap();
+    let links: Vec<(String, Option<String>)> = stmt
+        .query_map([source.as_str()], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        links,
+        vec![
+            (target.clone(), Some(target.clone())),
+            ("missing".to_string(), None)
+        ]
+    );
+}
+
+#[test]
+fn upgrade_backfills_every_content_derived_index() {
+    let mut conn = Connection::open_in_memory().unwrap();
+    migrate_to_v8(&conn);
+
+    let note = Uuid::new_v4().to_string();
+    let digest = "ab".repeat(32);
+    conn.execute(
+        "INSERT INTO atoms (uuid, type, content) VALUES (?1, 'note', ?2);",
+        [
+            note.as_str(),
+            &format!(
+                "---\nstatus: draft\n---\n# Launch plan\n\
+                 Ship #release via https://example.com\n\
+                 ![shot](attachment://{digest})\n- [x] done\n"
+            ),
+        ],
+    )
+    .unwrap();
+
+    apply_migrations(&mut conn).unwrap();
+
+    let query = |sql: &str| -> String {
+        conn.query_row(sql, [note.as_str()], |row| row.get(0))
+            .unwrap()
+    };
+    assert_eq!(
+        query("SELECT target FROM links WHERE atom_uuid = ?1;"),
+        "https://example.com"
+    );
+    assert_eq!(
+        query(
+            "SELECT t.name FROM atom_tags at
+             INNER JOIN tags t ON t.id = at.tag_id
+             WHERE at.atom_uuid = ?1 AND at.source = 'inline';"
+        ),
+        "release"
+    );
+    assert_eq!(
+        query("SELECT sha256 FROM atom_attachments WHERE atom_uuid = ?1;"),
+        digest
+    );
+    assert_eq!(
+        query("SELECT value_text FROM atom_properties WHERE atom_uuid = ?1 AND key = 'status';"),
+        "draft"
+    );
+    assert_eq!(
+        query(
+            "SELECT checklist_done || '/' || checklist_total
+             FROM atom_content_stats WHERE atom_uuid = ?1;"
+        ),
+        "1/1"
+    );
+    assert_eq!(
+        query("SELECT derived_title FROM atom_titles WHERE atom_uuid = ?1;"),
+        "Launch plan"
+    );
+}
+
 fn migrate_to_v8(conn: &Connection) {
     let migrations = [
         (1u32, include_str!("../src/db/migrations/0001_init.sql")),
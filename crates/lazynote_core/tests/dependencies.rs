@@ -0,0 +1,131 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    Atom, AtomId, AtomRepository, AtomType, SectionAtom, SectionOptions, SqliteAtomRepository,
+    TaskService, TaskServiceError, TaskStatus,
+};
+
+const HIDE_BLOCKED: SectionOptions = SectionOptions { hide_blocked: true };
+
+fn create_task(repo: &SqliteAtomRepository<'_>, content: &str) -> AtomId {
+    let mut atom = Atom::new(AtomType::Task, content);
+    atom.task_status = Some(TaskStatus::Todo);
+    repo.create_atom(&atom).unwrap()
+}
+
+fn ids(items: Vec<SectionAtom>) -> Vec<AtomId> {
+    items.into_iter().map(|item| item.atom.uuid).collect()
+}
+
+#[test]
+fn prerequisites_block_until_finished_and_reject_cycles() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let svc = TaskService::new(&repo, &conn);
+    let review = create_task(&repo, "review");
+    let deploy = create_task(&repo, "deploy");
+    let announce = create_task(&repo, "announce");
+    let note = repo
+        .create_atom(&Atom::new(AtomType::Note, "note"))
+        .unwrap();
+
+    assert!(svc.add_dependency(deploy, review).unwrap());
+    assert!(!svc.add_dependency(deploy, review).unwrap());
+    svc.add_dependency(announce, deploy).unwrap();
+    assert!(svc.is_blocked(deploy).unwrap());
+    assert!(!svc.is_blocked(review).unwrap());
+    assert_eq!(ids(svc.list_prerequisites(deploy).unwrap()), vec![review]);
+    assert_eq!(ids(svc.list_dependents(deploy).unwrap()), vec![announce]);
+
+    assert!(matches!(
+        svc.add_dependency(review, announce),
+        Err(TaskServiceError::DependencyCycle { .. })
+    ));
+    assert!(matches!(
+        svc.add_dependency(review, review),
+        Err(TaskServiceError::DependencyCycle { .. })
+    ));
+    assert!(matches!(
+        svc.add_dependency(review, note),
+        Err(TaskServiceError::NotATask(id)) if id == note
+    ));
+
+    svc.update_status(review, Some(TaskStatus::Done)).unwrap();
+    assert!(!svc.is_blocked(deploy).unwrap());
+    assert!(svc.is_blocked(announce).unwrap());
+    svc.update_status(deploy, Some(TaskStatus::Cancelled))
+        .unwrap();
+    assert!(!svc.is_blocked(announce).unwrap());
+
+    svc.update_status(deploy, Some(TaskStatus::Todo)).unwrap();
+    repo.soft_delete_atom(deploy).unwrap();
+    assert!(!svc.is_blocked(announce).unwrap());
+    assert!(svc.remove_dependency(announce, deploy).unwrap());
+    assert!(!svc.remove_dependency(announce, deploy).unwrap());
+}
+
+#[test]
+fn inbox_and_today_can_hide_blocked_tasks() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let svc = TaskService::new(&repo, &conn);
+    let review = create_task(&repo, "review");
+    let deploy = create_task(&repo, "deploy");
+    let mut ship = Atom::new(AtomType::Task, "ship today");
+    ship.task_status = Some(TaskStatus::Todo);
+    ship.end_at = Some(5_000);
+    let ship = repo.create_atom(&ship).unwrap();
+    svc.add_dependency(deploy, review).unwrap();
+    svc.add_dependency(ship, review).unwrap();
+
+    let inbox = svc.fetch_inbox(50, 0).unwrap();
+    let flagged: Vec<(AtomId, bool)> = inbox
+        .iter()
+        .map(|item| (item.atom.uuid, item.blocked))
+        .collect();
+    assert!(flagged.contains(&(deploy, true)));
+    assert!(flagged.contains(&(review, false)));
+    assert_eq!(
+        ids(svc.fetch_inbox_with(50, 0, HIDE_BLOCKED).unwrap()),
+        vec![review]
+    );
+
+    assert_eq!(ids(svc.fetch_today(0, 10_000, 50, 0).unwrap()), vec![ship]);
+    assert!(svc
+        .fetch_today_with(0, 10_000, 50, 0, HIDE_BLOCKED)
+        .unwrap()
+        .is_empty());
+
+    svc.update_status(review, Some(TaskStatus::Done)).unwrap();
+    assert_eq!(
+        ids(svc
+            .fetch_today_with(0, 10_000, 50, 0, HIDE_BLOCKED)
+            .unwrap()),
+        vec![ship]
+    );
+}
+
+#[test]
+fn topological_order_puts_prerequisites_first_and_keeps_outline_ties() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let svc = TaskService::new(&repo, &conn);
+    let project = create_task(&repo, "project");
+    let build = create_task(&repo, "build");
+    let design = create_task(&repo, "design");
+    let mockups = create_task(&repo, "mockups");
+    let research = create_task(&repo, "research");
+    let outside = create_task(&repo, "outside");
+    for child in [build, design, research] {
+        svc.set_parent(child, Some(project), None).unwrap();
+    }
+    svc.set_parent(mockups, Some(design), None).unwrap();
+
+    svc.add_dependency(build, research).unwrap();
+    svc.add_dependency(mockups, build).unwrap();
+    svc.add_dependency(design, outside).unwrap();
+
+    assert_eq!(
+        ids(svc.topological_order(project).unwrap()),
+        vec![design, research, build, mockups]
+    );
+}
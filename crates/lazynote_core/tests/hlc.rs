@@ -0,0 +1,151 @@
+use lazynote_core::db::{open_db, open_db_in_memory};
+use lazynote_core::{
+    local_node_id, observe_remote_hlc, stamp_hlc, Atom, AtomRepository, AtomType, HlcTimestamp,
+    NoteRepository, SqliteAtomRepository, SqliteNoteRepository, SqliteTreeRepository, TaskStatus,
+    TreeRepository,
+};
+use uuid::Uuid;
+
+fn atom_hlc(conn: &rusqlite::Connection, id: Uuid) -> HlcTimestamp {
+    let text: String = conn
+        .query_row(
+            "SELECT hlc_timestamp FROM atoms WHERE uuid = ?1;",
+            [id.to_string()],
+            |row| row.get(0),
+        )
+        .unwrap();
+    HlcTimestamp::parse(&text).unwrap()
+}
+
+fn node_hlc(conn: &rusqlite::Connection, id: Uuid) -> HlcTimestamp {
+    let text: String = conn
+        .query_row(
+            "SELECT hlc_timestamp FROM workspace_nodes WHERE node_uuid = ?1;",
+            [id.to_string()],
+            |row| row.get(0),
+        )
+        .unwrap();
+    HlcTimestamp::parse(&text).unwrap()
+}
+
+// ---------------------------------------------------------------------------
+// Clock persistence
+// ---------------------------------------------------------------------------
+
+#[test]
+fn node_id_and_clock_persist_across_reopen() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("hlc.db");
+
+    let conn = open_db(&path).unwrap();
+    let node_id = local_node_id(&conn).unwrap();
+    let last = stamp_hlc(&conn).unwrap();
+    drop(conn);
+
+    let reopened = open_db(&path).unwrap();
+    assert_eq!(local_node_id(&reopened).unwrap(), node_id);
+    let next = stamp_hlc(&reopened).unwrap();
+    assert!(next > last);
+    assert_eq!(next.node_id, node_id);
+}
+
+#[test]
+fn stamps_are_strictly_increasing() {
+    let conn = open_db_in_memory().unwrap();
+    let mut previous = stamp_hlc(&conn).unwrap();
+    for _ in 0..50 {
+        let next = stamp_hlc(&conn).unwrap();
+        assert!(next > previous);
+        previous = next;
+    }
+}
+
+#[test]
+fn observe_remote_advances_local_clock() {
+    let conn = open_db_in_memory().unwrap();
+    let local = stamp_hlc(&conn).unwrap();
+    let remote = HlcTimestamp {
+        physical_ms: local.physical_ms + 1_000,
+        logical: 4,
+        node_id: "remote01".to_string(),
+    };
+
+    let merged = observe_remote_hlc(&conn, &remote).unwrap();
+    assert!(merged > remote);
+    assert!(stamp_hlc(&conn).unwrap() > merged);
+}
+
+// ---------------------------------------------------------------------------
+// Repository write stamping
+// ---------------------------------------------------------------------------
+
+#[test]
+fn atom_writes_stamp_monotonic_hlc() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+
+    let mut atom = Atom::new(AtomType::Task, "stamp me");
+    atom.hlc_timestamp = Some("caller-value".to_string());
+    repo.create_atom(&atom).unwrap();
+    let created = atom_hlc(&conn, atom.uuid);
+    assert_eq!(created.node_id, local_node_id(&conn).unwrap());
+
+    atom.content = "edited".to_string();
+    repo.update_atom(&atom).unwrap();
+    let updated = atom_hlc(&conn, atom.uuid);
+    assert!(updated > created);
+
+    repo.update_atom_status(atom.uuid, Some(TaskStatus::Done))
+        .unwrap();
+    let status_changed = atom_hlc(&conn, atom.uuid);
+    assert!(status_changed > updated);
+
+    repo.soft_delete_atom(atom.uuid).unwrap();
+    let deleted = atom_hlc(&conn, atom.uuid);
+    assert!(deleted > status_changed);
+
+    let loaded = repo.get_atom(atom.uuid, true).unwrap().unwrap();
+    assert_eq!(loaded.hlc_timestamp, Some(deleted.to_string()));
+}
+
+#[test]
+fn note_writes_stamp_hlc() {
+    let mut conn = open_db_in_memory().unwrap();
+    let atom = Atom::new(AtomType::Note, "# note");
+    let created = {
+        let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+        repo.create_note(&atom).unwrap();
+        repo.update_note_full(atom.uuid, "# edited", Some("edited"), None)
+            .unwrap();
+        atom.uuid
+    };
+    let after_update = atom_hlc(&conn, created);
+
+    {
+        let mut repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+        repo.set_note_tags(created, &["work".to_string()]).unwrap();
+    }
+    assert!(atom_hlc(&conn, created) > after_update);
+}
+
+#[test]
+fn tree_writes_stamp_hlc() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteTreeRepository::try_new(&conn).unwrap();
+
+    let parent = repo.create_folder(None, "Projects").unwrap();
+    let child = repo.create_folder(Some(parent.node_uuid), "Q4").unwrap();
+    let created = HlcTimestamp::parse(child.hlc_timestamp.as_deref().unwrap()).unwrap();
+
+    repo.rename_node(child.node_uuid, "Q4 plans").unwrap();
+    let renamed = node_hlc(&conn, child.node_uuid);
+    assert!(renamed > created);
+
+    repo.move_node(child.node_uuid, None, Some(0)).unwrap();
+    let moved = node_hlc(&conn, child.node_uuid);
+    assert!(moved > renamed);
+    assert_eq!(node_hlc(&conn, parent.node_uuid), moved);
+
+    repo.delete_folder_delete_all(child.node_uuid).unwrap();
+    assert!(node_hlc(&conn, child.node_uuid) > moved);
+}
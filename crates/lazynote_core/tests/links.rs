@@ -0,0 +1,169 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    Atom, AtomRepository, AtomType, LinkService, NoteRepository, SqliteAtomRepository,
+    SqliteLinkRepository, SqliteNoteRepository, SqliteTreeRepository, TreeService, WikiLinkKind,
+};
+use rusqlite::Connection;
+use uuid::Uuid;
+
+fn insert_atom(conn: &Connection, kind: AtomType, content: &str) -> Uuid {
+    let repo = SqliteAtomRepository::try_new(conn).unwrap();
+    let atom = Atom::new(kind, content);
+    repo.create_atom(&atom).unwrap()
+}
+
+/// Creates a note with a root-level note_ref named `title`.
+fn insert_titled_note(conn: &Connection, title: &str, content: &str) -> (Uuid, Uuid) {
+    let atom_id = insert_atom(conn, AtomType::Note, content);
+    let tree = TreeService::new(SqliteTreeRepository::try_new(conn).unwrap());
+    let node = tree
+        .create_note_ref(None, atom_id, Some(title.to_string()))
+        .unwrap();
+    (atom_id, node.node_uuid)
+}
+
+fn links(conn: &Connection) -> LinkService<SqliteLinkRepository<'_>> {
+    LinkService::new(SqliteLinkRepository::try_new(conn).unwrap())
+}
+
+// ---------------------------------------------------------------------------
+// Extraction and resolution
+// ---------------------------------------------------------------------------
+
+#[test]
+fn title_and_uuid_links_resolve_on_save() {
+    let conn = open_db_in_memory().unwrap();
+    let (plan_id, _) = insert_titled_note(&conn, "Project Plan", "plan body");
+    let task_id = insert_atom(&conn, AtomType::Task, "review");
+    let source = insert_atom(
+        &conn,
+        AtomType::Note,
+        &format!("See [[project plan]] and [[{task_id}|the task]]"),
+    );
+
+    let outgoing = links(&conn).list_outgoing_links(source).unwrap();
+    assert_eq!(outgoing.len(), 2);
+    assert_eq!(outgoing[0].kind, WikiLinkKind::Title);
+    assert_eq!(outgoing[0].target_id, Some(plan_id));
+    assert_eq!(outgoing[1].kind, WikiLinkKind::Uuid);
+    assert_eq!(outgoing[1].target_id, Some(task_id));
+    assert_eq!(outgoing[1].label.as_deref(), Some("the task"));
+}
+
+#[test]
+fn content_update_replaces_link_set() {
+    let mut conn = open_db_in_memory().unwrap();
+    let source = insert_atom(&conn, AtomType::Note, "[[Alpha]] [[Beta]]");
+
+    {
+        let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+        repo.update_note_full(source, "[[Gamma]]", None, None)
+            .unwrap();
+    }
+
+    let targets: Vec<String> = links(&conn)
+        .list_outgoing_links(source)
+        .unwrap()
+        .into_iter()
+        .map(|link| link.target)
+        .collect();
+    assert_eq!(targets, vec!["Gamma"]);
+}
+
+#[test]
+fn backlinks_list_active_sources_and_skip_self_links() {
+    let conn = open_db_in_memory().unwrap();
+    let (target, _) = insert_titled_note(&conn, "Hub", "[[Hub]]");
+    let first = insert_atom(&conn, AtomType::Note, "to [[Hub]]");
+    let second = insert_atom(&conn, AtomType::Event, &format!("[[{target}]]"));
+    let deleted = insert_atom(&conn, AtomType::Task, "[[hub]]");
+    SqliteAtomRepository::try_new(&conn)
+        .unwrap()
+        .soft_delete_atom(deleted)
+        .unwrap();
+
+    let mut sources: Vec<Uuid> = links(&conn)
+        .list_backlinks(target)
+        .unwrap()
+        .into_iter()
+        .map(|link| link.source_id)
+        .collect();
+    sources.sort();
+    let mut expected = vec![first, second];
+    expected.sort();
+    assert_eq!(sources, expected);
+}
+
+// ---------------------------------------------------------------------------
+// Unresolved links and re-resolution
+// ---------------------------------------------------------------------------
+
+#[test]
+fn unresolved_links_include_missing_titles_and_deleted_targets() {
+    let conn = open_db_in_memory().unwrap();
+    let task_id = insert_atom(&conn, AtomType::Task, "gone soon");
+    let source = insert_atom(&conn, AtomType::Note, &format!("[[Nowhere]] [[{task_id}]]"));
+    SqliteAtomRepository::try_new(&conn)
+        .unwrap()
+        .soft_delete_atom(task_id)
+        .unwrap();
+
+    let unresolved = links(&conn).list_unresolved_links(None, 0).unwrap();
+    assert_eq!(unresolved.len(), 2);
+    assert!(unresolved.iter().all(|link| link.source_id == source));
+    assert!(unresolved.iter().all(|link| !link.is_resolved()));
+}
+
+#[test]
+fn renaming_note_ref_re_resolves_title_links() {
+    let conn = open_db_in_memory().unwrap();
+    let (note_id, node_id) = insert_titled_note(&conn, "Draft", "body");
+    let source = insert_atom(&conn, AtomType::Note, "[[Final Report]] [[Draft]]");
+
+    let before = links(&conn).list_outgoing_links(source).unwrap();
+    assert_eq!(before[0].target_id, None);
+    assert_eq!(before[1].target_id, Some(note_id));
+
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    tree.rename_node(node_id, "Final Report").unwrap();
+
+    let after = links(&conn).list_outgoing_links(source).unwrap();
+    assert_eq!(after[0].target_id, Some(note_id));
+    assert_eq!(after[1].target_id, None);
+    assert_eq!(links(&conn).list_backlinks(note_id).unwrap().len(), 1);
+}
+
+#[test]
+fn new_note_ref_resolves_pending_title_links() {
+    let conn = open_db_in_memory().unwrap();
+    let source = insert_atom(&conn, AtomType::Task, "write [[Later]]");
+    assert_eq!(
+        links(&conn).list_unresolved_links(None, 0).unwrap().len(),
+        1
+    );
+
+    let (later_id, _) = insert_titled_note(&conn, "Later", "body");
+
+    let outgoing = links(&conn).list_outgoing_links(source).unwrap();
+    assert_eq!(outgoing[0].target_id, Some(later_id));
+    assert!(links(&conn)
+        .list_unresolved_links(None, 0)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn rebuild_indexes_content_written_without_link_sync() {
+    let conn = open_db_in_memory().unwrap();
+    let source = insert_atom(&conn, AtomType::Note, "plain");
+    conn.execute(
+        "UPDATE atoms SET content = '[[Legacy]]' WHERE uuid = ?1;",
+        [source.to_string()],
+    )
+    .unwrap();
+    assert!(links(&conn).list_outgoing_links(source).unwrap().is_empty());
+
+    let indexed = links(&conn).rebuild_links().unwrap();
+    assert_eq!(indexed, 1);
+    assert_eq!(links(&conn).list_outgoing_links(source).unwrap().len(), 1);
+}
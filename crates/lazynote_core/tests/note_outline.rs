@@ -0,0 +1,77 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{ContentStats, NoteService, NoteServiceError, SqliteNoteRepository};
+use rusqlite::params;
+
+const CHECKLIST_NOTE: &str = "---
+owner: ana
+---
+# Release
+Ship the *beta* build.
+## Checklist
+- [x] tag commit
+- [ ] publish notes
+### Later
+```
+# not a heading
+```
+# Retro
+";
+
+#[test]
+fn outline_and_cached_stats_follow_note_content() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note_id = {
+        let service = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+        let note = service.create_note(CHECKLIST_NOTE).unwrap();
+        assert_eq!(
+            note.stats,
+            ContentStats {
+                word_count: 15,
+                char_count: 76,
+                checklist_done: 1,
+                checklist_total: 2,
+            }
+        );
+
+        let outline = service.note_outline(note.atom_id).unwrap();
+        let roots: Vec<&str> = outline.headings.iter().map(|h| h.text.as_str()).collect();
+        assert_eq!(roots, vec!["Release", "Retro"]);
+        let checklist = &outline.headings[0].children[0];
+        assert_eq!(checklist.text, "Checklist");
+        assert_eq!(checklist.children[0].text, "Later");
+        assert_eq!(
+            &CHECKLIST_NOTE[checklist.byte_start..checklist.byte_end],
+            "## Checklist\n"
+        );
+        assert_eq!(outline.stats, note.stats);
+
+        let updated = service
+            .update_note(note.atom_id, "- [x] tag commit\n- [x] publish notes")
+            .unwrap();
+        assert_eq!(
+            (updated.stats.checklist_done, updated.stats.checklist_total),
+            (2, 2)
+        );
+        assert!(matches!(
+            service.note_outline(uuid::Uuid::new_v4()),
+            Err(NoteServiceError::NoteNotFound(_))
+        ));
+        note.atom_id
+    };
+
+    let cached: i64 = conn
+        .query_row(
+            "SELECT checklist_done FROM atom_content_stats WHERE atom_uuid = ?1;",
+            params![note_id.to_string()],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(cached, 2);
+
+    // Rows saved before the cache existed fall back to parsing content.
+    conn.execute("DELETE FROM atom_content_stats;", []).unwrap();
+    let service = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+    let note = service.get_note(note_id).unwrap().unwrap();
+    assert_eq!(note.stats.checklist_done, 2);
+    assert_eq!(note.stats.word_count, 4);
+}
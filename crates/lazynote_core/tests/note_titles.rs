@@ -0,0 +1,112 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    LinkService, NoteService, SqliteLinkRepository, SqliteNoteRepository, SqliteTreeRepository,
+    TreeService, UNTITLED_NOTE_TITLE,
+};
+use rusqlite::{params, Connection};
+use uuid::Uuid;
+
+fn node_name(conn: &Connection, node_uuid: Uuid) -> String {
+    conn.query_row(
+        "SELECT display_name FROM workspace_nodes WHERE node_uuid = ?1;",
+        [node_uuid.to_string()],
+        |row| row.get(0),
+    )
+    .unwrap()
+}
+
+#[test]
+fn note_refs_follow_title_until_renamed() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note_id = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap())
+        .create_note("# Plan\nbody")
+        .unwrap()
+        .atom_id;
+    let (following, renamed, named) = {
+        let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+        let following = tree.create_note_ref(None, note_id, None).unwrap();
+        assert_eq!(following.display_name, "Plan");
+        assert!(following.name_follows_title);
+        let renamed = tree.create_note_ref(None, note_id, None).unwrap();
+        tree.rename_node(renamed.node_uuid, "Mine").unwrap();
+        let named = tree
+            .create_note_ref(None, note_id, Some("Pinned".to_string()))
+            .unwrap();
+        assert!(!named.name_follows_title);
+        (following.node_uuid, renamed.node_uuid, named.node_uuid)
+    };
+    let linker = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap())
+        .create_note("see [[Roadmap]]")
+        .unwrap()
+        .atom_id;
+
+    let updated = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap())
+        .update_note(note_id, "intro line\n\n## Roadmap\nbody")
+        .unwrap();
+    assert_eq!(updated.title, "Roadmap");
+    assert_eq!(node_name(&conn, following), "Roadmap");
+    assert_eq!(node_name(&conn, renamed), "Mine");
+    assert_eq!(node_name(&conn, named), "Pinned");
+
+    let outgoing = LinkService::new(SqliteLinkRepository::try_new(&conn).unwrap())
+        .list_outgoing_links(linker)
+        .unwrap();
+    assert_eq!(outgoing[0].target_id, Some(note_id));
+}
+
+#[test]
+fn title_override_wins_until_cleared() {
+    let mut conn = open_db_in_memory().unwrap();
+    let mut service = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+    let note_id = service.create_note("first line\nsecond").unwrap().atom_id;
+    assert_eq!(
+        service.get_note(note_id).unwrap().unwrap().title,
+        "first line"
+    );
+
+    let title = service
+        .set_note_title(note_id, Some("  Weekly   review ".to_string()))
+        .unwrap();
+    assert_eq!(title.title, "Weekly review");
+    assert_eq!(title.derived_title.as_deref(), Some("first line"));
+    service.update_note(note_id, "# Changed").unwrap();
+    assert_eq!(service.note_title(note_id).unwrap().title, "Weekly review");
+
+    let cleared = service
+        .set_note_title(note_id, Some("   ".to_string()))
+        .unwrap();
+    assert_eq!(cleared.title, "Changed");
+    assert_eq!(cleared.title_override, None);
+
+    let blank = service.create_note("```\ncode only\n```").unwrap();
+    assert_eq!(blank.title, UNTITLED_NOTE_TITLE);
+    assert!(service.set_note_title(Uuid::new_v4(), None).is_err());
+}
+
+#[test]
+fn repair_renames_legacy_untitled_note_refs_once() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note_id = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap())
+        .create_note("Groceries\n- milk")
+        .unwrap()
+        .atom_id;
+    let node_uuid = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap())
+        .create_note_ref(None, note_id, Some("Kept".to_string()))
+        .unwrap()
+        .node_uuid;
+    // Simulate a pre-title database after migration 0021 flagged the
+    // backfilled row: legacy name, no cached title.
+    conn.execute(
+        "UPDATE workspace_nodes
+         SET display_name = 'Untitled note', name_follows_title = 1
+         WHERE node_uuid = ?1;",
+        params![node_uuid.to_string()],
+    )
+    .unwrap();
+    conn.execute("DELETE FROM atom_titles;", []).unwrap();
+
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    assert_eq!(tree.repair_note_titles().unwrap(), 1);
+    assert_eq!(node_name(&conn, node_uuid), "Groceries");
+    assert_eq!(tree.repair_note_titles().unwrap(), 0);
+}
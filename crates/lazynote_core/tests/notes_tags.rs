@@ -1,11 +1,12 @@
 use lazynote_core::db::migrations::latest_version;
 use lazynote_core::db::open_db_in_memory;
 use lazynote_core::{
-    AtomService, NoteService, NoteServiceError, RepoError, SqliteAtomRepository,
-    SqliteNoteRepository,
+    parse_tag_expr, search_all, AtomId, AtomService, NoteService, NoteServiceError, RepoError,
+    SearchQuery, SqliteAtomRepository, SqliteNoteRepository, TagExpr, TagSource, TagUsage,
 };
 use rusqlite::params;
 use rusqlite::Connection;
+use std::collections::HashSet;
 
 #[test]
 fn create_and_update_note_derives_markdown_preview_fields() {
@@ -122,6 +123,115 @@ fn notes_list_supports_single_tag_filter() {
     assert_eq!(filtered.items[0].atom_id, note_work.atom_id);
 }
 
+#[test]
+fn notes_list_by_tag_expression_respects_precedence_and_untagged_notes() {
+    let mut conn = open_db_in_memory().unwrap();
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let mut service = NoteService::new(repo);
+    let mut create = |content: &str, tags: &[&str]| -> AtomId {
+        let note = service.create_note(content).unwrap();
+        service
+            .set_note_tags(
+                note.atom_id,
+                tags.iter().map(|tag| tag.to_string()).collect(),
+            )
+            .unwrap();
+        note.atom_id
+    };
+    let work = create("work", &["work"]);
+    let client_archived = create("client archived", &["client", "archived"]);
+    let work_urgent = create("work urgent", &["Work", "urgent"]);
+    let untagged = create("untagged", &[]);
+
+    let ids = |expr: &str| -> HashSet<AtomId> {
+        service
+            .list_notes_by_tags(parse_tag_expr(expr).unwrap(), Some(50), 0)
+            .unwrap()
+            .items
+            .into_iter()
+            .map(|note| note.atom_id)
+            .collect()
+    };
+
+    assert_eq!(
+        ids("(work OR client) AND NOT archived"),
+        HashSet::from([work, work_urgent])
+    );
+    // AND binds tighter than OR: client OR (work AND urgent).
+    assert_eq!(
+        ids("client OR work AND urgent"),
+        HashSet::from([client_archived, work_urgent])
+    );
+    assert_eq!(ids("NOT (work OR client)"), HashSet::from([untagged]));
+    assert!(ids("missing").is_empty());
+    assert_eq!(ids("NOT missing").len(), 4);
+}
+
+#[test]
+fn empty_tag_expression_groups_match_all_or_nothing() {
+    let mut conn = open_db_in_memory().unwrap();
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let mut service = NoteService::new(repo);
+    let tagged = service.create_note("tagged").unwrap();
+    service
+        .set_note_tags(tagged.atom_id, vec!["work".to_string()])
+        .unwrap();
+    service.create_note("plain").unwrap();
+
+    let count = |expr: TagExpr| {
+        service
+            .list_notes_by_tags(expr, Some(50), 0)
+            .unwrap()
+            .items
+            .len()
+    };
+    assert_eq!(count(TagExpr::And(Vec::new())), 2);
+    assert_eq!(count(TagExpr::Or(Vec::new())), 0);
+    assert_eq!(count(TagExpr::Or(Vec::new()).negate()), 2);
+    assert_eq!(
+        count(TagExpr::Or(vec![
+            TagExpr::tag("work").unwrap(),
+            TagExpr::And(Vec::new()).negate()
+        ])),
+        1
+    );
+}
+
+#[test]
+fn search_accepts_tag_expression_field_and_query_option() {
+    let mut conn = open_db_in_memory().unwrap();
+    let (work, client) = {
+        let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+        let mut service = NoteService::new(repo);
+        let work = service.create_note("budget work").unwrap();
+        let client = service.create_note("budget client").unwrap();
+        service
+            .set_note_tags(work.atom_id, vec!["work".to_string()])
+            .unwrap();
+        service
+            .set_note_tags(
+                client.atom_id,
+                vec!["client".to_string(), "archived".to_string()],
+            )
+            .unwrap();
+        (work, client)
+    };
+
+    let field_hits = search_all(
+        &conn,
+        &SearchQuery::new("budget tags:\"(work OR client) -archived\""),
+    )
+    .unwrap();
+    assert_eq!(field_hits.len(), 1);
+    assert_eq!(field_hits[0].atom_id, work.atom_id);
+
+    let mut query = SearchQuery::new("");
+    query.tag_expr = Some(parse_tag_expr("client").unwrap());
+    let option_hits = search_all(&conn, &query).unwrap();
+    assert_eq!(option_hits.len(), 1);
+    assert_eq!(option_hits[0].atom_id, client.atom_id);
+}
+
 #[test]
 fn notes_list_limit_defaults_to_10_and_caps_at_50() {
     let mut conn = open_db_in_memory().unwrap();
@@ -209,6 +319,361 @@ fn unused_tags_are_pruned_after_full_replacement() {
     assert!(!after_clear.contains(&"ephemeral".to_string()));
 }
 
+#[test]
+fn parent_tag_filters_include_descendants_only() {
+    let mut conn = open_db_in_memory().unwrap();
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let mut service = NoteService::new(repo);
+    let child = service.create_note("child").unwrap();
+    let parent = service.create_note("parent").unwrap();
+    let sibling = service.create_note("lookalike").unwrap();
+    let tagged = service
+        .set_note_tags(child.atom_id, vec![" Work / Client_A ".to_string()])
+        .unwrap();
+    assert_eq!(tagged.tags, vec!["work/client_a".to_string()]);
+    service
+        .set_note_tags(parent.atom_id, vec!["work".to_string()])
+        .unwrap();
+    service
+        .set_note_tags(sibling.atom_id, vec!["workshop".to_string()])
+        .unwrap();
+
+    let ids = |result: lazynote_core::NotesListResult| -> HashSet<AtomId> {
+        result.items.iter().map(|note| note.atom_id).collect()
+    };
+    let by_parent = ids(service.list_notes(Some("WORK".into()), None, 0).unwrap());
+    assert_eq!(by_parent, HashSet::from([child.atom_id, parent.atom_id]));
+    let by_child = ids(service
+        .list_notes(Some("work/client_a".into()), None, 0)
+        .unwrap());
+    assert_eq!(by_child, HashSet::from([child.atom_id]));
+    // `_` must not act as a LIKE wildcard.
+    let wildcard = ids(service
+        .list_notes(Some("work/client?a".replace('?', "_")), None, 0)
+        .unwrap());
+    assert_eq!(wildcard, HashSet::from([child.atom_id]));
+    let by_expr = ids(service
+        .list_notes_by_tags(parse_tag_expr("work -work/client_a").unwrap(), None, 0)
+        .unwrap());
+    assert_eq!(by_expr, HashSet::from([parent.atom_id]));
+}
+
+#[test]
+fn rename_merge_and_delete_rewrite_tag_subtrees() {
+    let mut conn = open_db_in_memory().unwrap();
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let mut service = NoteService::new(repo);
+    let first = service.create_note("first").unwrap();
+    let second = service.create_note("second").unwrap();
+    service
+        .set_note_tags(
+            first.atom_id,
+            vec!["work".to_string(), "work/client".to_string()],
+        )
+        .unwrap();
+    service
+        .set_note_tags(
+            second.atom_id,
+            vec!["job/client".to_string(), "todo".to_string()],
+        )
+        .unwrap();
+
+    assert!(matches!(
+        service.rename_tag("work", "job"),
+        Err(NoteServiceError::TagConflict(tag)) if tag == "job"
+    ));
+    assert!(matches!(
+        service.rename_tag("missing", "other"),
+        Err(NoteServiceError::TagNotFound(_))
+    ));
+    assert_eq!(service.rename_tag("Work", "office").unwrap(), 1);
+    assert_eq!(
+        service.get_note(first.atom_id).unwrap().unwrap().tags,
+        vec!["office".to_string(), "office/client".to_string()]
+    );
+
+    assert!(matches!(
+        service.merge_tags(vec!["office".to_string()], "office/sub"),
+        Err(NoteServiceError::InvalidTag(_))
+    ));
+    assert_eq!(
+        service
+            .merge_tags(vec!["office".to_string(), "todo".to_string()], "job")
+            .unwrap(),
+        2
+    );
+    assert_eq!(
+        service.get_note(first.atom_id).unwrap().unwrap().tags,
+        vec!["job".to_string(), "job/client".to_string()]
+    );
+    assert_eq!(
+        service.get_note(second.atom_id).unwrap().unwrap().tags,
+        vec!["job".to_string(), "job/client".to_string()]
+    );
+
+    assert_eq!(service.delete_tag("job/client").unwrap(), 2);
+    assert_eq!(service.list_tags().unwrap(), vec!["job".to_string()]);
+    assert_eq!(service.delete_tag("job").unwrap(), 2);
+    assert!(service.list_tags().unwrap().is_empty());
+    assert!(matches!(
+        service.delete_tag("job"),
+        Err(NoteServiceError::TagNotFound(_))
+    ));
+}
+
+#[test]
+fn tag_edits_stamp_affected_atoms() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note_id = {
+        let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+        let mut service = NoteService::new(repo);
+        let note = service.create_note("stamped").unwrap();
+        service
+            .set_note_tags(note.atom_id, vec!["alpha".to_string()])
+            .unwrap();
+        note.atom_id
+    };
+    conn.execute(
+        "UPDATE atoms SET updated_at = 1, hlc_timestamp = NULL WHERE uuid = ?1;",
+        params![note_id.to_string()],
+    )
+    .unwrap();
+
+    {
+        let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+        let mut service = NoteService::new(repo);
+        assert_eq!(service.rename_tag("alpha", "beta").unwrap(), 1);
+    }
+
+    let (updated_at, hlc): (i64, Option<String>) = conn
+        .query_row(
+            "SELECT updated_at, hlc_timestamp FROM atoms WHERE uuid = ?1;",
+            params![note_id.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert!(updated_at > 1);
+    assert!(hlc.is_some());
+}
+
+#[test]
+fn tag_usage_counts_direct_and_descendant_live_atoms() {
+    let mut conn = open_db_in_memory().unwrap();
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let mut service = NoteService::new(repo);
+    let first = service.create_note("first").unwrap();
+    let second = service.create_note("second").unwrap();
+    service
+        .set_note_tags(
+            first.atom_id,
+            vec!["area/home".to_string(), "area/home/garden".to_string()],
+        )
+        .unwrap();
+    service
+        .set_note_tags(second.atom_id, vec!["area/work".to_string()])
+        .unwrap();
+
+    let usage = service.list_tag_usage().unwrap();
+    let usage = |name: &str| -> TagUsage {
+        usage
+            .iter()
+            .find(|item| item.name == name)
+            .cloned()
+            .unwrap_or_else(|| panic!("missing usage for {name}"))
+    };
+    assert_eq!(
+        (usage("area").direct_count, usage("area").total_count),
+        (0, 2)
+    );
+    assert_eq!(
+        (
+            usage("area/home").direct_count,
+            usage("area/home").total_count
+        ),
+        (1, 1)
+    );
+    assert_eq!(usage("area/home/garden").total_count, 1);
+    assert_eq!(usage("area/work").direct_count, 1);
+}
+
+#[test]
+fn inline_hashtags_are_derived_on_save_and_removed_with_the_text() {
+    let mut conn = open_db_in_memory().unwrap();
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let mut service = NoteService::new(repo);
+    let note = service
+        .create_note("Sync #Meeting about #项目/二期\n```\n#not-a-tag\n```")
+        .unwrap();
+    assert_eq!(
+        note.tags,
+        vec!["meeting".to_string(), "项目/二期".to_string()]
+    );
+
+    service
+        .set_note_tags(
+            note.atom_id,
+            vec!["client".to_string(), "meeting".to_string()],
+        )
+        .unwrap();
+    let sources = service.list_note_tags(note.atom_id).unwrap();
+    let manual: Vec<&str> = sources
+        .iter()
+        .filter(|tag| tag.source == TagSource::Manual)
+        .map(|tag| tag.name.as_str())
+        .collect();
+    assert_eq!(manual, vec!["client", "meeting"]);
+    assert_eq!(
+        service.get_note(note.atom_id).unwrap().unwrap().tags,
+        vec![
+            "client".to_string(),
+            "meeting".to_string(),
+            "项目/二期".to_string()
+        ]
+    );
+
+    // Removing a hashtag drops only the derived link; the explicit
+    // `meeting` stays.
+    let updated = service
+        .update_note(note.atom_id, "Sync about the client #followup")
+        .unwrap();
+    assert_eq!(
+        updated.tags,
+        vec![
+            "client".to_string(),
+            "followup".to_string(),
+            "meeting".to_string()
+        ]
+    );
+    assert!(!service
+        .list_tags()
+        .unwrap()
+        .contains(&"项目/二期".to_string()));
+    service
+        .set_note_tags(note.atom_id, vec!["client".to_string()])
+        .unwrap();
+    assert!(!service
+        .list_tags()
+        .unwrap()
+        .contains(&"meeting".to_string()));
+
+    // Inline tags participate in filters like manual ones.
+    let listed = service
+        .list_notes(Some("followup".to_string()), None, 0)
+        .unwrap();
+    assert_eq!(listed.items.len(), 1);
+}
+
+#[test]
+fn rebuild_inline_tags_backfills_legacy_content_and_rewrites_hashtags_on_rename() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note_id = {
+        let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+        let service = NoteService::new(repo);
+        service.create_note("#legacy body").unwrap().atom_id
+    };
+    conn.execute(
+        "DELETE FROM atom_tags WHERE atom_uuid = ?1;",
+        params![note_id.to_string()],
+    )
+    .unwrap();
+
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let mut service = NoteService::new(repo);
+    assert!(service.get_note(note_id).unwrap().unwrap().tags.is_empty());
+    assert_eq!(service.rebuild_inline_tags().unwrap(), 1);
+    assert_eq!(
+        service.get_note(note_id).unwrap().unwrap().tags,
+        vec!["legacy".to_string()]
+    );
+
+    service.rename_tag("legacy", "archive/legacy").unwrap();
+    let renamed = service.get_note(note_id).unwrap().unwrap();
+    assert_eq!(renamed.content, "#archive/legacy body");
+
+    // Saving the editor buffer again keeps the renamed tag.
+    service
+        .update_note(note_id, format!("{} again", renamed.content))
+        .unwrap();
+    assert_eq!(
+        service.list_note_tags(note_id).unwrap(),
+        vec![lazynote_core::NoteTag {
+            name: "archive/legacy".to_string(),
+            source: TagSource::Inline,
+        }]
+    );
+    assert!(!service.list_tags().unwrap().contains(&"legacy".to_string()));
+}
+
+#[test]
+fn tag_edits_rewrite_inline_hashtags_in_content() {
+    let mut conn = open_db_in_memory().unwrap();
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let mut service = NoteService::new(repo);
+    let note = service
+        .create_note("---\ntags: [draft, keep]\n---\nPlan #Draft and #Old/Ideas, `#old` #misc")
+        .unwrap();
+
+    // Merge: `old/ideas` lands on `ideas`; the code span is not a marker.
+    service
+        .merge_tags(vec!["old".to_string()], "ideas")
+        .unwrap();
+    // A target that cannot be a hashtag is kept as a manual tag.
+    service.rename_tag("misc", "client a").unwrap();
+    // Delete: the marker stays as plain text; the frontmatter entry goes.
+    service.delete_tag("draft").unwrap();
+
+    let current = service.get_note(note.atom_id).unwrap().unwrap();
+    assert_eq!(
+        current.content,
+        "---\ntags: [keep]\n---\nPlan Draft and #ideas/ideas, `#old` misc"
+    );
+    assert_eq!(current.tags, vec!["client a", "ideas/ideas", "keep"]);
+
+    service
+        .update_note(note.atom_id, "Plan and #ideas/ideas")
+        .unwrap();
+    let current = service.get_note(note.atom_id).unwrap().unwrap();
+    assert_eq!(current.tags, vec!["client a", "ideas/ideas"]);
+}
+
+#[test]
+fn tag_edits_rewrite_frontmatter_tags_so_saves_keep_them() {
+    let mut conn = open_db_in_memory().unwrap();
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let mut service = NoteService::new(repo);
+    let note = service
+        .create_note(
+            "---\ntitle: Plan\ntags:\n  - Work/Client\n  - \"#draft\"\naliases: Roadmap\n---\nBody",
+        )
+        .unwrap();
+
+    service.rename_tag("work", "job").unwrap();
+    service
+        .merge_tags(vec!["draft".to_string()], "review")
+        .unwrap();
+    service.rename_tag("alias/roadmap", "plans").unwrap();
+
+    let current = service.get_note(note.atom_id).unwrap().unwrap();
+    assert_eq!(
+        current.content,
+        "---\ntitle: Plan\ntags:\n  - job/client\n  - review\n---\nBody"
+    );
+    assert_eq!(current.tags, vec!["job/client", "plans", "review"]);
+
+    // Re-saving the same buffer derives the new names, not the old ones.
+    service
+        .update_note(note.atom_id, current.content.clone())
+        .unwrap();
+    assert_eq!(
+        service.get_note(note.atom_id).unwrap().unwrap().tags,
+        vec!["job/client", "plans", "review"]
+    );
+    assert_eq!(
+        service.list_tags().unwrap(),
+        vec!["job/client", "plans", "review"]
+    );
+}
+
 #[test]
 fn note_repository_rejects_connection_missing_tags_table() {
     let mut conn = Connection::open_in_memory().unwrap();
@@ -261,6 +726,9 @@ fn create_minimal_atoms_table(conn: &Connection) {
             task_status TEXT NULL,
             start_at INTEGER NULL,
             end_at INTEGER NULL,
+            priority INTEGER NULL,
+            due_at INTEGER NULL,
+            all_day INTEGER NOT NULL DEFAULT 0,
             recurrence_rule TEXT NULL,
             is_deleted INTEGER NOT NULL DEFAULT 0,
             updated_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000)
@@ -0,0 +1,144 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    search_all, AtomProperty, NoteService, PropertyRepository, PropertyValue, SearchQuery,
+    SqliteNoteRepository, SqlitePropertyRepository,
+};
+use rusqlite::params;
+use std::collections::HashSet;
+
+const DRAFT_NOTE: &str = "---
+title: Launch plan
+status: draft
+due: 2026-03-01
+rating: 4
+pinned: true
+tags: [Work/Launch, urgent]
+aliases:
+  - Q1 Launch
+---
+# Plan #kickoff
+body text";
+
+#[test]
+fn frontmatter_is_parsed_into_properties_and_tags_on_save() {
+    let mut conn = open_db_in_memory().unwrap();
+    let service = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+    let note = service.create_note(DRAFT_NOTE).unwrap();
+
+    assert_eq!(
+        note.preview_text.as_deref(),
+        Some("Plan #kickoff body text")
+    );
+    assert_eq!(
+        note.tags,
+        vec![
+            "alias/q1 launch".to_string(),
+            "kickoff".to_string(),
+            "urgent".to_string(),
+            "work/launch".to_string(),
+        ]
+    );
+
+    let properties = service.list_note_properties(note.atom_id).unwrap();
+    assert_eq!(
+        properties,
+        vec![
+            AtomProperty {
+                key: "title".to_string(),
+                value: PropertyValue::String("Launch plan".to_string()),
+            },
+            AtomProperty {
+                key: "status".to_string(),
+                value: PropertyValue::String("draft".to_string()),
+            },
+            AtomProperty {
+                key: "due".to_string(),
+                value: PropertyValue::Date("2026-03-01".to_string()),
+            },
+            AtomProperty {
+                key: "rating".to_string(),
+                value: PropertyValue::Number(4.0),
+            },
+            AtomProperty {
+                key: "pinned".to_string(),
+                value: PropertyValue::Bool(true),
+            },
+            AtomProperty {
+                key: "tags".to_string(),
+                value: PropertyValue::List(vec!["Work/Launch".to_string(), "urgent".to_string()]),
+            },
+            AtomProperty {
+                key: "aliases".to_string(),
+                value: PropertyValue::List(vec!["Q1 Launch".to_string()]),
+            },
+        ]
+    );
+
+    let updated = service
+        .update_note(note.atom_id, "# Plan\nno frontmatter anymore")
+        .unwrap();
+    assert!(updated.tags.is_empty());
+    assert!(service
+        .list_note_properties(note.atom_id)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn prop_filters_match_text_numbers_dates_and_list_items() {
+    let mut conn = open_db_in_memory().unwrap();
+    let (draft, done) = {
+        let service = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+        let draft = service.create_note(DRAFT_NOTE).unwrap().atom_id;
+        let done = service
+            .create_note("---\nstatus: Done\ndue: 2025-12-01\nrating: 2\n---\nplan retro")
+            .unwrap()
+            .atom_id;
+        service.create_note("plan without properties").unwrap();
+        (draft, done)
+    };
+
+    let ids = |text: &str| -> HashSet<_> {
+        search_all(&conn, &SearchQuery::new(text))
+            .unwrap()
+            .into_iter()
+            .map(|hit| hit.atom_id)
+            .collect()
+    };
+    assert_eq!(ids("plan prop:status=DRAFT"), HashSet::from([draft]));
+    assert_eq!(ids("plan prop:due>=2026-01-01"), HashSet::from([draft]));
+    assert_eq!(ids("plan prop:due<2026-01-01"), HashSet::from([done]));
+    assert_eq!(ids("plan prop:rating>3"), HashSet::from([draft]));
+    assert_eq!(ids("plan prop:tags=urgent"), HashSet::from([draft]));
+    assert_eq!(ids("plan prop:pinned=true"), HashSet::from([draft]));
+    assert_eq!(ids("plan prop:rating"), HashSet::from([draft, done]));
+    assert_eq!(ids("plan prop:status -prop:pinned"), HashSet::from([done]));
+}
+
+#[test]
+fn rebuild_backfills_properties_for_existing_content() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note_id = {
+        let service = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+        service
+            .create_note("---\nowner: ana\n---\nbody")
+            .unwrap()
+            .atom_id
+    };
+    conn.execute(
+        "DELETE FROM atom_properties WHERE atom_uuid = ?1;",
+        params![note_id.to_string()],
+    )
+    .unwrap();
+
+    let repo = SqlitePropertyRepository::try_new(&conn).unwrap();
+    assert!(repo.list_properties(note_id).unwrap().is_empty());
+    assert_eq!(repo.rebuild_all_properties().unwrap(), 1);
+    assert_eq!(
+        repo.list_properties(note_id).unwrap(),
+        vec![AtomProperty {
+            key: "owner".to_string(),
+            value: PropertyValue::String("ana".to_string()),
+        }]
+    );
+}
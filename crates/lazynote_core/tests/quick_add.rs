@@ -0,0 +1,242 @@
+use chrono::{NaiveDate, TimeZone, Utc, Weekday};
+use lazynote_core::{
+    floating_span, AtomType, LocalCalendar, QuickAddDraft, QuickAddParser, QuickAddTokenKind,
+    TaskPriority,
+};
+
+fn utc_ms(month: u32, day: u32, hour: u32, minute: u32) -> i64 {
+    Utc.with_ymd_and_hms(2026, month, day, hour, minute, 0)
+        .unwrap()
+        .timestamp_millis()
+}
+
+fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+}
+
+/// Wednesday 2026-05-06 10:00 UTC.
+fn now() -> i64 {
+    utc_ms(5, 6, 10, 0)
+}
+
+fn utc_parser() -> QuickAddParser {
+    QuickAddParser::new(LocalCalendar::utc())
+}
+
+fn shanghai_parser() -> QuickAddParser {
+    QuickAddParser::new(LocalCalendar::new("Asia/Shanghai", Weekday::Mon).unwrap())
+}
+
+fn token_texts<'a>(input: &'a str, draft: &QuickAddDraft) -> Vec<(QuickAddTokenKind, &'a str)> {
+    draft
+        .tokens
+        .iter()
+        .map(|token| (token.kind, &input[token.start..token.end]))
+        .collect()
+}
+
+fn all_day(month: u32, day: u32) -> (Option<i64>, Option<i64>) {
+    let (start, end) = floating_span(date(month, day), date(month, day));
+    (Some(start), Some(end))
+}
+
+#[test]
+fn english_event_with_range_tag_and_priority() {
+    let input = "Dentist tomorrow 3pm-4pm #health !high";
+    let draft = utc_parser().parse(input, now());
+
+    assert_eq!(draft.kind, AtomType::Event);
+    assert_eq!(draft.title, "Dentist");
+    assert_eq!(draft.start_at, Some(utc_ms(5, 7, 15, 0)));
+    assert_eq!(draft.end_at, Some(utc_ms(5, 7, 16, 0)));
+    assert!(!draft.all_day);
+    assert_eq!(draft.tags, vec!["health"]);
+    assert_eq!(draft.priority, Some(TaskPriority::High));
+    assert_eq!(
+        token_texts(input, &draft),
+        vec![
+            (QuickAddTokenKind::Date, "tomorrow"),
+            (QuickAddTokenKind::Time, "3pm-4pm"),
+            (QuickAddTokenKind::Tag, "#health"),
+            (QuickAddTokenKind::Priority, "!high"),
+        ]
+    );
+}
+
+#[test]
+fn chinese_event_uses_calendar_timezone_and_char_spans() {
+    let input = "周五下午三点 开会";
+    let draft = shanghai_parser().parse(input, now());
+
+    assert_eq!(draft.kind, AtomType::Event);
+    assert_eq!(draft.title, "开会");
+    // Friday 15:00 in Shanghai is 07:00 UTC.
+    assert_eq!(draft.start_at, Some(utc_ms(5, 8, 7, 0)));
+    assert_eq!(draft.end_at, draft.start_at);
+    let spans: Vec<_> = draft
+        .tokens
+        .iter()
+        .map(|token| (token.kind, token.char_start, token.char_end))
+        .collect();
+    assert_eq!(
+        spans,
+        vec![
+            (QuickAddTokenKind::Date, 0, 2),
+            (QuickAddTokenKind::Time, 2, 6),
+        ]
+    );
+}
+
+#[test]
+fn chinese_ranges_durations_weeks_and_glued_text() {
+    let parser = shanghai_parser();
+
+    let input = "明天上午十点半到十一点 评审 #工作";
+    let review = parser.parse(input, now());
+    assert_eq!(review.title, "评审");
+    assert_eq!(review.start_at, Some(utc_ms(5, 7, 2, 30)));
+    assert_eq!(review.end_at, Some(utc_ms(5, 7, 3, 0)));
+    assert_eq!(review.tags, vec!["工作"]);
+
+    // Bare 1-7 o'clock means the afternoon; now is 18:00 in Shanghai.
+    let meeting = parser.parse("3点和老王开会一个半小时", now());
+    assert_eq!(meeting.title, "和老王开会");
+    assert_eq!(meeting.start_at, Some(utc_ms(5, 7, 7, 0)));
+    assert_eq!(meeting.end_at, Some(utc_ms(5, 7, 8, 30)));
+
+    let report = parser.parse("下周一 交报告 !高", now());
+    assert_eq!(report.kind, AtomType::Task);
+    assert!(report.all_day);
+    assert_eq!((report.start_at, report.end_at), all_day(5, 11));
+    assert_eq!(report.priority, Some(TaskPriority::High));
+    assert_eq!(report.title, "交报告");
+
+    let dinner = parser.parse("今晚八点 吃饭", now());
+    assert_eq!(dinner.start_at, Some(utc_ms(5, 6, 12, 0)));
+    let afternoon = parser.parse("明天下午 开会", now());
+    assert_eq!(afternoon.title, "开会");
+    assert_eq!(afternoon.start_at, Some(utc_ms(5, 7, 7, 0)));
+    let dated = parser.parse("5月20日 交房租", now());
+    assert_eq!((dated.start_at, dated.end_at), all_day(5, 20));
+    let later = parser.parse("三天后 复查", now());
+    assert_eq!((later.start_at, later.end_at), all_day(5, 9));
+}
+
+#[test]
+fn english_dates_resolve_relative_to_reference_date() {
+    let parser = utc_parser();
+    let cases = [
+        ("Pay rent on friday", (5, 8), "Pay rent"),
+        ("Pay rent next friday", (5, 15), "Pay rent"),
+        ("Pay rent this monday", (5, 4), "Pay rent"),
+        ("Pay rent wednesday", (5, 6), "Pay rent"),
+        ("Renew passport in 2 weeks", (5, 20), "Renew passport"),
+        ("Renew passport by May 20th", (5, 20), "Renew passport"),
+        ("Renew passport 3 March", (3, 3), "Renew passport"),
+        ("Renew passport 2026-06-01", (6, 1), "Renew passport"),
+        ("Renew passport 6/1", (6, 1), "Renew passport"),
+    ];
+    for (input, (month, day), title) in cases {
+        let draft = parser.parse(input, now());
+        assert_eq!(draft.kind, AtomType::Task, "{input}");
+        assert!(draft.all_day, "{input}");
+        assert_eq!(draft.title, title, "{input}");
+        let expected = if month < 5 {
+            let next_year = NaiveDate::from_ymd_opt(2027, month, day).unwrap();
+            let (start, end) = floating_span(next_year, next_year);
+            (Some(start), Some(end))
+        } else {
+            all_day(month, day)
+        };
+        assert_eq!((draft.start_at, draft.end_at), expected, "{input}");
+    }
+}
+
+#[test]
+fn english_times_roll_over_and_take_durations() {
+    let parser = utc_parser();
+
+    let standup = parser.parse("standup 9:30", now());
+    assert_eq!(standup.start_at, Some(utc_ms(5, 7, 9, 30)));
+
+    let lunch = parser.parse("lunch at noon for 30 min", now());
+    assert_eq!(lunch.title, "lunch");
+    assert_eq!(lunch.start_at, Some(utc_ms(5, 6, 12, 0)));
+    assert_eq!(lunch.end_at, Some(utc_ms(5, 6, 12, 30)));
+
+    let call = parser.parse("call mom tonight at 8", now());
+    assert_eq!(call.title, "call mom");
+    assert_eq!(call.start_at, Some(utc_ms(5, 6, 20, 0)));
+
+    let inherited = parser.parse("11-1pm workshop", now());
+    assert_eq!(inherited.start_at, Some(utc_ms(5, 6, 11, 0)));
+    assert_eq!(inherited.end_at, Some(utc_ms(5, 6, 13, 0)));
+
+    let overnight = parser.parse("deploy friday 10pm to 1am", now());
+    assert_eq!(overnight.title, "deploy");
+    assert_eq!(overnight.start_at, Some(utc_ms(5, 8, 22, 0)));
+    assert_eq!(overnight.end_at, Some(utc_ms(5, 9, 1, 0)));
+}
+
+#[test]
+fn kind_prefix_overrides_inference() {
+    let parser = utc_parser();
+
+    let task = parser.parse("task: review draft 3pm", now());
+    assert_eq!(task.kind, AtomType::Task);
+    assert_eq!(task.title, "review draft");
+    assert_eq!(task.start_at, Some(utc_ms(5, 6, 15, 0)));
+
+    let input = "note: ideas for friday #inbox !high";
+    let note = parser.parse(input, now());
+    assert_eq!(note.kind, AtomType::Note);
+    assert_eq!(note.title, "ideas for friday !high");
+    assert_eq!((note.start_at, note.priority), (None, None));
+    assert_eq!(note.tags, vec!["inbox"]);
+    assert_eq!(
+        token_texts(input, &note),
+        vec![
+            (QuickAddTokenKind::Kind, "note:"),
+            (QuickAddTokenKind::Tag, "#inbox"),
+        ]
+    );
+
+    let event = shanghai_parser().parse("日程：周会", now());
+    assert_eq!(
+        (event.kind, event.title.as_str()),
+        (AtomType::Event, "周会")
+    );
+}
+
+#[test]
+fn plain_and_ambiguous_text_stays_in_title() {
+    let parser = shanghai_parser();
+    for input in [
+        "buy milk",
+        "Enjoy the sun",
+        "read chapters 3-4",
+        "吃一点东西",
+        "Go!",
+    ] {
+        let draft = parser.parse(input, now());
+        assert_eq!(draft.kind, AtomType::Task, "{input}");
+        assert_eq!(draft.title, input, "{input}");
+        assert!(draft.tokens.is_empty(), "{input}");
+        assert_eq!(draft.start_at, None, "{input}");
+    }
+    assert_eq!(parser.parse("  ", now()).title, "");
+}
+
+#[test]
+fn parsing_is_deterministic_and_dst_aware() {
+    let new_york =
+        QuickAddParser::new(LocalCalendar::new("America/New_York", Weekday::Sun).unwrap());
+    // 2026-03-07 12:00 EST; 02:30 on March 8 falls in the spring-forward gap.
+    let reference = utc_ms(3, 7, 17, 0);
+    let first = new_york.parse("backup tomorrow 2:30am", reference);
+    assert_eq!(first.start_at, Some(utc_ms(3, 8, 7, 0)));
+    assert_eq!(new_york.parse("backup tomorrow 2:30am", reference), first);
+
+    let after_gap = new_york.parse("backup tomorrow 9am", reference);
+    assert_eq!(after_gap.start_at, Some(utc_ms(3, 8, 13, 0)));
+}
@@ -0,0 +1,436 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    Atom, AtomRepository, AtomType, AtomValidationError, OccurrenceEditScope, OccurrencePatch,
+    RecurrenceRepository, RecurrenceRule, RecurrenceService, RecurrenceServiceError, RepoError,
+    SectionAtomRow, SqliteAtomRepository, SqliteRecurrenceRepository, TaskPriority, TaskService,
+    TaskStatus,
+};
+
+const HOUR: i64 = 3_600_000;
+const DAY: i64 = 24 * HOUR;
+/// 2026-03-02T00:00:00Z, a Monday.
+const MONDAY: i64 = 1_772_409_600_000;
+
+fn setup() -> rusqlite::Connection {
+    open_db_in_memory().unwrap()
+}
+
+/// Creates a daily 09:00-10:00 standup series starting on `MONDAY`.
+fn insert_series(conn: &rusqlite::Connection, rule: &str) -> Atom {
+    let mut atom = Atom::new(AtomType::Event, "standup");
+    atom.start_at = Some(MONDAY + 9 * HOUR);
+    atom.end_at = Some(MONDAY + 10 * HOUR);
+    atom.recurrence_rule = Some(rule.to_string());
+    SqliteAtomRepository::try_new(conn)
+        .unwrap()
+        .create_atom(&atom)
+        .unwrap();
+    atom
+}
+
+fn occurrence_starts(rows: &[SectionAtomRow]) -> Vec<i64> {
+    rows.iter()
+        .filter_map(|row| row.occurrence_start_at)
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+#[test]
+fn invalid_rule_is_rejected_on_write() {
+    let conn = setup();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+
+    let mut atom = Atom::new(AtomType::Event, "bad");
+    atom.start_at = Some(MONDAY);
+    atom.recurrence_rule = Some("FREQ=SOMETIMES".to_string());
+    assert!(matches!(
+        repo.create_atom(&atom),
+        Err(RepoError::Validation(
+            AtomValidationError::InvalidRecurrenceRule(_)
+        ))
+    ));
+
+    atom.start_at = None;
+    atom.recurrence_rule = Some("FREQ=DAILY".to_string());
+    assert!(matches!(
+        repo.create_atom(&atom),
+        Err(RepoError::Validation(
+            AtomValidationError::RecurrenceWithoutStart
+        ))
+    ));
+}
+
+// ---------------------------------------------------------------------------
+// Section / calendar expansion
+// ---------------------------------------------------------------------------
+
+#[test]
+fn fetch_by_time_range_expands_weekly_byday() {
+    let conn = setup();
+    let series = insert_series(&conn, "FREQ=WEEKLY;BYDAY=MO,WE,FR");
+    let mut single = Atom::new(AtomType::Event, "one-off");
+    single.start_at = Some(MONDAY + DAY + 12 * HOUR);
+    single.end_at = Some(MONDAY + DAY + 13 * HOUR);
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    repo.create_atom(&single).unwrap();
+
+    let week = repo
+        .fetch_by_time_range(MONDAY, MONDAY + 7 * DAY, 50, 0)
+        .unwrap();
+
+    assert_eq!(week.len(), 4);
+    assert_eq!(
+        occurrence_starts(&week),
+        vec![
+            MONDAY + 9 * HOUR,
+            MONDAY + 2 * DAY + 9 * HOUR,
+            MONDAY + 4 * DAY + 9 * HOUR,
+        ]
+    );
+    assert_eq!(week[1].atom.uuid, single.uuid);
+    assert!(week[1].occurrence_start_at.is_none());
+    assert!(week
+        .iter()
+        .filter(|row| row.occurrence_start_at.is_some())
+        .all(|row| row.atom.uuid == series.uuid
+            && row.atom.end_at == row.atom.start_at.map(|start| start + HOUR)));
+}
+
+#[test]
+fn fetch_by_time_range_paginates_merged_rows() {
+    let conn = setup();
+    insert_series(&conn, "FREQ=DAILY;COUNT=10");
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+
+    let first = repo
+        .fetch_by_time_range(MONDAY, MONDAY + 30 * DAY, 4, 0)
+        .unwrap();
+    let second = repo
+        .fetch_by_time_range(MONDAY, MONDAY + 30 * DAY, 4, 8)
+        .unwrap();
+
+    assert_eq!(first.len(), 4);
+    assert_eq!(second.len(), 2);
+    assert_eq!(
+        second[1].occurrence_start_at,
+        Some(MONDAY + 9 * DAY + 9 * HOUR)
+    );
+}
+
+#[test]
+fn fetch_today_returns_only_todays_occurrence() {
+    let conn = setup();
+    let series = insert_series(&conn, "FREQ=DAILY");
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let svc = TaskService::new(&repo, &conn);
+
+    let bod = MONDAY + 5 * DAY;
+    let today = svc.fetch_today(bod, bod + DAY - 1, 50, 0).unwrap();
+
+    assert_eq!(today.len(), 1);
+    assert_eq!(today[0].atom.uuid, series.uuid);
+    assert_eq!(today[0].occurrence_start_at, Some(bod + 9 * HOUR));
+    assert_eq!(today[0].atom.start_at, Some(bod + 9 * HOUR));
+}
+
+#[test]
+fn fetch_upcoming_returns_next_occurrence_per_series() {
+    let conn = setup();
+    let series = insert_series(&conn, "FREQ=WEEKLY;BYDAY=MO");
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+
+    let eod = MONDAY + DAY - 1;
+    let upcoming = repo.fetch_upcoming(eod, 50, 0).unwrap();
+
+    assert_eq!(upcoming.len(), 1);
+    assert_eq!(upcoming[0].atom.uuid, series.uuid);
+    assert_eq!(
+        upcoming[0].occurrence_start_at,
+        Some(MONDAY + 7 * DAY + 9 * HOUR)
+    );
+}
+
+#[test]
+fn finished_series_is_excluded_from_sections() {
+    let conn = setup();
+    insert_series(&conn, "FREQ=DAILY;UNTIL=20260303T235959Z");
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+
+    let later_bod = MONDAY + 3 * DAY;
+    assert!(repo
+        .fetch_today(later_bod, later_bod + DAY - 1, 50, 0)
+        .unwrap()
+        .is_empty());
+    assert!(repo.fetch_upcoming(later_bod, 50, 0).unwrap().is_empty());
+}
+
+// ---------------------------------------------------------------------------
+// Occurrence edits
+// ---------------------------------------------------------------------------
+
+#[test]
+fn delete_this_occurrence_records_exdate() {
+    let conn = setup();
+    let series = insert_series(&conn, "FREQ=DAILY;COUNT=5");
+    let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+    let recurrences = SqliteRecurrenceRepository::try_new(&conn).unwrap();
+    let svc = RecurrenceService::new(&atoms, &recurrences);
+
+    let second = MONDAY + DAY + 9 * HOUR;
+    svc.delete_occurrence(series.uuid, second, OccurrenceEditScope::ThisOccurrence)
+        .unwrap();
+
+    let rows = atoms
+        .fetch_by_time_range(MONDAY, MONDAY + 30 * DAY, 50, 0)
+        .unwrap();
+    assert_eq!(rows.len(), 4);
+    assert!(!occurrence_starts(&rows).contains(&second));
+
+    let err = svc
+        .delete_occurrence(series.uuid, second, OccurrenceEditScope::ThisOccurrence)
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        RecurrenceServiceError::OccurrenceNotFound { .. }
+    ));
+}
+
+#[test]
+fn edit_this_occurrence_detaches_override_with_tags() {
+    let conn = setup();
+    let series = insert_series(&conn, "FREQ=DAILY;COUNT=3");
+    conn.execute("INSERT INTO tags (name) VALUES ('team');", [])
+        .unwrap();
+    conn.execute(
+        "INSERT INTO atom_tags (atom_uuid, tag_id)
+         SELECT ?1, id FROM tags WHERE name = 'team';",
+        [series.uuid.to_string()],
+    )
+    .unwrap();
+
+    let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+    let recurrences = SqliteRecurrenceRepository::try_new(&conn).unwrap();
+    let svc = RecurrenceService::new(&atoms, &recurrences);
+
+    let second = MONDAY + DAY + 9 * HOUR;
+    let patch = OccurrencePatch {
+        content: Some("standup (moved)".to_string()),
+        start_at: Some(second + 2 * HOUR),
+        ..OccurrencePatch::default()
+    };
+    let detached_id = svc
+        .edit_occurrence(
+            series.uuid,
+            second,
+            OccurrenceEditScope::ThisOccurrence,
+            &patch,
+        )
+        .unwrap();
+
+    let detached = atoms.get_atom(detached_id, false).unwrap().unwrap();
+    assert_eq!(detached.content, "standup (moved)");
+    assert_eq!(detached.start_at, Some(second + 2 * HOUR));
+    assert_eq!(detached.end_at, Some(second + 3 * HOUR));
+    assert!(detached.recurrence_rule.is_none());
+
+    let exceptions = recurrences.list_exceptions(series.uuid).unwrap();
+    assert_eq!(exceptions.len(), 1);
+    assert_eq!(exceptions[0].override_id, Some(detached_id));
+
+    let svc_tasks = TaskService::new(&atoms, &conn);
+    let day = svc_tasks
+        .fetch_by_time_range(MONDAY + DAY, MONDAY + 2 * DAY, 50, 0)
+        .unwrap();
+    assert_eq!(day.len(), 1);
+    assert_eq!(day[0].atom.uuid, detached_id);
+    assert_eq!(day[0].tags, vec!["team".to_string()]);
+}
+
+#[test]
+fn edit_this_and_following_splits_series() {
+    let conn = setup();
+    let series = insert_series(&conn, "FREQ=DAILY;COUNT=5");
+    let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+    let recurrences = SqliteRecurrenceRepository::try_new(&conn).unwrap();
+    let svc = RecurrenceService::new(&atoms, &recurrences);
+
+    let third = MONDAY + 2 * DAY + 9 * HOUR;
+    let patch = OccurrencePatch {
+        content: Some("standup v2".to_string()),
+        ..OccurrencePatch::default()
+    };
+    let new_series_id = svc
+        .edit_occurrence(
+            series.uuid,
+            third,
+            OccurrenceEditScope::ThisAndFollowing,
+            &patch,
+        )
+        .unwrap();
+    assert_ne!(new_series_id, series.uuid);
+
+    let old = atoms.get_atom(series.uuid, false).unwrap().unwrap();
+    let new = atoms.get_atom(new_series_id, false).unwrap().unwrap();
+    assert_eq!(
+        RecurrenceRule::parse(old.recurrence_rule.as_deref().unwrap())
+            .unwrap()
+            .count,
+        Some(2)
+    );
+    assert_eq!(
+        RecurrenceRule::parse(new.recurrence_rule.as_deref().unwrap())
+            .unwrap()
+            .count,
+        Some(3)
+    );
+    assert_eq!(new.start_at, Some(third));
+
+    let rows = atoms
+        .fetch_by_time_range(MONDAY, MONDAY + 30 * DAY, 50, 0)
+        .unwrap();
+    assert_eq!(rows.len(), 5);
+    assert!(rows[..2].iter().all(|row| row.atom.content == "standup"));
+    assert!(rows[2..].iter().all(|row| row.atom.content == "standup v2"));
+}
+
+#[test]
+fn occurrence_edits_keep_series_priority_and_deadline() {
+    let conn = setup();
+    let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+    let recurrences = SqliteRecurrenceRepository::try_new(&conn).unwrap();
+    let svc = RecurrenceService::new(&atoms, &recurrences);
+    let mut series = Atom::new(AtomType::Task, "file report");
+    series.task_status = Some(TaskStatus::Todo);
+    series.start_at = Some(MONDAY + 9 * HOUR);
+    series.priority = Some(TaskPriority::High);
+    series.due_at = Some(MONDAY + 30 * DAY);
+    series.recurrence_rule = Some("FREQ=DAILY;COUNT=5".to_string());
+    atoms.create_atom(&series).unwrap();
+
+    let patch = OccurrencePatch {
+        content: Some("file report v2".to_string()),
+        ..OccurrencePatch::default()
+    };
+    let edited = [
+        (MONDAY + 9 * HOUR, OccurrenceEditScope::ThisAndFollowing),
+        (MONDAY + DAY + 9 * HOUR, OccurrenceEditScope::ThisOccurrence),
+        (
+            MONDAY + 2 * DAY + 9 * HOUR,
+            OccurrenceEditScope::ThisAndFollowing,
+        ),
+    ]
+    .map(|(start, scope)| {
+        svc.edit_occurrence(series.uuid, start, scope, &patch)
+            .unwrap()
+    });
+    assert_eq!(edited[0], series.uuid);
+
+    for id in edited {
+        let atom = atoms.get_atom(id, false).unwrap().unwrap();
+        assert_eq!(atom.content, "file report v2");
+        assert_eq!(atom.priority, Some(TaskPriority::High));
+        assert_eq!(atom.due_at, Some(MONDAY + 30 * DAY));
+    }
+}
+
+#[test]
+fn delete_this_and_following_truncates_open_series() {
+    let conn = setup();
+    let series = insert_series(&conn, "FREQ=DAILY");
+    let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+    let recurrences = SqliteRecurrenceRepository::try_new(&conn).unwrap();
+    let svc = RecurrenceService::new(&atoms, &recurrences);
+
+    let fourth = MONDAY + 3 * DAY + 9 * HOUR;
+    svc.delete_occurrence(series.uuid, fourth, OccurrenceEditScope::ThisAndFollowing)
+        .unwrap();
+
+    let rows = atoms
+        .fetch_by_time_range(MONDAY, MONDAY + 30 * DAY, 50, 0)
+        .unwrap();
+    assert_eq!(rows.len(), 3);
+    assert!(atoms.fetch_upcoming(fourth, 50, 0).unwrap().is_empty());
+}
+
+#[test]
+fn edit_rejects_non_occurrence_start() {
+    let conn = setup();
+    let series = insert_series(&conn, "FREQ=WEEKLY;BYDAY=MO");
+    let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+    let recurrences = SqliteRecurrenceRepository::try_new(&conn).unwrap();
+    let svc = RecurrenceService::new(&atoms, &recurrences);
+
+    let tuesday = MONDAY + DAY + 9 * HOUR;
+    let err = svc
+        .edit_occurrence(
+            series.uuid,
+            tuesday,
+            OccurrenceEditScope::ThisOccurrence,
+            &OccurrencePatch {
+                task_status: Some(TaskStatus::Done),
+                ..OccurrencePatch::default()
+            },
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        RecurrenceServiceError::OccurrenceNotFound { .. }
+    ));
+}
+
+#[test]
+fn set_rule_makes_and_unmakes_a_series() {
+    let conn = setup();
+    let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+    let recurrences = SqliteRecurrenceRepository::try_new(&conn).unwrap();
+    let svc = RecurrenceService::new(&atoms, &recurrences);
+    let mut atom = Atom::new(AtomType::Event, "review");
+    atom.start_at = Some(MONDAY + 9 * HOUR);
+    atom.end_at = Some(MONDAY + 10 * HOUR);
+    atoms.create_atom(&atom).unwrap();
+
+    let updated = svc
+        .set_rule(atom.uuid, Some("rrule:freq=weekly;byday=mo,we;count=3"))
+        .unwrap();
+    assert_eq!(
+        updated.recurrence_rule.as_deref(),
+        Some("FREQ=WEEKLY;COUNT=3;BYDAY=MO,WE")
+    );
+    let rows = atoms
+        .fetch_by_time_range(MONDAY, MONDAY + 30 * DAY, 50, 0)
+        .unwrap();
+    assert_eq!(
+        occurrence_starts(&rows),
+        vec![
+            MONDAY + 9 * HOUR,
+            MONDAY + 2 * DAY + 9 * HOUR,
+            MONDAY + 7 * DAY + 9 * HOUR
+        ]
+    );
+
+    assert!(matches!(
+        svc.set_rule(atom.uuid, Some("FREQ=SOMETIMES")),
+        Err(RecurrenceServiceError::Repo(RepoError::Validation(
+            AtomValidationError::InvalidRecurrenceRule(_)
+        )))
+    ));
+
+    svc.set_rule(atom.uuid, None).unwrap();
+    let rows = atoms
+        .fetch_by_time_range(MONDAY, MONDAY + 30 * DAY, 50, 0)
+        .unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].occurrence_start_at, None);
+
+    let unscheduled = Atom::new(AtomType::Note, "no start");
+    atoms.create_atom(&unscheduled).unwrap();
+    assert!(matches!(
+        svc.set_rule(unscheduled.uuid, Some("FREQ=DAILY")),
+        Err(RecurrenceServiceError::Repo(RepoError::Validation(
+            AtomValidationError::RecurrenceWithoutStart
+        )))
+    ));
+}
@@ -0,0 +1,276 @@
+use chrono::NaiveDate;
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    Atom, AtomRepository, AtomType, OccurrenceEditScope, OccurrencePatch, RecurrenceService,
+    ReminderService, ReminderServiceError, ReminderTrigger, SqliteAtomRepository,
+    SqliteRecurrenceRepository, SqliteReminderRepository, TaskStatus,
+};
+
+const MINUTE: i64 = 60_000;
+
+fn at(day: u32, hour: u32, minute: u32) -> i64 {
+    NaiveDate::from_ymd_opt(2026, 5, day)
+        .unwrap()
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+        .and_utc()
+        .timestamp_millis()
+}
+
+fn insert(repo: &SqliteAtomRepository<'_>, kind: AtomType, start: Option<i64>) -> Atom {
+    let mut atom = Atom::new(kind, "standup");
+    atom.start_at = start;
+    atom.end_at = start.map(|start| start + 30 * MINUTE);
+    repo.create_atom(&atom).unwrap();
+    atom
+}
+
+fn due_fires(
+    svc: &ReminderService<'_, SqliteAtomRepository<'_>, SqliteReminderRepository<'_>>,
+    now_ms: i64,
+) -> Vec<(i64, Option<i64>, bool)> {
+    svc.next_due_reminders(now_ms)
+        .unwrap()
+        .into_iter()
+        .map(|due| (due.fire_at, due.occurrence_start_at, due.snoozed))
+        .collect()
+}
+
+#[test]
+fn absolute_and_relative_reminders_fire_once_until_acked() {
+    let conn = open_db_in_memory().unwrap();
+    let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+    let reminders = SqliteReminderRepository::try_new(&conn).unwrap();
+    let svc = ReminderService::new(&atoms, &reminders);
+    let event = insert(&atoms, AtomType::Event, Some(at(4, 9, 0)));
+
+    let relative = svc
+        .add_reminder(
+            event.uuid,
+            ReminderTrigger::Start {
+                offset_ms: -15 * MINUTE,
+            },
+            at(1, 0, 0),
+        )
+        .unwrap();
+    assert_eq!(relative.next_fire_at, Some(at(4, 8, 45)));
+    let absolute = svc
+        .add_reminder(
+            event.uuid,
+            ReminderTrigger::Absolute {
+                remind_at: at(3, 20, 0),
+            },
+            at(1, 0, 0),
+        )
+        .unwrap();
+
+    assert!(svc.next_due_reminders(at(3, 19, 59)).unwrap().is_empty());
+    assert_eq!(
+        svc.next_wakeup_at(at(3, 19, 59)).unwrap(),
+        Some(at(3, 20, 0))
+    );
+    assert_eq!(
+        due_fires(&svc, at(3, 20, 0)),
+        vec![(at(3, 20, 0), None, false)]
+    );
+    svc.ack_reminder(absolute.id, at(3, 20, 0)).unwrap();
+    assert!(svc.next_due_reminders(at(4, 8, 0)).unwrap().is_empty());
+
+    // Rescheduling the atom moves the pending relative firing.
+    let mut moved = event.clone();
+    moved.start_at = Some(at(4, 10, 0));
+    moved.end_at = Some(at(4, 10, 30));
+    atoms.update_atom(&moved).unwrap();
+    assert_eq!(svc.next_wakeup_at(at(4, 8, 0)).unwrap(), Some(at(4, 9, 45)));
+    let due = svc.next_due_reminders(at(4, 9, 50)).unwrap();
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].reminder.id, relative.id);
+    assert_eq!(due[0].atom.uuid, event.uuid);
+    let acked = svc.ack_reminder(relative.id, due[0].fire_at).unwrap();
+    assert_eq!(acked.acked_through, Some(at(4, 9, 45)));
+    assert_eq!(acked.next_fire_at, None);
+    assert!(svc.next_due_reminders(at(5, 0, 0)).unwrap().is_empty());
+    assert_eq!(svc.next_wakeup_at(at(5, 0, 0)).unwrap(), None);
+
+    let listed: Vec<_> = svc
+        .list_reminders(event.uuid)
+        .unwrap()
+        .into_iter()
+        .map(|reminder| reminder.id)
+        .collect();
+    assert_eq!(listed, vec![relative.id, absolute.id]);
+    assert!(svc.remove_reminder(absolute.id).unwrap());
+    assert!(!svc.remove_reminder(absolute.id).unwrap());
+    assert_eq!(svc.list_reminders(event.uuid).unwrap().len(), 1);
+}
+
+#[test]
+fn add_reminder_validates_atom_and_anchor() {
+    let conn = open_db_in_memory().unwrap();
+    let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+    let reminders = SqliteReminderRepository::try_new(&conn).unwrap();
+    let svc = ReminderService::new(&atoms, &reminders);
+    let task = insert(&atoms, AtomType::Task, None);
+
+    assert!(matches!(
+        svc.add_reminder(
+            task.uuid,
+            ReminderTrigger::Due { offset_ms: 0 },
+            at(1, 0, 0)
+        ),
+        Err(ReminderServiceError::MissingAnchor { anchor: "due", .. })
+    ));
+    assert!(matches!(
+        svc.add_reminder(
+            uuid::Uuid::new_v4(),
+            ReminderTrigger::Absolute { remind_at: 0 },
+            at(1, 0, 0)
+        ),
+        Err(ReminderServiceError::AtomNotFound(_))
+    ));
+    assert!(matches!(
+        svc.ack_reminder(uuid::Uuid::new_v4(), at(1, 0, 0)),
+        Err(ReminderServiceError::ReminderNotFound(_))
+    ));
+
+    let mut with_due = task.clone();
+    with_due.due_at = Some(at(6, 17, 0));
+    atoms.update_atom(&with_due).unwrap();
+    let reminder = svc
+        .add_reminder(
+            task.uuid,
+            ReminderTrigger::Due {
+                offset_ms: -60 * MINUTE,
+            },
+            at(1, 0, 0),
+        )
+        .unwrap();
+    assert_eq!(reminder.next_fire_at, Some(at(6, 16, 0)));
+
+    // Finished and trashed atoms keep their reminders silent.
+    atoms
+        .update_atom_status(task.uuid, Some(TaskStatus::Done))
+        .unwrap();
+    assert!(svc.next_due_reminders(at(7, 0, 0)).unwrap().is_empty());
+    atoms.update_atom_status(task.uuid, None).unwrap();
+    assert_eq!(due_fires(&svc, at(7, 0, 0)).len(), 1);
+    atoms.soft_delete_atom(task.uuid).unwrap();
+    assert!(svc.next_due_reminders(at(7, 0, 0)).unwrap().is_empty());
+}
+
+#[test]
+fn recurring_reminders_regenerate_per_occurrence() {
+    let conn = open_db_in_memory().unwrap();
+    let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+    let reminders = SqliteReminderRepository::try_new(&conn).unwrap();
+    let recurrences = SqliteRecurrenceRepository::try_new(&conn).unwrap();
+    let svc = ReminderService::new(&atoms, &reminders);
+    let mut series = Atom::new(AtomType::Event, "standup");
+    series.start_at = Some(at(4, 9, 0));
+    series.end_at = Some(at(4, 9, 15));
+    series.recurrence_rule = Some("FREQ=DAILY;COUNT=6".to_string());
+    atoms.create_atom(&series).unwrap();
+
+    // Created after the first occurrence: that one never fires.
+    let reminder = svc
+        .add_reminder(
+            series.uuid,
+            ReminderTrigger::Start {
+                offset_ms: -10 * MINUTE,
+            },
+            at(4, 12, 0),
+        )
+        .unwrap();
+    assert_eq!(reminder.next_fire_at, Some(at(5, 8, 50)));
+    assert_eq!(
+        due_fires(&svc, at(5, 8, 55)),
+        vec![(at(5, 8, 50), Some(at(5, 9, 0)), false)]
+    );
+    svc.ack_reminder(reminder.id, at(5, 8, 50)).unwrap();
+    assert_eq!(
+        svc.next_wakeup_at(at(5, 8, 55)).unwrap(),
+        Some(at(6, 8, 50))
+    );
+
+    // Skipped occurrence does not fire; missed ones collapse into the latest.
+    RecurrenceService::new(&atoms, &recurrences)
+        .delete_occurrence(
+            series.uuid,
+            at(8, 9, 0),
+            OccurrenceEditScope::ThisOccurrence,
+        )
+        .unwrap();
+    assert_eq!(
+        due_fires(&svc, at(8, 23, 0)),
+        vec![(at(7, 8, 50), Some(at(7, 9, 0)), false)]
+    );
+    svc.ack_reminder(reminder.id, at(7, 8, 50)).unwrap();
+    assert_eq!(
+        svc.next_wakeup_at(at(8, 23, 0)).unwrap(),
+        Some(at(9, 8, 50))
+    );
+
+    // A detached occurrence keeps reminding on its own atom.
+    let patch = OccurrencePatch {
+        start_at: Some(at(9, 11, 0)),
+        ..OccurrencePatch::default()
+    };
+    let detached = RecurrenceService::new(&atoms, &recurrences)
+        .edit_occurrence(
+            series.uuid,
+            at(9, 9, 0),
+            OccurrenceEditScope::ThisOccurrence,
+            &patch,
+        )
+        .unwrap();
+    let copied = svc.list_reminders(detached).unwrap();
+    assert_eq!(copied.len(), 1);
+    assert_eq!(copied[0].next_fire_at, Some(at(9, 10, 50)));
+    let due = svc.next_due_reminders(at(9, 23, 0)).unwrap();
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].atom.uuid, detached);
+    assert_eq!(due[0].fire_at, at(9, 10, 50));
+    assert_eq!(svc.next_wakeup_at(at(9, 23, 0)).unwrap(), None);
+}
+
+#[test]
+fn snoozed_firing_is_redelivered_once() {
+    let conn = open_db_in_memory().unwrap();
+    let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+    let reminders = SqliteReminderRepository::try_new(&conn).unwrap();
+    let svc = ReminderService::new(&atoms, &reminders);
+    let event = insert(&atoms, AtomType::Event, Some(at(4, 9, 0)));
+    let reminder = svc
+        .add_reminder(
+            event.uuid,
+            ReminderTrigger::Start { offset_ms: 0 },
+            at(1, 0, 0),
+        )
+        .unwrap();
+
+    assert!(matches!(
+        svc.snooze_reminder(reminder.id, at(4, 9, 0), at(4, 9, 0)),
+        Err(ReminderServiceError::InvalidSnooze { .. })
+    ));
+    let snoozed = svc
+        .snooze_reminder(reminder.id, at(4, 9, 0), at(4, 9, 10))
+        .unwrap();
+    assert_eq!(snoozed.next_fire_at, Some(at(4, 9, 10)));
+    assert!(svc.next_due_reminders(at(4, 9, 5)).unwrap().is_empty());
+    assert_eq!(svc.next_wakeup_at(at(4, 9, 5)).unwrap(), Some(at(4, 9, 10)));
+
+    assert_eq!(
+        due_fires(&svc, at(4, 9, 10)),
+        vec![(at(4, 9, 10), None, true)]
+    );
+    // Snoozing the snoozed firing again moves it.
+    svc.snooze_reminder(reminder.id, at(4, 9, 10), at(4, 9, 30))
+        .unwrap();
+    assert!(svc.next_due_reminders(at(4, 9, 20)).unwrap().is_empty());
+    let acked = svc.ack_reminder(reminder.id, at(4, 9, 30)).unwrap();
+    assert_eq!(
+        (acked.snoozed_until, acked.acked_through),
+        (None, Some(at(4, 9, 0)))
+    );
+    assert!(svc.next_due_reminders(at(4, 12, 0)).unwrap().is_empty());
+}
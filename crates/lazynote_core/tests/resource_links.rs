@@ -0,0 +1,186 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    AtomId, AtomRepository, LaunchPolicy, LaunchRejectReason, LaunchTargetKind, NoteService,
+    ResourceLinkService, ResourceLinkSource, SqliteAtomRepository, SqliteNoteRepository,
+    SqliteResourceLinkRepository,
+};
+use rusqlite::Connection;
+use url::Url;
+
+fn create_note(conn: &mut Connection, content: &str) -> AtomId {
+    let service = NoteService::new(SqliteNoteRepository::try_new(conn).unwrap());
+    service.create_note(content).unwrap().atom_id
+}
+
+fn update_note(conn: &mut Connection, atom_id: AtomId, content: &str) {
+    let service = NoteService::new(SqliteNoteRepository::try_new(conn).unwrap());
+    service.update_note(atom_id, content).unwrap();
+}
+
+fn links(conn: &Connection) -> ResourceLinkService<SqliteResourceLinkRepository<'_>> {
+    ResourceLinkService::new(SqliteResourceLinkRepository::try_new(conn).unwrap())
+}
+
+// ---------------------------------------------------------------------------
+// Index maintenance
+// ---------------------------------------------------------------------------
+
+#[test]
+fn create_note_indexes_markdown_and_bare_links() {
+    let mut conn = open_db_in_memory().unwrap();
+    let atom_id = create_note(
+        &mut conn,
+        "# Setup\n[Repo](https://github.com/example/repo) and https://ci.example/builds",
+    );
+
+    let listed = links(&conn).list_links(atom_id).unwrap();
+    assert_eq!(listed.len(), 2);
+    assert_eq!(listed[0].target, "https://github.com/example/repo");
+    assert_eq!(listed[0].label.as_deref(), Some("Repo"));
+    assert_eq!(listed[0].source, ResourceLinkSource::Markdown);
+    assert_eq!(listed[1].scheme, "https");
+    assert_eq!(listed[1].source, ResourceLinkSource::Bare);
+}
+
+#[test]
+fn update_note_replaces_links_and_keeps_ids_of_surviving_targets() {
+    let mut conn = open_db_in_memory().unwrap();
+    let atom_id = create_note(&mut conn, "https://keep.example https://drop.example");
+    let kept_id = links(&conn).list_links(atom_id).unwrap()[0].id;
+
+    update_note(
+        &mut conn,
+        atom_id,
+        "[new](https://new.example) then https://keep.example",
+    );
+
+    let listed = links(&conn).list_links(atom_id).unwrap();
+    let targets: Vec<&str> = listed.iter().map(|link| link.target.as_str()).collect();
+    assert_eq!(targets, vec!["https://new.example", "https://keep.example"]);
+    assert_eq!(listed[1].id, kept_id);
+    assert_eq!(listed[1].position, 1);
+}
+
+#[test]
+fn search_matches_targets_and_labels_and_hides_deleted_atoms() {
+    let mut conn = open_db_in_memory().unwrap();
+    let first = create_note(&mut conn, "[Quarter Report](https://docs.example/q4)");
+    let second = create_note(&mut conn, "https://report.example/weekly");
+    let deleted = create_note(&mut conn, "https://report.example/old");
+    SqliteAtomRepository::try_new(&conn)
+        .unwrap()
+        .soft_delete_atom(deleted)
+        .unwrap();
+
+    let mut owners: Vec<AtomId> = links(&conn)
+        .search_links("REPORT", None)
+        .unwrap()
+        .into_iter()
+        .map(|link| link.atom_id)
+        .collect();
+    owners.sort();
+    let mut expected = vec![first, second];
+    expected.sort();
+    assert_eq!(owners, expected);
+
+    assert!(links(&conn).search_links("   ", None).unwrap().is_empty());
+    assert!(links(&conn).search_links("100%", None).unwrap().is_empty());
+}
+
+// ---------------------------------------------------------------------------
+// Launch planning
+// ---------------------------------------------------------------------------
+
+#[test]
+fn launch_plan_vets_schemes_and_local_paths() {
+    let dir = tempfile::tempdir().unwrap();
+    let file_path = dir.path().join("brief.txt");
+    std::fs::write(&file_path, "brief").unwrap();
+    let folder_url = Url::from_directory_path(dir.path()).unwrap();
+    let file_url = Url::from_file_path(&file_path).unwrap();
+    let missing_url = Url::from_file_path(dir.path().join("missing.txt")).unwrap();
+
+    let mut conn = open_db_in_memory().unwrap();
+    let atom_id = create_note(
+        &mut conn,
+        &format!(
+            "https://site.example\n[cmd](cmd://calc)\n[file]({file_url})\n\
+             [missing]({missing_url})\n[remote](file://server/share/x)\n[dir]({folder_url})"
+        ),
+    );
+
+    let plan = links(&conn)
+        .plan_atom_launch(atom_id, &LaunchPolicy::default())
+        .unwrap();
+
+    let kinds: Vec<LaunchTargetKind> = plan.items.iter().map(|item| item.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            LaunchTargetKind::Folder,
+            LaunchTargetKind::File,
+            LaunchTargetKind::Url
+        ]
+    );
+    assert_eq!(
+        plan.items[1].local_path.as_deref(),
+        Some(file_path.as_path())
+    );
+    assert_eq!(plan.items[2].target, "https://site.example");
+
+    let reasons: Vec<LaunchRejectReason> = plan.rejected.iter().map(|item| item.reason).collect();
+    assert_eq!(
+        reasons,
+        vec![
+            LaunchRejectReason::SchemeNotAllowed,
+            LaunchRejectReason::PathNotFound,
+            LaunchRejectReason::NotLocalPath
+        ]
+    );
+    assert_eq!(plan.capped_count, 0);
+}
+
+#[test]
+fn launch_plan_applies_safety_cap_and_custom_whitelist() {
+    let mut conn = open_db_in_memory().unwrap();
+    let content: String = (0..12)
+        .map(|index| format!("https://site{index}.example\n"))
+        .chain(std::iter::once("[code](vscode://file/c/work)".to_string()))
+        .collect();
+    let atom_id = create_note(&mut conn, &content);
+    let service = links(&conn);
+
+    let default_plan = service
+        .plan_atom_launch(atom_id, &LaunchPolicy::default())
+        .unwrap();
+    assert_eq!(default_plan.items.len(), 10);
+    assert_eq!(default_plan.capped_count, 2);
+    assert_eq!(default_plan.rejected.len(), 1);
+
+    let policy = LaunchPolicy {
+        allowed_schemes: vec!["VSCode".to_string()],
+        max_items: 500,
+    };
+    let custom_plan = service.plan_atom_launch(atom_id, &policy).unwrap();
+    assert_eq!(custom_plan.applied_max_items, 50);
+    assert_eq!(custom_plan.items.len(), 1);
+    assert_eq!(custom_plan.items[0].target, "vscode://file/c/work");
+    assert_eq!(custom_plan.rejected.len(), 12);
+}
+
+#[test]
+fn launch_plan_for_selected_ids_keeps_selection_order() {
+    let mut conn = open_db_in_memory().unwrap();
+    let atom_id = create_note(&mut conn, "https://a.example https://b.example");
+    let service = links(&conn);
+    let listed = service.list_links(atom_id).unwrap();
+
+    let plan = service
+        .plan_links_launch(
+            &[listed[1].id, 9_999, listed[0].id],
+            &LaunchPolicy::default(),
+        )
+        .unwrap();
+    let targets: Vec<&str> = plan.items.iter().map(|item| item.target.as_str()).collect();
+    assert_eq!(targets, vec!["https://b.example", "https://a.example"]);
+}
@@ -0,0 +1,213 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    Atom, AtomRepository, AtomType, DiffLineKind, NoteService, RevisionService,
+    RevisionServiceError, SqliteAtomRepository, SqliteNoteRepository, SqliteRevisionRepository,
+    TaskStatus, REVISION_COALESCE_WINDOW_MS,
+};
+use rusqlite::Connection;
+use uuid::Uuid;
+
+fn create_note(conn: &mut Connection, content: &str) -> Uuid {
+    let repo = SqliteNoteRepository::try_new(conn).unwrap();
+    NoteService::new(repo).create_note(content).unwrap().atom_id
+}
+
+fn update_note(conn: &mut Connection, atom_id: Uuid, content: &str) {
+    let repo = SqliteNoteRepository::try_new(conn).unwrap();
+    NoteService::new(repo)
+        .update_note(atom_id, content)
+        .unwrap();
+}
+
+fn note_content(conn: &mut Connection, atom_id: Uuid) -> String {
+    let repo = SqliteNoteRepository::try_new(conn).unwrap();
+    NoteService::new(repo)
+        .get_note(atom_id)
+        .unwrap()
+        .unwrap()
+        .content
+}
+
+/// Moves every captured revision out of the coalescing window.
+fn age_revisions(conn: &Connection) {
+    conn.execute(
+        "UPDATE atom_revisions SET captured_at = captured_at - ?1;",
+        [REVISION_COALESCE_WINDOW_MS + 1],
+    )
+    .unwrap();
+}
+
+fn revision_contents(conn: &Connection, atom_id: Uuid) -> Vec<String> {
+    let service = RevisionService::new(SqliteRevisionRepository::try_new(conn).unwrap());
+    service
+        .list_revisions(atom_id, None, 0)
+        .unwrap()
+        .items
+        .into_iter()
+        .map(|revision| revision.content)
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Capture and coalescing
+// ---------------------------------------------------------------------------
+
+#[test]
+fn note_update_captures_prior_content() {
+    let mut conn = open_db_in_memory().unwrap();
+    let atom_id = create_note(&mut conn, "first draft");
+
+    update_note(&mut conn, atom_id, "second draft");
+
+    assert_eq!(revision_contents(&conn, atom_id), vec!["first draft"]);
+}
+
+#[test]
+fn rapid_autosaves_coalesce_into_one_revision() {
+    let mut conn = open_db_in_memory().unwrap();
+    let atom_id = create_note(&mut conn, "v0");
+
+    update_note(&mut conn, atom_id, "v1");
+    update_note(&mut conn, atom_id, "v2");
+    update_note(&mut conn, atom_id, "v3");
+    assert_eq!(revision_contents(&conn, atom_id), vec!["v0"]);
+
+    age_revisions(&conn);
+    update_note(&mut conn, atom_id, "v4");
+    assert_eq!(revision_contents(&conn, atom_id), vec!["v3", "v0"]);
+}
+
+#[test]
+fn unchanged_state_is_not_captured_twice() {
+    let mut conn = open_db_in_memory().unwrap();
+    let atom_id = create_note(&mut conn, "same");
+
+    update_note(&mut conn, atom_id, "same");
+    age_revisions(&conn);
+    update_note(&mut conn, atom_id, "same");
+
+    assert_eq!(revision_contents(&conn, atom_id), vec!["same"]);
+}
+
+#[test]
+fn task_status_update_captures_metadata() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let mut atom = Atom::new(AtomType::Task, "ship it");
+    atom.task_status = Some(TaskStatus::Todo);
+    repo.create_atom(&atom).unwrap();
+
+    repo.update_atom_status(atom.uuid, Some(TaskStatus::Done))
+        .unwrap();
+
+    let service = RevisionService::new(SqliteRevisionRepository::try_new(&conn).unwrap());
+    let revisions = service.list_revisions(atom.uuid, None, 0).unwrap().items;
+    assert_eq!(revisions.len(), 1);
+    assert_eq!(revisions[0].task_status, Some(TaskStatus::Todo));
+}
+
+// ---------------------------------------------------------------------------
+// List / diff / restore
+// ---------------------------------------------------------------------------
+
+#[test]
+fn list_revisions_paginates_newest_first() {
+    let mut conn = open_db_in_memory().unwrap();
+    let atom_id = create_note(&mut conn, "r0");
+    for next in ["r1", "r2", "r3"] {
+        update_note(&mut conn, atom_id, next);
+        age_revisions(&conn);
+    }
+
+    let service = RevisionService::new(SqliteRevisionRepository::try_new(&conn).unwrap());
+    let page = service.list_revisions(atom_id, Some(2), 1).unwrap();
+    assert_eq!(page.applied_limit, 2);
+    let contents: Vec<&str> = page.items.iter().map(|r| r.content.as_str()).collect();
+    assert_eq!(contents, vec!["r1", "r0"]);
+    assert!(page.items[0].revision_id > page.items[1].revision_id);
+}
+
+#[test]
+fn list_revisions_for_unknown_atom_is_not_found() {
+    let conn = open_db_in_memory().unwrap();
+    let service = RevisionService::new(SqliteRevisionRepository::try_new(&conn).unwrap());
+    let missing = Uuid::new_v4();
+    assert!(matches!(
+        service.list_revisions(missing, None, 0),
+        Err(RevisionServiceError::AtomNotFound(id)) if id == missing
+    ));
+}
+
+#[test]
+fn diff_between_revision_and_current_content_is_line_level() {
+    let mut conn = open_db_in_memory().unwrap();
+    let atom_id = create_note(&mut conn, "# Plan\nkeep\ndrop me\n");
+    update_note(&mut conn, atom_id, "# Plan\nkeep\nnew line\n");
+
+    let service = RevisionService::new(SqliteRevisionRepository::try_new(&conn).unwrap());
+    let revision_id = service.list_revisions(atom_id, None, 0).unwrap().items[0].revision_id;
+    let diff = service.diff_revisions(atom_id, revision_id, None).unwrap();
+
+    let changed: Vec<(DiffLineKind, &str)> = diff
+        .lines
+        .iter()
+        .filter(|line| line.kind != DiffLineKind::Unchanged)
+        .map(|line| (line.kind, line.text.as_str()))
+        .collect();
+    assert_eq!(
+        changed,
+        vec![
+            (DiffLineKind::Removed, "drop me"),
+            (DiffLineKind::Added, "new line"),
+        ]
+    );
+}
+
+#[test]
+fn diff_rejects_revision_of_another_atom() {
+    let mut conn = open_db_in_memory().unwrap();
+    let first = create_note(&mut conn, "a");
+    update_note(&mut conn, first, "b");
+    let second = create_note(&mut conn, "c");
+
+    let service = RevisionService::new(SqliteRevisionRepository::try_new(&conn).unwrap());
+    let foreign = service.list_revisions(first, None, 0).unwrap().items[0].revision_id;
+    assert!(matches!(
+        service.diff_revisions(second, foreign, None),
+        Err(RevisionServiceError::RevisionNotFound { .. })
+    ));
+}
+
+#[test]
+fn restore_revision_replaces_content_and_keeps_replaced_state() {
+    let mut conn = open_db_in_memory().unwrap();
+    let atom_id = create_note(&mut conn, "good paragraph");
+    update_note(&mut conn, atom_id, "oops");
+
+    {
+        let service = RevisionService::new(SqliteRevisionRepository::try_new(&conn).unwrap());
+        let revision_id = service.list_revisions(atom_id, None, 0).unwrap().items[0].revision_id;
+        service.restore_revision(atom_id, revision_id).unwrap();
+    }
+
+    assert_eq!(note_content(&mut conn, atom_id), "good paragraph");
+    assert_eq!(
+        revision_contents(&conn, atom_id),
+        vec!["oops", "good paragraph"]
+    );
+}
+
+#[test]
+fn restore_unknown_revision_is_rejected() {
+    let mut conn = open_db_in_memory().unwrap();
+    let atom_id = create_note(&mut conn, "body");
+
+    let service = RevisionService::new(SqliteRevisionRepository::try_new(&conn).unwrap());
+    assert!(matches!(
+        service.restore_revision(atom_id, 999),
+        Err(RevisionServiceError::RevisionNotFound {
+            revision_id: 999,
+            ..
+        })
+    ));
+}
@@ -1,7 +1,9 @@
 use lazynote_core::db::migrations::{apply_migrations, latest_version};
 use lazynote_core::db::open_db_in_memory;
 use lazynote_core::{
-    search_all, Atom, AtomRepository, AtomType, SearchError, SearchQuery, SqliteAtomRepository,
+    search_all, search_page, select_search_strategy, Atom, AtomRepository, AtomType, NoteService,
+    QueryParseErrorKind, SearchBoost, SearchError, SearchQuery, SearchStrategy,
+    SqliteAtomRepository, SqliteNoteRepository, SqliteTreeRepository, TaskStatus, TreeService,
 };
 use rusqlite::Connection;
 use std::collections::HashSet;
@@ -148,7 +150,7 @@ fn migration_bootstrap_indexes_existing_v3_atoms() {
     .unwrap();
     conn.execute_batch(
         "INSERT INTO atoms (uuid, type, content, is_deleted)
-         VALUES ('11111111-2222-4333-8444-555555555555', 'note', 'legacy indexed term', 0);",
+         VALUES ('11111111-2222-4333-8444-555555555555', 'note', 'legacy indexed term 历史笔记', 0);",
     )
     .unwrap();
     conn.execute_batch("PRAGMA user_version = 3;").unwrap();
@@ -161,4 +163,353 @@ fn migration_bootstrap_indexes_existing_v3_atoms() {
 
     let hits = search_all(&conn, &SearchQuery::new("legacy")).unwrap();
     assert_eq!(hits.len(), 1);
+    let cjk_hits = search_all(&conn, &SearchQuery::new("历史")).unwrap();
+    assert_eq!(cjk_hits.len(), 1);
+}
+
+#[test]
+fn strategy_selection_depends_on_script_and_term_length() {
+    assert_eq!(
+        select_search_strategy(&SearchQuery::new("rust search")),
+        SearchStrategy::Token
+    );
+    assert_eq!(
+        select_search_strategy(&SearchQuery::new("项目计划")),
+        SearchStrategy::Trigram
+    );
+    assert_eq!(
+        select_search_strategy(&SearchQuery::new("项目 roadmap")),
+        SearchStrategy::Substring
+    );
+
+    let mut raw = SearchQuery::new("项目*");
+    raw.raw_fts_syntax = true;
+    assert_eq!(select_search_strategy(&raw), SearchStrategy::Token);
+}
+
+#[test]
+fn two_character_chinese_terms_hit_by_substring() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let plan = Atom::new(AtomType::Note, "第四季度项目计划需要评审");
+    let other = Atom::new(AtomType::Note, "周末去爬山");
+    repo.create_atom(&plan).unwrap();
+    repo.create_atom(&other).unwrap();
+
+    let hits = search_all(&conn, &SearchQuery::new("项目")).unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].atom_id, plan.uuid);
+    let range = hits[0].highlights[0];
+    assert_eq!(&hits[0].snippet[range.start..range.end], "项目");
+    assert_eq!(range.char_end - range.char_start, 2);
+
+    let single = search_all(&conn, &SearchQuery::new("山")).unwrap();
+    assert_eq!(single.len(), 1);
+    assert_eq!(single[0].atom_id, other.uuid);
+}
+
+#[test]
+fn longer_chinese_and_mixed_queries_use_trigram_index() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let roadmap = Atom::new(AtomType::Task, "整理 Roadmap 项目计划文档");
+    let unrelated = Atom::new(AtomType::Note, "项目复盘");
+    repo.create_atom(&roadmap).unwrap();
+    repo.create_atom(&unrelated).unwrap();
+
+    let hits = search_all(&conn, &SearchQuery::new("项目计划")).unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].atom_id, roadmap.uuid);
+
+    let mixed = search_all(&conn, &SearchQuery::new("roadmap 项目")).unwrap();
+    assert_eq!(mixed.len(), 1);
+    assert_eq!(mixed[0].atom_id, roadmap.uuid);
+
+    let mut typed = SearchQuery::new("项目");
+    typed.kind = Some(AtomType::Note);
+    let typed_hits = search_all(&conn, &typed).unwrap();
+    assert_eq!(typed_hits.len(), 1);
+    assert_eq!(typed_hits[0].atom_id, unrelated.uuid);
+}
+
+#[test]
+fn latin_queries_keep_token_semantics() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    repo.create_atom(&Atom::new(AtomType::Note, "concatenate strings"))
+        .unwrap();
+
+    assert!(search_all(&conn, &SearchQuery::new("cat"))
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn cjk_search_excludes_soft_deleted_and_updated_content() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let mut atom = Atom::new(AtomType::Note, "旧的会议纪要");
+    repo.create_atom(&atom).unwrap();
+    atom.content = "新的会议纪要".to_string();
+    repo.update_atom(&atom).unwrap();
+
+    assert!(search_all(&conn, &SearchQuery::new("旧的"))
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        search_all(&conn, &SearchQuery::new("新的")).unwrap().len(),
+        1
+    );
+
+    repo.soft_delete_atom(atom.uuid).unwrap();
+    assert!(search_all(&conn, &SearchQuery::new("会议纪要"))
+        .unwrap()
+        .is_empty());
+}
+
+fn note_with_tags(conn: &mut Connection, content: &str, tags: &[&str]) -> lazynote_core::AtomId {
+    let mut service = NoteService::new(SqliteNoteRepository::try_new(conn).unwrap());
+    let atom_id = service.create_note(content).unwrap().atom_id;
+    service
+        .set_note_tags(atom_id, tags.iter().map(|tag| tag.to_string()).collect())
+        .unwrap();
+    atom_id
+}
+
+#[test]
+fn grammar_filters_by_tag_type_status_and_time() {
+    let mut conn = open_db_in_memory().unwrap();
+    let tagged = note_with_tags(&mut conn, "quarterly review draft", &["Work"]);
+    note_with_tags(&mut conn, "quarterly review personal", &["home"]);
+
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let mut open_task = Atom::new(AtomType::Task, "quarterly review prep");
+    open_task.task_status = Some(TaskStatus::Todo);
+    open_task.end_at = Some(1_793_000_000_000); // 2026-10-27
+    let mut done_task = Atom::new(AtomType::Task, "quarterly review booking");
+    done_task.task_status = Some(TaskStatus::Done);
+    done_task.end_at = Some(1_795_000_000_000); // 2026-11-19
+    repo.create_atom(&open_task).unwrap();
+    repo.create_atom(&done_task).unwrap();
+
+    let ids = |text: &str| -> HashSet<_> {
+        search_all(&conn, &SearchQuery::new(text))
+            .unwrap()
+            .into_iter()
+            .map(|hit| hit.atom_id)
+            .collect()
+    };
+
+    assert_eq!(ids("quarterly tag:work"), HashSet::from([tagged]));
+    assert_eq!(
+        ids("type:task status:todo"),
+        HashSet::from([open_task.uuid])
+    );
+    assert_eq!(ids("quarterly -status:done").len(), 3);
+    assert_eq!(ids("before:2026-11-01"), HashSet::from([open_task.uuid]));
+    assert_eq!(ids("after:2026-11-01"), HashSet::from([done_task.uuid]));
+    assert_eq!(
+        ids("TYPE:task -before:2026-11-01"),
+        HashSet::from([done_task.uuid])
+    );
+}
+
+#[test]
+fn grammar_supports_phrases_exclusions_and_folder_scope() {
+    let mut conn = open_db_in_memory().unwrap();
+    let scoped = note_with_tags(&mut conn, "launch exact phrase notes", &[]);
+    let nested = note_with_tags(&mut conn, "launch checklist archived", &[]);
+    let outside = note_with_tags(&mut conn, "launch phrase exact order", &[]);
+
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let projects = tree.create_folder(None, "Projects").unwrap();
+    let q4 = tree.create_folder(Some(projects.node_uuid), "Q4").unwrap();
+    let sub = tree.create_folder(Some(q4.node_uuid), "Design").unwrap();
+    tree.create_note_ref(Some(q4.node_uuid), scoped, None)
+        .unwrap();
+    tree.create_note_ref(Some(sub.node_uuid), nested, None)
+        .unwrap();
+    tree.create_note_ref(Some(projects.node_uuid), outside, None)
+        .unwrap();
+
+    let ids = |text: &str| -> Vec<_> {
+        search_all(&conn, &SearchQuery::new(text))
+            .unwrap()
+            .into_iter()
+            .map(|hit| hit.atom_id)
+            .collect()
+    };
+
+    assert_eq!(ids("\"exact phrase\""), vec![scoped]);
+    assert_eq!(
+        ids("launch -archived -phrase"),
+        Vec::<lazynote_core::AtomId>::new()
+    );
+    assert_eq!(ids("launch -archived in:projects/q4"), vec![scoped]);
+    let mut in_q4 = ids("in:\"Projects/Q4\"");
+    in_q4.sort();
+    let mut expected = vec![scoped, nested];
+    expected.sort();
+    assert_eq!(in_q4, expected);
+    assert_eq!(ids("launch -in:Projects/Q4"), vec![outside]);
+}
+
+#[test]
+fn grammar_errors_report_char_offsets_and_filters_keep_cjk_strategy() {
+    let conn = open_db_in_memory().unwrap();
+    let err = search_all(&conn, &SearchQuery::new("会议 status:later")).unwrap_err();
+    match err {
+        SearchError::InvalidSyntax(parse_err) => {
+            assert!(matches!(
+                parse_err.kind,
+                QueryParseErrorKind::InvalidValue { .. }
+            ));
+            assert_eq!((parse_err.start, parse_err.end), (10, 15));
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+
+    assert_eq!(
+        select_search_strategy(&SearchQuery::new("项目计划 tag:工作 -草稿")),
+        SearchStrategy::Trigram
+    );
+    assert_eq!(
+        select_search_strategy(&SearchQuery::new("rust tag:工作")),
+        SearchStrategy::Token
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Pagination, ranking and highlights
+// ---------------------------------------------------------------------------
+
+#[test]
+fn cursor_pagination_walks_all_hits_without_duplicates() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let mut expected = HashSet::new();
+    for index in 0..7 {
+        let atom = Atom::new(AtomType::Note, format!("paging sample {index}"));
+        repo.create_atom(&atom).unwrap();
+        expected.insert(atom.uuid);
+    }
+    // Equal timestamps force the uuid tie-break inside the keyset.
+    conn.execute("UPDATE atoms SET updated_at = 1000", [])
+        .unwrap();
+
+    let mut query = SearchQuery::new("paging");
+    query.limit = 3;
+    let mut seen = Vec::new();
+    let mut page_sizes = Vec::new();
+    loop {
+        let page = search_page(&conn, &query).unwrap();
+        page_sizes.push(page.hits.len());
+        seen.extend(page.hits.into_iter().map(|hit| hit.atom_id));
+        match page.next_cursor {
+            Some(cursor) => query.cursor = Some(cursor),
+            None => break,
+        }
+    }
+
+    assert_eq!(page_sizes, vec![3, 3, 1]);
+    assert_eq!(seen.len(), 7);
+    assert_eq!(seen.into_iter().collect::<HashSet<_>>(), expected);
+
+    query.cursor = Some("not-a-cursor".to_string());
+    assert!(matches!(
+        search_page(&conn, &query),
+        Err(SearchError::InvalidCursor(_))
+    ));
+}
+
+#[test]
+fn hits_expose_bm25_score_and_plain_highlight_ranges() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let dense = Atom::new(AtomType::Note, "alpha alpha alpha [tag]");
+    let sparse = Atom::new(
+        AtomType::Note,
+        "alpha appears once in this much longer note about other things",
+    );
+    repo.create_atom(&sparse).unwrap();
+    repo.create_atom(&dense).unwrap();
+
+    let hits = search_all(&conn, &SearchQuery::new("alpha")).unwrap();
+    assert_eq!(hits[0].atom_id, dense.uuid);
+    let bm25 = hits[0].bm25.expect("MATCH hits carry bm25");
+    assert!(bm25 < 0.0);
+    assert_eq!(hits[0].score, -bm25);
+    assert!(hits[0].score > hits[1].score);
+
+    // Literal brackets in content are not confused with highlight markers.
+    assert!(hits[0].snippet.contains("[tag]"));
+    assert_eq!(hits[0].highlights.len(), 3);
+    for range in &hits[0].highlights {
+        assert_eq!(&hits[0].snippet[range.start..range.end], "alpha");
+    }
+
+    let cjk = Atom::new(AtomType::Note, "第四季度项目计划");
+    repo.create_atom(&cjk).unwrap();
+    let cjk_hits = search_all(&conn, &SearchQuery::new("项目计划")).unwrap();
+    let range = cjk_hits[0].highlights[0];
+    assert_eq!(&cjk_hits[0].snippet[range.start..range.end], "项目计划");
+    assert_eq!((range.char_start, range.char_end), (4, 8));
+}
+
+#[test]
+fn type_and_recency_boosts_reorder_hits() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let old_note = Atom::new(AtomType::Note, "boost target");
+    let new_task = Atom::new(AtomType::Task, "boost target");
+    repo.create_atom(&old_note).unwrap();
+    repo.create_atom(&new_task).unwrap();
+    let day_ms: i64 = 86_400_000;
+    let now = lazynote_core::now_epoch_ms();
+    conn.execute(
+        "UPDATE atoms SET updated_at = ?1 WHERE uuid = ?2",
+        rusqlite::params![now - 400 * day_ms, old_note.uuid.to_string()],
+    )
+    .unwrap();
+    conn.execute(
+        "UPDATE atoms SET updated_at = ?1 WHERE uuid = ?2",
+        rusqlite::params![now, new_task.uuid.to_string()],
+    )
+    .unwrap();
+
+    let order = |boost: SearchBoost| -> Vec<_> {
+        let mut query = SearchQuery::new("boost");
+        query.boost = boost;
+        search_all(&conn, &query)
+            .unwrap()
+            .into_iter()
+            .map(|hit| hit.atom_id)
+            .collect()
+    };
+
+    assert_eq!(
+        order(SearchBoost {
+            note_weight: 3.0,
+            ..SearchBoost::default()
+        }),
+        vec![old_note.uuid, new_task.uuid]
+    );
+    assert_eq!(
+        order(SearchBoost {
+            note_weight: 1.5,
+            recency_weight: 5.0,
+            ..SearchBoost::default()
+        }),
+        vec![new_task.uuid, old_note.uuid]
+    );
+    // Non-finite knobs fall back to neutral values instead of failing.
+    assert_eq!(
+        order(SearchBoost {
+            note_weight: f64::NAN,
+            recency_weight: f64::INFINITY,
+            ..SearchBoost::default()
+        })
+        .len(),
+        2
+    );
 }
@@ -0,0 +1,140 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    Atom, AtomId, AtomRepository, AtomType, SqliteAtomRepository, SubtaskProgress, TaskService,
+    TaskServiceError, TaskStatus,
+};
+
+fn create_task(repo: &SqliteAtomRepository<'_>, content: &str) -> AtomId {
+    let mut atom = Atom::new(AtomType::Task, content);
+    atom.task_status = Some(TaskStatus::Todo);
+    repo.create_atom(&atom).unwrap()
+}
+
+fn child_ids(svc: &TaskService<'_, SqliteAtomRepository<'_>>, parent: AtomId) -> Vec<AtomId> {
+    svc.list_subtasks(parent)
+        .unwrap()
+        .into_iter()
+        .map(|item| item.atom.uuid)
+        .collect()
+}
+
+#[test]
+fn subtasks_attach_reorder_and_reject_cycles() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let svc = TaskService::new(&repo, &conn);
+    let root = create_task(&repo, "root");
+    let a = create_task(&repo, "a");
+    let b = create_task(&repo, "b");
+    let grandchild = create_task(&repo, "grandchild");
+    let note = repo
+        .create_atom(&Atom::new(AtomType::Note, "note"))
+        .unwrap();
+
+    svc.set_parent(a, Some(root), None).unwrap();
+    svc.set_parent(b, Some(root), Some(0)).unwrap();
+    svc.set_parent(grandchild, Some(a), None).unwrap();
+    assert_eq!(child_ids(&svc, root), vec![b, a]);
+    assert_eq!(svc.parent_task(grandchild).unwrap(), Some(a));
+
+    svc.reorder_subtasks(root, &[a, b]).unwrap();
+    assert_eq!(child_ids(&svc, root), vec![a, b]);
+    assert!(matches!(
+        svc.reorder_subtasks(root, &[a]),
+        Err(TaskServiceError::InvalidSubtaskOrder(id)) if id == root
+    ));
+
+    assert!(matches!(
+        svc.set_parent(root, Some(grandchild), None),
+        Err(TaskServiceError::SubtaskCycle { .. })
+    ));
+    assert!(matches!(
+        svc.set_parent(a, Some(a), None),
+        Err(TaskServiceError::SubtaskCycle { .. })
+    ));
+    assert!(matches!(
+        svc.set_parent(a, Some(note), None),
+        Err(TaskServiceError::NotATask(id)) if id == note
+    ));
+
+    // Deleted subtasks are hidden; detaching makes a task top-level.
+    repo.soft_delete_atom(b).unwrap();
+    assert_eq!(child_ids(&svc, root), vec![a]);
+    svc.set_parent(a, None, None).unwrap();
+    assert!(child_ids(&svc, root).is_empty());
+    assert_eq!(svc.parent_task(a).unwrap(), None);
+}
+
+#[test]
+fn finishing_last_subtask_rolls_completion_up() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let svc = TaskService::new(&repo, &conn);
+    let project = create_task(&repo, "project");
+    let phase = create_task(&repo, "phase");
+    let step_one = create_task(&repo, "step one");
+    let step_two = create_task(&repo, "step two");
+    let dropped = create_task(&repo, "dropped");
+    svc.set_parent(phase, Some(project), None).unwrap();
+    for step in [step_one, step_two, dropped] {
+        svc.set_parent(step, Some(phase), None).unwrap();
+    }
+    let status = |id| repo.get_atom(id, false).unwrap().unwrap().task_status;
+
+    svc.update_status(step_one, Some(TaskStatus::Done)).unwrap();
+    svc.update_status(dropped, Some(TaskStatus::Cancelled))
+        .unwrap();
+    assert_eq!(status(phase), Some(TaskStatus::Todo));
+    assert_eq!(
+        svc.subtask_progress(phase).unwrap(),
+        SubtaskProgress { done: 1, total: 2 }
+    );
+
+    svc.update_status(step_two, Some(TaskStatus::Done)).unwrap();
+    assert_eq!(status(phase), Some(TaskStatus::Done));
+    assert_eq!(status(project), Some(TaskStatus::Done));
+
+    // Reopening a subtask leaves the parent alone.
+    svc.update_status(step_two, Some(TaskStatus::Todo)).unwrap();
+    assert_eq!(status(phase), Some(TaskStatus::Done));
+}
+
+#[test]
+fn checklist_items_promote_into_subtasks() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let svc = TaskService::new(&repo, &conn);
+    let trip = create_task(
+        &repo,
+        "Plan trip\n\n- [ ] Book hotel\n- [x] Buy **tickets**\n  - [ ] seat upgrade\n- pack light\n",
+    );
+    let existing = create_task(&repo, "Renew passport");
+    svc.set_parent(existing, Some(trip), None).unwrap();
+
+    let created = svc.promote_checklist(trip).unwrap();
+    assert_eq!(created.len(), 2);
+    let children = svc.list_subtasks(trip).unwrap();
+    let summary: Vec<(&str, Option<TaskStatus>)> = children
+        .iter()
+        .map(|item| (item.atom.content.as_str(), item.atom.task_status))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("Renew passport", Some(TaskStatus::Todo)),
+            ("Book hotel", Some(TaskStatus::Todo)),
+            (
+                "Buy **tickets**\n- [ ] seat upgrade",
+                Some(TaskStatus::Done)
+            ),
+        ]
+    );
+    assert_eq!(
+        children[2].atom.preview_text.as_deref(),
+        Some("Buy tickets seat upgrade")
+    );
+
+    let parent = repo.get_atom(trip, false).unwrap().unwrap();
+    assert_eq!(parent.content, "Plan trip\n\n- pack light\n");
+    assert!(svc.promote_checklist(trip).unwrap().is_empty());
+}
@@ -0,0 +1,275 @@
+use chrono::NaiveDate;
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    Atom, AtomRepository, AtomType, LocalCalendar, SqliteAtomRepository, SqliteTimeEntryRepository,
+    SqliteTreeRepository, StatusUpdateOptions, TaskService, TaskStatus, TimeEntryPatch,
+    TimeTrackingService, TimeTrackingServiceError, TreeService,
+};
+use rusqlite::Connection;
+use uuid::Uuid;
+
+const MINUTE: i64 = 60_000;
+
+fn at(day: u32, hour: u32, minute: u32) -> i64 {
+    NaiveDate::from_ymd_opt(2026, 5, day)
+        .unwrap()
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+        .and_utc()
+        .timestamp_millis()
+}
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 5, day).unwrap()
+}
+
+fn insert(conn: &Connection, kind: AtomType, content: &str) -> Uuid {
+    let repo = SqliteAtomRepository::try_new(conn).unwrap();
+    repo.create_atom(&Atom::new(kind, content)).unwrap()
+}
+
+#[test]
+fn device_runs_one_timer_at_a_time() {
+    let conn = open_db_in_memory().unwrap();
+    let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+    let entries = SqliteTimeEntryRepository::try_new(&conn).unwrap();
+    let svc = TimeTrackingService::new(&atoms, &entries, LocalCalendar::utc());
+    let write = insert(&conn, AtomType::Task, "write spec");
+    let review = insert(&conn, AtomType::Task, "review spec");
+
+    let first = svc.start_timer(write, at(4, 9, 0)).unwrap();
+    assert!(first.is_running());
+    // Starting the running task again keeps the same timer.
+    assert_eq!(svc.start_timer(write, at(4, 9, 30)).unwrap(), first);
+
+    let second = svc.start_timer(review, at(4, 10, 0)).unwrap();
+    assert_eq!(
+        svc.get_entry(first.id).unwrap().ended_at,
+        Some(at(4, 10, 0))
+    );
+    assert_eq!(svc.running_timer().unwrap(), Some(second.clone()));
+
+    // Another device keeps its own timer.
+    let laptop = SqliteTimeEntryRepository::try_new_for_device(&conn, "laptop").unwrap();
+    let laptop_svc = TimeTrackingService::new(&atoms, &laptop, LocalCalendar::utc());
+    let remote = laptop_svc.start_timer(write, at(4, 10, 5)).unwrap();
+    assert_eq!(remote.device_id, "laptop");
+    assert_eq!(svc.running_timer().unwrap(), Some(second.clone()));
+
+    let stopped = svc.stop_timer(at(4, 10, 45)).unwrap().unwrap();
+    assert_eq!(stopped.id, second.id);
+    assert_eq!(stopped.duration_ms(at(5, 0, 0)), 45 * MINUTE);
+    assert_eq!(svc.stop_timer(at(4, 11, 0)).unwrap(), None);
+    assert!(laptop_svc.running_timer().unwrap().is_some());
+
+    let note = insert(&conn, AtomType::Note, "not billable");
+    assert!(matches!(
+        svc.start_timer(note, at(4, 11, 0)),
+        Err(TimeTrackingServiceError::NotATask(_))
+    ));
+    assert!(matches!(
+        svc.start_timer(Uuid::new_v4(), at(4, 11, 0)),
+        Err(TimeTrackingServiceError::AtomNotFound(_))
+    ));
+}
+
+#[test]
+fn manual_entries_are_validated_edited_and_deleted() {
+    let conn = open_db_in_memory().unwrap();
+    let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+    let entries = SqliteTimeEntryRepository::try_new(&conn).unwrap();
+    let svc = TimeTrackingService::new(&atoms, &entries, LocalCalendar::utc());
+    let task = insert(&conn, AtomType::Task, "client call");
+    let other = insert(&conn, AtomType::Task, "follow-up");
+
+    assert!(matches!(
+        svc.add_entry(task, at(4, 9, 0), at(4, 9, 0), None),
+        Err(TimeTrackingServiceError::InvalidTimeRange { .. })
+    ));
+    let entry = svc
+        .add_entry(
+            task,
+            at(4, 9, 0),
+            at(4, 10, 0),
+            Some("  kickoff ".to_string()),
+        )
+        .unwrap();
+    assert_eq!(entry.note.as_deref(), Some("kickoff"));
+    assert!(!entry.is_running());
+
+    let patch = TimeEntryPatch {
+        ended_at: Some(at(4, 8, 0)),
+        ..TimeEntryPatch::default()
+    };
+    assert!(matches!(
+        svc.update_entry(entry.id, &patch),
+        Err(TimeTrackingServiceError::InvalidTimeRange { .. })
+    ));
+    let patch = TimeEntryPatch {
+        atom_id: Some(other),
+        started_at: Some(at(4, 9, 15)),
+        note: Some(" ".to_string()),
+        ..TimeEntryPatch::default()
+    };
+    let edited = svc.update_entry(entry.id, &patch).unwrap();
+    assert_eq!(edited.atom_id, other);
+    assert_eq!(
+        (edited.started_at, edited.ended_at),
+        (at(4, 9, 15), Some(at(4, 10, 0)))
+    );
+    assert_eq!(edited.note, None);
+    assert!(svc.list_entries(task).unwrap().is_empty());
+    assert_eq!(svc.list_entries(other).unwrap(), vec![edited]);
+
+    // Setting an end stops a running entry.
+    let running = svc.start_timer(task, at(4, 11, 0)).unwrap();
+    let patch = TimeEntryPatch {
+        ended_at: Some(at(4, 11, 20)),
+        ..TimeEntryPatch::default()
+    };
+    svc.update_entry(running.id, &patch).unwrap();
+    assert_eq!(svc.running_timer().unwrap(), None);
+
+    assert!(svc.delete_entry(entry.id).unwrap());
+    assert!(!svc.delete_entry(entry.id).unwrap());
+    assert!(matches!(
+        svc.get_entry(entry.id),
+        Err(TimeTrackingServiceError::EntryNotFound(_))
+    ));
+}
+
+#[test]
+fn totals_group_by_day_tag_and_folder() {
+    let conn = open_db_in_memory().unwrap();
+    let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+    let entries = SqliteTimeEntryRepository::try_new(&conn).unwrap();
+    let svc = TimeTrackingService::new(&atoms, &entries, LocalCalendar::utc());
+    let design = insert(&conn, AtomType::Task, "design #acme #billable");
+    let mockups = insert(&conn, AtomType::Task, "mockups");
+    let admin = insert(&conn, AtomType::Task, "admin #internal");
+    TaskService::new(&atoms, &conn)
+        .set_parent(mockups, Some(design), None)
+        .unwrap();
+
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let clients = tree.create_folder(None, "Clients").unwrap();
+    let acme = tree.create_folder(Some(clients.node_uuid), "Acme").unwrap();
+    let brief = insert(&conn, AtomType::Note, &format!("Brief: [[{design}]]"));
+    tree.create_note_ref(Some(acme.node_uuid), brief, Some("Brief".to_string()))
+        .unwrap();
+
+    // 23:00-01:00 across midnight, a subtask session and an untagged timer.
+    svc.add_entry(design, at(4, 23, 0), at(5, 1, 0), None)
+        .unwrap();
+    svc.add_entry(mockups, at(5, 9, 0), at(5, 9, 30), None)
+        .unwrap();
+    svc.add_entry(admin, at(3, 8, 0), at(3, 9, 0), None)
+        .unwrap();
+    svc.start_timer(admin, at(6, 10, 0)).unwrap();
+    let now = at(6, 10, 15);
+
+    let days: Vec<_> = svc
+        .totals_by_day(date(4), date(6), now)
+        .unwrap()
+        .into_iter()
+        .map(|day| (day.date, day.total_ms))
+        .collect();
+    assert_eq!(
+        days,
+        vec![
+            (date(4), 60 * MINUTE),
+            (date(5), 90 * MINUTE),
+            (date(6), 15 * MINUTE)
+        ]
+    );
+
+    let tags: Vec<_> = svc
+        .totals_by_tag(date(3), date(6), now)
+        .unwrap()
+        .into_iter()
+        .map(|total| (total.tag, total.total_ms))
+        .collect();
+    assert_eq!(
+        tags,
+        vec![
+            ("acme".to_string(), 120 * MINUTE),
+            ("billable".to_string(), 120 * MINUTE),
+            ("internal".to_string(), 75 * MINUTE),
+        ]
+    );
+
+    // Clipped to the 5th; the subtask counts through its parent's note.
+    let folders: Vec<_> = svc
+        .totals_by_folder(date(5), date(5), now)
+        .unwrap()
+        .into_iter()
+        .map(|total| (total.folder_id, total.path, total.total_ms))
+        .collect();
+    assert_eq!(
+        folders,
+        vec![
+            (clients.node_uuid, "Clients".to_string(), 90 * MINUTE),
+            (acme.node_uuid, "Clients/Acme".to_string(), 90 * MINUTE),
+        ]
+    );
+
+    assert!(matches!(
+        svc.totals_by_day(date(6), date(4), now),
+        Err(TimeTrackingServiceError::InvalidDateRange { .. })
+    ));
+    // Trashed tasks drop out of reports.
+    atoms.soft_delete_atom(design).unwrap();
+    assert!(svc
+        .totals_by_folder(date(5), date(5), now)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn completing_a_task_optionally_stops_its_timer() {
+    let conn = open_db_in_memory().unwrap();
+    let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+    let entries = SqliteTimeEntryRepository::try_new(&conn).unwrap();
+    let svc = TimeTrackingService::new(&atoms, &entries, LocalCalendar::utc());
+    let tasks = TaskService::new(&atoms, &conn);
+    let parent = insert(&conn, AtomType::Task, "release");
+    let changelog = insert(&conn, AtomType::Task, "changelog");
+    let tag = insert(&conn, AtomType::Task, "tag build");
+    tasks.set_parent(changelog, Some(parent), None).unwrap();
+    tasks.set_parent(tag, Some(parent), None).unwrap();
+
+    // Plain status updates leave the timer alone.
+    svc.start_timer(changelog, at(4, 9, 0)).unwrap();
+    tasks
+        .update_status(changelog, Some(TaskStatus::Done))
+        .unwrap();
+    assert!(svc.running_timer().unwrap().is_some());
+
+    // Finishing the last subtask completes the parent and stops its timer.
+    svc.start_timer(parent, at(4, 10, 0)).unwrap();
+    let options = StatusUpdateOptions {
+        stop_timer_at: Some(at(4, 10, 40)),
+    };
+    tasks
+        .update_status_with(tag, Some(TaskStatus::Cancelled), options)
+        .unwrap();
+    assert_eq!(
+        atoms.get_atom(parent, false).unwrap().unwrap().task_status,
+        Some(TaskStatus::Done)
+    );
+    assert_eq!(svc.running_timer().unwrap(), None);
+    let parent_entries = svc.list_entries(parent).unwrap();
+    assert_eq!(parent_entries[0].ended_at, Some(at(4, 10, 40)));
+
+    // A timer started earlier than the stop time is never ended before start.
+    tasks.update_status(parent, Some(TaskStatus::Todo)).unwrap();
+    svc.start_timer(parent, at(4, 12, 0)).unwrap();
+    let options = StatusUpdateOptions {
+        stop_timer_at: Some(at(4, 11, 0)),
+    };
+    tasks
+        .update_status_with(parent, Some(TaskStatus::Done), options)
+        .unwrap();
+    let parent_entries = svc.list_entries(parent).unwrap();
+    assert_eq!(parent_entries[1].ended_at, Some(at(4, 12, 0)));
+}
@@ -1,6 +1,9 @@
+use chrono::{NaiveDate, Weekday};
 use lazynote_core::db::open_db_in_memory;
 use lazynote_core::{
-    Atom, AtomRepository, AtomType, SqliteAtomRepository, TaskService, TaskStatus,
+    floating_span, Atom, AtomRepository, AtomType, LocalCalendar, OccurrenceEditScope,
+    OccurrencePatch, RecurrenceService, SectionOptions, SqliteAtomRepository,
+    SqliteRecurrenceRepository, TaskPriority, TaskService, TaskStatus,
 };
 
 /// Helper: creates a migrated in-memory DB and returns (conn, repo).
@@ -181,6 +184,103 @@ fn fetch_upcoming_returns_future_atoms() {
     assert!(ids.contains(&future_event.uuid));
 }
 
+// ---------------------------------------------------------------------------
+// Deadlines and priority
+// ---------------------------------------------------------------------------
+
+#[test]
+fn due_at_places_atoms_in_today_or_upcoming() {
+    let conn = setup();
+    let mut due_today = make_atom(AtomType::Task, "due today", None, None);
+    due_today.due_at = Some(500);
+    let mut due_later = make_atom(AtomType::Task, "due later", None, None);
+    due_later.due_at = Some(5000);
+    // Scheduled for later but already due: the deadline wins.
+    let mut late = make_atom(AtomType::Task, "scheduled later, due now", Some(3000), None);
+    late.due_at = Some(900);
+    // Scheduled for later, due after that.
+    let mut planned = make_atom(AtomType::Task, "scheduled then due", Some(2000), None);
+    planned.due_at = Some(5000);
+    for atom in [&due_today, &due_later, &late, &planned] {
+        insert_atom(&conn, atom);
+    }
+
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let svc = TaskService::new(&repo, &conn);
+    assert!(svc.fetch_inbox(50, 0).unwrap().is_empty());
+    let today: Vec<_> = svc
+        .fetch_today(0, 1000, 50, 0)
+        .unwrap()
+        .into_iter()
+        .map(|s| s.atom.uuid)
+        .collect();
+    assert_eq!(today, vec![due_today.uuid, late.uuid]);
+    let upcoming: Vec<_> = svc
+        .fetch_upcoming(1000, 50, 0)
+        .unwrap()
+        .into_iter()
+        .map(|s| s.atom.uuid)
+        .collect();
+    assert_eq!(upcoming, vec![planned.uuid, due_later.uuid]);
+}
+
+#[test]
+fn fetch_overdue_sorts_by_priority_then_deadline() {
+    let conn = setup();
+    let low = make_atom(AtomType::Task, "low", None, None);
+    let high_late = make_atom(AtomType::Task, "high, due 300", None, None);
+    let high_early = make_atom(AtomType::Task, "high, due 200", None, None);
+    let legacy_ddl = make_atom(AtomType::Task, "end_at deadline", None, Some(50));
+    let done = make_atom(AtomType::Task, "done", None, None);
+    let not_yet = make_atom(AtomType::Task, "future", None, None);
+    let scheduled = make_atom(AtomType::Event, "past event", Some(10), Some(20));
+    for atom in [
+        &low,
+        &high_late,
+        &high_early,
+        &legacy_ddl,
+        &done,
+        &not_yet,
+        &scheduled,
+    ] {
+        insert_atom(&conn, atom);
+    }
+
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let svc = TaskService::new(&repo, &conn);
+    for (atom, due, priority) in [
+        (&low, 100, Some(TaskPriority::Low)),
+        (&high_late, 300, Some(TaskPriority::High)),
+        (&high_early, 200, Some(TaskPriority::High)),
+        (&done, 100, Some(TaskPriority::High)),
+        (&not_yet, 5000, Some(TaskPriority::High)),
+    ] {
+        svc.set_due_at(atom.uuid, Some(due)).unwrap();
+        svc.set_priority(atom.uuid, priority).unwrap();
+    }
+    svc.update_status(done.uuid, Some(TaskStatus::Done))
+        .unwrap();
+
+    let overdue: Vec<_> = svc
+        .fetch_overdue(1000, 50, 0)
+        .unwrap()
+        .into_iter()
+        .map(|s| s.atom.uuid)
+        .collect();
+    assert_eq!(
+        overdue,
+        vec![high_early.uuid, high_late.uuid, low.uuid, legacy_ddl.uuid]
+    );
+
+    let stored = repo.get_atom(low.uuid, false).unwrap().unwrap();
+    assert_eq!(stored.priority, Some(TaskPriority::Low));
+    assert_eq!(stored.due_at, Some(100));
+    svc.set_priority(low.uuid, None).unwrap();
+    svc.set_due_at(low.uuid, None).unwrap();
+    let cleared = repo.get_atom(low.uuid, false).unwrap().unwrap();
+    assert_eq!((cleared.priority, cleared.due_at), (None, None));
+}
+
 // ---------------------------------------------------------------------------
 // Status update
 // ---------------------------------------------------------------------------
@@ -258,13 +358,328 @@ fn fts_search_works_after_migration_6() {
         text: "unique_searchable_term_xyz".to_string(),
         kind: None,
         limit: 10,
-        raw_fts_syntax: false,
+        ..lazynote_core::SearchQuery::new("")
     };
     let results = lazynote_core::search_all(&conn, &query).unwrap();
     assert_eq!(results.len(), 1);
     assert_eq!(results[0].atom_id, note.uuid);
 }
 
+// ---------------------------------------------------------------------------
+// Local calendar windows across DST changes
+// ---------------------------------------------------------------------------
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn utc_ms(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> i64 {
+    date(year, month, day)
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+        .and_utc()
+        .timestamp_millis()
+}
+
+fn calendar(time_zone: &str) -> LocalCalendar {
+    LocalCalendar::new(time_zone, Weekday::Mon).unwrap()
+}
+
+fn today_ids(
+    svc: &TaskService<'_, SqliteAtomRepository<'_>>,
+    cal: &LocalCalendar,
+    now: i64,
+) -> Vec<uuid::Uuid> {
+    svc.fetch_today_in(cal, now, 50, 0, SectionOptions::default())
+        .unwrap()
+        .iter()
+        .map(|s| s.atom.uuid)
+        .collect()
+}
+
+fn upcoming_ids(
+    svc: &TaskService<'_, SqliteAtomRepository<'_>>,
+    cal: &LocalCalendar,
+    now: i64,
+) -> Vec<uuid::Uuid> {
+    svc.fetch_upcoming_in(cal, now, 50, 0)
+        .unwrap()
+        .iter()
+        .map(|s| s.atom.uuid)
+        .collect()
+}
+
+#[test]
+fn today_in_covers_23_hour_spring_forward_day() {
+    let conn = setup();
+    let ny = calendar("America/New_York");
+    // 2026-03-08 is 23h long in New York: 05:00Z .. 04:00Z next day.
+    let late = make_atom(
+        AtomType::Event,
+        "23:30 EDT",
+        Some(utc_ms(2026, 3, 9, 3, 30)),
+        Some(utc_ms(2026, 3, 9, 3, 45)),
+    );
+    let next_day = make_atom(
+        AtomType::Event,
+        "00:30 EDT next day",
+        Some(utc_ms(2026, 3, 9, 4, 30)),
+        Some(utc_ms(2026, 3, 9, 4, 45)),
+    );
+    insert_atom(&conn, &late);
+    insert_atom(&conn, &next_day);
+
+    let window = ny.day_window(utc_ms(2026, 3, 8, 16, 0));
+    assert_eq!(window.start_ms, utc_ms(2026, 3, 8, 5, 0));
+    assert_eq!(window.end_ms, utc_ms(2026, 3, 9, 4, 0) - 1);
+
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let svc = TaskService::new(&repo, &conn);
+    let now = utc_ms(2026, 3, 8, 16, 0);
+    assert_eq!(today_ids(&svc, &ny, now), vec![late.uuid]);
+    assert_eq!(upcoming_ids(&svc, &ny, now), vec![next_day.uuid]);
+}
+
+#[test]
+fn today_in_covers_25_hour_fall_back_day() {
+    let conn = setup();
+    let ny = calendar("America/New_York");
+    // 2026-11-01 is 25h long in New York: 04:00Z .. 05:00Z next day.
+    let last_hour = make_atom(
+        AtomType::Event,
+        "23:30 EST",
+        Some(utc_ms(2026, 11, 2, 4, 30)),
+        Some(utc_ms(2026, 11, 2, 4, 45)),
+    );
+    insert_atom(&conn, &last_hour);
+
+    let window = ny.day_window(utc_ms(2026, 11, 1, 12, 0));
+    assert_eq!(window.start_ms, utc_ms(2026, 11, 1, 4, 0));
+    assert_eq!(window.end_ms, utc_ms(2026, 11, 2, 5, 0) - 1);
+
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let svc = TaskService::new(&repo, &conn);
+    let now = utc_ms(2026, 11, 1, 12, 0);
+    assert_eq!(today_ids(&svc, &ny, now), vec![last_hour.uuid]);
+    assert!(upcoming_ids(&svc, &ny, now).is_empty());
+    // A caller-computed 24h window misses the extra hour.
+    let naive = svc
+        .fetch_today(window.start_ms, window.start_ms + 86_400_000 - 1, 50, 0)
+        .unwrap();
+    assert!(naive.is_empty());
+}
+
+#[test]
+fn all_day_event_matches_local_date_in_every_zone() {
+    let conn = setup();
+    let event = make_atom(AtomType::Event, "holiday", Some(0), Some(1));
+    insert_atom(&conn, &event);
+    let timed = make_atom(
+        AtomType::Event,
+        "lunch",
+        Some(utc_ms(2026, 3, 8, 20, 0)),
+        Some(utc_ms(2026, 3, 8, 21, 0)),
+    );
+    insert_atom(&conn, &timed);
+
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let svc = TaskService::new(&repo, &conn);
+    let stored = svc
+        .set_all_day(event.uuid, date(2026, 3, 8), date(2026, 3, 8))
+        .unwrap();
+    assert!(stored.all_day);
+    assert_eq!((stored.start_at, stored.end_at), {
+        let (start, end) = floating_span(date(2026, 3, 8), date(2026, 3, 8));
+        (Some(start), Some(end))
+    });
+
+    // 2026-03-08T01:00Z is March 8 in Tokyo but March 7 in Los Angeles.
+    let instant = utc_ms(2026, 3, 8, 1, 0);
+    let tokyo = calendar("Asia/Tokyo");
+    let los_angeles = calendar("America/Los_Angeles");
+    assert_eq!(today_ids(&svc, &tokyo, instant), vec![event.uuid]);
+    assert!(!today_ids(&svc, &los_angeles, instant).contains(&event.uuid));
+    assert_eq!(
+        upcoming_ids(&svc, &los_angeles, instant),
+        vec![event.uuid, timed.uuid]
+    );
+
+    // Later on March 8 in Los Angeles (after the DST switch) the all-day
+    // event is listed before timed atoms.
+    let afternoon = utc_ms(2026, 3, 8, 19, 0);
+    assert_eq!(
+        today_ids(&svc, &los_angeles, afternoon),
+        vec![event.uuid, timed.uuid]
+    );
+    assert!(upcoming_ids(&svc, &los_angeles, afternoon).is_empty());
+
+    // Rescheduling with instants makes the event timed again.
+    repo.update_event_times(event.uuid, instant, instant + 60_000)
+        .unwrap();
+    assert!(!repo.get_atom(event.uuid, false).unwrap().unwrap().all_day);
+}
+
+#[test]
+fn all_day_recurring_series_expands_on_local_dates() {
+    let conn = setup();
+    let (start, end) = floating_span(date(2026, 3, 1), date(2026, 3, 1));
+    let mut series = make_atom(AtomType::Task, "daily review", Some(start), Some(end));
+    series.all_day = true;
+    series.recurrence_rule = Some("FREQ=DAILY".to_string());
+    insert_atom(&conn, &series);
+
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let svc = TaskService::new(&repo, &conn);
+    let berlin = calendar("Europe/Berlin");
+    // 2026-03-29 is the spring-forward day in Berlin.
+    let now = utc_ms(2026, 3, 29, 10, 0);
+    let today = svc
+        .fetch_today_in(&berlin, now, 50, 0, SectionOptions::default())
+        .unwrap();
+    assert_eq!(today.len(), 1);
+    assert_eq!(
+        today[0].occurrence_start_at,
+        Some(floating_span(date(2026, 3, 29), date(2026, 3, 29)).0)
+    );
+
+    let upcoming = svc.fetch_upcoming_in(&berlin, now, 50, 0).unwrap();
+    assert_eq!(upcoming.len(), 1);
+    assert_eq!(
+        upcoming[0].occurrence_start_at,
+        Some(floating_span(date(2026, 3, 30), date(2026, 3, 30)).0)
+    );
+}
+
+#[test]
+fn timed_recurring_series_keeps_local_time_across_dst() {
+    let conn = setup();
+    let ny = calendar("America/New_York");
+    // Monday 2026-03-02 09:00 EST; New York springs forward on 2026-03-08.
+    let mut standup = make_atom(
+        AtomType::Event,
+        "standup",
+        Some(utc_ms(2026, 3, 2, 14, 0)),
+        Some(utc_ms(2026, 3, 2, 14, 15)),
+    );
+    standup.recurrence_rule = Some("FREQ=WEEKLY;BYDAY=MO".to_string());
+    insert_atom(&conn, &standup);
+
+    let repo = SqliteAtomRepository::try_new_with_calendar(&conn, ny).unwrap();
+    let svc = TaskService::new(&repo, &conn);
+    let monday = utc_ms(2026, 3, 9, 12, 0);
+    let today = svc
+        .fetch_today_in(&ny, monday, 50, 0, SectionOptions::default())
+        .unwrap();
+    assert_eq!(today.len(), 1);
+    assert_eq!(
+        today[0].occurrence_start_at,
+        Some(utc_ms(2026, 3, 9, 13, 0))
+    );
+    assert_eq!(today[0].atom.end_at, Some(utc_ms(2026, 3, 9, 13, 15)));
+
+    // Back to 09:00 EST after the 2026-11-01 fall-back.
+    let november = repo
+        .fetch_calendar_window(ny.dates_window(date(2026, 11, 2), date(2026, 11, 2)), 50, 0)
+        .unwrap();
+    assert_eq!(
+        november[0].occurrence_start_at,
+        Some(utc_ms(2026, 11, 2, 14, 0))
+    );
+
+    // Occurrence edits address the local-time starts.
+    let recurrences = SqliteRecurrenceRepository::try_new(&conn).unwrap();
+    let detached = RecurrenceService::new(&repo, &recurrences)
+        .edit_occurrence(
+            standup.uuid,
+            utc_ms(2026, 3, 9, 13, 0),
+            OccurrenceEditScope::ThisOccurrence,
+            &OccurrencePatch::default(),
+        )
+        .unwrap();
+    assert_eq!(
+        repo.get_atom(detached, false).unwrap().unwrap().start_at,
+        Some(utc_ms(2026, 3, 9, 13, 0))
+    );
+}
+
+#[test]
+fn recurring_byday_matches_local_weekday() {
+    let conn = setup();
+    let la = calendar("America/Los_Angeles");
+    // Monday 20:00 PST is already Tuesday in UTC.
+    let mut class = make_atom(
+        AtomType::Event,
+        "evening class",
+        Some(utc_ms(2026, 3, 3, 4, 0)),
+        Some(utc_ms(2026, 3, 3, 5, 0)),
+    );
+    class.recurrence_rule = Some("FREQ=WEEKLY;BYDAY=MO;COUNT=3".to_string());
+    insert_atom(&conn, &class);
+
+    let repo = SqliteAtomRepository::try_new_with_calendar(&conn, la).unwrap();
+    let starts: Vec<_> = repo
+        .fetch_calendar_window(la.dates_window(date(2026, 3, 1), date(2026, 3, 31)), 50, 0)
+        .unwrap()
+        .iter()
+        .filter_map(|row| row.occurrence_start_at)
+        .collect();
+    assert_eq!(
+        starts,
+        vec![
+            utc_ms(2026, 3, 3, 4, 0),
+            utc_ms(2026, 3, 10, 3, 0),
+            utc_ms(2026, 3, 17, 3, 0),
+        ]
+    );
+}
+
+#[test]
+fn editing_all_day_series_keeps_it_all_day() {
+    let conn = setup();
+    let (start, end) = floating_span(date(2026, 3, 1), date(2026, 3, 1));
+    let mut series = make_atom(AtomType::Event, "gym", Some(start), Some(end));
+    series.all_day = true;
+    series.recurrence_rule = Some("FREQ=DAILY;COUNT=5".to_string());
+    insert_atom(&conn, &series);
+
+    let repo = SqliteAtomRepository::try_new_with_calendar(&conn, calendar("Asia/Tokyo")).unwrap();
+    let recurrences = SqliteRecurrenceRepository::try_new(&conn).unwrap();
+    let svc = RecurrenceService::new(&repo, &recurrences);
+    let patch = OccurrencePatch {
+        content: Some("gym (legs)".to_string()),
+        ..OccurrencePatch::default()
+    };
+    let third = floating_span(date(2026, 3, 3), date(2026, 3, 3)).0;
+    for (start, scope) in [
+        (start, OccurrenceEditScope::ThisAndFollowing),
+        (third, OccurrenceEditScope::ThisOccurrence),
+    ] {
+        let id = svc
+            .edit_occurrence(series.uuid, start, scope, &patch)
+            .unwrap();
+        let edited = repo.get_atom(id, false).unwrap().unwrap();
+        assert!(edited.all_day);
+        assert_eq!(edited.start_at, Some(start));
+    }
+}
+
+#[test]
+fn all_day_span_must_be_day_aligned_and_ordered() {
+    let conn = setup();
+    let mut misaligned = make_atom(AtomType::Event, "bad", Some(1_000), Some(2_000));
+    misaligned.all_day = true;
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    assert!(repo.create_atom(&misaligned).is_err());
+
+    let event = make_atom(AtomType::Event, "trip", Some(0), Some(1));
+    insert_atom(&conn, &event);
+    let svc = TaskService::new(&repo, &conn);
+    assert!(svc
+        .set_all_day(event.uuid, date(2026, 3, 9), date(2026, 3, 8))
+        .is_err());
+    assert!(!repo.get_atom(event.uuid, false).unwrap().unwrap().all_day);
+}
+
 // ---------------------------------------------------------------------------
 // Tag enrichment in section queries
 // ---------------------------------------------------------------------------
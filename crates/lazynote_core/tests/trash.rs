@@ -0,0 +1,277 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    now_epoch_ms, Atom, AtomRepository, AtomType, FolderDeleteMode, PurgeOutcome, PurgeSummary,
+    SqliteAtomRepository, SqliteTrashRepository, SqliteTreeRepository, TrashService,
+    TrashServiceError, TreeService,
+};
+use rusqlite::{params, Connection};
+
+fn insert_atom(conn: &Connection, atom: &Atom) {
+    let repo = SqliteAtomRepository::try_new(conn).unwrap();
+    repo.create_atom(atom).unwrap();
+}
+
+fn soft_delete(conn: &Connection, atom: &Atom) {
+    let repo = SqliteAtomRepository::try_new(conn).unwrap();
+    repo.soft_delete_atom(atom.uuid).unwrap();
+}
+
+fn row_exists(conn: &Connection, atom: &Atom) -> bool {
+    conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM atoms WHERE uuid = ?1);",
+        [atom.uuid.to_string()],
+        |row| row.get::<_, i64>(0),
+    )
+    .unwrap()
+        == 1
+}
+
+#[test]
+fn list_trash_returns_only_deleted_atoms_filtered_by_kind() {
+    let conn = open_db_in_memory().unwrap();
+    let note = Atom::new(AtomType::Note, "trashed note");
+    let task = Atom::new(AtomType::Task, "trashed task");
+    let active = Atom::new(AtomType::Note, "active note");
+    insert_atom(&conn, &note);
+    insert_atom(&conn, &task);
+    insert_atom(&conn, &active);
+    soft_delete(&conn, &note);
+    soft_delete(&conn, &task);
+
+    let service = TrashService::new(SqliteTrashRepository::try_new(&conn).unwrap());
+    let all = service.list_trash(None, None, 0).unwrap();
+    assert_eq!(all.applied_limit, 20);
+    assert_eq!(all.items.len(), 2);
+    assert!(all.items.iter().all(|item| !item.sync_pending));
+
+    let notes = service
+        .list_trash(Some(AtomType::Note), Some(500), 0)
+        .unwrap();
+    assert_eq!(notes.applied_limit, 100);
+    assert_eq!(notes.items.len(), 1);
+    assert_eq!(notes.items[0].atom_id, note.uuid);
+    assert_eq!(notes.items[0].content, "trashed note");
+}
+
+#[test]
+fn restore_revives_note_ref_removed_with_deleted_folder() {
+    let conn = open_db_in_memory().unwrap();
+    let note = Atom::new(AtomType::Note, "inside folder");
+    insert_atom(&conn, &note);
+
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let folder = tree.create_folder(None, "Projects").unwrap();
+    let note_ref = tree
+        .create_note_ref(Some(folder.node_uuid), note.uuid, Some("Inside".into()))
+        .unwrap();
+    tree.delete_folder(folder.node_uuid, FolderDeleteMode::DeleteAll)
+        .unwrap();
+
+    let trash = TrashService::new(SqliteTrashRepository::try_new(&conn).unwrap());
+    let restored_ref = trash.restore_atom(note.uuid).unwrap();
+    assert_eq!(restored_ref, Some(note_ref.node_uuid));
+
+    let atom_repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let atom = atom_repo.get_atom(note.uuid, false).unwrap().unwrap();
+    assert!(!atom.is_deleted);
+
+    let root = tree.list_children(None).unwrap();
+    let revived = root
+        .iter()
+        .find(|node| node.node_uuid == note_ref.node_uuid)
+        .expect("note_ref moved to root");
+    assert_eq!(revived.display_name, "Inside");
+    assert!(trash.list_trash(None, None, 0).unwrap().items.is_empty());
+}
+
+#[test]
+fn restore_creates_root_note_ref_when_note_never_had_one() {
+    let conn = open_db_in_memory().unwrap();
+    let note = Atom::new(AtomType::Note, "orphan");
+    insert_atom(&conn, &note);
+    soft_delete(&conn, &note);
+
+    let trash = TrashService::new(SqliteTrashRepository::try_new(&conn).unwrap());
+    let created = trash
+        .restore_atom(note.uuid)
+        .unwrap()
+        .expect("new note_ref");
+
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let root = tree.list_children(None).unwrap();
+    assert!(root.iter().any(|node| node.node_uuid == created));
+
+    let err = trash.restore_atom(note.uuid).unwrap_err();
+    assert!(matches!(err, TrashServiceError::AtomNotFound(id) if id == note.uuid));
+}
+
+#[test]
+fn purge_removes_row_and_rejects_active_atoms() {
+    let conn = open_db_in_memory().unwrap();
+    let note = Atom::new(AtomType::Note, "gone");
+    let active = Atom::new(AtomType::Note, "stay");
+    insert_atom(&conn, &note);
+    insert_atom(&conn, &active);
+    soft_delete(&conn, &note);
+
+    let trash = TrashService::new(SqliteTrashRepository::try_new(&conn).unwrap());
+    assert_eq!(trash.purge_atom(note.uuid).unwrap(), PurgeOutcome::Purged);
+    assert!(!row_exists(&conn, &note));
+
+    let err = trash.purge_atom(active.uuid).unwrap_err();
+    assert!(matches!(err, TrashServiceError::AtomNotFound(id) if id == active.uuid));
+    assert!(row_exists(&conn, &active));
+}
+
+#[test]
+fn purge_keeps_scrubbed_tombstone_until_mapping_synced() {
+    let conn = open_db_in_memory().unwrap();
+    let task = Atom::new(AtomType::Task, "synced task body");
+    let other = Atom::new(AtomType::Task, "other series");
+    insert_atom(&conn, &task);
+    insert_atom(&conn, &other);
+    conn.execute(
+        "INSERT INTO external_mappings (provider, external_id, atom_uuid, last_synced_at)
+         VALUES ('caldav', 'ext-1', ?1, 1);",
+        [task.uuid.to_string()],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO recurrence_exceptions (series_uuid, original_start_at, override_uuid)
+         VALUES (?1, 1000, ?2), (?2, 2000, ?1);",
+        params![task.uuid.to_string(), other.uuid.to_string()],
+    )
+    .unwrap();
+    soft_delete(&conn, &task);
+
+    let trash = TrashService::new(SqliteTrashRepository::try_new(&conn).unwrap());
+    let listed = trash.list_trash(None, None, 0).unwrap();
+    assert!(listed.items[0].sync_pending);
+
+    assert_eq!(
+        trash.purge_atom(task.uuid).unwrap(),
+        PurgeOutcome::KeptForSync
+    );
+    let (content, purged_at): (String, Option<i64>) = conn
+        .query_row(
+            "SELECT content, purged_at FROM atoms WHERE uuid = ?1;",
+            [task.uuid.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert!(content.is_empty());
+    assert!(purged_at.is_some());
+    assert!(trash.list_trash(None, None, 0).unwrap().items.is_empty());
+    let exceptions: Vec<(String, Option<String>)> = conn
+        .prepare("SELECT series_uuid, override_uuid FROM recurrence_exceptions;")
+        .unwrap()
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(exceptions, vec![(other.uuid.to_string(), None)]);
+
+    conn.execute(
+        "UPDATE external_mappings
+         SET last_synced_at = (SELECT updated_at FROM atoms WHERE uuid = ?1)
+         WHERE atom_uuid = ?1;",
+        [task.uuid.to_string()],
+    )
+    .unwrap();
+    assert_eq!(trash.purge_atom(task.uuid).unwrap(), PurgeOutcome::Purged);
+    assert!(!row_exists(&conn, &task));
+}
+
+#[test]
+fn purge_expired_honors_retention_window() {
+    let conn = open_db_in_memory().unwrap();
+    let old = Atom::new(AtomType::Note, "old");
+    let recent = Atom::new(AtomType::Note, "recent");
+    insert_atom(&conn, &old);
+    insert_atom(&conn, &recent);
+    soft_delete(&conn, &old);
+    soft_delete(&conn, &recent);
+
+    let now = now_epoch_ms();
+    conn.execute(
+        "UPDATE atoms SET updated_at = ?2 WHERE uuid = ?1;",
+        params![old.uuid.to_string(), now - 40 * 86_400_000],
+    )
+    .unwrap();
+
+    let trash = TrashService::new(SqliteTrashRepository::try_new(&conn).unwrap());
+    assert_eq!(
+        trash.purge_expired(now, Some(0)).unwrap(),
+        PurgeSummary::default()
+    );
+    assert!(row_exists(&conn, &old));
+
+    let summary = trash.purge_expired(now, None).unwrap();
+    assert_eq!(summary.purged, 1);
+    assert_eq!(summary.kept_for_sync, 0);
+    assert!(!row_exists(&conn, &old));
+    assert!(row_exists(&conn, &recent));
+}
+
+#[test]
+fn purge_expired_scrubs_tombstone_once_and_drops_reminders_and_time_entries() {
+    let conn = open_db_in_memory().unwrap();
+    let task = Atom::new(AtomType::Task, "billable task");
+    insert_atom(&conn, &task);
+    let uuid = task.uuid.to_string();
+    conn.execute(
+        "INSERT INTO external_mappings (provider, external_id, atom_uuid, last_synced_at)
+         VALUES ('caldav', 'ext-1', ?1, 1);",
+        [&uuid],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO reminders (uuid, atom_uuid, anchor, remind_at, created_at)
+         VALUES ('r-1', ?1, 'absolute', 5, 1);",
+        [&uuid],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO time_entries (uuid, atom_uuid, device_id, started_at, ended_at)
+         VALUES ('t-1', ?1, 'desk', 1, 2);",
+        [&uuid],
+    )
+    .unwrap();
+    soft_delete(&conn, &task);
+    let now = now_epoch_ms();
+    conn.execute(
+        "UPDATE atoms SET updated_at = ?2 WHERE uuid = ?1;",
+        params![uuid, now - 40 * 86_400_000],
+    )
+    .unwrap();
+
+    let trash = TrashService::new(SqliteTrashRepository::try_new(&conn).unwrap());
+    let summary = trash.purge_expired(now, None).unwrap();
+    assert_eq!(summary.kept_for_sync, 1);
+    let leftovers: i64 = conn
+        .query_row(
+            "SELECT (SELECT COUNT(*) FROM reminders WHERE atom_uuid = ?1)
+                  + (SELECT COUNT(*) FROM time_entries WHERE atom_uuid = ?1);",
+            [&uuid],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(leftovers, 0);
+
+    // A pending tombstone is not purged or counted again.
+    assert_eq!(
+        trash.purge_expired(now, None).unwrap(),
+        PurgeSummary::default()
+    );
+
+    conn.execute(
+        "UPDATE external_mappings
+         SET last_synced_at = (SELECT updated_at FROM atoms WHERE uuid = ?1)
+         WHERE atom_uuid = ?1;",
+        [&uuid],
+    )
+    .unwrap();
+    let summary = trash.purge_expired(now, None).unwrap();
+    assert_eq!(summary.purged, 1);
+    assert!(!row_exists(&conn, &task));
+}
@@ -100,7 +100,8 @@ fn create_note_ref_success_for_note_atom() {
     assert_eq!(note_ref.kind, WorkspaceNodeKind::NoteRef);
     assert_eq!(note_ref.parent_uuid, Some(folder.node_uuid));
     assert_eq!(note_ref.atom_uuid, Some(note_atom.uuid));
-    assert_eq!(note_ref.display_name, "Untitled note");
+    assert_eq!(note_ref.display_name, "Note row");
+    assert!(note_ref.name_follows_title);
 }
 
 #[test]
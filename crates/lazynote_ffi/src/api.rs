@@ -11,14 +11,28 @@
 //! # See also
 //! - docs/architecture/logging.md
 
+use chrono::{NaiveDate, Weekday};
 use lazynote_core::db::open_db;
 use lazynote_core::{
-    core_version as core_version_inner, init_logging as init_logging_inner,
-    log_dart_event as log_dart_event_inner, ping as ping_inner, search_all, AtomId, AtomService,
-    AtomType, FolderDeleteMode, LogDartEventError, NoteRecord, NoteService, NoteServiceError,
-    ScheduleEventRequest, SearchQuery, SectionAtom, SqliteAtomRepository, SqliteNoteRepository,
-    SqliteTreeRepository, TaskService, TaskServiceError, TreeRepoError, TreeService,
-    TreeServiceError, WorkspaceNode, WorkspaceNodeKind,
+    core_version as core_version_inner, floating_dates, init_logging as init_logging_inner,
+    log_dart_event as log_dart_event_inner, now_epoch_ms, parse_search_query, parse_tag_expr,
+    ping as ping_inner, search_all, search_page, AtomConvertError, AtomId, AtomLink, AtomProperty,
+    AtomRepository, AtomRevision, AtomService, AtomType, Attachment, AttachmentGcSummary,
+    AttachmentService, AttachmentServiceError, CalendarConflict, CalendarDay, CalendarEntry,
+    CalendarService, CalendarServiceError, ConvertAtomRequest, DiffLine, FolderDeleteMode,
+    LaunchPlan, LaunchPolicy, LinkService, LocalCalendar, LogDartEventError, NoteRecord,
+    NoteService, NoteServiceError, NoteTitle, OccurrenceEditScope, OccurrencePatch, OutlineHeading,
+    PropertyValue, PurgeOutcome, PurgeSummary, QueryParseError, QuickAddParser, RecurrenceService,
+    RecurrenceServiceError, Reminder, ReminderService, ReminderServiceError, ReminderTrigger,
+    ResourceLink, ResourceLinkService, RevisionService, RevisionServiceError, ScheduleEventRequest,
+    SearchError, SearchHit, SearchQuery, SectionAtom, SectionOptions, SqliteAtomRepository,
+    SqliteAttachmentRepository, SqliteLinkRepository, SqliteNoteRepository,
+    SqliteRecurrenceRepository, SqliteReminderRepository, SqliteResourceLinkRepository,
+    SqliteRevisionRepository, SqliteTimeEntryRepository, SqliteTrashRepository,
+    SqliteTreeRepository, StatusUpdateOptions, TaskPriority, TaskService, TaskServiceError,
+    TimeEntry, TimeEntryPatch, TimeTrackingService, TimeTrackingServiceError, TrashItem,
+    TrashService, TrashServiceError, TreeRepoError, TreeService, TreeServiceError, WikiLinkKind,
+    WorkspaceNode, WorkspaceNodeKind,
 };
 use log::error;
 use std::path::PathBuf;
@@ -32,6 +46,7 @@ const LOG_DART_EVENT_MAX_EVENT_NAME_CHARS: usize = 64;
 const LOG_DART_EVENT_MAX_MODULE_CHARS: usize = 64;
 const LOG_DART_EVENT_MAX_MESSAGE_CHARS: usize = 512;
 static ENTRY_DB_PATH_OVERRIDE: Mutex<Option<PathBuf>> = Mutex::new(None);
+static LOCAL_CALENDAR: Mutex<Option<LocalCalendar>> = Mutex::new(None);
 
 /// Minimal health-check API for FRB smoke integration.
 ///
@@ -88,6 +103,29 @@ pub fn configure_entry_db_path(db_path: String) -> String {
     }
 }
 
+/// Configures the process-local timezone used by `*_local` section queries,
+/// `local_time_windows` and recurring timed atom expansion.
+///
+/// Input semantics:
+/// - `time_zone`: IANA name (e.g. `Europe/Berlin`), resolved by Flutter when
+///   the user setting is `system`. Looked up in the tz database bundled into
+///   Rust Core; no system zoneinfo or network access.
+/// - `week_start`: weekday name, e.g. `monday|sunday|saturday`
+///   (case-insensitive).
+///
+/// # FFI contract
+/// - Sync call, non-blocking.
+/// - Safe to call multiple times; latest successful setting wins. Until the
+///   first successful call, windows are computed in `UTC` with Monday weeks.
+/// - Returns empty string on success, error message on validation failure.
+#[flutter_rust_bridge::frb(sync)]
+pub fn configure_time_zone(time_zone: String, week_start: String) -> String {
+    match set_configured_time_zone(time_zone.as_str(), week_start.as_str()) {
+        Ok(()) => String::new(),
+        Err(err) => err,
+    }
+}
+
 /// Dart-side diagnostics logging response envelope.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LogDartEventResponse {
@@ -283,6 +321,114 @@ pub struct EntrySearchResponse {
     pub applied_limit: u32,
 }
 
+/// Highlighted span inside [`EntrySearchHitItem::snippet`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntrySearchHighlight {
+    /// Start byte offset (inclusive, UTF-8).
+    pub start: u32,
+    /// End byte offset (exclusive, UTF-8).
+    pub end: u32,
+    /// Start char offset (inclusive).
+    pub char_start: u32,
+    /// End char offset (exclusive).
+    pub char_end: u32,
+}
+
+/// Ranked search hit with structured highlights.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntrySearchHitItem {
+    /// Stable atom ID in string form.
+    pub atom_id: String,
+    /// Atom projection kind (`note|task|event`).
+    pub kind: String,
+    /// Plain-text snippet (no highlight markers).
+    pub snippet: String,
+    /// Matched spans inside `snippet`.
+    pub highlights: Vec<EntrySearchHighlight>,
+    /// Raw bm25 value (lower is better); `None` for filter/substring-only hits.
+    pub bm25: Option<f64>,
+    /// Final ranking score after boosts (higher is better).
+    pub score: f64,
+}
+
+/// Paged search response envelope.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntrySearchPageResponse {
+    /// Whether search execution succeeded.
+    pub ok: bool,
+    /// Optional stable error code for machine branching.
+    pub error_code: Option<String>,
+    /// Human-readable response message for diagnostics.
+    pub message: String,
+    /// Hits in ranking order.
+    pub items: Vec<EntrySearchHitItem>,
+    /// Cursor for the next page; `None` on the last page.
+    pub next_cursor: Option<String>,
+    /// Effective applied search limit.
+    pub applied_limit: u32,
+}
+
+/// Syntax check response for the structured search grammar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntrySearchSyntaxResponse {
+    /// Whether the text parsed successfully.
+    pub ok: bool,
+    /// Stable error code (`invalid_query_syntax`) when parsing failed.
+    pub error_code: Option<String>,
+    /// Parse failure category (`unterminated_quote|dangling_negation|missing_value|invalid_value`).
+    pub syntax_error: Option<String>,
+    /// Start char offset of the offending span (inclusive).
+    pub error_start: Option<u32>,
+    /// End char offset of the offending span (exclusive).
+    pub error_end: Option<u32>,
+    /// Number of recognized field filters.
+    pub filter_count: u32,
+    /// Human-readable response message for diagnostics.
+    pub message: String,
+}
+
+/// One recognized token of a quick-add draft.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryQuickAddToken {
+    /// Token category (`kind|date|time|duration|tag|priority`).
+    pub kind: String,
+    /// Start byte offset (inclusive).
+    pub start: u32,
+    /// End byte offset (exclusive).
+    pub end: u32,
+    /// Start char offset (inclusive).
+    pub char_start: u32,
+    /// End char offset (exclusive).
+    pub char_end: u32,
+}
+
+/// Natural-language quick-add draft response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryQuickAddResponse {
+    /// Always true; parsing never fails.
+    pub ok: bool,
+    /// Reserved for future failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Draft kind (`note|task|event`).
+    pub kind: String,
+    /// Input with recognized tokens removed.
+    pub title: String,
+    /// Start epoch ms, or floating date start when `all_day`.
+    pub start_at: Option<i64>,
+    /// End epoch ms, or floating date end when `all_day`.
+    pub end_at: Option<i64>,
+    /// Whether a date without time was recognized.
+    pub all_day: bool,
+    /// Normalized tags in input order.
+    pub tags: Vec<String>,
+    /// Priority (`low|medium|high`), or null.
+    pub priority: Option<String>,
+    /// Recognized tokens ordered by position.
+    pub tokens: Vec<EntryQuickAddToken>,
+}
+
 /// Generic action response envelope for single-entry command flow.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EntryActionResponse {
@@ -370,6 +516,303 @@ pub struct TagsListResponse {
     pub tags: Vec<String>,
 }
 
+/// Per-source tag split of one note.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteTagSourcesResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Tags set explicitly through `note_set_tags`.
+    pub manual_tags: Vec<String>,
+    /// Tags derived from content: inline `#hashtags` and frontmatter
+    /// `tags:` / `aliases:`.
+    pub inline_tags: Vec<String>,
+}
+
+/// One typed frontmatter property for FFI.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotePropertyItem {
+    /// Lowercase property key.
+    pub key: String,
+    /// `string|number|date|bool|list`.
+    pub value_type: String,
+    /// Text form for `string`, `date` and `bool` (`true`/`false`).
+    pub text: Option<String>,
+    /// Value for `number`.
+    pub number: Option<f64>,
+    /// Elements for `list`.
+    pub items: Vec<String>,
+}
+
+/// One heading of a note outline, flattened in document order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteOutlineHeading {
+    /// Markdown heading level, 1..=6.
+    pub level: u32,
+    /// Nesting depth in the outline tree (0 = top level).
+    pub depth: u32,
+    /// Index of the parent heading in `headings`; null for top level.
+    pub parent_index: Option<u32>,
+    /// Plain heading text without markup.
+    pub text: String,
+    /// Start byte offset in note content (inclusive, UTF-8).
+    pub byte_start: u32,
+    /// End byte offset in note content (exclusive, UTF-8).
+    pub byte_end: u32,
+}
+
+/// Note outline and content statistics response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteOutlineResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Headings in document (pre-order) order.
+    pub headings: Vec<NoteOutlineHeading>,
+    /// Words in rendered text (each CJK character counts as one).
+    pub word_count: u32,
+    /// Non-whitespace characters in rendered text.
+    pub char_count: u32,
+    /// Checked GFM task list items.
+    pub checklist_done: u32,
+    /// All GFM task list items.
+    pub checklist_total: u32,
+}
+
+/// Note title projection response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteTitleResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Effective title; `None` only on failure.
+    pub title: Option<String>,
+    /// Title derived from the first heading or first line of content.
+    pub derived_title: Option<String>,
+    /// Explicit title override, if set.
+    pub title_override: Option<String>,
+}
+
+/// Note frontmatter properties response envelope.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotePropertiesResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Properties in frontmatter declaration order.
+    pub items: Vec<NotePropertyItem>,
+}
+
+/// Tag rename/merge/delete response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagEditResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Number of atoms whose tag set changed.
+    pub affected_atoms: u32,
+}
+
+/// Usage counters for one hierarchical tag path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagUsageItem {
+    /// Normalized tag path, e.g. `work/client`.
+    pub name: String,
+    /// Live atoms carrying exactly this tag.
+    pub direct_count: u32,
+    /// Live atoms carrying this tag or any descendant.
+    pub total_count: u32,
+}
+
+/// Tag usage response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagsUsageResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Tag paths sorted by name, including implicit parents.
+    pub items: Vec<TagUsageItem>,
+}
+
+/// Note revision DTO returned by revision history APIs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteRevisionItem {
+    /// Revision id, unique per database.
+    pub revision_id: i64,
+    /// Stable note atom id.
+    pub atom_id: String,
+    /// Markdown content captured by this revision.
+    pub content: String,
+    /// Preview text captured by this revision.
+    pub preview_text: Option<String>,
+    /// `updated_at` of the captured state in epoch milliseconds.
+    pub source_updated_at: i64,
+    /// Capture timestamp in epoch milliseconds.
+    pub captured_at: i64,
+}
+
+/// Note revision list response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteRevisionsListResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Revisions sorted newest first.
+    pub items: Vec<NoteRevisionItem>,
+    /// Effective limit after normalization.
+    pub applied_limit: u32,
+}
+
+/// One line of a note revision diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteDiffLineItem {
+    /// Line kind label (`unchanged|added|removed`).
+    pub kind: String,
+    /// Line text without trailing newline.
+    pub text: String,
+    /// 1-based line number in the older version.
+    pub old_line: Option<u32>,
+    /// 1-based line number in the newer version.
+    pub new_line: Option<u32>,
+}
+
+/// Note revision diff response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteRevisionDiffResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Diff lines in document order.
+    pub lines: Vec<NoteDiffLineItem>,
+}
+
+/// One wiki-link edge returned by note link APIs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteLinkItem {
+    /// Atom whose content contains the reference.
+    pub source_atom_id: String,
+    /// Target text as written inside `[[...]]`.
+    pub target: String,
+    /// Optional display label (`[[target|label]]`).
+    pub label: Option<String>,
+    /// Link kind label (`title|uuid`).
+    pub kind: String,
+    /// Resolved active target atom; `None` when unresolved.
+    pub target_atom_id: Option<String>,
+}
+
+/// Note link graph response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteLinksResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Links written in this note, in document order.
+    pub outgoing: Vec<NoteLinkItem>,
+    /// Links from other active atoms resolving to this note.
+    pub backlinks: Vec<NoteLinkItem>,
+}
+
+/// One indexed resource link (Markdown link or bare URL).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceLinkItem {
+    /// Stable link row id.
+    pub link_id: i64,
+    /// Atom whose content contains the link.
+    pub atom_id: String,
+    /// Target as written in content.
+    pub target: String,
+    /// Lowercase scheme without `:`.
+    pub scheme: String,
+    /// Optional Markdown label.
+    pub label: Option<String>,
+}
+
+/// Resource link list/search response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceLinksResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Links in document (list) or recency (search) order.
+    pub items: Vec<ResourceLinkItem>,
+}
+
+/// One vetted launch target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchPlanItem {
+    /// Source link row id.
+    pub link_id: i64,
+    /// Target kind label (`folder|file|url`).
+    pub kind: String,
+    /// Target as written in content.
+    pub target: String,
+    /// Optional Markdown label.
+    pub label: Option<String>,
+    /// Resolved local path for `folder`/`file` targets.
+    pub local_path: Option<String>,
+}
+
+/// One link excluded from a launch plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchRejectionItem {
+    /// Source link row id.
+    pub link_id: i64,
+    /// Target as written in content.
+    pub target: String,
+    /// Reason label (`scheme_not_allowed|invalid_target|not_local_path|path_not_found`).
+    pub reason: String,
+}
+
+/// Launch plan response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchPlanResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Targets to open, in launch order.
+    pub items: Vec<LaunchPlanItem>,
+    /// Links that failed validation.
+    pub rejected: Vec<LaunchRejectionItem>,
+    /// Valid targets dropped by the safety cap.
+    pub capped_count: u32,
+    /// Effective cap after normalization.
+    pub applied_max_items: u32,
+}
+
 /// Workspace action response envelope.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WorkspaceActionResponse {
@@ -381,6 +824,19 @@ pub struct WorkspaceActionResponse {
     pub message: String,
 }
 
+/// Workspace note title repair response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceRepairResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Number of note_ref nodes renamed to their note title.
+    pub renamed_count: u32,
+}
+
 /// Workspace tree node DTO exposed over FFI.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WorkspaceNodeItem {
@@ -491,7 +947,11 @@ impl WorkspaceFfiError {
 enum NotesFfiError {
     InvalidNoteId(String),
     InvalidTag(String),
+    InvalidTagExpr(String),
+    TagNotFound(String),
+    TagConflict(String),
     NoteNotFound(String),
+    RevisionNotFound(String),
     DbBusy(String),
     DbError(String),
     InvalidArgument(String),
@@ -503,7 +963,11 @@ impl NotesFfiError {
         match self {
             Self::InvalidNoteId(_) => "invalid_note_id",
             Self::InvalidTag(_) => "invalid_tag",
+            Self::InvalidTagExpr(_) => "invalid_tag_expr",
+            Self::TagNotFound(_) => "tag_not_found",
+            Self::TagConflict(_) => "tag_conflict",
             Self::NoteNotFound(_) => "note_not_found",
+            Self::RevisionNotFound(_) => "revision_not_found",
             Self::DbBusy(_) => "db_busy",
             Self::DbError(_) => "db_error",
             Self::InvalidArgument(_) => "invalid_argument",
@@ -515,7 +979,11 @@ impl NotesFfiError {
         match self {
             Self::InvalidNoteId(value) => format!("invalid note id: {value}"),
             Self::InvalidTag(value) => format!("invalid tag: {value}"),
+            Self::InvalidTagExpr(value) => format!("invalid tag expression: {value}"),
+            Self::TagNotFound(value) => format!("tag not found: {value}"),
+            Self::TagConflict(value) => format!("tag already exists: {value}"),
             Self::NoteNotFound(value) => format!("note not found: {value}"),
+            Self::RevisionNotFound(value) => format!("note revision not found: {value}"),
             Self::DbBusy(value) => format!("notes database busy: {value}"),
             Self::DbError(value) => format!("notes database error: {value}"),
             Self::InvalidArgument(value) => format!("invalid argument: {value}"),
@@ -575,10 +1043,9 @@ fn entry_search_impl(
     };
 
     let query = SearchQuery {
-        text: query_text,
         kind: parsed_kind,
         limit: normalized_limit,
-        raw_fts_syntax: false,
+        ..SearchQuery::new(query_text)
     };
 
     match search_all(&conn, &query) {
@@ -602,7 +1069,7 @@ fn entry_search_impl(
         }
         Err(err) => EntrySearchResponse {
             ok: false,
-            error_code: Some("internal_error".to_string()),
+            error_code: Some(entry_search_error_code(&err).to_string()),
             items: Vec::new(),
             message: format!("entry_search failed: {err}"),
             applied_limit: normalized_limit,
@@ -610,47 +1077,206 @@ fn entry_search_impl(
     }
 }
 
-fn parse_entry_search_kind(raw: Option<String>) -> Result<Option<AtomType>, String> {
-    let Some(value) = raw else {
-        return Ok(None);
-    };
-    let normalized = value.trim().to_ascii_lowercase();
-    if normalized.is_empty() {
-        return Err("invalid kind: blank value is not allowed".to_string());
-    }
-    if normalized == "all" {
-        return Ok(None);
-    }
-    match normalized.as_str() {
-        "note" => Ok(Some(AtomType::Note)),
-        "task" => Ok(Some(AtomType::Task)),
-        "event" => Ok(Some(AtomType::Event)),
-        _ => Err(format!(
-            "invalid kind `{value}`; expected one of all|note|task|event"
-        )),
-    }
-}
-
-/// Creates a note from single-entry command flow.
+/// Searches one page with scores, highlight ranges and a next-page cursor.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
 /// - Never panics.
-/// - Returns operation result and created atom ID on success.
+/// - `cursor`: `next_cursor` from the previous page; `None` starts over.
+/// - Same `kind`/limit rules and error codes as [`entry_search`], plus
+///   `invalid_cursor`.
 #[flutter_rust_bridge::frb]
-pub async fn entry_create_note(content: String) -> EntryActionResponse {
-    entry_create_note_impl(content)
+pub async fn entry_search_page(
+    text: String,
+    kind: Option<String>,
+    limit: Option<u32>,
+    cursor: Option<String>,
+) -> EntrySearchPageResponse {
+    entry_search_page_impl(text, kind, limit, cursor)
 }
 
-fn entry_create_note_impl(content: String) -> EntryActionResponse {
-    match with_atom_service(|service| service.create_note(content.trim().to_string())) {
-        Ok(atom_id) => EntryActionResponse::success("Note created.", atom_id.to_string()),
-        Err(err) => EntryActionResponse::failure(format!("entry_create_note failed: {err}")),
+fn entry_search_page_impl(
+    text: String,
+    kind: Option<String>,
+    limit: Option<u32>,
+    cursor: Option<String>,
+) -> EntrySearchPageResponse {
+    let normalized_limit = normalize_entry_limit(limit);
+    let failure = |code: &str, message: String| EntrySearchPageResponse {
+        ok: false,
+        error_code: Some(code.to_string()),
+        message,
+        items: Vec::new(),
+        next_cursor: None,
+        applied_limit: normalized_limit,
+    };
+    let parsed_kind = match parse_entry_search_kind(kind) {
+        Ok(parsed) => parsed,
+        Err(err) => return failure("invalid_kind", err),
+    };
+    let conn = match open_db(resolve_entry_db_path()) {
+        Ok(conn) => conn,
+        Err(err) => return failure("db_error", format!("entry_search_page failed: {err}")),
+    };
+
+    let query = SearchQuery {
+        kind: parsed_kind,
+        limit: normalized_limit,
+        cursor: cursor.filter(|value| !value.trim().is_empty()),
+        ..SearchQuery::new(text.trim())
+    };
+    match search_page(&conn, &query) {
+        Ok(page) => {
+            let items = page
+                .hits
+                .into_iter()
+                .map(to_entry_search_hit_item)
+                .collect::<Vec<_>>();
+            EntrySearchPageResponse {
+                ok: true,
+                error_code: None,
+                message: format!("Found {} result(s).", items.len()),
+                items,
+                next_cursor: page.next_cursor,
+                applied_limit: normalized_limit,
+            }
+        }
+        Err(err) => failure(
+            entry_search_error_code(&err),
+            format!("entry_search_page failed: {err}"),
+        ),
     }
 }
 
-/// Creates a task from single-entry command flow.
-///
+fn entry_search_error_code(err: &SearchError) -> &'static str {
+    match err {
+        SearchError::InvalidSyntax(_) => "invalid_query_syntax",
+        SearchError::InvalidCursor(_) => "invalid_cursor",
+        _ => "internal_error",
+    }
+}
+
+/// Parses single-entry search text with the structured query grammar.
+///
+/// # FFI contract
+/// - Sync call, no DB access.
+/// - Offsets are char indices so the UI can underline the offending span.
+/// - Returns `invalid_query_syntax` with `error_start/error_end` on failure.
+#[flutter_rust_bridge::frb(sync)]
+pub fn entry_search_check(text: String) -> EntrySearchSyntaxResponse {
+    entry_search_check_impl(text)
+}
+
+fn entry_search_check_impl(text: String) -> EntrySearchSyntaxResponse {
+    match parse_search_query(&text) {
+        Ok(parsed) => EntrySearchSyntaxResponse {
+            ok: true,
+            error_code: None,
+            syntax_error: None,
+            error_start: None,
+            error_end: None,
+            filter_count: parsed.filters.len() as u32,
+            message: "Query is valid.".to_string(),
+        },
+        Err(err) => syntax_error_response(&err),
+    }
+}
+
+fn syntax_error_response(err: &QueryParseError) -> EntrySearchSyntaxResponse {
+    EntrySearchSyntaxResponse {
+        ok: false,
+        error_code: Some("invalid_query_syntax".to_string()),
+        syntax_error: Some(err.code().to_string()),
+        error_start: Some(err.start as u32),
+        error_end: Some(err.end as u32),
+        filter_count: 0,
+        message: err.to_string(),
+    }
+}
+
+/// Parses single-entry text into a typed quick-add draft.
+///
+/// # FFI contract
+/// - Sync call, no DB access; deterministic for the same `text`, `now_ms`
+///   and configured timezone (`configure_time_zone`).
+/// - Recognizes English and Chinese dates/times, `#tags`, `!high|!medium|!low`
+///   (`!高|!中|!低`) and `task:|event:|note:` prefixes.
+/// - Never fails; unrecognized text stays in `title`. Nothing is persisted.
+#[flutter_rust_bridge::frb(sync)]
+pub fn entry_quick_add_parse(text: String, now_ms: i64) -> EntryQuickAddResponse {
+    entry_quick_add_parse_impl(text, now_ms)
+}
+
+fn entry_quick_add_parse_impl(text: String, now_ms: i64) -> EntryQuickAddResponse {
+    let draft = QuickAddParser::new(configured_calendar()).parse(&text, now_ms);
+    EntryQuickAddResponse {
+        ok: true,
+        error_code: None,
+        message: format!("Recognized {} token(s).", draft.tokens.len()),
+        kind: atom_type_label(draft.kind).to_string(),
+        title: draft.title,
+        start_at: draft.start_at,
+        end_at: draft.end_at,
+        all_day: draft.all_day,
+        tags: draft.tags,
+        priority: draft
+            .priority
+            .map(|priority| priority_label(priority).to_string()),
+        tokens: draft
+            .tokens
+            .into_iter()
+            .map(|token| EntryQuickAddToken {
+                kind: token.kind.as_str().to_string(),
+                start: token.start as u32,
+                end: token.end as u32,
+                char_start: token.char_start as u32,
+                char_end: token.char_end as u32,
+            })
+            .collect(),
+    }
+}
+
+fn parse_entry_search_kind(raw: Option<String>) -> Result<Option<AtomType>, String> {
+    let Some(value) = raw else {
+        return Ok(None);
+    };
+    let normalized = value.trim().to_ascii_lowercase();
+    if normalized.is_empty() {
+        return Err("invalid kind: blank value is not allowed".to_string());
+    }
+    if normalized == "all" {
+        return Ok(None);
+    }
+    match normalized.as_str() {
+        "note" => Ok(Some(AtomType::Note)),
+        "task" => Ok(Some(AtomType::Task)),
+        "event" => Ok(Some(AtomType::Event)),
+        _ => Err(format!(
+            "invalid kind `{value}`; expected one of all|note|task|event"
+        )),
+    }
+}
+
+/// Creates a note from single-entry command flow.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Never panics.
+/// - Returns operation result and created atom ID on success.
+#[flutter_rust_bridge::frb]
+pub async fn entry_create_note(content: String) -> EntryActionResponse {
+    entry_create_note_impl(content)
+}
+
+fn entry_create_note_impl(content: String) -> EntryActionResponse {
+    match with_atom_service(|service| service.create_note(content.trim().to_string())) {
+        Ok(atom_id) => EntryActionResponse::success("Note created.", atom_id.to_string()),
+        Err(err) => EntryActionResponse::failure(format!("entry_create_note failed: {err}")),
+    }
+}
+
+/// Creates a task from single-entry command flow.
+///
 /// # FFI contract
 /// - Async call, DB-backed execution.
 /// - Never panics.
@@ -771,12 +1397,7 @@ fn note_get_impl(atom_id: String) -> NoteResponse {
         Err(err) => return note_failure(err),
     };
 
-    match with_note_service(|service| {
-        service
-            .get_note(parsed_id)
-            .map_err(NoteServiceError::from)?
-            .ok_or(NoteServiceError::NoteNotFound(parsed_id))
-    }) {
+    match note_get_required(parsed_id) {
         Ok(note) => NoteResponse {
             ok: true,
             error_code: None,
@@ -787,1013 +1408,4945 @@ fn note_get_impl(atom_id: String) -> NoteResponse {
     }
 }
 
-/// Lists notes with optional single-tag filter and pagination.
+/// Lists captured revisions of one note, newest first.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - Returns only `AtomType::Note` rows.
-/// - Limit normalization: default 10, max 50.
+/// - Limit normalization: default 20, max 100.
+/// - Rapid autosaves are coalesced; not every `note_update` yields a revision.
 #[flutter_rust_bridge::frb]
-pub async fn notes_list(
-    tag: Option<String>,
+pub async fn note_revisions_list(
+    atom_id: String,
     limit: Option<u32>,
     offset: Option<u32>,
-) -> NotesListResponse {
-    notes_list_impl(tag, limit, offset)
+) -> NoteRevisionsListResponse {
+    note_revisions_list_impl(atom_id, limit, offset)
 }
 
-fn notes_list_impl(
-    tag: Option<String>,
+fn note_revisions_list_impl(
+    atom_id: String,
     limit: Option<u32>,
     offset: Option<u32>,
-) -> NotesListResponse {
-    let resolved_offset = offset.unwrap_or(0);
-
-    match with_note_service(|service| service.list_notes(tag, limit, resolved_offset)) {
-        Ok(result) => NotesListResponse {
+) -> NoteRevisionsListResponse {
+    let applied_limit = lazynote_core::normalize_revision_limit(limit);
+    let result = parse_note_id(atom_id.as_str()).and_then(|parsed_id| {
+        with_revision_service(|service| {
+            service.list_revisions(parsed_id, limit, offset.unwrap_or(0))
+        })
+    });
+
+    match result {
+        Ok(result) => NoteRevisionsListResponse {
             ok: true,
             error_code: None,
-            message: format!("Loaded {} note(s).", result.items.len()),
-            items: result.items.into_iter().map(to_note_item).collect(),
+            message: format!("Loaded {} revision(s).", result.items.len()),
+            items: result
+                .items
+                .into_iter()
+                .map(to_note_revision_item)
+                .collect(),
             applied_limit: result.applied_limit,
         },
-        Err(err) => NotesListResponse {
+        Err(err) => NoteRevisionsListResponse {
             ok: false,
             error_code: Some(err.code().to_string()),
             message: err.message(),
             items: Vec::new(),
-            applied_limit: lazynote_core::normalize_note_limit(limit),
+            applied_limit,
         },
     }
 }
 
-/// Atomically replaces full tag set for one note.
+/// Computes a line-level diff between two note revisions.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - `tags` is treated as complete replacement, not incremental patch.
-/// - Returns typed envelope with stable error codes.
+/// - `to_revision_id = None` diffs against the current note content.
+/// - Returns `revision_not_found` when either revision does not belong to
+///   the note.
 #[flutter_rust_bridge::frb]
-pub async fn note_set_tags(atom_id: String, tags: Vec<String>) -> NoteResponse {
-    note_set_tags_impl(atom_id, tags)
+pub async fn note_revision_diff(
+    atom_id: String,
+    from_revision_id: i64,
+    to_revision_id: Option<i64>,
+) -> NoteRevisionDiffResponse {
+    note_revision_diff_impl(atom_id, from_revision_id, to_revision_id)
 }
 
-fn note_set_tags_impl(atom_id: String, tags: Vec<String>) -> NoteResponse {
+fn note_revision_diff_impl(
+    atom_id: String,
+    from_revision_id: i64,
+    to_revision_id: Option<i64>,
+) -> NoteRevisionDiffResponse {
+    let result = parse_note_id(atom_id.as_str()).and_then(|parsed_id| {
+        with_revision_service(|service| {
+            service.diff_revisions(parsed_id, from_revision_id, to_revision_id)
+        })
+    });
+
+    match result {
+        Ok(diff) => NoteRevisionDiffResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Diff has {} line(s).", diff.lines.len()),
+            lines: diff.lines.into_iter().map(to_note_diff_line_item).collect(),
+        },
+        Err(err) => NoteRevisionDiffResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            lines: Vec::new(),
+        },
+    }
+}
+
+/// Restores one revision as the current note content.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - The replaced content is kept as a new revision, so restore is undoable.
+/// - Returns the restored note on success.
+#[flutter_rust_bridge::frb]
+pub async fn note_revision_restore(atom_id: String, revision_id: i64) -> NoteResponse {
+    note_revision_restore_impl(atom_id, revision_id)
+}
+
+fn note_revision_restore_impl(atom_id: String, revision_id: i64) -> NoteResponse {
     let parsed_id = match parse_note_id(atom_id.as_str()) {
         Ok(value) => value,
         Err(err) => return note_failure(err),
     };
+    if let Err(err) = note_get_required(parsed_id) {
+        return note_failure(err);
+    }
+    if let Err(err) =
+        with_revision_service(|service| service.restore_revision(parsed_id, revision_id))
+    {
+        return note_failure(err);
+    }
 
-    match with_note_service(|service| service.set_note_tags(parsed_id, tags)) {
+    match note_get_required(parsed_id) {
         Ok(note) => NoteResponse {
             ok: true,
             error_code: None,
-            message: "Note tags replaced.".to_string(),
+            message: "Note revision restored.".to_string(),
             note: Some(to_note_item(note)),
         },
         Err(err) => note_failure(err),
     }
 }
 
-/// Lists normalized tags known by storage.
+/// Loads outgoing wiki-links and backlinks for one note.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - Returns typed envelope with stable error codes.
+/// - Outgoing links keep document order; unresolved ones carry
+///   `target_atom_id = None`.
+/// - Backlinks exclude self-references and deleted sources.
 #[flutter_rust_bridge::frb]
-pub async fn tags_list() -> TagsListResponse {
-    tags_list_impl()
+pub async fn note_links(atom_id: String) -> NoteLinksResponse {
+    note_links_impl(atom_id)
 }
 
-fn tags_list_impl() -> TagsListResponse {
-    match with_note_service(|service| service.list_tags().map_err(NoteServiceError::from)) {
-        Ok(tags) => TagsListResponse {
+fn note_links_impl(atom_id: String) -> NoteLinksResponse {
+    let result = parse_note_id(atom_id.as_str()).and_then(|parsed_id| {
+        note_get_required(parsed_id)?;
+        with_link_service(|service| {
+            Ok((
+                service.list_outgoing_links(parsed_id)?,
+                service.list_backlinks(parsed_id)?,
+            ))
+        })
+    });
+
+    match result {
+        Ok((outgoing, backlinks)) => NoteLinksResponse {
             ok: true,
             error_code: None,
-            message: format!("Loaded {} tag(s).", tags.len()),
-            tags,
+            message: format!(
+                "Loaded {} outgoing link(s) and {} backlink(s).",
+                outgoing.len(),
+                backlinks.len()
+            ),
+            outgoing: outgoing.into_iter().map(to_note_link_item).collect(),
+            backlinks: backlinks.into_iter().map(to_note_link_item).collect(),
         },
-        Err(err) => TagsListResponse {
+        Err(err) => NoteLinksResponse {
             ok: false,
             error_code: Some(err.code().to_string()),
             message: err.message(),
-            tags: Vec::new(),
+            outgoing: Vec::new(),
+            backlinks: Vec::new(),
         },
     }
 }
 
-/// Lists workspace child nodes under optional parent.
+/// Lists Markdown links and bare URLs indexed for one note.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - `parent_node_id` is optional UUID string; `None` lists root-level nodes.
+/// - Items keep document order.
 #[flutter_rust_bridge::frb]
-pub async fn workspace_list_children(
-    parent_node_id: Option<String>,
-) -> WorkspaceListChildrenResponse {
-    workspace_list_children_impl(parent_node_id)
+pub async fn links_list(atom_id: String) -> ResourceLinksResponse {
+    links_list_impl(atom_id)
 }
 
-fn workspace_list_children_impl(parent_node_id: Option<String>) -> WorkspaceListChildrenResponse {
-    let parsed_parent = match parse_optional_parent_node_id(parent_node_id) {
-        Ok(value) => value,
-        Err(err) => return workspace_list_failure(err),
-    };
-
-    match with_tree_service(|service| service.list_children(parsed_parent)) {
-        Ok(nodes) => WorkspaceListChildrenResponse {
-            ok: true,
-            error_code: None,
-            message: format!("Loaded {} workspace node(s).", nodes.len()),
-            items: nodes.into_iter().map(to_workspace_node_item).collect(),
-        },
-        Err(err) => workspace_list_failure(err),
-    }
+fn links_list_impl(atom_id: String) -> ResourceLinksResponse {
+    let result = parse_note_id(atom_id.as_str()).and_then(|parsed_id| {
+        note_get_required(parsed_id)?;
+        with_resource_link_service(|service| service.list_links(parsed_id))
+    });
+    resource_links_response(result)
 }
 
-/// Creates one workspace folder under optional parent.
+/// Searches indexed link targets and labels across active atoms.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - `parent_node_id` is optional UUID string; `None` creates root-level folder.
+/// - Case-insensitive substring match; blank query returns no items.
+/// - Limit normalization: default 20, max 100.
 #[flutter_rust_bridge::frb]
-pub async fn workspace_create_folder(
-    parent_node_id: Option<String>,
-    name: String,
-) -> WorkspaceNodeResponse {
-    workspace_create_folder_impl(parent_node_id, name)
+pub async fn links_search(query: String, limit: Option<u32>) -> ResourceLinksResponse {
+    links_search_impl(query, limit)
 }
 
-fn workspace_create_folder_impl(
-    parent_node_id: Option<String>,
-    name: String,
-) -> WorkspaceNodeResponse {
-    let parsed_parent = match parse_optional_parent_node_id(parent_node_id) {
-        Ok(value) => value,
-        Err(err) => return workspace_node_failure(err),
-    };
-
-    match with_tree_service(|service| service.create_folder(parsed_parent, name)) {
-        Ok(node) => WorkspaceNodeResponse {
-            ok: true,
-            error_code: None,
-            message: "Workspace folder created.".to_string(),
-            node: Some(to_workspace_node_item(node)),
-        },
-        Err(err) => workspace_node_failure(err),
-    }
+fn links_search_impl(query: String, limit: Option<u32>) -> ResourceLinksResponse {
+    let result = with_resource_link_service(|service| service.search_links(&query, limit));
+    resource_links_response(result)
 }
 
-/// Creates one workspace note_ref under optional parent.
+/// Builds a vetted "open all" plan for the links of one note.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - `atom_id` must be UUID string of a note atom.
+/// - Schemes are limited to `http/https/file`; callers cannot widen it.
+/// - `file://` targets must exist locally; `max_items` defaults to 10 and
+///   clamps to 50.
+/// - UI must only open `items`; core never launches anything itself.
 #[flutter_rust_bridge::frb]
-pub async fn workspace_create_note_ref(
-    parent_node_id: Option<String>,
-    atom_id: String,
-    display_name: Option<String>,
-) -> WorkspaceNodeResponse {
-    workspace_create_note_ref_impl(parent_node_id, atom_id, display_name)
+pub async fn links_launch_plan(atom_id: String, max_items: Option<u32>) -> LaunchPlanResponse {
+    links_launch_plan_impl(atom_id, max_items)
 }
 
-fn workspace_create_note_ref_impl(
-    parent_node_id: Option<String>,
-    atom_id: String,
-    display_name: Option<String>,
-) -> WorkspaceNodeResponse {
-    let parsed_parent = match parse_optional_parent_node_id(parent_node_id) {
-        Ok(value) => value,
-        Err(err) => return workspace_node_failure(err),
-    };
-    let parsed_atom_id = match parse_workspace_atom_id(atom_id.as_str()) {
-        Ok(value) => value,
-        Err(err) => return workspace_node_failure(err),
+fn links_launch_plan_impl(atom_id: String, max_items: Option<u32>) -> LaunchPlanResponse {
+    let policy = LaunchPolicy {
+        max_items: max_items.unwrap_or(0),
+        ..LaunchPolicy::default()
     };
-
-    match with_tree_service(|service| {
-        service.create_note_ref(parsed_parent, parsed_atom_id, display_name)
-    }) {
-        Ok(node) => WorkspaceNodeResponse {
-            ok: true,
-            error_code: None,
-            message: "Workspace note reference created.".to_string(),
-            node: Some(to_workspace_node_item(node)),
+    let result = parse_note_id(atom_id.as_str()).and_then(|parsed_id| {
+        note_get_required(parsed_id)?;
+        with_resource_link_service(|service| service.plan_atom_launch(parsed_id, &policy))
+    });
+
+    match result {
+        Ok(plan) => to_launch_plan_response(plan),
+        Err(err) => LaunchPlanResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            items: Vec::new(),
+            rejected: Vec::new(),
+            capped_count: 0,
+            applied_max_items: 0,
         },
-        Err(err) => workspace_node_failure(err),
     }
 }
 
-/// Renames one workspace node.
+/// Lists notes with optional single-tag filter and pagination.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - `node_id` must be UUID string.
+/// - Returns only `AtomType::Note` rows.
+/// - Limit normalization: default 10, max 50.
 #[flutter_rust_bridge::frb]
-pub async fn workspace_rename_node(node_id: String, new_name: String) -> WorkspaceActionResponse {
-    workspace_rename_node_impl(node_id, new_name)
+pub async fn notes_list(
+    tag: Option<String>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> NotesListResponse {
+    notes_list_impl(tag, limit, offset)
 }
 
-fn workspace_rename_node_impl(node_id: String, new_name: String) -> WorkspaceActionResponse {
-    let parsed_id = match parse_workspace_node_id(node_id.as_str()) {
-        Ok(value) => value,
-        Err(err) => return workspace_failure(err),
-    };
-    match with_tree_service(|service| service.rename_node(parsed_id, new_name)) {
-        Ok(()) => WorkspaceActionResponse {
+fn notes_list_impl(
+    tag: Option<String>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> NotesListResponse {
+    let resolved_offset = offset.unwrap_or(0);
+
+    match with_note_service(|service| service.list_notes(tag, limit, resolved_offset)) {
+        Ok(result) => NotesListResponse {
             ok: true,
             error_code: None,
-            message: "Workspace node renamed.".to_string(),
+            message: format!("Loaded {} note(s).", result.items.len()),
+            items: result.items.into_iter().map(to_note_item).collect(),
+            applied_limit: result.applied_limit,
+        },
+        Err(err) => NotesListResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            items: Vec::new(),
+            applied_limit: lazynote_core::normalize_note_limit(limit),
         },
-        Err(err) => workspace_failure(err),
     }
 }
 
-/// Moves one workspace node under optional new parent and target order.
+/// Lists notes matching a boolean tag expression with pagination.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - `new_parent_id = None` moves node to root level.
+/// - `tag_expr` syntax: `(work OR client) AND NOT archived`; `AND`/`OR`/`NOT`
+///   are case-insensitive, `-tag` means `NOT tag`, adjacent tags are ANDed.
+/// - Returns `invalid_tag_expr` (message carries char offsets) on parse errors.
+/// - Limit normalization: default 10, max 50.
 #[flutter_rust_bridge::frb]
-pub async fn workspace_move_node(
-    node_id: String,
-    new_parent_id: Option<String>,
-    target_order: Option<i64>,
-) -> WorkspaceActionResponse {
-    workspace_move_node_impl(node_id, new_parent_id, target_order)
+pub async fn notes_list_by_tags(
+    tag_expr: String,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> NotesListResponse {
+    notes_list_by_tags_impl(tag_expr, limit, offset)
 }
 
-fn workspace_move_node_impl(
-    node_id: String,
-    new_parent_id: Option<String>,
-    target_order: Option<i64>,
-) -> WorkspaceActionResponse {
-    let parsed_id = match parse_workspace_node_id(node_id.as_str()) {
-        Ok(value) => value,
-        Err(err) => return workspace_failure(err),
-    };
-    let parsed_parent = match parse_optional_parent_node_id(new_parent_id) {
-        Ok(value) => value,
-        Err(err) => return workspace_failure(err),
-    };
+fn notes_list_by_tags_impl(
+    tag_expr: String,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> NotesListResponse {
+    let resolved_offset = offset.unwrap_or(0);
+    let result = parse_tag_expr(&tag_expr)
+        .map_err(|err| NotesFfiError::InvalidTagExpr(err.to_string()))
+        .and_then(|expr| {
+            with_note_service(|service| service.list_notes_by_tags(expr, limit, resolved_offset))
+        });
 
-    match with_tree_service(|service| service.move_node(parsed_id, parsed_parent, target_order)) {
-        Ok(()) => WorkspaceActionResponse {
+    match result {
+        Ok(result) => NotesListResponse {
             ok: true,
             error_code: None,
-            message: "Workspace node moved.".to_string(),
+            message: format!("Loaded {} note(s).", result.items.len()),
+            items: result.items.into_iter().map(to_note_item).collect(),
+            applied_limit: result.applied_limit,
+        },
+        Err(err) => NotesListResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            items: Vec::new(),
+            applied_limit: lazynote_core::normalize_note_limit(limit),
         },
-        Err(err) => workspace_failure(err),
     }
 }
 
-/// Deletes one workspace folder by explicit mode (`dissolve|delete_all`).
+/// Atomically replaces full tag set for one note.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - `node_id` must be UUID string of a folder node.
-/// - `mode` must be one of `dissolve` or `delete_all`.
+/// - `tags` is treated as complete replacement, not incremental patch.
+/// - Replaces manual tags only; they are kept even when the content carries
+///   the same inline `#hashtag`.
+/// - Returns typed envelope with stable error codes.
 #[flutter_rust_bridge::frb]
-pub async fn workspace_delete_folder(node_id: String, mode: String) -> WorkspaceActionResponse {
-    workspace_delete_folder_impl(node_id, mode)
+pub async fn note_set_tags(atom_id: String, tags: Vec<String>) -> NoteResponse {
+    note_set_tags_impl(atom_id, tags)
 }
 
-fn workspace_delete_folder_impl(node_id: String, mode: String) -> WorkspaceActionResponse {
-    let parsed_id = match parse_workspace_node_id(node_id.as_str()) {
-        Ok(value) => value,
-        Err(err) => return workspace_failure(err),
-    };
-
-    let parsed_mode = match parse_folder_delete_mode(mode.as_str()) {
+fn note_set_tags_impl(atom_id: String, tags: Vec<String>) -> NoteResponse {
+    let parsed_id = match parse_note_id(atom_id.as_str()) {
         Ok(value) => value,
-        Err(err) => return workspace_failure(err),
+        Err(err) => return note_failure(err),
     };
 
-    match with_tree_service(|service| service.delete_folder(parsed_id, parsed_mode)) {
-        Ok(()) => WorkspaceActionResponse {
+    match with_note_service(|service| service.set_note_tags(parsed_id, tags)) {
+        Ok(note) => NoteResponse {
             ok: true,
             error_code: None,
-            message: "Workspace folder deleted.".to_string(),
+            message: "Note tags replaced.".to_string(),
+            note: Some(to_note_item(note)),
         },
-        Err(err) => workspace_failure(err),
+        Err(err) => note_failure(err),
     }
 }
 
-fn normalize_entry_limit(limit: Option<u32>) -> u32 {
-    match limit {
-        Some(0) => ENTRY_DEFAULT_LIMIT,
-        Some(value) if value > ENTRY_SEARCH_MAX_LIMIT => ENTRY_SEARCH_MAX_LIMIT,
-        Some(value) => value,
-        None => ENTRY_DEFAULT_LIMIT,
-    }
+/// Splits one note's tags by source (manual vs inline `#hashtag`).
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `NoteItem.tags` is the union of both lists; a tag can appear in both.
+/// - Inline tags follow the content and are not removable via
+///   `note_set_tags`.
+#[flutter_rust_bridge::frb]
+pub async fn note_tag_sources(atom_id: String) -> NoteTagSourcesResponse {
+    note_tag_sources_impl(atom_id)
 }
 
-fn resolve_entry_db_path() -> PathBuf {
-    if let Ok(raw) = std::env::var("LAZYNOTE_DB_PATH") {
-        let trimmed = raw.trim();
-        if !trimmed.is_empty() {
-            return PathBuf::from(trimmed);
-        }
-    }
-
-    match ENTRY_DB_PATH_OVERRIDE.lock() {
-        Ok(guard) => {
-            if let Some(path) = guard.as_ref() {
-                return path.clone();
+fn note_tag_sources_impl(atom_id: String) -> NoteTagSourcesResponse {
+    let result = parse_note_id(atom_id.as_str())
+        .and_then(|parsed_id| with_note_service(|service| service.list_note_tags(parsed_id)));
+    match result {
+        Ok(tags) => {
+            let (inline, manual): (Vec<_>, Vec<_>) = tags
+                .into_iter()
+                .partition(|tag| tag.source == lazynote_core::TagSource::Inline);
+            NoteTagSourcesResponse {
+                ok: true,
+                error_code: None,
+                message: "Note tag sources loaded.".to_string(),
+                manual_tags: manual.into_iter().map(|tag| tag.name).collect(),
+                inline_tags: inline.into_iter().map(|tag| tag.name).collect(),
             }
         }
-        Err(_) => {
-            error!("event=db_path_resolve module=ffi status=error error_code=mutex_poisoned");
-        }
+        Err(err) => NoteTagSourcesResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            manual_tags: Vec::new(),
+            inline_tags: Vec::new(),
+        },
     }
+}
 
-    std::env::temp_dir().join(ENTRY_DB_FILE_NAME)
+/// Lists typed properties parsed from one note's YAML frontmatter.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Properties are re-derived from content on every save; empty when the
+///   note has no (or malformed) frontmatter.
+/// - Exactly one of `text` / `number` / `items` is populated per item.
+#[flutter_rust_bridge::frb]
+pub async fn note_properties(atom_id: String) -> NotePropertiesResponse {
+    note_properties_impl(atom_id)
 }
 
-fn set_configured_entry_db_path(db_path: &str) -> Result<(), String> {
-    let trimmed = db_path.trim();
-    if trimmed.is_empty() {
-        return Err("db_path must not be empty".to_string());
+fn note_properties_impl(atom_id: String) -> NotePropertiesResponse {
+    let result = parse_note_id(atom_id.as_str())
+        .and_then(|parsed_id| with_note_service(|service| service.list_note_properties(parsed_id)));
+    match result {
+        Ok(properties) => NotePropertiesResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} note properties.", properties.len()),
+            items: properties.into_iter().map(to_note_property_item).collect(),
+        },
+        Err(err) => NotePropertiesResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            items: Vec::new(),
+        },
     }
+}
 
-    let path = PathBuf::from(trimmed);
-    if !path.is_absolute() {
-        return Err("db_path must be an absolute path".to_string());
-    }
+/// Returns one note's heading outline plus word/char counts and checklist
+/// progress.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Headings come from a CommonMark/GFM parse (headings inside code blocks
+///   are ignored); byte offsets index into `NoteItem.content`.
+/// - Counts are cached per save and exclude frontmatter and markup.
+#[flutter_rust_bridge::frb]
+pub async fn note_outline(atom_id: String) -> NoteOutlineResponse {
+    note_outline_impl(atom_id)
+}
 
-    if let Some(parent) = path.parent() {
-        if !parent.as_os_str().is_empty() {
-            std::fs::create_dir_all(parent)
-                .map_err(|err| format!("failed to create db parent directory: {err}"))?;
+fn note_outline_impl(atom_id: String) -> NoteOutlineResponse {
+    let result = parse_note_id(atom_id.as_str())
+        .and_then(|parsed_id| with_note_service(|service| service.note_outline(parsed_id)));
+    match result {
+        Ok(outline) => {
+            let mut headings = Vec::new();
+            flatten_outline(&outline.headings, 0, None, &mut headings);
+            NoteOutlineResponse {
+                ok: true,
+                error_code: None,
+                message: format!("Loaded {} heading(s).", headings.len()),
+                headings,
+                word_count: outline.stats.word_count,
+                char_count: outline.stats.char_count,
+                checklist_done: outline.stats.checklist_done,
+                checklist_total: outline.stats.checklist_total,
+            }
         }
+        Err(err) => NoteOutlineResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            headings: Vec::new(),
+            word_count: 0,
+            char_count: 0,
+            checklist_done: 0,
+            checklist_total: 0,
+        },
     }
+}
 
-    let mut guard = ENTRY_DB_PATH_OVERRIDE
-        .lock()
-        .map_err(|_| "entry db path lock poisoned".to_string())?;
-    *guard = Some(path);
-    Ok(())
+/// Returns one note's title projection.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `title` = `title_override`, else `derived_title`, else `Untitled note`.
+/// - `derived_title` is the first heading, else the first text line (max 80
+///   chars), refreshed on every save.
+#[flutter_rust_bridge::frb]
+pub async fn note_title(atom_id: String) -> NoteTitleResponse {
+    note_title_impl(atom_id)
 }
 
-fn with_atom_service(
-    f: impl FnOnce(
-        &AtomService<SqliteAtomRepository<'_>>,
-    ) -> lazynote_core::RepoResult<lazynote_core::AtomId>,
-) -> Result<lazynote_core::AtomId, String> {
-    let db_path = resolve_entry_db_path();
-    let conn = open_db(&db_path).map_err(|err| format!("entry DB open failed: {err}"))?;
-    let repo = SqliteAtomRepository::try_new(&conn)
-        .map_err(|err| format!("entry repo init failed: {err}"))?;
-    let service = AtomService::new(repo);
-    f(&service).map_err(|err| err.to_string())
+fn note_title_impl(atom_id: String) -> NoteTitleResponse {
+    let result = parse_note_id(atom_id.as_str())
+        .and_then(|parsed_id| with_note_service(|service| service.note_title(parsed_id)));
+    to_note_title_response(result, "Note title loaded.")
 }
 
-fn with_note_service<T>(
-    f: impl FnOnce(&mut NoteService<SqliteNoteRepository<'_>>) -> Result<T, NoteServiceError>,
-) -> Result<T, NotesFfiError> {
-    let db_path = resolve_entry_db_path();
-    let mut conn = open_db(&db_path).map_err(map_db_error)?;
-    let repo = SqliteNoteRepository::try_new(&mut conn).map_err(map_repo_error)?;
-    let mut service = NoteService::new(repo);
-    f(&mut service).map_err(map_note_service_error)
+/// Sets or clears one note's explicit title.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `title = None` or blank clears the override; whitespace runs collapse.
+/// - Workspace note_refs that were never renamed manually take the new
+///   effective title; `[[Title]]` links re-resolve.
+#[flutter_rust_bridge::frb]
+pub async fn note_set_title(atom_id: String, title: Option<String>) -> NoteTitleResponse {
+    note_set_title_impl(atom_id, title)
 }
 
-fn with_tree_service<T>(
-    f: impl FnOnce(&TreeService<SqliteTreeRepository<'_>>) -> Result<T, TreeServiceError>,
-) -> Result<T, WorkspaceFfiError> {
-    let db_path = resolve_entry_db_path();
-    let conn = open_db(&db_path).map_err(map_workspace_db_error)?;
-    let repo = SqliteTreeRepository::try_new(&conn).map_err(map_tree_repo_error)?;
-    let service = TreeService::new(repo);
-    f(&service).map_err(map_tree_service_error)
+fn note_set_title_impl(atom_id: String, title: Option<String>) -> NoteTitleResponse {
+    let result = parse_note_id(atom_id.as_str()).and_then(|parsed_id| {
+        with_note_service(|service| service.set_note_title(parsed_id, title))
+    });
+    to_note_title_response(result, "Note title updated.")
 }
 
-fn parse_folder_delete_mode(raw: &str) -> Result<FolderDeleteMode, WorkspaceFfiError> {
-    match raw.trim() {
-        "dissolve" => Ok(FolderDeleteMode::Dissolve),
-        "delete_all" => Ok(FolderDeleteMode::DeleteAll),
-        other => Err(WorkspaceFfiError::InvalidDeleteMode(other.to_string())),
+fn to_note_title_response(
+    result: Result<NoteTitle, NotesFfiError>,
+    message: &str,
+) -> NoteTitleResponse {
+    match result {
+        Ok(title) => NoteTitleResponse {
+            ok: true,
+            error_code: None,
+            message: message.to_string(),
+            title: Some(title.title),
+            derived_title: title.derived_title,
+            title_override: title.title_override,
+        },
+        Err(err) => NoteTitleResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            title: None,
+            derived_title: None,
+            title_override: None,
+        },
     }
 }
 
-fn parse_workspace_node_id(raw: &str) -> Result<Uuid, WorkspaceFfiError> {
-    Uuid::parse_str(raw.trim()).map_err(|_| WorkspaceFfiError::InvalidNodeId(raw.to_string()))
+fn flatten_outline(
+    nodes: &[OutlineHeading],
+    depth: u32,
+    parent_index: Option<u32>,
+    out: &mut Vec<NoteOutlineHeading>,
+) {
+    for node in nodes {
+        let index = out.len() as u32;
+        out.push(NoteOutlineHeading {
+            level: u32::from(node.level),
+            depth,
+            parent_index,
+            text: node.text.clone(),
+            byte_start: node.byte_start as u32,
+            byte_end: node.byte_end as u32,
+        });
+        flatten_outline(&node.children, depth + 1, Some(index), out);
+    }
 }
 
-fn parse_optional_parent_node_id(raw: Option<String>) -> Result<Option<Uuid>, WorkspaceFfiError> {
-    match raw {
-        None => Ok(None),
-        Some(value) => {
-            let trimmed = value.trim();
-            if trimmed.is_empty() {
-                return Err(WorkspaceFfiError::InvalidParentNodeId(value));
-            }
-            Uuid::parse_str(trimmed)
-                .map(Some)
-                .map_err(|_| WorkspaceFfiError::InvalidParentNodeId(value))
+fn to_note_property_item(property: AtomProperty) -> NotePropertyItem {
+    let value_type = property.value.type_name().to_string();
+    let (text, number, items) = match property.value {
+        PropertyValue::String(value) | PropertyValue::Date(value) => {
+            (Some(value), None, Vec::new())
         }
+        PropertyValue::Number(value) => (None, Some(value), Vec::new()),
+        PropertyValue::Bool(value) => (Some(value.to_string()), None, Vec::new()),
+        PropertyValue::List(values) => (None, None, values),
+    };
+    NotePropertyItem {
+        key: property.key,
+        value_type,
+        text,
+        number,
+        items,
     }
 }
 
-fn parse_workspace_atom_id(raw: &str) -> Result<AtomId, WorkspaceFfiError> {
-    Uuid::parse_str(raw.trim()).map_err(|_| WorkspaceFfiError::InvalidAtomId(raw.to_string()))
+/// Lists normalized tags known by storage.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Returns typed envelope with stable error codes.
+#[flutter_rust_bridge::frb]
+pub async fn tags_list() -> TagsListResponse {
+    tags_list_impl()
 }
 
-fn parse_note_id(raw: &str) -> Result<AtomId, NotesFfiError> {
-    Uuid::parse_str(raw.trim()).map_err(|_| NotesFfiError::InvalidNoteId(raw.to_string()))
+fn tags_list_impl() -> TagsListResponse {
+    match with_note_service(|service| service.list_tags().map_err(NoteServiceError::from)) {
+        Ok(tags) => TagsListResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} tag(s).", tags.len()),
+            tags,
+        },
+        Err(err) => TagsListResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            tags: Vec::new(),
+        },
+    }
 }
 
-fn to_note_item(value: NoteRecord) -> NoteItem {
-    NoteItem {
-        atom_id: value.atom_id.to_string(),
-        content: value.content,
-        preview_text: value.preview_text,
-        preview_image: value.preview_image,
-        updated_at: value.updated_at,
-        tags: value.tags,
-    }
+/// Renames one tag and its `/` descendants across all atoms.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `work` -> `job` also moves `work/client` to `job/client`.
+/// - Returns `tag_not_found` when `from` has no row, `tag_conflict` when a
+///   renamed path already exists (use `tags_merge` instead).
+#[flutter_rust_bridge::frb]
+pub async fn tag_rename(from: String, to: String) -> TagEditResponse {
+    tag_rename_impl(from, to)
 }
 
-fn workspace_node_kind_label(kind: WorkspaceNodeKind) -> &'static str {
-    match kind {
+fn tag_rename_impl(from: String, to: String) -> TagEditResponse {
+    to_tag_edit_response(
+        with_note_service(|service| service.rename_tag(&from, &to)),
+        "renamed",
+    )
+}
+
+/// Merges source tags (and descendants) into one target tag.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Atoms carrying both a source and the target keep a single link.
+/// - Returns `invalid_tag` when `target` sits below a source tag.
+#[flutter_rust_bridge::frb]
+pub async fn tags_merge(sources: Vec<String>, target: String) -> TagEditResponse {
+    tags_merge_impl(sources, target)
+}
+
+fn tags_merge_impl(sources: Vec<String>, target: String) -> TagEditResponse {
+    to_tag_edit_response(
+        with_note_service(|service| service.merge_tags(sources, &target)),
+        "merged",
+    )
+}
+
+/// Removes one tag and its `/` descendants from every atom.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Returns `tag_not_found` when no matching tag exists.
+#[flutter_rust_bridge::frb]
+pub async fn tag_delete(name: String) -> TagEditResponse {
+    tag_delete_impl(name)
+}
+
+fn tag_delete_impl(name: String) -> TagEditResponse {
+    to_tag_edit_response(
+        with_note_service(|service| service.delete_tag(&name)),
+        "deleted",
+    )
+}
+
+/// Lists per-tag usage counts over live atoms.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Implicit parents (`work` for a lone `work/client`) report
+///   `direct_count = 0`.
+#[flutter_rust_bridge::frb]
+pub async fn tags_usage() -> TagsUsageResponse {
+    tags_usage_impl()
+}
+
+fn tags_usage_impl() -> TagsUsageResponse {
+    match with_note_service(|service| service.list_tag_usage().map_err(NoteServiceError::from)) {
+        Ok(usage) => TagsUsageResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} tag(s).", usage.len()),
+            items: usage
+                .into_iter()
+                .map(|tag| TagUsageItem {
+                    name: tag.name,
+                    direct_count: tag.direct_count,
+                    total_count: tag.total_count,
+                })
+                .collect(),
+        },
+        Err(err) => TagsUsageResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            items: Vec::new(),
+        },
+    }
+}
+
+fn to_tag_edit_response(result: Result<u32, NotesFfiError>, verb: &str) -> TagEditResponse {
+    match result {
+        Ok(affected_atoms) => TagEditResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Tag {verb}; {affected_atoms} atom(s) updated."),
+            affected_atoms,
+        },
+        Err(err) => TagEditResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            affected_atoms: 0,
+        },
+    }
+}
+
+/// Lists workspace child nodes under optional parent.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `parent_node_id` is optional UUID string; `None` lists root-level nodes.
+#[flutter_rust_bridge::frb]
+pub async fn workspace_list_children(
+    parent_node_id: Option<String>,
+) -> WorkspaceListChildrenResponse {
+    workspace_list_children_impl(parent_node_id)
+}
+
+fn workspace_list_children_impl(parent_node_id: Option<String>) -> WorkspaceListChildrenResponse {
+    let parsed_parent = match parse_optional_parent_node_id(parent_node_id) {
+        Ok(value) => value,
+        Err(err) => return workspace_list_failure(err),
+    };
+
+    match with_tree_service(|service| service.list_children(parsed_parent)) {
+        Ok(nodes) => WorkspaceListChildrenResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} workspace node(s).", nodes.len()),
+            items: nodes.into_iter().map(to_workspace_node_item).collect(),
+        },
+        Err(err) => workspace_list_failure(err),
+    }
+}
+
+/// Creates one workspace folder under optional parent.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `parent_node_id` is optional UUID string; `None` creates root-level folder.
+#[flutter_rust_bridge::frb]
+pub async fn workspace_create_folder(
+    parent_node_id: Option<String>,
+    name: String,
+) -> WorkspaceNodeResponse {
+    workspace_create_folder_impl(parent_node_id, name)
+}
+
+fn workspace_create_folder_impl(
+    parent_node_id: Option<String>,
+    name: String,
+) -> WorkspaceNodeResponse {
+    let parsed_parent = match parse_optional_parent_node_id(parent_node_id) {
+        Ok(value) => value,
+        Err(err) => return workspace_node_failure(err),
+    };
+
+    match with_tree_service(|service| service.create_folder(parsed_parent, name)) {
+        Ok(node) => WorkspaceNodeResponse {
+            ok: true,
+            error_code: None,
+            message: "Workspace folder created.".to_string(),
+            node: Some(to_workspace_node_item(node)),
+        },
+        Err(err) => workspace_node_failure(err),
+    }
+}
+
+/// Creates one workspace note_ref under optional parent.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `atom_id` must be UUID string of a note atom.
+/// - `display_name = None` names the node after the note title and keeps it
+///   following title changes until renamed.
+#[flutter_rust_bridge::frb]
+pub async fn workspace_create_note_ref(
+    parent_node_id: Option<String>,
+    atom_id: String,
+    display_name: Option<String>,
+) -> WorkspaceNodeResponse {
+    workspace_create_note_ref_impl(parent_node_id, atom_id, display_name)
+}
+
+fn workspace_create_note_ref_impl(
+    parent_node_id: Option<String>,
+    atom_id: String,
+    display_name: Option<String>,
+) -> WorkspaceNodeResponse {
+    let parsed_parent = match parse_optional_parent_node_id(parent_node_id) {
+        Ok(value) => value,
+        Err(err) => return workspace_node_failure(err),
+    };
+    let parsed_atom_id = match parse_workspace_atom_id(atom_id.as_str()) {
+        Ok(value) => value,
+        Err(err) => return workspace_node_failure(err),
+    };
+
+    match with_tree_service(|service| {
+        service.create_note_ref(parsed_parent, parsed_atom_id, display_name)
+    }) {
+        Ok(node) => WorkspaceNodeResponse {
+            ok: true,
+            error_code: None,
+            message: "Workspace note reference created.".to_string(),
+            node: Some(to_workspace_node_item(node)),
+        },
+        Err(err) => workspace_node_failure(err),
+    }
+}
+
+/// Renames one workspace node.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `node_id` must be UUID string.
+#[flutter_rust_bridge::frb]
+pub async fn workspace_rename_node(node_id: String, new_name: String) -> WorkspaceActionResponse {
+    workspace_rename_node_impl(node_id, new_name)
+}
+
+fn workspace_rename_node_impl(node_id: String, new_name: String) -> WorkspaceActionResponse {
+    let parsed_id = match parse_workspace_node_id(node_id.as_str()) {
+        Ok(value) => value,
+        Err(err) => return workspace_failure(err),
+    };
+    match with_tree_service(|service| service.rename_node(parsed_id, new_name)) {
+        Ok(()) => WorkspaceActionResponse {
+            ok: true,
+            error_code: None,
+            message: "Workspace node renamed.".to_string(),
+        },
+        Err(err) => workspace_failure(err),
+    }
+}
+
+/// Renames title-following note_refs whose name is stale.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Fixes legacy `Untitled note` rows from the note_ref backfill; safe to
+///   call on every startup (returns `renamed_count = 0` once repaired).
+#[flutter_rust_bridge::frb]
+pub async fn workspace_repair_note_titles() -> WorkspaceRepairResponse {
+    workspace_repair_note_titles_impl()
+}
+
+fn workspace_repair_note_titles_impl() -> WorkspaceRepairResponse {
+    match with_tree_service(|service| service.repair_note_titles()) {
+        Ok(renamed_count) => WorkspaceRepairResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Repaired {renamed_count} note reference name(s)."),
+            renamed_count,
+        },
+        Err(err) => WorkspaceRepairResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            renamed_count: 0,
+        },
+    }
+}
+
+/// Moves one workspace node under optional new parent and target order.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `new_parent_id = None` moves node to root level.
+#[flutter_rust_bridge::frb]
+pub async fn workspace_move_node(
+    node_id: String,
+    new_parent_id: Option<String>,
+    target_order: Option<i64>,
+) -> WorkspaceActionResponse {
+    workspace_move_node_impl(node_id, new_parent_id, target_order)
+}
+
+fn workspace_move_node_impl(
+    node_id: String,
+    new_parent_id: Option<String>,
+    target_order: Option<i64>,
+) -> WorkspaceActionResponse {
+    let parsed_id = match parse_workspace_node_id(node_id.as_str()) {
+        Ok(value) => value,
+        Err(err) => return workspace_failure(err),
+    };
+    let parsed_parent = match parse_optional_parent_node_id(new_parent_id) {
+        Ok(value) => value,
+        Err(err) => return workspace_failure(err),
+    };
+
+    match with_tree_service(|service| service.move_node(parsed_id, parsed_parent, target_order)) {
+        Ok(()) => WorkspaceActionResponse {
+            ok: true,
+            error_code: None,
+            message: "Workspace node moved.".to_string(),
+        },
+        Err(err) => workspace_failure(err),
+    }
+}
+
+/// Deletes one workspace folder by explicit mode (`dissolve|delete_all`).
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `node_id` must be UUID string of a folder node.
+/// - `mode` must be one of `dissolve` or `delete_all`.
+#[flutter_rust_bridge::frb]
+pub async fn workspace_delete_folder(node_id: String, mode: String) -> WorkspaceActionResponse {
+    workspace_delete_folder_impl(node_id, mode)
+}
+
+fn workspace_delete_folder_impl(node_id: String, mode: String) -> WorkspaceActionResponse {
+    let parsed_id = match parse_workspace_node_id(node_id.as_str()) {
+        Ok(value) => value,
+        Err(err) => return workspace_failure(err),
+    };
+
+    let parsed_mode = match parse_folder_delete_mode(mode.as_str()) {
+        Ok(value) => value,
+        Err(err) => return workspace_failure(err),
+    };
+
+    match with_tree_service(|service| service.delete_folder(parsed_id, parsed_mode)) {
+        Ok(()) => WorkspaceActionResponse {
+            ok: true,
+            error_code: None,
+            message: "Workspace folder deleted.".to_string(),
+        },
+        Err(err) => workspace_failure(err),
+    }
+}
+
+fn normalize_entry_limit(limit: Option<u32>) -> u32 {
+    match limit {
+        Some(0) => ENTRY_DEFAULT_LIMIT,
+        Some(value) if value > ENTRY_SEARCH_MAX_LIMIT => ENTRY_SEARCH_MAX_LIMIT,
+        Some(value) => value,
+        None => ENTRY_DEFAULT_LIMIT,
+    }
+}
+
+fn resolve_entry_db_path() -> PathBuf {
+    if let Ok(raw) = std::env::var("LAZYNOTE_DB_PATH") {
+        let trimmed = raw.trim();
+        if !trimmed.is_empty() {
+            return PathBuf::from(trimmed);
+        }
+    }
+
+    match ENTRY_DB_PATH_OVERRIDE.lock() {
+        Ok(guard) => {
+            if let Some(path) = guard.as_ref() {
+                return path.clone();
+            }
+        }
+        Err(_) => {
+            error!("event=db_path_resolve module=ffi status=error error_code=mutex_poisoned");
+        }
+    }
+
+    std::env::temp_dir().join(ENTRY_DB_FILE_NAME)
+}
+
+fn set_configured_entry_db_path(db_path: &str) -> Result<(), String> {
+    let trimmed = db_path.trim();
+    if trimmed.is_empty() {
+        return Err("db_path must not be empty".to_string());
+    }
+
+    let path = PathBuf::from(trimmed);
+    if !path.is_absolute() {
+        return Err("db_path must be an absolute path".to_string());
+    }
+
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)
+                .map_err(|err| format!("failed to create db parent directory: {err}"))?;
+        }
+    }
+
+    let mut guard = ENTRY_DB_PATH_OVERRIDE
+        .lock()
+        .map_err(|_| "entry db path lock poisoned".to_string())?;
+    *guard = Some(path);
+    Ok(())
+}
+
+fn set_configured_time_zone(time_zone: &str, week_start: &str) -> Result<(), String> {
+    let week_start = week_start.trim().parse::<Weekday>().map_err(|_| {
+        format!(
+            "invalid week_start `{}`: expected a weekday name",
+            week_start.trim()
+        )
+    })?;
+    let calendar = LocalCalendar::new(time_zone, week_start).map_err(|err| err.to_string())?;
+    let mut guard = LOCAL_CALENDAR
+        .lock()
+        .map_err(|_| "local calendar lock poisoned".to_string())?;
+    *guard = Some(calendar);
+    Ok(())
+}
+
+fn configured_calendar() -> LocalCalendar {
+    match LOCAL_CALENDAR.lock() {
+        Ok(guard) => guard.unwrap_or_default(),
+        Err(_) => {
+            error!("event=time_zone_resolve module=ffi status=error error_code=mutex_poisoned");
+            LocalCalendar::default()
+        }
+    }
+}
+
+fn with_atom_service(
+    f: impl FnOnce(
+        &AtomService<SqliteAtomRepository<'_>>,
+    ) -> lazynote_core::RepoResult<lazynote_core::AtomId>,
+) -> Result<lazynote_core::AtomId, String> {
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(|err| format!("entry DB open failed: {err}"))?;
+    let repo = SqliteAtomRepository::try_new(&conn)
+        .map_err(|err| format!("entry repo init failed: {err}"))?;
+    let service = AtomService::new(repo);
+    f(&service).map_err(|err| err.to_string())
+}
+
+fn with_note_service<T>(
+    f: impl FnOnce(&mut NoteService<SqliteNoteRepository<'_>>) -> Result<T, NoteServiceError>,
+) -> Result<T, NotesFfiError> {
+    let db_path = resolve_entry_db_path();
+    let mut conn = open_db(&db_path).map_err(map_db_error)?;
+    let repo = SqliteNoteRepository::try_new(&mut conn).map_err(map_repo_error)?;
+    let mut service = NoteService::new(repo);
+    f(&mut service).map_err(map_note_service_error)
+}
+
+fn with_revision_service<T>(
+    f: impl FnOnce(&RevisionService<SqliteRevisionRepository<'_>>) -> Result<T, RevisionServiceError>,
+) -> Result<T, NotesFfiError> {
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(map_db_error)?;
+    let repo = SqliteRevisionRepository::try_new(&conn).map_err(map_repo_error)?;
+    let service = RevisionService::new(repo);
+    f(&service).map_err(map_revision_service_error)
+}
+
+fn with_link_service<T>(
+    f: impl FnOnce(&LinkService<SqliteLinkRepository<'_>>) -> lazynote_core::RepoResult<T>,
+) -> Result<T, NotesFfiError> {
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(map_db_error)?;
+    let repo = SqliteLinkRepository::try_new(&conn).map_err(map_repo_error)?;
+    let service = LinkService::new(repo);
+    f(&service).map_err(map_repo_error)
+}
+
+fn with_resource_link_service<T>(
+    f: impl FnOnce(
+        &ResourceLinkService<SqliteResourceLinkRepository<'_>>,
+    ) -> lazynote_core::RepoResult<T>,
+) -> Result<T, NotesFfiError> {
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(map_db_error)?;
+    let repo = SqliteResourceLinkRepository::try_new(&conn).map_err(map_repo_error)?;
+    let service = ResourceLinkService::new(repo);
+    f(&service).map_err(map_repo_error)
+}
+
+fn note_get_required(atom_id: AtomId) -> Result<NoteRecord, NotesFfiError> {
+    with_note_service(|service| {
+        service
+            .get_note(atom_id)
+            .map_err(NoteServiceError::from)?
+            .ok_or(NoteServiceError::NoteNotFound(atom_id))
+    })
+}
+
+fn with_tree_service<T>(
+    f: impl FnOnce(&TreeService<SqliteTreeRepository<'_>>) -> Result<T, TreeServiceError>,
+) -> Result<T, WorkspaceFfiError> {
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(map_workspace_db_error)?;
+    let repo = SqliteTreeRepository::try_new(&conn).map_err(map_tree_repo_error)?;
+    let service = TreeService::new(repo);
+    f(&service).map_err(map_tree_service_error)
+}
+
+fn parse_folder_delete_mode(raw: &str) -> Result<FolderDeleteMode, WorkspaceFfiError> {
+    match raw.trim() {
+        "dissolve" => Ok(FolderDeleteMode::Dissolve),
+        "delete_all" => Ok(FolderDeleteMode::DeleteAll),
+        other => Err(WorkspaceFfiError::InvalidDeleteMode(other.to_string())),
+    }
+}
+
+fn parse_workspace_node_id(raw: &str) -> Result<Uuid, WorkspaceFfiError> {
+    Uuid::parse_str(raw.trim()).map_err(|_| WorkspaceFfiError::InvalidNodeId(raw.to_string()))
+}
+
+fn parse_optional_parent_node_id(raw: Option<String>) -> Result<Option<Uuid>, WorkspaceFfiError> {
+    match raw {
+        None => Ok(None),
+        Some(value) => {
+            let trimmed = value.trim();
+            if trimmed.is_empty() {
+                return Err(WorkspaceFfiError::InvalidParentNodeId(value));
+            }
+            Uuid::parse_str(trimmed)
+                .map(Some)
+                .map_err(|_| WorkspaceFfiError::InvalidParentNodeId(value))
+        }
+    }
+}
+
+fn parse_workspace_atom_id(raw: &str) -> Result<AtomId, WorkspaceFfiError> {
+    Uuid::parse_str(raw.trim()).map_err(|_| WorkspaceFfiError::InvalidAtomId(raw.to_string()))
+}
+
+fn parse_note_id(raw: &str) -> Result<AtomId, NotesFfiError> {
+    Uuid::parse_str(raw.trim()).map_err(|_| NotesFfiError::InvalidNoteId(raw.to_string()))
+}
+
+fn to_note_item(value: NoteRecord) -> NoteItem {
+    NoteItem {
+        atom_id: value.atom_id.to_string(),
+        content: value.content,
+        preview_text: value.preview_text,
+        preview_image: value.preview_image,
+        updated_at: value.updated_at,
+        tags: value.tags,
+    }
+}
+
+fn to_note_revision_item(value: AtomRevision) -> NoteRevisionItem {
+    NoteRevisionItem {
+        revision_id: value.revision_id,
+        atom_id: value.atom_id.to_string(),
+        content: value.content,
+        preview_text: value.preview_text,
+        source_updated_at: value.source_updated_at,
+        captured_at: value.captured_at,
+    }
+}
+
+fn to_note_diff_line_item(value: DiffLine) -> NoteDiffLineItem {
+    NoteDiffLineItem {
+        kind: value.kind.as_str().to_string(),
+        text: value.text,
+        old_line: value.old_line,
+        new_line: value.new_line,
+    }
+}
+
+fn to_note_link_item(value: AtomLink) -> NoteLinkItem {
+    NoteLinkItem {
+        source_atom_id: value.source_id.to_string(),
+        target: value.target,
+        label: value.label,
+        kind: match value.kind {
+            WikiLinkKind::Title => "title",
+            WikiLinkKind::Uuid => "uuid",
+        }
+        .to_string(),
+        target_atom_id: value.target_id.map(|id| id.to_string()),
+    }
+}
+
+fn to_resource_link_item(value: ResourceLink) -> ResourceLinkItem {
+    ResourceLinkItem {
+        link_id: value.id,
+        atom_id: value.atom_id.to_string(),
+        target: value.target,
+        scheme: value.scheme,
+        label: value.label,
+    }
+}
+
+fn resource_links_response(
+    result: Result<Vec<ResourceLink>, NotesFfiError>,
+) -> ResourceLinksResponse {
+    match result {
+        Ok(items) => ResourceLinksResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} link(s).", items.len()),
+            items: items.into_iter().map(to_resource_link_item).collect(),
+        },
+        Err(err) => ResourceLinksResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            items: Vec::new(),
+        },
+    }
+}
+
+fn to_launch_plan_response(plan: LaunchPlan) -> LaunchPlanResponse {
+    LaunchPlanResponse {
+        ok: true,
+        error_code: None,
+        message: format!(
+            "Planned {} target(s), rejected {}, capped {}.",
+            plan.items.len(),
+            plan.rejected.len(),
+            plan.capped_count
+        ),
+        items: plan
+            .items
+            .into_iter()
+            .map(|item| LaunchPlanItem {
+                link_id: item.link_id,
+                kind: item.kind.as_str().to_string(),
+                target: item.target,
+                label: item.label,
+                local_path: item
+                    .local_path
+                    .map(|path| path.to_string_lossy().into_owned()),
+            })
+            .collect(),
+        rejected: plan
+            .rejected
+            .into_iter()
+            .map(|item| LaunchRejectionItem {
+                link_id: item.link_id,
+                target: item.target,
+                reason: item.reason.as_str().to_string(),
+            })
+            .collect(),
+        capped_count: plan.capped_count,
+        applied_max_items: plan.applied_max_items,
+    }
+}
+
+fn workspace_node_kind_label(kind: WorkspaceNodeKind) -> &'static str {
+    match kind {
         WorkspaceNodeKind::Folder => "folder",
         WorkspaceNodeKind::NoteRef => "note_ref",
     }
 }
 
-fn to_workspace_node_item(node: WorkspaceNode) -> WorkspaceNodeItem {
-    WorkspaceNodeItem {
-        node_id: node.node_uuid.to_string(),
-        kind: workspace_node_kind_label(node.kind).to_string(),
-        parent_node_id: node.parent_uuid.map(|value| value.to_string()),
-        atom_id: node.atom_uuid.map(|value| value.to_string()),
-        display_name: node.display_name,
-        sort_order: node.sort_order,
+fn to_workspace_node_item(node: WorkspaceNode) -> WorkspaceNodeItem {
+    WorkspaceNodeItem {
+        node_id: node.node_uuid.to_string(),
+        kind: workspace_node_kind_label(node.kind).to_string(),
+        parent_node_id: node.parent_uuid.map(|value| value.to_string()),
+        atom_id: node.atom_uuid.map(|value| value.to_string()),
+        display_name: node.display_name,
+        sort_order: node.sort_order,
+    }
+}
+
+fn note_failure(error: NotesFfiError) -> NoteResponse {
+    NoteResponse {
+        ok: false,
+        error_code: Some(error.code().to_string()),
+        message: error.message(),
+        note: None,
+    }
+}
+
+fn workspace_failure(error: WorkspaceFfiError) -> WorkspaceActionResponse {
+    WorkspaceActionResponse {
+        ok: false,
+        error_code: Some(error.code().to_string()),
+        message: error.message(),
+    }
+}
+
+fn workspace_node_failure(error: WorkspaceFfiError) -> WorkspaceNodeResponse {
+    WorkspaceNodeResponse {
+        ok: false,
+        error_code: Some(error.code().to_string()),
+        message: error.message(),
+        node: None,
+    }
+}
+
+fn workspace_list_failure(error: WorkspaceFfiError) -> WorkspaceListChildrenResponse {
+    WorkspaceListChildrenResponse {
+        ok: false,
+        error_code: Some(error.code().to_string()),
+        message: error.message(),
+        items: Vec::new(),
+    }
+}
+
+fn map_note_service_error(err: NoteServiceError) -> NotesFfiError {
+    match err {
+        NoteServiceError::InvalidTag(value) => NotesFfiError::InvalidTag(value),
+        NoteServiceError::NoteNotFound(atom_id) => NotesFfiError::NoteNotFound(atom_id.to_string()),
+        NoteServiceError::TagNotFound(value) => NotesFfiError::TagNotFound(value),
+        NoteServiceError::TagConflict(value) => NotesFfiError::TagConflict(value),
+        NoteServiceError::Repo(repo_err) => map_repo_error(repo_err),
+        NoteServiceError::InconsistentState(details) => {
+            NotesFfiError::Internal(details.to_string())
+        }
+    }
+}
+
+fn map_revision_service_error(err: RevisionServiceError) -> NotesFfiError {
+    match err {
+        RevisionServiceError::AtomNotFound(atom_id) => {
+            NotesFfiError::NoteNotFound(atom_id.to_string())
+        }
+        RevisionServiceError::RevisionNotFound {
+            atom_id,
+            revision_id,
+        } => NotesFfiError::RevisionNotFound(format!("{atom_id}#{revision_id}")),
+        RevisionServiceError::Repo(repo_err) => map_repo_error(repo_err),
+    }
+}
+
+fn map_repo_error(err: lazynote_core::RepoError) -> NotesFfiError {
+    match err {
+        lazynote_core::RepoError::NotFound(atom_id) => {
+            NotesFfiError::NoteNotFound(atom_id.to_string())
+        }
+        lazynote_core::RepoError::Validation(validation) => {
+            NotesFfiError::InvalidArgument(validation.to_string())
+        }
+        lazynote_core::RepoError::Db(db_err) => map_db_error(db_err),
+        lazynote_core::RepoError::UninitializedConnection {
+            expected_version,
+            actual_version,
+        } => NotesFfiError::DbError(format!(
+            "repository requires schema {expected_version}, got {actual_version}"
+        )),
+        lazynote_core::RepoError::MissingRequiredTable(table) => {
+            NotesFfiError::DbError(format!("missing required table `{table}`"))
+        }
+        lazynote_core::RepoError::MissingRequiredColumn { table, column } => {
+            NotesFfiError::DbError(format!(
+                "missing required column `{column}` in table `{table}`"
+            ))
+        }
+        lazynote_core::RepoError::InvalidData(details) => NotesFfiError::Internal(details),
+    }
+}
+
+fn map_db_error(err: lazynote_core::db::DbError) -> NotesFfiError {
+    if is_db_busy(&err) {
+        NotesFfiError::DbBusy(err.to_string())
+    } else {
+        NotesFfiError::DbError(err.to_string())
+    }
+}
+
+fn map_workspace_db_error(err: lazynote_core::db::DbError) -> WorkspaceFfiError {
+    if is_db_busy(&err) {
+        WorkspaceFfiError::DbBusy(err.to_string())
+    } else {
+        WorkspaceFfiError::DbError(err.to_string())
+    }
+}
+
+fn map_tree_repo_error(err: TreeRepoError) -> WorkspaceFfiError {
+    match err {
+        TreeRepoError::Db(db_err) => map_workspace_db_error(db_err),
+        TreeRepoError::NodeNotFound(node_id) => {
+            WorkspaceFfiError::NodeNotFound(node_id.to_string())
+        }
+        TreeRepoError::NodeNotFolder(node_id) => {
+            WorkspaceFfiError::NodeNotFolder(node_id.to_string())
+        }
+        TreeRepoError::UninitializedConnection {
+            expected_version,
+            actual_version,
+        } => WorkspaceFfiError::DbError(format!(
+            "repository requires schema {expected_version}, got {actual_version}"
+        )),
+        TreeRepoError::MissingRequiredTable(table) => {
+            WorkspaceFfiError::DbError(format!("missing required table `{table}`"))
+        }
+        TreeRepoError::MissingRequiredColumn { table, column } => WorkspaceFfiError::DbError(
+            format!("missing required column `{column}` in table `{table}`"),
+        ),
+        TreeRepoError::InvalidData(details) => WorkspaceFfiError::Internal(details),
+    }
+}
+
+fn map_tree_service_error(err: TreeServiceError) -> WorkspaceFfiError {
+    match err {
+        TreeServiceError::InvalidDisplayName => {
+            WorkspaceFfiError::InvalidDisplayName("display name must not be blank".to_string())
+        }
+        TreeServiceError::NodeNotFound(node_id) => {
+            WorkspaceFfiError::NodeNotFound(node_id.to_string())
+        }
+        TreeServiceError::ParentNotFound(node_id) => {
+            WorkspaceFfiError::ParentNotFound(node_id.to_string())
+        }
+        TreeServiceError::ParentMustBeFolder(node_id) => {
+            WorkspaceFfiError::ParentNotFolder(node_id.to_string())
+        }
+        TreeServiceError::NodeMustBeFolder(node_id) => {
+            WorkspaceFfiError::NodeNotFolder(node_id.to_string())
+        }
+        TreeServiceError::AtomNotFound(atom_id) => {
+            WorkspaceFfiError::AtomNotFound(atom_id.to_string())
+        }
+        TreeServiceError::AtomNotNote(atom_id) => {
+            WorkspaceFfiError::AtomNotNote(atom_id.to_string())
+        }
+        TreeServiceError::CycleDetected {
+            node_uuid,
+            parent_uuid,
+        } => WorkspaceFfiError::CycleDetected(format!("node={node_uuid} parent={parent_uuid}")),
+        TreeServiceError::Repo(repo_err) => map_tree_repo_error(repo_err),
+    }
+}
+
+fn is_db_busy(err: &lazynote_core::db::DbError) -> bool {
+    matches!(
+        err,
+        lazynote_core::db::DbError::Sqlite(rusqlite::Error::SqliteFailure(sqlite_err, _))
+            if sqlite_err.code == rusqlite::ErrorCode::DatabaseBusy
+                || sqlite_err.code == rusqlite::ErrorCode::DatabaseLocked
+    )
+}
+
+fn to_entry_search_item(hit: lazynote_core::SearchHit) -> EntrySearchItem {
+    EntrySearchItem {
+        atom_id: hit.atom_id.to_string(),
+        kind: atom_type_label(hit.kind).to_string(),
+        snippet: hit.snippet,
+    }
+}
+
+fn to_entry_search_hit_item(hit: SearchHit) -> EntrySearchHitItem {
+    EntrySearchHitItem {
+        atom_id: hit.atom_id.to_string(),
+        kind: atom_type_label(hit.kind).to_string(),
+        snippet: hit.snippet,
+        highlights: hit
+            .highlights
+            .into_iter()
+            .map(|range| EntrySearchHighlight {
+                start: range.start as u32,
+                end: range.end as u32,
+                char_start: range.char_start as u32,
+                char_end: range.char_end as u32,
+            })
+            .collect(),
+        bm25: hit.bm25,
+        score: hit.score,
+    }
+}
+
+fn atom_type_label(kind: AtomType) -> &'static str {
+    match kind {
+        AtomType::Note => "note",
+        AtomType::Task => "task",
+        AtomType::Event => "event",
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tasks / Section APIs (v0.1.5)
+// ---------------------------------------------------------------------------
+
+/// Atom list item returned by section queries (Inbox/Today/Upcoming).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomListItem {
+    /// Stable atom ID in string form.
+    pub atom_id: String,
+    /// Atom projection kind (`note|task|event`).
+    pub kind: String,
+    /// Raw markdown content.
+    pub content: String,
+    /// Derived plain-text preview.
+    pub preview_text: Option<String>,
+    /// Derived first markdown image path.
+    pub preview_image: Option<String>,
+    /// Normalized lowercase tags for this atom.
+    pub tags: Vec<String>,
+    /// Epoch ms — start boundary (NULL = no start).
+    pub start_at: Option<i64>,
+    /// Epoch ms — end boundary (NULL = no end).
+    pub end_at: Option<i64>,
+    /// Current task status string, or null if statusless.
+    pub task_status: Option<String>,
+    /// Update timestamp in epoch milliseconds.
+    pub updated_at: i64,
+}
+
+/// Section list response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomListResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Section items.
+    pub items: Vec<AtomListItem>,
+    /// Effective limit after normalization.
+    pub applied_limit: u32,
+}
+
+/// Task priority/deadline response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomTaskMetaResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Target atom ID.
+    pub atom_id: Option<String>,
+    /// Priority (`low|medium|high`), or null if unprioritized.
+    pub priority: Option<String>,
+    /// Deadline in epoch ms, or null if none.
+    pub due_at: Option<i64>,
+}
+
+/// Subtask hierarchy response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubtaskResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Target task ID.
+    pub atom_id: Option<String>,
+    /// Parent task ID of the target, or null if top-level.
+    pub parent_id: Option<String>,
+    /// Active subtask IDs of the target in display order.
+    pub subtask_ids: Vec<String>,
+    /// Subtasks with status `done`.
+    pub done: u32,
+    /// Subtasks counted for progress (cancelled excluded).
+    pub total: u32,
+}
+
+/// Task dependency response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskDependencyResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Target task ID.
+    pub atom_id: Option<String>,
+    /// Whether any prerequisite is still unfinished.
+    pub blocked: bool,
+    /// Active prerequisite task IDs, oldest dependency first.
+    pub prerequisite_ids: Vec<String>,
+    /// Active task IDs depending on the target, oldest dependency first.
+    pub dependent_ids: Vec<String>,
+}
+
+/// Local day/week/month boundaries response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalTimeWindowsResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Configured IANA timezone name.
+    pub time_zone: String,
+    /// Configured first weekday (`monday|...|sunday`).
+    pub week_start: String,
+    /// First instant of the local day, epoch ms.
+    pub day_start_ms: i64,
+    /// Last instant of the local day, epoch ms.
+    pub day_end_ms: i64,
+    /// First instant of the local week, epoch ms.
+    pub week_start_ms: i64,
+    /// Last instant of the local week, epoch ms.
+    pub week_end_ms: i64,
+    /// First instant of the local month, epoch ms.
+    pub month_start_ms: i64,
+    /// Last instant of the local month, epoch ms.
+    pub month_end_ms: i64,
+}
+
+/// Atom schedule (timed or all-day) response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomScheduleResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Target atom ID.
+    pub atom_id: Option<String>,
+    /// Whether `start_at/end_at` are floating all-day dates.
+    pub all_day: bool,
+    /// Start boundary: epoch ms, or floating ms when all-day.
+    pub start_at: Option<i64>,
+    /// End boundary: epoch ms, or floating ms when all-day.
+    pub end_at: Option<i64>,
+    /// First all-day date (`YYYY-MM-DD`), or null when timed.
+    pub start_date: Option<String>,
+    /// Last all-day date (`YYYY-MM-DD`, inclusive), or null when timed.
+    pub end_date: Option<String>,
+}
+
+/// Atom kind conversion response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomConvertResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Converted atom ID (unchanged by conversion).
+    pub atom_id: Option<String>,
+    /// Resulting kind (`note|task|event`).
+    pub kind: Option<String>,
+    /// Resulting task status string, or null if statusless.
+    pub task_status: Option<String>,
+    /// Resulting start boundary in epoch ms.
+    pub start_at: Option<i64>,
+    /// Resulting end boundary in epoch ms.
+    pub end_at: Option<i64>,
+}
+
+const SECTION_DEFAULT_LIMIT: u32 = 50;
+const SECTION_LIMIT_MAX: u32 = 50;
+
+#[derive(Debug)]
+#[allow(dead_code)] // Internal reserved for future use
+enum AtomFfiError {
+    InvalidAtomId(String),
+    AtomNotFound(String),
+    InvalidStatus(String),
+    InvalidTimeRange(String),
+    InvalidArgument(String),
+    EventStartRequired(String),
+    NotATask(String),
+    CycleDetected(String),
+    NotRecurring(String),
+    OccurrenceNotFound(String),
+    DbError(String),
+    Internal(String),
+}
+
+impl AtomFfiError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidAtomId(_) => "invalid_atom_id",
+            Self::AtomNotFound(_) => "atom_not_found",
+            Self::InvalidStatus(_) => "invalid_status",
+            Self::InvalidTimeRange(_) => "invalid_time_range",
+            Self::InvalidArgument(_) => "invalid_argument",
+            Self::EventStartRequired(_) => "event_start_required",
+            Self::NotATask(_) => "not_a_task",
+            Self::CycleDetected(_) => "cycle_detected",
+            Self::NotRecurring(_) => "not_recurring",
+            Self::OccurrenceNotFound(_) => "occurrence_not_found",
+            Self::DbError(_) => "db_error",
+            Self::Internal(_) => "internal_error",
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::InvalidAtomId(v) => format!("invalid atom id: {v}"),
+            Self::AtomNotFound(v) => format!("atom not found: {v}"),
+            Self::InvalidStatus(v) => format!("invalid status: {v}"),
+            Self::InvalidTimeRange(v) => format!("invalid time range: {v}"),
+            Self::InvalidArgument(v) => format!("invalid argument: {v}"),
+            Self::EventStartRequired(v) => format!("event start required: {v}"),
+            Self::NotATask(v) => format!("atom is not a task: {v}"),
+            Self::CycleDetected(v) => format!("cycle detected: {v}"),
+            Self::NotRecurring(v) => format!("atom is not recurring: {v}"),
+            Self::OccurrenceNotFound(v) => format!("occurrence not found: {v}"),
+            Self::DbError(v) => format!("database error: {v}"),
+            Self::Internal(v) => format!("internal error: {v}"),
+        }
+    }
+}
+
+fn map_task_service_error(err: TaskServiceError) -> AtomFfiError {
+    match err {
+        TaskServiceError::AtomNotFound(id) => AtomFfiError::AtomNotFound(id.to_string()),
+        TaskServiceError::NotATask(id) => AtomFfiError::NotATask(id.to_string()),
+        TaskServiceError::SubtaskCycle {
+            child_id,
+            parent_id,
+        } => AtomFfiError::CycleDetected(format!("parent {parent_id} is inside task {child_id}")),
+        TaskServiceError::DependencyCycle {
+            task_id,
+            depends_on_id,
+        } => AtomFfiError::CycleDetected(format!("{depends_on_id} already depends on {task_id}")),
+        TaskServiceError::InvalidSubtaskOrder(id) => AtomFfiError::InvalidArgument(format!(
+            "ordered ids must be a permutation of the subtasks of {id}"
+        )),
+        TaskServiceError::Repo(lazynote_core::RepoError::Validation(
+            lazynote_core::AtomValidationError::InvalidEventWindow { start, end },
+        )) => {
+            AtomFfiError::InvalidTimeRange(format!("end_at ({end}) must be >= start_at ({start})"))
+        }
+        TaskServiceError::Repo(lazynote_core::RepoError::Validation(
+            err @ lazynote_core::AtomValidationError::InvalidAllDaySpan { .. },
+        )) => AtomFfiError::InvalidTimeRange(err.to_string()),
+        TaskServiceError::Repo(repo_err) => AtomFfiError::DbError(repo_err.to_string()),
+    }
+}
+
+fn with_task_service<T>(
+    f: impl FnOnce(&TaskService<'_, SqliteAtomRepository<'_>>) -> Result<T, TaskServiceError>,
+) -> Result<T, AtomFfiError> {
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let repo = SqliteAtomRepository::try_new_with_calendar(&conn, configured_calendar())
+        .map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let service = TaskService::new(&repo, &conn);
+    f(&service).map_err(map_task_service_error)
+}
+
+fn normalize_section_limit(limit: Option<u32>) -> u32 {
+    match limit {
+        Some(0) => SECTION_DEFAULT_LIMIT,
+        Some(v) if v > SECTION_LIMIT_MAX => SECTION_LIMIT_MAX,
+        Some(v) => v,
+        None => SECTION_DEFAULT_LIMIT,
+    }
+}
+
+fn to_atom_list_item(sa: SectionAtom) -> AtomListItem {
+    AtomListItem {
+        atom_id: sa.atom.uuid.to_string(),
+        kind: atom_type_label(sa.atom.kind).to_string(),
+        content: sa.atom.content,
+        preview_text: sa.atom.preview_text,
+        preview_image: sa.atom.preview_image,
+        tags: sa.tags,
+        start_at: sa.atom.start_at,
+        end_at: sa.atom.end_at,
+        task_status: sa.atom.task_status.map(task_status_label),
+        updated_at: sa.updated_at,
+    }
+}
+
+fn task_status_label(status: lazynote_core::TaskStatus) -> String {
+    match status {
+        lazynote_core::TaskStatus::Todo => "todo",
+        lazynote_core::TaskStatus::InProgress => "in_progress",
+        lazynote_core::TaskStatus::Done => "done",
+        lazynote_core::TaskStatus::Cancelled => "cancelled",
+    }
+    .to_string()
+}
+
+fn atom_list_failure(err: AtomFfiError, limit: u32) -> AtomListResponse {
+    AtomListResponse {
+        ok: false,
+        error_code: Some(err.code().to_string()),
+        message: err.message(),
+        items: Vec::new(),
+        applied_limit: limit,
+    }
+}
+
+/// Lists inbox atoms (both `start_at` and `end_at` NULL).
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Excludes done/cancelled atoms.
+#[flutter_rust_bridge::frb]
+pub async fn tasks_list_inbox(limit: Option<u32>, offset: Option<u32>) -> AtomListResponse {
+    tasks_list_inbox_impl(limit, offset)
+}
+
+fn tasks_list_inbox_impl(limit: Option<u32>, offset: Option<u32>) -> AtomListResponse {
+    tasks_list_inbox_with(limit, offset, SectionOptions::default())
+}
+
+/// Lists inbox atoms without blocked tasks.
+///
+/// # FFI contract
+/// - Same as `tasks_list_inbox`, minus tasks with an unfinished prerequisite.
+#[flutter_rust_bridge::frb]
+pub async fn tasks_list_inbox_unblocked(
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> AtomListResponse {
+    tasks_list_inbox_unblocked_impl(limit, offset)
+}
+
+fn tasks_list_inbox_unblocked_impl(limit: Option<u32>, offset: Option<u32>) -> AtomListResponse {
+    tasks_list_inbox_with(limit, offset, SectionOptions { hide_blocked: true })
+}
+
+fn tasks_list_inbox_with(
+    limit: Option<u32>,
+    offset: Option<u32>,
+    options: SectionOptions,
+) -> AtomListResponse {
+    let norm_limit = normalize_section_limit(limit);
+    let norm_offset = offset.unwrap_or(0);
+    match with_task_service(|svc| svc.fetch_inbox_with(norm_limit, norm_offset, options)) {
+        Ok(items) => AtomListResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} inbox item(s).", items.len()),
+            items: items.into_iter().map(to_atom_list_item).collect(),
+            applied_limit: norm_limit,
+        },
+        Err(err) => atom_list_failure(err, norm_limit),
+    }
+}
+
+/// Lists atoms active today based on time-matrix rules.
+///
+/// # FFI contract
+/// - `bod_ms`/`eod_ms`: device-local day boundaries in epoch ms.
+/// - Async call, DB-backed execution.
+/// - Excludes done/cancelled atoms.
+#[flutter_rust_bridge::frb]
+pub async fn tasks_list_today(
+    bod_ms: i64,
+    eod_ms: i64,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> AtomListResponse {
+    tasks_list_today_impl(bod_ms, eod_ms, limit, offset)
+}
+
+fn tasks_list_today_impl(
+    bod_ms: i64,
+    eod_ms: i64,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> AtomListResponse {
+    tasks_list_today_with(bod_ms, eod_ms, limit, offset, SectionOptions::default())
+}
+
+/// Lists today atoms without blocked tasks.
+///
+/// # FFI contract
+/// - Same as `tasks_list_today`, minus tasks (and recurring task series)
+///   with an unfinished prerequisite.
+#[flutter_rust_bridge::frb]
+pub async fn tasks_list_today_unblocked(
+    bod_ms: i64,
+    eod_ms: i64,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> AtomListResponse {
+    tasks_list_today_unblocked_impl(bod_ms, eod_ms, limit, offset)
+}
+
+fn tasks_list_today_unblocked_impl(
+    bod_ms: i64,
+    eod_ms: i64,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> AtomListResponse {
+    tasks_list_today_with(
+        bod_ms,
+        eod_ms,
+        limit,
+        offset,
+        SectionOptions { hide_blocked: true },
+    )
+}
+
+fn tasks_list_today_with(
+    bod_ms: i64,
+    eod_ms: i64,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    options: SectionOptions,
+) -> AtomListResponse {
+    let norm_limit = normalize_section_limit(limit);
+    let norm_offset = offset.unwrap_or(0);
+    match with_task_service(|svc| {
+        svc.fetch_today_with(bod_ms, eod_ms, norm_limit, norm_offset, options)
+    }) {
+        Ok(items) => AtomListResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} today item(s).", items.len()),
+            items: items.into_iter().map(to_atom_list_item).collect(),
+            applied_limit: norm_limit,
+        },
+        Err(err) => atom_list_failure(err, norm_limit),
+    }
+}
+
+/// Lists atoms anchored entirely in the future.
+///
+/// # FFI contract
+/// - `eod_ms`: end of today in epoch ms.
+/// - Async call, DB-backed execution.
+/// - Excludes done/cancelled atoms.
+#[flutter_rust_bridge::frb]
+pub async fn tasks_list_upcoming(
+    eod_ms: i64,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> AtomListResponse {
+    tasks_list_upcoming_impl(eod_ms, limit, offset)
+}
+
+fn tasks_list_upcoming_impl(
+    eod_ms: i64,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> AtomListResponse {
+    let norm_limit = normalize_section_limit(limit);
+    let norm_offset = offset.unwrap_or(0);
+    match with_task_service(|svc| svc.fetch_upcoming(eod_ms, norm_limit, norm_offset)) {
+        Ok(items) => AtomListResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} upcoming item(s).", items.len()),
+            items: items.into_iter().map(to_atom_list_item).collect(),
+            applied_limit: norm_limit,
+        },
+        Err(err) => atom_list_failure(err, norm_limit),
+    }
+}
+
+/// Lists atoms active on the local day of `now_ms`.
+///
+/// # FFI contract
+/// - `now_ms`: current instant in epoch ms; the day window is computed by
+///   Rust Core in the timezone set via `configure_time_zone` (DST-aware).
+/// - All-day atoms match by local date and are listed before timed atoms.
+/// - `hide_blocked`: omit tasks with an unfinished prerequisite.
+/// - Async call, DB-backed execution. Excludes done/cancelled atoms.
+#[flutter_rust_bridge::frb]
+pub async fn tasks_list_today_local(
+    now_ms: i64,
+    hide_blocked: bool,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> AtomListResponse {
+    tasks_list_today_local_impl(now_ms, hide_blocked, limit, offset)
+}
+
+fn tasks_list_today_local_impl(
+    now_ms: i64,
+    hide_blocked: bool,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> AtomListResponse {
+    let norm_limit = normalize_section_limit(limit);
+    let norm_offset = offset.unwrap_or(0);
+    let calendar = configured_calendar();
+    let options = SectionOptions { hide_blocked };
+    match with_task_service(|svc| {
+        svc.fetch_today_in(&calendar, now_ms, norm_limit, norm_offset, options)
+    }) {
+        Ok(items) => AtomListResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} today item(s).", items.len()),
+            items: items.into_iter().map(to_atom_list_item).collect(),
+            applied_limit: norm_limit,
+        },
+        Err(err) => atom_list_failure(err, norm_limit),
+    }
+}
+
+/// Lists atoms anchored after the local day of `now_ms`.
+///
+/// # FFI contract
+/// - `now_ms`: current instant in epoch ms; see `tasks_list_today_local`.
+/// - All-day atoms sort at their local midnight.
+/// - Async call, DB-backed execution. Excludes done/cancelled atoms.
+#[flutter_rust_bridge::frb]
+pub async fn tasks_list_upcoming_local(
+    now_ms: i64,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> AtomListResponse {
+    tasks_list_upcoming_local_impl(now_ms, limit, offset)
+}
+
+fn tasks_list_upcoming_local_impl(
+    now_ms: i64,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> AtomListResponse {
+    let norm_limit = normalize_section_limit(limit);
+    let norm_offset = offset.unwrap_or(0);
+    let calendar = configured_calendar();
+    match with_task_service(|svc| svc.fetch_upcoming_in(&calendar, now_ms, norm_limit, norm_offset))
+    {
+        Ok(items) => AtomListResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} upcoming item(s).", items.len()),
+            items: items.into_iter().map(to_atom_list_item).collect(),
+            applied_limit: norm_limit,
+        },
+        Err(err) => atom_list_failure(err, norm_limit),
+    }
+}
+
+/// Returns local day, week and month boundaries containing `now_ms`.
+///
+/// # FFI contract
+/// - Sync call, non-blocking; no DB access.
+/// - Uses the timezone and week start set via `configure_time_zone`.
+/// - Boundaries are inclusive epoch ms; DST days are 23h or 25h long.
+#[flutter_rust_bridge::frb(sync)]
+pub fn local_time_windows(now_ms: i64) -> LocalTimeWindowsResponse {
+    local_time_windows_impl(now_ms)
+}
+
+fn local_time_windows_impl(now_ms: i64) -> LocalTimeWindowsResponse {
+    let calendar = configured_calendar();
+    let day = calendar.day_window(now_ms);
+    let week = calendar.week_window(now_ms);
+    let month = calendar.month_window(now_ms);
+    LocalTimeWindowsResponse {
+        ok: true,
+        error_code: None,
+        message: "Computed.".to_string(),
+        time_zone: calendar.time_zone_name().to_string(),
+        week_start: weekday_label(calendar.week_start()).to_string(),
+        day_start_ms: day.start_ms,
+        day_end_ms: day.end_ms,
+        week_start_ms: week.start_ms,
+        week_end_ms: week.end_ms,
+        month_start_ms: month.start_ms,
+        month_end_ms: month.end_ms,
+    }
+}
+
+fn weekday_label(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "monday",
+        Weekday::Tue => "tuesday",
+        Weekday::Wed => "wednesday",
+        Weekday::Thu => "thursday",
+        Weekday::Fri => "friday",
+        Weekday::Sat => "saturday",
+        Weekday::Sun => "sunday",
+    }
+}
+
+/// Reads whether one atom is timed or all-day, with its bounds.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `atom_not_found` for missing or deleted atoms.
+#[flutter_rust_bridge::frb]
+pub async fn atom_schedule(atom_id: String) -> AtomScheduleResponse {
+    atom_schedule_impl(atom_id)
+}
+
+fn atom_schedule_impl(atom_id: String) -> AtomScheduleResponse {
+    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
+        Ok(id) => id,
+        Err(_) => return atom_schedule_failure(AtomFfiError::InvalidAtomId(atom_id)),
+    };
+    atom_schedule_result(with_task_service(|svc| svc.get_atom(parsed_id)), "Loaded.")
+}
+
+/// Makes any atom all-day over local dates `start_date..=end_date`.
+///
+/// # FFI contract
+/// - Dates are `YYYY-MM-DD`; `end_date` is inclusive and defaults to
+///   `start_date`.
+/// - Stored bounds are floating: the atom covers the same dates in every
+///   timezone. `calendar_update_event` makes it timed again.
+/// - `invalid_argument` for malformed dates; `invalid_time_range` when
+///   `end_date < start_date`.
+/// - Async call, DB-backed execution.
+#[flutter_rust_bridge::frb]
+pub async fn atom_set_all_day(
+    atom_id: String,
+    start_date: String,
+    end_date: Option<String>,
+) -> AtomScheduleResponse {
+    atom_set_all_day_impl(atom_id, start_date, end_date)
+}
+
+fn atom_set_all_day_impl(
+    atom_id: String,
+    start_date: String,
+    end_date: Option<String>,
+) -> AtomScheduleResponse {
+    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
+        Ok(id) => id,
+        Err(_) => return atom_schedule_failure(AtomFfiError::InvalidAtomId(atom_id)),
+    };
+    let first = match parse_local_date(&start_date) {
+        Ok(date) => date,
+        Err(err) => return atom_schedule_failure(err),
+    };
+    let last = match end_date.as_deref().map(parse_local_date) {
+        None => first,
+        Some(Ok(date)) => date,
+        Some(Err(err)) => return atom_schedule_failure(err),
+    };
+    atom_schedule_result(
+        with_task_service(|svc| svc.set_all_day(parsed_id, first, last)),
+        "All-day dates updated.",
+    )
+}
+
+fn parse_local_date(value: &str) -> Result<NaiveDate, AtomFfiError> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").map_err(|_| {
+        AtomFfiError::InvalidArgument(format!("date `{}` must be YYYY-MM-DD", value.trim()))
+    })
+}
+
+fn atom_schedule_result(
+    result: Result<lazynote_core::Atom, AtomFfiError>,
+    message: &str,
+) -> AtomScheduleResponse {
+    match result {
+        Ok(atom) => {
+            let dates = if atom.all_day {
+                floating_dates(atom.start_at, atom.end_at)
+            } else {
+                None
+            };
+            AtomScheduleResponse {
+                ok: true,
+                error_code: None,
+                message: message.to_string(),
+                atom_id: Some(atom.uuid.to_string()),
+                all_day: atom.all_day,
+                start_at: atom.start_at,
+                end_at: atom.end_at,
+                start_date: dates.map(|(first, _)| first.format("%Y-%m-%d").to_string()),
+                end_date: dates.map(|(_, last)| last.format("%Y-%m-%d").to_string()),
+            }
+        }
+        Err(err) => atom_schedule_failure(err),
+    }
+}
+
+fn atom_schedule_failure(err: AtomFfiError) -> AtomScheduleResponse {
+    AtomScheduleResponse {
+        ok: false,
+        error_code: Some(err.code().to_string()),
+        message: err.message(),
+        atom_id: None,
+        all_day: false,
+        start_at: None,
+        end_at: None,
+        start_date: None,
+        end_date: None,
+    }
+}
+
+/// Updates `task_status` for any atom type (universal completion).
+///
+/// # FFI contract
+/// - `status`: one of `todo|in_progress|done|cancelled`, or null to clear (demote).
+/// - Async call, DB-backed execution.
+/// - Idempotent: setting the same status twice succeeds.
+#[flutter_rust_bridge::frb]
+pub async fn atom_update_status(atom_id: String, status: Option<String>) -> EntryActionResponse {
+    atom_update_status_impl(atom_id, status)
+}
+
+fn atom_update_status_impl(atom_id: String, status: Option<String>) -> EntryActionResponse {
+    atom_update_status_with_impl(atom_id, status, None)
+}
+
+/// Updates `task_status`, optionally stopping running timers on completion.
+///
+/// # FFI contract
+/// - Same as `atom_update_status`.
+/// - `stop_timer_at`: when set, this device's running timers on every atom
+///   that becomes `done` (auto-completed parents included) stop at this
+///   epoch ms; null leaves timers running.
+#[flutter_rust_bridge::frb]
+pub async fn atom_update_status_with(
+    atom_id: String,
+    status: Option<String>,
+    stop_timer_at: Option<i64>,
+) -> EntryActionResponse {
+    atom_update_status_with_impl(atom_id, status, stop_timer_at)
+}
+
+fn atom_update_status_with_impl(
+    atom_id: String,
+    status: Option<String>,
+    stop_timer_at: Option<i64>,
+) -> EntryActionResponse {
+    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
+        Ok(id) => id,
+        Err(_) => {
+            let err = AtomFfiError::InvalidAtomId(atom_id);
+            return EntryActionResponse {
+                ok: false,
+                atom_id: None,
+                message: err.message(),
+            };
+        }
+    };
+
+    let parsed_status = match status.as_deref() {
+        None => None,
+        Some("todo") => Some(lazynote_core::TaskStatus::Todo),
+        Some("in_progress") => Some(lazynote_core::TaskStatus::InProgress),
+        Some("done") => Some(lazynote_core::TaskStatus::Done),
+        Some("cancelled") => Some(lazynote_core::TaskStatus::Cancelled),
+        Some(other) => {
+            let err = AtomFfiError::InvalidStatus(other.to_string());
+            return EntryActionResponse {
+                ok: false,
+                atom_id: None,
+                message: err.message(),
+            };
+        }
+    };
+
+    let options = StatusUpdateOptions { stop_timer_at };
+    match with_task_service(|svc| svc.update_status_with(parsed_id, parsed_status, options)) {
+        Ok(()) => EntryActionResponse {
+            ok: true,
+            atom_id: Some(parsed_id.to_string()),
+            message: "Status updated.".to_string(),
+        },
+        Err(err) => EntryActionResponse {
+            ok: false,
+            atom_id: None,
+            message: err.message(),
+        },
+    }
+}
+
+/// Lists incomplete atoms past their deadline, highest priority first.
+///
+/// # FFI contract
+/// - `now_ms`: current time in epoch ms; deadlines strictly before it are
+///   overdue.
+/// - Deadline = `due_at`, else `end_at` of a deadline-only atom.
+/// - Order: priority `high > medium > low > none`, then earliest deadline.
+/// - Async call, DB-backed execution. Excludes done/cancelled atoms.
+#[flutter_rust_bridge::frb]
+pub async fn tasks_list_overdue(
+    now_ms: i64,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> AtomListResponse {
+    tasks_list_overdue_impl(now_ms, limit, offset)
+}
+
+fn tasks_list_overdue_impl(
+    now_ms: i64,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> AtomListResponse {
+    let norm_limit = normalize_section_limit(limit);
+    let norm_offset = offset.unwrap_or(0);
+    match with_task_service(|svc| svc.fetch_overdue(now_ms, norm_limit, norm_offset)) {
+        Ok(items) => AtomListResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} overdue item(s).", items.len()),
+            items: items.into_iter().map(to_atom_list_item).collect(),
+            applied_limit: norm_limit,
+        },
+        Err(err) => atom_list_failure(err, norm_limit),
+    }
+}
+
+/// Reads priority and deadline of one atom.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `atom_not_found` for missing or deleted atoms.
+#[flutter_rust_bridge::frb]
+pub async fn atom_task_meta(atom_id: String) -> AtomTaskMetaResponse {
+    atom_task_meta_impl(atom_id)
+}
+
+fn atom_task_meta_impl(atom_id: String) -> AtomTaskMetaResponse {
+    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
+        Ok(id) => id,
+        Err(_) => return atom_task_meta_failure(AtomFfiError::InvalidAtomId(atom_id)),
+    };
+    atom_task_meta_result(with_task_service(|svc| svc.get_atom(parsed_id)), "Loaded.")
+}
+
+/// Sets or clears the priority of any atom type.
+///
+/// # FFI contract
+/// - `priority`: one of `low|medium|high`, or null to clear.
+/// - Async call, DB-backed execution. Idempotent.
+#[flutter_rust_bridge::frb]
+pub async fn atom_set_priority(atom_id: String, priority: Option<String>) -> AtomTaskMetaResponse {
+    atom_set_priority_impl(atom_id, priority)
+}
+
+fn atom_set_priority_impl(atom_id: String, priority: Option<String>) -> AtomTaskMetaResponse {
+    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
+        Ok(id) => id,
+        Err(_) => return atom_task_meta_failure(AtomFfiError::InvalidAtomId(atom_id)),
+    };
+    let parsed_priority = match priority.as_deref().map(str::trim) {
+        None => None,
+        Some("low") => Some(TaskPriority::Low),
+        Some("medium") => Some(TaskPriority::Medium),
+        Some("high") => Some(TaskPriority::High),
+        Some(other) => {
+            return atom_task_meta_failure(AtomFfiError::InvalidArgument(format!(
+                "priority `{other}` must be one of low|medium|high"
+            )))
+        }
+    };
+    atom_task_meta_result(
+        with_task_service(|svc| {
+            svc.set_priority(parsed_id, parsed_priority)?;
+            svc.get_atom(parsed_id)
+        }),
+        "Priority updated.",
+    )
+}
+
+/// Sets or clears the `due_at` deadline of any atom type.
+///
+/// # FFI contract
+/// - `due_at`: epoch ms, or null to clear. Independent of `start_at/end_at`.
+/// - Async call, DB-backed execution. Idempotent.
+#[flutter_rust_bridge::frb]
+pub async fn atom_set_due(atom_id: String, due_at: Option<i64>) -> AtomTaskMetaResponse {
+    atom_set_due_impl(atom_id, due_at)
+}
+
+fn atom_set_due_impl(atom_id: String, due_at: Option<i64>) -> AtomTaskMetaResponse {
+    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
+        Ok(id) => id,
+        Err(_) => return atom_task_meta_failure(AtomFfiError::InvalidAtomId(atom_id)),
+    };
+    atom_task_meta_result(
+        with_task_service(|svc| {
+            svc.set_due_at(parsed_id, due_at)?;
+            svc.get_atom(parsed_id)
+        }),
+        "Deadline updated.",
+    )
+}
+
+fn atom_task_meta_result(
+    result: Result<lazynote_core::Atom, AtomFfiError>,
+    message: &str,
+) -> AtomTaskMetaResponse {
+    match result {
+        Ok(atom) => AtomTaskMetaResponse {
+            ok: true,
+            error_code: None,
+            message: message.to_string(),
+            atom_id: Some(atom.uuid.to_string()),
+            priority: atom
+                .priority
+                .map(|priority| priority_label(priority).to_string()),
+            due_at: atom.due_at,
+        },
+        Err(err) => atom_task_meta_failure(err),
+    }
+}
+
+fn priority_label(priority: TaskPriority) -> &'static str {
+    match priority {
+        TaskPriority::Low => "low",
+        TaskPriority::Medium => "medium",
+        TaskPriority::High => "high",
+    }
+}
+
+fn atom_task_meta_failure(err: AtomFfiError) -> AtomTaskMetaResponse {
+    AtomTaskMetaResponse {
+        ok: false,
+        error_code: Some(err.code().to_string()),
+        message: err.message(),
+        atom_id: None,
+        priority: None,
+        due_at: None,
+    }
+}
+
+/// Lists the active subtasks of one task in display order.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution. Not paginated; `applied_limit` echoes
+///   the item count.
+/// - Includes done/cancelled subtasks; excludes deleted ones.
+#[flutter_rust_bridge::frb]
+pub async fn tasks_list_subtasks(parent_id: String) -> AtomListResponse {
+    tasks_list_subtasks_impl(parent_id)
+}
+
+fn tasks_list_subtasks_impl(parent_id: String) -> AtomListResponse {
+    let parsed_id = match Uuid::parse_str(parent_id.trim()) {
+        Ok(id) => id,
+        Err(_) => return atom_list_failure(AtomFfiError::InvalidAtomId(parent_id), 0),
+    };
+    match with_task_service(|svc| svc.list_subtasks(parsed_id)) {
+        Ok(items) => AtomListResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} subtask(s).", items.len()),
+            applied_limit: items.len() as u32,
+            items: items.into_iter().map(to_atom_list_item).collect(),
+        },
+        Err(err) => atom_list_failure(err, 0),
+    }
+}
+
+/// Reads parent, subtask order and completion progress of one task.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `total` excludes cancelled subtasks.
+#[flutter_rust_bridge::frb]
+pub async fn task_subtasks(atom_id: String) -> SubtaskResponse {
+    task_subtasks_impl(atom_id)
+}
+
+fn task_subtasks_impl(atom_id: String) -> SubtaskResponse {
+    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
+        Ok(id) => id,
+        Err(_) => return subtask_failure(AtomFfiError::InvalidAtomId(atom_id)),
+    };
+    subtask_result(parsed_id, |_| Ok(()), "Loaded.")
+}
+
+/// Moves a task under a parent task, or back to top level.
+///
+/// # FFI contract
+/// - Both atoms must be tasks (`not_a_task` otherwise).
+/// - `parent_id`: null detaches the task.
+/// - `target_index`: position among siblings; null or out of range appends.
+/// - `cycle_detected` when the parent is the task itself or one of its
+///   descendants.
+/// - Response describes the moved task.
+#[flutter_rust_bridge::frb]
+pub async fn subtask_set_parent(
+    atom_id: String,
+    parent_id: Option<String>,
+    target_index: Option<u32>,
+) -> SubtaskResponse {
+    subtask_set_parent_impl(atom_id, parent_id, target_index)
+}
+
+fn subtask_set_parent_impl(
+    atom_id: String,
+    parent_id: Option<String>,
+    target_index: Option<u32>,
+) -> SubtaskResponse {
+    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
+        Ok(id) => id,
+        Err(_) => return subtask_failure(AtomFfiError::InvalidAtomId(atom_id)),
+    };
+    let parsed_parent = match parent_id {
+        None => None,
+        Some(raw) => match Uuid::parse_str(raw.trim()) {
+            Ok(id) => Some(id),
+            Err(_) => return subtask_failure(AtomFfiError::InvalidAtomId(raw)),
+        },
+    };
+    subtask_result(
+        parsed_id,
+        |svc| svc.set_parent(parsed_id, parsed_parent, target_index),
+        "Parent updated.",
+    )
+}
+
+/// Rewrites the subtask order of one task.
+///
+/// # FFI contract
+/// - `ordered_ids` must list every active subtask exactly once
+///   (`invalid_argument` otherwise).
+#[flutter_rust_bridge::frb]
+pub async fn subtasks_reorder(parent_id: String, ordered_ids: Vec<String>) -> SubtaskResponse {
+    subtasks_reorder_impl(parent_id, ordered_ids)
+}
+
+fn subtasks_reorder_impl(parent_id: String, ordered_ids: Vec<String>) -> SubtaskResponse {
+    let parsed_id = match Uuid::parse_str(parent_id.trim()) {
+        Ok(id) => id,
+        Err(_) => return subtask_failure(AtomFfiError::InvalidAtomId(parent_id)),
+    };
+    let mut parsed_order = Vec::with_capacity(ordered_ids.len());
+    for raw in ordered_ids {
+        match Uuid::parse_str(raw.trim()) {
+            Ok(id) => parsed_order.push(id),
+            Err(_) => return subtask_failure(AtomFfiError::InvalidAtomId(raw)),
+        }
+    }
+    subtask_result(
+        parsed_id,
+        |svc| svc.reorder_subtasks(parsed_id, &parsed_order),
+        "Subtasks reordered.",
+    )
+}
+
+/// Turns the top-level `- [ ]`/`- [x]` items of a task into subtasks.
+///
+/// # FFI contract
+/// - Checked items become `done` subtasks; items are removed from the
+///   parent content and appended after existing subtasks.
+/// - Returns the full subtask list; `message` reports the promoted count.
+#[flutter_rust_bridge::frb]
+pub async fn task_promote_checklist(atom_id: String) -> SubtaskResponse {
+    task_promote_checklist_impl(atom_id)
+}
+
+fn task_promote_checklist_impl(atom_id: String) -> SubtaskResponse {
+    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
+        Ok(id) => id,
+        Err(_) => return subtask_failure(AtomFfiError::InvalidAtomId(atom_id)),
+    };
+    let mut promoted = 0usize;
+    let mut response = subtask_result(
+        parsed_id,
+        |svc| {
+            promoted = svc.promote_checklist(parsed_id)?.len();
+            Ok(())
+        },
+        "",
+    );
+    if response.ok {
+        response.message = format!("Promoted {promoted} checklist item(s).");
+    }
+    response
+}
+
+fn subtask_result(
+    atom_id: AtomId,
+    action: impl FnOnce(&TaskService<'_, SqliteAtomRepository<'_>>) -> Result<(), TaskServiceError>,
+    message: &str,
+) -> SubtaskResponse {
+    let result = with_task_service(|svc| {
+        action(svc)?;
+        svc.get_atom(atom_id)?;
+        let parent = svc.parent_task(atom_id)?;
+        let subtasks = svc.list_subtasks(atom_id)?;
+        let progress = svc.subtask_progress(atom_id)?;
+        Ok((parent, subtasks, progress))
+    });
+    match result {
+        Ok((parent, subtasks, progress)) => SubtaskResponse {
+            ok: true,
+            error_code: None,
+            message: message.to_string(),
+            atom_id: Some(atom_id.to_string()),
+            parent_id: parent.map(|id| id.to_string()),
+            subtask_ids: subtasks
+                .into_iter()
+                .map(|item| item.atom.uuid.to_string())
+                .collect(),
+            done: progress.done,
+            total: progress.total,
+        },
+        Err(err) => subtask_failure(err),
+    }
+}
+
+fn subtask_failure(err: AtomFfiError) -> SubtaskResponse {
+    SubtaskResponse {
+        ok: false,
+        error_code: Some(err.code().to_string()),
+        message: err.message(),
+        atom_id: None,
+        parent_id: None,
+        subtask_ids: Vec::new(),
+        done: 0,
+        total: 0,
+    }
+}
+
+/// Reads blocked state, prerequisites and dependents of one task.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `not_a_task` when the atom is not a task.
+#[flutter_rust_bridge::frb]
+pub async fn task_dependencies(atom_id: String) -> TaskDependencyResponse {
+    task_dependencies_impl(atom_id)
+}
+
+fn task_dependencies_impl(atom_id: String) -> TaskDependencyResponse {
+    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
+        Ok(id) => id,
+        Err(_) => return task_dependency_failure(AtomFfiError::InvalidAtomId(atom_id)),
+    };
+    task_dependency_result(parsed_id, |_| Ok(()), "Loaded.")
+}
+
+/// Makes a task depend on a prerequisite task.
+///
+/// # FFI contract
+/// - Both atoms must be tasks (`not_a_task` otherwise).
+/// - `cycle_detected` when the prerequisite is the task itself or already
+///   depends on it, directly or transitively.
+/// - Idempotent: adding an existing dependency succeeds.
+#[flutter_rust_bridge::frb]
+pub async fn task_add_dependency(atom_id: String, depends_on_id: String) -> TaskDependencyResponse {
+    task_add_dependency_impl(atom_id, depends_on_id)
+}
+
+fn task_add_dependency_impl(atom_id: String, depends_on_id: String) -> TaskDependencyResponse {
+    let (task_id, prerequisite_id) = match parse_dependency_pair(atom_id, depends_on_id) {
+        Ok(ids) => ids,
+        Err(err) => return task_dependency_failure(err),
+    };
+    task_dependency_result(
+        task_id,
+        |svc| svc.add_dependency(task_id, prerequisite_id).map(|_| ()),
+        "Dependency added.",
+    )
+}
+
+/// Removes a dependency between two tasks.
+///
+/// # FFI contract
+/// - Idempotent: removing a missing dependency succeeds.
+#[flutter_rust_bridge::frb]
+pub async fn task_remove_dependency(
+    atom_id: String,
+    depends_on_id: String,
+) -> TaskDependencyResponse {
+    task_remove_dependency_impl(atom_id, depends_on_id)
+}
+
+fn task_remove_dependency_impl(atom_id: String, depends_on_id: String) -> TaskDependencyResponse {
+    let (task_id, prerequisite_id) = match parse_dependency_pair(atom_id, depends_on_id) {
+        Ok(ids) => ids,
+        Err(err) => return task_dependency_failure(err),
+    };
+    task_dependency_result(
+        task_id,
+        |svc| svc.remove_dependency(task_id, prerequisite_id).map(|_| ()),
+        "Dependency removed.",
+    )
+}
+
+/// Lists the subtask tree of a project task in dependency order.
+///
+/// # FFI contract
+/// - Prerequisites come before their dependents; ties keep outline order.
+/// - Includes all statuses; not paginated (`applied_limit` echoes the item
+///   count).
+#[flutter_rust_bridge::frb]
+pub async fn tasks_list_project_order(project_id: String) -> AtomListResponse {
+    tasks_list_project_order_impl(project_id)
+}
+
+fn tasks_list_project_order_impl(project_id: String) -> AtomListResponse {
+    let parsed_id = match Uuid::parse_str(project_id.trim()) {
+        Ok(id) => id,
+        Err(_) => return atom_list_failure(AtomFfiError::InvalidAtomId(project_id), 0),
+    };
+    match with_task_service(|svc| svc.topological_order(parsed_id)) {
+        Ok(items) => AtomListResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} project task(s).", items.len()),
+            applied_limit: items.len() as u32,
+            items: items.into_iter().map(to_atom_list_item).collect(),
+        },
+        Err(err) => atom_list_failure(err, 0),
+    }
+}
+
+fn parse_dependency_pair(
+    atom_id: String,
+    depends_on_id: String,
+) -> Result<(AtomId, AtomId), AtomFfiError> {
+    let task_id =
+        Uuid::parse_str(atom_id.trim()).map_err(|_| AtomFfiError::InvalidAtomId(atom_id))?;
+    let prerequisite_id = Uuid::parse_str(depends_on_id.trim())
+        .map_err(|_| AtomFfiError::InvalidAtomId(depends_on_id))?;
+    Ok((task_id, prerequisite_id))
+}
+
+fn task_dependency_result(
+    atom_id: AtomId,
+    action: impl FnOnce(&TaskService<'_, SqliteAtomRepository<'_>>) -> Result<(), TaskServiceError>,
+    message: &str,
+) -> TaskDependencyResponse {
+    let result = with_task_service(|svc| {
+        action(svc)?;
+        let prerequisites = svc.list_prerequisites(atom_id)?;
+        let dependents = svc.list_dependents(atom_id)?;
+        let blocked = svc.is_blocked(atom_id)?;
+        Ok((prerequisites, dependents, blocked))
+    });
+    match result {
+        Ok((prerequisites, dependents, blocked)) => TaskDependencyResponse {
+            ok: true,
+            error_code: None,
+            message: message.to_string(),
+            atom_id: Some(atom_id.to_string()),
+            blocked,
+            prerequisite_ids: prerequisites
+                .into_iter()
+                .map(|item| item.atom.uuid.to_string())
+                .collect(),
+            dependent_ids: dependents
+                .into_iter()
+                .map(|item| item.atom.uuid.to_string())
+                .collect(),
+        },
+        Err(err) => task_dependency_failure(err),
+    }
+}
+
+fn task_dependency_failure(err: AtomFfiError) -> TaskDependencyResponse {
+    TaskDependencyResponse {
+        ok: false,
+        error_code: Some(err.code().to_string()),
+        message: err.message(),
+        atom_id: None,
+        blocked: false,
+        prerequisite_ids: Vec::new(),
+        dependent_ids: Vec::new(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Calendar APIs (PR-0012A)
+// ---------------------------------------------------------------------------
+
+/// Lists atoms with both `start_at` and `end_at` that overlap the given time range.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Includes all statuses (done/cancelled shown on calendar).
+/// - Range overlap: `start_at < range_end AND end_at > range_start`.
+#[flutter_rust_bridge::frb]
+pub async fn calendar_list_by_range(
+    start_ms: i64,
+    end_ms: i64,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> AtomListResponse {
+    calendar_list_by_range_impl(start_ms, end_ms, limit, offset)
+}
+
+fn calendar_list_by_range_impl(
+    start_ms: i64,
+    end_ms: i64,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> AtomListResponse {
+    let norm_limit = normalize_section_limit(limit);
+    let norm_offset = offset.unwrap_or(0);
+    match with_task_service(|svc| {
+        svc.fetch_by_time_range(start_ms, end_ms, norm_limit, norm_offset)
+    }) {
+        Ok(items) => AtomListResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} calendar event(s).", items.len()),
+            items: items.into_iter().map(to_atom_list_item).collect(),
+            applied_limit: norm_limit,
+        },
+        Err(err) => atom_list_failure(err, norm_limit),
+    }
+}
+
+/// Updates only `start_at` and `end_at` for a calendar event.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Validates `end_ms >= start_ms`; returns `invalid_time_range` on failure.
+/// - Returns `atom_not_found` when target atom does not exist.
+#[flutter_rust_bridge::frb]
+pub async fn calendar_update_event(
+    atom_id: String,
+    start_ms: i64,
+    end_ms: i64,
+) -> EntryActionResponse {
+    calendar_update_event_impl(atom_id, start_ms, end_ms)
+}
+
+fn calendar_update_event_impl(atom_id: String, start_ms: i64, end_ms: i64) -> EntryActionResponse {
+    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
+        Ok(id) => id,
+        Err(_) => {
+            let err = AtomFfiError::InvalidAtomId(atom_id);
+            return EntryActionResponse {
+                ok: false,
+                atom_id: None,
+                message: err.message(),
+            };
+        }
+    };
+
+    match with_task_service(|svc| svc.update_event_times(parsed_id, start_ms, end_ms)) {
+        Ok(()) => EntryActionResponse {
+            ok: true,
+            atom_id: Some(parsed_id.to_string()),
+            message: "Event times updated.".to_string(),
+        },
+        Err(err) => EntryActionResponse {
+            ok: false,
+            atom_id: None,
+            message: err.message(),
+        },
+    }
+}
+
+/// One atom placed on one local day of a calendar view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEntryItem {
+    /// Stable atom ID (series ID for recurring occurrences).
+    pub atom_id: String,
+    /// Atom projection kind (`note|task|event`).
+    pub kind: String,
+    /// Raw markdown content.
+    pub content: String,
+    /// Derived plain-text preview.
+    pub preview_text: Option<String>,
+    /// Current task status string, or null if statusless.
+    pub task_status: Option<String>,
+    /// Whether `start_at/end_at` are floating all-day dates.
+    pub all_day: bool,
+    /// Start boundary of the atom (or occurrence).
+    pub start_at: i64,
+    /// End boundary of the atom (or occurrence).
+    pub end_at: i64,
+    /// Original occurrence start for recurring series, else null.
+    pub occurrence_start_at: Option<i64>,
+    /// Start of the part shown on this day, epoch ms.
+    pub visible_start_ms: i64,
+    /// End (exclusive) of the part shown on this day, epoch ms.
+    pub visible_end_ms: i64,
+    /// Whether the atom started on an earlier day.
+    pub continues_before: bool,
+    /// Whether the atom ends on a later day.
+    pub continues_after: bool,
+    /// Zero-based layout column inside its overlap group.
+    pub column: u32,
+    /// Columns used by its overlap group.
+    pub column_count: u32,
+}
+
+/// One local day of a calendar view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarDayItem {
+    /// Local date (`YYYY-MM-DD`).
+    pub date: String,
+    /// First instant of the day, epoch ms.
+    pub start_ms: i64,
+    /// Last instant of the day, epoch ms (inclusive).
+    pub end_ms: i64,
+    /// All-day atoms covering the date.
+    pub all_day: Vec<CalendarEntryItem>,
+    /// Timed atoms touching the day, ordered by visible start.
+    pub timed: Vec<CalendarEntryItem>,
+}
+
+/// Calendar day buckets response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarDaysResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// One bucket per local date, in order.
+    pub days: Vec<CalendarDayItem>,
+}
+
+/// Two double-booked events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarConflictItem {
+    /// Event that starts first.
+    pub first_atom_id: String,
+    /// Occurrence start of the first event when recurring.
+    pub first_occurrence_start_at: Option<i64>,
+    /// Overlapping event.
+    pub second_atom_id: String,
+    /// Occurrence start of the second event when recurring.
+    pub second_occurrence_start_at: Option<i64>,
+    /// Start of the shared time, epoch ms.
+    pub overlap_start_ms: i64,
+    /// End (exclusive) of the shared time, epoch ms.
+    pub overlap_end_ms: i64,
+}
+
+/// Calendar conflicts response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarConflictsResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Conflicts ordered by overlap start.
+    pub items: Vec<CalendarConflictItem>,
+}
+
+/// A free gap in the calendar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarFreeSlotItem {
+    /// Start of the gap, epoch ms.
+    pub start_ms: i64,
+    /// End (exclusive) of the gap, epoch ms.
+    pub end_ms: i64,
+}
+
+/// Calendar free slots response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarFreeSlotsResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Free gaps in time order.
+    pub slots: Vec<CalendarFreeSlotItem>,
+}
+
+/// Returns per-day buckets for local dates `start_date..=end_date`.
+///
+/// # FFI contract
+/// - Dates are `YYYY-MM-DD` in the timezone set via `configure_time_zone`.
+/// - Multi-day atoms appear in every day they touch; timed entries carry
+///   overlap `column`/`column_count`. Includes all statuses.
+/// - `invalid_argument` for malformed dates or ranges above 93 days;
+///   `invalid_time_range` when `end_date < start_date`.
+/// - Async call, DB-backed execution.
+#[flutter_rust_bridge::frb]
+pub async fn calendar_days(start_date: String, end_date: String) -> CalendarDaysResponse {
+    calendar_days_impl(start_date, end_date)
+}
+
+fn calendar_days_impl(start_date: String, end_date: String) -> CalendarDaysResponse {
+    let (first, last) = match (parse_local_date(&start_date), parse_local_date(&end_date)) {
+        (Ok(first), Ok(last)) => (first, last),
+        (Err(err), _) | (_, Err(err)) => return calendar_days_failure(err),
+    };
+    calendar_days_result(with_calendar_service(|svc| svc.days(first, last)))
+}
+
+/// Returns per-day buckets for the local week containing `now_ms`.
+///
+/// # FFI contract
+/// - Week start follows `configure_time_zone`; otherwise as `calendar_days`.
+#[flutter_rust_bridge::frb]
+pub async fn calendar_week(now_ms: i64) -> CalendarDaysResponse {
+    calendar_week_impl(now_ms)
+}
+
+fn calendar_week_impl(now_ms: i64) -> CalendarDaysResponse {
+    calendar_days_result(with_calendar_service(|svc| svc.week_days(now_ms)))
+}
+
+/// Returns per-day buckets for the local month containing `now_ms`.
+///
+/// # FFI contract
+/// - Same as `calendar_days` for the whole month.
+#[flutter_rust_bridge::frb]
+pub async fn calendar_month(now_ms: i64) -> CalendarDaysResponse {
+    calendar_month_impl(now_ms)
+}
+
+fn calendar_month_impl(now_ms: i64) -> CalendarDaysResponse {
+    calendar_days_result(with_calendar_service(|svc| svc.month_days(now_ms)))
+}
+
+/// Lists double-booked events touching local dates `start_date..=end_date`.
+///
+/// # FFI contract
+/// - Only timed `event` atoms with a positive duration that are not
+///   `cancelled` count; touching events (`end == start`) do not conflict.
+/// - Errors as `calendar_days`. Async call, DB-backed execution.
+#[flutter_rust_bridge::frb]
+pub async fn calendar_conflicts(start_date: String, end_date: String) -> CalendarConflictsResponse {
+    calendar_conflicts_impl(start_date, end_date)
+}
+
+fn calendar_conflicts_impl(start_date: String, end_date: String) -> CalendarConflictsResponse {
+    let (first, last) = match (parse_local_date(&start_date), parse_local_date(&end_date)) {
+        (Ok(first), Ok(last)) => (first, last),
+        (Err(err), _) | (_, Err(err)) => return calendar_conflicts_failure(err),
+    };
+    match with_calendar_service(|svc| svc.conflicts(first, last)) {
+        Ok(conflicts) => CalendarConflictsResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Found {} conflict(s).", conflicts.len()),
+            items: conflicts
+                .into_iter()
+                .map(to_calendar_conflict_item)
+                .collect(),
+        },
+        Err(err) => calendar_conflicts_failure(err),
+    }
+}
+
+/// Finds free gaps of at least `duration_minutes` in `[start_ms, end_ms)`.
+///
+/// # FFI contract
+/// - Busy time: timed atoms of any kind with a positive duration, except
+///   `cancelled`; all-day atoms never block time.
+/// - `invalid_time_range` when `end_ms <= start_ms`; `invalid_argument` for
+///   `duration_minutes == 0` or ranges above 93 days.
+/// - Async call, DB-backed execution.
+#[flutter_rust_bridge::frb]
+pub async fn calendar_free_slots(
+    start_ms: i64,
+    end_ms: i64,
+    duration_minutes: u32,
+) -> CalendarFreeSlotsResponse {
+    calendar_free_slots_impl(start_ms, end_ms, duration_minutes)
+}
+
+fn calendar_free_slots_impl(
+    start_ms: i64,
+    end_ms: i64,
+    duration_minutes: u32,
+) -> CalendarFreeSlotsResponse {
+    match with_calendar_service(|svc| svc.free_slots(start_ms, end_ms, duration_minutes)) {
+        Ok(slots) => CalendarFreeSlotsResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Found {} free slot(s).", slots.len()),
+            slots: slots
+                .into_iter()
+                .map(|slot| CalendarFreeSlotItem {
+                    start_ms: slot.start_ms,
+                    end_ms: slot.end_ms,
+                })
+                .collect(),
+        },
+        Err(err) => CalendarFreeSlotsResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            slots: Vec::new(),
+        },
+    }
+}
+
+fn with_calendar_service<T>(
+    f: impl FnOnce(&CalendarService<'_, SqliteAtomRepository<'_>>) -> Result<T, CalendarServiceError>,
+) -> Result<T, AtomFfiError> {
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let calendar = configured_calendar();
+    let repo = SqliteAtomRepository::try_new_with_calendar(&conn, calendar)
+        .map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let service = CalendarService::new(&repo, calendar);
+    f(&service).map_err(map_calendar_service_error)
+}
+
+fn map_calendar_service_error(err: CalendarServiceError) -> AtomFfiError {
+    match err {
+        CalendarServiceError::InvalidDateRange { .. }
+        | CalendarServiceError::InvalidTimeRange { .. } => {
+            AtomFfiError::InvalidTimeRange(err.to_string())
+        }
+        CalendarServiceError::RangeTooLarge { .. }
+        | CalendarServiceError::InvalidSlotDuration(_) => {
+            AtomFfiError::InvalidArgument(err.to_string())
+        }
+        CalendarServiceError::Repo(repo_err) => AtomFfiError::DbError(repo_err.to_string()),
+    }
+}
+
+fn calendar_days_result(result: Result<Vec<CalendarDay>, AtomFfiError>) -> CalendarDaysResponse {
+    match result {
+        Ok(days) => CalendarDaysResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} day(s).", days.len()),
+            days: days.into_iter().map(to_calendar_day_item).collect(),
+        },
+        Err(err) => calendar_days_failure(err),
+    }
+}
+
+fn calendar_days_failure(err: AtomFfiError) -> CalendarDaysResponse {
+    CalendarDaysResponse {
+        ok: false,
+        error_code: Some(err.code().to_string()),
+        message: err.message(),
+        days: Vec::new(),
+    }
+}
+
+fn calendar_conflicts_failure(err: AtomFfiError) -> CalendarConflictsResponse {
+    CalendarConflictsResponse {
+        ok: false,
+        error_code: Some(err.code().to_string()),
+        message: err.message(),
+        items: Vec::new(),
+    }
+}
+
+fn to_calendar_day_item(day: CalendarDay) -> CalendarDayItem {
+    CalendarDayItem {
+        date: day.date.format("%Y-%m-%d").to_string(),
+        start_ms: day.start_ms,
+        end_ms: day.end_ms,
+        all_day: day
+            .all_day
+            .into_iter()
+            .map(to_calendar_entry_item)
+            .collect(),
+        timed: day.timed.into_iter().map(to_calendar_entry_item).collect(),
+    }
+}
+
+fn to_calendar_entry_item(entry: CalendarEntry) -> CalendarEntryItem {
+    CalendarEntryItem {
+        atom_id: entry.atom.uuid.to_string(),
+        kind: atom_type_label(entry.atom.kind).to_string(),
+        content: entry.atom.content,
+        preview_text: entry.atom.preview_text,
+        task_status: entry.atom.task_status.map(task_status_label),
+        all_day: entry.atom.all_day,
+        start_at: entry.atom.start_at.unwrap_or(entry.visible_start_ms),
+        end_at: entry.atom.end_at.unwrap_or(entry.visible_end_ms),
+        occurrence_start_at: entry.occurrence_start_at,
+        visible_start_ms: entry.visible_start_ms,
+        visible_end_ms: entry.visible_end_ms,
+        continues_before: entry.continues_before,
+        continues_after: entry.continues_after,
+        column: entry.column,
+        column_count: entry.column_count,
+    }
+}
+
+fn to_calendar_conflict_item(conflict: CalendarConflict) -> CalendarConflictItem {
+    CalendarConflictItem {
+        first_atom_id: conflict.first.uuid.to_string(),
+        first_occurrence_start_at: conflict.first_occurrence_start_at,
+        second_atom_id: conflict.second.uuid.to_string(),
+        second_occurrence_start_at: conflict.second_occurrence_start_at,
+        overlap_start_ms: conflict.overlap_start_ms,
+        overlap_end_ms: conflict.overlap_end_ms,
+    }
+}
+
+/// Converts one atom between `note`, `task` and `event` in place.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `target_kind`: one of `note|task|event`; UUID, content, tags and
+///   workspace note_refs are kept.
+/// - `note`: clears status and times. `task`: keeps status (default `todo`).
+///   `event`: start = `start_ms`, else current start, else a deadline-only
+///   `end_at`; returns `event_start_required` when none applies.
+/// - `start_ms`/`end_ms` override current times for task/event targets;
+///   `invalid_time_range` when the result has `end < start`.
+/// - note_refs hide while the atom is not a note and reappear on converting
+///   back.
+#[flutter_rust_bridge::frb]
+pub async fn atom_convert(
+    atom_id: String,
+    target_kind: String,
+    start_ms: Option<i64>,
+    end_ms: Option<i64>,
+) -> AtomConvertResponse {
+    atom_convert_impl(atom_id, target_kind, start_ms, end_ms)
+}
+
+fn atom_convert_impl(
+    atom_id: String,
+    target_kind: String,
+    start_ms: Option<i64>,
+    end_ms: Option<i64>,
+) -> AtomConvertResponse {
+    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
+        Ok(id) => id,
+        Err(_) => return atom_convert_failure(AtomFfiError::InvalidAtomId(atom_id)),
+    };
+    let target = match parse_entry_search_kind(Some(target_kind)) {
+        Ok(Some(kind)) => kind,
+        Ok(None) => {
+            return atom_convert_failure(AtomFfiError::InvalidArgument(
+                "target_kind must be one of note|task|event".to_string(),
+            ))
+        }
+        Err(message) => return atom_convert_failure(AtomFfiError::InvalidArgument(message)),
+    };
+    let request = ConvertAtomRequest {
+        target,
+        start_epoch_ms: start_ms,
+        end_epoch_ms: end_ms,
+    };
+
+    match with_atom_convert_service(|svc| svc.convert_atom(parsed_id, &request)) {
+        Ok(atom) => AtomConvertResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Converted to {}.", atom_type_label(atom.kind)),
+            atom_id: Some(atom.uuid.to_string()),
+            kind: Some(atom_type_label(atom.kind).to_string()),
+            task_status: atom.task_status.map(task_status_label),
+            start_at: atom.start_at,
+            end_at: atom.end_at,
+        },
+        Err(err) => atom_convert_failure(err),
+    }
+}
+
+fn atom_convert_failure(err: AtomFfiError) -> AtomConvertResponse {
+    AtomConvertResponse {
+        ok: false,
+        error_code: Some(err.code().to_string()),
+        message: err.message(),
+        atom_id: None,
+        kind: None,
+        task_status: None,
+        start_at: None,
+        end_at: None,
+    }
+}
+
+fn with_atom_convert_service<T>(
+    f: impl FnOnce(&AtomService<SqliteAtomRepository<'_>>) -> Result<T, AtomConvertError>,
+) -> Result<T, AtomFfiError> {
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let repo =
+        SqliteAtomRepository::try_new(&conn).map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let service = AtomService::new(repo);
+    f(&service).map_err(|err| match err {
+        AtomConvertError::AtomNotFound(id) => AtomFfiError::AtomNotFound(id.to_string()),
+        AtomConvertError::EventStartRequired(id) => {
+            AtomFfiError::EventStartRequired(id.to_string())
+        }
+        AtomConvertError::Repo(repo_err) => {
+            map_task_service_error(TaskServiceError::Repo(repo_err))
+        }
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Recurrence APIs
+// ---------------------------------------------------------------------------
+
+/// Recurring series / occurrence edit response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Atom holding the result: the series, a detached occurrence or a
+    /// split-off series.
+    pub atom_id: Option<String>,
+    /// Canonical RRULE of that atom, or null when it does not recur.
+    pub recurrence_rule: Option<String>,
+}
+
+/// Sets or clears the recurrence rule of one atom.
+///
+/// # FFI contract
+/// - `rule`: RFC 5545 RRULE value (`FREQ=WEEKLY;BYDAY=MO,WE`), or null to
+///   make the atom a single atom again. Stored in canonical form.
+/// - The series is anchored at the atom's `start_at` and expands in the
+///   configured time zone.
+/// - `invalid_argument` for malformed rules or atoms without `start_at`.
+/// - Async call, DB-backed execution. Idempotent.
+#[flutter_rust_bridge::frb]
+pub async fn atom_set_recurrence(atom_id: String, rule: Option<String>) -> RecurrenceResponse {
+    atom_set_recurrence_impl(atom_id, rule)
+}
+
+fn atom_set_recurrence_impl(atom_id: String, rule: Option<String>) -> RecurrenceResponse {
+    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
+        Ok(id) => id,
+        Err(_) => return recurrence_failure(AtomFfiError::InvalidAtomId(atom_id)),
+    };
+    recurrence_result(
+        with_recurrence_service(|svc, _| {
+            let atom = svc.set_rule(parsed_id, rule.as_deref())?;
+            Ok((atom.uuid, atom.recurrence_rule))
+        }),
+        "Recurrence updated.",
+    )
+}
+
+/// Edits one occurrence of a recurring series.
+///
+/// # FFI contract
+/// - Occurrences are addressed by `series_id` (the item `atom_id`) plus
+///   `occurrence_start_at` as returned in list/calendar items.
+/// - `scope`: `this` detaches a standalone atom for the occurrence;
+///   `this_and_following` splits the series there (or edits it in place
+///   from the first occurrence).
+/// - Null fields keep the series value; changing only `start_at` keeps the
+///   duration. `status` is `todo|in_progress|done|cancelled`.
+/// - `recurrence_rule` replaces the rule of the following part and is only
+///   accepted with `this_and_following`.
+/// - Returns the atom now holding the occurrence.
+/// - `atom_not_found`, `not_recurring`, `occurrence_not_found` (not generated
+///   by the rule or already excepted), `invalid_time_range`.
+/// - Async call, DB-backed execution.
+#[flutter_rust_bridge::frb]
+#[allow(clippy::too_many_arguments)]
+pub async fn recurrence_edit_occurrence(
+    series_id: String,
+    occurrence_start_at: i64,
+    scope: String,
+    content: Option<String>,
+    start_at: Option<i64>,
+    end_at: Option<i64>,
+    status: Option<String>,
+    recurrence_rule: Option<String>,
+) -> RecurrenceResponse {
+    recurrence_edit_occurrence_impl(
+        series_id,
+        occurrence_start_at,
+        scope,
+        content,
+        start_at,
+        end_at,
+        status,
+        recurrence_rule,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn recurrence_edit_occurrence_impl(
+    series_id: String,
+    occurrence_start_at: i64,
+    scope: String,
+    content: Option<String>,
+    start_at: Option<i64>,
+    end_at: Option<i64>,
+    status: Option<String>,
+    recurrence_rule: Option<String>,
+) -> RecurrenceResponse {
+    let parsed_id = match Uuid::parse_str(series_id.trim()) {
+        Ok(id) => id,
+        Err(_) => return recurrence_failure(AtomFfiError::InvalidAtomId(series_id)),
+    };
+    let scope = match parse_occurrence_scope(&scope) {
+        Ok(scope) => scope,
+        Err(err) => return recurrence_failure(err),
+    };
+    if scope == OccurrenceEditScope::ThisOccurrence && recurrence_rule.is_some() {
+        return recurrence_failure(AtomFfiError::InvalidArgument(
+            "recurrence_rule requires scope `this_and_following`".to_string(),
+        ));
+    }
+    let task_status = match status.as_deref().map(str::trim) {
+        None => None,
+        Some("todo") => Some(lazynote_core::TaskStatus::Todo),
+        Some("in_progress") => Some(lazynote_core::TaskStatus::InProgress),
+        Some("done") => Some(lazynote_core::TaskStatus::Done),
+        Some("cancelled") => Some(lazynote_core::TaskStatus::Cancelled),
+        Some(other) => {
+            return recurrence_failure(AtomFfiError::InvalidStatus(other.to_string()));
+        }
+    };
+    let patch = OccurrencePatch {
+        content,
+        start_at,
+        end_at,
+        task_status,
+        recurrence_rule,
+    };
+    recurrence_result(
+        with_recurrence_service(|svc, atoms| {
+            let id = svc.edit_occurrence(parsed_id, occurrence_start_at, scope, &patch)?;
+            let rule = atoms
+                .get_atom(id, false)?
+                .and_then(|atom| atom.recurrence_rule);
+            Ok((id, rule))
+        }),
+        "Occurrence updated.",
+    )
+}
+
+/// Deletes one occurrence of a recurring series.
+///
+/// # FFI contract
+/// - `scope`: `this` skips the occurrence (EXDATE); `this_and_following`
+///   ends the series before it, or soft-deletes the whole series from the
+///   first occurrence.
+/// - Returns the series ID and its remaining rule (null once deleted).
+/// - Same error codes as `recurrence_edit_occurrence`.
+/// - Async call, DB-backed execution.
+#[flutter_rust_bridge::frb]
+pub async fn recurrence_delete_occurrence(
+    series_id: String,
+    occurrence_start_at: i64,
+    scope: String,
+) -> RecurrenceResponse {
+    recurrence_delete_occurrence_impl(series_id, occurrence_start_at, scope)
+}
+
+fn recurrence_delete_occurrence_impl(
+    series_id: String,
+    occurrence_start_at: i64,
+    scope: String,
+) -> RecurrenceResponse {
+    let parsed_id = match Uuid::parse_str(series_id.trim()) {
+        Ok(id) => id,
+        Err(_) => return recurrence_failure(AtomFfiError::InvalidAtomId(series_id)),
+    };
+    let scope = match parse_occurrence_scope(&scope) {
+        Ok(scope) => scope,
+        Err(err) => return recurrence_failure(err),
+    };
+    recurrence_result(
+        with_recurrence_service(|svc, atoms| {
+            svc.delete_occurrence(parsed_id, occurrence_start_at, scope)?;
+            let rule = atoms
+                .get_atom(parsed_id, false)?
+                .and_then(|atom| atom.recurrence_rule);
+            Ok((parsed_id, rule))
+        }),
+        "Occurrence deleted.",
+    )
+}
+
+fn parse_occurrence_scope(value: &str) -> Result<OccurrenceEditScope, AtomFfiError> {
+    match value.trim() {
+        "this" => Ok(OccurrenceEditScope::ThisOccurrence),
+        "this_and_following" => Ok(OccurrenceEditScope::ThisAndFollowing),
+        other => Err(AtomFfiError::InvalidArgument(format!(
+            "scope `{other}` must be one of this|this_and_following"
+        ))),
+    }
+}
+
+fn recurrence_result(
+    result: Result<(AtomId, Option<String>), AtomFfiError>,
+    message: &str,
+) -> RecurrenceResponse {
+    match result {
+        Ok((atom_id, recurrence_rule)) => RecurrenceResponse {
+            ok: true,
+            error_code: None,
+            message: message.to_string(),
+            atom_id: Some(atom_id.to_string()),
+            recurrence_rule,
+        },
+        Err(err) => recurrence_failure(err),
+    }
+}
+
+fn recurrence_failure(err: AtomFfiError) -> RecurrenceResponse {
+    RecurrenceResponse {
+        ok: false,
+        error_code: Some(err.code().to_string()),
+        message: err.message(),
+        atom_id: None,
+        recurrence_rule: None,
+    }
+}
+
+fn map_recurrence_service_error(err: RecurrenceServiceError) -> AtomFfiError {
+    match err {
+        RecurrenceServiceError::SeriesNotFound(id) => AtomFfiError::AtomNotFound(id.to_string()),
+        RecurrenceServiceError::NotRecurring(id) => AtomFfiError::NotRecurring(id.to_string()),
+        RecurrenceServiceError::OccurrenceNotFound {
+            series_id,
+            occurrence_start_at,
+        } => AtomFfiError::OccurrenceNotFound(format!("{occurrence_start_at} in {series_id}")),
+        RecurrenceServiceError::Repo(lazynote_core::RepoError::Validation(
+            err @ (lazynote_core::AtomValidationError::InvalidRecurrenceRule(_)
+            | lazynote_core::AtomValidationError::RecurrenceWithoutStart),
+        )) => AtomFfiError::InvalidArgument(err.to_string()),
+        RecurrenceServiceError::Repo(repo_err) => {
+            map_task_service_error(TaskServiceError::Repo(repo_err))
+        }
+    }
+}
+
+fn with_recurrence_service<T>(
+    f: impl FnOnce(
+        &RecurrenceService<'_, SqliteAtomRepository<'_>, SqliteRecurrenceRepository<'_>>,
+        &SqliteAtomRepository<'_>,
+    ) -> Result<T, RecurrenceServiceError>,
+) -> Result<T, AtomFfiError> {
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let atoms = SqliteAtomRepository::try_new_with_calendar(&conn, configured_calendar())
+        .map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let recurrences = SqliteRecurrenceRepository::try_new(&conn)
+        .map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let service = RecurrenceService::new(&atoms, &recurrences);
+    f(&service, &atoms).map_err(map_recurrence_service_error)
+}
+
+// ---------------------------------------------------------------------------
+// Trash APIs
+// ---------------------------------------------------------------------------
+
+/// One trashed atom item for FFI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashListItem {
+    /// Stable atom ID in string form.
+    pub atom_id: String,
+    /// Atom kind: `note|task|event`.
+    pub kind: String,
+    /// Raw content at deletion time.
+    pub content: String,
+    /// Optional preview text (notes only).
+    pub preview_text: Option<String>,
+    /// Deletion timestamp in epoch milliseconds.
+    pub deleted_at: i64,
+    /// Whether a provider still has to sync the deletion.
+    pub sync_pending: bool,
+}
+
+/// Trash list response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashListResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Trashed items, most recently deleted first.
+    pub items: Vec<TrashListItem>,
+    /// Effective limit after normalization.
+    pub applied_limit: u32,
+}
+
+/// Trash restore response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashRestoreResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Restored atom ID on success.
+    pub atom_id: Option<String>,
+    /// `note_ref` node revived or created for the restored note, if any.
+    pub note_ref_node_id: Option<String>,
+}
+
+/// Trash purge response envelope (single or retention batch).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashPurgeResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Atoms removed permanently.
+    pub purged: u32,
+    /// Atoms kept as content-free tombstones until sync catches up.
+    pub kept_for_sync: u32,
+}
+
+fn map_trash_service_error(err: TrashServiceError) -> AtomFfiError {
+    match err {
+        TrashServiceError::AtomNotFound(id) => AtomFfiError::AtomNotFound(id.to_string()),
+        TrashServiceError::Repo(repo_err) => AtomFfiError::DbError(repo_err.to_string()),
+    }
+}
+
+fn with_trash_service<T>(
+    f: impl FnOnce(&TrashService<SqliteTrashRepository<'_>>) -> Result<T, TrashServiceError>,
+) -> Result<T, AtomFfiError> {
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let repo =
+        SqliteTrashRepository::try_new(&conn).map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let service = TrashService::new(repo);
+    f(&service).map_err(map_trash_service_error)
+}
+
+fn to_trash_list_item(item: TrashItem) -> TrashListItem {
+    TrashListItem {
+        atom_id: item.atom_id.to_string(),
+        kind: atom_type_label(item.kind).to_string(),
+        content: item.content,
+        preview_text: item.preview_text,
+        deleted_at: item.deleted_at,
+        sync_pending: item.sync_pending,
+    }
+}
+
+fn trash_purge_failure(err: AtomFfiError) -> TrashPurgeResponse {
+    TrashPurgeResponse {
+        ok: false,
+        error_code: Some(err.code().to_string()),
+        message: err.message(),
+        purged: 0,
+        kept_for_sync: 0,
+    }
+}
+
+fn to_trash_purge_response(summary: PurgeSummary) -> TrashPurgeResponse {
+    TrashPurgeResponse {
+        ok: true,
+        error_code: None,
+        message: format!(
+            "Purged {} item(s); {} kept for sync.",
+            summary.purged, summary.kept_for_sync
+        ),
+        purged: summary.purged,
+        kept_for_sync: summary.kept_for_sync,
+    }
+}
+
+/// Lists soft-deleted atoms that can still be restored.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `kind`: optional `all|note|task|event`; invalid values return
+///   `invalid_argument`.
+/// - `limit` defaults to 20 and clamps to 100.
+/// - Sorted by deletion time descending.
+#[flutter_rust_bridge::frb]
+pub async fn trash_list(
+    kind: Option<String>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> TrashListResponse {
+    trash_list_impl(kind, limit, offset)
+}
+
+fn trash_list_impl(
+    kind: Option<String>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> TrashListResponse {
+    let applied_limit = lazynote_core::normalize_trash_limit(limit);
+    let kind = match parse_entry_search_kind(kind) {
+        Ok(kind) => kind,
+        Err(message) => {
+            let err = AtomFfiError::InvalidArgument(message);
+            return TrashListResponse {
+                ok: false,
+                error_code: Some(err.code().to_string()),
+                message: err.message(),
+                items: Vec::new(),
+                applied_limit,
+            };
+        }
+    };
+    match with_trash_service(|svc| svc.list_trash(kind, limit, offset.unwrap_or(0))) {
+        Ok(result) => TrashListResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} trashed item(s).", result.items.len()),
+            items: result.items.into_iter().map(to_trash_list_item).collect(),
+            applied_limit: result.applied_limit,
+        },
+        Err(err) => TrashListResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            items: Vec::new(),
+            applied_limit,
+        },
+    }
+}
+
+/// Restores one trashed atom.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Returns `atom_not_found` when the atom is not in the trash.
+/// - A restored note always has an active `note_ref`; `note_ref_node_id` is
+///   set when one had to be revived or created (at root level).
+#[flutter_rust_bridge::frb]
+pub async fn trash_restore(atom_id: String) -> TrashRestoreResponse {
+    trash_restore_impl(atom_id)
+}
+
+fn trash_restore_impl(atom_id: String) -> TrashRestoreResponse {
+    let result = Uuid::parse_str(atom_id.trim())
+        .map_err(|_| AtomFfiError::InvalidAtomId(atom_id.clone()))
+        .and_then(|id| with_trash_service(|svc| svc.restore_atom(id)).map(|node| (id, node)));
+    match result {
+        Ok((id, node)) => TrashRestoreResponse {
+            ok: true,
+            error_code: None,
+            message: "Restored.".to_string(),
+            atom_id: Some(id.to_string()),
+            note_ref_node_id: node.map(|node| node.to_string()),
+        },
+        Err(err) => TrashRestoreResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            atom_id: None,
+            note_ref_node_id: None,
+        },
+    }
+}
+
+/// Permanently purges one trashed atom.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Returns `atom_not_found` for missing or non-deleted atoms.
+/// - When a provider mapping has not synced the deletion, content is
+///   scrubbed and a tombstone is kept (`kept_for_sync = 1`).
+#[flutter_rust_bridge::frb]
+pub async fn trash_purge(atom_id: String) -> TrashPurgeResponse {
+    trash_purge_impl(atom_id)
+}
+
+fn trash_purge_impl(atom_id: String) -> TrashPurgeResponse {
+    let result = Uuid::parse_str(atom_id.trim())
+        .map_err(|_| AtomFfiError::InvalidAtomId(atom_id.clone()))
+        .and_then(|id| with_trash_service(|svc| svc.purge_atom(id)));
+    match result {
+        Ok(outcome) => to_trash_purge_response(PurgeSummary {
+            purged: u32::from(outcome == PurgeOutcome::Purged),
+            kept_for_sync: u32::from(outcome == PurgeOutcome::KeptForSync),
+        }),
+        Err(err) => trash_purge_failure(err),
+    }
+}
+
+/// Purges atoms that stayed in the trash longer than the retention window.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution; intended for app start/idle.
+/// - `retention_days`: the `trash.retention_days` setting; null means 30,
+///   `0` disables auto-purge, values clamp to 3650.
+/// - Same tombstone rule as [`trash_purge`].
+#[flutter_rust_bridge::frb]
+pub async fn trash_purge_expired(retention_days: Option<u32>) -> TrashPurgeResponse {
+    trash_purge_expired_impl(retention_days)
+}
+
+fn trash_purge_expired_impl(retention_days: Option<u32>) -> TrashPurgeResponse {
+    match with_trash_service(|svc| svc.purge_expired(now_epoch_ms(), retention_days)) {
+        Ok(summary) => to_trash_purge_response(summary),
+        Err(err) => trash_purge_failure(err),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Attachment APIs
+// ---------------------------------------------------------------------------
+
+/// One stored attachment for FFI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttachmentItem {
+    /// Lowercase hex SHA-256 of the blob.
+    pub sha256: String,
+    /// Markdown-usable `attachment://<sha256>` URI.
+    pub uri: String,
+    /// Blob size in bytes.
+    pub byte_size: i64,
+    /// MIME type guessed from the first imported file name.
+    pub mime_type: Option<String>,
+    /// File name of the first import.
+    pub original_name: Option<String>,
+    /// Latest import timestamp in epoch milliseconds; GC grace counts from it.
+    pub created_at: i64,
+    /// Number of atoms referencing this attachment.
+    pub ref_count: u32,
+}
+
+/// Attachment import response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttachmentImportResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Stored attachment on success.
+    pub attachment: Option<AttachmentItem>,
+    /// Whether identical bytes were already stored.
+    pub deduplicated: bool,
+}
+
+/// Attachment resolve response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttachmentResolveResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Attachment metadata on success.
+    pub attachment: Option<AttachmentItem>,
+    /// Absolute blob path for rendering/opening.
+    pub file_path: Option<String>,
+}
+
+/// Per-atom attachment list response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttachmentsListResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Attachments in document order.
+    pub items: Vec<AttachmentItem>,
+}
+
+/// Attachment GC response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttachmentGcResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Zero-reference metadata rows removed.
+    pub removed_rows: u32,
+    /// Blob files removed.
+    pub removed_blobs: u32,
+    /// Bytes freed on disk.
+    pub freed_bytes: u64,
+}
+
+#[derive(Debug)]
+enum AttachmentFfiError {
+    InvalidPath(String),
+    SourceNotFound(String),
+    InvalidUri(String),
+    AttachmentNotFound(String),
+    InvalidAtomId(String),
+    IoError(String),
+    DbError(String),
+}
+
+impl AttachmentFfiError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidPath(_) => "invalid_path",
+            Self::SourceNotFound(_) => "source_not_found",
+            Self::InvalidUri(_) => "invalid_attachment_uri",
+            Self::AttachmentNotFound(_) => "attachment_not_found",
+            Self::InvalidAtomId(_) => "invalid_atom_id",
+            Self::IoError(_) => "io_error",
+            Self::DbError(_) => "db_error",
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::InvalidPath(v) => format!("invalid path: {v}"),
+            Self::SourceNotFound(v) => format!("attachment source not found: {v}"),
+            Self::InvalidUri(v) => format!("invalid attachment uri: {v}"),
+            Self::AttachmentNotFound(v) => format!("attachment not found: {v}"),
+            Self::InvalidAtomId(v) => format!("invalid atom id: {v}"),
+            Self::IoError(v) => format!("attachment store error: {v}"),
+            Self::DbError(v) => format!("database error: {v}"),
+        }
+    }
+}
+
+fn map_attachment_service_error(err: AttachmentServiceError) -> AttachmentFfiError {
+    match err {
+        AttachmentServiceError::SourceNotFound(path) => {
+            AttachmentFfiError::SourceNotFound(path.display().to_string())
+        }
+        AttachmentServiceError::InvalidUri(value) => AttachmentFfiError::InvalidUri(value),
+        AttachmentServiceError::AttachmentNotFound(sha256) => {
+            AttachmentFfiError::AttachmentNotFound(sha256)
+        }
+        AttachmentServiceError::Io(err) => AttachmentFfiError::IoError(err.to_string()),
+        AttachmentServiceError::Repo(err) => AttachmentFfiError::DbError(err.to_string()),
     }
 }
 
-fn note_failure(error: NotesFfiError) -> NoteResponse {
-    NoteResponse {
-        ok: false,
-        error_code: Some(error.code().to_string()),
-        message: error.message(),
-        note: None,
+fn with_attachment_service<T>(
+    f: impl FnOnce(
+        &AttachmentService<SqliteAttachmentRepository<'_>>,
+    ) -> Result<T, AttachmentServiceError>,
+) -> Result<T, AttachmentFfiError> {
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(|e| AttachmentFfiError::DbError(e.to_string()))?;
+    let repo = SqliteAttachmentRepository::try_new(&conn)
+        .map_err(|e| AttachmentFfiError::DbError(e.to_string()))?;
+    let store_root =
+        AttachmentService::<SqliteAttachmentRepository<'_>>::store_root_for_db(&db_path);
+    let service = AttachmentService::new(repo, store_root);
+    f(&service).map_err(map_attachment_service_error)
+}
+
+fn to_attachment_item(attachment: Attachment) -> AttachmentItem {
+    AttachmentItem {
+        uri: attachment.uri(),
+        sha256: attachment.sha256,
+        byte_size: attachment.byte_size,
+        mime_type: attachment.mime_type,
+        original_name: attachment.original_name,
+        created_at: attachment.created_at,
+        ref_count: attachment.ref_count,
     }
 }
 
-fn workspace_failure(error: WorkspaceFfiError) -> WorkspaceActionResponse {
-    WorkspaceActionResponse {
-        ok: false,
-        error_code: Some(error.code().to_string()),
-        message: error.message(),
+/// Copies one file into the content-addressed attachment store.
+///
+/// # FFI contract
+/// - Async call, DB + filesystem execution.
+/// - Blob is stored next to the entry DB under `attachments/`.
+/// - Identical bytes are stored once (`deduplicated = true` on repeats).
+/// - Returns `attachment.uri` (`attachment://<sha256>`) for use in markdown.
+#[flutter_rust_bridge::frb]
+pub async fn attachment_import(source_path: String) -> AttachmentImportResponse {
+    attachment_import_impl(source_path)
+}
+
+fn attachment_import_impl(source_path: String) -> AttachmentImportResponse {
+    let trimmed = source_path.trim();
+    let result = if trimmed.is_empty() {
+        Err(AttachmentFfiError::InvalidPath(
+            "source path must not be blank".to_string(),
+        ))
+    } else {
+        with_attachment_service(|svc| svc.import_file(std::path::Path::new(trimmed)))
+    };
+    match result {
+        Ok(imported) => AttachmentImportResponse {
+            ok: true,
+            error_code: None,
+            message: if imported.deduplicated {
+                "Attachment already stored.".to_string()
+            } else {
+                "Attachment imported.".to_string()
+            },
+            attachment: Some(to_attachment_item(imported.attachment)),
+            deduplicated: imported.deduplicated,
+        },
+        Err(err) => AttachmentImportResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            attachment: None,
+            deduplicated: false,
+        },
+    }
+}
+
+/// Resolves an `attachment://` URI to its stored blob path.
+///
+/// # FFI contract
+/// - Async call, DB + filesystem execution.
+/// - Accepts `attachment://<sha256>` or a bare digest.
+/// - Returns `attachment_not_found` when the blob is not on this device.
+#[flutter_rust_bridge::frb]
+pub async fn attachment_resolve(uri: String) -> AttachmentResolveResponse {
+    attachment_resolve_impl(uri)
+}
+
+fn attachment_resolve_impl(uri: String) -> AttachmentResolveResponse {
+    match with_attachment_service(|svc| svc.resolve(&uri)) {
+        Ok((attachment, path)) => AttachmentResolveResponse {
+            ok: true,
+            error_code: None,
+            message: "Attachment resolved.".to_string(),
+            attachment: Some(to_attachment_item(attachment)),
+            file_path: Some(path.display().to_string()),
+        },
+        Err(err) => AttachmentResolveResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            attachment: None,
+            file_path: None,
+        },
+    }
+}
+
+/// Lists attachments referenced by one atom's content.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Document order; references without a stored blob are skipped.
+#[flutter_rust_bridge::frb]
+pub async fn note_attachments(atom_id: String) -> AttachmentsListResponse {
+    note_attachments_impl(atom_id)
+}
+
+fn note_attachments_impl(atom_id: String) -> AttachmentsListResponse {
+    let result = Uuid::parse_str(atom_id.trim())
+        .map_err(|_| AttachmentFfiError::InvalidAtomId(atom_id.clone()))
+        .and_then(|id| with_attachment_service(|svc| svc.list_for_atom(id)));
+    match result {
+        Ok(items) => AttachmentsListResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} attachment(s).", items.len()),
+            items: items.into_iter().map(to_attachment_item).collect(),
+        },
+        Err(err) => AttachmentsListResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            items: Vec::new(),
+        },
+    }
+}
+
+/// Removes attachments no atom references anymore.
+///
+/// # FFI contract
+/// - Async call, DB + filesystem execution; intended for app idle.
+/// - `grace_ms`: keep unreferenced attachments younger than this window so
+///   freshly imported files survive until saved; null means 24 hours.
+/// - Trashed atoms still count as references until purged.
+#[flutter_rust_bridge::frb]
+pub async fn attachments_gc(grace_ms: Option<i64>) -> AttachmentGcResponse {
+    attachments_gc_impl(grace_ms)
+}
+
+fn attachments_gc_impl(grace_ms: Option<i64>) -> AttachmentGcResponse {
+    let grace_ms = grace_ms.unwrap_or(lazynote_core::ATTACHMENT_GC_DEFAULT_GRACE_MS);
+    match with_attachment_service(|svc| svc.collect_garbage(now_epoch_ms(), grace_ms)) {
+        Ok(AttachmentGcSummary {
+            removed_rows,
+            removed_blobs,
+            freed_bytes,
+        }) => AttachmentGcResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Removed {removed_blobs} attachment blob(s)."),
+            removed_rows,
+            removed_blobs,
+            freed_bytes,
+        },
+        Err(err) => AttachmentGcResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            removed_rows: 0,
+            removed_blobs: 0,
+            freed_bytes: 0,
+        },
+    }
+}
+
+/// One reminder attached to an atom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReminderItem {
+    /// Stable reminder ID in string form.
+    pub reminder_id: String,
+    /// Owning atom ID.
+    pub atom_id: String,
+    /// Fire anchor (`absolute|start|due`).
+    pub anchor: String,
+    /// Absolute fire time in epoch ms (`absolute` only).
+    pub remind_at: Option<i64>,
+    /// Offset from the anchor in ms, negative = before (`start|due` only).
+    pub offset_ms: Option<i64>,
+    /// Latest acknowledged scheduled fire time in epoch ms.
+    pub acked_through: Option<i64>,
+    /// Pending snooze redelivery time in epoch ms.
+    pub snoozed_until: Option<i64>,
+    /// Next pending fire time in epoch ms; null when exhausted or silent.
+    pub next_fire_at: Option<i64>,
+    /// Creation time in epoch ms.
+    pub created_at: i64,
+}
+
+/// Single reminder response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReminderResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Reminder state after the operation; null on failure and removal.
+    pub reminder: Option<ReminderItem>,
+}
+
+/// Per-atom reminder list response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReminderListResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Reminders, oldest first.
+    pub items: Vec<ReminderItem>,
+}
+
+/// One reminder firing due for delivery.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueReminderItem {
+    /// Reminder state.
+    pub reminder: ReminderItem,
+    /// Owning atom projection kind (`note|task|event`).
+    pub atom_kind: String,
+    /// Owning atom markdown content.
+    pub content: String,
+    /// Owning atom plain-text preview.
+    pub preview_text: Option<String>,
+    /// Delivery time in epoch ms; pass back to ack/snooze.
+    pub fire_at: i64,
+    /// Occurrence start for recurring atoms.
+    pub occurrence_start_at: Option<i64>,
+    /// Whether this is a snoozed firing being redelivered.
+    pub snoozed: bool,
+}
+
+/// Due reminder poll response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueRemindersResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Due firings ordered by `fire_at`.
+    pub items: Vec<DueReminderItem>,
+    /// Earliest pending fire time after `now_ms`; when to poll next.
+    pub next_wakeup_at: Option<i64>,
+}
+
+#[derive(Debug)]
+enum ReminderFfiError {
+    InvalidAtomId(String),
+    InvalidReminderId(String),
+    InvalidAnchor(String),
+    AtomNotFound(String),
+    ReminderNotFound(String),
+    MissingAnchor(String),
+    InvalidSnooze(String),
+    DbError(String),
+}
+
+impl ReminderFfiError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidAtomId(_) => "invalid_atom_id",
+            Self::InvalidReminderId(_) => "invalid_reminder_id",
+            Self::InvalidAnchor(_) => "invalid_anchor",
+            Self::AtomNotFound(_) => "atom_not_found",
+            Self::ReminderNotFound(_) => "reminder_not_found",
+            Self::MissingAnchor(_) => "missing_anchor",
+            Self::InvalidSnooze(_) => "invalid_snooze",
+            Self::DbError(_) => "db_error",
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::InvalidAtomId(v) => format!("invalid atom id: {v}"),
+            Self::InvalidReminderId(v) => format!("invalid reminder id: {v}"),
+            Self::InvalidAnchor(v) => format!("invalid reminder anchor: {v}"),
+            Self::AtomNotFound(v) => format!("atom not found: {v}"),
+            Self::ReminderNotFound(v) => format!("reminder not found: {v}"),
+            Self::MissingAnchor(v) => format!("missing reminder anchor: {v}"),
+            Self::InvalidSnooze(v) => format!("invalid snooze: {v}"),
+            Self::DbError(v) => format!("database error: {v}"),
+        }
+    }
+}
+
+fn map_reminder_service_error(err: ReminderServiceError) -> ReminderFfiError {
+    match err {
+        ReminderServiceError::AtomNotFound(id) => ReminderFfiError::AtomNotFound(id.to_string()),
+        ReminderServiceError::ReminderNotFound(id) => {
+            ReminderFfiError::ReminderNotFound(id.to_string())
+        }
+        err @ ReminderServiceError::MissingAnchor { .. } => {
+            ReminderFfiError::MissingAnchor(err.to_string())
+        }
+        err @ ReminderServiceError::InvalidSnooze { .. } => {
+            ReminderFfiError::InvalidSnooze(err.to_string())
+        }
+        ReminderServiceError::Repo(err) => ReminderFfiError::DbError(err.to_string()),
+    }
+}
+
+fn with_reminder_service<T>(
+    f: impl FnOnce(
+        &ReminderService<SqliteAtomRepository<'_>, SqliteReminderRepository<'_>>,
+    ) -> Result<T, ReminderServiceError>,
+) -> Result<T, ReminderFfiError> {
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(|e| ReminderFfiError::DbError(e.to_string()))?;
+    let calendar = configured_calendar();
+    let atoms = SqliteAtomRepository::try_new_with_calendar(&conn, calendar)
+        .map_err(|e| ReminderFfiError::DbError(e.to_string()))?;
+    let reminders = SqliteReminderRepository::try_new_with_calendar(&conn, calendar)
+        .map_err(|e| ReminderFfiError::DbError(e.to_string()))?;
+    let service = ReminderService::new(&atoms, &reminders);
+    f(&service).map_err(map_reminder_service_error)
+}
+
+fn parse_reminder_id(raw: &str) -> Result<Uuid, ReminderFfiError> {
+    Uuid::parse_str(raw.trim()).map_err(|_| ReminderFfiError::InvalidReminderId(raw.to_string()))
+}
+
+fn parse_reminder_trigger(
+    anchor: &str,
+    remind_at: Option<i64>,
+    offset_ms: Option<i64>,
+) -> Result<ReminderTrigger, ReminderFfiError> {
+    match anchor.trim().to_ascii_lowercase().as_str() {
+        "absolute" => remind_at
+            .map(|remind_at| ReminderTrigger::Absolute { remind_at })
+            .ok_or_else(|| {
+                ReminderFfiError::InvalidAnchor("absolute reminder requires remind_at".to_string())
+            }),
+        "start" => Ok(ReminderTrigger::Start {
+            offset_ms: offset_ms.unwrap_or(0),
+        }),
+        "due" => Ok(ReminderTrigger::Due {
+            offset_ms: offset_ms.unwrap_or(0),
+        }),
+        _ => Err(ReminderFfiError::InvalidAnchor(anchor.to_string())),
+    }
+}
+
+fn to_reminder_item(reminder: Reminder) -> ReminderItem {
+    let (remind_at, offset_ms) = match reminder.trigger {
+        ReminderTrigger::Absolute { remind_at } => (Some(remind_at), None),
+        ReminderTrigger::Start { offset_ms } | ReminderTrigger::Due { offset_ms } => {
+            (None, Some(offset_ms))
+        }
+    };
+    ReminderItem {
+        reminder_id: reminder.id.to_string(),
+        atom_id: reminder.atom_id.to_string(),
+        anchor: reminder.trigger.anchor().to_string(),
+        remind_at,
+        offset_ms,
+        acked_through: reminder.acked_through,
+        snoozed_until: reminder.snoozed_until,
+        next_fire_at: reminder.next_fire_at,
+        created_at: reminder.created_at,
+    }
+}
+
+fn reminder_response(
+    result: Result<Option<Reminder>, ReminderFfiError>,
+    message: &str,
+) -> ReminderResponse {
+    match result {
+        Ok(reminder) => ReminderResponse {
+            ok: true,
+            error_code: None,
+            message: message.to_string(),
+            reminder: reminder.map(to_reminder_item),
+        },
+        Err(err) => ReminderResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            reminder: None,
+        },
+    }
+}
+
+/// Attaches a reminder to an atom.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `anchor`: `absolute` (requires `remind_at`), or `start|due` with
+///   `offset_ms` (negative = before, null = 0).
+/// - `missing_anchor` when the atom has no `start_at`/`due_at` to anchor to.
+/// - Firings scheduled before the call are never delivered.
+#[flutter_rust_bridge::frb]
+pub async fn reminder_add(
+    atom_id: String,
+    anchor: String,
+    remind_at: Option<i64>,
+    offset_ms: Option<i64>,
+) -> ReminderResponse {
+    reminder_add_impl(atom_id, anchor, remind_at, offset_ms)
+}
+
+fn reminder_add_impl(
+    atom_id: String,
+    anchor: String,
+    remind_at: Option<i64>,
+    offset_ms: Option<i64>,
+) -> ReminderResponse {
+    let result = Uuid::parse_str(atom_id.trim())
+        .map_err(|_| ReminderFfiError::InvalidAtomId(atom_id.clone()))
+        .and_then(|id| Ok((id, parse_reminder_trigger(&anchor, remind_at, offset_ms)?)))
+        .and_then(|(id, trigger)| {
+            with_reminder_service(|svc| svc.add_reminder(id, trigger, now_epoch_ms()).map(Some))
+        });
+    reminder_response(result, "Reminder added.")
+}
+
+/// Lists reminders of one atom.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Oldest first; `next_fire_at` is computed from the atom at read time.
+#[flutter_rust_bridge::frb]
+pub async fn atom_reminders(atom_id: String) -> ReminderListResponse {
+    atom_reminders_impl(atom_id)
+}
+
+fn atom_reminders_impl(atom_id: String) -> ReminderListResponse {
+    let result = Uuid::parse_str(atom_id.trim())
+        .map_err(|_| ReminderFfiError::InvalidAtomId(atom_id.clone()))
+        .and_then(|id| with_reminder_service(|svc| svc.list_reminders(id)));
+    match result {
+        Ok(items) => ReminderListResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} reminder(s).", items.len()),
+            items: items.into_iter().map(to_reminder_item).collect(),
+        },
+        Err(err) => ReminderListResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            items: Vec::new(),
+        },
     }
 }
 
-fn workspace_node_failure(error: WorkspaceFfiError) -> WorkspaceNodeResponse {
-    WorkspaceNodeResponse {
-        ok: false,
-        error_code: Some(error.code().to_string()),
-        message: error.message(),
-        node: None,
+/// Removes one reminder.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Idempotent: removing a missing reminder succeeds.
+#[flutter_rust_bridge::frb]
+pub async fn reminder_remove(reminder_id: String) -> ReminderResponse {
+    reminder_remove_impl(reminder_id)
+}
+
+fn reminder_remove_impl(reminder_id: String) -> ReminderResponse {
+    let result = parse_reminder_id(&reminder_id)
+        .and_then(|id| with_reminder_service(|svc| svc.remove_reminder(id)));
+    match result {
+        Ok(true) => reminder_response(Ok(None), "Reminder removed."),
+        Ok(false) => reminder_response(Ok(None), "Reminder already removed."),
+        Err(err) => reminder_response(Err(err), ""),
     }
 }
 
-fn workspace_list_failure(error: WorkspaceFfiError) -> WorkspaceListChildrenResponse {
-    WorkspaceListChildrenResponse {
-        ok: false,
-        error_code: Some(error.code().to_string()),
-        message: error.message(),
-        items: Vec::new(),
-    }
+/// Acknowledges one delivered firing.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `fire_at` is `DueReminderItem.fire_at`; acknowledging a scheduled
+///   firing also acknowledges every earlier one, a snoozed one clears the
+///   snooze.
+#[flutter_rust_bridge::frb]
+pub async fn reminder_ack(reminder_id: String, fire_at: i64) -> ReminderResponse {
+    reminder_ack_impl(reminder_id, fire_at)
 }
 
-fn map_note_service_error(err: NoteServiceError) -> NotesFfiError {
-    match err {
-        NoteServiceError::InvalidTag(value) => NotesFfiError::InvalidTag(value),
-        NoteServiceError::NoteNotFound(atom_id) => NotesFfiError::NoteNotFound(atom_id.to_string()),
-        NoteServiceError::Repo(repo_err) => map_repo_error(repo_err),
-        NoteServiceError::InconsistentState(details) => {
-            NotesFfiError::Internal(details.to_string())
-        }
-    }
+fn reminder_ack_impl(reminder_id: String, fire_at: i64) -> ReminderResponse {
+    let result = parse_reminder_id(&reminder_id)
+        .and_then(|id| with_reminder_service(|svc| svc.ack_reminder(id, fire_at).map(Some)));
+    reminder_response(result, "Reminder acknowledged.")
 }
 
-fn map_repo_error(err: lazynote_core::RepoError) -> NotesFfiError {
-    match err {
-        lazynote_core::RepoError::NotFound(atom_id) => {
-            NotesFfiError::NoteNotFound(atom_id.to_string())
-        }
-        lazynote_core::RepoError::Validation(validation) => {
-            NotesFfiError::InvalidArgument(validation.to_string())
-        }
-        lazynote_core::RepoError::Db(db_err) => map_db_error(db_err),
-        lazynote_core::RepoError::UninitializedConnection {
-            expected_version,
-            actual_version,
-        } => NotesFfiError::DbError(format!(
-            "repository requires schema {expected_version}, got {actual_version}"
-        )),
-        lazynote_core::RepoError::MissingRequiredTable(table) => {
-            NotesFfiError::DbError(format!("missing required table `{table}`"))
-        }
-        lazynote_core::RepoError::MissingRequiredColumn { table, column } => {
-            NotesFfiError::DbError(format!(
-                "missing required column `{column}` in table `{table}`"
-            ))
-        }
-        lazynote_core::RepoError::InvalidData(details) => NotesFfiError::Internal(details),
-    }
+/// Snoozes one delivered firing.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `fire_at` is `DueReminderItem.fire_at`; the firing is redelivered once
+///   at `until_ms` (`snoozed = true`).
+/// - `invalid_snooze` unless `until_ms > fire_at`.
+#[flutter_rust_bridge::frb]
+pub async fn reminder_snooze(reminder_id: String, fire_at: i64, until_ms: i64) -> ReminderResponse {
+    reminder_snooze_impl(reminder_id, fire_at, until_ms)
 }
 
-fn map_db_error(err: lazynote_core::db::DbError) -> NotesFfiError {
-    if is_db_busy(&err) {
-        NotesFfiError::DbBusy(err.to_string())
-    } else {
-        NotesFfiError::DbError(err.to_string())
-    }
+fn reminder_snooze_impl(reminder_id: String, fire_at: i64, until_ms: i64) -> ReminderResponse {
+    let result = parse_reminder_id(&reminder_id).and_then(|id| {
+        with_reminder_service(|svc| svc.snooze_reminder(id, fire_at, until_ms).map(Some))
+    });
+    reminder_response(result, "Reminder snoozed.")
 }
 
-fn map_workspace_db_error(err: lazynote_core::db::DbError) -> WorkspaceFfiError {
-    if is_db_busy(&err) {
-        WorkspaceFfiError::DbBusy(err.to_string())
-    } else {
-        WorkspaceFfiError::DbError(err.to_string())
-    }
+/// Polls reminder firings due at `now_ms`.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution; intended for a scheduler loop or
+///   platform notifier.
+/// - Per reminder: its latest missed scheduled firing (older ones collapse)
+///   plus a due snooze. Recurring atoms fire once per occurrence.
+/// - Items stay due until acknowledged or snoozed.
+/// - `next_wakeup_at` is the earliest pending fire time after `now_ms`.
+#[flutter_rust_bridge::frb]
+pub async fn reminders_due(now_ms: i64) -> DueRemindersResponse {
+    reminders_due_impl(now_ms)
 }
 
-fn map_tree_repo_error(err: TreeRepoError) -> WorkspaceFfiError {
-    match err {
-        TreeRepoError::Db(db_err) => map_workspace_db_error(db_err),
-        TreeRepoError::NodeNotFound(node_id) => {
-            WorkspaceFfiError::NodeNotFound(node_id.to_string())
-        }
-        TreeRepoError::NodeNotFolder(node_id) => {
-            WorkspaceFfiError::NodeNotFolder(node_id.to_string())
-        }
-        TreeRepoError::UninitializedConnection {
-            expected_version,
-            actual_version,
-        } => WorkspaceFfiError::DbError(format!(
-            "repository requires schema {expected_version}, got {actual_version}"
-        )),
-        TreeRepoError::MissingRequiredTable(table) => {
-            WorkspaceFfiError::DbError(format!("missing required table `{table}`"))
-        }
-        TreeRepoError::MissingRequiredColumn { table, column } => WorkspaceFfiError::DbError(
-            format!("missing required column `{column}` in table `{table}`"),
-        ),
-        TreeRepoError::InvalidData(details) => WorkspaceFfiError::Internal(details),
+fn reminders_due_impl(now_ms: i64) -> DueRemindersResponse {
+    let result = with_reminder_service(|svc| {
+        Ok((svc.next_due_reminders(now_ms)?, svc.next_wakeup_at(now_ms)?))
+    });
+    match result {
+        Ok((due, next_wakeup_at)) => DueRemindersResponse {
+            ok: true,
+            error_code: None,
+            message: format!("{} reminder(s) due.", due.len()),
+            items: due
+                .into_iter()
+                .map(|due| DueReminderItem {
+                    atom_kind: atom_type_label(due.atom.kind).to_string(),
+                    content: due.atom.content,
+                    preview_text: due.atom.preview_text,
+                    reminder: to_reminder_item(due.reminder),
+                    fire_at: due.fire_at,
+                    occurrence_start_at: due.occurrence_start_at,
+                    snoozed: due.snoozed,
+                })
+                .collect(),
+            next_wakeup_at,
+        },
+        Err(err) => DueRemindersResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            items: Vec::new(),
+            next_wakeup_at: None,
+        },
     }
 }
 
-fn map_tree_service_error(err: TreeServiceError) -> WorkspaceFfiError {
-    match err {
-        TreeServiceError::InvalidDisplayName => {
-            WorkspaceFfiError::InvalidDisplayName("display name must not be blank".to_string())
-        }
-        TreeServiceError::NodeNotFound(node_id) => {
-            WorkspaceFfiError::NodeNotFound(node_id.to_string())
-        }
-        TreeServiceError::ParentNotFound(node_id) => {
-            WorkspaceFfiError::ParentNotFound(node_id.to_string())
-        }
-        TreeServiceError::ParentMustBeFolder(node_id) => {
-            WorkspaceFfiError::ParentNotFolder(node_id.to_string())
-        }
-        TreeServiceError::NodeMustBeFolder(node_id) => {
-            WorkspaceFfiError::NodeNotFolder(node_id.to_string())
-        }
-        TreeServiceError::AtomNotFound(atom_id) => {
-            WorkspaceFfiError::AtomNotFound(atom_id.to_string())
-        }
-        TreeServiceError::AtomNotNote(atom_id) => {
-            WorkspaceFfiError::AtomNotNote(atom_id.to_string())
-        }
-        TreeServiceError::CycleDetected {
-            node_uuid,
-            parent_uuid,
-        } => WorkspaceFfiError::CycleDetected(format!("node={node_uuid} parent={parent_uuid}")),
-        TreeServiceError::Repo(repo_err) => map_tree_repo_error(repo_err),
-    }
+/// One tracked time session on a task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeEntryItem {
+    /// Stable entry ID in string form.
+    pub entry_id: String,
+    /// Tracked task atom ID.
+    pub atom_id: String,
+    /// Device that recorded the entry.
+    pub device_id: String,
+    /// Session start in epoch ms.
+    pub started_at: i64,
+    /// Session end in epoch ms; null while the timer runs.
+    pub ended_at: Option<i64>,
+    /// Optional free-text note.
+    pub note: Option<String>,
+    /// Whether the timer is still running.
+    pub running: bool,
+    /// Tracked ms; running timers count up to the response time.
+    pub duration_ms: i64,
 }
 
-fn is_db_busy(err: &lazynote_core::db::DbError) -> bool {
-    matches!(
-        err,
-        lazynote_core::db::DbError::Sqlite(rusqlite::Error::SqliteFailure(sqlite_err, _))
-            if sqlite_err.code == rusqlite::ErrorCode::DatabaseBusy
-                || sqlite_err.code == rusqlite::ErrorCode::DatabaseLocked
-    )
+/// Single time entry response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeEntryResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Entry after the operation; null on failure, deletion or no timer.
+    pub entry: Option<TimeEntryItem>,
 }
 
-fn to_entry_search_item(hit: lazynote_core::SearchHit) -> EntrySearchItem {
-    EntrySearchItem {
-        atom_id: hit.atom_id.to_string(),
-        kind: atom_type_label(hit.kind).to_string(),
-        snippet: hit.snippet,
-    }
+/// Per-atom time entry list response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeEntryListResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Entries, oldest start first.
+    pub items: Vec<TimeEntryItem>,
+    /// Sum of `duration_ms` over `items`.
+    pub total_ms: i64,
 }
 
-fn atom_type_label(kind: AtomType) -> &'static str {
-    match kind {
-        AtomType::Note => "note",
-        AtomType::Task => "task",
-        AtomType::Event => "event",
-    }
+/// Tracked time of one local day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeDayTotalItem {
+    /// Local date (`YYYY-MM-DD`).
+    pub date: String,
+    /// Tracked ms inside the day.
+    pub total_ms: i64,
 }
 
-// ---------------------------------------------------------------------------
-// Tasks / Section APIs (v0.1.5)
-// ---------------------------------------------------------------------------
+/// Tracked time of atoms carrying one tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeTagTotalItem {
+    /// Normalized lowercase tag.
+    pub tag: String,
+    /// Tracked ms inside the report range.
+    pub total_ms: i64,
+}
 
-/// Atom list item returned by section queries (Inbox/Today/Upcoming).
+/// Tracked time attributed to one workspace folder.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct AtomListItem {
-    /// Stable atom ID in string form.
-    pub atom_id: String,
-    /// Atom projection kind (`note|task|event`).
-    pub kind: String,
-    /// Raw markdown content.
-    pub content: String,
-    /// Derived plain-text preview.
-    pub preview_text: Option<String>,
-    /// Derived first markdown image path.
-    pub preview_image: Option<String>,
-    /// Normalized lowercase tags for this atom.
-    pub tags: Vec<String>,
-    /// Epoch ms — start boundary (NULL = no start).
-    pub start_at: Option<i64>,
-    /// Epoch ms — end boundary (NULL = no end).
-    pub end_at: Option<i64>,
-    /// Current task status string, or null if statusless.
-    pub task_status: Option<String>,
-    /// Update timestamp in epoch milliseconds.
-    pub updated_at: i64,
+pub struct TimeFolderTotalItem {
+    /// Folder node ID.
+    pub folder_id: String,
+    /// Display path from the root (`A/B`).
+    pub path: String,
+    /// Tracked ms inside the report range, subfolders included.
+    pub total_ms: i64,
 }
 
-/// Section list response envelope.
+/// Time report response envelope.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct AtomListResponse {
+pub struct TimeReportResponse {
     /// Whether operation succeeded.
     pub ok: bool,
     /// Stable machine-readable error code for failure paths.
     pub error_code: Option<String>,
     /// Human-readable message for diagnostics/UI.
     pub message: String,
-    /// Section items.
-    pub items: Vec<AtomListItem>,
-    /// Effective limit after normalization.
-    pub applied_limit: u32,
+    /// One item per local date in the range, zero days included.
+    pub days: Vec<TimeDayTotalItem>,
+    /// Per-tag totals, largest first.
+    pub tags: Vec<TimeTagTotalItem>,
+    /// Per-folder totals, ordered by path.
+    pub folders: Vec<TimeFolderTotalItem>,
 }
 
-const SECTION_DEFAULT_LIMIT: u32 = 50;
-const SECTION_LIMIT_MAX: u32 = 50;
-
 #[derive(Debug)]
-#[allow(dead_code)] // Internal reserved for future use
-enum AtomFfiError {
+enum TimeFfiError {
     InvalidAtomId(String),
+    InvalidEntryId(String),
+    InvalidArgument(String),
     AtomNotFound(String),
-    InvalidStatus(String),
+    NotATask(String),
+    EntryNotFound(String),
     InvalidTimeRange(String),
     DbError(String),
-    Internal(String),
 }
 
-impl AtomFfiError {
+impl TimeFfiError {
     fn code(&self) -> &'static str {
         match self {
             Self::InvalidAtomId(_) => "invalid_atom_id",
+            Self::InvalidEntryId(_) => "invalid_time_entry_id",
+            Self::InvalidArgument(_) => "invalid_argument",
             Self::AtomNotFound(_) => "atom_not_found",
-            Self::InvalidStatus(_) => "invalid_status",
+            Self::NotATask(_) => "not_a_task",
+            Self::EntryNotFound(_) => "time_entry_not_found",
             Self::InvalidTimeRange(_) => "invalid_time_range",
             Self::DbError(_) => "db_error",
-            Self::Internal(_) => "internal_error",
         }
     }
 
     fn message(&self) -> String {
         match self {
             Self::InvalidAtomId(v) => format!("invalid atom id: {v}"),
+            Self::InvalidEntryId(v) => format!("invalid time entry id: {v}"),
+            Self::InvalidArgument(v) => format!("invalid argument: {v}"),
             Self::AtomNotFound(v) => format!("atom not found: {v}"),
-            Self::InvalidStatus(v) => format!("invalid status: {v}"),
+            Self::NotATask(v) => format!("atom is not a task: {v}"),
+            Self::EntryNotFound(v) => format!("time entry not found: {v}"),
             Self::InvalidTimeRange(v) => format!("invalid time range: {v}"),
             Self::DbError(v) => format!("database error: {v}"),
-            Self::Internal(v) => format!("internal error: {v}"),
         }
     }
 }
 
-fn map_task_service_error(err: TaskServiceError) -> AtomFfiError {
+fn map_time_tracking_service_error(err: TimeTrackingServiceError) -> TimeFfiError {
     match err {
-        TaskServiceError::AtomNotFound(id) => AtomFfiError::AtomNotFound(id.to_string()),
-        TaskServiceError::Repo(lazynote_core::RepoError::Validation(
-            lazynote_core::AtomValidationError::InvalidEventWindow { start, end },
-        )) => {
-            AtomFfiError::InvalidTimeRange(format!("end_at ({end}) must be >= start_at ({start})"))
+        TimeTrackingServiceError::AtomNotFound(id) => TimeFfiError::AtomNotFound(id.to_string()),
+        TimeTrackingServiceError::NotATask(id) => TimeFfiError::NotATask(id.to_string()),
+        TimeTrackingServiceError::EntryNotFound(id) => TimeFfiError::EntryNotFound(id.to_string()),
+        err @ (TimeTrackingServiceError::InvalidTimeRange { .. }
+        | TimeTrackingServiceError::InvalidDateRange { .. }) => {
+            TimeFfiError::InvalidTimeRange(err.to_string())
         }
-        TaskServiceError::Repo(repo_err) => AtomFfiError::DbError(repo_err.to_string()),
+        err @ TimeTrackingServiceError::RangeTooLarge { .. } => {
+            TimeFfiError::InvalidArgument(err.to_string())
+        }
+        TimeTrackingServiceError::Repo(err) => TimeFfiError::DbError(err.to_string()),
     }
 }
 
-fn with_task_service<T>(
-    f: impl FnOnce(&TaskService<'_, SqliteAtomRepository<'_>>) -> Result<T, TaskServiceError>,
-) -> Result<T, AtomFfiError> {
+fn with_time_tracking_service<T>(
+    f: impl FnOnce(
+        &TimeTrackingService<SqliteAtomRepository<'_>, SqliteTimeEntryRepository<'_>>,
+    ) -> Result<T, TimeTrackingServiceError>,
+) -> Result<T, TimeFfiError> {
     let db_path = resolve_entry_db_path();
-    let conn = open_db(&db_path).map_err(|e| AtomFfiError::DbError(e.to_string()))?;
-    let repo =
-        SqliteAtomRepository::try_new(&conn).map_err(|e| AtomFfiError::DbError(e.to_string()))?;
-    let service = TaskService::new(&repo, &conn);
-    f(&service).map_err(map_task_service_error)
+    let conn = open_db(&db_path).map_err(|e| TimeFfiError::DbError(e.to_string()))?;
+    let atoms =
+        SqliteAtomRepository::try_new(&conn).map_err(|e| TimeFfiError::DbError(e.to_string()))?;
+    let entries = SqliteTimeEntryRepository::try_new(&conn)
+        .map_err(|e| TimeFfiError::DbError(e.to_string()))?;
+    let service = TimeTrackingService::new(&atoms, &entries, configured_calendar());
+    f(&service).map_err(map_time_tracking_service_error)
 }
 
-fn normalize_section_limit(limit: Option<u32>) -> u32 {
-    match limit {
-        Some(0) => SECTION_DEFAULT_LIMIT,
-        Some(v) if v > SECTION_LIMIT_MAX => SECTION_LIMIT_MAX,
-        Some(v) => v,
-        None => SECTION_DEFAULT_LIMIT,
-    }
+fn parse_time_atom_id(raw: &str) -> Result<Uuid, TimeFfiError> {
+    Uuid::parse_str(raw.trim()).map_err(|_| TimeFfiError::InvalidAtomId(raw.to_string()))
 }
-
-fn to_atom_list_item(sa: SectionAtom) -> AtomListItem {
-    AtomListItem {
-        atom_id: sa.atom.uuid.to_string(),
-        kind: atom_type_label(sa.atom.kind).to_string(),
-        content: sa.atom.content,
-        preview_text: sa.atom.preview_text,
-        preview_image: sa.atom.preview_image,
-        tags: sa.tags,
-        start_at: sa.atom.start_at,
-        end_at: sa.atom.end_at,
-        task_status: sa.atom.task_status.map(|s| {
-            match s {
-                lazynote_core::TaskStatus::Todo => "todo",
-                lazynote_core::TaskStatus::InProgress => "in_progress",
-                lazynote_core::TaskStatus::Done => "done",
-                lazynote_core::TaskStatus::Cancelled => "cancelled",
-            }
-            .to_string()
-        }),
-        updated_at: sa.updated_at,
+
+fn parse_time_entry_id(raw: &str) -> Result<Uuid, TimeFfiError> {
+    Uuid::parse_str(raw.trim()).map_err(|_| TimeFfiError::InvalidEntryId(raw.to_string()))
+}
+
+fn to_time_entry_item(entry: TimeEntry, now_ms: i64) -> TimeEntryItem {
+    TimeEntryItem {
+        entry_id: entry.id.to_string(),
+        atom_id: entry.atom_id.to_string(),
+        running: entry.is_running(),
+        duration_ms: entry.duration_ms(now_ms),
+        device_id: entry.device_id,
+        started_at: entry.started_at,
+        ended_at: entry.ended_at,
+        note: entry.note,
     }
 }
 
-fn atom_list_failure(err: AtomFfiError, limit: u32) -> AtomListResponse {
-    AtomListResponse {
-        ok: false,
-        error_code: Some(err.code().to_string()),
-        message: err.message(),
-        items: Vec::new(),
-        applied_limit: limit,
+fn time_entry_response(
+    result: Result<Option<TimeEntry>, TimeFfiError>,
+    message: &str,
+) -> TimeEntryResponse {
+    match result {
+        Ok(entry) => TimeEntryResponse {
+            ok: true,
+            error_code: None,
+            message: message.to_string(),
+            entry: entry.map(|entry| to_time_entry_item(entry, now_epoch_ms())),
+        },
+        Err(err) => TimeEntryResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            entry: None,
+        },
     }
 }
 
-/// Lists inbox atoms (both `start_at` and `end_at` NULL).
+/// Starts this device's timer on a task.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - Excludes done/cancelled atoms.
+/// - At most one running timer per device: a timer running on another task
+///   stops at `now_ms` first; one on the same task is returned unchanged.
+/// - `not_a_task` for non-task atoms.
 #[flutter_rust_bridge::frb]
-pub async fn tasks_list_inbox(limit: Option<u32>, offset: Option<u32>) -> AtomListResponse {
-    tasks_list_inbox_impl(limit, offset)
+pub async fn time_timer_start(atom_id: String, now_ms: i64) -> TimeEntryResponse {
+    time_timer_start_impl(atom_id, now_ms)
 }
 
-fn tasks_list_inbox_impl(limit: Option<u32>, offset: Option<u32>) -> AtomListResponse {
-    let norm_limit = normalize_section_limit(limit);
-    let norm_offset = offset.unwrap_or(0);
-    match with_task_service(|svc| svc.fetch_inbox(norm_limit, norm_offset)) {
-        Ok(items) => AtomListResponse {
-            ok: true,
-            error_code: None,
-            message: format!("Loaded {} inbox item(s).", items.len()),
-            items: items.into_iter().map(to_atom_list_item).collect(),
-            applied_limit: norm_limit,
-        },
-        Err(err) => atom_list_failure(err, norm_limit),
-    }
+fn time_timer_start_impl(atom_id: String, now_ms: i64) -> TimeEntryResponse {
+    let result = parse_time_atom_id(&atom_id)
+        .and_then(|id| with_time_tracking_service(|svc| svc.start_timer(id, now_ms).map(Some)));
+    time_entry_response(result, "Timer started.")
 }
 
-/// Lists atoms active today based on time-matrix rules.
+/// Stops this device's running timer.
 ///
 /// # FFI contract
-/// - `bod_ms`/`eod_ms`: device-local day boundaries in epoch ms.
 /// - Async call, DB-backed execution.
-/// - Excludes done/cancelled atoms.
+/// - Idempotent: with no running timer succeeds with `entry = null`.
 #[flutter_rust_bridge::frb]
-pub async fn tasks_list_today(
-    bod_ms: i64,
-    eod_ms: i64,
-    limit: Option<u32>,
-    offset: Option<u32>,
-) -> AtomListResponse {
-    tasks_list_today_impl(bod_ms, eod_ms, limit, offset)
+pub async fn time_timer_stop(now_ms: i64) -> TimeEntryResponse {
+    time_timer_stop_impl(now_ms)
 }
 
-fn tasks_list_today_impl(
-    bod_ms: i64,
-    eod_ms: i64,
-    limit: Option<u32>,
-    offset: Option<u32>,
-) -> AtomListResponse {
-    let norm_limit = normalize_section_limit(limit);
-    let norm_offset = offset.unwrap_or(0);
-    match with_task_service(|svc| svc.fetch_today(bod_ms, eod_ms, norm_limit, norm_offset)) {
-        Ok(items) => AtomListResponse {
-            ok: true,
-            error_code: None,
-            message: format!("Loaded {} today item(s).", items.len()),
-            items: items.into_iter().map(to_atom_list_item).collect(),
-            applied_limit: norm_limit,
-        },
-        Err(err) => atom_list_failure(err, norm_limit),
+fn time_timer_stop_impl(now_ms: i64) -> TimeEntryResponse {
+    match with_time_tracking_service(|svc| svc.stop_timer(now_ms)) {
+        Ok(Some(entry)) => time_entry_response(Ok(Some(entry)), "Timer stopped."),
+        Ok(None) => time_entry_response(Ok(None), "No timer running."),
+        Err(err) => time_entry_response(Err(err), ""),
     }
 }
 
-/// Lists atoms anchored entirely in the future.
+/// Returns this device's running timer.
 ///
 /// # FFI contract
-/// - `eod_ms`: end of today in epoch ms.
 /// - Async call, DB-backed execution.
-/// - Excludes done/cancelled atoms.
+/// - `entry = null` when no timer runs.
 #[flutter_rust_bridge::frb]
-pub async fn tasks_list_upcoming(
-    eod_ms: i64,
-    limit: Option<u32>,
-    offset: Option<u32>,
-) -> AtomListResponse {
-    tasks_list_upcoming_impl(eod_ms, limit, offset)
+pub async fn time_timer_running() -> TimeEntryResponse {
+    time_timer_running_impl()
 }
 
-fn tasks_list_upcoming_impl(
-    eod_ms: i64,
-    limit: Option<u32>,
-    offset: Option<u32>,
-) -> AtomListResponse {
-    let norm_limit = normalize_section_limit(limit);
-    let norm_offset = offset.unwrap_or(0);
-    match with_task_service(|svc| svc.fetch_upcoming(eod_ms, norm_limit, norm_offset)) {
-        Ok(items) => AtomListResponse {
-            ok: true,
-            error_code: None,
-            message: format!("Loaded {} upcoming item(s).", items.len()),
-            items: items.into_iter().map(to_atom_list_item).collect(),
-            applied_limit: norm_limit,
-        },
-        Err(err) => atom_list_failure(err, norm_limit),
-    }
+fn time_timer_running_impl() -> TimeEntryResponse {
+    time_entry_response(
+        with_time_tracking_service(|svc| svc.running_timer()),
+        "Running timer loaded.",
+    )
 }
 
-/// Updates `task_status` for any atom type (universal completion).
+/// Records a finished time session on a task.
 ///
 /// # FFI contract
-/// - `status`: one of `todo|in_progress|done|cancelled`, or null to clear (demote).
 /// - Async call, DB-backed execution.
-/// - Idempotent: setting the same status twice succeeds.
+/// - `invalid_time_range` unless `ended_at > started_at`.
+/// - Blank `note` is stored as null.
 #[flutter_rust_bridge::frb]
-pub async fn atom_update_status(atom_id: String, status: Option<String>) -> EntryActionResponse {
-    atom_update_status_impl(atom_id, status)
+pub async fn time_entry_add(
+    atom_id: String,
+    started_at: i64,
+    ended_at: i64,
+    note: Option<String>,
+) -> TimeEntryResponse {
+    time_entry_add_impl(atom_id, started_at, ended_at, note)
 }
 
-fn atom_update_status_impl(atom_id: String, status: Option<String>) -> EntryActionResponse {
-    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
-        Ok(id) => id,
-        Err(_) => {
-            let err = AtomFfiError::InvalidAtomId(atom_id);
-            return EntryActionResponse {
-                ok: false,
-                atom_id: None,
-                message: err.message(),
-            };
-        }
-    };
-
-    let parsed_status = match status.as_deref() {
-        None => None,
-        Some("todo") => Some(lazynote_core::TaskStatus::Todo),
-        Some("in_progress") => Some(lazynote_core::TaskStatus::InProgress),
-        Some("done") => Some(lazynote_core::TaskStatus::Done),
-        Some("cancelled") => Some(lazynote_core::TaskStatus::Cancelled),
-        Some(other) => {
-            let err = AtomFfiError::InvalidStatus(other.to_string());
-            return EntryActionResponse {
-                ok: false,
-                atom_id: None,
-                message: err.message(),
-            };
-        }
-    };
+fn time_entry_add_impl(
+    atom_id: String,
+    started_at: i64,
+    ended_at: i64,
+    note: Option<String>,
+) -> TimeEntryResponse {
+    let result = parse_time_atom_id(&atom_id).and_then(|id| {
+        with_time_tracking_service(|svc| svc.add_entry(id, started_at, ended_at, note).map(Some))
+    });
+    time_entry_response(result, "Time entry added.")
+}
 
-    match with_task_service(|svc| svc.update_status(parsed_id, parsed_status)) {
-        Ok(()) => EntryActionResponse {
-            ok: true,
-            atom_id: Some(parsed_id.to_string()),
-            message: "Status updated.".to_string(),
-        },
-        Err(err) => EntryActionResponse {
-            ok: false,
-            atom_id: None,
-            message: err.message(),
-        },
-    }
+/// Edits one time entry; null arguments keep the stored value.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `atom_id` moves the entry to another task; `ended_at` stops a running
+///   entry; blank `note` clears it.
+/// - `invalid_time_range` when a stopped entry would not end after its start.
+#[flutter_rust_bridge::frb]
+pub async fn time_entry_update(
+    entry_id: String,
+    atom_id: Option<String>,
+    started_at: Option<i64>,
+    ended_at: Option<i64>,
+    note: Option<String>,
+) -> TimeEntryResponse {
+    time_entry_update_impl(entry_id, atom_id, started_at, ended_at, note)
 }
 
-// ---------------------------------------------------------------------------
-// Calendar APIs (PR-0012A)
-// ---------------------------------------------------------------------------
+fn time_entry_update_impl(
+    entry_id: String,
+    atom_id: Option<String>,
+    started_at: Option<i64>,
+    ended_at: Option<i64>,
+    note: Option<String>,
+) -> TimeEntryResponse {
+    let result = parse_time_entry_id(&entry_id)
+        .and_then(|id| {
+            let atom_id = atom_id.as_deref().map(parse_time_atom_id).transpose()?;
+            Ok((
+                id,
+                TimeEntryPatch {
+                    atom_id,
+                    started_at,
+                    ended_at,
+                    note,
+                },
+            ))
+        })
+        .and_then(|(id, patch)| {
+            with_time_tracking_service(|svc| svc.update_entry(id, &patch).map(Some))
+        });
+    time_entry_response(result, "Time entry updated.")
+}
 
-/// Lists atoms with both `start_at` and `end_at` that overlap the given time range.
+/// Deletes one time entry.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - Includes all statuses (done/cancelled shown on calendar).
-/// - Range overlap: `start_at < range_end AND end_at > range_start`.
+/// - Idempotent: deleting a missing entry succeeds.
 #[flutter_rust_bridge::frb]
-pub async fn calendar_list_by_range(
-    start_ms: i64,
-    end_ms: i64,
-    limit: Option<u32>,
-    offset: Option<u32>,
-) -> AtomListResponse {
-    calendar_list_by_range_impl(start_ms, end_ms, limit, offset)
+pub async fn time_entry_delete(entry_id: String) -> TimeEntryResponse {
+    time_entry_delete_impl(entry_id)
 }
 
-fn calendar_list_by_range_impl(
-    start_ms: i64,
-    end_ms: i64,
-    limit: Option<u32>,
-    offset: Option<u32>,
-) -> AtomListResponse {
-    let norm_limit = normalize_section_limit(limit);
-    let norm_offset = offset.unwrap_or(0);
-    match with_task_service(|svc| {
-        svc.fetch_by_time_range(start_ms, end_ms, norm_limit, norm_offset)
-    }) {
-        Ok(items) => AtomListResponse {
-            ok: true,
-            error_code: None,
-            message: format!("Loaded {} calendar event(s).", items.len()),
-            items: items.into_iter().map(to_atom_list_item).collect(),
-            applied_limit: norm_limit,
-        },
-        Err(err) => atom_list_failure(err, norm_limit),
+fn time_entry_delete_impl(entry_id: String) -> TimeEntryResponse {
+    let result = parse_time_entry_id(&entry_id)
+        .and_then(|id| with_time_tracking_service(|svc| svc.delete_entry(id)));
+    match result {
+        Ok(true) => time_entry_response(Ok(None), "Time entry deleted."),
+        Ok(false) => time_entry_response(Ok(None), "Time entry already deleted."),
+        Err(err) => time_entry_response(Err(err), ""),
     }
 }
 
-/// Updates only `start_at` and `end_at` for a calendar event.
+/// Lists time entries of one atom.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - Validates `end_ms >= start_ms`; returns `invalid_time_range` on failure.
-/// - Returns `atom_not_found` when target atom does not exist.
+/// - Oldest start first, all devices; running timers count up to `now_ms`
+///   in `duration_ms` and `total_ms`.
 #[flutter_rust_bridge::frb]
-pub async fn calendar_update_event(
-    atom_id: String,
-    start_ms: i64,
-    end_ms: i64,
-) -> EntryActionResponse {
-    calendar_update_event_impl(atom_id, start_ms, end_ms)
+pub async fn atom_time_entries(atom_id: String, now_ms: i64) -> TimeEntryListResponse {
+    atom_time_entries_impl(atom_id, now_ms)
 }
 
-fn calendar_update_event_impl(atom_id: String, start_ms: i64, end_ms: i64) -> EntryActionResponse {
-    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
-        Ok(id) => id,
-        Err(_) => {
-            let err = AtomFfiError::InvalidAtomId(atom_id);
-            return EntryActionResponse {
-                ok: false,
-                atom_id: None,
-                message: err.message(),
-            };
+fn atom_time_entries_impl(atom_id: String, now_ms: i64) -> TimeEntryListResponse {
+    let result = parse_time_atom_id(&atom_id)
+        .and_then(|id| with_time_tracking_service(|svc| svc.list_entries(id)));
+    match result {
+        Ok(entries) => {
+            let items: Vec<TimeEntryItem> = entries
+                .into_iter()
+                .map(|entry| to_time_entry_item(entry, now_ms))
+                .collect();
+            TimeEntryListResponse {
+                ok: true,
+                error_code: None,
+                message: format!("Loaded {} time entry(ies).", items.len()),
+                total_ms: items.iter().map(|item| item.duration_ms).sum(),
+                items,
+            }
         }
-    };
+        Err(err) => TimeEntryListResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            items: Vec::new(),
+            total_ms: 0,
+        },
+    }
+}
 
-    match with_task_service(|svc| svc.update_event_times(parsed_id, start_ms, end_ms)) {
-        Ok(()) => EntryActionResponse {
+/// Reports tracked time for local dates `start_date..=end_date`.
+///
+/// # FFI contract
+/// - Dates are `YYYY-MM-DD` in the timezone set via `configure_time_zone`.
+/// - Entries are clipped to the range; running timers count up to `now_ms`.
+/// - `tags`: an entry counts toward every tag of its task; untagged time is
+///   not listed.
+/// - `folders`: a task belongs to the folders holding notes that link to it
+///   (or to a parent task); totals include subfolders.
+/// - `invalid_argument` for malformed dates or ranges above 366 days;
+///   `invalid_time_range` when `end_date < start_date`.
+/// - Async call, DB-backed execution; trashed tasks are excluded.
+#[flutter_rust_bridge::frb]
+pub async fn time_report(start_date: String, end_date: String, now_ms: i64) -> TimeReportResponse {
+    time_report_impl(start_date, end_date, now_ms)
+}
+
+fn time_report_impl(start_date: String, end_date: String, now_ms: i64) -> TimeReportResponse {
+    let result = parse_time_date(&start_date)
+        .and_then(|first| Ok((first, parse_time_date(&end_date)?)))
+        .and_then(|(first, last)| {
+            with_time_tracking_service(|svc| {
+                Ok((
+                    svc.totals_by_day(first, last, now_ms)?,
+                    svc.totals_by_tag(first, last, now_ms)?,
+                    svc.totals_by_folder(first, last, now_ms)?,
+                ))
+            })
+        });
+    match result {
+        Ok((days, tags, folders)) => TimeReportResponse {
             ok: true,
-            atom_id: Some(parsed_id.to_string()),
-            message: "Event times updated.".to_string(),
+            error_code: None,
+            message: format!(
+                "Tracked {} ms over {} day(s).",
+                days.iter().map(|day| day.total_ms).sum::<i64>(),
+                days.len()
+            ),
+            days: days
+                .into_iter()
+                .map(|day| TimeDayTotalItem {
+                    date: day.date.format("%Y-%m-%d").to_string(),
+                    total_ms: day.total_ms,
+                })
+                .collect(),
+            tags: tags
+                .into_iter()
+                .map(|total| TimeTagTotalItem {
+                    tag: total.tag,
+                    total_ms: total.total_ms,
+                })
+                .collect(),
+            folders: folders
+                .into_iter()
+                .map(|total| TimeFolderTotalItem {
+                    folder_id: total.folder_id.to_string(),
+                    path: total.path,
+                    total_ms: total.total_ms,
+                })
+                .collect(),
         },
-        Err(err) => EntryActionResponse {
+        Err(err) => TimeReportResponse {
             ok: false,
-            atom_id: None,
+            error_code: Some(err.code().to_string()),
             message: err.message(),
+            days: Vec::new(),
+            tags: Vec::new(),
+            folders: Vec::new(),
         },
     }
 }
 
+fn parse_time_date(value: &str) -> Result<NaiveDate, TimeFfiError> {
+    parse_local_date(value).map_err(|_| {
+        TimeFfiError::InvalidArgument(format!("date `{}` must be YYYY-MM-DD", value.trim()))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        calendar_list_by_range_impl, calendar_update_event_impl, configure_entry_db_path,
-        core_version, entry_create_note_impl, entry_create_task_impl, entry_schedule_impl,
-        entry_search_impl, init_logging, log_dart_event_impl, map_db_error,
-        map_log_dart_event_error, map_repo_error, map_workspace_db_error, note_create_impl,
-        note_get_impl, note_set_tags_impl, note_update_impl, notes_list_impl, ping, tags_list_impl,
-        workspace_create_folder_impl, workspace_create_note_ref_impl, workspace_delete_folder_impl,
-        workspace_list_children_impl, workspace_move_node_impl, workspace_rename_node_impl,
-        NotesFfiError, WorkspaceFfiError,
+        atom_convert_impl, atom_reminders_impl, atom_schedule_impl, atom_set_all_day_impl,
+        atom_set_due_impl, atom_set_priority_impl, atom_set_recurrence_impl, atom_task_meta_impl,
+        atom_time_entries_impl, atom_update_status_with_impl, attachment_import_impl,
+        attachment_resolve_impl, attachments_gc_impl, calendar_conflicts_impl, calendar_days_impl,
+        calendar_free_slots_impl, calendar_list_by_range_impl, calendar_month_impl,
+        calendar_update_event_impl, calendar_week_impl, configure_entry_db_path,
+        configure_time_zone, core_version, entry_create_note_impl, entry_create_task_impl,
+        entry_quick_add_parse_impl, entry_schedule_impl, entry_search_check_impl,
+        entry_search_impl, entry_search_page_impl, init_logging, links_launch_plan_impl,
+        links_list_impl, links_search_impl, local_time_windows_impl, log_dart_event_impl,
+        map_db_error, map_log_dart_event_error, map_repo_error, map_workspace_db_error,
+        note_attachments_impl, note_create_impl, note_get_impl, note_links_impl, note_outline_impl,
+        note_properties_impl, note_revision_diff_impl, note_revision_restore_impl,
+        note_revisions_list_impl, note_set_tags_impl, note_set_title_impl, note_tag_sources_impl,
+        note_title_impl, note_update_impl, notes_list_by_tags_impl, notes_list_impl, ping,
+        recurrence_delete_occurrence_impl, recurrence_edit_occurrence_impl, reminder_ack_impl,
+        reminder_add_impl, reminder_remove_impl, reminder_snooze_impl, reminders_due_impl,
+        subtask_set_parent_impl, subtasks_reorder_impl, tag_delete_impl, tag_rename_impl,
+        tags_list_impl, tags_merge_impl, tags_usage_impl, task_add_dependency_impl,
+        task_dependencies_impl, task_promote_checklist_impl, task_remove_dependency_impl,
+        task_subtasks_impl, tasks_list_inbox_unblocked_impl, tasks_list_overdue_impl,
+        tasks_list_project_order_impl, tasks_list_subtasks_impl, tasks_list_today_local_impl,
+        tasks_list_upcoming_local_impl, time_entry_add_impl, time_entry_delete_impl,
+        time_entry_update_impl, time_report_impl, time_timer_running_impl, time_timer_start_impl,
+        time_timer_stop_impl, trash_list_impl, trash_purge_expired_impl, trash_purge_impl,
+        trash_restore_impl, workspace_create_folder_impl, workspace_create_note_ref_impl,
+        workspace_delete_folder_impl, workspace_list_children_impl, workspace_move_node_impl,
+        workspace_rename_node_impl, workspace_repair_note_titles_impl, NotesFfiError,
+        WorkspaceFfiError,
     };
     use lazynote_core::db::open_db;
     use lazynote_core::LogDartEventError;
@@ -1963,25 +6516,90 @@ mod tests {
     }
 
     #[test]
-    fn entry_search_kind_filter_is_case_insensitive() {
+    fn entry_search_kind_filter_is_case_insensitive() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("entry-search-kind-case");
+
+        let note = entry_create_note_impl(format!("note {token}"));
+        assert!(note.ok, "{}", note.message);
+
+        let task = entry_create_task_impl(format!("task {token}"));
+        assert!(task.ok, "{}", task.message);
+
+        let note_response = entry_search_impl(token.clone(), Some("NOTE".to_string()), Some(50));
+        assert!(note_response.ok, "{}", note_response.message);
+        assert!(!note_response.items.is_empty());
+        assert!(note_response.items.iter().all(|item| item.kind == "note"));
+
+        let task_response = entry_search_impl(token, Some("Task".to_string()), Some(50));
+        assert!(task_response.ok, "{}", task_response.message);
+        assert!(!task_response.items.is_empty());
+        assert!(task_response.items.iter().all(|item| item.kind == "task"));
+    }
+
+    #[test]
+    fn entry_search_applies_field_filters_and_reports_syntax_errors() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("entry-search-grammar");
+
+        let note = entry_create_note_impl(format!("note {token}"));
+        assert!(note.ok, "{}", note.message);
+        let task = entry_create_task_impl(format!("task {token}"));
+        assert!(task.ok, "{}", task.message);
+        let task_id = task.atom_id.expect("task create should return atom_id");
+
+        let response = entry_search_impl(format!("{token} type:task status:todo"), None, None);
+        assert!(response.ok, "{}", response.message);
+        let ids: Vec<&str> = response
+            .items
+            .iter()
+            .map(|item| item.atom_id.as_str())
+            .collect();
+        assert_eq!(ids, vec![task_id.as_str()]);
+
+        let invalid = entry_search_impl(format!("{token} status:later"), None, None);
+        assert!(!invalid.ok);
+        assert_eq!(invalid.error_code.as_deref(), Some("invalid_query_syntax"));
+
+        let check = entry_search_check_impl("tag:work \"open".to_string());
+        assert!(!check.ok);
+        assert_eq!(check.syntax_error.as_deref(), Some("unterminated_quote"));
+        assert_eq!((check.error_start, check.error_end), (Some(9), Some(14)));
+
+        let valid = entry_search_check_impl("tag:work -archived".to_string());
+        assert!(valid.ok);
+        assert_eq!(valid.filter_count, 1);
+    }
+
+    #[test]
+    fn entry_search_page_returns_cursor_scores_and_highlights() {
         let _guard = acquire_test_db_lock();
-        let token = unique_token("entry-search-kind-case");
-
-        let note = entry_create_note_impl(format!("note {token}"));
-        assert!(note.ok, "{}", note.message);
+        let token = unique_token("entrypage");
+        for index in 0..3 {
+            let created = entry_create_note_impl(format!("{token} item {index}"));
+            assert!(created.ok, "{}", created.message);
+        }
 
-        let task = entry_create_task_impl(format!("task {token}"));
-        assert!(task.ok, "{}", task.message);
+        let first = entry_search_page_impl(token.clone(), None, Some(2), None);
+        assert!(first.ok, "{}", first.message);
+        assert_eq!(first.items.len(), 2);
+        let hit = &first.items[0];
+        assert!(hit.bm25.is_some());
+        let range = &hit.highlights[0];
+        assert_eq!(
+            &hit.snippet[range.start as usize..range.end as usize],
+            token.as_str()
+        );
+        let cursor = first.next_cursor.expect("first page should have cursor");
 
-        let note_response = entry_search_impl(token.clone(), Some("NOTE".to_string()), Some(50));
-        assert!(note_response.ok, "{}", note_response.message);
-        assert!(!note_response.items.is_empty());
-        assert!(note_response.items.iter().all(|item| item.kind == "note"));
+        let second = entry_search_page_impl(token, None, Some(2), Some(cursor));
+        assert!(second.ok, "{}", second.message);
+        assert_eq!(second.items.len(), 1);
+        assert!(second.next_cursor.is_none());
 
-        let task_response = entry_search_impl(token, Some("Task".to_string()), Some(50));
-        assert!(task_response.ok, "{}", task_response.message);
-        assert!(!task_response.items.is_empty());
-        assert!(task_response.items.iter().all(|item| item.kind == "task"));
+        let invalid =
+            entry_search_page_impl("x".to_string(), None, None, Some("bogus".to_string()));
+        assert_eq!(invalid.error_code.as_deref(), Some("invalid_cursor"));
     }
 
     #[test]
@@ -2024,6 +6642,157 @@ mod tests {
         assert_eq!(end, None);
     }
 
+    #[test]
+    fn reminders_fire_snooze_and_ack_through_ffi() {
+        let _guard = acquire_test_db_lock();
+        let start = super::now_epoch_ms() + 10 * 86_400_000;
+        let event = entry_schedule_impl(unique_token("reminder-event"), start, None);
+        let atom_id = event.atom_id.expect("schedule should return atom_id");
+
+        let added = reminder_add_impl(
+            atom_id.clone(),
+            "start".to_string(),
+            None,
+            Some(-15 * 60_000),
+        );
+        assert!(added.ok, "{}", added.message);
+        let reminder = added.reminder.expect("reminder item");
+        assert_eq!(reminder.anchor, "start");
+        assert_eq!(reminder.next_fire_at, Some(start - 15 * 60_000));
+        let listed = atom_reminders_impl(atom_id.clone());
+        assert_eq!(listed.items, vec![reminder.clone()]);
+
+        let due_for = |now_ms: i64| -> Vec<(i64, bool)> {
+            reminders_due_impl(now_ms)
+                .items
+                .into_iter()
+                .filter(|item| item.reminder.reminder_id == reminder.reminder_id)
+                .map(|item| (item.fire_at, item.snoozed))
+                .collect()
+        };
+        let fire_at = start - 15 * 60_000;
+        assert!(due_for(fire_at - 1).is_empty());
+        let due = reminders_due_impl(fire_at);
+        assert!(due.ok, "{}", due.message);
+        let item = due
+            .items
+            .iter()
+            .find(|item| item.reminder.reminder_id == reminder.reminder_id)
+            .expect("reminder should be due");
+        assert_eq!((item.atom_kind.as_str(), item.fire_at), ("event", fire_at));
+
+        let snoozed = reminder_snooze_impl(reminder.reminder_id.clone(), fire_at, start);
+        assert!(snoozed.ok, "{}", snoozed.message);
+        assert!(due_for(start - 1).is_empty());
+        assert_eq!(due_for(start), vec![(start, true)]);
+        let acked = reminder_ack_impl(reminder.reminder_id.clone(), start);
+        assert_eq!(acked.reminder.and_then(|item| item.next_fire_at), None);
+        assert!(due_for(start + 1).is_empty());
+
+        let invalid = reminder_snooze_impl(reminder.reminder_id.clone(), start, start);
+        assert_eq!(invalid.error_code.as_deref(), Some("invalid_snooze"));
+        let task = entry_create_task_impl(unique_token("reminder-task"));
+        let missing = reminder_add_impl(
+            task.atom_id.expect("task id"),
+            "due".to_string(),
+            None,
+            None,
+        );
+        assert_eq!(missing.error_code.as_deref(), Some("missing_anchor"));
+        let bad_anchor = reminder_add_impl(atom_id.clone(), "absolute".to_string(), None, None);
+        assert_eq!(bad_anchor.error_code.as_deref(), Some("invalid_anchor"));
+        let bad_id = reminder_ack_impl("nope".to_string(), 0);
+        assert_eq!(bad_id.error_code.as_deref(), Some("invalid_reminder_id"));
+
+        assert!(reminder_remove_impl(reminder.reminder_id.clone()).ok);
+        let again = reminder_remove_impl(reminder.reminder_id.clone());
+        assert!(again.ok && again.reminder.is_none());
+        assert!(atom_reminders_impl(atom_id).items.is_empty());
+    }
+
+    #[test]
+    fn time_tracking_timers_entries_and_report_through_ffi() {
+        let _guard = acquire_test_db_lock();
+        let tag = unique_token("billing");
+        let task = entry_create_task_impl(format!("{} #{tag}", unique_token("time-task")));
+        let task_id = task.atom_id.expect("task id");
+        let other = entry_create_task_impl(unique_token("time-other"));
+        let other_id = other.atom_id.expect("other task id");
+        let noon = chrono::NaiveDate::from_ymd_opt(2099, 3, 10)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_millis();
+
+        let started = time_timer_start_impl(task_id.clone(), noon);
+        assert!(started.ok, "{}", started.message);
+        let first = started.entry.expect("running entry");
+        assert!(first.running);
+        let switched = time_timer_start_impl(other_id.clone(), noon + 30 * 60_000);
+        let second = switched.entry.expect("switched entry");
+        assert_eq!(second.atom_id, other_id);
+        assert_eq!(
+            time_timer_running_impl().entry.map(|entry| entry.entry_id),
+            Some(second.entry_id.clone())
+        );
+
+        // Completing with a stop time ends the running timer.
+        let done = atom_update_status_with_impl(
+            other_id.clone(),
+            Some("done".to_string()),
+            Some(noon + 45 * 60_000),
+        );
+        assert!(done.ok, "{}", done.message);
+        assert!(time_timer_running_impl().entry.is_none());
+        let stopped = time_timer_stop_impl(noon + 50 * 60_000);
+        assert!(stopped.ok && stopped.entry.is_none());
+
+        let added = time_entry_add_impl(
+            task_id.clone(),
+            noon + 60 * 60_000,
+            noon + 90 * 60_000,
+            Some("review".to_string()),
+        );
+        assert!(added.ok, "{}", added.message);
+        let manual = added.entry.expect("manual entry");
+        let edited = time_entry_update_impl(
+            manual.entry_id.clone(),
+            None,
+            None,
+            Some(noon + 120 * 60_000),
+            None,
+        );
+        assert_eq!(
+            edited.entry.map(|entry| (entry.duration_ms, entry.note)),
+            Some((60 * 60_000, Some("review".to_string())))
+        );
+        let listed = atom_time_entries_impl(task_id.clone(), noon);
+        assert_eq!(listed.items.len(), 2);
+        assert_eq!(listed.total_ms, 90 * 60_000);
+
+        let report = time_report_impl("2099-03-10".to_string(), "2099-03-10".to_string(), noon);
+        assert!(report.ok, "{}", report.message);
+        assert_eq!(report.days.len(), 1);
+        let tagged: Vec<_> = report
+            .tags
+            .iter()
+            .filter(|item| item.tag == tag)
+            .map(|item| item.total_ms)
+            .collect();
+        assert_eq!(tagged, vec![90 * 60_000]);
+
+        let invalid = time_entry_add_impl(task_id.clone(), noon, noon, None);
+        assert_eq!(invalid.error_code.as_deref(), Some("invalid_time_range"));
+        let bad_date = time_report_impl("03/10".to_string(), "2099-03-10".to_string(), noon);
+        assert_eq!(bad_date.error_code.as_deref(), Some("invalid_argument"));
+        let bad_id = time_entry_delete_impl("nope".to_string());
+        assert_eq!(bad_id.error_code.as_deref(), Some("invalid_time_entry_id"));
+        assert!(time_entry_delete_impl(manual.entry_id.clone()).ok);
+        let again = time_entry_delete_impl(manual.entry_id);
+        assert!(again.ok && again.entry.is_none());
+    }
+
     #[test]
     fn entry_schedule_rejects_reversed_time_range() {
         let _guard = acquire_test_db_lock();
@@ -2080,6 +6849,718 @@ mod tests {
         );
     }
 
+    #[test]
+    fn notes_list_by_tags_filters_with_boolean_expression() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("tagexpr");
+        let keep_tag = format!("{token}-keep");
+        let drop_tag = format!("{token}-drop");
+        let kept = note_create_impl("kept".to_string());
+        let kept_id = kept.note.expect("kept note").atom_id;
+        let dropped = note_create_impl("dropped".to_string());
+        let dropped_id = dropped.note.expect("dropped note").atom_id;
+        assert!(note_set_tags_impl(kept_id.clone(), vec![keep_tag.clone()]).ok);
+        assert!(note_set_tags_impl(dropped_id, vec![keep_tag.clone(), drop_tag.clone()]).ok);
+
+        let response =
+            notes_list_by_tags_impl(format!("{keep_tag} AND NOT {drop_tag}"), Some(50), None);
+        assert!(response.ok, "{}", response.message);
+        let ids: Vec<&str> = response
+            .items
+            .iter()
+            .map(|item| item.atom_id.as_str())
+            .collect();
+        assert_eq!(ids, vec![kept_id.as_str()]);
+
+        let invalid = notes_list_by_tags_impl("(work OR".to_string(), None, None);
+        assert!(!invalid.ok);
+        assert_eq!(invalid.error_code.as_deref(), Some("invalid_tag_expr"));
+    }
+
+    #[test]
+    fn inline_hashtags_follow_content_and_keep_manual_tags() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("inline");
+        let created = note_create_impl(format!("standup #{token}-meeting"));
+        let atom_id = created.note.expect("created note").atom_id;
+        assert!(
+            note_set_tags_impl(
+                atom_id.clone(),
+                vec![format!("{token}-client"), format!("{token}-meeting")]
+            )
+            .ok
+        );
+
+        let sources = note_tag_sources_impl(atom_id.clone());
+        assert!(sources.ok, "{}", sources.message);
+        assert_eq!(sources.inline_tags, vec![format!("{token}-meeting")]);
+        assert_eq!(
+            sources.manual_tags,
+            vec![format!("{token}-client"), format!("{token}-meeting")]
+        );
+
+        let updated = note_update_impl(atom_id.clone(), "standup notes".to_string());
+        let tags = updated.note.expect("updated note").tags;
+        assert_eq!(
+            tags,
+            vec![format!("{token}-client"), format!("{token}-meeting")]
+        );
+        let sources = note_tag_sources_impl(atom_id.clone());
+        assert!(sources.inline_tags.is_empty());
+
+        let invalid = note_tag_sources_impl("not-a-uuid".to_string());
+        assert_eq!(invalid.error_code.as_deref(), Some("invalid_note_id"));
+    }
+
+    #[test]
+    fn note_outline_flattens_heading_tree_with_stats() {
+        let _guard = acquire_test_db_lock();
+        let created =
+            note_create_impl("# Plan\n## Goals\n- [x] draft\n- [ ] review\n# Notes".to_string());
+        let atom_id = created.note.expect("created note").atom_id;
+
+        let response = note_outline_impl(atom_id);
+        assert!(response.ok, "{}", response.message);
+        let summary: Vec<(&str, u32, Option<u32>)> = response
+            .headings
+            .iter()
+            .map(|h| (h.text.as_str(), h.depth, h.parent_index))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("Plan", 0, None), ("Goals", 1, Some(0)), ("Notes", 0, None)]
+        );
+        assert_eq!(
+            (
+                response.headings[1].byte_start,
+                response.headings[1].byte_end
+            ),
+            (7, 16)
+        );
+        assert_eq!((response.checklist_done, response.checklist_total), (1, 2));
+        assert_eq!(response.word_count, 5);
+
+        let invalid = note_outline_impl("bad".to_string());
+        assert_eq!(invalid.error_code.as_deref(), Some("invalid_note_id"));
+    }
+
+    #[test]
+    fn note_set_title_renames_title_following_note_refs() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("title");
+        let created = note_create_impl(format!("# {token}\nbody"));
+        let atom_id = created.note.expect("created note").atom_id;
+        let folder = workspace_create_folder_impl(None, format!("folder-{token}"))
+            .node
+            .expect("folder")
+            .node_id;
+        let node = workspace_create_note_ref_impl(Some(folder.clone()), atom_id.clone(), None)
+            .node
+            .expect("note ref");
+        assert_eq!(node.display_name, token);
+
+        let response = note_set_title_impl(atom_id.clone(), Some(format!("Custom {token}")));
+        assert!(response.ok, "{}", response.message);
+        assert_eq!(response.derived_title.as_deref(), Some(token.as_str()));
+        let children = workspace_list_children_impl(Some(folder));
+        assert_eq!(children.items[0].display_name, format!("Custom {token}"));
+
+        let cleared = note_set_title_impl(atom_id.clone(), None);
+        assert_eq!(cleared.title.as_deref(), Some(token.as_str()));
+        assert_eq!(note_title_impl(atom_id).title_override, None);
+        let repair = workspace_repair_note_titles_impl();
+        assert!(repair.ok, "{}", repair.message);
+
+        let missing = note_title_impl(uuid::Uuid::new_v4().to_string());
+        assert_eq!(missing.error_code.as_deref(), Some("note_not_found"));
+    }
+
+    #[test]
+    fn atom_convert_switches_kind_and_requires_event_start() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("convert");
+        let created = note_create_impl(format!("# {token}"));
+        let atom_id = created.note.expect("created note").atom_id;
+
+        let task = atom_convert_impl(atom_id.clone(), "task".to_string(), None, None);
+        assert!(task.ok, "{}", task.message);
+        assert_eq!(task.kind.as_deref(), Some("task"));
+        assert_eq!(task.task_status.as_deref(), Some("todo"));
+
+        let no_start = atom_convert_impl(atom_id.clone(), "event".to_string(), None, None);
+        assert_eq!(no_start.error_code.as_deref(), Some("event_start_required"));
+        let inverted = atom_convert_impl(
+            atom_id.clone(),
+            "event".to_string(),
+            Some(2_000),
+            Some(1_000),
+        );
+        assert_eq!(inverted.error_code.as_deref(), Some("invalid_time_range"));
+
+        let event = atom_convert_impl(atom_id.clone(), "EVENT".to_string(), Some(1_000), None);
+        assert!(event.ok, "{}", event.message);
+        assert_eq!(event.atom_id.as_deref(), Some(atom_id.as_str()));
+        assert_eq!((event.start_at, event.end_at), (Some(1_000), None));
+
+        let note = atom_convert_impl(atom_id.clone(), "note".to_string(), None, None);
+        assert_eq!(note.task_status, None);
+        assert_eq!(
+            note_title_impl(atom_id).title.as_deref(),
+            Some(token.as_str())
+        );
+
+        let bad_kind = atom_convert_impl(
+            uuid::Uuid::new_v4().to_string(),
+            "all".to_string(),
+            None,
+            None,
+        );
+        assert_eq!(bad_kind.error_code.as_deref(), Some("invalid_argument"));
+        let missing = atom_convert_impl(
+            uuid::Uuid::new_v4().to_string(),
+            "task".to_string(),
+            None,
+            None,
+        );
+        assert_eq!(missing.error_code.as_deref(), Some("atom_not_found"));
+    }
+
+    #[test]
+    fn overdue_list_orders_by_priority() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("overdue");
+        let low = entry_create_task_impl(format!("low {token}"))
+            .atom_id
+            .unwrap();
+        let high = entry_create_task_impl(format!("high {token}"))
+            .atom_id
+            .unwrap();
+        for (id, priority) in [(&low, "low"), (&high, "high")] {
+            let response = atom_set_priority_impl(id.clone(), Some(priority.to_string()));
+            assert!(response.ok, "{}", response.message);
+            assert_eq!(response.priority.as_deref(), Some(priority));
+            let due = atom_set_due_impl(id.clone(), Some(1_000));
+            assert_eq!(due.due_at, Some(1_000));
+        }
+
+        let overdue = tasks_list_overdue_impl(2_000, Some(100), None);
+        assert!(overdue.ok, "{}", overdue.message);
+        let ids: Vec<&str> = overdue
+            .items
+            .iter()
+            .map(|item| item.atom_id.as_str())
+            .filter(|id| *id == low || *id == high)
+            .collect();
+        assert_eq!(ids, vec![high.as_str(), low.as_str()]);
+
+        let cleared = atom_set_priority_impl(low.clone(), None);
+        assert_eq!(cleared.priority, None);
+        assert_eq!(atom_task_meta_impl(low).due_at, Some(1_000));
+        let invalid = atom_set_priority_impl(high, Some("urgent".to_string()));
+        assert_eq!(invalid.error_code.as_deref(), Some("invalid_argument"));
+    }
+
+    #[test]
+    fn subtasks_attach_reorder_promote_and_reject_cycles() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("subtasks");
+        let parent = entry_create_task_impl(format!("trip {token}\n- [x] tickets\n- [ ] hotel"))
+            .atom_id
+            .unwrap();
+        let child = entry_create_task_impl(format!("passport {token}"))
+            .atom_id
+            .unwrap();
+
+        let attached = subtask_set_parent_impl(child.clone(), Some(parent.clone()), None);
+        assert!(attached.ok, "{}", attached.message);
+        assert_eq!(attached.parent_id.as_deref(), Some(parent.as_str()));
+
+        let promoted = task_promote_checklist_impl(parent.clone());
+        assert!(promoted.ok, "{}", promoted.message);
+        assert_eq!(promoted.subtask_ids.len(), 3);
+        assert_eq!(promoted.subtask_ids[0], child);
+        assert_eq!((promoted.done, promoted.total), (1, 3));
+
+        let mut reversed = promoted.subtask_ids.clone();
+        reversed.reverse();
+        let reordered = subtasks_reorder_impl(parent.clone(), reversed.clone());
+        assert_eq!(reordered.subtask_ids, reversed);
+        let listed = tasks_list_subtasks_impl(parent.clone());
+        let contents: Vec<&str> = listed.items.iter().map(|i| i.content.as_str()).collect();
+        assert_eq!(contents[0], "hotel");
+        let partial = subtasks_reorder_impl(parent.clone(), vec![child.clone()]);
+        assert_eq!(partial.error_code.as_deref(), Some("invalid_argument"));
+
+        let cycle = subtask_set_parent_impl(parent.clone(), Some(child.clone()), None);
+        assert_eq!(cycle.error_code.as_deref(), Some("cycle_detected"));
+        let note = entry_create_note_impl(format!("note {token}"))
+            .atom_id
+            .unwrap();
+        let not_task = subtask_set_parent_impl(child.clone(), Some(note), None);
+        assert_eq!(not_task.error_code.as_deref(), Some("not_a_task"));
+
+        let detached = subtask_set_parent_impl(child.clone(), None, None);
+        assert_eq!(detached.parent_id, None);
+        assert_eq!(task_subtasks_impl(parent).subtask_ids.len(), 2);
+    }
+
+    #[test]
+    fn dependencies_block_tasks_and_order_projects() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("deps");
+        let project = entry_create_task_impl(format!("release {token}"))
+            .atom_id
+            .unwrap();
+        let deploy = entry_create_task_impl(format!("deploy {token}"))
+            .atom_id
+            .unwrap();
+        let review = entry_create_task_impl(format!("review {token}"))
+            .atom_id
+            .unwrap();
+        for id in [&deploy, &review] {
+            assert!(subtask_set_parent_impl(id.clone(), Some(project.clone()), None).ok);
+        }
+
+        let added = task_add_dependency_impl(deploy.clone(), review.clone());
+        assert!(added.ok, "{}", added.message);
+        assert!(added.blocked);
+        assert_eq!(added.prerequisite_ids, vec![review.clone()]);
+        assert_eq!(
+            task_dependencies_impl(review.clone()).dependent_ids,
+            vec![deploy.clone()]
+        );
+
+        let order = tasks_list_project_order_impl(project.clone());
+        let ids: Vec<&str> = order.items.iter().map(|i| i.atom_id.as_str()).collect();
+        assert_eq!(ids, vec![review.as_str(), deploy.as_str()]);
+
+        let unblocked = tasks_list_inbox_unblocked_impl(Some(100), None);
+        assert!(unblocked.items.iter().all(|item| item.atom_id != deploy));
+        assert!(unblocked.items.iter().any(|item| item.atom_id == review));
+
+        let cycle = task_add_dependency_impl(review.clone(), deploy.clone());
+        assert_eq!(cycle.error_code.as_deref(), Some("cycle_detected"));
+
+        let removed = task_remove_dependency_impl(deploy.clone(), review);
+        assert!(removed.ok, "{}", removed.message);
+        assert!(!removed.blocked);
+        assert!(removed.prerequisite_ids.is_empty());
+    }
+
+    #[test]
+    fn recurrence_endpoints_set_rules_and_edit_occurrences() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("recurrence");
+        let day = 86_400_000;
+        let start = 1_772_442_000_000; // 2026-03-02T09:00:00Z
+        let series = create_test_event(&format!("standup {token}"), start, start + 900_000);
+
+        let not_recurring =
+            recurrence_delete_occurrence_impl(series.clone(), start, "this".to_string());
+        assert_eq!(not_recurring.error_code.as_deref(), Some("not_recurring"));
+        let malformed = atom_set_recurrence_impl(series.clone(), Some("FREQ=HOURLY".to_string()));
+        assert_eq!(malformed.error_code.as_deref(), Some("invalid_argument"));
+
+        let set = atom_set_recurrence_impl(series.clone(), Some("freq=daily;count=4".to_string()));
+        assert!(set.ok, "{}", set.message);
+        assert_eq!(set.recurrence_rule.as_deref(), Some("FREQ=DAILY;COUNT=4"));
+
+        let bad_scope = recurrence_delete_occurrence_impl(series.clone(), start, "all".to_string());
+        assert_eq!(bad_scope.error_code.as_deref(), Some("invalid_argument"));
+        let off_grid =
+            recurrence_delete_occurrence_impl(series.clone(), start + 1, "this".to_string());
+        assert_eq!(off_grid.error_code.as_deref(), Some("occurrence_not_found"));
+        let rule_on_one = recurrence_edit_occurrence_impl(
+            series.clone(),
+            start + day,
+            "this".to_string(),
+            None,
+            None,
+            None,
+            None,
+            Some("FREQ=WEEKLY".to_string()),
+        );
+        assert_eq!(rule_on_one.error_code.as_deref(), Some("invalid_argument"));
+
+        let detached = recurrence_edit_occurrence_impl(
+            series.clone(),
+            start + day,
+            "this".to_string(),
+            Some(format!("standup moved {token}")),
+            Some(start + day + 3_600_000),
+            None,
+            None,
+            None,
+        );
+        assert!(detached.ok, "{}", detached.message);
+        assert_ne!(detached.atom_id.as_deref(), Some(series.as_str()));
+        assert_eq!(detached.recurrence_rule, None);
+
+        let split = recurrence_edit_occurrence_impl(
+            series.clone(),
+            start + 2 * day,
+            "this_and_following".to_string(),
+            None,
+            None,
+            None,
+            Some("done".to_string()),
+            Some("FREQ=WEEKLY".to_string()),
+        );
+        assert!(split.ok, "{}", split.message);
+        let following = split.atom_id.unwrap();
+        assert_ne!(following, series);
+        assert_eq!(split.recurrence_rule.as_deref(), Some("FREQ=WEEKLY"));
+
+        let truncated = recurrence_delete_occurrence_impl(
+            following.clone(),
+            start + 2 * day,
+            "this_and_following".to_string(),
+        );
+        assert!(truncated.ok, "{}", truncated.message);
+        assert_eq!(truncated.atom_id.as_deref(), Some(following.as_str()));
+        assert_eq!(truncated.recurrence_rule, None);
+
+        let cleared = atom_set_recurrence_impl(series.clone(), None);
+        assert!(cleared.ok, "{}", cleared.message);
+        assert_eq!(cleared.recurrence_rule, None);
+    }
+
+    #[test]
+    fn time_zone_drives_local_windows_and_all_day_sections() {
+        let _guard = acquire_test_db_lock();
+        assert!(!configure_time_zone("Mars/Olympus".to_string(), "monday".to_string()).is_empty());
+        assert!(!configure_time_zone("UTC".to_string(), "someday".to_string()).is_empty());
+        assert_eq!(
+            configure_time_zone("America/New_York".to_string(), "Sunday".to_string()),
+            ""
+        );
+
+        // 2026-03-08 (spring forward) is 23h long in New York.
+        let windows = local_time_windows_impl(1_772_985_600_000);
+        assert_eq!(windows.time_zone, "America/New_York");
+        assert_eq!(windows.week_start, "sunday");
+        assert_eq!(windows.day_start_ms, 1_772_946_000_000);
+        assert_eq!(
+            windows.day_end_ms - windows.day_start_ms + 1,
+            23 * 3_600_000
+        );
+        assert_eq!(windows.week_start_ms, windows.day_start_ms);
+
+        let token = unique_token("allday");
+        let task = entry_create_task_impl(format!("holiday {token}"))
+            .atom_id
+            .unwrap();
+        let bad_date = atom_set_all_day_impl(task.clone(), "2099-13-01".to_string(), None);
+        assert_eq!(bad_date.error_code.as_deref(), Some("invalid_argument"));
+        let reversed = atom_set_all_day_impl(
+            task.clone(),
+            "2099-01-06".to_string(),
+            Some("2099-01-05".to_string()),
+        );
+        assert_eq!(reversed.error_code.as_deref(), Some("invalid_time_range"));
+
+        let set = atom_set_all_day_impl(task.clone(), "2099-01-05".to_string(), None);
+        assert!(set.ok, "{}", set.message);
+        assert!(set.all_day);
+        assert_eq!(set.start_date.as_deref(), Some("2099-01-05"));
+        assert_eq!(set.end_date.as_deref(), Some("2099-01-05"));
+        assert_eq!(atom_schedule_impl(task.clone()), set_as_loaded(&set));
+
+        // 2099-01-06T03:00Z is still January 5 in New York.
+        let today = tasks_list_today_local_impl(4_071_351_600_000, false, Some(50), None);
+        assert!(today.ok, "{}", today.message);
+        assert!(today.items.iter().any(|item| item.atom_id == task));
+        let upcoming = tasks_list_upcoming_local_impl(4_071_351_600_000, Some(50), None);
+        assert!(upcoming.items.iter().all(|item| item.atom_id != task));
+
+        assert_eq!(
+            configure_time_zone("UTC".to_string(), "monday".to_string()),
+            ""
+        );
+        let utc_today = tasks_list_today_local_impl(4_071_351_600_000, false, Some(50), None);
+        assert!(utc_today.items.iter().all(|item| item.atom_id != task));
+    }
+
+    fn set_as_loaded(set: &super::AtomScheduleResponse) -> super::AtomScheduleResponse {
+        super::AtomScheduleResponse {
+            message: "Loaded.".to_string(),
+            ..set.clone()
+        }
+    }
+
+    #[test]
+    fn calendar_views_bucket_days_and_find_conflicts_and_free_slots() {
+        let _guard = acquire_test_db_lock();
+        assert_eq!(
+            configure_time_zone("UTC".to_string(), "monday".to_string()),
+            ""
+        );
+        let at = |hour: u32, minute: u32| {
+            chrono::NaiveDate::from_ymd_opt(2097, 6, 3)
+                .unwrap()
+                .and_hms_opt(hour, minute, 0)
+                .unwrap()
+                .and_utc()
+                .timestamp_millis()
+        };
+        let token = unique_token("calendar");
+        let review = entry_schedule_impl(format!("review {token}"), at(9, 0), Some(at(10, 0)))
+            .atom_id
+            .unwrap();
+        let call = entry_schedule_impl(format!("call {token}"), at(9, 30), Some(at(11, 0)))
+            .atom_id
+            .unwrap();
+        let ours = |id: &str| id == review || id == call;
+
+        let days = calendar_days_impl("2097-06-02".to_string(), "2097-06-03".to_string());
+        assert!(days.ok, "{}", days.message);
+        assert_eq!(days.days.len(), 2);
+        assert_eq!(days.days[1].date, "2097-06-03");
+        let layout: Vec<_> = days.days[1]
+            .timed
+            .iter()
+            .filter(|entry| ours(&entry.atom_id))
+            .map(|entry| (entry.atom_id.clone(), entry.column, entry.column_count))
+            .collect();
+        // The shared test DB may hold events from earlier runs at the same times.
+        assert_eq!(layout.len(), 2);
+        assert_eq!(layout[0].0, review);
+        assert_ne!(layout[0].1, layout[1].1);
+        assert!(layout.iter().all(|(_, _, count)| *count >= 2));
+        assert!(days.days[0].timed.iter().all(|entry| !ours(&entry.atom_id)));
+
+        let conflicts = calendar_conflicts_impl("2097-06-03".to_string(), "2097-06-03".to_string());
+        assert!(conflicts.ok, "{}", conflicts.message);
+        let conflict = conflicts
+            .items
+            .iter()
+            .find(|item| ours(&item.first_atom_id) && ours(&item.second_atom_id))
+            .expect("review/call conflict");
+        assert_eq!(
+            (conflict.overlap_start_ms, conflict.overlap_end_ms),
+            (at(9, 30), at(10, 0))
+        );
+
+        let slots = calendar_free_slots_impl(at(8, 0), at(12, 0), 45);
+        assert!(slots.ok, "{}", slots.message);
+        assert_eq!(
+            slots.slots.first().map(|slot| slot.start_ms),
+            Some(at(8, 0))
+        );
+        assert!(slots
+            .slots
+            .iter()
+            .all(|slot| slot.end_ms <= at(9, 0) || slot.start_ms >= at(11, 0)));
+
+        let reversed = calendar_days_impl("2097-06-03".to_string(), "2097-06-02".to_string());
+        assert_eq!(reversed.error_code.as_deref(), Some("invalid_time_range"));
+        let malformed = calendar_conflicts_impl("2097-6-x".to_string(), "2097-06-03".to_string());
+        assert_eq!(malformed.error_code.as_deref(), Some("invalid_argument"));
+        let too_long = calendar_days_impl("2097-01-01".to_string(), "2097-12-31".to_string());
+        assert_eq!(too_long.error_code.as_deref(), Some("invalid_argument"));
+        let zero = calendar_free_slots_impl(at(8, 0), at(12, 0), 0);
+        assert_eq!(zero.error_code.as_deref(), Some("invalid_argument"));
+        assert_eq!(calendar_month_impl(at(8, 0)).days.len(), 30);
+        assert_eq!(calendar_week_impl(at(8, 0)).days[0].date, "2097-06-03");
+    }
+
+    #[test]
+    fn entry_quick_add_parse_returns_draft_in_configured_time_zone() {
+        let _guard = acquire_test_db_lock();
+        assert_eq!(
+            configure_time_zone("Asia/Shanghai".to_string(), "monday".to_string()),
+            ""
+        );
+        // Wednesday 2026-05-06 10:00 UTC (18:00 in Shanghai).
+        let text = "周五下午三点 开会 #工作 !高";
+        let draft = entry_quick_add_parse_impl(text.to_string(), 1_778_061_600_000);
+        assert!(draft.ok, "{}", draft.message);
+        assert_eq!(draft.kind, "event");
+        assert_eq!(draft.title, "开会");
+        // Friday 15:00 in Shanghai.
+        assert_eq!(draft.start_at, Some(1_778_223_600_000));
+        assert_eq!(draft.end_at, draft.start_at);
+        assert_eq!(draft.tags, vec!["工作".to_string()]);
+        assert_eq!(draft.priority.as_deref(), Some("high"));
+        let kinds: Vec<_> = draft
+            .tokens
+            .iter()
+            .map(|token| (token.kind.as_str(), token.char_start, token.char_end))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("date", 0, 2),
+                ("time", 2, 6),
+                ("tag", 10, 13),
+                ("priority", 14, 16)
+            ]
+        );
+
+        assert_eq!(
+            configure_time_zone("UTC".to_string(), "monday".to_string()),
+            ""
+        );
+        let plain = entry_quick_add_parse_impl("buy milk".to_string(), 1_778_061_600_000);
+        assert_eq!(
+            (plain.kind.as_str(), plain.title.as_str()),
+            ("task", "buy milk")
+        );
+        assert!(plain.tokens.is_empty() && plain.start_at.is_none());
+    }
+
+    #[test]
+    fn note_properties_expose_typed_frontmatter_values() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("props");
+        let created = note_create_impl(format!(
+            "---\nstatus: draft\nrating: 3\ntags: [{token}]\n---\nbody"
+        ));
+        let atom_id = created.note.expect("created note").atom_id;
+
+        let response = note_properties_impl(atom_id.clone());
+        assert!(response.ok, "{}", response.message);
+        let summary: Vec<(&str, &str)> = response
+            .items
+            .iter()
+            .map(|item| (item.key.as_str(), item.value_type.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("status", "string"), ("rating", "number"), ("tags", "list")]
+        );
+        assert_eq!(response.items[0].text.as_deref(), Some("draft"));
+        assert_eq!(response.items[1].number, Some(3.0));
+        assert_eq!(response.items[2].items, vec![token.clone()]);
+
+        let sources = note_tag_sources_impl(atom_id);
+        assert_eq!(sources.inline_tags, vec![token]);
+
+        let missing = note_properties_impl(uuid::Uuid::new_v4().to_string());
+        assert_eq!(missing.error_code.as_deref(), Some("note_not_found"));
+    }
+
+    #[test]
+    fn tag_rename_merge_delete_report_affected_atoms_and_usage() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("taghier");
+        let created = note_create_impl("hierarchical tag source".to_string());
+        let atom_id = created.note.expect("created note").atom_id;
+        assert!(note_set_tags_impl(atom_id.clone(), vec![format!("{token}/Client")]).ok);
+
+        let usage = tags_usage_impl();
+        assert!(usage.ok, "{}", usage.message);
+        let parent = usage
+            .items
+            .iter()
+            .find(|item| item.name == token)
+            .expect("implicit parent should be listed");
+        assert_eq!((parent.direct_count, parent.total_count), (0, 1));
+
+        let renamed = tag_rename_impl(token.clone(), format!("{token}-job"));
+        assert!(renamed.ok, "{}", renamed.message);
+        assert_eq!(renamed.affected_atoms, 1);
+        let filtered = notes_list_impl(Some(format!("{token}-job")), Some(50), None);
+        assert!(filtered.items.iter().any(|item| item.atom_id == atom_id));
+
+        let merged = tags_merge_impl(vec![format!("{token}-job/client")], format!("{token}-x"));
+        assert!(merged.ok, "{}", merged.message);
+        let deleted = tag_delete_impl(format!("{token}-x"));
+        assert!(deleted.ok, "{}", deleted.message);
+        assert_eq!(deleted.affected_atoms, 1);
+
+        let missing = tag_delete_impl(format!("{token}-x"));
+        assert_eq!(missing.error_code.as_deref(), Some("tag_not_found"));
+        let blank = tag_rename_impl(" / ".to_string(), "x".to_string());
+        assert_eq!(blank.error_code.as_deref(), Some("invalid_tag"));
+    }
+
+    #[test]
+    fn attachment_import_resolve_and_list_round_trip() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("attach");
+        let source = std::env::temp_dir().join(format!("{token}.png"));
+        std::fs::write(&source, token.as_bytes()).expect("write source");
+
+        let imported = attachment_import_impl(source.display().to_string());
+        assert!(imported.ok, "{}", imported.message);
+        let item = imported.attachment.expect("imported attachment");
+        assert_eq!(item.mime_type.as_deref(), Some("image/png"));
+        let again = attachment_import_impl(source.display().to_string());
+        assert!(again.deduplicated);
+        std::fs::remove_file(&source).expect("remove source");
+
+        let note = note_create_impl(format!("![photo]({})", item.uri));
+        let atom_id = note.note.expect("created note").atom_id;
+        let listed = note_attachments_impl(atom_id);
+        assert!(listed.ok, "{}", listed.message);
+        assert_eq!(listed.items.len(), 1);
+        assert_eq!(listed.items[0].ref_count, 1);
+
+        let resolved = attachment_resolve_impl(item.uri.clone());
+        assert!(resolved.ok, "{}", resolved.message);
+        let path = resolved.file_path.expect("blob path");
+        assert_eq!(std::fs::read(path).expect("read blob"), token.as_bytes());
+
+        let gc = attachments_gc_impl(None);
+        assert!(gc.ok, "{}", gc.message);
+        assert!(attachment_resolve_impl(item.uri).ok);
+
+        let missing = attachment_import_impl(source.display().to_string());
+        assert_eq!(missing.error_code.as_deref(), Some("source_not_found"));
+        let blank = attachment_import_impl("  ".to_string());
+        assert_eq!(blank.error_code.as_deref(), Some("invalid_path"));
+        let invalid = attachment_resolve_impl("attachment://xyz".to_string());
+        assert_eq!(
+            invalid.error_code.as_deref(),
+            Some("invalid_attachment_uri")
+        );
+    }
+
+    #[test]
+    fn trash_list_restore_and_purge_round_trip() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("trash");
+        let created = note_create_impl(format!("trash me {token}"));
+        let atom_id = created.note.expect("created note").atom_id;
+        let conn = open_db(super::resolve_entry_db_path()).expect("open db");
+        conn.execute(
+            "UPDATE atoms SET is_deleted = 1 WHERE uuid = ?1;",
+            [atom_id.as_str()],
+        )
+        .expect("soft delete");
+
+        let listed = trash_list_impl(Some("note".to_string()), Some(100), None);
+        assert!(listed.ok, "{}", listed.message);
+        assert!(listed.items.iter().any(|item| item.atom_id == atom_id));
+
+        let restored = trash_restore_impl(atom_id.clone());
+        assert!(restored.ok, "{}", restored.message);
+        assert!(restored.note_ref_node_id.is_some());
+        assert!(note_get_impl(atom_id.clone()).ok);
+
+        let active = trash_purge_impl(atom_id.clone());
+        assert_eq!(active.error_code.as_deref(), Some("atom_not_found"));
+
+        conn.execute(
+            "UPDATE atoms SET is_deleted = 1 WHERE uuid = ?1;",
+            [atom_id.as_str()],
+        )
+        .expect("soft delete again");
+        let purged = trash_purge_impl(atom_id.clone());
+        assert!(purged.ok, "{}", purged.message);
+        assert_eq!((purged.purged, purged.kept_for_sync), (1, 0));
+
+        let disabled = trash_purge_expired_impl(Some(0));
+        assert!(disabled.ok);
+        assert_eq!(disabled.purged, 0);
+
+        let invalid_kind = trash_list_impl(Some("memo".to_string()), None, None);
+        assert_eq!(invalid_kind.error_code.as_deref(), Some("invalid_argument"));
+        let invalid_id = trash_restore_impl("not-a-uuid".to_string());
+        assert_eq!(invalid_id.error_code.as_deref(), Some("invalid_atom_id"));
+    }
+
     #[test]
     fn notes_list_caps_limit_and_filters_single_tag() {
         let _guard = acquire_test_db_lock();
@@ -2159,6 +7640,102 @@ mod tests {
         assert_eq!(response.error_code.as_deref(), Some("invalid_note_id"));
     }
 
+    #[test]
+    fn note_revisions_list_diff_and_restore_round_trip() {
+        let _guard = acquire_test_db_lock();
+        let created = note_create_impl("keep\nlost paragraph".to_string());
+        assert!(created.ok, "{}", created.message);
+        let atom_id = created.note.expect("created note payload").atom_id;
+        let updated = note_update_impl(atom_id.clone(), "keep".to_string());
+        assert!(updated.ok, "{}", updated.message);
+
+        let listed = note_revisions_list_impl(atom_id.clone(), Some(500), None);
+        assert!(listed.ok, "{}", listed.message);
+        assert_eq!(listed.applied_limit, 100);
+        assert_eq!(listed.items.len(), 1);
+        let revision_id = listed.items[0].revision_id;
+
+        let diff = note_revision_diff_impl(atom_id.clone(), revision_id, None);
+        assert!(diff.ok, "{}", diff.message);
+        assert!(diff
+            .lines
+            .iter()
+            .any(|line| line.kind == "removed" && line.text == "lost paragraph"));
+
+        let restored = note_revision_restore_impl(atom_id, revision_id);
+        assert!(restored.ok, "{}", restored.message);
+        assert_eq!(
+            restored.note.expect("restored note payload").content,
+            "keep\nlost paragraph"
+        );
+    }
+
+    #[test]
+    fn note_revision_restore_unknown_revision_returns_error_code() {
+        let _guard = acquire_test_db_lock();
+        let created = note_create_impl("single version".to_string());
+        assert!(created.ok, "{}", created.message);
+        let atom_id = created.note.expect("created note payload").atom_id;
+
+        let response = note_revision_restore_impl(atom_id, -1);
+        assert!(!response.ok);
+        assert_eq!(response.error_code.as_deref(), Some("revision_not_found"));
+    }
+
+    #[test]
+    fn note_links_returns_outgoing_links_and_backlinks() {
+        let _guard = acquire_test_db_lock();
+        let target = note_create_impl("link target".to_string());
+        assert!(target.ok, "{}", target.message);
+        let target_id = target.note.expect("target note payload").atom_id;
+        let source = note_create_impl(format!("see [[{target_id}|spec]] and [[Missing]]"));
+        assert!(source.ok, "{}", source.message);
+        let source_id = source.note.expect("source note payload").atom_id;
+
+        let outgoing = note_links_impl(source_id.clone());
+        assert!(outgoing.ok, "{}", outgoing.message);
+        assert_eq!(outgoing.outgoing.len(), 2);
+        assert_eq!(outgoing.outgoing[0].kind, "uuid");
+        assert_eq!(
+            outgoing.outgoing[0].target_atom_id.as_deref(),
+            Some(target_id.as_str())
+        );
+        assert!(outgoing.outgoing[1].target_atom_id.is_none());
+
+        let incoming = note_links_impl(target_id);
+        assert!(incoming.ok, "{}", incoming.message);
+        assert_eq!(incoming.backlinks.len(), 1);
+        assert_eq!(incoming.backlinks[0].source_atom_id, source_id);
+    }
+
+    #[test]
+    fn links_list_search_and_launch_plan_round_trip() {
+        let _guard = acquire_test_db_lock();
+        let marker = unique_token("ffi-links");
+        let created = note_create_impl(format!(
+            "[docs](https://{marker}.example/docs) https://{marker}.example/ci [x](cmd://run)"
+        ));
+        assert!(created.ok, "{}", created.message);
+        let atom_id = created.note.expect("created note payload").atom_id;
+
+        let listed = links_list_impl(atom_id.clone());
+        assert!(listed.ok, "{}", listed.message);
+        assert_eq!(listed.items.len(), 3);
+        assert_eq!(listed.items[0].label.as_deref(), Some("docs"));
+
+        let searched = links_search_impl(marker, Some(500));
+        assert!(searched.ok, "{}", searched.message);
+        assert_eq!(searched.items.len(), 2);
+
+        let plan = links_launch_plan_impl(atom_id, Some(1));
+        assert!(plan.ok, "{}", plan.message);
+        assert_eq!(plan.applied_max_items, 1);
+        assert_eq!(plan.items.len(), 1);
+        assert_eq!(plan.items[0].kind, "url");
+        assert_eq!(plan.capped_count, 1);
+        assert_eq!(plan.rejected[0].reason, "scheme_not_allowed");
+    }
+
     #[test]
     fn invalid_persisted_data_maps_to_internal_error() {
         let mapped = map_repo_error(lazynote_core::RepoError::InvalidData(
@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 2046837960;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1246707851;
 
 // Section: executor
 
@@ -45,7 +45,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__atom_update_status_impl(
+fn wire__crate__api__atom_convert_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +53,7 @@ fn wire__crate__api__atom_update_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "atom_update_status",
+            debug_name: "atom_convert",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -68,13 +68,21 @@ fn wire__crate__api__atom_update_status_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_atom_id = <String>::sse_decode(&mut deserializer);
-            let api_status = <Option<String>>::sse_decode(&mut deserializer);
+            let api_target_kind = <String>::sse_decode(&mut deserializer);
+            let api_start_ms = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_end_ms = <Option<i64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::atom_update_status(api_atom_id, api_status).await,
+                            crate::api::atom_convert(
+                                api_atom_id,
+                                api_target_kind,
+                                api_start_ms,
+                                api_end_ms,
+                            )
+                            .await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -84,7 +92,7 @@ fn wire__crate__api__atom_update_status_impl(
         },
     )
 }
-fn wire__crate__api__calendar_list_by_range_impl(
+fn wire__crate__api__atom_reminders_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -92,7 +100,7 @@ fn wire__crate__api__calendar_list_by_range_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "calendar_list_by_range",
+            debug_name: "atom_reminders",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -106,23 +114,13 @@ fn wire__crate__api__calendar_list_by_range_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_start_ms = <i64>::sse_decode(&mut deserializer);
-            let api_end_ms = <i64>::sse_decode(&mut deserializer);
-            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::calendar_list_by_range(
-                                api_start_ms,
-                                api_end_ms,
-                                api_limit,
-                                api_offset,
-                            )
-                            .await,
-                        )?;
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::atom_reminders(api_atom_id).await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -131,7 +129,7 @@ fn wire__crate__api__calendar_list_by_range_impl(
         },
     )
 }
-fn wire__crate__api__calendar_update_event_impl(
+fn wire__crate__api__atom_schedule_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -139,7 +137,7 @@ fn wire__crate__api__calendar_update_event_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "calendar_update_event",
+            debug_name: "atom_schedule",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -154,20 +152,12 @@ fn wire__crate__api__calendar_update_event_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_atom_id = <String>::sse_decode(&mut deserializer);
-            let api_start_ms = <i64>::sse_decode(&mut deserializer);
-            let api_end_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::calendar_update_event(
-                                api_atom_id,
-                                api_start_ms,
-                                api_end_ms,
-                            )
-                            .await,
-                        )?;
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::atom_schedule(api_atom_id).await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -176,16 +166,17 @@ fn wire__crate__api__calendar_update_event_impl(
         },
     )
 }
-fn wire__crate__api__configure_entry_db_path_impl(
+fn wire__crate__api__atom_set_all_day_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "configure_entry_db_path",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "atom_set_all_day",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -197,26 +188,36 @@ fn wire__crate__api__configure_entry_db_path_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_db_path = <String>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_start_date = <String>::sse_decode(&mut deserializer);
+            let api_end_date = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::configure_entry_db_path(api_db_path))?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::atom_set_all_day(api_atom_id, api_start_date, api_end_date)
+                                .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__core_version_impl(
+fn wire__crate__api__atom_set_due_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "core_version",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "atom_set_due",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -228,15 +229,24 @@ fn wire__crate__api__core_version_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_due_at = <Option<i64>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::core_version())?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::atom_set_due(api_atom_id, api_due_at).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__entry_create_note_impl(
+fn wire__crate__api__atom_set_priority_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -244,7 +254,7 @@ fn wire__crate__api__entry_create_note_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "entry_create_note",
+            debug_name: "atom_set_priority",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -258,13 +268,15 @@ fn wire__crate__api__entry_create_note_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_priority = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::entry_create_note(api_content).await)?;
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::atom_set_priority(api_atom_id, api_priority).await,
+                        )?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -273,7 +285,7 @@ fn wire__crate__api__entry_create_note_impl(
         },
     )
 }
-fn wire__crate__api__entry_create_task_impl(
+fn wire__crate__api__atom_set_recurrence_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -281,7 +293,7 @@ fn wire__crate__api__entry_create_task_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "entry_create_task",
+            debug_name: "atom_set_recurrence",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -295,13 +307,15 @@ fn wire__crate__api__entry_create_task_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_rule = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::entry_create_task(api_content).await)?;
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::atom_set_recurrence(api_atom_id, api_rule).await,
+                        )?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -310,7 +324,7 @@ fn wire__crate__api__entry_create_task_impl(
         },
     )
 }
-fn wire__crate__api__entry_schedule_impl(
+fn wire__crate__api__atom_task_meta_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -318,7 +332,7 @@ fn wire__crate__api__entry_schedule_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "entry_schedule",
+            debug_name: "atom_task_meta",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -332,21 +346,13 @@ fn wire__crate__api__entry_schedule_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_title = <String>::sse_decode(&mut deserializer);
-            let api_start_epoch_ms = <i64>::sse_decode(&mut deserializer);
-            let api_end_epoch_ms = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::entry_schedule(
-                                api_title,
-                                api_start_epoch_ms,
-                                api_end_epoch_ms,
-                            )
-                            .await,
-                        )?;
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::atom_task_meta(api_atom_id).await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -355,7 +361,7 @@ fn wire__crate__api__entry_schedule_impl(
         },
     )
 }
-fn wire__crate__api__entry_search_impl(
+fn wire__crate__api__atom_time_entries_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -363,7 +369,7 @@ fn wire__crate__api__entry_search_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "entry_search",
+            debug_name: "atom_time_entries",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -377,15 +383,14 @@ fn wire__crate__api__entry_search_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_text = <String>::sse_decode(&mut deserializer);
-            let api_kind = <Option<String>>::sse_decode(&mut deserializer);
-            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::entry_search(api_text, api_kind, api_limit).await,
+                            crate::api::atom_time_entries(api_atom_id, api_now_ms).await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -395,16 +400,17 @@ fn wire__crate__api__entry_search_impl(
         },
     )
 }
-fn wire__crate__api__init_logging_impl(
+fn wire__crate__api__atom_update_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_logging",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "atom_update_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -416,27 +422,34 @@ fn wire__crate__api__init_logging_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_level = <String>::sse_decode(&mut deserializer);
-            let api_log_dir = <String>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_status = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::init_logging(api_level, api_log_dir))?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::atom_update_status(api_atom_id, api_status).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__log_dart_event_impl(
+fn wire__crate__api__atom_update_status_with_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "log_dart_event",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "atom_update_status_with",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -448,24 +461,30 @@ fn wire__crate__api__log_dart_event_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_level = <String>::sse_decode(&mut deserializer);
-            let api_event_name = <String>::sse_decode(&mut deserializer);
-            let api_module = <String>::sse_decode(&mut deserializer);
-            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_status = <Option<String>>::sse_decode(&mut deserializer);
+            let api_stop_timer_at = <Option<i64>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::log_dart_event(
-                    api_level,
-                    api_event_name,
-                    api_module,
-                    api_message,
-                ))?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::atom_update_status_with(
+                                api_atom_id,
+                                api_status,
+                                api_stop_timer_at,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__note_create_impl(
+fn wire__crate__api__attachment_import_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -473,7 +492,7 @@ fn wire__crate__api__note_create_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_create",
+            debug_name: "attachment_import",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -487,13 +506,14 @@ fn wire__crate__api__note_create_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_source_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::note_create(api_content).await)?;
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::attachment_import(api_source_path).await,
+                        )?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -502,7 +522,7 @@ fn wire__crate__api__note_create_impl(
         },
     )
 }
-fn wire__crate__api__note_get_impl(
+fn wire__crate__api__attachment_resolve_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -510,7 +530,7 @@ fn wire__crate__api__note_get_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_get",
+            debug_name: "attachment_resolve",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -524,13 +544,13 @@ fn wire__crate__api__note_get_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_uri = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok =
-                            Result::<_, ()>::Ok(crate::api::note_get(api_atom_id).await)?;
+                            Result::<_, ()>::Ok(crate::api::attachment_resolve(api_uri).await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -539,7 +559,7 @@ fn wire__crate__api__note_get_impl(
         },
     )
 }
-fn wire__crate__api__note_set_tags_impl(
+fn wire__crate__api__attachments_gc_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -547,7 +567,7 @@ fn wire__crate__api__note_set_tags_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_set_tags",
+            debug_name: "attachments_gc",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -561,15 +581,13 @@ fn wire__crate__api__note_set_tags_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_atom_id = <String>::sse_decode(&mut deserializer);
-            let api_tags = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_grace_ms = <Option<i64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::note_set_tags(api_atom_id, api_tags).await,
-                        )?;
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::attachments_gc(api_grace_ms).await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -578,7 +596,7 @@ fn wire__crate__api__note_set_tags_impl(
         },
     )
 }
-fn wire__crate__api__note_update_impl(
+fn wire__crate__api__calendar_conflicts_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -586,7 +604,7 @@ fn wire__crate__api__note_update_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_update",
+            debug_name: "calendar_conflicts",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -600,14 +618,14 @@ fn wire__crate__api__note_update_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_atom_id = <String>::sse_decode(&mut deserializer);
-            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_start_date = <String>::sse_decode(&mut deserializer);
+            let api_end_date = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::note_update(api_atom_id, api_content).await,
+                            crate::api::calendar_conflicts(api_start_date, api_end_date).await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -617,7 +635,7 @@ fn wire__crate__api__note_update_impl(
         },
     )
 }
-fn wire__crate__api__notes_list_impl(
+fn wire__crate__api__calendar_days_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -625,7 +643,7 @@ fn wire__crate__api__notes_list_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "notes_list",
+            debug_name: "calendar_days",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -639,15 +657,14 @@ fn wire__crate__api__notes_list_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_tag = <Option<String>>::sse_decode(&mut deserializer);
-            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_start_date = <String>::sse_decode(&mut deserializer);
+            let api_end_date = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::notes_list(api_tag, api_limit, api_offset).await,
+                            crate::api::calendar_days(api_start_date, api_end_date).await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -657,16 +674,17 @@ fn wire__crate__api__notes_list_impl(
         },
     )
 }
-fn wire__crate__api__ping_impl(
+fn wire__crate__api__calendar_free_slots_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "ping",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "calendar_free_slots",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -678,23 +696,38 @@ fn wire__crate__api__ping_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_start_ms = <i64>::sse_decode(&mut deserializer);
+            let api_end_ms = <i64>::sse_decode(&mut deserializer);
+            let api_duration_minutes = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::ping())?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__tags_list_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::calendar_free_slots(
+                                api_start_ms,
+                                api_end_ms,
+                                api_duration_minutes,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__calendar_list_by_range_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tags_list",
+            debug_name: "calendar_list_by_range",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -708,11 +741,23 @@ fn wire__crate__api__tags_list_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_start_ms = <i64>::sse_decode(&mut deserializer);
+            let api_end_ms = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(crate::api::tags_list().await)?;
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::calendar_list_by_range(
+                                api_start_ms,
+                                api_end_ms,
+                                api_limit,
+                                api_offset,
+                            )
+                            .await,
+                        )?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -721,7 +766,7 @@ fn wire__crate__api__tags_list_impl(
         },
     )
 }
-fn wire__crate__api__tasks_list_inbox_impl(
+fn wire__crate__api__calendar_month_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -729,7 +774,7 @@ fn wire__crate__api__tasks_list_inbox_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tasks_list_inbox",
+            debug_name: "calendar_month",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -743,15 +788,13 @@ fn wire__crate__api__tasks_list_inbox_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::tasks_list_inbox(api_limit, api_offset).await,
-                        )?;
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::calendar_month(api_now_ms).await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -760,7 +803,7 @@ fn wire__crate__api__tasks_list_inbox_impl(
         },
     )
 }
-fn wire__crate__api__tasks_list_today_impl(
+fn wire__crate__api__calendar_update_event_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -768,7 +811,7 @@ fn wire__crate__api__tasks_list_today_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tasks_list_today",
+            debug_name: "calendar_update_event",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -782,17 +825,18 @@ fn wire__crate__api__tasks_list_today_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_bod_ms = <i64>::sse_decode(&mut deserializer);
-            let api_eod_ms = <i64>::sse_decode(&mut deserializer);
-            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_start_ms = <i64>::sse_decode(&mut deserializer);
+            let api_end_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::tasks_list_today(
-                                api_bod_ms, api_eod_ms, api_limit, api_offset,
+                            crate::api::calendar_update_event(
+                                api_atom_id,
+                                api_start_ms,
+                                api_end_ms,
                             )
                             .await,
                         )?;
@@ -804,7 +848,7 @@ fn wire__crate__api__tasks_list_today_impl(
         },
     )
 }
-fn wire__crate__api__tasks_list_upcoming_impl(
+fn wire__crate__api__calendar_week_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -812,7 +856,7 @@ fn wire__crate__api__tasks_list_upcoming_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tasks_list_upcoming",
+            debug_name: "calendar_week",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -826,17 +870,13 @@ fn wire__crate__api__tasks_list_upcoming_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_eod_ms = <i64>::sse_decode(&mut deserializer);
-            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::tasks_list_upcoming(api_eod_ms, api_limit, api_offset)
-                                .await,
-                        )?;
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::calendar_week(api_now_ms).await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -845,17 +885,16 @@ fn wire__crate__api__tasks_list_upcoming_impl(
         },
     )
 }
-fn wire__crate__api__workspace_create_folder_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__configure_entry_db_path_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "workspace_create_folder",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "configure_entry_db_path",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -867,34 +906,26 @@ fn wire__crate__api__workspace_create_folder_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_parent_node_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_db_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::workspace_create_folder(api_parent_node_id, api_name).await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::configure_entry_db_path(api_db_path))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__workspace_create_note_ref_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__configure_time_zone_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "workspace_create_note_ref",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "configure_time_zone",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -906,30 +937,49 @@ fn wire__crate__api__workspace_create_note_ref_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_parent_node_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_atom_id = <String>::sse_decode(&mut deserializer);
-            let api_display_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_time_zone = <String>::sse_decode(&mut deserializer);
+            let api_week_start = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::workspace_create_note_ref(
-                                api_parent_node_id,
-                                api_atom_id,
-                                api_display_name,
-                            )
-                            .await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::configure_time_zone(
+                    api_time_zone,
+                    api_week_start,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__core_version_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "core_version",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
-            }
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::core_version())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__workspace_delete_folder_impl(
+fn wire__crate__api__entry_create_note_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -937,7 +987,7 @@ fn wire__crate__api__workspace_delete_folder_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "workspace_delete_folder",
+            debug_name: "entry_create_note",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -951,15 +1001,13 @@ fn wire__crate__api__workspace_delete_folder_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_node_id = <String>::sse_decode(&mut deserializer);
-            let api_mode = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::workspace_delete_folder(api_node_id, api_mode).await,
-                        )?;
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::entry_create_note(api_content).await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -968,7 +1016,7 @@ fn wire__crate__api__workspace_delete_folder_impl(
         },
     )
 }
-fn wire__crate__api__workspace_list_children_impl(
+fn wire__crate__api__entry_create_task_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -976,7 +1024,7 @@ fn wire__crate__api__workspace_list_children_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "workspace_list_children",
+            debug_name: "entry_create_task",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -990,14 +1038,13 @@ fn wire__crate__api__workspace_list_children_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_parent_node_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::workspace_list_children(api_parent_node_id).await,
-                        )?;
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::entry_create_task(api_content).await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1006,7 +1053,39 @@ fn wire__crate__api__workspace_list_children_impl(
         },
     )
 }
-fn wire__crate__api__workspace_move_node_impl(
+fn wire__crate__api__entry_quick_add_parse_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "entry_quick_add_parse",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::entry_quick_add_parse(api_text, api_now_ms))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__entry_schedule_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1014,7 +1093,7 @@ fn wire__crate__api__workspace_move_node_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "workspace_move_node",
+            debug_name: "entry_schedule",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1028,18 +1107,18 @@ fn wire__crate__api__workspace_move_node_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_node_id = <String>::sse_decode(&mut deserializer);
-            let api_new_parent_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_target_order = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_title = <String>::sse_decode(&mut deserializer);
+            let api_start_epoch_ms = <i64>::sse_decode(&mut deserializer);
+            let api_end_epoch_ms = <Option<i64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::workspace_move_node(
-                                api_node_id,
-                                api_new_parent_id,
-                                api_target_order,
+                            crate::api::entry_schedule(
+                                api_title,
+                                api_start_epoch_ms,
+                                api_end_epoch_ms,
                             )
                             .await,
                         )?;
@@ -1051,7 +1130,7 @@ fn wire__crate__api__workspace_move_node_impl(
         },
     )
 }
-fn wire__crate__api__workspace_rename_node_impl(
+fn wire__crate__api__entry_search_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1059,7 +1138,7 @@ fn wire__crate__api__workspace_rename_node_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "workspace_rename_node",
+            debug_name: "entry_search",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1073,14 +1152,15 @@ fn wire__crate__api__workspace_rename_node_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_node_id = <String>::sse_decode(&mut deserializer);
-            let api_new_name = <String>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_kind = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::workspace_rename_node(api_node_id, api_new_name).await,
+                            crate::api::entry_search(api_text, api_kind, api_limit).await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -1090,1018 +1170,7876 @@ fn wire__crate__api__workspace_rename_node_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
-}
-
-impl SseDecode for crate::api::AtomListItem {
-    // Codec=Sse (Serialization based), see doc to use other codecs
+fn wire__crate__api__entry_search_check_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "entry_search_check",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::entry_search_check(api_text))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__entry_search_page_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "entry_search_page",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_kind = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_cursor = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::entry_search_page(
+                                api_text, api_kind, api_limit, api_cursor,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__init_logging_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_logging",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_level = <String>::sse_decode(&mut deserializer);
+            let api_log_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::init_logging(api_level, api_log_dir))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__links_launch_plan_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "links_launch_plan",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_max_items = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::links_launch_plan(api_atom_id, api_max_items).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__links_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "links_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::links_list(api_atom_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__links_search_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "links_search",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::links_search(api_query, api_limit).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__local_time_windows_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "local_time_windows",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::local_time_windows(api_now_ms))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__log_dart_event_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "log_dart_event",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_level = <String>::sse_decode(&mut deserializer);
+            let api_event_name = <String>::sse_decode(&mut deserializer);
+            let api_module = <String>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::log_dart_event(
+                    api_level,
+                    api_event_name,
+                    api_module,
+                    api_message,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__note_attachments_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_attachments",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::note_attachments(api_atom_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_create_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_create",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::note_create(api_content).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_get_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_get",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::note_get(api_atom_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_links_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_links",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::note_links(api_atom_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_outline_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_outline",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::note_outline(api_atom_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_properties_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_properties",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::note_properties(api_atom_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_revision_diff_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_revision_diff",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_from_revision_id = <i64>::sse_decode(&mut deserializer);
+            let api_to_revision_id = <Option<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::note_revision_diff(
+                                api_atom_id,
+                                api_from_revision_id,
+                                api_to_revision_id,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_revision_restore_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_revision_restore",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_revision_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::note_revision_restore(api_atom_id, api_revision_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_revisions_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_revisions_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::note_revisions_list(api_atom_id, api_limit, api_offset)
+                                .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_set_tags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_set_tags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_tags = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::note_set_tags(api_atom_id, api_tags).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_set_title_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_set_title",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_title = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::note_set_title(api_atom_id, api_title).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_tag_sources_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_tag_sources",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::note_tag_sources(api_atom_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_title_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_title",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::note_title(api_atom_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_update_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_update",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::note_update(api_atom_id, api_content).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__notes_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "notes_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_tag = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::notes_list(api_tag, api_limit, api_offset).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__notes_list_by_tags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "notes_list_by_tags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_tag_expr = <String>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::notes_list_by_tags(api_tag_expr, api_limit, api_offset)
+                                .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__ping_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ping",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::ping())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__recurrence_delete_occurrence_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "recurrence_delete_occurrence",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_series_id = <String>::sse_decode(&mut deserializer);
+            let api_occurrence_start_at = <i64>::sse_decode(&mut deserializer);
+            let api_scope = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::recurrence_delete_occurrence(
+                                api_series_id,
+                                api_occurrence_start_at,
+                                api_scope,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__recurrence_edit_occurrence_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "recurrence_edit_occurrence",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_series_id = <String>::sse_decode(&mut deserializer);
+            let api_occurrence_start_at = <i64>::sse_decode(&mut deserializer);
+            let api_scope = <String>::sse_decode(&mut deserializer);
+            let api_content = <Option<String>>::sse_decode(&mut deserializer);
+            let api_start_at = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_end_at = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_status = <Option<String>>::sse_decode(&mut deserializer);
+            let api_recurrence_rule = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::recurrence_edit_occurrence(
+                                api_series_id,
+                                api_occurrence_start_at,
+                                api_scope,
+                                api_content,
+                                api_start_at,
+                                api_end_at,
+                                api_status,
+                                api_recurrence_rule,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__reminder_ack_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reminder_ack",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_reminder_id = <String>::sse_decode(&mut deserializer);
+            let api_fire_at = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::reminder_ack(api_reminder_id, api_fire_at).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__reminder_add_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reminder_add",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_anchor = <String>::sse_decode(&mut deserializer);
+            let api_remind_at = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_offset_ms = <Option<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::reminder_add(
+                                api_atom_id,
+                                api_anchor,
+                                api_remind_at,
+                                api_offset_ms,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__reminder_remove_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reminder_remove",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_reminder_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::reminder_remove(api_reminder_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__reminder_snooze_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reminder_snooze",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_reminder_id = <String>::sse_decode(&mut deserializer);
+            let api_fire_at = <i64>::sse_decode(&mut deserializer);
+            let api_until_ms = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::reminder_snooze(api_reminder_id, api_fire_at, api_until_ms)
+                                .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__reminders_due_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reminders_due",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::reminders_due(api_now_ms).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__subtask_set_parent_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subtask_set_parent",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_target_index = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::subtask_set_parent(
+                                api_atom_id,
+                                api_parent_id,
+                                api_target_index,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__subtasks_reorder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subtasks_reorder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_parent_id = <String>::sse_decode(&mut deserializer);
+            let api_ordered_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::subtasks_reorder(api_parent_id, api_ordered_ids).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tag_delete_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tag_delete",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::tag_delete(api_name).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tag_rename_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tag_rename",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_from = <String>::sse_decode(&mut deserializer);
+            let api_to = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::tag_rename(api_from, api_to).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tags_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tags_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(crate::api::tags_list().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tags_merge_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tags_merge",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sources = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_target = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::tags_merge(api_sources, api_target).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tags_usage_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tags_usage",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(crate::api::tags_usage().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__task_add_dependency_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "task_add_dependency",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_depends_on_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::task_add_dependency(api_atom_id, api_depends_on_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__task_dependencies_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "task_dependencies",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::task_dependencies(api_atom_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__task_promote_checklist_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "task_promote_checklist",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::task_promote_checklist(api_atom_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__task_remove_dependency_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "task_remove_dependency",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_depends_on_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::task_remove_dependency(api_atom_id, api_depends_on_id)
+                                .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__task_subtasks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "task_subtasks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::task_subtasks(api_atom_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tasks_list_inbox_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tasks_list_inbox",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::tasks_list_inbox(api_limit, api_offset).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tasks_list_inbox_unblocked_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tasks_list_inbox_unblocked",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::tasks_list_inbox_unblocked(api_limit, api_offset).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tasks_list_overdue_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tasks_list_overdue",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::tasks_list_overdue(api_now_ms, api_limit, api_offset).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tasks_list_project_order_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tasks_list_project_order",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_project_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::tasks_list_project_order(api_project_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tasks_list_subtasks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tasks_list_subtasks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_parent_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::tasks_list_subtasks(api_parent_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tasks_list_today_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tasks_list_today",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bod_ms = <i64>::sse_decode(&mut deserializer);
+            let api_eod_ms = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::tasks_list_today(
+                                api_bod_ms, api_eod_ms, api_limit, api_offset,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tasks_list_today_local_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tasks_list_today_local",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_hide_blocked = <bool>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::tasks_list_today_local(
+                                api_now_ms,
+                                api_hide_blocked,
+                                api_limit,
+                                api_offset,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tasks_list_today_unblocked_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tasks_list_today_unblocked",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bod_ms = <i64>::sse_decode(&mut deserializer);
+            let api_eod_ms = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::tasks_list_today_unblocked(
+                                api_bod_ms, api_eod_ms, api_limit, api_offset,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tasks_list_upcoming_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tasks_list_upcoming",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_eod_ms = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::tasks_list_upcoming(api_eod_ms, api_limit, api_offset)
+                                .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tasks_list_upcoming_local_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tasks_list_upcoming_local",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::tasks_list_upcoming_local(
+                                api_now_ms, api_limit, api_offset,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__time_entry_add_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "time_entry_add",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_started_at = <i64>::sse_decode(&mut deserializer);
+            let api_ended_at = <i64>::sse_decode(&mut deserializer);
+            let api_note = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::time_entry_add(
+                                api_atom_id,
+                                api_started_at,
+                                api_ended_at,
+                                api_note,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__time_entry_delete_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "time_entry_delete",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_entry_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::time_entry_delete(api_entry_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__time_entry_update_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "time_entry_update",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_entry_id = <String>::sse_decode(&mut deserializer);
+            let api_atom_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_started_at = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_ended_at = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_note = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::time_entry_update(
+                                api_entry_id,
+                                api_atom_id,
+                                api_started_at,
+                                api_ended_at,
+                                api_note,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__time_report_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "time_report",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_start_date = <String>::sse_decode(&mut deserializer);
+            let api_end_date = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::time_report(api_start_date, api_end_date, api_now_ms).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__time_timer_running_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "time_timer_running",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::time_timer_running().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__time_timer_start_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "time_timer_start",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::time_timer_start(api_atom_id, api_now_ms).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__time_timer_stop_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "time_timer_stop",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_now_ms = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::time_timer_stop(api_now_ms).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__trash_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "trash_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_kind = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::trash_list(api_kind, api_limit, api_offset).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__trash_purge_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "trash_purge",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::trash_purge(api_atom_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__trash_purge_expired_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "trash_purge_expired",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_retention_days = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::trash_purge_expired(api_retention_days).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__trash_restore_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "trash_restore",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::trash_restore(api_atom_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_create_folder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_create_folder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_parent_node_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::workspace_create_folder(api_parent_node_id, api_name).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_create_note_ref_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_create_note_ref",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_parent_node_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_display_name = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::workspace_create_note_ref(
+                                api_parent_node_id,
+                                api_atom_id,
+                                api_display_name,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_delete_folder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_delete_folder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_node_id = <String>::sse_decode(&mut deserializer);
+            let api_mode = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::workspace_delete_folder(api_node_id, api_mode).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_list_children_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_list_children",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_parent_node_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::workspace_list_children(api_parent_node_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_move_node_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_move_node",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_node_id = <String>::sse_decode(&mut deserializer);
+            let api_new_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_target_order = <Option<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::workspace_move_node(
+                                api_node_id,
+                                api_new_parent_id,
+                                api_target_order,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_rename_node_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_rename_node",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_node_id = <String>::sse_decode(&mut deserializer);
+            let api_new_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::workspace_rename_node(api_node_id, api_new_name).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_repair_note_titles_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_repair_note_titles",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::workspace_repair_note_titles().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::AtomConvertResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
+        let mut var_kind = <Option<String>>::sse_decode(deserializer);
+        let mut var_taskStatus = <Option<String>>::sse_decode(deserializer);
+        let mut var_startAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_endAt = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::AtomConvertResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            atom_id: var_atomId,
+            kind: var_kind,
+            task_status: var_taskStatus,
+            start_at: var_startAt,
+            end_at: var_endAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AtomListItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_atomId = <String>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_previewText = <Option<String>>::sse_decode(deserializer);
+        let mut var_previewImage = <Option<String>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_startAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_endAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_taskStatus = <Option<String>>::sse_decode(deserializer);
+        let mut var_updatedAt = <i64>::sse_decode(deserializer);
+        return crate::api::AtomListItem {
+            atom_id: var_atomId,
+            kind: var_kind,
+            content: var_content,
+            preview_text: var_previewText,
+            preview_image: var_previewImage,
+            tags: var_tags,
+            start_at: var_startAt,
+            end_at: var_endAt,
+            task_status: var_taskStatus,
+            updated_at: var_updatedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AtomListResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::AtomListItem>>::sse_decode(deserializer);
+        let mut var_appliedLimit = <u32>::sse_decode(deserializer);
+        return crate::api::AtomListResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+            applied_limit: var_appliedLimit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AtomScheduleResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
+        let mut var_allDay = <bool>::sse_decode(deserializer);
+        let mut var_startAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_endAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_startDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_endDate = <Option<String>>::sse_decode(deserializer);
+        return crate::api::AtomScheduleResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            atom_id: var_atomId,
+            all_day: var_allDay,
+            start_at: var_startAt,
+            end_at: var_endAt,
+            start_date: var_startDate,
+            end_date: var_endDate,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AtomTaskMetaResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
+        let mut var_priority = <Option<String>>::sse_decode(deserializer);
+        let mut var_dueAt = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::AtomTaskMetaResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            atom_id: var_atomId,
+            priority: var_priority,
+            due_at: var_dueAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AttachmentGcResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_removedRows = <u32>::sse_decode(deserializer);
+        let mut var_removedBlobs = <u32>::sse_decode(deserializer);
+        let mut var_freedBytes = <u64>::sse_decode(deserializer);
+        return crate::api::AttachmentGcResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            removed_rows: var_removedRows,
+            removed_blobs: var_removedBlobs,
+            freed_bytes: var_freedBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AttachmentImportResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_attachment = <Option<crate::api::AttachmentItem>>::sse_decode(deserializer);
+        let mut var_deduplicated = <bool>::sse_decode(deserializer);
+        return crate::api::AttachmentImportResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            attachment: var_attachment,
+            deduplicated: var_deduplicated,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AttachmentItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sha256 = <String>::sse_decode(deserializer);
+        let mut var_uri = <String>::sse_decode(deserializer);
+        let mut var_byteSize = <i64>::sse_decode(deserializer);
+        let mut var_mimeType = <Option<String>>::sse_decode(deserializer);
+        let mut var_originalName = <Option<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_refCount = <u32>::sse_decode(deserializer);
+        return crate::api::AttachmentItem {
+            sha256: var_sha256,
+            uri: var_uri,
+            byte_size: var_byteSize,
+            mime_type: var_mimeType,
+            original_name: var_originalName,
+            created_at: var_createdAt,
+            ref_count: var_refCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AttachmentResolveResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_attachment = <Option<crate::api::AttachmentItem>>::sse_decode(deserializer);
+        let mut var_filePath = <Option<String>>::sse_decode(deserializer);
+        return crate::api::AttachmentResolveResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            attachment: var_attachment,
+            file_path: var_filePath,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AttachmentsListResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::AttachmentItem>>::sse_decode(deserializer);
+        return crate::api::AttachmentsListResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::CalendarConflictItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_firstAtomId = <String>::sse_decode(deserializer);
+        let mut var_firstOccurrenceStartAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_secondAtomId = <String>::sse_decode(deserializer);
+        let mut var_secondOccurrenceStartAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_overlapStartMs = <i64>::sse_decode(deserializer);
+        let mut var_overlapEndMs = <i64>::sse_decode(deserializer);
+        return crate::api::CalendarConflictItem {
+            first_atom_id: var_firstAtomId,
+            first_occurrence_start_at: var_firstOccurrenceStartAt,
+            second_atom_id: var_secondAtomId,
+            second_occurrence_start_at: var_secondOccurrenceStartAt,
+            overlap_start_ms: var_overlapStartMs,
+            overlap_end_ms: var_overlapEndMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::CalendarConflictsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::CalendarConflictItem>>::sse_decode(deserializer);
+        return crate::api::CalendarConflictsResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+        };
+    }
+}
+
+impl SseDecode for crate::api::CalendarDayItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_date = <String>::sse_decode(deserializer);
+        let mut var_startMs = <i64>::sse_decode(deserializer);
+        let mut var_endMs = <i64>::sse_decode(deserializer);
+        let mut var_allDay = <Vec<crate::api::CalendarEntryItem>>::sse_decode(deserializer);
+        let mut var_timed = <Vec<crate::api::CalendarEntryItem>>::sse_decode(deserializer);
+        return crate::api::CalendarDayItem {
+            date: var_date,
+            start_ms: var_startMs,
+            end_ms: var_endMs,
+            all_day: var_allDay,
+            timed: var_timed,
+        };
+    }
+}
+
+impl SseDecode for crate::api::CalendarDaysResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_days = <Vec<crate::api::CalendarDayItem>>::sse_decode(deserializer);
+        return crate::api::CalendarDaysResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            days: var_days,
+        };
+    }
+}
+
+impl SseDecode for crate::api::CalendarEntryItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_atomId = <String>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_previewText = <Option<String>>::sse_decode(deserializer);
+        let mut var_taskStatus = <Option<String>>::sse_decode(deserializer);
+        let mut var_allDay = <bool>::sse_decode(deserializer);
+        let mut var_startAt = <i64>::sse_decode(deserializer);
+        let mut var_endAt = <i64>::sse_decode(deserializer);
+        let mut var_occurrenceStartAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_visibleStartMs = <i64>::sse_decode(deserializer);
+        let mut var_visibleEndMs = <i64>::sse_decode(deserializer);
+        let mut var_continuesBefore = <bool>::sse_decode(deserializer);
+        let mut var_continuesAfter = <bool>::sse_decode(deserializer);
+        let mut var_column = <u32>::sse_decode(deserializer);
+        let mut var_columnCount = <u32>::sse_decode(deserializer);
+        return crate::api::CalendarEntryItem {
+            atom_id: var_atomId,
+            kind: var_kind,
+            content: var_content,
+            preview_text: var_previewText,
+            task_status: var_taskStatus,
+            all_day: var_allDay,
+            start_at: var_startAt,
+            end_at: var_endAt,
+            occurrence_start_at: var_occurrenceStartAt,
+            visible_start_ms: var_visibleStartMs,
+            visible_end_ms: var_visibleEndMs,
+            continues_before: var_continuesBefore,
+            continues_after: var_continuesAfter,
+            column: var_column,
+            column_count: var_columnCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::CalendarFreeSlotItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startMs = <i64>::sse_decode(deserializer);
+        let mut var_endMs = <i64>::sse_decode(deserializer);
+        return crate::api::CalendarFreeSlotItem {
+            start_ms: var_startMs,
+            end_ms: var_endMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::CalendarFreeSlotsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_slots = <Vec<crate::api::CalendarFreeSlotItem>>::sse_decode(deserializer);
+        return crate::api::CalendarFreeSlotsResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            slots: var_slots,
+        };
+    }
+}
+
+impl SseDecode for crate::api::DueReminderItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_reminder = <crate::api::ReminderItem>::sse_decode(deserializer);
+        let mut var_atomKind = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_previewText = <Option<String>>::sse_decode(deserializer);
+        let mut var_fireAt = <i64>::sse_decode(deserializer);
+        let mut var_occurrenceStartAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_snoozed = <bool>::sse_decode(deserializer);
+        return crate::api::DueReminderItem {
+            reminder: var_reminder,
+            atom_kind: var_atomKind,
+            content: var_content,
+            preview_text: var_previewText,
+            fire_at: var_fireAt,
+            occurrence_start_at: var_occurrenceStartAt,
+            snoozed: var_snoozed,
+        };
+    }
+}
+
+impl SseDecode for crate::api::DueRemindersResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::DueReminderItem>>::sse_decode(deserializer);
+        let mut var_nextWakeupAt = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::DueRemindersResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+            next_wakeup_at: var_nextWakeupAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EntryActionResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::EntryActionResponse {
+            ok: var_ok,
+            atom_id: var_atomId,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EntryQuickAddResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_startAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_endAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_allDay = <bool>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_priority = <Option<String>>::sse_decode(deserializer);
+        let mut var_tokens = <Vec<crate::api::EntryQuickAddToken>>::sse_decode(deserializer);
+        return crate::api::EntryQuickAddResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            kind: var_kind,
+            title: var_title,
+            start_at: var_startAt,
+            end_at: var_endAt,
+            all_day: var_allDay,
+            tags: var_tags,
+            priority: var_priority,
+            tokens: var_tokens,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EntryQuickAddToken {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_start = <u32>::sse_decode(deserializer);
+        let mut var_end = <u32>::sse_decode(deserializer);
+        let mut var_charStart = <u32>::sse_decode(deserializer);
+        let mut var_charEnd = <u32>::sse_decode(deserializer);
+        return crate::api::EntryQuickAddToken {
+            kind: var_kind,
+            start: var_start,
+            end: var_end,
+            char_start: var_charStart,
+            char_end: var_charEnd,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EntrySearchHighlight {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_start = <u32>::sse_decode(deserializer);
+        let mut var_end = <u32>::sse_decode(deserializer);
+        let mut var_charStart = <u32>::sse_decode(deserializer);
+        let mut var_charEnd = <u32>::sse_decode(deserializer);
+        return crate::api::EntrySearchHighlight {
+            start: var_start,
+            end: var_end,
+            char_start: var_charStart,
+            char_end: var_charEnd,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EntrySearchHitItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_atomId = <String>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_snippet = <String>::sse_decode(deserializer);
+        let mut var_highlights = <Vec<crate::api::EntrySearchHighlight>>::sse_decode(deserializer);
+        let mut var_bm25 = <Option<f64>>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        return crate::api::EntrySearchHitItem {
+            atom_id: var_atomId,
+            kind: var_kind,
+            snippet: var_snippet,
+            highlights: var_highlights,
+            bm25: var_bm25,
+            score: var_score,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EntrySearchItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_atomId = <String>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_snippet = <String>::sse_decode(deserializer);
+        return crate::api::EntrySearchItem {
+            atom_id: var_atomId,
+            kind: var_kind,
+            snippet: var_snippet,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EntrySearchPageResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::EntrySearchHitItem>>::sse_decode(deserializer);
+        let mut var_nextCursor = <Option<String>>::sse_decode(deserializer);
+        let mut var_appliedLimit = <u32>::sse_decode(deserializer);
+        return crate::api::EntrySearchPageResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+            next_cursor: var_nextCursor,
+            applied_limit: var_appliedLimit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EntrySearchResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::EntrySearchItem>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_appliedLimit = <u32>::sse_decode(deserializer);
+        return crate::api::EntrySearchResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            items: var_items,
+            message: var_message,
+            applied_limit: var_appliedLimit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EntrySearchSyntaxResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_syntaxError = <Option<String>>::sse_decode(deserializer);
+        let mut var_errorStart = <Option<u32>>::sse_decode(deserializer);
+        let mut var_errorEnd = <Option<u32>>::sse_decode(deserializer);
+        let mut var_filterCount = <u32>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::EntrySearchSyntaxResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            syntax_error: var_syntaxError,
+            error_start: var_errorStart,
+            error_end: var_errorEnd,
+            filter_count: var_filterCount,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::LaunchPlanItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_linkId = <i64>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_target = <String>::sse_decode(deserializer);
+        let mut var_label = <Option<String>>::sse_decode(deserializer);
+        let mut var_localPath = <Option<String>>::sse_decode(deserializer);
+        return crate::api::LaunchPlanItem {
+            link_id: var_linkId,
+            kind: var_kind,
+            target: var_target,
+            label: var_label,
+            local_path: var_localPath,
+        };
+    }
+}
+
+impl SseDecode for crate::api::LaunchPlanResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::LaunchPlanItem>>::sse_decode(deserializer);
+        let mut var_rejected = <Vec<crate::api::LaunchRejectionItem>>::sse_decode(deserializer);
+        let mut var_cappedCount = <u32>::sse_decode(deserializer);
+        let mut var_appliedMaxItems = <u32>::sse_decode(deserializer);
+        return crate::api::LaunchPlanResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+            rejected: var_rejected,
+            capped_count: var_cappedCount,
+            applied_max_items: var_appliedMaxItems,
+        };
+    }
+}
+
+impl SseDecode for crate::api::LaunchRejectionItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_linkId = <i64>::sse_decode(deserializer);
+        let mut var_target = <String>::sse_decode(deserializer);
+        let mut var_reason = <String>::sse_decode(deserializer);
+        return crate::api::LaunchRejectionItem {
+            link_id: var_linkId,
+            target: var_target,
+            reason: var_reason,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::AtomListItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::AtomListItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::AttachmentItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::AttachmentItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::CalendarConflictItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::CalendarConflictItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::CalendarDayItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::CalendarDayItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::CalendarEntryItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::CalendarEntryItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::CalendarFreeSlotItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::CalendarFreeSlotItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::DueReminderItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::DueReminderItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::EntryQuickAddToken> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::EntryQuickAddToken>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::EntrySearchHighlight> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::EntrySearchHighlight>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::EntrySearchHitItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::EntrySearchHitItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::EntrySearchItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::EntrySearchItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::LaunchPlanItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::LaunchPlanItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::LaunchRejectionItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::LaunchRejectionItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::NoteDiffLineItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::NoteDiffLineItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::NoteItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::NoteItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::NoteLinkItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::NoteLinkItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::NoteOutlineHeading> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::NoteOutlineHeading>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::NotePropertyItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::NotePropertyItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::NoteRevisionItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::NoteRevisionItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::ReminderItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::ReminderItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::ResourceLinkItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::ResourceLinkItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TagUsageItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TagUsageItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TimeDayTotalItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TimeDayTotalItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TimeEntryItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TimeEntryItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TimeFolderTotalItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TimeFolderTotalItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TimeTagTotalItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TimeTagTotalItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TrashListItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TrashListItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::WorkspaceNodeItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::WorkspaceNodeItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::LocalTimeWindowsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_timeZone = <String>::sse_decode(deserializer);
+        let mut var_weekStart = <String>::sse_decode(deserializer);
+        let mut var_dayStartMs = <i64>::sse_decode(deserializer);
+        let mut var_dayEndMs = <i64>::sse_decode(deserializer);
+        let mut var_weekStartMs = <i64>::sse_decode(deserializer);
+        let mut var_weekEndMs = <i64>::sse_decode(deserializer);
+        let mut var_monthStartMs = <i64>::sse_decode(deserializer);
+        let mut var_monthEndMs = <i64>::sse_decode(deserializer);
+        return crate::api::LocalTimeWindowsResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            time_zone: var_timeZone,
+            week_start: var_weekStart,
+            day_start_ms: var_dayStartMs,
+            day_end_ms: var_dayEndMs,
+            week_start_ms: var_weekStartMs,
+            week_end_ms: var_weekEndMs,
+            month_start_ms: var_monthStartMs,
+            month_end_ms: var_monthEndMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::LogDartEventResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::LogDartEventResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NoteDiffLineItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_oldLine = <Option<u32>>::sse_decode(deserializer);
+        let mut var_newLine = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::NoteDiffLineItem {
+            kind: var_kind,
+            text: var_text,
+            old_line: var_oldLine,
+            new_line: var_newLine,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NoteItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_atomId = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_previewText = <Option<String>>::sse_decode(deserializer);
+        let mut var_previewImage = <Option<String>>::sse_decode(deserializer);
+        let mut var_updatedAt = <i64>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::NoteItem {
+            atom_id: var_atomId,
+            content: var_content,
+            preview_text: var_previewText,
+            preview_image: var_previewImage,
+            updated_at: var_updatedAt,
+            tags: var_tags,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NoteLinkItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sourceAtomId = <String>::sse_decode(deserializer);
+        let mut var_target = <String>::sse_decode(deserializer);
+        let mut var_label = <Option<String>>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_targetAtomId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::NoteLinkItem {
+            source_atom_id: var_sourceAtomId,
+            target: var_target,
+            label: var_label,
+            kind: var_kind,
+            target_atom_id: var_targetAtomId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NoteLinksResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_outgoing = <Vec<crate::api::NoteLinkItem>>::sse_decode(deserializer);
+        let mut var_backlinks = <Vec<crate::api::NoteLinkItem>>::sse_decode(deserializer);
+        return crate::api::NoteLinksResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            outgoing: var_outgoing,
+            backlinks: var_backlinks,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NoteOutlineHeading {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_level = <u32>::sse_decode(deserializer);
+        let mut var_depth = <u32>::sse_decode(deserializer);
+        let mut var_parentIndex = <Option<u32>>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_byteStart = <u32>::sse_decode(deserializer);
+        let mut var_byteEnd = <u32>::sse_decode(deserializer);
+        return crate::api::NoteOutlineHeading {
+            level: var_level,
+            depth: var_depth,
+            parent_index: var_parentIndex,
+            text: var_text,
+            byte_start: var_byteStart,
+            byte_end: var_byteEnd,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NoteOutlineResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_headings = <Vec<crate::api::NoteOutlineHeading>>::sse_decode(deserializer);
+        let mut var_wordCount = <u32>::sse_decode(deserializer);
+        let mut var_charCount = <u32>::sse_decode(deserializer);
+        let mut var_checklistDone = <u32>::sse_decode(deserializer);
+        let mut var_checklistTotal = <u32>::sse_decode(deserializer);
+        return crate::api::NoteOutlineResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            headings: var_headings,
+            word_count: var_wordCount,
+            char_count: var_charCount,
+            checklist_done: var_checklistDone,
+            checklist_total: var_checklistTotal,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NotePropertiesResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::NotePropertyItem>>::sse_decode(deserializer);
+        return crate::api::NotePropertiesResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NotePropertyItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_valueType = <String>::sse_decode(deserializer);
+        let mut var_text = <Option<String>>::sse_decode(deserializer);
+        let mut var_number = <Option<f64>>::sse_decode(deserializer);
+        let mut var_items = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::NotePropertyItem {
+            key: var_key,
+            value_type: var_valueType,
+            text: var_text,
+            number: var_number,
+            items: var_items,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NoteResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_note = <Option<crate::api::NoteItem>>::sse_decode(deserializer);
+        return crate::api::NoteResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            note: var_note,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NoteRevisionDiffResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_lines = <Vec<crate::api::NoteDiffLineItem>>::sse_decode(deserializer);
+        return crate::api::NoteRevisionDiffResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            lines: var_lines,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NoteRevisionItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_revisionId = <i64>::sse_decode(deserializer);
+        let mut var_atomId = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_previewText = <Option<String>>::sse_decode(deserializer);
+        let mut var_sourceUpdatedAt = <i64>::sse_decode(deserializer);
+        let mut var_capturedAt = <i64>::sse_decode(deserializer);
+        return crate::api::NoteRevisionItem {
+            revision_id: var_revisionId,
+            atom_id: var_atomId,
+            content: var_content,
+            preview_text: var_previewText,
+            source_updated_at: var_sourceUpdatedAt,
+            captured_at: var_capturedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NoteRevisionsListResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::NoteRevisionItem>>::sse_decode(deserializer);
+        let mut var_appliedLimit = <u32>::sse_decode(deserializer);
+        return crate::api::NoteRevisionsListResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+            applied_limit: var_appliedLimit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NoteTagSourcesResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_manualTags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_inlineTags = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::NoteTagSourcesResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            manual_tags: var_manualTags,
+            inline_tags: var_inlineTags,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NoteTitleResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_derivedTitle = <Option<String>>::sse_decode(deserializer);
+        let mut var_titleOverride = <Option<String>>::sse_decode(deserializer);
+        return crate::api::NoteTitleResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            title: var_title,
+            derived_title: var_derivedTitle,
+            title_override: var_titleOverride,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NotesListResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::NoteItem>>::sse_decode(deserializer);
+        let mut var_appliedLimit = <u32>::sse_decode(deserializer);
+        return crate::api::NotesListResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+            applied_limit: var_appliedLimit,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::AttachmentItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::AttachmentItem>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::NoteItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::NoteItem>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::ReminderItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::ReminderItem>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::TimeEntryItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::TimeEntryItem>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::WorkspaceNodeItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::WorkspaceNodeItem>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::RecurrenceResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
+        let mut var_recurrenceRule = <Option<String>>::sse_decode(deserializer);
+        return crate::api::RecurrenceResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            atom_id: var_atomId,
+            recurrence_rule: var_recurrenceRule,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ReminderItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_reminderId = <String>::sse_decode(deserializer);
+        let mut var_atomId = <String>::sse_decode(deserializer);
+        let mut var_anchor = <String>::sse_decode(deserializer);
+        let mut var_remindAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_offsetMs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_ackedThrough = <Option<i64>>::sse_decode(deserializer);
+        let mut var_snoozedUntil = <Option<i64>>::sse_decode(deserializer);
+        let mut var_nextFireAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        return crate::api::ReminderItem {
+            reminder_id: var_reminderId,
+            atom_id: var_atomId,
+            anchor: var_anchor,
+            remind_at: var_remindAt,
+            offset_ms: var_offsetMs,
+            acked_through: var_ackedThrough,
+            snoozed_until: var_snoozedUntil,
+            next_fire_at: var_nextFireAt,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ReminderListResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::ReminderItem>>::sse_decode(deserializer);
+        return crate::api::ReminderListResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ReminderResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_reminder = <Option<crate::api::ReminderItem>>::sse_decode(deserializer);
+        return crate::api::ReminderResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            reminder: var_reminder,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ResourceLinkItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_linkId = <i64>::sse_decode(deserializer);
+        let mut var_atomId = <String>::sse_decode(deserializer);
+        let mut var_target = <String>::sse_decode(deserializer);
+        let mut var_scheme = <String>::sse_decode(deserializer);
+        let mut var_label = <Option<String>>::sse_decode(deserializer);
+        return crate::api::ResourceLinkItem {
+            link_id: var_linkId,
+            atom_id: var_atomId,
+            target: var_target,
+            scheme: var_scheme,
+            label: var_label,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ResourceLinksResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::ResourceLinkItem>>::sse_decode(deserializer);
+        return crate::api::ResourceLinksResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+        };
+    }
+}
+
+impl SseDecode for crate::api::SubtaskResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
+        let mut var_parentId = <Option<String>>::sse_decode(deserializer);
+        let mut var_subtaskIds = <Vec<String>>::sse_decode(deserializer);
+        let mut var_done = <u32>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        return crate::api::SubtaskResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            atom_id: var_atomId,
+            parent_id: var_parentId,
+            subtask_ids: var_subtaskIds,
+            done: var_done,
+            total: var_total,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TagEditResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_affectedAtoms = <u32>::sse_decode(deserializer);
+        return crate::api::TagEditResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            affected_atoms: var_affectedAtoms,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TagUsageItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_directCount = <u32>::sse_decode(deserializer);
+        let mut var_totalCount = <u32>::sse_decode(deserializer);
+        return crate::api::TagUsageItem {
+            name: var_name,
+            direct_count: var_directCount,
+            total_count: var_totalCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TagsListResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::TagsListResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            tags: var_tags,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TagsUsageResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::TagUsageItem>>::sse_decode(deserializer);
+        return crate::api::TagsUsageResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TaskDependencyResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
+        let mut var_blocked = <bool>::sse_decode(deserializer);
+        let mut var_prerequisiteIds = <Vec<String>>::sse_decode(deserializer);
+        let mut var_dependentIds = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::TaskDependencyResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            atom_id: var_atomId,
+            blocked: var_blocked,
+            prerequisite_ids: var_prerequisiteIds,
+            dependent_ids: var_dependentIds,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TimeDayTotalItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_date = <String>::sse_decode(deserializer);
+        let mut var_totalMs = <i64>::sse_decode(deserializer);
+        return crate::api::TimeDayTotalItem {
+            date: var_date,
+            total_ms: var_totalMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TimeEntryItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_entryId = <String>::sse_decode(deserializer);
+        let mut var_atomId = <String>::sse_decode(deserializer);
+        let mut var_deviceId = <String>::sse_decode(deserializer);
+        let mut var_startedAt = <i64>::sse_decode(deserializer);
+        let mut var_endedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_note = <Option<String>>::sse_decode(deserializer);
+        let mut var_running = <bool>::sse_decode(deserializer);
+        let mut var_durationMs = <i64>::sse_decode(deserializer);
+        return crate::api::TimeEntryItem {
+            entry_id: var_entryId,
+            atom_id: var_atomId,
+            device_id: var_deviceId,
+            started_at: var_startedAt,
+            ended_at: var_endedAt,
+            note: var_note,
+            running: var_running,
+            duration_ms: var_durationMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TimeEntryListResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::TimeEntryItem>>::sse_decode(deserializer);
+        let mut var_totalMs = <i64>::sse_decode(deserializer);
+        return crate::api::TimeEntryListResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+            total_ms: var_totalMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TimeEntryResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_entry = <Option<crate::api::TimeEntryItem>>::sse_decode(deserializer);
+        return crate::api::TimeEntryResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            entry: var_entry,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TimeFolderTotalItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_folderId = <String>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_totalMs = <i64>::sse_decode(deserializer);
+        return crate::api::TimeFolderTotalItem {
+            folder_id: var_folderId,
+            path: var_path,
+            total_ms: var_totalMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TimeReportResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_days = <Vec<crate::api::TimeDayTotalItem>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<crate::api::TimeTagTotalItem>>::sse_decode(deserializer);
+        let mut var_folders = <Vec<crate::api::TimeFolderTotalItem>>::sse_decode(deserializer);
+        return crate::api::TimeReportResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            days: var_days,
+            tags: var_tags,
+            folders: var_folders,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TimeTagTotalItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tag = <String>::sse_decode(deserializer);
+        let mut var_totalMs = <i64>::sse_decode(deserializer);
+        return crate::api::TimeTagTotalItem {
+            tag: var_tag,
+            total_ms: var_totalMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TrashListItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_atomId = <String>::sse_decode(deserializer);
         let mut var_kind = <String>::sse_decode(deserializer);
         let mut var_content = <String>::sse_decode(deserializer);
         let mut var_previewText = <Option<String>>::sse_decode(deserializer);
-        let mut var_previewImage = <Option<String>>::sse_decode(deserializer);
-        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
-        let mut var_startAt = <Option<i64>>::sse_decode(deserializer);
-        let mut var_endAt = <Option<i64>>::sse_decode(deserializer);
-        let mut var_taskStatus = <Option<String>>::sse_decode(deserializer);
-        let mut var_updatedAt = <i64>::sse_decode(deserializer);
-        return crate::api::AtomListItem {
+        let mut var_deletedAt = <i64>::sse_decode(deserializer);
+        let mut var_syncPending = <bool>::sse_decode(deserializer);
+        return crate::api::TrashListItem {
             atom_id: var_atomId,
             kind: var_kind,
             content: var_content,
             preview_text: var_previewText,
-            preview_image: var_previewImage,
-            tags: var_tags,
-            start_at: var_startAt,
-            end_at: var_endAt,
-            task_status: var_taskStatus,
-            updated_at: var_updatedAt,
+            deleted_at: var_deletedAt,
+            sync_pending: var_syncPending,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TrashListResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::TrashListItem>>::sse_decode(deserializer);
+        let mut var_appliedLimit = <u32>::sse_decode(deserializer);
+        return crate::api::TrashListResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+            applied_limit: var_appliedLimit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TrashPurgeResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_purged = <u32>::sse_decode(deserializer);
+        let mut var_keptForSync = <u32>::sse_decode(deserializer);
+        return crate::api::TrashPurgeResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            purged: var_purged,
+            kept_for_sync: var_keptForSync,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TrashRestoreResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
+        let mut var_noteRefNodeId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::TrashRestoreResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            atom_id: var_atomId,
+            note_ref_node_id: var_noteRefNodeId,
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::WorkspaceActionResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::WorkspaceActionResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WorkspaceListChildrenResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::WorkspaceNodeItem>>::sse_decode(deserializer);
+        return crate::api::WorkspaceListChildrenResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WorkspaceNodeItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nodeId = <String>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_parentNodeId = <Option<String>>::sse_decode(deserializer);
+        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
+        let mut var_displayName = <String>::sse_decode(deserializer);
+        let mut var_sortOrder = <i64>::sse_decode(deserializer);
+        return crate::api::WorkspaceNodeItem {
+            node_id: var_nodeId,
+            kind: var_kind,
+            parent_node_id: var_parentNodeId,
+            atom_id: var_atomId,
+            display_name: var_displayName,
+            sort_order: var_sortOrder,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WorkspaceNodeResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_node = <Option<crate::api::WorkspaceNodeItem>>::sse_decode(deserializer);
+        return crate::api::WorkspaceNodeResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            node: var_node,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WorkspaceRepairResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_renamedCount = <u32>::sse_decode(deserializer);
+        return crate::api::WorkspaceRepairResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            renamed_count: var_renamedCount,
         };
     }
 }
 
-impl SseDecode for crate::api::AtomListResponse {
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__atom_convert_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__atom_reminders_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__atom_schedule_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__atom_set_all_day_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__atom_set_due_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__atom_set_priority_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__atom_set_recurrence_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__atom_task_meta_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__atom_time_entries_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__atom_update_status_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__atom_update_status_with_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__attachment_import_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__attachment_resolve_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__attachments_gc_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__calendar_conflicts_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__calendar_days_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__calendar_free_slots_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__calendar_list_by_range_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__calendar_month_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__calendar_update_event_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__calendar_week_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__entry_create_note_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__entry_create_task_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__entry_schedule_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__entry_search_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__entry_search_page_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__links_launch_plan_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__links_list_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__links_search_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__note_attachments_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__note_create_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__note_get_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__note_links_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__note_outline_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__note_properties_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__note_revision_diff_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__note_revision_restore_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__note_revisions_list_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__note_set_tags_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__note_set_title_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__note_tag_sources_impl(port, ptr, rust_vec_len, data_len),
+        50 => wire__crate__api__note_title_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__note_update_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__notes_list_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__notes_list_by_tags_impl(port, ptr, rust_vec_len, data_len),
+        55 => {
+            wire__crate__api__recurrence_delete_occurrence_impl(port, ptr, rust_vec_len, data_len)
+        }
+        56 => wire__crate__api__recurrence_edit_occurrence_impl(port, ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__reminder_ack_impl(port, ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__reminder_add_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__reminder_remove_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__reminder_snooze_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__reminders_due_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__subtask_set_parent_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__subtasks_reorder_impl(port, ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__tag_delete_impl(port, ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__tag_rename_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__tags_list_impl(port, ptr, rust_vec_len, data_len),
+        67 => wire__crate__api__tags_merge_impl(port, ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__tags_usage_impl(port, ptr, rust_vec_len, data_len),
+        69 => wire__crate__api__task_add_dependency_impl(port, ptr, rust_vec_len, data_len),
+        70 => wire__crate__api__task_dependencies_impl(port, ptr, rust_vec_len, data_len),
+        71 => wire__crate__api__task_promote_checklist_impl(port, ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__task_remove_dependency_impl(port, ptr, rust_vec_len, data_len),
+        73 => wire__crate__api__task_subtasks_impl(port, ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__tasks_list_inbox_impl(port, ptr, rust_vec_len, data_len),
+        75 => wire__crate__api__tasks_list_inbox_unblocked_impl(port, ptr, rust_vec_len, data_len),
+        76 => wire__crate__api__tasks_list_overdue_impl(port, ptr, rust_vec_len, data_len),
+        77 => wire__crate__api__tasks_list_project_order_impl(port, ptr, rust_vec_len, data_len),
+        78 => wire__crate__api__tasks_list_subtasks_impl(port, ptr, rust_vec_len, data_len),
+        79 => wire__crate__api__tasks_list_today_impl(port, ptr, rust_vec_len, data_len),
+        80 => wire__crate__api__tasks_list_today_local_impl(port, ptr, rust_vec_len, data_len),
+        81 => wire__crate__api__tasks_list_today_unblocked_impl(port, ptr, rust_vec_len, data_len),
+        82 => wire__crate__api__tasks_list_upcoming_impl(port, ptr, rust_vec_len, data_len),
+        83 => wire__crate__api__tasks_list_upcoming_local_impl(port, ptr, rust_vec_len, data_len),
+        84 => wire__crate__api__time_entry_add_impl(port, ptr, rust_vec_len, data_len),
+        85 => wire__crate__api__time_entry_delete_impl(port, ptr, rust_vec_len, data_len),
+        86 => wire__crate__api__time_entry_update_impl(port, ptr, rust_vec_len, data_len),
+        87 => wire__crate__api__time_report_impl(port, ptr, rust_vec_len, data_len),
+        88 => wire__crate__api__time_timer_running_impl(port, ptr, rust_vec_len, data_len),
+        89 => wire__crate__api__time_timer_start_impl(port, ptr, rust_vec_len, data_len),
+        90 => wire__crate__api__time_timer_stop_impl(port, ptr, rust_vec_len, data_len),
+        91 => wire__crate__api__trash_list_impl(port, ptr, rust_vec_len, data_len),
+        92 => wire__crate__api__trash_purge_impl(port, ptr, rust_vec_len, data_len),
+        93 => wire__crate__api__trash_purge_expired_impl(port, ptr, rust_vec_len, data_len),
+        94 => wire__crate__api__trash_restore_impl(port, ptr, rust_vec_len, data_len),
+        95 => wire__crate__api__workspace_create_folder_impl(port, ptr, rust_vec_len, data_len),
+        96 => wire__crate__api__workspace_create_note_ref_impl(port, ptr, rust_vec_len, data_len),
+        97 => wire__crate__api__workspace_delete_folder_impl(port, ptr, rust_vec_len, data_len),
+        98 => wire__crate__api__workspace_list_children_impl(port, ptr, rust_vec_len, data_len),
+        99 => wire__crate__api__workspace_move_node_impl(port, ptr, rust_vec_len, data_len),
+        100 => wire__crate__api__workspace_rename_node_impl(port, ptr, rust_vec_len, data_len),
+        101 => {
+            wire__crate__api__workspace_repair_note_titles_impl(port, ptr, rust_vec_len, data_len)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        22 => wire__crate__api__configure_entry_db_path_impl(ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__configure_time_zone_impl(ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__core_version_impl(ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__entry_quick_add_parse_impl(ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__entry_search_check_impl(ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__init_logging_impl(ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__local_time_windows_impl(ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__log_dart_event_impl(ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__ping_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AtomConvertResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.task_status.into_into_dart().into_dart(),
+            self.start_at.into_into_dart().into_dart(),
+            self.end_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::AtomConvertResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AtomConvertResponse>
+    for crate::api::AtomConvertResponse
+{
+    fn into_into_dart(self) -> crate::api::AtomConvertResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AtomListItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.atom_id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.preview_text.into_into_dart().into_dart(),
+            self.preview_image.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.start_at.into_into_dart().into_dart(),
+            self.end_at.into_into_dart().into_dart(),
+            self.task_status.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AtomListItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AtomListItem> for crate::api::AtomListItem {
+    fn into_into_dart(self) -> crate::api::AtomListItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AtomListResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+            self.applied_limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AtomListResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AtomListResponse>
+    for crate::api::AtomListResponse
+{
+    fn into_into_dart(self) -> crate::api::AtomListResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AtomScheduleResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.all_day.into_into_dart().into_dart(),
+            self.start_at.into_into_dart().into_dart(),
+            self.end_at.into_into_dart().into_dart(),
+            self.start_date.into_into_dart().into_dart(),
+            self.end_date.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::AtomScheduleResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AtomScheduleResponse>
+    for crate::api::AtomScheduleResponse
+{
+    fn into_into_dart(self) -> crate::api::AtomScheduleResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AtomTaskMetaResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.priority.into_into_dart().into_dart(),
+            self.due_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::AtomTaskMetaResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AtomTaskMetaResponse>
+    for crate::api::AtomTaskMetaResponse
+{
+    fn into_into_dart(self) -> crate::api::AtomTaskMetaResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AttachmentGcResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.removed_rows.into_into_dart().into_dart(),
+            self.removed_blobs.into_into_dart().into_dart(),
+            self.freed_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::AttachmentGcResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AttachmentGcResponse>
+    for crate::api::AttachmentGcResponse
+{
+    fn into_into_dart(self) -> crate::api::AttachmentGcResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AttachmentImportResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.attachment.into_into_dart().into_dart(),
+            self.deduplicated.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::AttachmentImportResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AttachmentImportResponse>
+    for crate::api::AttachmentImportResponse
+{
+    fn into_into_dart(self) -> crate::api::AttachmentImportResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AttachmentItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.sha256.into_into_dart().into_dart(),
+            self.uri.into_into_dart().into_dart(),
+            self.byte_size.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+            self.original_name.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.ref_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AttachmentItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AttachmentItem> for crate::api::AttachmentItem {
+    fn into_into_dart(self) -> crate::api::AttachmentItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AttachmentResolveResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.attachment.into_into_dart().into_dart(),
+            self.file_path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::AttachmentResolveResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AttachmentResolveResponse>
+    for crate::api::AttachmentResolveResponse
+{
+    fn into_into_dart(self) -> crate::api::AttachmentResolveResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AttachmentsListResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::AttachmentsListResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AttachmentsListResponse>
+    for crate::api::AttachmentsListResponse
+{
+    fn into_into_dart(self) -> crate::api::AttachmentsListResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::CalendarConflictItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.first_atom_id.into_into_dart().into_dart(),
+            self.first_occurrence_start_at.into_into_dart().into_dart(),
+            self.second_atom_id.into_into_dart().into_dart(),
+            self.second_occurrence_start_at.into_into_dart().into_dart(),
+            self.overlap_start_ms.into_into_dart().into_dart(),
+            self.overlap_end_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::CalendarConflictItem
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::CalendarConflictItem>
+    for crate::api::CalendarConflictItem
+{
+    fn into_into_dart(self) -> crate::api::CalendarConflictItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::CalendarConflictsResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::CalendarConflictsResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::CalendarConflictsResponse>
+    for crate::api::CalendarConflictsResponse
+{
+    fn into_into_dart(self) -> crate::api::CalendarConflictsResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::CalendarDayItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.date.into_into_dart().into_dart(),
+            self.start_ms.into_into_dart().into_dart(),
+            self.end_ms.into_into_dart().into_dart(),
+            self.all_day.into_into_dart().into_dart(),
+            self.timed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::CalendarDayItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::CalendarDayItem>
+    for crate::api::CalendarDayItem
+{
+    fn into_into_dart(self) -> crate::api::CalendarDayItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::CalendarDaysResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.days.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::CalendarDaysResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::CalendarDaysResponse>
+    for crate::api::CalendarDaysResponse
+{
+    fn into_into_dart(self) -> crate::api::CalendarDaysResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::CalendarEntryItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.atom_id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.preview_text.into_into_dart().into_dart(),
+            self.task_status.into_into_dart().into_dart(),
+            self.all_day.into_into_dart().into_dart(),
+            self.start_at.into_into_dart().into_dart(),
+            self.end_at.into_into_dart().into_dart(),
+            self.occurrence_start_at.into_into_dart().into_dart(),
+            self.visible_start_ms.into_into_dart().into_dart(),
+            self.visible_end_ms.into_into_dart().into_dart(),
+            self.continues_before.into_into_dart().into_dart(),
+            self.continues_after.into_into_dart().into_dart(),
+            self.column.into_into_dart().into_dart(),
+            self.column_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::CalendarEntryItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::CalendarEntryItem>
+    for crate::api::CalendarEntryItem
+{
+    fn into_into_dart(self) -> crate::api::CalendarEntryItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::CalendarFreeSlotItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start_ms.into_into_dart().into_dart(),
+            self.end_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::CalendarFreeSlotItem
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::CalendarFreeSlotItem>
+    for crate::api::CalendarFreeSlotItem
+{
+    fn into_into_dart(self) -> crate::api::CalendarFreeSlotItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::CalendarFreeSlotsResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.slots.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::CalendarFreeSlotsResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::CalendarFreeSlotsResponse>
+    for crate::api::CalendarFreeSlotsResponse
+{
+    fn into_into_dart(self) -> crate::api::CalendarFreeSlotsResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::DueReminderItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.reminder.into_into_dart().into_dart(),
+            self.atom_kind.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.preview_text.into_into_dart().into_dart(),
+            self.fire_at.into_into_dart().into_dart(),
+            self.occurrence_start_at.into_into_dart().into_dart(),
+            self.snoozed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::DueReminderItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::DueReminderItem>
+    for crate::api::DueReminderItem
+{
+    fn into_into_dart(self) -> crate::api::DueReminderItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::DueRemindersResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+            self.next_wakeup_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::DueRemindersResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::DueRemindersResponse>
+    for crate::api::DueRemindersResponse
+{
+    fn into_into_dart(self) -> crate::api::DueRemindersResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EntryActionResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::EntryActionResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EntryActionResponse>
+    for crate::api::EntryActionResponse
+{
+    fn into_into_dart(self) -> crate::api::EntryActionResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EntryQuickAddResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.start_at.into_into_dart().into_dart(),
+            self.end_at.into_into_dart().into_dart(),
+            self.all_day.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.priority.into_into_dart().into_dart(),
+            self.tokens.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::EntryQuickAddResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EntryQuickAddResponse>
+    for crate::api::EntryQuickAddResponse
+{
+    fn into_into_dart(self) -> crate::api::EntryQuickAddResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EntryQuickAddToken {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+            self.char_start.into_into_dart().into_dart(),
+            self.char_end.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::EntryQuickAddToken
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EntryQuickAddToken>
+    for crate::api::EntryQuickAddToken
+{
+    fn into_into_dart(self) -> crate::api::EntryQuickAddToken {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EntrySearchHighlight {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+            self.char_start.into_into_dart().into_dart(),
+            self.char_end.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::EntrySearchHighlight
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EntrySearchHighlight>
+    for crate::api::EntrySearchHighlight
+{
+    fn into_into_dart(self) -> crate::api::EntrySearchHighlight {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EntrySearchHitItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.atom_id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.snippet.into_into_dart().into_dart(),
+            self.highlights.into_into_dart().into_dart(),
+            self.bm25.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::EntrySearchHitItem
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EntrySearchHitItem>
+    for crate::api::EntrySearchHitItem
+{
+    fn into_into_dart(self) -> crate::api::EntrySearchHitItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EntrySearchItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.atom_id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.snippet.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::EntrySearchItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EntrySearchItem>
+    for crate::api::EntrySearchItem
+{
+    fn into_into_dart(self) -> crate::api::EntrySearchItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EntrySearchPageResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+            self.next_cursor.into_into_dart().into_dart(),
+            self.applied_limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::EntrySearchPageResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EntrySearchPageResponse>
+    for crate::api::EntrySearchPageResponse
+{
+    fn into_into_dart(self) -> crate::api::EntrySearchPageResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EntrySearchResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.applied_limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::EntrySearchResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EntrySearchResponse>
+    for crate::api::EntrySearchResponse
+{
+    fn into_into_dart(self) -> crate::api::EntrySearchResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EntrySearchSyntaxResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.syntax_error.into_into_dart().into_dart(),
+            self.error_start.into_into_dart().into_dart(),
+            self.error_end.into_into_dart().into_dart(),
+            self.filter_count.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::EntrySearchSyntaxResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EntrySearchSyntaxResponse>
+    for crate::api::EntrySearchSyntaxResponse
+{
+    fn into_into_dart(self) -> crate::api::EntrySearchSyntaxResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::LaunchPlanItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.link_id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.target.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.local_path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::LaunchPlanItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::LaunchPlanItem> for crate::api::LaunchPlanItem {
+    fn into_into_dart(self) -> crate::api::LaunchPlanItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::LaunchPlanResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+            self.rejected.into_into_dart().into_dart(),
+            self.capped_count.into_into_dart().into_dart(),
+            self.applied_max_items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::LaunchPlanResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::LaunchPlanResponse>
+    for crate::api::LaunchPlanResponse
+{
+    fn into_into_dart(self) -> crate::api::LaunchPlanResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::LaunchRejectionItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.link_id.into_into_dart().into_dart(),
+            self.target.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::LaunchRejectionItem
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::LaunchRejectionItem>
+    for crate::api::LaunchRejectionItem
+{
+    fn into_into_dart(self) -> crate::api::LaunchRejectionItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::LocalTimeWindowsResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.time_zone.into_into_dart().into_dart(),
+            self.week_start.into_into_dart().into_dart(),
+            self.day_start_ms.into_into_dart().into_dart(),
+            self.day_end_ms.into_into_dart().into_dart(),
+            self.week_start_ms.into_into_dart().into_dart(),
+            self.week_end_ms.into_into_dart().into_dart(),
+            self.month_start_ms.into_into_dart().into_dart(),
+            self.month_end_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::LocalTimeWindowsResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::LocalTimeWindowsResponse>
+    for crate::api::LocalTimeWindowsResponse
+{
+    fn into_into_dart(self) -> crate::api::LocalTimeWindowsResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::LogDartEventResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::LogDartEventResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::LogDartEventResponse>
+    for crate::api::LogDartEventResponse
+{
+    fn into_into_dart(self) -> crate::api::LogDartEventResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NoteDiffLineItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.old_line.into_into_dart().into_dart(),
+            self.new_line.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NoteDiffLineItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteDiffLineItem>
+    for crate::api::NoteDiffLineItem
+{
+    fn into_into_dart(self) -> crate::api::NoteDiffLineItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NoteItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.atom_id.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.preview_text.into_into_dart().into_dart(),
+            self.preview_image.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NoteItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteItem> for crate::api::NoteItem {
+    fn into_into_dart(self) -> crate::api::NoteItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NoteLinkItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source_atom_id.into_into_dart().into_dart(),
+            self.target.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.target_atom_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NoteLinkItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteLinkItem> for crate::api::NoteLinkItem {
+    fn into_into_dart(self) -> crate::api::NoteLinkItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NoteLinksResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.outgoing.into_into_dart().into_dart(),
+            self.backlinks.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NoteLinksResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteLinksResponse>
+    for crate::api::NoteLinksResponse
+{
+    fn into_into_dart(self) -> crate::api::NoteLinksResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NoteOutlineHeading {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.level.into_into_dart().into_dart(),
+            self.depth.into_into_dart().into_dart(),
+            self.parent_index.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.byte_start.into_into_dart().into_dart(),
+            self.byte_end.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::NoteOutlineHeading
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteOutlineHeading>
+    for crate::api::NoteOutlineHeading
+{
+    fn into_into_dart(self) -> crate::api::NoteOutlineHeading {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NoteOutlineResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.headings.into_into_dart().into_dart(),
+            self.word_count.into_into_dart().into_dart(),
+            self.char_count.into_into_dart().into_dart(),
+            self.checklist_done.into_into_dart().into_dart(),
+            self.checklist_total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::NoteOutlineResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteOutlineResponse>
+    for crate::api::NoteOutlineResponse
+{
+    fn into_into_dart(self) -> crate::api::NoteOutlineResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NotePropertiesResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::NotePropertiesResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NotePropertiesResponse>
+    for crate::api::NotePropertiesResponse
+{
+    fn into_into_dart(self) -> crate::api::NotePropertiesResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NotePropertyItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.value_type.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.number.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NotePropertyItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NotePropertyItem>
+    for crate::api::NotePropertyItem
+{
+    fn into_into_dart(self) -> crate::api::NotePropertyItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NoteResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.note.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NoteResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteResponse> for crate::api::NoteResponse {
+    fn into_into_dart(self) -> crate::api::NoteResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NoteRevisionDiffResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.lines.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::NoteRevisionDiffResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteRevisionDiffResponse>
+    for crate::api::NoteRevisionDiffResponse
+{
+    fn into_into_dart(self) -> crate::api::NoteRevisionDiffResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NoteRevisionItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.revision_id.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.preview_text.into_into_dart().into_dart(),
+            self.source_updated_at.into_into_dart().into_dart(),
+            self.captured_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NoteRevisionItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteRevisionItem>
+    for crate::api::NoteRevisionItem
+{
+    fn into_into_dart(self) -> crate::api::NoteRevisionItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NoteRevisionsListResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+            self.applied_limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::NoteRevisionsListResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteRevisionsListResponse>
+    for crate::api::NoteRevisionsListResponse
+{
+    fn into_into_dart(self) -> crate::api::NoteRevisionsListResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NoteTagSourcesResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.manual_tags.into_into_dart().into_dart(),
+            self.inline_tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::NoteTagSourcesResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteTagSourcesResponse>
+    for crate::api::NoteTagSourcesResponse
+{
+    fn into_into_dart(self) -> crate::api::NoteTagSourcesResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NoteTitleResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.derived_title.into_into_dart().into_dart(),
+            self.title_override.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NoteTitleResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteTitleResponse>
+    for crate::api::NoteTitleResponse
+{
+    fn into_into_dart(self) -> crate::api::NoteTitleResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NotesListResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+            self.applied_limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NotesListResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NotesListResponse>
+    for crate::api::NotesListResponse
+{
+    fn into_into_dart(self) -> crate::api::NotesListResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RecurrenceResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.recurrence_rule.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::RecurrenceResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RecurrenceResponse>
+    for crate::api::RecurrenceResponse
+{
+    fn into_into_dart(self) -> crate::api::RecurrenceResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ReminderItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.reminder_id.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.anchor.into_into_dart().into_dart(),
+            self.remind_at.into_into_dart().into_dart(),
+            self.offset_ms.into_into_dart().into_dart(),
+            self.acked_through.into_into_dart().into_dart(),
+            self.snoozed_until.into_into_dart().into_dart(),
+            self.next_fire_at.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ReminderItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ReminderItem> for crate::api::ReminderItem {
+    fn into_into_dart(self) -> crate::api::ReminderItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ReminderListResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ReminderListResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ReminderListResponse>
+    for crate::api::ReminderListResponse
+{
+    fn into_into_dart(self) -> crate::api::ReminderListResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ReminderResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.reminder.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ReminderResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ReminderResponse>
+    for crate::api::ReminderResponse
+{
+    fn into_into_dart(self) -> crate::api::ReminderResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ResourceLinkItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.link_id.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.target.into_into_dart().into_dart(),
+            self.scheme.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ResourceLinkItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ResourceLinkItem>
+    for crate::api::ResourceLinkItem
+{
+    fn into_into_dart(self) -> crate::api::ResourceLinkItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ResourceLinksResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ResourceLinksResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ResourceLinksResponse>
+    for crate::api::ResourceLinksResponse
+{
+    fn into_into_dart(self) -> crate::api::ResourceLinksResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SubtaskResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.parent_id.into_into_dart().into_dart(),
+            self.subtask_ids.into_into_dart().into_dart(),
+            self.done.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::SubtaskResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SubtaskResponse>
+    for crate::api::SubtaskResponse
+{
+    fn into_into_dart(self) -> crate::api::SubtaskResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TagEditResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.affected_atoms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TagEditResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TagEditResponse>
+    for crate::api::TagEditResponse
+{
+    fn into_into_dart(self) -> crate::api::TagEditResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TagUsageItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.direct_count.into_into_dart().into_dart(),
+            self.total_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TagUsageItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TagUsageItem> for crate::api::TagUsageItem {
+    fn into_into_dart(self) -> crate::api::TagUsageItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TagsListResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TagsListResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TagsListResponse>
+    for crate::api::TagsListResponse
+{
+    fn into_into_dart(self) -> crate::api::TagsListResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TagsUsageResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TagsUsageResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TagsUsageResponse>
+    for crate::api::TagsUsageResponse
+{
+    fn into_into_dart(self) -> crate::api::TagsUsageResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TaskDependencyResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.blocked.into_into_dart().into_dart(),
+            self.prerequisite_ids.into_into_dart().into_dart(),
+            self.dependent_ids.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::TaskDependencyResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TaskDependencyResponse>
+    for crate::api::TaskDependencyResponse
+{
+    fn into_into_dart(self) -> crate::api::TaskDependencyResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TimeDayTotalItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.date.into_into_dart().into_dart(),
+            self.total_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TimeDayTotalItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TimeDayTotalItem>
+    for crate::api::TimeDayTotalItem
+{
+    fn into_into_dart(self) -> crate::api::TimeDayTotalItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TimeEntryItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.entry_id.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.device_id.into_into_dart().into_dart(),
+            self.started_at.into_into_dart().into_dart(),
+            self.ended_at.into_into_dart().into_dart(),
+            self.note.into_into_dart().into_dart(),
+            self.running.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TimeEntryItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TimeEntryItem> for crate::api::TimeEntryItem {
+    fn into_into_dart(self) -> crate::api::TimeEntryItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TimeEntryListResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+            self.total_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::TimeEntryListResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TimeEntryListResponse>
+    for crate::api::TimeEntryListResponse
+{
+    fn into_into_dart(self) -> crate::api::TimeEntryListResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TimeEntryResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.entry.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TimeEntryResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TimeEntryResponse>
+    for crate::api::TimeEntryResponse
+{
+    fn into_into_dart(self) -> crate::api::TimeEntryResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TimeFolderTotalItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.folder_id.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.total_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::TimeFolderTotalItem
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TimeFolderTotalItem>
+    for crate::api::TimeFolderTotalItem
+{
+    fn into_into_dart(self) -> crate::api::TimeFolderTotalItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TimeReportResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.days.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.folders.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::TimeReportResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TimeReportResponse>
+    for crate::api::TimeReportResponse
+{
+    fn into_into_dart(self) -> crate::api::TimeReportResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TimeTagTotalItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tag.into_into_dart().into_dart(),
+            self.total_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TimeTagTotalItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TimeTagTotalItem>
+    for crate::api::TimeTagTotalItem
+{
+    fn into_into_dart(self) -> crate::api::TimeTagTotalItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TrashListItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.atom_id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.preview_text.into_into_dart().into_dart(),
+            self.deleted_at.into_into_dart().into_dart(),
+            self.sync_pending.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TrashListItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TrashListItem> for crate::api::TrashListItem {
+    fn into_into_dart(self) -> crate::api::TrashListItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TrashListResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+            self.applied_limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TrashListResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TrashListResponse>
+    for crate::api::TrashListResponse
+{
+    fn into_into_dart(self) -> crate::api::TrashListResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TrashPurgeResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.purged.into_into_dart().into_dart(),
+            self.kept_for_sync.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::TrashPurgeResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TrashPurgeResponse>
+    for crate::api::TrashPurgeResponse
+{
+    fn into_into_dart(self) -> crate::api::TrashPurgeResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TrashRestoreResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.note_ref_node_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::TrashRestoreResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TrashRestoreResponse>
+    for crate::api::TrashRestoreResponse
+{
+    fn into_into_dart(self) -> crate::api::TrashRestoreResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WorkspaceActionResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WorkspaceActionResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WorkspaceActionResponse>
+    for crate::api::WorkspaceActionResponse
+{
+    fn into_into_dart(self) -> crate::api::WorkspaceActionResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WorkspaceListChildrenResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WorkspaceListChildrenResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WorkspaceListChildrenResponse>
+    for crate::api::WorkspaceListChildrenResponse
+{
+    fn into_into_dart(self) -> crate::api::WorkspaceListChildrenResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WorkspaceNodeItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.node_id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.parent_node_id.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.display_name.into_into_dart().into_dart(),
+            self.sort_order.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WorkspaceNodeItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WorkspaceNodeItem>
+    for crate::api::WorkspaceNodeItem
+{
+    fn into_into_dart(self) -> crate::api::WorkspaceNodeItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WorkspaceNodeResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.node.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WorkspaceNodeResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WorkspaceNodeResponse>
+    for crate::api::WorkspaceNodeResponse
+{
+    fn into_into_dart(self) -> crate::api::WorkspaceNodeResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::WorkspaceRepairResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.renamed_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::WorkspaceRepairResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::WorkspaceRepairResponse>
+    for crate::api::WorkspaceRepairResponse
+{
+    fn into_into_dart(self) -> crate::api::WorkspaceRepairResponse {
+        self
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::AtomConvertResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.atom_id, serializer);
+        <Option<String>>::sse_encode(self.kind, serializer);
+        <Option<String>>::sse_encode(self.task_status, serializer);
+        <Option<i64>>::sse_encode(self.start_at, serializer);
+        <Option<i64>>::sse_encode(self.end_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::AtomListItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.atom_id, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <Option<String>>::sse_encode(self.preview_text, serializer);
+        <Option<String>>::sse_encode(self.preview_image, serializer);
+        <Vec<String>>::sse_encode(self.tags, serializer);
+        <Option<i64>>::sse_encode(self.start_at, serializer);
+        <Option<i64>>::sse_encode(self.end_at, serializer);
+        <Option<String>>::sse_encode(self.task_status, serializer);
+        <i64>::sse_encode(self.updated_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::AtomListResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::AtomListItem>>::sse_encode(self.items, serializer);
+        <u32>::sse_encode(self.applied_limit, serializer);
+    }
+}
+
+impl SseEncode for crate::api::AtomScheduleResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.atom_id, serializer);
+        <bool>::sse_encode(self.all_day, serializer);
+        <Option<i64>>::sse_encode(self.start_at, serializer);
+        <Option<i64>>::sse_encode(self.end_at, serializer);
+        <Option<String>>::sse_encode(self.start_date, serializer);
+        <Option<String>>::sse_encode(self.end_date, serializer);
+    }
+}
+
+impl SseEncode for crate::api::AtomTaskMetaResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.atom_id, serializer);
+        <Option<String>>::sse_encode(self.priority, serializer);
+        <Option<i64>>::sse_encode(self.due_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::AttachmentGcResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <u32>::sse_encode(self.removed_rows, serializer);
+        <u32>::sse_encode(self.removed_blobs, serializer);
+        <u64>::sse_encode(self.freed_bytes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::AttachmentImportResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<crate::api::AttachmentItem>>::sse_encode(self.attachment, serializer);
+        <bool>::sse_encode(self.deduplicated, serializer);
+    }
+}
+
+impl SseEncode for crate::api::AttachmentItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.sha256, serializer);
+        <String>::sse_encode(self.uri, serializer);
+        <i64>::sse_encode(self.byte_size, serializer);
+        <Option<String>>::sse_encode(self.mime_type, serializer);
+        <Option<String>>::sse_encode(self.original_name, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <u32>::sse_encode(self.ref_count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::AttachmentResolveResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<crate::api::AttachmentItem>>::sse_encode(self.attachment, serializer);
+        <Option<String>>::sse_encode(self.file_path, serializer);
+    }
+}
+
+impl SseEncode for crate::api::AttachmentsListResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::AttachmentItem>>::sse_encode(self.items, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::CalendarConflictItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.first_atom_id, serializer);
+        <Option<i64>>::sse_encode(self.first_occurrence_start_at, serializer);
+        <String>::sse_encode(self.second_atom_id, serializer);
+        <Option<i64>>::sse_encode(self.second_occurrence_start_at, serializer);
+        <i64>::sse_encode(self.overlap_start_ms, serializer);
+        <i64>::sse_encode(self.overlap_end_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::CalendarConflictsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::CalendarConflictItem>>::sse_encode(self.items, serializer);
+    }
+}
+
+impl SseEncode for crate::api::CalendarDayItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.date, serializer);
+        <i64>::sse_encode(self.start_ms, serializer);
+        <i64>::sse_encode(self.end_ms, serializer);
+        <Vec<crate::api::CalendarEntryItem>>::sse_encode(self.all_day, serializer);
+        <Vec<crate::api::CalendarEntryItem>>::sse_encode(self.timed, serializer);
+    }
+}
+
+impl SseEncode for crate::api::CalendarDaysResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::CalendarDayItem>>::sse_encode(self.days, serializer);
+    }
+}
+
+impl SseEncode for crate::api::CalendarEntryItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.atom_id, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <Option<String>>::sse_encode(self.preview_text, serializer);
+        <Option<String>>::sse_encode(self.task_status, serializer);
+        <bool>::sse_encode(self.all_day, serializer);
+        <i64>::sse_encode(self.start_at, serializer);
+        <i64>::sse_encode(self.end_at, serializer);
+        <Option<i64>>::sse_encode(self.occurrence_start_at, serializer);
+        <i64>::sse_encode(self.visible_start_ms, serializer);
+        <i64>::sse_encode(self.visible_end_ms, serializer);
+        <bool>::sse_encode(self.continues_before, serializer);
+        <bool>::sse_encode(self.continues_after, serializer);
+        <u32>::sse_encode(self.column, serializer);
+        <u32>::sse_encode(self.column_count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::CalendarFreeSlotItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.start_ms, serializer);
+        <i64>::sse_encode(self.end_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::CalendarFreeSlotsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::CalendarFreeSlotItem>>::sse_encode(self.slots, serializer);
+    }
+}
+
+impl SseEncode for crate::api::DueReminderItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::ReminderItem>::sse_encode(self.reminder, serializer);
+        <String>::sse_encode(self.atom_kind, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <Option<String>>::sse_encode(self.preview_text, serializer);
+        <i64>::sse_encode(self.fire_at, serializer);
+        <Option<i64>>::sse_encode(self.occurrence_start_at, serializer);
+        <bool>::sse_encode(self.snoozed, serializer);
+    }
+}
+
+impl SseEncode for crate::api::DueRemindersResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::DueReminderItem>>::sse_encode(self.items, serializer);
+        <Option<i64>>::sse_encode(self.next_wakeup_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::EntryActionResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.atom_id, serializer);
+        <String>::sse_encode(self.message, serializer);
+    }
+}
+
+impl SseEncode for crate::api::EntryQuickAddResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <Option<i64>>::sse_encode(self.start_at, serializer);
+        <Option<i64>>::sse_encode(self.end_at, serializer);
+        <bool>::sse_encode(self.all_day, serializer);
+        <Vec<String>>::sse_encode(self.tags, serializer);
+        <Option<String>>::sse_encode(self.priority, serializer);
+        <Vec<crate::api::EntryQuickAddToken>>::sse_encode(self.tokens, serializer);
+    }
+}
+
+impl SseEncode for crate::api::EntryQuickAddToken {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <u32>::sse_encode(self.start, serializer);
+        <u32>::sse_encode(self.end, serializer);
+        <u32>::sse_encode(self.char_start, serializer);
+        <u32>::sse_encode(self.char_end, serializer);
+    }
+}
+
+impl SseEncode for crate::api::EntrySearchHighlight {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.start, serializer);
+        <u32>::sse_encode(self.end, serializer);
+        <u32>::sse_encode(self.char_start, serializer);
+        <u32>::sse_encode(self.char_end, serializer);
+    }
+}
+
+impl SseEncode for crate::api::EntrySearchHitItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.atom_id, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.snippet, serializer);
+        <Vec<crate::api::EntrySearchHighlight>>::sse_encode(self.highlights, serializer);
+        <Option<f64>>::sse_encode(self.bm25, serializer);
+        <f64>::sse_encode(self.score, serializer);
+    }
+}
+
+impl SseEncode for crate::api::EntrySearchItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.atom_id, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.snippet, serializer);
+    }
+}
+
+impl SseEncode for crate::api::EntrySearchPageResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ok = <bool>::sse_decode(deserializer);
-        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        let mut var_items = <Vec<crate::api::AtomListItem>>::sse_decode(deserializer);
-        let mut var_appliedLimit = <u32>::sse_decode(deserializer);
-        return crate::api::AtomListResponse {
-            ok: var_ok,
-            error_code: var_errorCode,
-            message: var_message,
-            items: var_items,
-            applied_limit: var_appliedLimit,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::EntrySearchHitItem>>::sse_encode(self.items, serializer);
+        <Option<String>>::sse_encode(self.next_cursor, serializer);
+        <u32>::sse_encode(self.applied_limit, serializer);
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for crate::api::EntrySearchResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <Vec<crate::api::EntrySearchItem>>::sse_encode(self.items, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <u32>::sse_encode(self.applied_limit, serializer);
     }
 }
 
-impl SseDecode for crate::api::EntryActionResponse {
+impl SseEncode for crate::api::EntrySearchSyntaxResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ok = <bool>::sse_decode(deserializer);
-        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        return crate::api::EntryActionResponse {
-            ok: var_ok,
-            atom_id: var_atomId,
-            message: var_message,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <Option<String>>::sse_encode(self.syntax_error, serializer);
+        <Option<u32>>::sse_encode(self.error_start, serializer);
+        <Option<u32>>::sse_encode(self.error_end, serializer);
+        <u32>::sse_encode(self.filter_count, serializer);
+        <String>::sse_encode(self.message, serializer);
     }
 }
 
-impl SseDecode for crate::api::EntrySearchItem {
+impl SseEncode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_atomId = <String>::sse_decode(deserializer);
-        let mut var_kind = <String>::sse_decode(deserializer);
-        let mut var_snippet = <String>::sse_decode(deserializer);
-        return crate::api::EntrySearchItem {
-            atom_id: var_atomId,
-            kind: var_kind,
-            snippet: var_snippet,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for crate::api::EntrySearchResponse {
+impl SseEncode for i64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ok = <bool>::sse_decode(deserializer);
-        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
-        let mut var_items = <Vec<crate::api::EntrySearchItem>>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        let mut var_appliedLimit = <u32>::sse_decode(deserializer);
-        return crate::api::EntrySearchResponse {
-            ok: var_ok,
-            error_code: var_errorCode,
-            items: var_items,
-            message: var_message,
-            applied_limit: var_appliedLimit,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for i64 {
+impl SseEncode for crate::api::LaunchPlanItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.link_id, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.target, serializer);
+        <Option<String>>::sse_encode(self.label, serializer);
+        <Option<String>>::sse_encode(self.local_path, serializer);
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseEncode for crate::api::LaunchPlanResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::LaunchPlanItem>>::sse_encode(self.items, serializer);
+        <Vec<crate::api::LaunchRejectionItem>>::sse_encode(self.rejected, serializer);
+        <u32>::sse_encode(self.capped_count, serializer);
+        <u32>::sse_encode(self.applied_max_items, serializer);
+    }
+}
+
+impl SseEncode for crate::api::LaunchRejectionItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.link_id, serializer);
+        <String>::sse_encode(self.target, serializer);
+        <String>::sse_encode(self.reason, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::AtomListItem> {
+impl SseEncode for Vec<crate::api::AtomListItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::AtomListItem>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::AtomListItem>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::EntrySearchItem> {
+impl SseEncode for Vec<crate::api::AttachmentItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::EntrySearchItem>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::AttachmentItem>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::NoteItem> {
+impl SseEncode for Vec<crate::api::CalendarConflictItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::NoteItem>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::CalendarConflictItem>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Vec<crate::api::CalendarDayItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::CalendarDayItem>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::WorkspaceNodeItem> {
+impl SseEncode for Vec<crate::api::CalendarEntryItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::WorkspaceNodeItem>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::CalendarEntryItem>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for crate::api::LogDartEventResponse {
+impl SseEncode for Vec<crate::api::CalendarFreeSlotItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ok = <bool>::sse_decode(deserializer);
-        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        return crate::api::LogDartEventResponse {
-            ok: var_ok,
-            error_code: var_errorCode,
-            message: var_message,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::CalendarFreeSlotItem>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::NoteItem {
+impl SseEncode for Vec<crate::api::DueReminderItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_atomId = <String>::sse_decode(deserializer);
-        let mut var_content = <String>::sse_decode(deserializer);
-        let mut var_previewText = <Option<String>>::sse_decode(deserializer);
-        let mut var_previewImage = <Option<String>>::sse_decode(deserializer);
-        let mut var_updatedAt = <i64>::sse_decode(deserializer);
-        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
-        return crate::api::NoteItem {
-            atom_id: var_atomId,
-            content: var_content,
-            preview_text: var_previewText,
-            preview_image: var_previewImage,
-            updated_at: var_updatedAt,
-            tags: var_tags,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::DueReminderItem>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::NoteResponse {
+impl SseEncode for Vec<crate::api::EntryQuickAddToken> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ok = <bool>::sse_decode(deserializer);
-        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        let mut var_note = <Option<crate::api::NoteItem>>::sse_decode(deserializer);
-        return crate::api::NoteResponse {
-            ok: var_ok,
-            error_code: var_errorCode,
-            message: var_message,
-            note: var_note,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::EntryQuickAddToken>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::NotesListResponse {
+impl SseEncode for Vec<crate::api::EntrySearchHighlight> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ok = <bool>::sse_decode(deserializer);
-        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        let mut var_items = <Vec<crate::api::NoteItem>>::sse_decode(deserializer);
-        let mut var_appliedLimit = <u32>::sse_decode(deserializer);
-        return crate::api::NotesListResponse {
-            ok: var_ok,
-            error_code: var_errorCode,
-            message: var_message,
-            items: var_items,
-            applied_limit: var_appliedLimit,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::EntrySearchHighlight>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for Vec<crate::api::EntrySearchHitItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::EntrySearchHitItem>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<i64> {
+impl SseEncode for Vec<crate::api::EntrySearchItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i64>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::EntrySearchItem>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<crate::api::NoteItem> {
+impl SseEncode for Vec<crate::api::LaunchPlanItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::NoteItem>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::LaunchPlanItem>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<u32> {
+impl SseEncode for Vec<crate::api::LaunchRejectionItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u32>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::LaunchRejectionItem>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<crate::api::WorkspaceNodeItem> {
+impl SseEncode for Vec<crate::api::NoteDiffLineItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::WorkspaceNodeItem>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::NoteDiffLineItem>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for crate::api::TagsListResponse {
+impl SseEncode for Vec<crate::api::NoteItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ok = <bool>::sse_decode(deserializer);
-        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
-        return crate::api::TagsListResponse {
-            ok: var_ok,
-            error_code: var_errorCode,
-            message: var_message,
-            tags: var_tags,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::NoteItem>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for Vec<crate::api::NoteLinkItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::NoteLinkItem>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Vec<crate::api::NoteOutlineHeading> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::NoteOutlineHeading>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for Vec<crate::api::NotePropertyItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::NotePropertyItem>::sse_encode(item, serializer);
+        }
+    }
 }
 
-impl SseDecode for crate::api::WorkspaceActionResponse {
+impl SseEncode for Vec<crate::api::NoteRevisionItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ok = <bool>::sse_decode(deserializer);
-        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        return crate::api::WorkspaceActionResponse {
-            ok: var_ok,
-            error_code: var_errorCode,
-            message: var_message,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::NoteRevisionItem>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::WorkspaceListChildrenResponse {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ok = <bool>::sse_decode(deserializer);
-        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        let mut var_items = <Vec<crate::api::WorkspaceNodeItem>>::sse_decode(deserializer);
-        return crate::api::WorkspaceListChildrenResponse {
-            ok: var_ok,
-            error_code: var_errorCode,
-            message: var_message,
-            items: var_items,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::WorkspaceNodeItem {
+impl SseEncode for Vec<crate::api::ReminderItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_nodeId = <String>::sse_decode(deserializer);
-        let mut var_kind = <String>::sse_decode(deserializer);
-        let mut var_parentNodeId = <Option<String>>::sse_decode(deserializer);
-        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
-        let mut var_displayName = <String>::sse_decode(deserializer);
-        let mut var_sortOrder = <i64>::sse_decode(deserializer);
-        return crate::api::WorkspaceNodeItem {
-            node_id: var_nodeId,
-            kind: var_kind,
-            parent_node_id: var_parentNodeId,
-            atom_id: var_atomId,
-            display_name: var_displayName,
-            sort_order: var_sortOrder,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::ReminderItem>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::WorkspaceNodeResponse {
+impl SseEncode for Vec<crate::api::ResourceLinkItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ok = <bool>::sse_decode(deserializer);
-        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        let mut var_node = <Option<crate::api::WorkspaceNodeItem>>::sse_decode(deserializer);
-        return crate::api::WorkspaceNodeResponse {
-            ok: var_ok,
-            error_code: var_errorCode,
-            message: var_message,
-            node: var_node,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::ResourceLinkItem>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Vec<crate::api::TagUsageItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TagUsageItem>::sse_encode(item, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__atom_update_status_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__calendar_list_by_range_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__calendar_update_event_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__entry_create_note_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__entry_create_task_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__entry_schedule_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__entry_search_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__note_create_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__note_get_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__note_set_tags_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__note_update_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__notes_list_impl(port, ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__tags_list_impl(port, ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__tasks_list_inbox_impl(port, ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__tasks_list_today_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__tasks_list_upcoming_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__workspace_create_folder_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__workspace_create_note_ref_impl(port, ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__workspace_delete_folder_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__workspace_list_children_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__workspace_move_node_impl(port, ptr, rust_vec_len, data_len),
-        27 => wire__crate__api__workspace_rename_node_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for Vec<crate::api::TimeDayTotalItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TimeDayTotalItem>::sse_encode(item, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        4 => wire__crate__api__configure_entry_db_path_impl(ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__core_version_impl(ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__init_logging_impl(ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__log_dart_event_impl(ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__ping_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for Vec<crate::api::TimeEntryItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TimeEntryItem>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::AtomListItem {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.atom_id.into_into_dart().into_dart(),
-            self.kind.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
-            self.preview_text.into_into_dart().into_dart(),
-            self.preview_image.into_into_dart().into_dart(),
-            self.tags.into_into_dart().into_dart(),
-            self.start_at.into_into_dart().into_dart(),
-            self.end_at.into_into_dart().into_dart(),
-            self.task_status.into_into_dart().into_dart(),
-            self.updated_at.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AtomListItem {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::AtomListItem> for crate::api::AtomListItem {
-    fn into_into_dart(self) -> crate::api::AtomListItem {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::AtomListResponse {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.ok.into_into_dart().into_dart(),
-            self.error_code.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-            self.items.into_into_dart().into_dart(),
-            self.applied_limit.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AtomListResponse {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::AtomListResponse>
-    for crate::api::AtomListResponse
-{
-    fn into_into_dart(self) -> crate::api::AtomListResponse {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::EntryActionResponse {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.ok.into_into_dart().into_dart(),
-            self.atom_id.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::EntryActionResponse
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::EntryActionResponse>
-    for crate::api::EntryActionResponse
-{
-    fn into_into_dart(self) -> crate::api::EntryActionResponse {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::EntrySearchItem {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.atom_id.into_into_dart().into_dart(),
-            self.kind.into_into_dart().into_dart(),
-            self.snippet.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for Vec<crate::api::TimeFolderTotalItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TimeFolderTotalItem>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::EntrySearchItem {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::EntrySearchItem>
-    for crate::api::EntrySearchItem
-{
-    fn into_into_dart(self) -> crate::api::EntrySearchItem {
-        self
+
+impl SseEncode for Vec<crate::api::TimeTagTotalItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TimeTagTotalItem>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::EntrySearchResponse {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.ok.into_into_dart().into_dart(),
-            self.error_code.into_into_dart().into_dart(),
-            self.items.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-            self.applied_limit.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::api::TrashListItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TrashListItem>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::EntrySearchResponse
-{
+
+impl SseEncode for Vec<crate::api::WorkspaceNodeItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::WorkspaceNodeItem>::sse_encode(item, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::EntrySearchResponse>
-    for crate::api::EntrySearchResponse
-{
-    fn into_into_dart(self) -> crate::api::EntrySearchResponse {
-        self
+
+impl SseEncode for crate::api::LocalTimeWindowsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <String>::sse_encode(self.time_zone, serializer);
+        <String>::sse_encode(self.week_start, serializer);
+        <i64>::sse_encode(self.day_start_ms, serializer);
+        <i64>::sse_encode(self.day_end_ms, serializer);
+        <i64>::sse_encode(self.week_start_ms, serializer);
+        <i64>::sse_encode(self.week_end_ms, serializer);
+        <i64>::sse_encode(self.month_start_ms, serializer);
+        <i64>::sse_encode(self.month_end_ms, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::LogDartEventResponse {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.ok.into_into_dart().into_dart(),
-            self.error_code.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::LogDartEventResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::LogDartEventResponse
-{
+
+impl SseEncode for crate::api::NoteDiffLineItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <Option<u32>>::sse_encode(self.old_line, serializer);
+        <Option<u32>>::sse_encode(self.new_line, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::LogDartEventResponse>
-    for crate::api::LogDartEventResponse
-{
-    fn into_into_dart(self) -> crate::api::LogDartEventResponse {
-        self
+
+impl SseEncode for crate::api::NoteItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.atom_id, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <Option<String>>::sse_encode(self.preview_text, serializer);
+        <Option<String>>::sse_encode(self.preview_image, serializer);
+        <i64>::sse_encode(self.updated_at, serializer);
+        <Vec<String>>::sse_encode(self.tags, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::NoteItem {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.atom_id.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
-            self.preview_text.into_into_dart().into_dart(),
-            self.preview_image.into_into_dart().into_dart(),
-            self.updated_at.into_into_dart().into_dart(),
-            self.tags.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::NoteLinkItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.source_atom_id, serializer);
+        <String>::sse_encode(self.target, serializer);
+        <Option<String>>::sse_encode(self.label, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <Option<String>>::sse_encode(self.target_atom_id, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NoteItem {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteItem> for crate::api::NoteItem {
-    fn into_into_dart(self) -> crate::api::NoteItem {
-        self
+
+impl SseEncode for crate::api::NoteLinksResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::NoteLinkItem>>::sse_encode(self.outgoing, serializer);
+        <Vec<crate::api::NoteLinkItem>>::sse_encode(self.backlinks, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::NoteResponse {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.ok.into_into_dart().into_dart(),
-            self.error_code.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-            self.note.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::NoteOutlineHeading {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.level, serializer);
+        <u32>::sse_encode(self.depth, serializer);
+        <Option<u32>>::sse_encode(self.parent_index, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <u32>::sse_encode(self.byte_start, serializer);
+        <u32>::sse_encode(self.byte_end, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NoteResponse {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteResponse> for crate::api::NoteResponse {
-    fn into_into_dart(self) -> crate::api::NoteResponse {
-        self
+
+impl SseEncode for crate::api::NoteOutlineResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::NoteOutlineHeading>>::sse_encode(self.headings, serializer);
+        <u32>::sse_encode(self.word_count, serializer);
+        <u32>::sse_encode(self.char_count, serializer);
+        <u32>::sse_encode(self.checklist_done, serializer);
+        <u32>::sse_encode(self.checklist_total, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::NotesListResponse {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.ok.into_into_dart().into_dart(),
-            self.error_code.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-            self.items.into_into_dart().into_dart(),
-            self.applied_limit.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::NotePropertiesResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::NotePropertyItem>>::sse_encode(self.items, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NotesListResponse {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::NotesListResponse>
-    for crate::api::NotesListResponse
-{
-    fn into_into_dart(self) -> crate::api::NotesListResponse {
-        self
+
+impl SseEncode for crate::api::NotePropertyItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.value_type, serializer);
+        <Option<String>>::sse_encode(self.text, serializer);
+        <Option<f64>>::sse_encode(self.number, serializer);
+        <Vec<String>>::sse_encode(self.items, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::TagsListResponse {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.ok.into_into_dart().into_dart(),
-            self.error_code.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-            self.tags.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::NoteResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<crate::api::NoteItem>>::sse_encode(self.note, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TagsListResponse {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::TagsListResponse>
-    for crate::api::TagsListResponse
-{
-    fn into_into_dart(self) -> crate::api::TagsListResponse {
-        self
+
+impl SseEncode for crate::api::NoteRevisionDiffResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::NoteDiffLineItem>>::sse_encode(self.lines, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WorkspaceActionResponse {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.ok.into_into_dart().into_dart(),
-            self.error_code.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::NoteRevisionItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.revision_id, serializer);
+        <String>::sse_encode(self.atom_id, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <Option<String>>::sse_encode(self.preview_text, serializer);
+        <i64>::sse_encode(self.source_updated_at, serializer);
+        <i64>::sse_encode(self.captured_at, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::WorkspaceActionResponse
-{
+
+impl SseEncode for crate::api::NoteRevisionsListResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::NoteRevisionItem>>::sse_encode(self.items, serializer);
+        <u32>::sse_encode(self.applied_limit, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WorkspaceActionResponse>
-    for crate::api::WorkspaceActionResponse
-{
-    fn into_into_dart(self) -> crate::api::WorkspaceActionResponse {
-        self
+
+impl SseEncode for crate::api::NoteTagSourcesResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<String>>::sse_encode(self.manual_tags, serializer);
+        <Vec<String>>::sse_encode(self.inline_tags, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WorkspaceListChildrenResponse {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.ok.into_into_dart().into_dart(),
-            self.error_code.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-            self.items.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::NoteTitleResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<String>>::sse_encode(self.derived_title, serializer);
+        <Option<String>>::sse_encode(self.title_override, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::WorkspaceListChildrenResponse
-{
+
+impl SseEncode for crate::api::NotesListResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::NoteItem>>::sse_encode(self.items, serializer);
+        <u32>::sse_encode(self.applied_limit, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WorkspaceListChildrenResponse>
-    for crate::api::WorkspaceListChildrenResponse
-{
-    fn into_into_dart(self) -> crate::api::WorkspaceListChildrenResponse {
-        self
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WorkspaceNodeItem {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.node_id.into_into_dart().into_dart(),
-            self.kind.into_into_dart().into_dart(),
-            self.parent_node_id.into_into_dart().into_dart(),
-            self.atom_id.into_into_dart().into_dart(),
-            self.display_name.into_into_dart().into_dart(),
-            self.sort_order.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::AttachmentItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::AttachmentItem>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::WorkspaceNodeItem {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WorkspaceNodeItem>
-    for crate::api::WorkspaceNodeItem
-{
-    fn into_into_dart(self) -> crate::api::WorkspaceNodeItem {
-        self
+
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::WorkspaceNodeResponse {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.ok.into_into_dart().into_dart(),
-            self.error_code.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-            self.node.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::WorkspaceNodeResponse
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::WorkspaceNodeResponse>
-    for crate::api::WorkspaceNodeResponse
-{
-    fn into_into_dart(self) -> crate::api::WorkspaceNodeResponse {
-        self
+
+impl SseEncode for Option<crate::api::NoteItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::NoteItem>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Option<crate::api::ReminderItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::ReminderItem>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::AtomListItem {
+impl SseEncode for Option<crate::api::TimeEntryItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.atom_id, serializer);
-        <String>::sse_encode(self.kind, serializer);
-        <String>::sse_encode(self.content, serializer);
-        <Option<String>>::sse_encode(self.preview_text, serializer);
-        <Option<String>>::sse_encode(self.preview_image, serializer);
-        <Vec<String>>::sse_encode(self.tags, serializer);
-        <Option<i64>>::sse_encode(self.start_at, serializer);
-        <Option<i64>>::sse_encode(self.end_at, serializer);
-        <Option<String>>::sse_encode(self.task_status, serializer);
-        <i64>::sse_encode(self.updated_at, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::TimeEntryItem>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::AtomListResponse {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.ok, serializer);
-        <Option<String>>::sse_encode(self.error_code, serializer);
-        <String>::sse_encode(self.message, serializer);
-        <Vec<crate::api::AtomListItem>>::sse_encode(self.items, serializer);
-        <u32>::sse_encode(self.applied_limit, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for Option<crate::api::WorkspaceNodeItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::WorkspaceNodeItem>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::EntryActionResponse {
+impl SseEncode for crate::api::RecurrenceResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.ok, serializer);
-        <Option<String>>::sse_encode(self.atom_id, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
         <String>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.atom_id, serializer);
+        <Option<String>>::sse_encode(self.recurrence_rule, serializer);
     }
 }
 
-impl SseEncode for crate::api::EntrySearchItem {
+impl SseEncode for crate::api::ReminderItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.reminder_id, serializer);
         <String>::sse_encode(self.atom_id, serializer);
-        <String>::sse_encode(self.kind, serializer);
-        <String>::sse_encode(self.snippet, serializer);
+        <String>::sse_encode(self.anchor, serializer);
+        <Option<i64>>::sse_encode(self.remind_at, serializer);
+        <Option<i64>>::sse_encode(self.offset_ms, serializer);
+        <Option<i64>>::sse_encode(self.acked_through, serializer);
+        <Option<i64>>::sse_encode(self.snoozed_until, serializer);
+        <Option<i64>>::sse_encode(self.next_fire_at, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
     }
 }
 
-impl SseEncode for crate::api::EntrySearchResponse {
+impl SseEncode for crate::api::ReminderListResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.ok, serializer);
         <Option<String>>::sse_encode(self.error_code, serializer);
-        <Vec<crate::api::EntrySearchItem>>::sse_encode(self.items, serializer);
         <String>::sse_encode(self.message, serializer);
-        <u32>::sse_encode(self.applied_limit, serializer);
+        <Vec<crate::api::ReminderItem>>::sse_encode(self.items, serializer);
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for crate::api::ReminderResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<crate::api::ReminderItem>>::sse_encode(self.reminder, serializer);
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for crate::api::ResourceLinkItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
+        <i64>::sse_encode(self.link_id, serializer);
+        <String>::sse_encode(self.atom_id, serializer);
+        <String>::sse_encode(self.target, serializer);
+        <String>::sse_encode(self.scheme, serializer);
+        <Option<String>>::sse_encode(self.label, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::AtomListItem> {
+impl SseEncode for crate::api::ResourceLinksResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::AtomListItem>::sse_encode(item, serializer);
-        }
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::ResourceLinkItem>>::sse_encode(self.items, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::EntrySearchItem> {
+impl SseEncode for crate::api::SubtaskResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::EntrySearchItem>::sse_encode(item, serializer);
-        }
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.atom_id, serializer);
+        <Option<String>>::sse_encode(self.parent_id, serializer);
+        <Vec<String>>::sse_encode(self.subtask_ids, serializer);
+        <u32>::sse_encode(self.done, serializer);
+        <u32>::sse_encode(self.total, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::NoteItem> {
+impl SseEncode for crate::api::TagEditResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::NoteItem>::sse_encode(item, serializer);
-        }
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <u32>::sse_encode(self.affected_atoms, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::TagUsageItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.name, serializer);
+        <u32>::sse_encode(self.direct_count, serializer);
+        <u32>::sse_encode(self.total_count, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::WorkspaceNodeItem> {
+impl SseEncode for crate::api::TagsListResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::WorkspaceNodeItem>::sse_encode(item, serializer);
-        }
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<String>>::sse_encode(self.tags, serializer);
     }
 }
 
-impl SseEncode for crate::api::LogDartEventResponse {
+impl SseEncode for crate::api::TagsUsageResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.ok, serializer);
         <Option<String>>::sse_encode(self.error_code, serializer);
         <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::TagUsageItem>>::sse_encode(self.items, serializer);
     }
 }
 
-impl SseEncode for crate::api::NoteItem {
+impl SseEncode for crate::api::TaskDependencyResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.atom_id, serializer);
+        <bool>::sse_encode(self.blocked, serializer);
+        <Vec<String>>::sse_encode(self.prerequisite_ids, serializer);
+        <Vec<String>>::sse_encode(self.dependent_ids, serializer);
+    }
+}
+
+impl SseEncode for crate::api::TimeDayTotalItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.date, serializer);
+        <i64>::sse_encode(self.total_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::TimeEntryItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.entry_id, serializer);
         <String>::sse_encode(self.atom_id, serializer);
-        <String>::sse_encode(self.content, serializer);
-        <Option<String>>::sse_encode(self.preview_text, serializer);
-        <Option<String>>::sse_encode(self.preview_image, serializer);
-        <i64>::sse_encode(self.updated_at, serializer);
-        <Vec<String>>::sse_encode(self.tags, serializer);
+        <String>::sse_encode(self.device_id, serializer);
+        <i64>::sse_encode(self.started_at, serializer);
+        <Option<i64>>::sse_encode(self.ended_at, serializer);
+        <Option<String>>::sse_encode(self.note, serializer);
+        <bool>::sse_encode(self.running, serializer);
+        <i64>::sse_encode(self.duration_ms, serializer);
     }
 }
 
-impl SseEncode for crate::api::NoteResponse {
+impl SseEncode for crate::api::TimeEntryListResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.ok, serializer);
         <Option<String>>::sse_encode(self.error_code, serializer);
         <String>::sse_encode(self.message, serializer);
-        <Option<crate::api::NoteItem>>::sse_encode(self.note, serializer);
+        <Vec<crate::api::TimeEntryItem>>::sse_encode(self.items, serializer);
+        <i64>::sse_encode(self.total_ms, serializer);
     }
 }
 
-impl SseEncode for crate::api::NotesListResponse {
+impl SseEncode for crate::api::TimeEntryResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.ok, serializer);
         <Option<String>>::sse_encode(self.error_code, serializer);
         <String>::sse_encode(self.message, serializer);
-        <Vec<crate::api::NoteItem>>::sse_encode(self.items, serializer);
-        <u32>::sse_encode(self.applied_limit, serializer);
+        <Option<crate::api::TimeEntryItem>>::sse_encode(self.entry, serializer);
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for crate::api::TimeFolderTotalItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.folder_id, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <i64>::sse_encode(self.total_ms, serializer);
     }
 }
 
-impl SseEncode for Option<i64> {
+impl SseEncode for crate::api::TimeReportResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i64>::sse_encode(value, serializer);
-        }
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::TimeDayTotalItem>>::sse_encode(self.days, serializer);
+        <Vec<crate::api::TimeTagTotalItem>>::sse_encode(self.tags, serializer);
+        <Vec<crate::api::TimeFolderTotalItem>>::sse_encode(self.folders, serializer);
     }
 }
 
-impl SseEncode for Option<crate::api::NoteItem> {
+impl SseEncode for crate::api::TimeTagTotalItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::NoteItem>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.tag, serializer);
+        <i64>::sse_encode(self.total_ms, serializer);
     }
 }
 
-impl SseEncode for Option<u32> {
+impl SseEncode for crate::api::TrashListItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u32>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.atom_id, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <Option<String>>::sse_encode(self.preview_text, serializer);
+        <i64>::sse_encode(self.deleted_at, serializer);
+        <bool>::sse_encode(self.sync_pending, serializer);
     }
 }
 
-impl SseEncode for Option<crate::api::WorkspaceNodeItem> {
+impl SseEncode for crate::api::TrashListResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::WorkspaceNodeItem>::sse_encode(value, serializer);
-        }
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::TrashListItem>>::sse_encode(self.items, serializer);
+        <u32>::sse_encode(self.applied_limit, serializer);
     }
 }
 
-impl SseEncode for crate::api::TagsListResponse {
+impl SseEncode for crate::api::TrashPurgeResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.ok, serializer);
         <Option<String>>::sse_encode(self.error_code, serializer);
         <String>::sse_encode(self.message, serializer);
-        <Vec<String>>::sse_encode(self.tags, serializer);
+        <u32>::sse_encode(self.purged, serializer);
+        <u32>::sse_encode(self.kept_for_sync, serializer);
+    }
+}
+
+impl SseEncode for crate::api::TrashRestoreResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.atom_id, serializer);
+        <Option<String>>::sse_encode(self.note_ref_node_id, serializer);
     }
 }
 
@@ -2112,6 +9050,13 @@ impl SseEncode for u32 {
     }
 }
 
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2165,6 +9110,16 @@ impl SseEncode for crate::api::WorkspaceNodeResponse {
     }
 }
 
+impl SseEncode for crate::api::WorkspaceRepairResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <u32>::sse_encode(self.renamed_count, serializer);
+    }
+}
+
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {